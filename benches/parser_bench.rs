@@ -2,7 +2,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use owl2_reasoner::parser::turtle::TurtleParser;
-use owl2_reasoner::parser::OntologyParser;
+use owl2_reasoner::parser::{NtriplesParser, OntologyParser, ParserConfig};
 
 /// Benchmark Turtle parsing performance
 pub fn bench_turtle_parsing(c: &mut Criterion) {
@@ -110,5 +110,70 @@ fn generate_large_turtle() -> String {
     content
 }
 
-criterion_group!(benches, bench_turtle_parsing);
+/// Benchmark sequential against parallel parsing of a million-line N-Triples file
+pub fn bench_large_ntriples_parsing(c: &mut Criterion) {
+    let path = std::env::temp_dir().join("owl2_reasoner_bench_1m.nt");
+    std::fs::write(&path, generate_ntriples(1_000_000)).expect("write N-Triples file");
+
+    let mut group = c.benchmark_group("ntriples_parsing");
+    group.sample_size(10);
+
+    for parallel in [false, true] {
+        let parser = NtriplesParser::with_config(ParserConfig {
+            parallel_ntriples: parallel,
+            ..Default::default()
+        });
+        let name = if parallel { "parallel" } else { "sequential" };
+        group.bench_with_input(
+            BenchmarkId::new("parse_1m_lines", name),
+            &path,
+            |b, path| {
+                b.iter(|| {
+                    let result = parser.parse_file(black_box(path));
+                    let _ = black_box(result);
+                })
+            },
+        );
+    }
+
+    group.finish();
+    let _ = std::fs::remove_file(&path);
+}
+
+/// Helper function to generate N-Triples content with the given number of lines
+fn generate_ntriples(lines: usize) -> String {
+    const RDF_TYPE: &str = "<http://www.w3.org/1999/02/22-rdf-syntax-ns#type>";
+    const SUBCLASS_OF: &str = "<http://www.w3.org/2000/01/rdf-schema#subClassOf>";
+    const LABEL: &str = "<http://www.w3.org/2000/01/rdf-schema#label>";
+
+    // Groups of four lines: a class, its superclass, an instance and its label
+    let mut content = String::with_capacity(lines * 100);
+    for i in 0..lines {
+        let k = i / 4;
+        let line = match i % 4 {
+            0 => format!(
+                "<http://example.org/Class{}> {} <http://www.w3.org/2002/07/owl#Class> .\n",
+                k, RDF_TYPE
+            ),
+            1 => format!(
+                "<http://example.org/Class{}> {} <http://example.org/Class{}> .\n",
+                k + 1,
+                SUBCLASS_OF,
+                (k + 1) / 2
+            ),
+            2 => format!(
+                "<http://example.org/Individual{}> {} <http://example.org/Class{}> .\n",
+                k, RDF_TYPE, k
+            ),
+            _ => format!(
+                "<http://example.org/Individual{}> {} \"individual {}\"@en .\n",
+                k, LABEL, k
+            ),
+        };
+        content.push_str(&line);
+    }
+    content
+}
+
+criterion_group!(benches, bench_turtle_parsing, bench_large_ntriples_parsing);
 criterion_main!(benches);
@@ -6,7 +6,8 @@ use owl2_reasoner::entities::Class;
 use owl2_reasoner::iri::IRI;
 use owl2_reasoner::ontology::Ontology;
 use owl2_reasoner::reasoning::{
-    tableaux::ReasoningConfig as TableauxConfig, OwlReasoner, ReasoningConfig, SimpleReasoner,
+    tableaux::{ReasoningConfig as TableauxConfig, TableauxReasoner},
+    OwlReasoner, ReasoningConfig, SimpleReasoner,
 };
 use owl2_reasoner::Reasoner;
 
@@ -39,6 +40,9 @@ pub fn bench_consistency_checking(c: &mut Criterion) {
             enable_parallel: false,
             parallel_workers: None,
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         };
         let advanced_config = ReasoningConfig {
             enable_reasoning: true,
@@ -73,6 +77,9 @@ pub fn bench_consistency_checking(c: &mut Criterion) {
                 enable_parallel: false,
                 parallel_workers: None,
                 parallel_chunk_size: 64,
+                max_branches: None,
+                deterministic: false,
+                record_trace: false,
             },
         };
         // Hybrid reasoning configuration
@@ -127,6 +134,9 @@ pub fn bench_class_satisfiability(c: &mut Criterion) {
             enable_parallel: false,
             parallel_workers: None,
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         };
         let advanced_config = ReasoningConfig {
             enable_reasoning: true,
@@ -154,6 +164,35 @@ pub fn bench_class_satisfiability(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark checking every class in one pass against per-class checks
+pub fn bench_all_class_satisfiability(c: &mut Criterion) {
+    let mut group = c.benchmark_group("all_class_satisfiability");
+
+    for size in [10, 50, 100].iter() {
+        let ontology = create_hierarchy_ontology(*size);
+
+        group.bench_with_input(BenchmarkId::new("per_class", size), size, |b, _| {
+            b.iter(|| {
+                let reasoner = TableauxReasoner::new(black_box(ontology.clone()));
+                for class in ontology.classes().iter() {
+                    let result = reasoner.is_class_satisfiable(black_box(class.iri()));
+                    let _ = black_box(result);
+                }
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("all_in_one_pass", size), size, |b, _| {
+            b.iter(|| {
+                let mut reasoner = TableauxReasoner::new(black_box(ontology.clone()));
+                let result = reasoner.check_all_class_satisfiability();
+                let _ = black_box(result);
+            })
+        });
+    }
+
+    group.finish();
+}
+
 /// Benchmark cache operations
 pub fn bench_cache_operations(c: &mut Criterion) {
     let mut group = c.benchmark_group("cache_operations");
@@ -238,6 +277,9 @@ pub fn bench_subclass_checking(c: &mut Criterion) {
             enable_parallel: false,
             parallel_workers: None,
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         };
         let advanced_config = ReasoningConfig {
             enable_reasoning: true,
@@ -293,6 +335,9 @@ pub fn bench_memory_usage(c: &mut Criterion) {
             enable_parallel: false,
             parallel_workers: None,
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         };
         let advanced_config = ReasoningConfig {
             enable_reasoning: true,
@@ -346,6 +391,9 @@ pub fn bench_large_scale_ontologies(c: &mut Criterion) {
             enable_parallel: false,
             parallel_workers: None,
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         };
         let advanced_config = ReasoningConfig {
             enable_reasoning: true,
@@ -404,6 +452,7 @@ criterion_group!(
     reasoning_bench,
     bench_consistency_checking,
     bench_class_satisfiability,
+    bench_all_class_satisfiability,
     bench_cache_operations,
     bench_subclass_checking,
     bench_memory_usage,
@@ -339,6 +339,9 @@ pub fn bench_tableaux_integration(c: &mut Criterion) {
                             enable_parallel: false,
                             parallel_workers: None,
                             parallel_chunk_size: 64,
+                            max_branches: None,
+                            deterministic: false,
+                            record_trace: false,
                         },
                     );
 
@@ -362,6 +365,9 @@ pub fn bench_tableaux_integration(c: &mut Criterion) {
                     enable_parallel: false,
                     parallel_workers: None,
                     parallel_chunk_size: 64,
+                    max_branches: None,
+                    deterministic: false,
+                    record_trace: false,
                 },
             ),
             (
@@ -374,6 +380,9 @@ pub fn bench_tableaux_integration(c: &mut Criterion) {
                     enable_parallel: false,
                     parallel_workers: None,
                     parallel_chunk_size: 64,
+                    max_branches: None,
+                    deterministic: false,
+                    record_trace: false,
                 },
             ),
             (
@@ -386,6 +395,9 @@ pub fn bench_tableaux_integration(c: &mut Criterion) {
                     enable_parallel: false,
                     parallel_workers: None,
                     parallel_chunk_size: 64,
+                    max_branches: None,
+                    deterministic: false,
+                    record_trace: false,
                 },
             ),
         ];
@@ -3,27 +3,30 @@
 //! Defines complex class expressions for building class hierarchies.
 
 use super::property_expressions::{DataPropertyExpression, ObjectPropertyExpression};
-use crate::entities::Class;
+use crate::entities::{Class, Individual};
 use crate::iri::IRI;
+use once_cell::sync::Lazy;
 use smallvec::SmallVec;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 /// A class expression in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ClassExpression {
     /// Named class
     Class(Class),
     /// Object intersection of (C and D)
-    ObjectIntersectionOf(SmallVec<[Box<ClassExpression>; 4]>),
+    ObjectIntersectionOf(SmallVec<[Arc<ClassExpression>; 4]>),
     /// Object union of (C or D)
-    ObjectUnionOf(SmallVec<[Box<ClassExpression>; 4]>),
+    ObjectUnionOf(SmallVec<[Arc<ClassExpression>; 4]>),
     /// Object complement of (not C)
-    ObjectComplementOf(Box<ClassExpression>),
+    ObjectComplementOf(Arc<ClassExpression>),
     /// Object one of {a, b, c}
     ObjectOneOf(Box<SmallVec<[crate::entities::Individual; 8]>>),
     /// Object some values from (∃R.C)
-    ObjectSomeValuesFrom(Box<ObjectPropertyExpression>, Box<ClassExpression>),
+    ObjectSomeValuesFrom(Box<ObjectPropertyExpression>, Arc<ClassExpression>),
     /// Object all values from (∀R.C)
-    ObjectAllValuesFrom(Box<ObjectPropertyExpression>, Box<ClassExpression>),
+    ObjectAllValuesFrom(Box<ObjectPropertyExpression>, Arc<ClassExpression>),
     /// Object has value (R(a))
     ObjectHasValue(Box<ObjectPropertyExpression>, crate::entities::Individual),
     /// Object has self (R(a,a))
@@ -53,19 +56,19 @@ impl ClassExpression {
     pub fn simplify(&self) -> ClassExpression {
         match self {
             ClassExpression::ObjectIntersectionOf(operands) => {
-                let simplified: SmallVec<[Box<ClassExpression>; 4]> =
-                    operands.iter().map(|op| Box::new(op.simplify())).collect();
+                let simplified: SmallVec<[Arc<ClassExpression>; 4]> =
+                    operands.iter().map(|op| Arc::new(op.simplify())).collect();
                 if simplified.len() == 1 {
-                    *simplified[0].clone()
+                    (*simplified[0]).clone()
                 } else {
                     ClassExpression::ObjectIntersectionOf(simplified)
                 }
             }
             ClassExpression::ObjectUnionOf(operands) => {
-                let simplified: SmallVec<[Box<ClassExpression>; 4]> =
-                    operands.iter().map(|op| Box::new(op.simplify())).collect();
+                let simplified: SmallVec<[Arc<ClassExpression>; 4]> =
+                    operands.iter().map(|op| Arc::new(op.simplify())).collect();
                 if simplified.len() == 1 {
-                    *simplified[0].clone()
+                    (*simplified[0]).clone()
                 } else {
                     ClassExpression::ObjectUnionOf(simplified)
                 }
@@ -191,6 +194,354 @@ impl ClassExpression {
     }
 }
 
+impl ClassExpression {
+    /// Get the complement of this class expression in negation normal form
+    ///
+    /// The negation is pushed through intersections, unions and object
+    /// restrictions, so `¬∃R.C` becomes `∀R.¬C`; double negations cancel.
+    pub fn negate(&self) -> ClassExpression {
+        match self {
+            ClassExpression::ObjectComplementOf(expr) => (**expr).clone(),
+            ClassExpression::ObjectIntersectionOf(operands) => ClassExpression::ObjectUnionOf(
+                operands.iter().map(|op| Arc::new(op.negate())).collect(),
+            ),
+            ClassExpression::ObjectUnionOf(operands) => ClassExpression::ObjectIntersectionOf(
+                operands.iter().map(|op| Arc::new(op.negate())).collect(),
+            ),
+            ClassExpression::ObjectSomeValuesFrom(prop, expr) => {
+                ClassExpression::ObjectAllValuesFrom(prop.clone(), Arc::new(expr.negate()))
+            }
+            ClassExpression::ObjectAllValuesFrom(prop, expr) => {
+                ClassExpression::ObjectSomeValuesFrom(prop.clone(), Arc::new(expr.negate()))
+            }
+            _ => ClassExpression::ObjectComplementOf(Arc::new(self.clone())),
+        }
+    }
+}
+
+/// The top class `owl:Thing`, shared as the filler of unqualified cardinality restrictions
+static THING: Lazy<ClassExpression> =
+    Lazy::new(|| ClassExpression::Class(Class::new(crate::constants::owl::thing())));
+
+/// The kind of bound a cardinality restriction places on its neighbours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardinalityBound {
+    /// At least `n` neighbours
+    Min,
+    /// At most `n` neighbours
+    Max,
+    /// Exactly `n` neighbours
+    Exact,
+}
+
+/// An object cardinality restriction `≥n R.C`, `≤n R.C` or `=n R.C`
+///
+/// Unqualified restrictions such as `≥n R` are read as qualified with
+/// `owl:Thing`, so the reasoner treats both forms through this one view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ObjectCardinality<'a> {
+    /// Whether the restriction is a lower, upper or exact bound
+    pub bound: CardinalityBound,
+    /// The number of neighbours
+    pub cardinality: u32,
+    /// The property leading to the neighbours
+    pub property: &'a ObjectPropertyExpression,
+    /// The class the counted neighbours belong to
+    pub filler: &'a ClassExpression,
+}
+
+impl ObjectCardinality<'_> {
+    /// Get the least number of neighbours required, if this is a lower bound
+    pub fn min(&self) -> Option<u32> {
+        match self.bound {
+            CardinalityBound::Min | CardinalityBound::Exact => Some(self.cardinality),
+            CardinalityBound::Max => None,
+        }
+    }
+
+    /// Get the most neighbours allowed, if this is an upper bound
+    pub fn max(&self) -> Option<u32> {
+        match self.bound {
+            CardinalityBound::Max | CardinalityBound::Exact => Some(self.cardinality),
+            CardinalityBound::Min => None,
+        }
+    }
+
+    /// Check whether the filler is anything other than `owl:Thing`
+    pub fn is_qualified(&self) -> bool {
+        !self.filler.is_thing()
+    }
+
+    /// Get the equivalent unqualified class expression, if the filler is `owl:Thing`
+    pub fn to_unqualified(&self) -> Option<ClassExpression> {
+        if self.is_qualified() {
+            return None;
+        }
+        let property = Box::new(self.property.clone());
+        Some(match self.bound {
+            CardinalityBound::Min => {
+                ClassExpression::ObjectMinCardinality(self.cardinality, property)
+            }
+            CardinalityBound::Max => {
+                ClassExpression::ObjectMaxCardinality(self.cardinality, property)
+            }
+            CardinalityBound::Exact => {
+                ClassExpression::ObjectExactCardinality(self.cardinality, property)
+            }
+        })
+    }
+}
+
+impl ClassExpression {
+    /// Get the top class `owl:Thing`
+    pub fn thing() -> &'static ClassExpression {
+        &THING
+    }
+
+    /// Check if this is the named class `owl:Thing`
+    pub fn is_thing(&self) -> bool {
+        matches!(self, ClassExpression::Class(class) if class.is_thing())
+    }
+
+    /// View an object cardinality restriction as qualified, with `owl:Thing` as filler
+    pub fn as_object_cardinality(&self) -> Option<ObjectCardinality<'_>> {
+        let (bound, cardinality, property) = match self {
+            ClassExpression::ObjectMinCardinality(n, property) => {
+                (CardinalityBound::Min, *n, property)
+            }
+            ClassExpression::ObjectMaxCardinality(n, property) => {
+                (CardinalityBound::Max, *n, property)
+            }
+            ClassExpression::ObjectExactCardinality(n, property) => {
+                (CardinalityBound::Exact, *n, property)
+            }
+            _ => return None,
+        };
+        Some(ObjectCardinality {
+            bound,
+            cardinality,
+            property,
+            filler: Self::thing(),
+        })
+    }
+}
+
+impl ClassExpression {
+    /// Get a canonical form of this class expression for caching and deduplication
+    ///
+    /// The key is in negation normal form, nested intersections and unions are
+    /// flattened, and the operands of commutative constructors are sorted and
+    /// deduplicated, so `A ⊓ B` and `B ⊓ A` have the same key.
+    pub fn semantic_key(&self) -> ClassExpression {
+        match self {
+            ClassExpression::ObjectComplementOf(inner) => match &**inner {
+                ClassExpression::ObjectComplementOf(expr) => expr.semantic_key(),
+                ClassExpression::ObjectIntersectionOf(_)
+                | ClassExpression::ObjectUnionOf(_)
+                | ClassExpression::ObjectSomeValuesFrom(_, _)
+                | ClassExpression::ObjectAllValuesFrom(_, _) => inner.negate().semantic_key(),
+                _ => ClassExpression::ObjectComplementOf(Arc::new(inner.semantic_key())),
+            },
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                Self::canonical_operands(operands, true)
+            }
+            ClassExpression::ObjectUnionOf(operands) => Self::canonical_operands(operands, false),
+            ClassExpression::ObjectOneOf(individuals) => {
+                let mut individuals = individuals.to_vec();
+                individuals.sort();
+                individuals.dedup();
+                ClassExpression::ObjectOneOf(Box::new(individuals.into()))
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                ClassExpression::ObjectSomeValuesFrom(
+                    property.clone(),
+                    Arc::new(filler.semantic_key()),
+                )
+            }
+            ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                ClassExpression::ObjectAllValuesFrom(
+                    property.clone(),
+                    Arc::new(filler.semantic_key()),
+                )
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Canonicalize the operands of an intersection or union
+    fn canonical_operands(
+        operands: &SmallVec<[Arc<ClassExpression>; 4]>,
+        intersection: bool,
+    ) -> ClassExpression {
+        let mut flattened = Vec::new();
+        for operand in operands {
+            // A named class is its own key, so its allocation is shared
+            if operand.is_named() {
+                flattened.push(Arc::clone(operand));
+                continue;
+            }
+            match (operand.semantic_key(), intersection) {
+                (ClassExpression::ObjectIntersectionOf(nested), true)
+                | (ClassExpression::ObjectUnionOf(nested), false) => flattened.extend(nested),
+                (operand, _) => flattened.push(Arc::new(operand)),
+            }
+        }
+        flattened.sort();
+        flattened.dedup();
+
+        if flattened.len() == 1 {
+            Arc::unwrap_or_clone(flattened.remove(0))
+        } else if intersection {
+            ClassExpression::ObjectIntersectionOf(flattened.into())
+        } else {
+            ClassExpression::ObjectUnionOf(flattened.into())
+        }
+    }
+}
+
+impl ClassExpression {
+    /// Get the IRIs of all entities used in this class expression
+    pub fn signature(&self) -> Vec<Arc<IRI>> {
+        let mut signature = Vec::new();
+        self.collect_signature(&mut signature);
+        signature
+    }
+
+    /// Append the IRIs of all entities used in this class expression
+    pub(crate) fn collect_signature(&self, signature: &mut Vec<Arc<IRI>>) {
+        match self {
+            ClassExpression::Class(class) => signature.push(class.iri().clone()),
+            ClassExpression::ObjectIntersectionOf(operands)
+            | ClassExpression::ObjectUnionOf(operands) => {
+                for operand in operands {
+                    operand.collect_signature(signature);
+                }
+            }
+            ClassExpression::ObjectComplementOf(expr) => expr.collect_signature(signature),
+            ClassExpression::ObjectOneOf(individuals) => {
+                signature.extend(individuals.iter().filter_map(|i| i.iri().cloned()));
+            }
+            ClassExpression::ObjectSomeValuesFrom(prop, expr)
+            | ClassExpression::ObjectAllValuesFrom(prop, expr) => {
+                signature.push(prop.property_iri().clone());
+                expr.collect_signature(signature);
+            }
+            ClassExpression::ObjectHasValue(prop, individual) => {
+                signature.push(prop.property_iri().clone());
+                if let Individual::Named(named) = individual {
+                    signature.push(named.iri().clone());
+                }
+            }
+            ClassExpression::ObjectHasSelf(prop)
+            | ClassExpression::ObjectMinCardinality(_, prop)
+            | ClassExpression::ObjectMaxCardinality(_, prop)
+            | ClassExpression::ObjectExactCardinality(_, prop) => {
+                signature.push(prop.property_iri().clone());
+            }
+            ClassExpression::DataSomeValuesFrom(prop, range)
+            | ClassExpression::DataAllValuesFrom(prop, range) => {
+                signature.push(prop.property_iri().clone());
+                range.collect_signature(signature);
+            }
+            ClassExpression::DataHasValue(prop, _)
+            | ClassExpression::DataMinCardinality(_, prop)
+            | ClassExpression::DataMaxCardinality(_, prop)
+            | ClassExpression::DataExactCardinality(_, prop) => {
+                signature.push(prop.property_iri().clone());
+            }
+        }
+    }
+}
+
+/// Interns class expressions so that identical subtrees share one allocation
+///
+/// Like IRI interning, equal nested expressions are replaced by a single shared
+/// `Arc`, so an `∃R.C` repeated across many axioms is stored once. Operands
+/// are interned bottom-up, which makes equal subtrees share storage at every
+/// level of nesting. Entries stay cached until [`evict_unused`](Self::evict_unused)
+/// or [`clear`](Self::clear) drops them.
+#[derive(Debug, Clone, Default)]
+pub struct ClassExpressionCache {
+    expressions: HashSet<Arc<ClassExpression>>,
+}
+
+impl ClassExpressionCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the shared copy of an expression, interning its operands first
+    pub fn intern(&mut self, expression: ClassExpression) -> Arc<ClassExpression> {
+        let mut shared = Arc::new(expression);
+        self.share(&mut shared);
+        shared
+    }
+
+    /// Replace the operands of an expression with their shared copies
+    ///
+    /// The expression itself stays where it is, which suits expressions held
+    /// inline by axioms.
+    pub fn intern_operands(&mut self, expression: &mut ClassExpression) {
+        match expression {
+            ClassExpression::ObjectIntersectionOf(operands)
+            | ClassExpression::ObjectUnionOf(operands) => {
+                for operand in operands.iter_mut() {
+                    self.share(operand);
+                }
+            }
+            ClassExpression::ObjectComplementOf(operand)
+            | ClassExpression::ObjectSomeValuesFrom(_, operand)
+            | ClassExpression::ObjectAllValuesFrom(_, operand) => self.share(operand),
+            _ => {}
+        }
+    }
+
+    /// Point `operand` at the cached copy of its expression, caching it if new
+    fn share(&mut self, operand: &mut Arc<ClassExpression>) {
+        if let Some(shared) = self.expressions.get(&**operand) {
+            *operand = Arc::clone(shared);
+            return;
+        }
+        self.intern_operands(Arc::make_mut(operand));
+        self.expressions.insert(Arc::clone(operand));
+    }
+
+    /// Number of distinct expressions in the cache
+    pub fn len(&self) -> usize {
+        self.expressions.len()
+    }
+
+    /// Check whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.expressions.is_empty()
+    }
+
+    /// Drop the cached expressions nothing outside the cache uses any more
+    ///
+    /// An expression is also kept while a cached expression holds it, so the
+    /// sweep repeats until the operands of evicted expressions are released
+    /// too. Returns the number of expressions evicted.
+    pub fn evict_unused(&mut self) -> usize {
+        let before = self.expressions.len();
+        loop {
+            let remaining = self.expressions.len();
+            self.expressions
+                .retain(|expression| Arc::strong_count(expression) > 1);
+            if self.expressions.len() == remaining {
+                return before - remaining;
+            }
+        }
+    }
+
+    /// Drop every cached expression
+    ///
+    /// Expressions already shared keep their storage for as long as they are used.
+    pub fn clear(&mut self) {
+        self.expressions.clear();
+    }
+}
+
 impl From<Class> for ClassExpression {
     fn from(class: Class) -> Self {
         ClassExpression::Class(class)
@@ -198,7 +549,7 @@ impl From<Class> for ClassExpression {
 }
 
 /// Data ranges for data property expressions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DataRange {
     /// Datatype restriction
     Datatype(IRI),
@@ -214,8 +565,26 @@ pub enum DataRange {
     DatatypeRestriction(IRI, Vec<FacetRestriction>),
 }
 
+impl DataRange {
+    /// Append the IRIs of all datatypes used in this data range
+    pub(crate) fn collect_signature(&self, signature: &mut Vec<Arc<IRI>>) {
+        match self {
+            DataRange::Datatype(datatype) | DataRange::DatatypeRestriction(datatype, _) => {
+                signature.push(Arc::new(datatype.clone()));
+            }
+            DataRange::DataIntersectionOf(ranges) | DataRange::DataUnionOf(ranges) => {
+                for range in ranges {
+                    range.collect_signature(signature);
+                }
+            }
+            DataRange::DataComplementOf(range) => range.collect_signature(signature),
+            DataRange::DataOneOf(_) => {}
+        }
+    }
+}
+
 /// Facet restrictions for datatype restrictions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct FacetRestriction {
     /// The facet (e.g., xsd:minInclusive)
     facet: IRI,
@@ -0,0 +1,563 @@
+//! OWL 2 functional-syntax rendering of axioms and expressions
+//!
+//! `Display` writes axioms, class expressions, property expressions and data
+//! ranges as in the OWL 2 structural specification, with full IRIs in angle
+//! brackets and anonymous individuals as `_:` labels. Operands are written in
+//! the order they are stored, so equal values always render the same way and
+//! the output can serve as a canonical form (see
+//! [`Ontology::content_hash`](crate::Ontology::content_hash)). The RDF
+//! collection, container and reification axioms, which have no functional
+//! syntax, are written in the same style under their own names.
+
+use super::*;
+use crate::entities::{AnnotationValue, Individual};
+use std::fmt::{self, Display, Formatter};
+
+/// Write `items` separated by single spaces
+fn spaced<T: Display>(f: &mut Formatter<'_>, items: impl IntoIterator<Item = T>) -> fmt::Result {
+    for (i, item) in items.into_iter().enumerate() {
+        if i > 0 {
+            f.write_str(" ")?;
+        }
+        write!(f, "{}", item)?;
+    }
+    Ok(())
+}
+
+/// An IRI in angle brackets
+struct Full<'a>(&'a IRI);
+
+impl Display for Full<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "<{}>", self.0.as_str())
+    }
+}
+
+/// A quoted literal with its language tag or datatype
+struct Quoted<'a>(&'a Literal);
+
+impl Display for Quoted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("\"")?;
+        for c in self.0.lexical_form().chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        f.write_str("\"")?;
+        match self.0.language_tag() {
+            Some(tag) => write!(f, "@{}", tag),
+            None => write!(f, "^^{}", Full(self.0.datatype())),
+        }
+    }
+}
+
+/// A blank node label
+struct Blank<'a>(&'a str);
+
+impl Display for Blank<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.0.starts_with("_:") {
+            f.write_str(self.0)
+        } else {
+            write!(f, "_:{}", self.0)
+        }
+    }
+}
+
+/// A named or anonymous individual, or a literal, in an RDF-derived axiom
+enum Node<'a> {
+    Named(&'a IRI),
+    Anonymous(&'a AnonymousIndividual),
+    Literal(&'a Literal),
+}
+
+impl Display for Node<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Named(iri) => write!(f, "{}", Full(iri)),
+            Node::Anonymous(anon) => write!(f, "{}", Blank(anon.node_id())),
+            Node::Literal(literal) => write!(f, "{}", Quoted(literal)),
+        }
+    }
+}
+
+impl<'a> From<&'a PropertyAssertionObject> for Node<'a> {
+    fn from(object: &'a PropertyAssertionObject) -> Self {
+        match object {
+            PropertyAssertionObject::Named(iri) => Node::Named(iri),
+            PropertyAssertionObject::Anonymous(anon) => Node::Anonymous(anon),
+        }
+    }
+}
+
+impl<'a> From<&'a CollectionItem> for Node<'a> {
+    fn from(item: &'a CollectionItem) -> Self {
+        match item {
+            CollectionItem::Named(iri) => Node::Named(iri),
+            CollectionItem::Anonymous(anon) => Node::Anonymous(anon),
+            CollectionItem::Literal(literal) => Node::Literal(literal),
+        }
+    }
+}
+
+impl<'a> From<&'a ContainerItem> for Node<'a> {
+    fn from(item: &'a ContainerItem) -> Self {
+        match item {
+            ContainerItem::Named(iri) => Node::Named(iri),
+            ContainerItem::Anonymous(anon) => Node::Anonymous(anon),
+            ContainerItem::Literal(literal) => Node::Literal(literal),
+        }
+    }
+}
+
+impl<'a> From<&'a ReificationObject> for Node<'a> {
+    fn from(object: &'a ReificationObject) -> Self {
+        match object {
+            ReificationObject::Named(iri) => Node::Named(iri),
+            ReificationObject::Anonymous(anon) => Node::Anonymous(anon),
+            ReificationObject::Literal(literal) => Node::Literal(literal),
+        }
+    }
+}
+
+struct IndividualRef<'a>(&'a Individual);
+
+impl Display for IndividualRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Individual::Named(named) => write!(f, "{}", Full(named.iri())),
+            Individual::Anonymous(anon) => write!(f, "{}", Blank(anon.node_id())),
+        }
+    }
+}
+
+struct AnnotationRef<'a>(&'a Annotation);
+
+impl Display for AnnotationRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Annotation({} ", Full(self.0.property()))?;
+        write_annotation_value(f, self.0.value())?;
+        f.write_str(")")
+    }
+}
+
+fn write_annotation_value(f: &mut Formatter<'_>, value: &AnnotationValue) -> fmt::Result {
+    match value {
+        AnnotationValue::IRI(iri) => write!(f, "{}", Full(iri)),
+        AnnotationValue::Literal(literal) => write!(f, "{}", Quoted(literal)),
+        AnnotationValue::AnonymousIndividual(node_id) => write!(f, "{}", Blank(node_id)),
+    }
+}
+
+/// `Name(` followed by space-separated IRIs and `)`
+fn iris<'a>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    iris: impl IntoIterator<Item = &'a Arc<IRI>>,
+) -> fmt::Result {
+    write!(f, "{}(", name)?;
+    spaced(f, iris.into_iter().map(|iri| Full(iri)))?;
+    f.write_str(")")
+}
+
+fn property_assertion(f: &mut Formatter<'_>, axiom: &PropertyAssertionAxiom) -> fmt::Result {
+    write!(
+        f,
+        "ObjectPropertyAssertion({} {} {})",
+        Full(axiom.property()),
+        Full(axiom.subject()),
+        Node::from(axiom.object())
+    )
+}
+
+impl Display for ObjectPropertyExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjectPropertyExpression::ObjectProperty(property) => {
+                write!(f, "{}", Full(property.iri()))
+            }
+            ObjectPropertyExpression::ObjectInverseOf(inner) => {
+                write!(f, "ObjectInverseOf({})", inner)
+            }
+        }
+    }
+}
+
+impl Display for DataPropertyExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Full(self.property_iri()))
+    }
+}
+
+impl Display for DataRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DataRange::Datatype(datatype) => write!(f, "{}", Full(datatype)),
+            DataRange::DataIntersectionOf(ranges) => {
+                f.write_str("DataIntersectionOf(")?;
+                spaced(f, ranges)?;
+                f.write_str(")")
+            }
+            DataRange::DataUnionOf(ranges) => {
+                f.write_str("DataUnionOf(")?;
+                spaced(f, ranges)?;
+                f.write_str(")")
+            }
+            DataRange::DataComplementOf(range) => write!(f, "DataComplementOf({})", range),
+            DataRange::DataOneOf(literals) => {
+                f.write_str("DataOneOf(")?;
+                spaced(f, literals.iter().map(Quoted))?;
+                f.write_str(")")
+            }
+            DataRange::DatatypeRestriction(datatype, facets) => {
+                write!(f, "DatatypeRestriction({}", Full(datatype))?;
+                for facet in facets {
+                    write!(f, " {} {}", Full(facet.facet()), Quoted(facet.value()))?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl Display for ClassExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ClassExpression::Class(class) => write!(f, "{}", Full(class.iri())),
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                f.write_str("ObjectIntersectionOf(")?;
+                spaced(f, operands)?;
+                f.write_str(")")
+            }
+            ClassExpression::ObjectUnionOf(operands) => {
+                f.write_str("ObjectUnionOf(")?;
+                spaced(f, operands)?;
+                f.write_str(")")
+            }
+            ClassExpression::ObjectComplementOf(operand) => {
+                write!(f, "ObjectComplementOf({})", operand)
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                f.write_str("ObjectOneOf(")?;
+                spaced(f, individuals.iter().map(IndividualRef))?;
+                f.write_str(")")
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                write!(f, "ObjectSomeValuesFrom({} {})", property, filler)
+            }
+            ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                write!(f, "ObjectAllValuesFrom({} {})", property, filler)
+            }
+            ClassExpression::ObjectHasValue(property, individual) => {
+                write!(
+                    f,
+                    "ObjectHasValue({} {})",
+                    property,
+                    IndividualRef(individual)
+                )
+            }
+            ClassExpression::ObjectHasSelf(property) => write!(f, "ObjectHasSelf({})", property),
+            ClassExpression::ObjectMinCardinality(n, property) => {
+                write!(f, "ObjectMinCardinality({} {})", n, property)
+            }
+            ClassExpression::ObjectMaxCardinality(n, property) => {
+                write!(f, "ObjectMaxCardinality({} {})", n, property)
+            }
+            ClassExpression::ObjectExactCardinality(n, property) => {
+                write!(f, "ObjectExactCardinality({} {})", n, property)
+            }
+            ClassExpression::DataSomeValuesFrom(property, range) => {
+                write!(f, "DataSomeValuesFrom({} {})", property, range)
+            }
+            ClassExpression::DataAllValuesFrom(property, range) => {
+                write!(f, "DataAllValuesFrom({} {})", property, range)
+            }
+            ClassExpression::DataHasValue(property, literal) => {
+                write!(f, "DataHasValue({} {})", property, Quoted(literal))
+            }
+            ClassExpression::DataMinCardinality(n, property) => {
+                write!(f, "DataMinCardinality({} {})", n, property)
+            }
+            ClassExpression::DataMaxCardinality(n, property) => {
+                write!(f, "DataMaxCardinality({} {})", n, property)
+            }
+            ClassExpression::DataExactCardinality(n, property) => {
+                write!(f, "DataExactCardinality({} {})", n, property)
+            }
+        }
+    }
+}
+
+impl Display for Axiom {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Axiom::SubClassOf(axiom) => {
+                write!(
+                    f,
+                    "SubClassOf({} {})",
+                    axiom.sub_class(),
+                    axiom.super_class()
+                )
+            }
+            Axiom::EquivalentClasses(axiom) => {
+                f.write_str("EquivalentClasses(")?;
+                spaced(f, axiom.class_expressions())?;
+                f.write_str(")")
+            }
+            Axiom::DisjointClasses(axiom) => iris(f, "DisjointClasses", axiom.classes()),
+            Axiom::ClassAssertion(axiom) => write!(
+                f,
+                "ClassAssertion({} {})",
+                axiom.class_expr(),
+                Full(axiom.individual())
+            ),
+            Axiom::PropertyAssertion(axiom) => property_assertion(f, axiom),
+            Axiom::DataPropertyAssertion(axiom) => write!(
+                f,
+                "DataPropertyAssertion({} {} {})",
+                Full(axiom.property()),
+                Full(axiom.subject()),
+                Quoted(axiom.value())
+            ),
+            Axiom::SubObjectProperty(axiom) => write!(
+                f,
+                "SubObjectPropertyOf({} {})",
+                Full(axiom.sub_property()),
+                Full(axiom.super_property())
+            ),
+            Axiom::EquivalentObjectProperties(axiom) => {
+                iris(f, "EquivalentObjectProperties", axiom.properties())
+            }
+            Axiom::DisjointObjectProperties(axiom) => {
+                iris(f, "DisjointObjectProperties", axiom.properties())
+            }
+            Axiom::FunctionalProperty(axiom) => {
+                write!(f, "FunctionalObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::InverseFunctionalProperty(axiom) => write!(
+                f,
+                "InverseFunctionalObjectProperty({})",
+                Full(axiom.property())
+            ),
+            Axiom::ReflexiveProperty(axiom) => {
+                write!(f, "ReflexiveObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::IrreflexiveProperty(axiom) => {
+                write!(f, "IrreflexiveObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::SymmetricProperty(axiom) => {
+                write!(f, "SymmetricObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::AsymmetricProperty(axiom) => {
+                write!(f, "AsymmetricObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::TransitiveProperty(axiom) => {
+                write!(f, "TransitiveObjectProperty({})", Full(axiom.property()))
+            }
+            Axiom::SubPropertyChainOf(axiom) => {
+                f.write_str("SubObjectPropertyOf(ObjectPropertyChain(")?;
+                spaced(f, axiom.property_chain())?;
+                write!(f, ") {})", axiom.super_property())
+            }
+            Axiom::InverseObjectProperties(axiom) => write!(
+                f,
+                "InverseObjectProperties({} {})",
+                axiom.property1(),
+                axiom.property2()
+            ),
+            Axiom::SubDataProperty(axiom) => write!(
+                f,
+                "SubDataPropertyOf({} {})",
+                Full(axiom.sub_property()),
+                Full(axiom.super_property())
+            ),
+            Axiom::EquivalentDataProperties(axiom) => {
+                iris(f, "EquivalentDataProperties", axiom.properties())
+            }
+            Axiom::DisjointDataProperties(axiom) => {
+                iris(f, "DisjointDataProperties", axiom.properties())
+            }
+            Axiom::FunctionalDataProperty(axiom) => {
+                write!(f, "FunctionalDataProperty({})", Full(axiom.property()))
+            }
+            Axiom::SameIndividual(axiom) => iris(f, "SameIndividual", axiom.individuals()),
+            Axiom::DifferentIndividuals(axiom) => {
+                iris(f, "DifferentIndividuals", axiom.individuals())
+            }
+            Axiom::HasKey(axiom) => {
+                write!(f, "HasKey({} (", axiom.class_expression())?;
+                spaced(f, axiom.properties().iter().map(|iri| Full(iri)))?;
+                f.write_str(") ())")
+            }
+            Axiom::AnnotationAssertion(axiom) => {
+                write!(
+                    f,
+                    "AnnotationAssertion({} {} ",
+                    Full(axiom.annotation_property()),
+                    Full(axiom.subject())
+                )?;
+                write_annotation_value(f, axiom.value())?;
+                f.write_str(")")
+            }
+            Axiom::SubAnnotationPropertyOf(axiom) => write!(
+                f,
+                "SubAnnotationPropertyOf({} {})",
+                Full(axiom.sub_property()),
+                Full(axiom.super_property())
+            ),
+            Axiom::AnnotationPropertyDomain(axiom) => write!(
+                f,
+                "AnnotationPropertyDomain({} {})",
+                Full(axiom.property()),
+                Full(axiom.domain())
+            ),
+            Axiom::AnnotationPropertyRange(axiom) => write!(
+                f,
+                "AnnotationPropertyRange({} {})",
+                Full(axiom.property()),
+                Full(axiom.range())
+            ),
+            Axiom::ObjectMinQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} ObjectMinCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler()
+            ),
+            Axiom::ObjectMaxQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} ObjectMaxCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler()
+            ),
+            Axiom::ObjectExactQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} ObjectExactCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler()
+            ),
+            Axiom::DataMinQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} DataMinCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                Full(axiom.filler())
+            ),
+            Axiom::DataMaxQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} DataMaxCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                Full(axiom.filler())
+            ),
+            Axiom::DataExactQualifiedCardinality(axiom) => write!(
+                f,
+                "SubClassOf({} DataExactCardinality({} {} {}))",
+                Full(&crate::constants::owl::thing()),
+                axiom.cardinality(),
+                axiom.property(),
+                Full(axiom.filler())
+            ),
+            Axiom::ObjectPropertyDomain(axiom) => write!(
+                f,
+                "ObjectPropertyDomain({} {})",
+                axiom.property_expression(),
+                axiom.domain()
+            ),
+            Axiom::ObjectPropertyRange(axiom) => write!(
+                f,
+                "ObjectPropertyRange({} {})",
+                axiom.property_expression(),
+                axiom.range()
+            ),
+            Axiom::DataPropertyDomain(axiom) => write!(
+                f,
+                "DataPropertyDomain({} {})",
+                Full(axiom.property()),
+                axiom.domain()
+            ),
+            Axiom::DataPropertyRange(axiom) => write!(
+                f,
+                "DataPropertyRange({} {})",
+                Full(axiom.property()),
+                Full(axiom.range())
+            ),
+            Axiom::NegativeObjectPropertyAssertion(axiom) => write!(
+                f,
+                "NegativeObjectPropertyAssertion({} {} {})",
+                Full(axiom.property()),
+                Full(axiom.subject()),
+                Full(axiom.object())
+            ),
+            Axiom::NegativeDataPropertyAssertion(axiom) => write!(
+                f,
+                "NegativeDataPropertyAssertion({} {} {})",
+                Full(axiom.property()),
+                Full(axiom.subject()),
+                Quoted(axiom.value())
+            ),
+            Axiom::Import(axiom) => {
+                f.write_str("Import(")?;
+                for annotation in axiom.annotations() {
+                    write!(f, "{} ", AnnotationRef(annotation))?;
+                }
+                write!(f, "{}", Full(axiom.imported_ontology()))?;
+                if let Some(version) = axiom.version_iri() {
+                    write!(f, " {}", Full(version))?;
+                }
+                f.write_str(")")
+            }
+            Axiom::Collection(axiom) => {
+                write!(
+                    f,
+                    "Collection({} {} (",
+                    Full(axiom.subject()),
+                    Full(axiom.property())
+                )?;
+                spaced(f, axiom.items().iter().map(Node::from))?;
+                f.write_str("))")
+            }
+            Axiom::Container(axiom) => {
+                let kind = match axiom.container_type() {
+                    ContainerType::Sequence => "Seq",
+                    ContainerType::Bag => "Bag",
+                    ContainerType::Alternative => "Alt",
+                };
+                write!(
+                    f,
+                    "Container({} {} {} (",
+                    kind,
+                    Full(axiom.subject()),
+                    Full(axiom.property())
+                )?;
+                spaced(f, axiom.items().iter().map(Node::from))?;
+                f.write_str("))")
+            }
+            Axiom::Reification(axiom) => {
+                write!(
+                    f,
+                    "Reification({} {} {} {}",
+                    Full(axiom.reification_resource()),
+                    Full(axiom.subject()),
+                    Full(axiom.predicate()),
+                    Node::from(axiom.object())
+                )?;
+                for property in axiom.properties() {
+                    f.write_str(" ")?;
+                    property_assertion(f, property)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@
 //! between classes, properties, and individuals.
 
 pub mod class_expressions;
+mod functional_syntax;
 pub mod property_expressions;
 
 pub use crate::entities::{Annotation, AnonymousIndividual, Literal, ObjectProperty};
@@ -108,7 +109,7 @@ pub enum AxiomType {
 }
 
 /// OWL2 Axiom types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Axiom {
     /// Subclass axiom: C ⊑ D
     SubClassOf(Box<SubClassOfAxiom>),
@@ -278,15 +279,250 @@ impl Axiom {
         }
     }
 
-    /// Get the signature IRIs of this axiom (main entities involved)
+    /// Check whether this axiom asserts facts about individuals (ABox)
+    ///
+    /// Class and property assertions, their negations, and sameAs and
+    /// differentFrom axioms are assertional; every other axiom belongs to the
+    /// schema (TBox and RBox).
+    pub fn is_assertional(&self) -> bool {
+        matches!(
+            self,
+            Axiom::ClassAssertion(_)
+                | Axiom::PropertyAssertion(_)
+                | Axiom::DataPropertyAssertion(_)
+                | Axiom::NegativeObjectPropertyAssertion(_)
+                | Axiom::NegativeDataPropertyAssertion(_)
+                | Axiom::SameIndividual(_)
+                | Axiom::DifferentIndividuals(_)
+        )
+    }
+
+    /// View a global object qualified cardinality axiom `⊤ ⊑ ≥n R.C` as its restriction
+    pub fn as_object_cardinality(&self) -> Option<class_expressions::ObjectCardinality<'_>> {
+        use class_expressions::{CardinalityBound, ObjectCardinality};
+        let (bound, cardinality, property, filler) = match self {
+            Axiom::ObjectMinQualifiedCardinality(axiom) => (
+                CardinalityBound::Min,
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::ObjectMaxQualifiedCardinality(axiom) => (
+                CardinalityBound::Max,
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::ObjectExactQualifiedCardinality(axiom) => (
+                CardinalityBound::Exact,
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            _ => return None,
+        };
+        Some(ObjectCardinality {
+            bound,
+            cardinality,
+            property,
+            filler,
+        })
+    }
+
+    /// Get the signature IRIs of this axiom (all named entities involved)
     pub fn signature(&self) -> Vec<Arc<IRI>> {
-        // Simplified signature extraction - will be enhanced with proper axiom methods
-        Vec::new() // Placeholder implementation
+        let mut sig: Vec<Arc<IRI>> = Vec::new();
+        match self {
+            Axiom::SubClassOf(ax) => {
+                ax.sub_class().collect_signature(&mut sig);
+                ax.super_class().collect_signature(&mut sig);
+            }
+            Axiom::EquivalentClasses(ax) => {
+                for expression in ax.class_expressions() {
+                    expression.collect_signature(&mut sig);
+                }
+            }
+            Axiom::DisjointClasses(ax) => sig.extend(ax.classes().iter().cloned()),
+            Axiom::ClassAssertion(ax) => {
+                sig.push(ax.individual().clone());
+                ax.class_expr().collect_signature(&mut sig);
+            }
+            Axiom::PropertyAssertion(ax) => {
+                sig.push(ax.subject().clone());
+                sig.push(ax.property().clone());
+                sig.extend(ax.object_iri().cloned());
+            }
+            Axiom::DataPropertyAssertion(ax) => {
+                sig.push(ax.subject().clone());
+                sig.push(ax.property().clone());
+            }
+            Axiom::SubObjectProperty(ax) => {
+                sig.push(ax.sub_property().clone());
+                sig.push(ax.super_property().clone());
+            }
+            Axiom::EquivalentObjectProperties(ax) => sig.extend(ax.properties().iter().cloned()),
+            Axiom::DisjointObjectProperties(ax) => sig.extend(ax.properties().iter().cloned()),
+            Axiom::FunctionalProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::InverseFunctionalProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::ReflexiveProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::IrreflexiveProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::SymmetricProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::AsymmetricProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::TransitiveProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::SubPropertyChainOf(ax) => {
+                sig.extend(ax.property_chain().iter().map(|p| p.property_iri().clone()));
+                sig.push(ax.super_property().property_iri().clone());
+            }
+            Axiom::InverseObjectProperties(ax) => {
+                sig.push(ax.property1().property_iri().clone());
+                sig.push(ax.property2().property_iri().clone());
+            }
+            Axiom::SubDataProperty(ax) => {
+                sig.push(ax.sub_property().clone());
+                sig.push(ax.super_property().clone());
+            }
+            Axiom::EquivalentDataProperties(ax) => sig.extend(ax.properties().iter().cloned()),
+            Axiom::DisjointDataProperties(ax) => sig.extend(ax.properties().iter().cloned()),
+            Axiom::FunctionalDataProperty(ax) => sig.push(ax.property().clone()),
+            Axiom::SameIndividual(ax) => sig.extend(ax.individuals().iter().cloned()),
+            Axiom::DifferentIndividuals(ax) => sig.extend(ax.individuals().iter().cloned()),
+            Axiom::HasKey(ax) => {
+                ax.class_expression().collect_signature(&mut sig);
+                sig.extend(ax.properties().iter().cloned());
+            }
+            Axiom::AnnotationAssertion(ax) => {
+                sig.push(ax.annotation_property().clone());
+                sig.push(ax.subject().clone());
+            }
+            Axiom::SubAnnotationPropertyOf(ax) => {
+                sig.push(ax.sub_property().clone());
+                sig.push(ax.super_property().clone());
+            }
+            Axiom::AnnotationPropertyDomain(ax) => {
+                sig.push(ax.property().clone());
+                sig.push(ax.domain().clone());
+            }
+            Axiom::AnnotationPropertyRange(ax) => {
+                sig.push(ax.property().clone());
+                sig.push(ax.range().clone());
+            }
+            Axiom::ObjectMinQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                ax.filler().collect_signature(&mut sig);
+            }
+            Axiom::ObjectMaxQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                ax.filler().collect_signature(&mut sig);
+            }
+            Axiom::ObjectExactQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                ax.filler().collect_signature(&mut sig);
+            }
+            Axiom::DataMinQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                sig.push(ax.filler().clone());
+            }
+            Axiom::DataMaxQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                sig.push(ax.filler().clone());
+            }
+            Axiom::DataExactQualifiedCardinality(ax) => {
+                sig.push(ax.property().property_iri().clone());
+                sig.push(ax.filler().clone());
+            }
+            Axiom::ObjectPropertyDomain(ax) => {
+                sig.push(Arc::new(ax.property().clone()));
+                ax.domain().collect_signature(&mut sig);
+            }
+            Axiom::ObjectPropertyRange(ax) => {
+                sig.push(Arc::new(ax.property().clone()));
+                ax.range().collect_signature(&mut sig);
+            }
+            Axiom::DataPropertyDomain(ax) => {
+                sig.push(Arc::new(ax.property().clone()));
+                ax.domain().collect_signature(&mut sig);
+            }
+            Axiom::DataPropertyRange(ax) => {
+                sig.push(Arc::new(ax.property().clone()));
+                sig.push(Arc::new(ax.range().clone()));
+            }
+            Axiom::NegativeObjectPropertyAssertion(ax) => {
+                sig.push(Arc::new(ax.subject().clone()));
+                sig.push(Arc::new(ax.property().clone()));
+                sig.push(Arc::new(ax.object().clone()));
+            }
+            Axiom::NegativeDataPropertyAssertion(ax) => {
+                sig.push(Arc::new(ax.subject().clone()));
+                sig.push(Arc::new(ax.property().clone()));
+            }
+            Axiom::Import(_) => {}
+            Axiom::Collection(ax) => {
+                sig.push(ax.subject().clone());
+                sig.push(ax.property().clone());
+                sig.extend(ax.items().iter().filter_map(|item| match item {
+                    CollectionItem::Named(iri) => Some(iri.clone()),
+                    _ => None,
+                }));
+            }
+            Axiom::Container(ax) => {
+                sig.push(Arc::new(ax.subject().clone()));
+                sig.push(Arc::new(ax.property().clone()));
+                sig.extend(ax.items().iter().filter_map(|item| match item {
+                    ContainerItem::Named(iri) => Some(Arc::new(iri.clone())),
+                    _ => None,
+                }));
+            }
+            Axiom::Reification(ax) => {
+                sig.push(ax.subject().clone());
+                sig.push(ax.predicate().clone());
+                if let ReificationObject::Named(iri) = ax.object() {
+                    sig.push(iri.clone());
+                }
+            }
+        }
+
+        let mut seen = std::collections::HashSet::new();
+        sig.retain(|iri| seen.insert(iri.clone()));
+        sig
+    }
+
+    /// The class expressions this axiom holds directly
+    pub(crate) fn class_expressions(&self) -> Vec<&class_expressions::ClassExpression> {
+        match self {
+            Axiom::SubClassOf(ax) => vec![&ax.sub_class, &ax.super_class],
+            Axiom::EquivalentClasses(ax) => ax.expressions.iter().collect(),
+            Axiom::ClassAssertion(ax) => vec![&ax.class_expr],
+            Axiom::HasKey(ax) => vec![&ax.class_expression],
+            Axiom::ObjectMinQualifiedCardinality(ax) => vec![&ax.filler],
+            Axiom::ObjectMaxQualifiedCardinality(ax) => vec![&ax.filler],
+            Axiom::ObjectExactQualifiedCardinality(ax) => vec![&ax.filler],
+            Axiom::ObjectPropertyDomain(ax) => vec![&ax.domain],
+            Axiom::ObjectPropertyRange(ax) => vec![&ax.range],
+            Axiom::DataPropertyDomain(ax) => vec![&ax.domain],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Mutable access to the class expressions this axiom holds directly
+    pub(crate) fn class_expressions_mut(&mut self) -> Vec<&mut class_expressions::ClassExpression> {
+        match self {
+            Axiom::SubClassOf(ax) => vec![&mut ax.sub_class, &mut ax.super_class],
+            Axiom::EquivalentClasses(ax) => ax.expressions.iter_mut().collect(),
+            Axiom::ClassAssertion(ax) => vec![&mut ax.class_expr],
+            Axiom::HasKey(ax) => vec![&mut ax.class_expression],
+            Axiom::ObjectMinQualifiedCardinality(ax) => vec![&mut ax.filler],
+            Axiom::ObjectMaxQualifiedCardinality(ax) => vec![&mut ax.filler],
+            Axiom::ObjectExactQualifiedCardinality(ax) => vec![&mut ax.filler],
+            Axiom::ObjectPropertyDomain(ax) => vec![&mut ax.domain],
+            Axiom::ObjectPropertyRange(ax) => vec![&mut ax.range],
+            Axiom::DataPropertyDomain(ax) => vec![&mut ax.domain],
+            _ => Vec::new(),
+        }
     }
 }
 
 /// Subclass axiom: C ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubClassOfAxiom {
     sub_class: class_expressions::ClassExpression,
     super_class: class_expressions::ClassExpression,
@@ -318,28 +554,77 @@ impl SubClassOfAxiom {
     pub fn involves_class(&self, class_iri: &IRI) -> bool {
         self.sub_class.contains_class(class_iri) || self.super_class.contains_class(class_iri)
     }
+
+    /// Whether the subclass and superclass are the same, as in `C ⊑ C`
+    ///
+    /// Such an axiom is a tautology, so reasoning skips it.
+    pub fn is_reflexive(&self) -> bool {
+        self.sub_class == self.super_class
+    }
 }
 
 /// Equivalent classes axiom: C ≡ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Operands may be arbitrary class expressions, so the axiom can also state a
+/// definition such as `Parent ≡ ∃hasChild.Person`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EquivalentClassesAxiom {
+    expressions: Vec<class_expressions::ClassExpression>,
+    /// Named operands of `expressions`, in order
     classes: Vec<Arc<IRI>>,
 }
 
 impl EquivalentClassesAxiom {
-    /// Create a new equivalent classes axiom
+    /// Create a new equivalent classes axiom between named classes
     pub fn new(classes: Vec<Arc<IRI>>) -> Self {
-        EquivalentClassesAxiom { classes }
+        let expressions = classes
+            .iter()
+            .map(|iri| {
+                class_expressions::ClassExpression::Class(crate::entities::Class::new(
+                    (**iri).clone(),
+                ))
+            })
+            .collect();
+        EquivalentClassesAxiom {
+            expressions,
+            classes,
+        }
     }
 
-    /// Get the equivalent classes
+    /// Create a new equivalent classes axiom between class expressions
+    pub fn from_expressions(expressions: Vec<class_expressions::ClassExpression>) -> Self {
+        let classes = expressions
+            .iter()
+            .filter_map(|expression| expression.as_named())
+            .map(|class| Arc::clone(class.iri()))
+            .collect();
+        EquivalentClassesAxiom {
+            expressions,
+            classes,
+        }
+    }
+
+    /// Get the named equivalent classes
+    ///
+    /// Complex operands are skipped; use [`class_expressions`](Self::class_expressions)
+    /// to see every operand.
     pub fn classes(&self) -> &Vec<Arc<IRI>> {
         &self.classes
     }
+
+    /// Get every operand of the axiom
+    pub fn class_expressions(&self) -> &[class_expressions::ClassExpression] {
+        &self.expressions
+    }
+
+    /// Check whether any operand is a complex class expression
+    pub fn is_definition(&self) -> bool {
+        self.classes.len() < self.expressions.len()
+    }
 }
 
 /// Disjoint classes axiom: C ⊓ D ⊑ ⊥
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DisjointClassesAxiom {
     classes: Vec<Arc<IRI>>,
 }
@@ -357,7 +642,7 @@ impl DisjointClassesAxiom {
 }
 
 /// Class assertion axiom: a ∈ C
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ClassAssertionAxiom {
     individual: Arc<IRI>,
     class_expr: class_expressions::ClassExpression,
@@ -384,7 +669,7 @@ impl ClassAssertionAxiom {
 }
 
 /// Property assertion axiom: (a, b) ∈ P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PropertyAssertionAxiom {
     subject: Arc<IRI>,
     property: Arc<IRI>,
@@ -392,7 +677,7 @@ pub struct PropertyAssertionAxiom {
 }
 
 /// Data property assertion axiom: (a, v) ∈ P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataPropertyAssertionAxiom {
     subject: Arc<IRI>,
     property: Arc<IRI>,
@@ -491,10 +776,22 @@ impl PropertyAssertionAxiom {
             PropertyAssertionObject::Anonymous(individual) => Some(&**individual),
         }
     }
+
+    /// Get the object as an IRI, naming an anonymous object by its blank node `_:id`
+    ///
+    /// Lets reasoning treat an anonymous object as one more individual.
+    pub fn object_node_iri(&self) -> OwlResult<Arc<IRI>> {
+        match &self.object {
+            PropertyAssertionObject::Named(iri) => Ok(iri.clone()),
+            PropertyAssertionObject::Anonymous(individual) => {
+                create_blank_node_iri(individual.node_id())
+            }
+        }
+    }
 }
 
 /// Subobject property axiom: P ⊑ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubObjectPropertyAxiom {
     sub_property: Arc<IRI>,
     super_property: Arc<IRI>,
@@ -521,7 +818,7 @@ impl SubObjectPropertyAxiom {
 }
 
 /// Equivalent object properties axiom: P ≡ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EquivalentObjectPropertiesAxiom {
     properties: Vec<Arc<IRI>>,
 }
@@ -539,7 +836,7 @@ impl EquivalentObjectPropertiesAxiom {
 }
 
 /// Disjoint object properties axiom: P ⊓ Q ⊑ ⊥
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DisjointObjectPropertiesAxiom {
     properties: Vec<Arc<IRI>>,
 }
@@ -557,7 +854,7 @@ impl DisjointObjectPropertiesAxiom {
 }
 
 /// Functional property axiom: ⊤ ⊑ ≤1P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FunctionalPropertyAxiom {
     property: Arc<IRI>,
 }
@@ -575,7 +872,7 @@ impl FunctionalPropertyAxiom {
 }
 
 /// Inverse functional property axiom: ⊤ ⊑ ≤1P⁻
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct InverseFunctionalPropertyAxiom {
     property: Arc<IRI>,
 }
@@ -593,7 +890,7 @@ impl InverseFunctionalPropertyAxiom {
 }
 
 /// Reflexive property axiom: ⊤ ⊑ ∃P.Self
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ReflexivePropertyAxiom {
     property: Arc<IRI>,
 }
@@ -611,7 +908,7 @@ impl ReflexivePropertyAxiom {
 }
 
 /// Irreflexive property axiom: ⊥ ⊑ ∃P.Self
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct IrreflexivePropertyAxiom {
     property: Arc<IRI>,
 }
@@ -629,7 +926,7 @@ impl IrreflexivePropertyAxiom {
 }
 
 /// Symmetric property axiom: P ≡ P⁻
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SymmetricPropertyAxiom {
     property: Arc<IRI>,
 }
@@ -647,7 +944,7 @@ impl SymmetricPropertyAxiom {
 }
 
 /// Asymmetric property axiom: P ⊓ P⁻ ⊑ ⊥
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AsymmetricPropertyAxiom {
     property: Arc<IRI>,
 }
@@ -665,7 +962,7 @@ impl AsymmetricPropertyAxiom {
 }
 
 /// Transitive property axiom: P⁺ ⊑ P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct TransitivePropertyAxiom {
     property: Arc<IRI>,
 }
@@ -683,7 +980,7 @@ impl TransitivePropertyAxiom {
 }
 
 /// Property chain axiom: P₁ ∘ ... ∘ Pₙ ⊑ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubPropertyChainOfAxiom {
     property_chain: Vec<ObjectPropertyExpression>,
     super_property: ObjectPropertyExpression,
@@ -713,7 +1010,7 @@ impl SubPropertyChainOfAxiom {
 }
 
 /// Inverse object properties axiom: P ≡ Q⁻
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct InverseObjectPropertiesAxiom {
     property1: ObjectPropertyExpression,
     property2: ObjectPropertyExpression,
@@ -740,7 +1037,7 @@ impl InverseObjectPropertiesAxiom {
 }
 
 /// Subdata property axiom: Q ⊑ P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubDataPropertyAxiom {
     sub_property: Arc<IRI>,
     super_property: Arc<IRI>,
@@ -767,7 +1064,7 @@ impl SubDataPropertyAxiom {
 }
 
 /// Equivalent data properties axiom: P ≡ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EquivalentDataPropertiesAxiom {
     properties: Vec<Arc<IRI>>,
 }
@@ -785,7 +1082,7 @@ impl EquivalentDataPropertiesAxiom {
 }
 
 /// Disjoint data properties axiom: P ⊓ Q ⊑ ⊥
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DisjointDataPropertiesAxiom {
     properties: Vec<Arc<IRI>>,
 }
@@ -803,7 +1100,7 @@ impl DisjointDataPropertiesAxiom {
 }
 
 /// Functional data property axiom: ⊤ ⊑ ≤1P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct FunctionalDataPropertyAxiom {
     property: Arc<IRI>,
 }
@@ -821,7 +1118,7 @@ impl FunctionalDataPropertyAxiom {
 }
 
 /// Same individual axiom: a = b
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SameIndividualAxiom {
     individuals: Vec<Arc<IRI>>,
 }
@@ -839,7 +1136,7 @@ impl SameIndividualAxiom {
 }
 
 /// Different individuals axiom: a ≠ b
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DifferentIndividualsAxiom {
     individuals: Vec<Arc<IRI>>,
 }
@@ -857,7 +1154,7 @@ impl DifferentIndividualsAxiom {
 }
 
 /// Has key axiom: P₁,...,Pₙ ⊑ Key(C)
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct HasKeyAxiom {
     class_expression: class_expressions::ClassExpression,
     properties: Vec<Arc<IRI>>,
@@ -887,7 +1184,7 @@ impl HasKeyAxiom {
 }
 
 /// Annotation assertion axiom: ⊤ ⊑ ∃r.{@a}
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnnotationAssertionAxiom {
     annotation_property: Arc<IRI>,
     subject: Arc<IRI>,
@@ -925,7 +1222,7 @@ impl AnnotationAssertionAxiom {
 }
 
 /// Sub-annotation property axiom: P ⊑ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct SubAnnotationPropertyOfAxiom {
     sub_property: Arc<IRI>,
     super_property: Arc<IRI>,
@@ -952,7 +1249,7 @@ impl SubAnnotationPropertyOfAxiom {
 }
 
 /// Annotation property domain axiom: ∀P.C ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnnotationPropertyDomainAxiom {
     property: Arc<IRI>,
     domain: Arc<IRI>,
@@ -976,7 +1273,7 @@ impl AnnotationPropertyDomainAxiom {
 }
 
 /// Annotation property range axiom: ∀P.C ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnnotationPropertyRangeAxiom {
     property: Arc<IRI>,
     range: Arc<IRI>,
@@ -1000,7 +1297,7 @@ impl AnnotationPropertyRangeAxiom {
 }
 
 /// Object minimum qualified cardinality axiom: ⊤ ⊑ ≥n R.C
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectMinQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1038,7 +1335,7 @@ impl ObjectMinQualifiedCardinalityAxiom {
 }
 
 /// Object maximum qualified cardinality axiom: ⊤ ⊑ ≤n R.C
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectMaxQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1076,7 +1373,7 @@ impl ObjectMaxQualifiedCardinalityAxiom {
 }
 
 /// Object exact qualified cardinality axiom: ⊤ ⊑ =n R.C
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectExactQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1114,7 +1411,7 @@ impl ObjectExactQualifiedCardinalityAxiom {
 }
 
 /// Data minimum qualified cardinality axiom: ⊤ ⊑ ≥n R.D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataMinQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1148,7 +1445,7 @@ impl DataMinQualifiedCardinalityAxiom {
 }
 
 /// Data maximum qualified cardinality axiom: ⊤ ⊑ ≤n R.D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataMaxQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1182,7 +1479,7 @@ impl DataMaxQualifiedCardinalityAxiom {
 }
 
 /// Data exact qualified cardinality axiom: ⊤ ⊑ =n R.D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataExactQualifiedCardinalityAxiom {
     cardinality: u32,
     property: ObjectPropertyExpression,
@@ -1216,20 +1513,33 @@ impl DataExactQualifiedCardinalityAxiom {
 }
 
 /// Object property domain axiom: ∀P.C ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectPropertyDomainAxiom {
-    property: Arc<IRI>,
+    property: ObjectPropertyExpression,
     domain: class_expressions::ClassExpression,
 }
 
 impl ObjectPropertyDomainAxiom {
     /// Create a new object property domain axiom
     pub fn new(property: Arc<IRI>, domain: class_expressions::ClassExpression) -> Self {
+        Self::from_expression(ObjectProperty::new(property).into(), domain)
+    }
+
+    /// Create a domain axiom for a property expression, such as an inverse property
+    pub fn from_expression(
+        property: ObjectPropertyExpression,
+        domain: class_expressions::ClassExpression,
+    ) -> Self {
         ObjectPropertyDomainAxiom { property, domain }
     }
 
-    /// Get the property
+    /// Get the named property underlying the property expression
     pub fn property(&self) -> &IRI {
+        self.property.property_iri()
+    }
+
+    /// Get the property expression
+    pub fn property_expression(&self) -> &ObjectPropertyExpression {
         &self.property
     }
 
@@ -1240,20 +1550,33 @@ impl ObjectPropertyDomainAxiom {
 }
 
 /// Object property range axiom: ∀P.D ⊑ C
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ObjectPropertyRangeAxiom {
-    property: IRI,
+    property: ObjectPropertyExpression,
     range: class_expressions::ClassExpression,
 }
 
 impl ObjectPropertyRangeAxiom {
     /// Create a new object property range axiom
     pub fn new(property: IRI, range: class_expressions::ClassExpression) -> Self {
+        Self::from_expression(ObjectProperty::new(property).into(), range)
+    }
+
+    /// Create a range axiom for a property expression, such as an inverse property
+    pub fn from_expression(
+        property: ObjectPropertyExpression,
+        range: class_expressions::ClassExpression,
+    ) -> Self {
         ObjectPropertyRangeAxiom { property, range }
     }
 
-    /// Get the property
+    /// Get the named property underlying the property expression
     pub fn property(&self) -> &IRI {
+        self.property.property_iri()
+    }
+
+    /// Get the property expression
+    pub fn property_expression(&self) -> &ObjectPropertyExpression {
         &self.property
     }
 
@@ -1264,7 +1587,7 @@ impl ObjectPropertyRangeAxiom {
 }
 
 /// Data property domain axiom: ∀Q.C ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataPropertyDomainAxiom {
     property: IRI,
     domain: class_expressions::ClassExpression,
@@ -1288,7 +1611,7 @@ impl DataPropertyDomainAxiom {
 }
 
 /// Data property range axiom: ∃Q.l ⊑ D
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct DataPropertyRangeAxiom {
     property: IRI,
     range: IRI,
@@ -1312,7 +1635,7 @@ impl DataPropertyRangeAxiom {
 }
 
 /// Negative object property assertion axiom: (a, b) ∉ P
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NegativeObjectPropertyAssertionAxiom {
     subject: IRI,
     property: IRI,
@@ -1346,7 +1669,7 @@ impl NegativeObjectPropertyAssertionAxiom {
 }
 
 /// Negative data property assertion axiom: (a, l) ∉ Q
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NegativeDataPropertyAssertionAxiom {
     subject: IRI,
     property: IRI,
@@ -1380,25 +1703,58 @@ impl NegativeDataPropertyAssertionAxiom {
 }
 
 /// Import axiom: imports ontology with given IRI
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ImportAxiom {
     imported_ontology: Arc<IRI>,
+    version_iri: Option<Arc<IRI>>,
+    annotations: Vec<Annotation>,
 }
 
 impl ImportAxiom {
     /// Create a new import axiom
     pub fn new(imported_ontology: Arc<IRI>) -> Self {
-        ImportAxiom { imported_ontology }
+        Self::with_annotations(imported_ontology, Vec::new())
+    }
+
+    /// Create an import axiom annotated, for example, with the reason for the import
+    pub fn with_annotations(imported_ontology: Arc<IRI>, annotations: Vec<Annotation>) -> Self {
+        ImportAxiom {
+            imported_ontology,
+            version_iri: None,
+            annotations,
+        }
+    }
+
+    /// Pin the import to a version of the imported ontology
+    pub fn with_version_iri(mut self, version_iri: Arc<IRI>) -> Self {
+        self.version_iri = Some(version_iri);
+        self
     }
 
     /// Get the imported ontology IRI
     pub fn imported_ontology(&self) -> &Arc<IRI> {
         &self.imported_ontology
     }
+
+    /// Get the version IRI the import is pinned to, if any
+    pub fn version_iri(&self) -> Option<&Arc<IRI>> {
+        self.version_iri.as_ref()
+    }
+
+    /// The IRI to fetch the import from: the version IRI if there is one,
+    /// otherwise the ontology IRI
+    pub fn resolution_iri(&self) -> &Arc<IRI> {
+        self.version_iri.as_ref().unwrap_or(&self.imported_ontology)
+    }
+
+    /// Get the annotations on the import
+    pub fn annotations(&self) -> &[Annotation] {
+        &self.annotations
+    }
 }
 
 /// RDF Collection axiom representing ordered lists using rdf:first, rdf:rest, rdf:nil
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CollectionAxiom {
     /// The subject that has the collection
     subject: Arc<IRI>,
@@ -1409,7 +1765,7 @@ pub struct CollectionAxiom {
 }
 
 /// Individual item in a collection
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CollectionItem {
     Named(Arc<IRI>),
     Anonymous(Box<AnonymousIndividual>),
@@ -1551,7 +1907,7 @@ pub enum ContainerType {
 }
 
 /// RDF Container axiom: represents Seq, Bag, or Alt containers
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ContainerAxiom {
     /// The subject that has the container
     subject: IRI,
@@ -1564,7 +1920,7 @@ pub struct ContainerAxiom {
 }
 
 /// Individual item in a container
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ContainerItem {
     Named(IRI),
     Anonymous(Box<AnonymousIndividual>),
@@ -1689,7 +2045,7 @@ impl ContainerAxiom {
 }
 
 /// RDF Reification axiom: represents statements about statements using rdf:subject, rdf:predicate, rdf:object
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ReificationAxiom {
     /// The reified statement resource (blank node or named resource)
     reification_resource: Arc<IRI>,
@@ -1704,13 +2060,30 @@ pub struct ReificationAxiom {
 }
 
 /// Object in a reified statement
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ReificationObject {
     Named(Arc<IRI>),
     Anonymous(Box<AnonymousIndividual>),
     Literal(Literal),
 }
 
+impl ReificationObject {
+    /// Assert this object as the value of `property` on `subject`
+    pub fn to_assertion(&self, subject: Arc<IRI>, property: Arc<IRI>) -> Axiom {
+        match self {
+            ReificationObject::Named(iri) => Axiom::PropertyAssertion(Box::new(
+                PropertyAssertionAxiom::new(subject, property, iri.clone()),
+            )),
+            ReificationObject::Anonymous(anon) => Axiom::PropertyAssertion(Box::new(
+                PropertyAssertionAxiom::new_with_anonymous(subject, property, (**anon).clone()),
+            )),
+            ReificationObject::Literal(literal) => Axiom::DataPropertyAssertion(Box::new(
+                DataPropertyAssertionAxiom::new(subject, property, literal.clone()),
+            )),
+        }
+    }
+}
+
 impl ReificationAxiom {
     /// Create a new reification axiom
     pub fn new(
@@ -1775,8 +2148,11 @@ impl ReificationAxiom {
         self.properties.push(property);
     }
 
-    /// Create property assertions from the reification
-    pub fn to_property_assertions(&self) -> OwlResult<Vec<PropertyAssertionAxiom>> {
+    /// Create the assertions of the reification triples
+    ///
+    /// A literal object becomes a data property assertion on `rdf:object`, so the
+    /// statement maps back to the triples it was read from.
+    pub fn to_property_assertions(&self) -> OwlResult<Vec<Axiom>> {
         let mut assertions = Vec::new();
 
         // Add rdf:subject assertion
@@ -1785,7 +2161,7 @@ impl ReificationAxiom {
             IRI::new_optimized(rdf::subject().as_str())?,
             self.subject.clone(),
         );
-        assertions.push(subject_assertion);
+        assertions.push(Axiom::PropertyAssertion(Box::new(subject_assertion)));
 
         // Add rdf:predicate assertion
         let predicate_assertion = PropertyAssertionAxiom::new(
@@ -1793,27 +2169,20 @@ impl ReificationAxiom {
             IRI::new_optimized(rdf::predicate().as_str())?,
             self.predicate.clone(),
         );
-        assertions.push(predicate_assertion);
+        assertions.push(Axiom::PropertyAssertion(Box::new(predicate_assertion)));
 
         // Add rdf:object assertion
-        let object_iri = match &self.object {
-            ReificationObject::Named(iri) => IRI::new_optimized(iri.as_str())?,
-            ReificationObject::Anonymous(anon) => create_blank_node_iri(anon.node_id())?,
-            ReificationObject::Literal(lit) => {
-                // For literals, create a temporary IRI (simplification)
-                create_blank_node_iri(&format!("literal_{}", lit.lexical_form()))?
-            }
-        };
-
-        let object_assertion = PropertyAssertionAxiom::new(
+        assertions.push(self.object.to_assertion(
             self.reification_resource.clone(),
             IRI::new_optimized(rdf::object().as_str())?,
-            object_iri,
-        );
-        assertions.push(object_assertion);
+        ));
 
         // Add additional properties
-        assertions.extend(self.properties.clone());
+        assertions.extend(
+            self.properties
+                .iter()
+                .map(|property| Axiom::PropertyAssertion(Box::new(property.clone()))),
+        );
 
         // Add rdf:type assertion to identify as rdf:Statement
         let type_assertion = PropertyAssertionAxiom::new(
@@ -1821,7 +2190,7 @@ impl ReificationAxiom {
             IRI::new_optimized(rdf::type_property().as_str())?,
             IRI::new_optimized(rdf::statement().as_str())?,
         );
-        assertions.push(type_assertion);
+        assertions.push(Axiom::PropertyAssertion(Box::new(type_assertion)));
 
         Ok(assertions)
     }
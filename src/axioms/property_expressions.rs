@@ -3,9 +3,11 @@
 //! Defines complex property expressions for building property hierarchies.
 
 use crate::entities::{DataProperty, ObjectProperty};
+use crate::iri::IRI;
+use std::sync::Arc;
 
 /// Object property expressions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum ObjectPropertyExpression {
     /// Named object property
     ObjectProperty(Box<ObjectProperty>),
@@ -38,6 +40,14 @@ impl ObjectPropertyExpression {
             _ => None,
         }
     }
+
+    /// Get the IRI of the named property underlying this expression
+    pub fn property_iri(&self) -> &Arc<IRI> {
+        match self {
+            ObjectPropertyExpression::ObjectProperty(prop) => prop.iri(),
+            ObjectPropertyExpression::ObjectInverseOf(inner) => inner.property_iri(),
+        }
+    }
 }
 
 impl From<ObjectProperty> for ObjectPropertyExpression {
@@ -47,7 +57,7 @@ impl From<ObjectProperty> for ObjectPropertyExpression {
 }
 
 /// Data property expressions
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DataPropertyExpression {
     /// Named data property
     DataProperty(DataProperty),
@@ -65,6 +75,13 @@ impl DataPropertyExpression {
             DataPropertyExpression::DataProperty(prop) => Some(prop),
         }
     }
+
+    /// Get the IRI of the named property underlying this expression
+    pub fn property_iri(&self) -> &Arc<IRI> {
+        match self {
+            DataPropertyExpression::DataProperty(prop) => prop.iri(),
+        }
+    }
 }
 
 impl From<DataProperty> for DataPropertyExpression {
@@ -36,7 +36,7 @@
 //! ```
 
 use crate::error::{OwlError, OwlResult};
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use std::fmt;
 use std::hash::Hash;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
@@ -598,7 +598,7 @@ where
 
         // Update orders
         self.update_insertion_order(&key)?;
-        self.update_access_order(&key)?;
+        self.update_access_order(&key, entries.len())?;
 
         if self.config.enable_stats {
             self.stats.update_size(entries.len());
@@ -692,16 +692,22 @@ where
     fn evict_entries(&self, entries: &mut HashMap<K, (V, CacheMetadata)>) -> OwlResult<()> {
         let to_evict = self.select_entries_for_eviction(entries)?;
 
-        for key in to_evict {
-            entries.remove(&key);
+        for key in &to_evict {
+            entries.remove(key);
 
             if self.config.enable_stats {
                 self.stats.record_eviction();
                 self.stats.update_size(entries.len());
             }
+        }
 
-            // Clean up order tracking
-            self.cleanup_order_tracking(&key)?;
+        // Clean up order tracking in one pass rather than once per evicted key
+        let evicted: HashSet<&K> = to_evict.iter().collect();
+        if let Ok(mut access_order) = self.access_order.write() {
+            access_order.retain(|k| !evicted.contains(k));
+        }
+        if let Ok(mut insertion_order) = self.insertion_order.write() {
+            insertion_order.retain(|k| !evicted.contains(k));
         }
 
         Ok(())
@@ -723,10 +729,14 @@ where
 
     /// Select entries using LRU strategy
     fn select_lru_entries(&self, _entries: &HashMap<K, (V, CacheMetadata)>) -> OwlResult<Vec<K>> {
-        let access_order = self.access_order.read().map_err(|e| OwlError::CacheError {
-            operation: "lru_selection".to_string(),
-            message: format!("Failed to acquire read lock: {}", e),
-        })?;
+        let mut access_order = self
+            .access_order
+            .write()
+            .map_err(|e| OwlError::CacheError {
+                operation: "lru_selection".to_string(),
+                message: format!("Failed to acquire write lock: {}", e),
+            })?;
+        Self::compact_access_order(&mut access_order);
 
         // Evict the oldest 10% of entries
         let to_evict_count = (self.config.max_size / 10).max(1);
@@ -786,7 +796,11 @@ where
     }
 
     /// Update access order for LRU tracking
-    fn update_access_order(&self, key: &K) -> OwlResult<()> {
+    ///
+    /// Accesses are appended, leaving stale earlier positions of the key behind;
+    /// these are dropped once they outnumber the `live_entries` of the cache, so
+    /// an access costs amortized constant time instead of a scan of the order.
+    fn update_access_order(&self, key: &K, live_entries: usize) -> OwlResult<()> {
         let mut access_order = self
             .access_order
             .write()
@@ -795,13 +809,26 @@ where
                 message: format!("Failed to acquire write lock: {}", e),
             })?;
 
-        // Remove key if it exists and re-add to end
-        access_order.retain(|k| k != key);
         access_order.push(key.clone());
+        if access_order.len() > 2 * live_entries + 64 {
+            Self::compact_access_order(&mut access_order);
+        }
 
         Ok(())
     }
 
+    /// Keep only the most recent position of each key in the access order
+    fn compact_access_order(access_order: &mut Vec<K>) {
+        let mut seen = HashSet::with_capacity(access_order.len());
+        let mut compacted: Vec<K> = access_order
+            .drain(..)
+            .rev()
+            .filter(|k| seen.insert(k.clone()))
+            .collect();
+        compacted.reverse();
+        *access_order = compacted;
+    }
+
     /// Update insertion order for FIFO tracking
     fn update_insertion_order(&self, key: &K) -> OwlResult<()> {
         let mut insertion_order =
@@ -843,7 +870,7 @@ where
         }
 
         // Update access order for LRU tracking
-        self.update_access_order(key)?;
+        self.update_access_order(key, entries.len())?;
 
         Ok(())
     }
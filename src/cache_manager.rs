@@ -3,9 +3,14 @@
 //! This module provides encapsulated management for global caches
 //! with proper synchronization and monitoring capabilities.
 
-use crate::cache::BoundedCache;
-use crate::error::OwlError;
+use crate::cache::{BoundedCache, BoundedCacheStatsSnapshot};
+use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::parser::{Format, ParserConfig};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
@@ -145,7 +150,13 @@ impl GlobalCacheManager {
     }
 
     /// Get or create an IRI in the cache
-    pub fn get_or_create_iri(&self, iri_str: String) -> Result<Arc<IRI>, OwlError> {
+    pub fn get_or_create_iri(&self, mut iri_str: String) -> Result<Arc<IRI>, OwlError> {
+        // Cache under the normalized spelling so that variants share an entry
+        let normalized = crate::iri::global_iri_normalization().apply(&iri_str);
+        if let std::borrow::Cow::Owned(normalized) = normalized {
+            iri_str = normalized;
+        }
+
         // Try to get from cache first
         {
             let cache = self.iri_cache.read().map_err(|e| OwlError::CacheError {
@@ -273,3 +284,112 @@ pub fn global_cache_stats() -> CacheStatsSnapshot {
 pub fn clear_global_iri_cache() -> Result<(), OwlError> {
     global_cache_manager().clear_iri_cache()
 }
+
+/// Cache of parsed ontologies keyed by their source document
+///
+/// Documents are identified by a hash of their content together with the
+/// format and a fingerprint of the parser configuration, so parsing the same
+/// bytes with another format or configuration is a miss. Hits share the
+/// ontology of the first parse.
+#[derive(Debug)]
+pub struct ParseCache {
+    entries: BoundedCache<ParseKey, Arc<Ontology>>,
+}
+
+/// What a parse depends on
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ParseKey {
+    content_hash: u64,
+    content_len: usize,
+    format: Format,
+    config_fingerprint: u64,
+}
+
+impl ParseKey {
+    fn new(content: &str, format: Format, config: &ParserConfig) -> Self {
+        let mut hasher = DefaultHasher::new();
+        content.as_bytes().hash(&mut hasher);
+        ParseKey {
+            content_hash: hasher.finish(),
+            content_len: content.len(),
+            format,
+            config_fingerprint: config_fingerprint(config),
+        }
+    }
+}
+
+/// Hash of every setting of a parser configuration
+fn config_fingerprint(config: &ParserConfig) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    let mut prefixes: Vec<_> = config.prefixes.iter().collect();
+    prefixes.sort();
+    prefixes.hash(&mut hasher);
+    // The remaining settings through their Debug form, so new ones are covered too
+    let settings = ParserConfig {
+        prefixes: HashMap::new(),
+        ..config.clone()
+    };
+    format!("{:?}", settings).hash(&mut hasher);
+    hasher.finish()
+}
+
+impl Default for ParseCache {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
+
+impl ParseCache {
+    /// Create a cache holding at most `max_entries` ontologies
+    pub fn new(max_entries: usize) -> Self {
+        let config = BoundedCache::<ParseKey, Arc<Ontology>>::builder()
+            .max_size(max_entries)
+            .enable_stats(true);
+        Self {
+            entries: BoundedCache::from_builder(config),
+        }
+    }
+
+    /// Parse a document, or return the ontology of an earlier identical parse
+    ///
+    /// Failed parses are not cached.
+    pub fn parse(
+        &self,
+        content: &str,
+        format: Format,
+        config: &ParserConfig,
+    ) -> OwlResult<Arc<Ontology>> {
+        let key = ParseKey::new(content, format, config);
+        if let Some(ontology) = self.entries.get(&key)? {
+            return Ok(ontology);
+        }
+
+        let ontology = Arc::new(
+            format
+                .parser_with_config(config.clone())
+                .parse_str(content)?,
+        );
+        self.entries.insert(key, Arc::clone(&ontology))?;
+        Ok(ontology)
+    }
+
+    /// Hits, misses and evictions so far
+    pub fn stats(&self) -> BoundedCacheStatsSnapshot {
+        self.entries.stats()
+    }
+
+    /// Number of cached ontologies
+    pub fn len(&self) -> OwlResult<usize> {
+        self.entries.len()
+    }
+
+    /// Whether no ontology is cached
+    pub fn is_empty(&self) -> OwlResult<bool> {
+        self.entries.is_empty()
+    }
+
+    /// Drop every cached ontology
+    pub fn clear(&self) -> OwlResult<()> {
+        self.entries.clear()
+    }
+}
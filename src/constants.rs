@@ -66,6 +66,16 @@ pub mod rdf {
     }
 }
 
+/// RDFS vocabulary IRIs
+pub mod rdfs {
+    use crate::iri::IRI;
+
+    /// rdfs:label annotation property
+    pub fn label() -> IRI {
+        IRI::new("http://www.w3.org/2000/01/rdf-schema#label").expect("Valid RDFS label IRI")
+    }
+}
+
 /// OWL vocabulary IRIs
 pub mod owl {
     use crate::iri::IRI;
@@ -199,5 +209,11 @@ pub mod messages {
 /// Blank node prefix
 pub const BLANK_NODE_PREFIX: &str = "_:";
 
+/// Namespace of the IRIs the Turtle parser gives blank node subjects
+pub const TURTLE_BLANK_NODE_NAMESPACE: &str = "http://blank.node/";
+
+/// Annotation property of source comments kept by `ParserConfig::preserve_comments`
+pub const SOURCE_COMMENT: &str = "urn:owl2-reasoner:source-comment";
+
 /// RDF element prefix for container properties
 pub const RDF_ELEMENT_PREFIX: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#_";
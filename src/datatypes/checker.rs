@@ -0,0 +1,70 @@
+//! Membership of literals in datatypes
+//!
+//! Used to check data property ranges: a literal belongs to a datatype if its
+//! own datatype is the same or derived from it in the datatype map (see
+//! [`LiteralParserRegistry`](crate::entities::LiteralParserRegistry)) and its
+//! lexical form is valid for the literal parser registered for the datatype.
+
+use crate::axioms::DataPropertyAssertionAxiom;
+use crate::entities::{global_literal_parsers, Literal};
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+
+const RDFS_LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+
+/// Whether `datatype` is `ancestor` or derived from it in the global datatype map
+pub fn is_derived_from(datatype: &str, ancestor: &str) -> bool {
+    global_literal_parsers().is_derived_from(datatype, ancestor)
+}
+
+/// Check whether a literal is a value of a datatype
+///
+/// `rdfs:Literal` contains every literal. For another datatype recognized by
+/// the global datatype map, the literal's datatype must be derived from it and
+/// its lexical form must be accepted by the literal parsers of both datatypes.
+/// Unrecognized datatypes may be defined by restricting any datatype, so every
+/// literal is accepted for them.
+pub fn is_literal_of_datatype(literal: &Literal, datatype: &IRI) -> bool {
+    let name = datatype.as_str();
+    let map = global_literal_parsers();
+    if name == RDFS_LITERAL || !map.is_recognized(name) {
+        return true;
+    }
+    map.is_derived_from(literal.datatype().as_str(), name)
+        && map.is_well_formed(literal)
+        && map.is_well_formed(&Literal::typed(literal.lexical_form(), datatype.clone()))
+}
+
+/// Data property assertions whose value is not in a range of the property
+///
+/// Each of these makes the ontology inconsistent.
+pub fn data_range_violations(ontology: &Ontology) -> Vec<&DataPropertyAssertionAxiom> {
+    let ranges = ontology.data_property_range_axioms();
+    ontology
+        .data_property_assertions()
+        .into_iter()
+        .filter(|assertion| {
+            ranges.iter().any(|range| {
+                range.property() == &**assertion.property()
+                    && !is_literal_of_datatype(assertion.value(), range.range())
+            })
+        })
+        .collect()
+}
+
+/// Whether the lexical form of a literal is invalid for its own datatype,
+/// such as `"abc"^^xsd:integer`
+///
+/// Literals of datatypes without a registered literal parser are never malformed.
+pub fn is_malformed_literal(literal: &Literal) -> bool {
+    !literal.is_well_formed()
+}
+
+/// Data property assertions whose value is a malformed literal
+pub fn malformed_literals(ontology: &Ontology) -> Vec<&DataPropertyAssertionAxiom> {
+    ontology
+        .data_property_assertions()
+        .into_iter()
+        .filter(|assertion| is_malformed_literal(assertion.value()))
+        .collect()
+}
@@ -1,8 +1,11 @@
 //! Datatype value space utilities for OWL 2 datatype reasoning
 //!
 //! This module provides utilities for reasoning about OWL 2 datatypes,
-//! particularly for detecting empty datatype restrictions.
+//! particularly for detecting empty datatype restrictions and checking
+//! literals against data property ranges.
 
+pub mod checker;
 pub mod value_space;
 
+pub use checker::*;
 pub use value_space::*;
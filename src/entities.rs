@@ -7,8 +7,10 @@ use crate::axioms;
 use crate::cache_manager;
 use crate::error::OwlResult;
 use crate::iri::IRI;
+use once_cell::sync::Lazy;
+use parking_lot::{RwLock, RwLockReadGuard};
 use smallvec::SmallVec;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Well-known IRI constants
@@ -82,7 +84,7 @@ pub fn force_global_entity_cache_eviction(count: usize) -> OwlResult<usize> {
 }
 
 /// A named class in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Class {
     /// The IRI of the class
     iri: Arc<IRI>,
@@ -323,6 +325,18 @@ impl PartialEq for ObjectProperty {
 
 impl Eq for ObjectProperty {}
 
+impl PartialOrd for ObjectProperty {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ObjectProperty {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iri.cmp(&other.iri)
+    }
+}
+
 impl std::hash::Hash for ObjectProperty {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.iri.hash(state);
@@ -414,6 +428,18 @@ impl PartialEq for DataProperty {
 
 impl Eq for DataProperty {}
 
+impl PartialOrd for DataProperty {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DataProperty {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iri.cmp(&other.iri)
+    }
+}
+
 impl std::hash::Hash for DataProperty {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         self.iri.hash(state);
@@ -472,7 +498,7 @@ pub enum DataPropertyCharacteristic {
 }
 
 /// A named individual in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct NamedIndividual {
     /// The IRI of the individual
     iri: Arc<IRI>,
@@ -537,7 +563,7 @@ impl NamedIndividual {
 }
 
 /// An annotation in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Annotation {
     /// The annotation property
     property: Arc<IRI>,
@@ -567,7 +593,7 @@ impl Annotation {
 }
 
 /// Annotation values in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum AnnotationValue {
     /// IRI reference
     IRI(Arc<IRI>),
@@ -608,7 +634,7 @@ impl From<&str> for AnnotationValue {
 }
 
 /// A literal value in OWL2
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Literal {
     /// The lexical value
     lexical_form: String,
@@ -679,10 +705,424 @@ impl Literal {
     pub fn is_typed(&self) -> bool {
         !self.is_plain() && !self.is_lang_tagged()
     }
+
+    /// Parse the literal with the parser registered for its datatype
+    ///
+    /// Returns `None` when no parser is registered for the datatype or the
+    /// lexical form is not valid for it.
+    pub fn value(&self) -> Option<LiteralValue> {
+        GLOBAL_LITERAL_PARSERS.read().parse(self)
+    }
+
+    /// Get the value of the literal as a string
+    pub fn as_string(&self) -> Option<String> {
+        match self.value()? {
+            LiteralValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value of the literal as a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match self.value()? {
+            LiteralValue::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value of the literal as an integer
+    pub fn as_i64(&self) -> Option<i64> {
+        match self.value()? {
+            LiteralValue::Integer(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the value of the literal as a floating point number, widening integers
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.value()? {
+            LiteralValue::Double(value) => Some(value),
+            LiteralValue::Integer(value) => Some(value as f64),
+            _ => None,
+        }
+    }
+
+    /// Check whether the lexical form is valid for the datatype
+    ///
+    /// Literals of datatypes without a registered parser are always valid.
+    pub fn is_well_formed(&self) -> bool {
+        GLOBAL_LITERAL_PARSERS.read().is_well_formed(self)
+    }
+}
+
+/// The typed value of a literal
+#[derive(Debug, Clone, PartialEq)]
+pub enum LiteralValue {
+    String(String),
+    Boolean(bool),
+    Integer(i64),
+    Double(f64),
+}
+
+/// Parses the lexical form of a literal into its typed value
+pub type LiteralParser = Arc<dyn Fn(&str) -> Option<LiteralValue> + Send + Sync>;
+
+/// The datatype map: recognized datatypes, the datatype each is derived from,
+/// and literal parsers for their value spaces
+///
+/// The default registry recognizes the OWL 2 datatype map and has parsers for
+/// the `xsd` string, boolean, integer and floating point datatypes, and
+/// `rdf:langString`. Literals of a recognized datatype are checked against
+/// data property ranges; other datatypes accept every literal.
+#[derive(Clone)]
+pub struct LiteralParserRegistry {
+    parsers: HashMap<String, LiteralParser>,
+    /// Recognized datatypes, with the datatype each is directly derived from
+    datatypes: HashMap<String, Option<String>>,
+}
+
+impl std::fmt::Debug for LiteralParserRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut datatypes: Vec<&String> = self.parsers.keys().collect();
+        datatypes.sort();
+        let mut recognized: Vec<&String> = self.datatypes.keys().collect();
+        recognized.sort();
+        f.debug_struct("LiteralParserRegistry")
+            .field("parsers", &datatypes)
+            .field("datatypes", &recognized)
+            .finish()
+    }
+}
+
+impl Default for LiteralParserRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiteralParserRegistry {
+    /// Create a registry with the OWL 2 datatype map and the built-in parsers
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        for (datatype, base) in OWL2_DATATYPE_MAP {
+            registry.register_datatype(*datatype, *base);
+        }
+        for name in ["string", "normalizedString", "token", "language", "anyURI"] {
+            registry.register_xsd(name, |lexical| {
+                Some(LiteralValue::String(lexical.to_string()))
+            });
+        }
+        registry.register(RDF_LANG_STRING, |lexical| {
+            Some(LiteralValue::String(lexical.to_string()))
+        });
+        registry.register_xsd("boolean", |lexical| match lexical.trim() {
+            "true" | "1" => Some(LiteralValue::Boolean(true)),
+            "false" | "0" => Some(LiteralValue::Boolean(false)),
+            _ => None,
+        });
+        for (name, min, max) in [
+            ("integer", i64::MIN, i64::MAX),
+            ("long", i64::MIN, i64::MAX),
+            ("int", i32::MIN as i64, i32::MAX as i64),
+            ("short", i16::MIN as i64, i16::MAX as i64),
+            ("byte", i8::MIN as i64, i8::MAX as i64),
+            ("nonNegativeInteger", 0, i64::MAX),
+            ("positiveInteger", 1, i64::MAX),
+            ("nonPositiveInteger", i64::MIN, 0),
+            ("negativeInteger", i64::MIN, -1),
+            ("unsignedLong", 0, i64::MAX),
+            ("unsignedInt", 0, u32::MAX as i64),
+            ("unsignedShort", 0, u16::MAX as i64),
+            ("unsignedByte", 0, u8::MAX as i64),
+        ] {
+            registry.register_xsd(name, move |lexical| {
+                let value: i64 = lexical.trim().parse().ok()?;
+                (min..=max)
+                    .contains(&value)
+                    .then_some(LiteralValue::Integer(value))
+            });
+        }
+        registry.register_xsd("decimal", |lexical| {
+            let lexical = lexical.trim();
+            let digits = lexical.trim_start_matches(['+', '-']);
+            let valid = lexical.len() - digits.len() <= 1
+                && digits.chars().any(|c| c.is_ascii_digit())
+                && digits.chars().all(|c| c.is_ascii_digit() || c == '.')
+                && digits.matches('.').count() <= 1;
+            if !valid {
+                return None;
+            }
+            lexical.parse().ok().map(LiteralValue::Double)
+        });
+        for name in ["double", "float"] {
+            registry.register_xsd(name, |lexical| {
+                let value = match lexical.trim() {
+                    "INF" | "+INF" => f64::INFINITY,
+                    "-INF" => f64::NEG_INFINITY,
+                    "NaN" => f64::NAN,
+                    // Rust also accepts spellings such as "inf" that xsd does not
+                    other
+                        if other
+                            .chars()
+                            .any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E') =>
+                    {
+                        return None
+                    }
+                    other => other.parse().ok()?,
+                };
+                Some(LiteralValue::Double(value))
+            });
+        }
+        registry
+    }
+
+    /// Create a registry without any datatypes or parsers
+    pub fn empty() -> Self {
+        Self {
+            parsers: HashMap::new(),
+            datatypes: HashMap::new(),
+        }
+    }
+
+    /// Recognize a datatype, derived from `base` if given
+    ///
+    /// Literals of a datatype derived from another are also values of it when
+    /// their lexical form is valid for both.
+    pub fn register_datatype<D: Into<String>>(&mut self, datatype: D, base: Option<&str>) {
+        self.datatypes
+            .insert(datatype.into(), base.map(str::to_string));
+    }
+
+    /// Stop recognizing a datatype, keeping its parser
+    pub fn unregister_datatype(&mut self, datatype: &str) -> bool {
+        self.datatypes.remove(datatype).is_some()
+    }
+
+    /// Whether the datatype is in the map
+    pub fn is_recognized(&self, datatype: &str) -> bool {
+        self.datatypes.contains_key(datatype)
+    }
+
+    /// The datatype a recognized datatype is directly derived from
+    pub fn base_datatype(&self, datatype: &str) -> Option<&str> {
+        self.datatypes.get(datatype)?.as_deref()
+    }
+
+    /// Whether `datatype` is `ancestor` or derived from it
+    pub fn is_derived_from(&self, datatype: &str, ancestor: &str) -> bool {
+        let mut current = datatype;
+        // The length of the map bounds any chain without a cycle
+        for _ in 0..=self.datatypes.len() {
+            if current == ancestor {
+                return true;
+            }
+            match self.base_datatype(current) {
+                Some(base) => current = base,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    /// Register the parser for a datatype, replacing any previous one
+    pub fn register<D, F>(&mut self, datatype: D, parser: F)
+    where
+        D: Into<String>,
+        F: Fn(&str) -> Option<LiteralValue> + Send + Sync + 'static,
+    {
+        self.parsers.insert(datatype.into(), Arc::new(parser));
+    }
+
+    fn register_xsd<F>(&mut self, name: &str, parser: F)
+    where
+        F: Fn(&str) -> Option<LiteralValue> + Send + Sync + 'static,
+    {
+        self.register(format!("http://www.w3.org/2001/XMLSchema#{}", name), parser);
+    }
+
+    /// Remove the parser for a datatype
+    pub fn unregister(&mut self, datatype: &str) -> Option<LiteralParser> {
+        self.parsers.remove(datatype)
+    }
+
+    /// Get the parser for a datatype
+    pub fn parser(&self, datatype: &str) -> Option<&LiteralParser> {
+        self.parsers.get(datatype)
+    }
+
+    /// Parse a literal with the parser for its datatype
+    pub fn parse(&self, literal: &Literal) -> Option<LiteralValue> {
+        self.parser(literal.datatype().as_str())?(literal.lexical_form())
+    }
+
+    /// Check whether a literal parses, treating datatypes without a parser as valid
+    pub fn is_well_formed(&self, literal: &Literal) -> bool {
+        match self.parser(literal.datatype().as_str()) {
+            Some(parser) => parser(literal.lexical_form()).is_some(),
+            None => true,
+        }
+    }
+}
+
+/// The OWL 2 datatype map, with the datatype each is directly derived from
+const OWL2_DATATYPE_MAP: &[(&str, Option<&str>)] = &[
+    ("http://www.w3.org/2000/01/rdf-schema#Literal", None),
+    ("http://www.w3.org/2002/07/owl#real", None),
+    (
+        "http://www.w3.org/2002/07/owl#rational",
+        Some("http://www.w3.org/2002/07/owl#real"),
+    ),
+    (
+        "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral",
+        None,
+    ),
+    (
+        RDF_LANG_STRING,
+        Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral"),
+    ),
+    (
+        "http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral",
+        None,
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#decimal",
+        Some("http://www.w3.org/2002/07/owl#rational"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#integer",
+        Some("http://www.w3.org/2001/XMLSchema#decimal"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#long",
+        Some("http://www.w3.org/2001/XMLSchema#integer"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#int",
+        Some("http://www.w3.org/2001/XMLSchema#long"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#short",
+        Some("http://www.w3.org/2001/XMLSchema#int"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#byte",
+        Some("http://www.w3.org/2001/XMLSchema#short"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#nonNegativeInteger",
+        Some("http://www.w3.org/2001/XMLSchema#integer"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#positiveInteger",
+        Some("http://www.w3.org/2001/XMLSchema#nonNegativeInteger"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#unsignedLong",
+        Some("http://www.w3.org/2001/XMLSchema#nonNegativeInteger"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#unsignedInt",
+        Some("http://www.w3.org/2001/XMLSchema#unsignedLong"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#unsignedShort",
+        Some("http://www.w3.org/2001/XMLSchema#unsignedInt"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#unsignedByte",
+        Some("http://www.w3.org/2001/XMLSchema#unsignedShort"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#nonPositiveInteger",
+        Some("http://www.w3.org/2001/XMLSchema#integer"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#negativeInteger",
+        Some("http://www.w3.org/2001/XMLSchema#nonPositiveInteger"),
+    ),
+    ("http://www.w3.org/2001/XMLSchema#double", None),
+    ("http://www.w3.org/2001/XMLSchema#float", None),
+    (
+        XSD_STRING,
+        Some("http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#normalizedString",
+        Some(XSD_STRING),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#token",
+        Some("http://www.w3.org/2001/XMLSchema#normalizedString"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#language",
+        Some("http://www.w3.org/2001/XMLSchema#token"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#Name",
+        Some("http://www.w3.org/2001/XMLSchema#token"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#NCName",
+        Some("http://www.w3.org/2001/XMLSchema#Name"),
+    ),
+    (
+        "http://www.w3.org/2001/XMLSchema#NMTOKEN",
+        Some("http://www.w3.org/2001/XMLSchema#token"),
+    ),
+    ("http://www.w3.org/2001/XMLSchema#boolean", None),
+    ("http://www.w3.org/2001/XMLSchema#hexBinary", None),
+    ("http://www.w3.org/2001/XMLSchema#base64Binary", None),
+    ("http://www.w3.org/2001/XMLSchema#anyURI", None),
+    ("http://www.w3.org/2001/XMLSchema#dateTime", None),
+    (
+        "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
+        Some("http://www.w3.org/2001/XMLSchema#dateTime"),
+    ),
+];
+
+static GLOBAL_LITERAL_PARSERS: Lazy<RwLock<LiteralParserRegistry>> =
+    Lazy::new(|| RwLock::new(LiteralParserRegistry::new()));
+
+/// The global registry, for the datatype checks elsewhere in the crate
+pub(crate) fn global_literal_parsers() -> RwLockReadGuard<'static, LiteralParserRegistry> {
+    GLOBAL_LITERAL_PARSERS.read()
+}
+
+/// Register a literal parser for a datatype in the global registry used by
+/// the [`Literal`] accessors
+pub fn register_literal_parser<D, F>(datatype: D, parser: F)
+where
+    D: Into<String>,
+    F: Fn(&str) -> Option<LiteralValue> + Send + Sync + 'static,
+{
+    GLOBAL_LITERAL_PARSERS.write().register(datatype, parser);
+}
+
+/// Remove the literal parser for a datatype from the global registry
+pub fn unregister_literal_parser(datatype: &str) -> Option<LiteralParser> {
+    GLOBAL_LITERAL_PARSERS.write().unregister(datatype)
+}
+
+/// Recognize a datatype in the global registry, derived from `base` if given
+pub fn register_datatype<D: Into<String>>(datatype: D, base: Option<&str>) {
+    GLOBAL_LITERAL_PARSERS
+        .write()
+        .register_datatype(datatype, base);
+}
+
+/// Stop recognizing a datatype in the global registry
+pub fn unregister_datatype(datatype: &str) -> bool {
+    GLOBAL_LITERAL_PARSERS.write().unregister_datatype(datatype)
+}
+
+/// Replace the global literal parser registry, returning the previous one
+pub fn set_global_literal_parsers(registry: LiteralParserRegistry) -> LiteralParserRegistry {
+    std::mem::replace(&mut *GLOBAL_LITERAL_PARSERS.write(), registry)
 }
 
 /// Anonymous individual (blank node)
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct AnonymousIndividual {
     /// The node ID
     node_id: String,
@@ -716,7 +1156,7 @@ impl AnonymousIndividual {
 }
 
 /// Any individual (named or anonymous)
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Individual {
     /// Named individual
     Named(NamedIndividual),
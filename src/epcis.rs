@@ -318,11 +318,31 @@ impl EPCISEvent {
         self
     }
 
+    /// Identifier of the location where the event happened, preferring the
+    /// business location over the read point's location
+    pub fn location_id(&self) -> Option<&str> {
+        self.business_location
+            .as_ref()
+            .or(self
+                .read_point
+                .as_ref()
+                .map(|read_point| &read_point.location))
+            .map(|location| location.id.as_str())
+    }
+
     /// Convert to OWL2 ontology representation
     pub fn to_owl2(&self) -> OwlResult<(Ontology, Vec<String>)> {
         let mut ontology = Ontology::new();
         ontology.set_iri("http://example.org/epcis/test-ontology");
 
+        let individual_iris = self.add_to_ontology(&mut ontology)?;
+        Ok((ontology, individual_iris))
+    }
+
+    /// Add this event's entities and axioms to an existing ontology
+    ///
+    /// Returns the IRIs of the individuals created for the event and its EPCs.
+    pub fn add_to_ontology(&self, ontology: &mut Ontology) -> OwlResult<Vec<String>> {
         let mut individual_iris = Vec::new();
 
         // Create EPCIS classes if they don't exist
@@ -343,14 +363,14 @@ impl EPCISEvent {
         ontology.add_class_assertion(event_assertion)?;
 
         // Add event properties
-        self.add_event_properties(&mut ontology, &event_iri)?;
+        self.add_event_properties(ontology, &event_iri)?;
 
         // Add participants, locations, etc.
-        self.add_participants(&mut ontology)?;
-        self.add_locations(&mut ontology)?;
-        self.add_epcs(&mut ontology, &mut individual_iris)?;
+        self.add_participants(ontology)?;
+        self.add_locations(ontology, &event_iri)?;
+        self.add_epcs(ontology, &mut individual_iris)?;
 
-        Ok((ontology, individual_iris))
+        Ok(individual_iris)
     }
 
     fn add_event_properties(&self, ontology: &mut Ontology, event_iri: &str) -> OwlResult<()> {
@@ -388,9 +408,29 @@ impl EPCISEvent {
         Ok(())
     }
 
-    fn add_locations(&self, ontology: &mut Ontology) -> OwlResult<()> {
+    fn add_locations(&self, ontology: &mut Ontology, event_iri: &str) -> OwlResult<()> {
         let location_class = Class::new("http://example.org/epcis/BusinessLocation");
-        ontology.add_class(location_class)?;
+        ontology.add_class(location_class.clone())?;
+
+        if let Some(location_id) = self.location_id() {
+            let location_prop = ObjectProperty::new("http://example.org/epcis/bizLocation");
+            ontology.add_object_property(location_prop.clone())?;
+
+            let location_individual = NamedIndividual::new(format!(
+                "http://example.org/epcis/locations/{}",
+                location_id
+            ));
+            ontology.add_named_individual(location_individual.clone())?;
+            ontology.add_class_assertion(ClassAssertionAxiom::new(
+                location_individual.iri().clone(),
+                ClassExpression::from(location_class),
+            ))?;
+            ontology.add_property_assertion(PropertyAssertionAxiom::new(
+                IRI::new_optimized(event_iri)?,
+                location_prop.iri().clone(),
+                location_individual.iri().clone(),
+            ))?;
+        }
         Ok(())
     }
 
@@ -3,12 +3,15 @@
 //! This module provides parsers for EPCIS documents in XML and JSON formats,
 //! converting them into OWL2 ontologies for reasoning.
 
+use crate::parser::common::escape_xml;
 use crate::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::Read;
 use std::path::Path;
+use std::time::SystemTime;
+use xmltree::Element;
 
 /// EPCIS document parser configuration
 #[derive(Debug, Clone)]
@@ -160,6 +163,159 @@ impl EPCISDocumentParser {
         epcs
     }
 
+    /// Parse an EPCIS XML document with master data into full events
+    ///
+    /// Read points and business locations are described once in the
+    /// `EPCISMasterData` of the header and referenced by id from the events;
+    /// the references are resolved here, so each event carries the complete
+    /// read point and location again. An id without master data yields a
+    /// read point or location with only that id set.
+    pub fn parse_events_xml_str(&self, content: &str) -> OwlResult<Vec<EPCISEvent>> {
+        let root = Element::parse(content.as_bytes())
+            .map_err(|e| OwlError::ParseError(format!("Invalid EPCIS document: {}", e)))?;
+
+        let mut locations = HashMap::new();
+        let mut read_point_sites = Vec::new();
+        for vocabulary in descendants(&root, "Vocabulary") {
+            let vocabulary_type = vocabulary.attributes.get("type").map(String::as_str);
+            for element in descendants(vocabulary, "VocabularyElement") {
+                let Some(id) = element.attributes.get("id") else {
+                    continue;
+                };
+                let attributes = vocabulary_attributes(element);
+                match vocabulary_type {
+                    Some(LOCATION_VOCABULARY) => {
+                        locations.insert(id.clone(), parse_location(id, &attributes));
+                    }
+                    Some(READ_POINT_VOCABULARY) => read_point_sites.push((id.clone(), attributes)),
+                    _ => {}
+                }
+            }
+        }
+        // Read points may be listed before the locations they refer to
+        let read_points: HashMap<String, ReadPoint> = read_point_sites
+            .into_iter()
+            .map(|(id, attributes)| {
+                let read_point = parse_read_point(&id, &attributes, &locations);
+                (id, read_point)
+            })
+            .collect();
+
+        let mut events = Vec::new();
+        for event_list in descendants(&root, "EventList") {
+            for element in event_list
+                .children
+                .iter()
+                .filter_map(|node| node.as_element())
+            {
+                events.push(self.parse_event(element, &locations, &read_points)?);
+            }
+        }
+        Ok(events)
+    }
+
+    /// Parse one event element, resolving its master data references
+    fn parse_event(
+        &self,
+        element: &Element,
+        locations: &HashMap<String, BusinessLocation>,
+        read_points: &HashMap<String, ReadPoint>,
+    ) -> OwlResult<EPCISEvent> {
+        let event_type = match element.name.as_str() {
+            "ObjectEvent" => EPCISEventType::ObjectEvent,
+            "AggregationEvent" => EPCISEventType::AggregationEvent,
+            "TransactionEvent" => EPCISEventType::TransactionEvent,
+            "TransformationEvent" => EPCISEventType::TransformationEvent,
+            other => {
+                return Err(OwlError::ParseError(format!(
+                    "Unsupported EPCIS event type: {}",
+                    other
+                )))
+            }
+        };
+        let event_id = child_text(element, "eventID")
+            .unwrap_or_else(|| format!("event_{}", rand::random::<u64>()));
+        let mut event = EPCISEvent::new(event_id, event_type);
+
+        if let Some(time) = child_text(element, "eventTime") {
+            event.event_time = parse_time(&time)?;
+            event.record_time = event.event_time;
+        }
+        if let Some(time) = child_text(element, "recordTime") {
+            event.record_time = parse_time(&time)?;
+        }
+        event.action = match child_text(element, "action").as_deref() {
+            None | Some("ADD") => EPCISAction::Add,
+            Some("OBSERVE") => EPCISAction::Observe,
+            Some("DELETE") => EPCISAction::Delete,
+            Some(other) => {
+                return Err(OwlError::ParseError(format!(
+                    "Unknown EPCIS action: {}",
+                    other
+                )))
+            }
+        };
+        event.biz_step = child_text(element, "bizStep").map(|urn| parse_business_step(&urn));
+        event.disposition = child_text(element, "disposition").map(|urn| parse_disposition(&urn));
+        event.parent_id = child_text(element, "parentID");
+
+        if let Some(list) = element.get_child("epcList") {
+            event.epc_list = child_texts(list, "epc");
+        }
+        if let Some(list) = element.get_child("childEPCs") {
+            event.child_epcs = Some(child_texts(list, "epc"));
+        }
+        if let Some(list) = element.get_child("quantityList") {
+            for quantity in descendants(list, "quantityElement") {
+                let class = child_text(quantity, "epcClass").unwrap_or_default();
+                let amount = child_text(quantity, "quantity").unwrap_or_default();
+                let amount = amount.parse().map_err(|_| {
+                    OwlError::ParseError(format!("Invalid quantity for {}: {}", class, amount))
+                })?;
+                event.quantity_list.insert(class, amount);
+            }
+        }
+        if let Some(list) = element.get_child("bizTransactionList") {
+            for transaction in descendants(list, "bizTransaction") {
+                event.business_transaction_list.push(BusinessTransaction {
+                    transaction_type: transaction
+                        .attributes
+                        .get("type")
+                        .cloned()
+                        .unwrap_or_default(),
+                    transaction_id: element_text(transaction),
+                });
+            }
+        }
+
+        if let Some(id) = element
+            .get_child("readPoint")
+            .and_then(|rp| child_text(rp, "id"))
+        {
+            event.read_point = Some(read_points.get(&id).cloned().unwrap_or_else(|| {
+                ReadPoint::new(
+                    id.clone(),
+                    String::new(),
+                    bare_location(&id),
+                    ReaderType::Manual,
+                )
+            }));
+        }
+        if let Some(id) = element
+            .get_child("bizLocation")
+            .and_then(|bl| child_text(bl, "id"))
+        {
+            event.business_location = Some(
+                locations
+                    .get(&id)
+                    .cloned()
+                    .unwrap_or_else(|| bare_location(&id)),
+            );
+        }
+
+        Ok(event)
+    }
+
     /// Convert EPCIS events to OWL2 ontology
     pub fn to_ontology(&self, events: &[EPCISSimpleEvent]) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
@@ -310,6 +466,62 @@ impl EPCISDocumentWriter {
             .to_string())
     }
 
+    /// Write events to EPCIS XML together with the master data they reference
+    ///
+    /// Each distinct read point and business location is written once as a
+    /// vocabulary element in the header and the events refer to it by id, so
+    /// [`EPCISDocumentParser::parse_events_xml_str`] restores them in full.
+    /// Sources, destinations and extensions are not written.
+    pub fn write_events_xml(&self, events: &[EPCISEvent]) -> OwlResult<String> {
+        let mut locations = BTreeMap::new();
+        let mut read_points = BTreeMap::new();
+        for event in events {
+            if let Some(read_point) = &event.read_point {
+                read_points.entry(&read_point.id).or_insert(read_point);
+                locations
+                    .entry(&read_point.location.id)
+                    .or_insert(&read_point.location);
+            }
+            if let Some(location) = &event.business_location {
+                locations.entry(&location.id).or_insert(location);
+            }
+        }
+
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <EPCISDocument xmlns=\"urn:epcglobal:epcis:xsd:2\" schemaVersion=\"2.0\">\n",
+        );
+        if !locations.is_empty() {
+            xml.push_str(
+                "    <EPCISHeader>\n        <EPCISMasterData>\n            <VocabularyList>\n",
+            );
+            write_vocabulary(
+                &mut xml,
+                LOCATION_VOCABULARY,
+                locations
+                    .values()
+                    .map(|location| (&location.id, location_attributes(location))),
+            );
+            write_vocabulary(
+                &mut xml,
+                READ_POINT_VOCABULARY,
+                read_points
+                    .values()
+                    .map(|read_point| (&read_point.id, read_point_attributes(read_point))),
+            );
+            xml.push_str(
+                "            </VocabularyList>\n        </EPCISMasterData>\n    </EPCISHeader>\n",
+            );
+        }
+
+        xml.push_str("    <EPCISBody>\n        <EventList>\n");
+        for event in events {
+            write_event(&mut xml, event);
+        }
+        xml.push_str("        </EventList>\n    </EPCISBody>\n</EPCISDocument>\n");
+        Ok(xml)
+    }
+
     /// Write ontology to EPCIS JSON format (placeholder)
     pub fn write_json(&self, _ontology: &Ontology) -> OwlResult<String> {
         Ok(r#"{
@@ -326,3 +538,383 @@ impl Default for EPCISDocumentWriter {
         Self::new()
     }
 }
+
+/// CBV master data attributes
+const MDA: &str = "urn:epcglobal:cbv:mda#";
+/// Master data attributes the CBV has no term for
+const EXTENSION_MDA: &str = "http://example.org/epcis/mda#";
+const LOCATION_VOCABULARY: &str = "urn:epcglobal:epcis:vtype:BusinessLocation";
+const READ_POINT_VOCABULARY: &str = "urn:epcglobal:epcis:vtype:ReadPoint";
+const BIZ_STEP_PREFIX: &str = "urn:epcglobal:cbv:bizstep:";
+const DISPOSITION_PREFIX: &str = "urn:epcglobal:cbv:disp:";
+
+/// CBV names of the business steps, used in both directions
+const BUSINESS_STEPS: [(EPCISBusinessStep, &str); 13] = [
+    (EPCISBusinessStep::Manufacturing, "producing"),
+    (EPCISBusinessStep::Assembling, "assembling"),
+    (EPCISBusinessStep::Commissioning, "commissioning"),
+    (EPCISBusinessStep::Receiving, "receiving"),
+    (EPCISBusinessStep::Shipping, "shipping"),
+    (EPCISBusinessStep::Loading, "loading"),
+    (EPCISBusinessStep::Unloading, "unloading"),
+    (EPCISBusinessStep::Picking, "picking"),
+    (EPCISBusinessStep::Packing, "packing"),
+    (EPCISBusinessStep::Selling, "retail_selling"),
+    (EPCISBusinessStep::Inspecting, "inspecting"),
+    (EPCISBusinessStep::Testing, "sampling"),
+    (EPCISBusinessStep::Certifying, "certifying"),
+];
+
+/// CBV names of the dispositions, used in both directions
+const DISPOSITIONS: [(EPCISDisposition, &str); 11] = [
+    (EPCISDisposition::InProgress, "in_progress"),
+    (EPCISDisposition::Complete, "completeness_verified"),
+    (EPCISDisposition::InStock, "sellable_accessible"),
+    (EPCISDisposition::OutOfStock, "unavailable"),
+    (EPCISDisposition::Reserved, "reserved"),
+    (EPCISDisposition::Passed, "conformant"),
+    (EPCISDisposition::Failed, "non_conformant"),
+    (EPCISDisposition::UnderInspection, "under_inspection"),
+    (EPCISDisposition::Quarantined, "quarantined"),
+    (EPCISDisposition::Recalled, "recalled"),
+    (EPCISDisposition::Destroyed, "destroyed"),
+];
+
+const READER_TYPES: [ReaderType; 5] = [
+    ReaderType::RFID,
+    ReaderType::Barcode,
+    ReaderType::Manual,
+    ReaderType::IoT,
+    ReaderType::API,
+];
+
+const CAPABILITIES: [LocationCapability; 7] = [
+    LocationCapability::Manufacturing,
+    LocationCapability::Warehousing,
+    LocationCapability::Distribution,
+    LocationCapability::Retail,
+    LocationCapability::QualityTesting,
+    LocationCapability::Certification,
+    LocationCapability::Customs,
+];
+
+fn business_step_urn(step: &EPCISBusinessStep) -> String {
+    match BUSINESS_STEPS.iter().find(|(known, _)| known == step) {
+        Some((_, name)) => format!("{}{}", BIZ_STEP_PREFIX, name),
+        None => match step {
+            EPCISBusinessStep::Custom(urn) => urn.clone(),
+            _ => unreachable!("every named business step has a CBV name"),
+        },
+    }
+}
+
+fn parse_business_step(urn: &str) -> EPCISBusinessStep {
+    let name = urn.strip_prefix(BIZ_STEP_PREFIX).unwrap_or(urn);
+    BUSINESS_STEPS
+        .into_iter()
+        .find(|(_, known)| *known == name)
+        .map(|(step, _)| step)
+        .unwrap_or_else(|| EPCISBusinessStep::Custom(urn.to_string()))
+}
+
+fn disposition_urn(disposition: &EPCISDisposition) -> String {
+    match DISPOSITIONS.iter().find(|(known, _)| known == disposition) {
+        Some((_, name)) => format!("{}{}", DISPOSITION_PREFIX, name),
+        None => match disposition {
+            EPCISDisposition::Custom(urn) => urn.clone(),
+            _ => unreachable!("every named disposition has a CBV name"),
+        },
+    }
+}
+
+fn parse_disposition(urn: &str) -> EPCISDisposition {
+    let name = urn.strip_prefix(DISPOSITION_PREFIX).unwrap_or(urn);
+    DISPOSITIONS
+        .into_iter()
+        .find(|(_, known)| *known == name)
+        .map(|(disposition, _)| disposition)
+        .unwrap_or_else(|| EPCISDisposition::Custom(urn.to_string()))
+}
+
+fn format_time(time: SystemTime) -> String {
+    chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+}
+
+fn parse_time(text: &str) -> OwlResult<SystemTime> {
+    chrono::DateTime::parse_from_rfc3339(text)
+        .map(SystemTime::from)
+        .map_err(|e| OwlError::ParseError(format!("Invalid EPCIS time {}: {}", text, e)))
+}
+
+fn location_attributes(location: &BusinessLocation) -> Vec<(String, String)> {
+    let address = &location.address;
+    let mut attributes: Vec<(String, String)> = [
+        ("name", &location.name),
+        ("streetAddressOne", &address.street),
+        ("city", &address.city),
+        ("state", &address.state),
+        ("postalCode", &address.postal_code),
+        ("countryCode", &address.country),
+    ]
+    .into_iter()
+    .filter(|(_, value)| !value.is_empty())
+    .map(|(name, value)| (format!("{}{}", MDA, name), value.clone()))
+    .collect();
+    if let Some((latitude, longitude)) = location.coordinates {
+        attributes.push((format!("{}latitude", MDA), latitude.to_string()));
+        attributes.push((format!("{}longitude", MDA), longitude.to_string()));
+    }
+    for capability in &location.capabilities {
+        attributes.push((
+            format!("{}capability", EXTENSION_MDA),
+            format!("{:?}", capability),
+        ));
+    }
+    attributes
+}
+
+fn read_point_attributes(read_point: &ReadPoint) -> Vec<(String, String)> {
+    let mut attributes = Vec::new();
+    if !read_point.name.is_empty() {
+        attributes.push((format!("{}name", MDA), read_point.name.clone()));
+    }
+    attributes.push((format!("{}site", MDA), read_point.location.id.clone()));
+    attributes.push((
+        format!("{}readerType", EXTENSION_MDA),
+        format!("{:?}", read_point.reader_type),
+    ));
+    attributes
+}
+
+fn write_vocabulary<'a>(
+    xml: &mut String,
+    vocabulary_type: &str,
+    elements: impl Iterator<Item = (&'a String, Vec<(String, String)>)>,
+) {
+    let mut elements = elements.peekable();
+    if elements.peek().is_none() {
+        return;
+    }
+    xml.push_str(&format!(
+        "                <Vocabulary type=\"{}\">\n                    <VocabularyElementList>\n",
+        vocabulary_type
+    ));
+    for (id, attributes) in elements {
+        xml.push_str(&format!(
+            "                        <VocabularyElement id=\"{}\">\n",
+            escape_xml(id)
+        ));
+        for (attribute, value) in attributes {
+            xml.push_str(&format!(
+                "                            <attribute id=\"{}\">{}</attribute>\n",
+                escape_xml(&attribute),
+                escape_xml(&value)
+            ));
+        }
+        xml.push_str("                        </VocabularyElement>\n");
+    }
+    xml.push_str("                    </VocabularyElementList>\n                </Vocabulary>\n");
+}
+
+fn write_event(xml: &mut String, event: &EPCISEvent) {
+    const INDENT: &str = "                ";
+    let field = |xml: &mut String, name: &str, value: &str| {
+        xml.push_str(&format!(
+            "{}<{}>{}</{}>\n",
+            INDENT,
+            name,
+            escape_xml(value),
+            name
+        ));
+    };
+    let list = |xml: &mut String, name: &str, epcs: &[String]| {
+        xml.push_str(&format!("{}<{}>\n", INDENT, name));
+        for epc in epcs {
+            xml.push_str(&format!("{}    <epc>{}</epc>\n", INDENT, escape_xml(epc)));
+        }
+        xml.push_str(&format!("{}</{}>\n", INDENT, name));
+    };
+
+    xml.push_str(&format!("            <{}>\n", event.event_type));
+    field(xml, "eventTime", &format_time(event.event_time));
+    field(xml, "recordTime", &format_time(event.record_time));
+    field(xml, "eventID", &event.event_id);
+    if let Some(parent_id) = &event.parent_id {
+        field(xml, "parentID", parent_id);
+    }
+    if !event.epc_list.is_empty() {
+        list(xml, "epcList", &event.epc_list);
+    }
+    if let Some(child_epcs) = &event.child_epcs {
+        list(xml, "childEPCs", child_epcs);
+    }
+    field(xml, "action", &event.action.to_string());
+    if let Some(step) = &event.biz_step {
+        field(xml, "bizStep", &business_step_urn(step));
+    }
+    if let Some(disposition) = &event.disposition {
+        field(xml, "disposition", &disposition_urn(disposition));
+    }
+    if let Some(read_point) = &event.read_point {
+        xml.push_str(&format!(
+            "{}<readPoint><id>{}</id></readPoint>\n",
+            INDENT,
+            escape_xml(&read_point.id)
+        ));
+    }
+    if let Some(location) = &event.business_location {
+        xml.push_str(&format!(
+            "{}<bizLocation><id>{}</id></bizLocation>\n",
+            INDENT,
+            escape_xml(&location.id)
+        ));
+    }
+    if !event.business_transaction_list.is_empty() {
+        xml.push_str(&format!("{}<bizTransactionList>\n", INDENT));
+        for transaction in &event.business_transaction_list {
+            xml.push_str(&format!(
+                "{}    <bizTransaction type=\"{}\">{}</bizTransaction>\n",
+                INDENT,
+                escape_xml(&transaction.transaction_type),
+                escape_xml(&transaction.transaction_id)
+            ));
+        }
+        xml.push_str(&format!("{}</bizTransactionList>\n", INDENT));
+    }
+    if !event.quantity_list.is_empty() {
+        let quantities: BTreeMap<_, _> = event.quantity_list.iter().collect();
+        xml.push_str(&format!("{}<quantityList>\n", INDENT));
+        for (class, quantity) in quantities {
+            xml.push_str(&format!(
+                "{0}    <quantityElement>\n{0}        <epcClass>{1}</epcClass>\n\
+                 {0}        <quantity>{2}</quantity>\n{0}    </quantityElement>\n",
+                INDENT,
+                escape_xml(class),
+                quantity
+            ));
+        }
+        xml.push_str(&format!("{}</quantityList>\n", INDENT));
+    }
+    xml.push_str(&format!("            </{}>\n", event.event_type));
+}
+
+/// All elements named `name` below `element`, in document order
+fn descendants<'a>(element: &'a Element, name: &str) -> Vec<&'a Element> {
+    let mut found = Vec::new();
+    for child in element.children.iter().filter_map(|node| node.as_element()) {
+        if child.name == name {
+            found.push(child);
+        }
+        found.extend(descendants(child, name));
+    }
+    found
+}
+
+fn element_text(element: &Element) -> String {
+    element
+        .get_text()
+        .map(|text| text.trim().to_string())
+        .unwrap_or_default()
+}
+
+fn child_text(element: &Element, name: &str) -> Option<String> {
+    element.get_child(name).map(element_text)
+}
+
+fn child_texts(element: &Element, name: &str) -> Vec<String> {
+    element
+        .children
+        .iter()
+        .filter_map(|node| node.as_element())
+        .filter(|child| child.name == name)
+        .map(element_text)
+        .collect()
+}
+
+/// The attributes of a vocabulary element as (attribute id, value) pairs
+fn vocabulary_attributes(element: &Element) -> Vec<(String, String)> {
+    element
+        .children
+        .iter()
+        .filter_map(|node| node.as_element())
+        .filter(|child| child.name == "attribute")
+        .filter_map(|child| {
+            let id = child.attributes.get("id")?;
+            Some((id.clone(), element_text(child)))
+        })
+        .collect()
+}
+
+fn bare_location(id: &str) -> BusinessLocation {
+    BusinessLocation::new(
+        id.to_string(),
+        String::new(),
+        Address::new(
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+            String::new(),
+        ),
+    )
+}
+
+fn parse_location(id: &str, attributes: &[(String, String)]) -> BusinessLocation {
+    let mut location = bare_location(id);
+    let (mut latitude, mut longitude) = (None, None);
+    for (attribute, value) in attributes {
+        if let Some(name) = attribute.strip_prefix(MDA) {
+            match name {
+                "name" => location.name = value.clone(),
+                "streetAddressOne" => location.address.street = value.clone(),
+                "city" => location.address.city = value.clone(),
+                "state" => location.address.state = value.clone(),
+                "postalCode" => location.address.postal_code = value.clone(),
+                "countryCode" => location.address.country = value.clone(),
+                "latitude" => latitude = value.parse().ok(),
+                "longitude" => longitude = value.parse().ok(),
+                _ => {}
+            }
+        } else if attribute == &format!("{}capability", EXTENSION_MDA) {
+            if let Some(capability) = CAPABILITIES
+                .into_iter()
+                .find(|capability| format!("{:?}", capability) == *value)
+            {
+                location.capabilities.push(capability);
+            }
+        }
+    }
+    location.coordinates = latitude.zip(longitude);
+    location
+}
+
+fn parse_read_point(
+    id: &str,
+    attributes: &[(String, String)],
+    locations: &HashMap<String, BusinessLocation>,
+) -> ReadPoint {
+    let mut read_point = ReadPoint::new(
+        id.to_string(),
+        String::new(),
+        bare_location(id),
+        ReaderType::Manual,
+    );
+    for (attribute, value) in attributes {
+        if *attribute == format!("{}name", MDA) {
+            read_point.name = value.clone();
+        } else if *attribute == format!("{}site", MDA) {
+            read_point.location = locations
+                .get(value)
+                .cloned()
+                .unwrap_or_else(|| bare_location(value));
+        } else if *attribute == format!("{}readerType", EXTENSION_MDA) {
+            if let Some(reader_type) = READER_TYPES
+                .into_iter()
+                .find(|reader_type| format!("{:?}", reader_type) == *value)
+            {
+                read_point.reader_type = reader_type;
+            }
+        }
+    }
+    read_point
+}
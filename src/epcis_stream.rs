@@ -0,0 +1,220 @@
+//! Concurrent EPCIS event stream processing
+//!
+//! [`EpcisStreamProcessor`] receives EPCIS events one at a time, either pushed
+//! directly or drained from a channel, adds each to a shared ontology as it
+//! arrives, and runs registered continuous queries against it. A query fires
+//! its callback for every new event that matches it; events that arrived
+//! before a query was registered are not replayed.
+
+use crate::epcis::{EPCISAction, EPCISBusinessStep, EPCISDisposition, EPCISEvent, EPCISEventType};
+use crate::error::OwlResult;
+use crate::ontology::Ontology;
+use parking_lot::{Mutex, RwLock, RwLockReadGuard};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+
+/// Criteria an incoming EPCIS event must satisfy; unset criteria match anything
+#[derive(Debug, Clone, Default)]
+pub struct EpcisQuery {
+    event_type: Option<EPCISEventType>,
+    action: Option<EPCISAction>,
+    business_step: Option<EPCISBusinessStep>,
+    disposition: Option<EPCISDisposition>,
+    location_id: Option<String>,
+    epc: Option<String>,
+}
+
+impl EpcisQuery {
+    /// Create a query matching every event
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match events of the given type
+    pub fn event_type(mut self, event_type: EPCISEventType) -> Self {
+        self.event_type = Some(event_type);
+        self
+    }
+
+    /// Match events with the given action
+    pub fn action(mut self, action: EPCISAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    /// Match events with the given business step
+    pub fn business_step(mut self, step: EPCISBusinessStep) -> Self {
+        self.business_step = Some(step);
+        self
+    }
+
+    /// Match events with the given disposition
+    pub fn disposition(mut self, disposition: EPCISDisposition) -> Self {
+        self.disposition = Some(disposition);
+        self
+    }
+
+    /// Match events that happened at the location with this identifier
+    pub fn at_location(mut self, location_id: impl Into<String>) -> Self {
+        self.location_id = Some(location_id.into());
+        self
+    }
+
+    /// Match events that mention this EPC
+    pub fn with_epc(mut self, epc: impl Into<String>) -> Self {
+        self.epc = Some(epc.into());
+        self
+    }
+
+    /// Check whether an event satisfies every criterion of the query
+    pub fn matches(&self, event: &EPCISEvent) -> bool {
+        self.event_type
+            .as_ref()
+            .is_none_or(|event_type| *event_type == event.event_type)
+            && self
+                .action
+                .as_ref()
+                .is_none_or(|action| *action == event.action)
+            && self
+                .business_step
+                .as_ref()
+                .is_none_or(|step| event.biz_step.as_ref() == Some(step))
+            && self
+                .disposition
+                .as_ref()
+                .is_none_or(|disposition| event.disposition.as_ref() == Some(disposition))
+            && self
+                .location_id
+                .as_deref()
+                .is_none_or(|location_id| event.location_id() == Some(location_id))
+            && self.epc.as_ref().is_none_or(|epc| {
+                event.epc_list.contains(epc)
+                    || event
+                        .child_epcs
+                        .as_ref()
+                        .is_some_and(|children| children.contains(epc))
+            })
+    }
+}
+
+/// Identifier of a registered continuous query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QueryId(u64);
+
+/// Callback invoked with each new event matching a continuous query
+pub type EventCallback = Arc<dyn Fn(&EPCISEvent) + Send + Sync>;
+
+struct ContinuousQuery {
+    id: QueryId,
+    query: EpcisQuery,
+    callback: EventCallback,
+}
+
+/// Incrementally maintains an ontology from a stream of EPCIS events
+pub struct EpcisStreamProcessor {
+    ontology: RwLock<Ontology>,
+    queries: Mutex<Vec<ContinuousQuery>>,
+    next_query_id: AtomicU64,
+    events_processed: AtomicUsize,
+}
+
+impl EpcisStreamProcessor {
+    /// Create a processor over an empty ontology
+    pub fn new() -> Self {
+        Self::with_ontology(Ontology::new())
+    }
+
+    /// Create a processor that adds events to an existing ontology
+    pub fn with_ontology(ontology: Ontology) -> Self {
+        Self {
+            ontology: RwLock::new(ontology),
+            queries: Mutex::new(Vec::new()),
+            next_query_id: AtomicU64::new(0),
+            events_processed: AtomicUsize::new(0),
+        }
+    }
+
+    /// Register a continuous query whose callback fires for each new matching event
+    pub fn register_query<F>(&self, query: EpcisQuery, callback: F) -> QueryId
+    where
+        F: Fn(&EPCISEvent) + Send + Sync + 'static,
+    {
+        let id = QueryId(self.next_query_id.fetch_add(1, Ordering::Relaxed));
+        self.queries.lock().push(ContinuousQuery {
+            id,
+            query,
+            callback: Arc::new(callback),
+        });
+        id
+    }
+
+    /// Remove a continuous query, returning whether it was registered
+    pub fn unregister_query(&self, id: QueryId) -> bool {
+        let mut queries = self.queries.lock();
+        let before = queries.len();
+        queries.retain(|query| query.id != id);
+        queries.len() != before
+    }
+
+    /// Add an event to the ontology and fire the queries it matches
+    ///
+    /// Returns the number of callbacks fired.
+    pub fn push(&self, event: EPCISEvent) -> OwlResult<usize> {
+        event.add_to_ontology(&mut self.ontology.write())?;
+        self.events_processed.fetch_add(1, Ordering::Relaxed);
+
+        // Callbacks run outside the lock so they may register or remove queries
+        let callbacks: Vec<EventCallback> = self
+            .queries
+            .lock()
+            .iter()
+            .filter(|query| query.query.matches(&event))
+            .map(|query| query.callback.clone())
+            .collect();
+        for callback in &callbacks {
+            callback(&event);
+        }
+        Ok(callbacks.len())
+    }
+
+    /// Process events from a channel until every sender has been dropped
+    ///
+    /// Returns the number of events processed.
+    pub fn process_channel(&self, receiver: Receiver<EPCISEvent>) -> OwlResult<usize> {
+        let mut processed = 0;
+        for event in receiver {
+            self.push(event)?;
+            processed += 1;
+        }
+        Ok(processed)
+    }
+
+    /// Process a channel on a background thread
+    pub fn spawn(self: &Arc<Self>, receiver: Receiver<EPCISEvent>) -> JoinHandle<OwlResult<usize>> {
+        let processor = Arc::clone(self);
+        std::thread::spawn(move || processor.process_channel(receiver))
+    }
+
+    /// Read access to the ontology built from the events so far
+    pub fn ontology(&self) -> RwLockReadGuard<'_, Ontology> {
+        self.ontology.read()
+    }
+
+    /// Number of events processed so far
+    pub fn events_processed(&self) -> usize {
+        self.events_processed.load(Ordering::Relaxed)
+    }
+
+    /// Number of registered continuous queries
+    pub fn query_count(&self) -> usize {
+        self.queries.lock().len()
+    }
+}
+
+impl Default for EpcisStreamProcessor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,6 @@
 //! Error types for the OWL2 reasoner
 
+use std::cell::RefCell;
 use thiserror::Error;
 
 /// OWL2 Reasoner error type
@@ -22,11 +23,11 @@ pub enum OwlError {
     UnknownPrefix(String),
 
     /// Parse errors
-    #[error("Parse error: {0}")]
+    #[error("Parse error: {}", abbreviate_iris(.0))]
     ParseError(String),
 
     /// Parse errors with line and column information
-    #[error("Parse error at line {line}, column {column}: {message}")]
+    #[error("Parse error at line {line}, column {column}: {}", abbreviate_iris(.message))]
     ParseErrorWithLocation {
         line: usize,
         column: usize,
@@ -38,11 +39,11 @@ pub enum OwlError {
     SerializationError(String),
 
     /// Reasoning errors
-    #[error("Reasoning error: {0}")]
+    #[error("Reasoning error: {}", abbreviate_iris(.0))]
     ReasoningError(String),
 
     /// Tableaux reasoning errors
-    #[error("Tableaux reasoning error: {node_id}: {message}")]
+    #[error("Tableaux reasoning error: {node_id}: {}", abbreviate_iris(.message))]
     TableauxError { node_id: usize, message: String },
 
     /// Graph operation errors
@@ -50,7 +51,7 @@ pub enum OwlError {
     GraphError { operation: String, message: String },
 
     /// Query errors
-    #[error("Query error: {0}")]
+    #[error("Query error: {}", abbreviate_iris(.0))]
     QueryError(String),
 
     /// Storage errors
@@ -70,11 +71,15 @@ pub enum OwlError {
     },
 
     /// Validation errors
-    #[error("Validation error: {0}")]
+    #[error("Validation error: {}", abbreviate_iris(.0))]
     ValidationError(String),
 
     /// Entity validation errors
-    #[error("Entity validation error: {entity_type} '{name}': {message}")]
+    #[error(
+        "Entity validation error: {entity_type} '{}': {}",
+        abbreviate_iris(.name),
+        abbreviate_iris(.message)
+    )]
     EntityValidationError {
         entity_type: String,
         name: String,
@@ -82,19 +87,19 @@ pub enum OwlError {
     },
 
     /// Axiom validation errors
-    #[error("Axiom validation error: {axiom_type}: {message}")]
+    #[error("Axiom validation error: {axiom_type}: {}", abbreviate_iris(.message))]
     AxiomValidationError { axiom_type: String, message: String },
 
     /// OWL2 specification violations
-    #[error("OWL2 specification violation: {0}")]
+    #[error("OWL2 specification violation: {}", abbreviate_iris(.0))]
     OwlViolation(String),
 
     /// Profile validation errors
-    #[error("Profile validation error: {profile}: {message}")]
+    #[error("Profile validation error: {profile}: {}", abbreviate_iris(.message))]
     ProfileViolation { profile: String, message: String },
 
     /// Inconsistent ontology
-    #[error("Inconsistent ontology: {0}")]
+    #[error("Inconsistent ontology: {}", abbreviate_iris(.0))]
     InconsistentOntology(String),
 
     /// Resource limit exceeded errors
@@ -114,7 +119,11 @@ pub enum OwlError {
     ConfigError { parameter: String, message: String },
 
     /// Import resolution errors
-    #[error("Import resolution error for {iri}: {message}")]
+    #[error(
+        "Import resolution error for {}: {}",
+        abbreviate_iris(.iri.as_str()),
+        abbreviate_iris(.message)
+    )]
     ImportResolutionError {
         iri: crate::iri::IRI,
         message: String,
@@ -185,6 +194,131 @@ pub enum OwlError {
 /// Result type for OWL2 operations
 pub type OwlResult<T> = Result<T, OwlError>;
 
+thread_local! {
+    static IRI_DISPLAY: RefCell<IriDisplay> = RefCell::new(IriDisplay::default());
+}
+
+/// How IRIs are written in error messages formatted on the current thread
+///
+/// With registered prefixes, IRIs under a known namespace are shown as CURIEs,
+/// so `<http://example.org/Person>` reads `ex:Person` once `ex` is registered
+/// for `http://example.org/`. The default registers no prefixes, and verbose
+/// mode always shows full IRIs.
+#[derive(Debug, Clone, Default)]
+pub struct IriDisplay {
+    prefixes: Vec<(String, String)>,
+    verbose: bool,
+}
+
+impl IriDisplay {
+    /// Create a display configuration with no prefixes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a display configuration from the prefixes of an IRI registry
+    pub fn from_registry(registry: &crate::iri::IRIRegistry) -> Self {
+        registry
+            .prefixes()
+            .fold(Self::new(), |display, (prefix, namespace)| {
+                display.with_prefix(prefix, namespace)
+            })
+    }
+
+    /// Register a prefix, replacing any earlier namespace for it
+    pub fn with_prefix(mut self, prefix: &str, namespace: &str) -> Self {
+        self.prefixes.retain(|(existing, _)| existing != prefix);
+        self.prefixes
+            .push((prefix.to_string(), namespace.to_string()));
+        // Longer namespaces win when one is nested in another
+        self.prefixes
+            .sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        self
+    }
+
+    /// Show full IRIs even where a prefix applies
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    /// Whether full IRIs are shown
+    pub fn is_verbose(&self) -> bool {
+        self.verbose
+    }
+
+    /// Rewrite the IRIs in `text` that fall under a registered namespace as CURIEs
+    ///
+    /// Angle brackets around an abbreviated IRI are dropped.
+    pub fn abbreviate(&self, text: &str) -> String {
+        if self.verbose || self.prefixes.is_empty() {
+            return text.to_string();
+        }
+
+        let mut result = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some((start, prefix, namespace)) = self.find_namespace(rest) {
+            let after = &rest[start + namespace.len()..];
+            let local_len = after
+                .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.')))
+                .unwrap_or(after.len());
+            // A trailing full stop ends the sentence rather than the name
+            let local = after[..local_len].trim_end_matches('.');
+            let following = after[local.len()..].chars().next();
+            let preceding = rest[..start].chars().next_back();
+
+            // Leave IRIs that only start with the namespace, such as ones with
+            // a further path segment, in full
+            let is_boundary = |c: Option<char>| {
+                c.is_none_or(|c| c.is_whitespace() || "<>()[]{},;'\"`".contains(c))
+            };
+            if !is_boundary(preceding) || !(is_boundary(following) || following == Some('.')) {
+                let end = start + namespace.len();
+                result.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+
+            let bracketed = preceding == Some('<') && following == Some('>');
+            let (before_end, skip) = if bracketed {
+                (start - 1, local.len() + 1)
+            } else {
+                (start, local.len())
+            };
+            result.push_str(&rest[..before_end]);
+            result.push_str(prefix);
+            result.push(':');
+            result.push_str(local);
+            rest = &after[skip..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// The earliest occurrence of a registered namespace in `text`
+    fn find_namespace(&self, text: &str) -> Option<(usize, &str, &str)> {
+        self.prefixes
+            .iter()
+            .filter(|(_, namespace)| !namespace.is_empty())
+            .filter_map(|(prefix, namespace)| {
+                text.find(namespace.as_str())
+                    .map(|start| (start, prefix.as_str(), namespace.as_str()))
+            })
+            .min_by_key(|(start, _, namespace)| (*start, std::cmp::Reverse(namespace.len())))
+    }
+}
+
+/// Set how IRIs are written in error messages on the current thread,
+/// returning the previous configuration
+pub fn set_iri_display(display: IriDisplay) -> IriDisplay {
+    IRI_DISPLAY.with(|current| current.replace(display))
+}
+
+/// Abbreviate the IRIs in an error message with the current thread's configuration
+fn abbreviate_iris(text: &str) -> String {
+    IRI_DISPLAY.with(|display| display.borrow().abbreviate(text))
+}
+
 /// Error context builder for better error messages
 #[derive(Debug, Clone)]
 pub struct ErrorContext {
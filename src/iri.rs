@@ -10,6 +10,7 @@
 //! - **Namespace Support**: Prefix-based IRI abbreviations (e.g., `owl:Class`)
 //! - **Memory Efficiency**: Arc-based sharing and pre-computed hashes
 //! - **Performance**: O(1) cache lookups and optimized comparisons
+//! - **Normalization**: Optional canonical forms, see [`set_global_iri_normalization`]
 //!
 //! ## Usage
 //!
@@ -37,9 +38,10 @@
 use crate::cache::BoundedCache;
 use crate::error::{OwlError, OwlResult};
 use once_cell::sync::Lazy;
+use std::borrow::Cow;
 use std::fmt;
 use std::hash::{DefaultHasher, Hash, Hasher};
-use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 /// Global IRI cache for interning IRIs across the entire application
@@ -248,6 +250,160 @@ pub fn get_global_iri_cache_limit() -> usize {
     GLOBAL_IRI_CACHE_LIMIT.load(Ordering::Relaxed)
 }
 
+/// Rewrites that make equivalent spellings of an IRI intern to the same [`IRI`]
+///
+/// Every option is off by default, so IRIs are kept exactly as written unless
+/// normalization is enabled with [`set_global_iri_normalization`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IriNormalization {
+    /// Drop a trailing `/` from the path, so `http://ex.org/Person/` is
+    /// `http://ex.org/Person` (a bare `/` after the authority is kept)
+    pub trailing_slash: bool,
+    /// Drop an empty fragment, so `http://ex.org/Person#` is `http://ex.org/Person`
+    pub empty_fragment: bool,
+    /// Write the hex digits of percent-encodings in upper case (`%c3%a9` is `%C3%A9`)
+    pub percent_encoding_case: bool,
+    /// Drop the default port of `http` (80) and `https` (443)
+    pub default_port: bool,
+}
+
+impl IriNormalization {
+    /// No normalization: IRIs are interned exactly as written
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Every normalization
+    pub fn all() -> Self {
+        Self {
+            trailing_slash: true,
+            empty_fragment: true,
+            percent_encoding_case: true,
+            default_port: true,
+        }
+    }
+
+    /// Whether any normalization is enabled
+    pub fn is_enabled(&self) -> bool {
+        *self != Self::none()
+    }
+
+    /// The normalized form of an IRI string
+    pub fn apply<'a>(&self, iri: &'a str) -> Cow<'a, str> {
+        if !self.is_enabled() {
+            return Cow::Borrowed(iri);
+        }
+
+        let mut normalized = Cow::Borrowed(iri);
+        if self.percent_encoding_case {
+            normalized = upper_case_percent_encodings(normalized);
+        }
+        if self.default_port {
+            normalized = remove_default_port(normalized);
+        }
+        if self.empty_fragment && normalized.ends_with('#') {
+            normalized.to_mut().pop();
+        }
+        if self.trailing_slash && normalized.ends_with('/') {
+            let path_start = authority_end(&normalized);
+            let path = &normalized[path_start..];
+            if path.len() > 1 && !path.contains(['?', '#']) {
+                normalized.to_mut().pop();
+            }
+        }
+        normalized
+    }
+
+    fn to_bits(self) -> u8 {
+        self.trailing_slash as u8
+            | (self.empty_fragment as u8) << 1
+            | (self.percent_encoding_case as u8) << 2
+            | (self.default_port as u8) << 3
+    }
+
+    fn from_bits(bits: u8) -> Self {
+        Self {
+            trailing_slash: bits & 1 != 0,
+            empty_fragment: bits & 1 << 1 != 0,
+            percent_encoding_case: bits & 1 << 2 != 0,
+            default_port: bits & 1 << 3 != 0,
+        }
+    }
+}
+
+/// Normalization applied to every IRI before it is interned
+static GLOBAL_IRI_NORMALIZATION: AtomicU8 = AtomicU8::new(0);
+
+/// Set the normalization applied to every IRI created from now on
+///
+/// IRIs created earlier keep their spelling, so set this before loading any
+/// ontology whose IRIs should unify.
+pub fn set_global_iri_normalization(normalization: IriNormalization) {
+    GLOBAL_IRI_NORMALIZATION.store(normalization.to_bits(), Ordering::Relaxed);
+}
+
+/// Get the normalization applied to every IRI before it is interned
+pub fn global_iri_normalization() -> IriNormalization {
+    IriNormalization::from_bits(GLOBAL_IRI_NORMALIZATION.load(Ordering::Relaxed))
+}
+
+/// Byte offset where the path starts, after `scheme://authority`
+fn authority_end(iri: &str) -> usize {
+    match iri.find("://") {
+        Some(scheme_end) => {
+            let authority_start = scheme_end + 3;
+            iri[authority_start..]
+                .find(['/', '?', '#'])
+                .map_or(iri.len(), |end| authority_start + end)
+        }
+        None => iri.find(':').map_or(0, |colon| colon + 1),
+    }
+}
+
+fn upper_case_percent_encodings(iri: Cow<'_, str>) -> Cow<'_, str> {
+    let bytes = iri.as_bytes();
+    let needs_change = bytes.windows(3).any(|window| {
+        window[0] == b'%'
+            && window[1..].iter().all(u8::is_ascii_hexdigit)
+            && window[1..].iter().any(u8::is_ascii_lowercase)
+    });
+    if !needs_change {
+        return iri;
+    }
+
+    let mut normalized = String::with_capacity(iri.len());
+    let mut chars = iri.chars();
+    while let Some(c) = chars.next() {
+        normalized.push(c);
+        if c == '%' {
+            let digits: String = chars.clone().take(2).collect();
+            if digits.len() == 2 && digits.chars().all(|d| d.is_ascii_hexdigit()) {
+                normalized.push_str(&digits.to_ascii_uppercase());
+                chars.nth(1);
+            }
+        }
+    }
+    Cow::Owned(normalized)
+}
+
+fn remove_default_port(iri: Cow<'_, str>) -> Cow<'_, str> {
+    let default_port = if iri.starts_with("http://") {
+        ":80"
+    } else if iri.starts_with("https://") {
+        ":443"
+    } else {
+        return iri;
+    };
+    let authority_end = authority_end(&iri);
+    if iri[..authority_end].ends_with(default_port) {
+        let mut normalized = iri.into_owned();
+        normalized.replace_range(authority_end - default_port.len()..authority_end, "");
+        Cow::Owned(normalized)
+    } else {
+        iri
+    }
+}
+
 /// Force eviction of N entries from global IRI cache
 pub fn force_global_iri_cache_eviction(count: usize) -> OwlResult<usize> {
     // This would require adding a force_eviction method to BoundedCache
@@ -334,7 +490,10 @@ impl<'de> serde::Deserialize<'de> for IRI {
 impl IRI {
     /// Create a new IRI from a string with global caching
     pub fn new<S: Into<String>>(iri: S) -> OwlResult<Self> {
-        let iri_str = iri.into();
+        let mut iri_str = iri.into();
+        if let Cow::Owned(normalized) = global_iri_normalization().apply(&iri_str) {
+            iri_str = normalized;
+        }
 
         // Minimal validation: reject empty strings and IRIs without scheme separator.
         // Many components and tests currently accept relaxed IRI forms.
@@ -378,6 +537,8 @@ impl IRI {
 
     /// Create a new optimized IRI with zero-copy operations and `Arc<IRI>` return
     pub fn new_optimized<S: AsRef<str>>(iri_str: S) -> OwlResult<Arc<IRI>> {
+        let normalization = global_iri_normalization();
+        let iri_str = normalization.apply(iri_str.as_ref());
         let iri_str = iri_str.as_ref();
 
         // Minimal validation: reject empty strings and IRIs without scheme separator.
@@ -109,6 +109,12 @@ pub mod datatypes;
 
 /// OWL2 Profile validation (EL, QL, RL) with comprehensive checking
 pub mod profiles;
+
+/// Lint-style diagnostics for common ontology modeling pitfalls
+pub mod linter;
+
+/// Validation of the ABox against SHACL-lite shapes
+pub mod shacl;
 pub mod test_data_generator;
 
 /// GS1 EPCIS ontology implementation for supply chain traceability
@@ -122,6 +128,9 @@ pub mod epcis_parser;
 /// EPCIS test data generator for different scales
 pub mod epcis_test_generator;
 
+/// Concurrent EPCIS event stream processing with continuous queries
+pub mod epcis_stream;
+
 /// Configurable caching system with eviction strategies
 pub mod cache;
 
@@ -149,11 +158,19 @@ pub use axioms::*;
 pub use entities::*;
 pub use epcis::*;
 pub use epcis_test_generator::*;
-pub use error::{OwlError, OwlResult};
+pub use error::{set_iri_display, IriDisplay, OwlError, OwlResult};
 pub use iri::IRI;
-pub use ontology::Ontology;
-pub use parser::{ImportResolver, ImportResolverConfig, OntologyParser, ParserFactory};
+pub use linter::{LintKind, LintSeverity, LintWarning, OntologyLinter};
+pub use ontology::{
+    AnonymousMergeReport, CompactionReport, Insertion, InsertionValidator, Ontology,
+    OntologyHeader, PropertyCharacteristics, PunKinds, Signature, SizeLimits,
+};
+pub use parser::{ImportCycle, ImportResolver, ImportResolverConfig, OntologyParser, ParserFactory};
 pub use reasoning::{
-    OwlReasoner, PatternTerm, QueryEngine, QueryPattern, Reasoner, SimpleReasoner, TriplePattern,
+    AssertionOutcome, ImpactReport, OwlReasoner, PatternTerm, QueryEngine, QueryPattern,
+    Reasoner, SimpleReasoner, TriplePattern,
+};
+pub use shacl::{
+    NodeShape, PropertyShape, ShaclConstraint, ShaclReport, ShaclViolation, ShapesGraph,
 };
 pub use test_data_generator::*;
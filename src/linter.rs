@@ -0,0 +1,327 @@
+//! Lint-style diagnostics for common ontology modeling pitfalls
+//!
+//! The linter inspects the asserted structure of an ontology and reports
+//! suspicious patterns. It is neither a profile check nor a reasoning task:
+//! every warning is derived from the declared entities and the axiom
+//! signature index, so linting is cheap even on large ontologies.
+
+use crate::axioms::{Axiom, AxiomType, ClassExpression};
+use crate::constants::owl;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::Arc;
+
+/// Severity of a lint warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// Worth a look, but often intentional
+    Info,
+    /// Likely a modeling mistake
+    Warning,
+}
+
+/// Kind of modeling pitfall detected by the linter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintKind {
+    /// Class with no superclass, subclass or equivalent class
+    OrphanClass,
+    /// Property with a declared domain but no declared range
+    PropertyWithoutRange,
+    /// Inverse properties whose domains, ranges or characteristics disagree
+    InconsistentInverse,
+    /// Declared entity that no axiom refers to
+    UnusedEntity,
+    /// Subclass axiom with the same class on both sides, as in `C ⊑ C`
+    ReflexiveSubClass,
+}
+
+/// A single diagnostic produced by [`OntologyLinter`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    /// The kind of pitfall
+    pub kind: LintKind,
+    /// How serious the finding is
+    pub severity: LintSeverity,
+    /// The entity the warning is about
+    pub subject: IRI,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{:?}] {:?}: {}", self.severity, self.kind, self.message)
+    }
+}
+
+/// Diagnostics pass reporting common modeling pitfalls
+pub struct OntologyLinter<'a> {
+    ontology: &'a Ontology,
+    disabled: HashSet<LintKind>,
+}
+
+impl<'a> OntologyLinter<'a> {
+    /// Create a linter for the given ontology with all checks enabled
+    pub fn new(ontology: &'a Ontology) -> Self {
+        OntologyLinter {
+            ontology,
+            disabled: HashSet::new(),
+        }
+    }
+
+    /// Disable a check
+    pub fn without(mut self, kind: LintKind) -> Self {
+        self.disabled.insert(kind);
+        self
+    }
+
+    /// Run all enabled checks, returning warnings ordered by severity (most severe first)
+    pub fn lint(&self) -> Vec<LintWarning> {
+        let mut warnings = Vec::new();
+
+        if self.is_enabled(LintKind::OrphanClass) {
+            self.check_orphan_classes(&mut warnings);
+        }
+        if self.is_enabled(LintKind::PropertyWithoutRange) {
+            self.check_properties_without_range(&mut warnings);
+        }
+        if self.is_enabled(LintKind::InconsistentInverse) {
+            self.check_inverse_properties(&mut warnings);
+        }
+        if self.is_enabled(LintKind::UnusedEntity) {
+            self.check_unused_entities(&mut warnings);
+        }
+        if self.is_enabled(LintKind::ReflexiveSubClass) {
+            self.check_reflexive_subclasses(&mut warnings);
+        }
+
+        warnings.sort_by(|a, b| {
+            b.severity
+                .cmp(&a.severity)
+                .then(a.kind.cmp(&b.kind))
+                .then_with(|| a.subject.cmp(&b.subject))
+        });
+        warnings
+    }
+
+    fn is_enabled(&self, kind: LintKind) -> bool {
+        !self.disabled.contains(&kind)
+    }
+
+    /// Classes that are disconnected from the class hierarchy
+    fn check_orphan_classes(&self, warnings: &mut Vec<LintWarning>) {
+        let mut connected: HashSet<Arc<IRI>> = HashSet::new();
+
+        for axiom in self.ontology.subclass_axioms() {
+            // C ⊑ C does not place C in the hierarchy
+            if axiom.is_reflexive() {
+                continue;
+            }
+            if let ClassExpression::Class(sub) = axiom.sub_class() {
+                connected.insert(sub.iri().clone());
+            }
+            if let ClassExpression::Class(sup) = axiom.super_class() {
+                connected.insert(sup.iri().clone());
+            }
+        }
+        for axiom in self.ontology.equivalent_classes_axioms() {
+            connected.extend(axiom.classes().iter().cloned());
+        }
+
+        let thing = owl::thing();
+        let nothing = owl::nothing();
+        for class in self.ontology.classes() {
+            let iri = class.iri();
+            if **iri == thing || **iri == nothing || connected.contains(iri) {
+                continue;
+            }
+            warnings.push(LintWarning {
+                kind: LintKind::OrphanClass,
+                severity: LintSeverity::Info,
+                subject: (**iri).clone(),
+                message: format!(
+                    "Class {} has no superclass, subclass or equivalent class",
+                    iri
+                ),
+            });
+        }
+    }
+
+    /// Properties with a domain axiom but no range axiom
+    fn check_properties_without_range(&self, warnings: &mut Vec<LintWarning>) {
+        let object_ranges: HashSet<&IRI> = self
+            .ontology
+            .object_property_range_axioms()
+            .into_iter()
+            .map(|axiom| axiom.property())
+            .collect();
+        let data_ranges: HashSet<&IRI> = self
+            .ontology
+            .data_property_range_axioms()
+            .into_iter()
+            .map(|axiom| axiom.property())
+            .collect();
+
+        let mut reported = HashSet::new();
+        let object_domains = self
+            .ontology
+            .object_property_domain_axioms()
+            .into_iter()
+            .map(|axiom| axiom.property())
+            .filter(|property| !object_ranges.contains(property));
+        let data_domains = self
+            .ontology
+            .data_property_domain_axioms()
+            .into_iter()
+            .map(|axiom| axiom.property())
+            .filter(|property| !data_ranges.contains(property));
+
+        for property in object_domains.chain(data_domains) {
+            if reported.insert(property) {
+                warnings.push(LintWarning {
+                    kind: LintKind::PropertyWithoutRange,
+                    severity: LintSeverity::Warning,
+                    subject: property.clone(),
+                    message: format!("Property {} has a domain but no range", property),
+                });
+            }
+        }
+    }
+
+    /// Inverse property pairs whose domain/range or transitivity do not mirror each other
+    fn check_inverse_properties(&self, warnings: &mut Vec<LintWarning>) {
+        let mut domains: HashMap<&IRI, HashSet<&IRI>> = HashMap::new();
+        for axiom in self.ontology.object_property_domain_axioms() {
+            if !axiom.property_expression().is_named() {
+                continue;
+            }
+            if let ClassExpression::Class(class) = axiom.domain() {
+                domains
+                    .entry(axiom.property())
+                    .or_default()
+                    .insert(class.iri().as_ref());
+            }
+        }
+        let mut ranges: HashMap<&IRI, HashSet<&IRI>> = HashMap::new();
+        for axiom in self.ontology.object_property_range_axioms() {
+            if !axiom.property_expression().is_named() {
+                continue;
+            }
+            if let ClassExpression::Class(class) = axiom.range() {
+                ranges
+                    .entry(axiom.property())
+                    .or_default()
+                    .insert(class.iri().as_ref());
+            }
+        }
+        for axiom in self
+            .ontology
+            .axioms_of_type(AxiomType::InverseObjectProperties)
+        {
+            let Axiom::InverseObjectProperties(inverse) = axiom else {
+                continue;
+            };
+            let p = inverse.property1().property_iri().as_ref();
+            let q = inverse.property2().property_iri().as_ref();
+
+            let mirrored =
+                |left: Option<&HashSet<&IRI>>, right: Option<&HashSet<&IRI>>| match (left, right) {
+                    (Some(left), Some(right)) => left == right,
+                    _ => true,
+                };
+
+            let mut problems = Vec::new();
+            if !mirrored(domains.get(p), ranges.get(q)) {
+                problems.push(format!("domain of {} differs from range of {}", p, q));
+            }
+            if !mirrored(ranges.get(p), domains.get(q)) {
+                problems.push(format!("range of {} differs from domain of {}", p, q));
+            }
+            if self.ontology.property_characteristics(p).transitive
+                != self.ontology.property_characteristics(q).transitive
+            {
+                problems.push("only one of the pair is declared transitive".to_string());
+            }
+
+            if !problems.is_empty() {
+                warnings.push(LintWarning {
+                    kind: LintKind::InconsistentInverse,
+                    severity: LintSeverity::Warning,
+                    subject: p.clone(),
+                    message: format!(
+                        "Inverse properties {} and {} are inconsistent: {}",
+                        p,
+                        q,
+                        problems.join("; ")
+                    ),
+                });
+            }
+        }
+    }
+
+    /// Declared entities that no axiom mentions
+    fn check_unused_entities(&self, warnings: &mut Vec<LintWarning>) {
+        let declared = self
+            .ontology
+            .classes()
+            .iter()
+            .map(|c| ("Class", c.iri()))
+            .chain(
+                self.ontology
+                    .object_properties()
+                    .iter()
+                    .map(|p| ("Object property", p.iri())),
+            )
+            .chain(
+                self.ontology
+                    .data_properties()
+                    .iter()
+                    .map(|p| ("Data property", p.iri())),
+            )
+            .chain(
+                self.ontology
+                    .named_individuals()
+                    .iter()
+                    .map(|i| ("Individual", i.iri())),
+            );
+
+        for (entity_kind, iri) in declared {
+            if !self.ontology.is_referenced(iri) {
+                warnings.push(LintWarning {
+                    kind: LintKind::UnusedEntity,
+                    severity: LintSeverity::Info,
+                    subject: (**iri).clone(),
+                    message: format!("{} {} is declared but never used", entity_kind, iri),
+                });
+            }
+        }
+    }
+
+    /// Subclass axioms stating that a class is a subclass of itself
+    fn check_reflexive_subclasses(&self, warnings: &mut Vec<LintWarning>) {
+        for axiom in self.ontology.subclass_axioms() {
+            if !axiom.is_reflexive() {
+                continue;
+            }
+            let subject = match axiom.sub_class() {
+                ClassExpression::Class(class) => (**class.iri()).clone(),
+                expression => expression
+                    .signature()
+                    .first()
+                    .map(|iri| (**iri).clone())
+                    .unwrap_or_else(owl::thing),
+            };
+            warnings.push(LintWarning {
+                kind: LintKind::ReflexiveSubClass,
+                severity: LintSeverity::Info,
+                message: format!(
+                    "Subclass axiom on {} has the same class on both sides",
+                    subject
+                ),
+                subject,
+            });
+        }
+    }
+}
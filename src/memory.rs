@@ -7,7 +7,8 @@ use crate::cache_manager;
 use crate::entities::clear_global_entity_cache;
 use crate::iri::clear_global_iri_cache;
 use once_cell::sync::Lazy;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -27,6 +28,51 @@ pub struct MemoryStats {
     pub cleanup_count: u64,
     /// Memory pressure level (0.0 to 1.0)
     pub pressure_level: f64,
+    /// Whether the usage figures were measured by [`TrackingAllocator`]
+    /// rather than estimated
+    pub measured: bool,
+}
+
+/// Heap bytes currently allocated through [`TrackingAllocator`]
+static TRACKED_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Whether [`TrackingAllocator`] has served any allocation
+static TRACKING: AtomicBool = AtomicBool::new(false);
+
+/// Global allocator that counts live heap bytes for the memory monitor
+///
+/// Memory usage is otherwise estimated from the IRI cache size. Installing
+/// this allocator in a binary, test or bench makes [`MemoryStats::total_usage`]
+/// report the live heap:
+///
+/// ```no_run
+/// use owl2_reasoner::memory::TrackingAllocator;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+/// ```
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            TRACKED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+            TRACKING.store(true, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        TRACKED_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Live heap bytes counted by [`TrackingAllocator`], if it is installed
+pub fn tracked_heap_bytes() -> Option<usize> {
+    TRACKING
+        .load(Ordering::Relaxed)
+        .then(|| TRACKED_BYTES.load(Ordering::Relaxed))
 }
 
 /// Memory monitoring configuration
@@ -81,6 +127,7 @@ impl MemoryMonitor {
                 entity_cache_size: 0,
                 cleanup_count: 0,
                 pressure_level: 0.0,
+                measured: false,
             }),
             cleanup_count: AtomicU64::new(0),
             last_cleanup: Mutex::new(Instant::now()),
@@ -126,6 +173,7 @@ impl MemoryMonitor {
                         entity_cache_size: 0,
                         cleanup_count: self.cleanup_count.load(Ordering::Relaxed),
                         pressure_level: 0.0,
+                        measured: tracked_heap_bytes().is_some(),
                     };
                 }
             };
@@ -133,6 +181,7 @@ impl MemoryMonitor {
         // Update current usage safely
         stats.total_usage = self.get_current_memory_usage_safe();
         stats.peak_usage = stats.peak_usage.max(stats.total_usage);
+        stats.measured = tracked_heap_bytes().is_some();
 
         // Update cache sizes (now using unified cache) with graceful fallback
         match cache_manager::global_cache_manager().get_iri_cache_size() {
@@ -280,6 +329,10 @@ impl MemoryMonitor {
 
     /// Safe memory usage estimation without unsafe operations
     fn get_current_memory_usage_safe(&self) -> usize {
+        if let Some(bytes) = tracked_heap_bytes() {
+            return bytes;
+        }
+
         // Use a safe, platform-independent estimation approach
         // This avoids unsafe platform-specific code
 
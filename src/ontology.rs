@@ -44,11 +44,576 @@ use crate::axioms::class_expressions::ClassExpression;
 use crate::entities::*;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::{IRIRegistry, IRI};
-use crate::parser::import_resolver::ImportResolver;
+use crate::parser::import_resolver::{ImportCycle, ImportResolver};
+use crate::profiles::{Owl2Profile, Owl2ProfileValidator, ProfileValidator, ProfileViolation};
+use crate::reasoning::{OwlReasoner, Reasoner};
 use hashbrown::HashMap;
 use std::collections::HashSet;
 use std::sync::Arc;
 
+const OWL_THING: &str = "http://www.w3.org/2002/07/owl#Thing";
+const OWL_VERSION_INFO: &str = "http://www.w3.org/2002/07/owl#versionInfo";
+
+/// Outcome of adding an entity to an [`Ontology`]
+///
+/// Adding an entity that is already declared is not an error; the outcome lets
+/// callers that consider duplicates a bug detect them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Insertion {
+    /// The entity was newly added
+    Inserted,
+    /// The entity was already present and the ontology is unchanged
+    AlreadyPresent,
+}
+
+impl Insertion {
+    fn from_inserted(inserted: bool) -> Self {
+        if inserted {
+            Insertion::Inserted
+        } else {
+            Insertion::AlreadyPresent
+        }
+    }
+
+    /// Whether the entity was newly added
+    pub fn is_inserted(self) -> bool {
+        self == Insertion::Inserted
+    }
+}
+
+/// Check run on each axiom before it is added to an [`Ontology`]
+///
+/// See [`Ontology::set_insertion_validator`].
+#[derive(Clone)]
+pub struct InsertionValidator(Arc<AxiomCheck>);
+
+type AxiomCheck = dyn Fn(&axioms::Axiom) -> OwlResult<()> + Send + Sync;
+
+impl InsertionValidator {
+    /// Check an axiom, returning the error that rejects it
+    pub fn check(&self, axiom: &axioms::Axiom) -> OwlResult<()> {
+        (self.0)(axiom)
+    }
+}
+
+impl std::fmt::Debug for InsertionValidator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("InsertionValidator")
+    }
+}
+
+/// Upper bounds on the size of an [`Ontology`]
+///
+/// See [`Ontology::set_size_limits`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SizeLimits {
+    /// Maximum number of axioms (None = unlimited)
+    pub max_axioms: Option<usize>,
+    /// Maximum number of entities as counted by [`Ontology::entity_count`]
+    /// (None = unlimited)
+    pub max_entities: Option<usize>,
+}
+
+/// Ontology-level metadata: the ontology IRI, version IRI and annotations
+///
+/// Annotations such as `owl:versionInfo`, `rdfs:comment` or `dc:creator` that
+/// describe the ontology itself live here rather than among the axioms.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OntologyHeader {
+    /// The ontology IRI
+    pub ontology_iri: Option<Arc<IRI>>,
+    /// The version IRI
+    pub version_iri: Option<Arc<IRI>>,
+    /// Annotations on the ontology
+    pub annotations: Vec<Annotation>,
+}
+
+impl OntologyHeader {
+    /// Get the values of the header annotations with the given property
+    pub fn annotation_values(&self, property: &IRI) -> Vec<&AnnotationValue> {
+        self.annotations
+            .iter()
+            .filter(|annotation| **annotation.property() == *property)
+            .map(|annotation| annotation.value())
+            .collect()
+    }
+
+    /// Get the lexical form of the `owl:versionInfo` annotation, if any
+    pub fn version_info(&self) -> Option<&str> {
+        self.annotations.iter().find_map(|annotation| {
+            match (annotation.property().as_str(), annotation.value()) {
+                (OWL_VERSION_INFO, AnnotationValue::Literal(literal)) => {
+                    Some(literal.lexical_form())
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Entity kinds an IRI is used as, reported by [`Ontology::is_punned`]
+///
+/// OWL2 DL allows the same IRI to name entities of different kinds, for
+/// example a class that is also an individual. The two uses have independent
+/// interpretations, so an assertion about the individual says nothing about
+/// the class and vice versa.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PunKinds {
+    /// Declared or used as a class
+    pub class: bool,
+    /// Declared or used as an object property
+    pub object_property: bool,
+    /// Declared or used as a data property
+    pub data_property: bool,
+    /// Declared or used as an annotation property
+    pub annotation_property: bool,
+    /// Declared or used as a named individual
+    pub individual: bool,
+}
+
+impl PunKinds {
+    /// Number of entity kinds the IRI is used as
+    pub fn count(&self) -> usize {
+        [
+            self.class,
+            self.object_property,
+            self.data_property,
+            self.annotation_property,
+            self.individual,
+        ]
+        .into_iter()
+        .filter(|&kind| kind)
+        .count()
+    }
+
+    /// Whether the IRI is used as more than one kind of entity
+    pub fn is_punned(&self) -> bool {
+        self.count() > 1
+    }
+
+    /// Record the kinds `iri` is used as within a class expression
+    fn add_class_expression(&mut self, expression: &ClassExpression, iri: &IRI) {
+        for sub in expression.collect_subexpressions() {
+            match sub {
+                ClassExpression::Class(class) => self.class |= **class.iri() == *iri,
+                ClassExpression::ObjectOneOf(individuals) => {
+                    self.individual |= individuals
+                        .iter()
+                        .any(|individual| individual.iri().is_some_and(|i| **i == *iri));
+                }
+                ClassExpression::ObjectHasValue(property, individual) => {
+                    self.object_property |= property.property_iri().as_ref() == iri;
+                    self.individual |= individual.iri().is_some_and(|i| **i == *iri);
+                }
+                ClassExpression::ObjectSomeValuesFrom(property, _)
+                | ClassExpression::ObjectAllValuesFrom(property, _)
+                | ClassExpression::ObjectHasSelf(property)
+                | ClassExpression::ObjectMinCardinality(_, property)
+                | ClassExpression::ObjectMaxCardinality(_, property)
+                | ClassExpression::ObjectExactCardinality(_, property) => {
+                    self.object_property |= property.property_iri().as_ref() == iri;
+                }
+                ClassExpression::DataSomeValuesFrom(property, _)
+                | ClassExpression::DataAllValuesFrom(property, _)
+                | ClassExpression::DataHasValue(property, _)
+                | ClassExpression::DataMinCardinality(_, property)
+                | ClassExpression::DataMaxCardinality(_, property)
+                | ClassExpression::DataExactCardinality(_, property) => {
+                    self.data_property |= property.property_iri().as_ref() == iri;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Characteristics of an object property, reported by
+/// [`Ontology::property_characteristics`]
+///
+/// Each flag is set when a characteristic axiom names the property or the
+/// declared property entity carries the characteristic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PropertyCharacteristics {
+    /// Each subject has at most one object
+    pub functional: bool,
+    /// Each object has at most one subject
+    pub inverse_functional: bool,
+    /// R(a,b) and R(b,c) imply R(a,c)
+    pub transitive: bool,
+    /// R(a,b) implies R(b,a)
+    pub symmetric: bool,
+    /// R(a,b) excludes R(b,a)
+    pub asymmetric: bool,
+    /// R(a,a) holds for every individual
+    pub reflexive: bool,
+    /// R(a,a) holds for no individual
+    pub irreflexive: bool,
+}
+
+impl PropertyCharacteristics {
+    /// Whether the property has the given characteristic
+    pub fn has(&self, characteristic: ObjectPropertyCharacteristic) -> bool {
+        match characteristic {
+            ObjectPropertyCharacteristic::Functional => self.functional,
+            ObjectPropertyCharacteristic::InverseFunctional => self.inverse_functional,
+            ObjectPropertyCharacteristic::Transitive => self.transitive,
+            ObjectPropertyCharacteristic::Symmetric => self.symmetric,
+            ObjectPropertyCharacteristic::Asymmetric => self.asymmetric,
+            ObjectPropertyCharacteristic::Reflexive => self.reflexive,
+            ObjectPropertyCharacteristic::Irreflexive => self.irreflexive,
+        }
+    }
+
+    /// Record a characteristic
+    fn set(&mut self, characteristic: ObjectPropertyCharacteristic) {
+        let flag = match characteristic {
+            ObjectPropertyCharacteristic::Functional => &mut self.functional,
+            ObjectPropertyCharacteristic::InverseFunctional => &mut self.inverse_functional,
+            ObjectPropertyCharacteristic::Transitive => &mut self.transitive,
+            ObjectPropertyCharacteristic::Symmetric => &mut self.symmetric,
+            ObjectPropertyCharacteristic::Asymmetric => &mut self.asymmetric,
+            ObjectPropertyCharacteristic::Reflexive => &mut self.reflexive,
+            ObjectPropertyCharacteristic::Irreflexive => &mut self.irreflexive,
+        };
+        *flag = true;
+    }
+
+    /// Whether the property has no characteristic at all
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The entities an ontology's axioms use, by kind
+///
+/// Returned by [`Ontology::used_signature`]. Only entities mentioned by a logical
+/// axiom are included, whether or not they are declared, and including those
+/// that only occur inside nested class expressions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Signature {
+    /// Classes used in class expressions and class axioms
+    pub classes: HashSet<Arc<IRI>>,
+    /// Object properties used in restrictions, assertions and property axioms
+    pub object_properties: HashSet<Arc<IRI>>,
+    /// Data properties used in restrictions, assertions and property axioms
+    pub data_properties: HashSet<Arc<IRI>>,
+    /// Named individuals used in assertions and enumerations
+    pub individuals: HashSet<Arc<IRI>>,
+    /// Datatypes used in data ranges
+    pub datatypes: HashSet<Arc<IRI>>,
+}
+
+impl Signature {
+    /// Total number of entities in the signature
+    pub fn len(&self) -> usize {
+        self.classes.len()
+            + self.object_properties.len()
+            + self.data_properties.len()
+            + self.individuals.len()
+            + self.datatypes.len()
+    }
+
+    /// Whether the signature holds no entities
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the IRI is used as any kind of entity
+    pub fn contains(&self, iri: &IRI) -> bool {
+        self.classes.contains(iri)
+            || self.object_properties.contains(iri)
+            || self.data_properties.contains(iri)
+            || self.individuals.contains(iri)
+            || self.datatypes.contains(iri)
+    }
+
+    /// Record the entities of an axiom
+    ///
+    /// Key properties are not typed by the axiom, so `ontology`'s declarations
+    /// decide whether they are data or object properties.
+    fn add_axiom(&mut self, axiom: &axioms::Axiom, ontology: &Ontology) {
+        use axioms::Axiom;
+
+        match axiom {
+            Axiom::SubClassOf(axiom) => {
+                self.add_class_expression(axiom.sub_class());
+                self.add_class_expression(axiom.super_class());
+            }
+            Axiom::EquivalentClasses(axiom) => {
+                for expression in axiom.class_expressions() {
+                    self.add_class_expression(expression);
+                }
+            }
+            Axiom::DisjointClasses(axiom) => insert_all(&mut self.classes, axiom.classes()),
+            Axiom::ClassAssertion(axiom) => {
+                insert(&mut self.individuals, axiom.individual());
+                self.add_class_expression(axiom.class_expr());
+            }
+            Axiom::PropertyAssertion(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.object_properties, axiom.property());
+                if let Some(object) = axiom.object_iri() {
+                    insert(&mut self.individuals, object);
+                }
+            }
+            Axiom::DataPropertyAssertion(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.data_properties, axiom.property());
+            }
+            Axiom::NegativeObjectPropertyAssertion(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.object_properties, axiom.property());
+                insert(&mut self.individuals, axiom.object());
+            }
+            Axiom::NegativeDataPropertyAssertion(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.data_properties, axiom.property());
+            }
+            Axiom::SameIndividual(axiom) => insert_all(&mut self.individuals, axiom.individuals()),
+            Axiom::DifferentIndividuals(axiom) => {
+                insert_all(&mut self.individuals, axiom.individuals())
+            }
+            Axiom::SubObjectProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.sub_property());
+                insert(&mut self.object_properties, axiom.super_property());
+            }
+            Axiom::EquivalentObjectProperties(axiom) => {
+                insert_all(&mut self.object_properties, axiom.properties())
+            }
+            Axiom::DisjointObjectProperties(axiom) => {
+                insert_all(&mut self.object_properties, axiom.properties())
+            }
+            Axiom::FunctionalProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::InverseFunctionalProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::ReflexiveProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::IrreflexiveProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::SymmetricProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::AsymmetricProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::TransitiveProperty(axiom) => {
+                insert(&mut self.object_properties, axiom.property())
+            }
+            Axiom::SubPropertyChainOf(axiom) => {
+                for property in axiom.property_chain() {
+                    insert(&mut self.object_properties, property.property_iri());
+                }
+                insert(
+                    &mut self.object_properties,
+                    axiom.super_property().property_iri(),
+                );
+            }
+            Axiom::InverseObjectProperties(axiom) => {
+                insert(
+                    &mut self.object_properties,
+                    axiom.property1().property_iri(),
+                );
+                insert(
+                    &mut self.object_properties,
+                    axiom.property2().property_iri(),
+                );
+            }
+            Axiom::ObjectPropertyDomain(axiom) => {
+                insert(&mut self.object_properties, axiom.property());
+                self.add_class_expression(axiom.domain());
+            }
+            Axiom::ObjectPropertyRange(axiom) => {
+                insert(&mut self.object_properties, axiom.property());
+                self.add_class_expression(axiom.range());
+            }
+            Axiom::ObjectMinQualifiedCardinality(axiom) => {
+                insert(&mut self.object_properties, axiom.property().property_iri());
+                self.add_class_expression(axiom.filler());
+            }
+            Axiom::ObjectMaxQualifiedCardinality(axiom) => {
+                insert(&mut self.object_properties, axiom.property().property_iri());
+                self.add_class_expression(axiom.filler());
+            }
+            Axiom::ObjectExactQualifiedCardinality(axiom) => {
+                insert(&mut self.object_properties, axiom.property().property_iri());
+                self.add_class_expression(axiom.filler());
+            }
+            Axiom::SubDataProperty(axiom) => {
+                insert(&mut self.data_properties, axiom.sub_property());
+                insert(&mut self.data_properties, axiom.super_property());
+            }
+            Axiom::EquivalentDataProperties(axiom) => {
+                insert_all(&mut self.data_properties, axiom.properties())
+            }
+            Axiom::DisjointDataProperties(axiom) => {
+                insert_all(&mut self.data_properties, axiom.properties())
+            }
+            Axiom::FunctionalDataProperty(axiom) => {
+                insert(&mut self.data_properties, axiom.property())
+            }
+            Axiom::DataPropertyDomain(axiom) => {
+                insert(&mut self.data_properties, axiom.property());
+                self.add_class_expression(axiom.domain());
+            }
+            Axiom::DataPropertyRange(axiom) => {
+                insert(&mut self.data_properties, axiom.property());
+                insert(&mut self.datatypes, axiom.range());
+            }
+            Axiom::DataMinQualifiedCardinality(axiom) => {
+                insert(&mut self.data_properties, axiom.property().property_iri());
+                insert(&mut self.datatypes, axiom.filler());
+            }
+            Axiom::DataMaxQualifiedCardinality(axiom) => {
+                insert(&mut self.data_properties, axiom.property().property_iri());
+                insert(&mut self.datatypes, axiom.filler());
+            }
+            Axiom::DataExactQualifiedCardinality(axiom) => {
+                insert(&mut self.data_properties, axiom.property().property_iri());
+                insert(&mut self.datatypes, axiom.filler());
+            }
+            Axiom::HasKey(axiom) => {
+                self.add_class_expression(axiom.class_expression());
+                for property in axiom.properties() {
+                    if ontology.data_properties.iter().any(|p| p.iri() == property) {
+                        insert(&mut self.data_properties, property);
+                    } else {
+                        insert(&mut self.object_properties, property);
+                    }
+                }
+            }
+            Axiom::Collection(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.object_properties, axiom.property());
+                for item in axiom.items() {
+                    if let axioms::CollectionItem::Named(iri) = item {
+                        insert(&mut self.individuals, iri);
+                    }
+                }
+            }
+            Axiom::Container(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                insert(&mut self.object_properties, axiom.property());
+                for item in axiom.items() {
+                    if let axioms::ContainerItem::Named(iri) = item {
+                        insert(&mut self.individuals, iri);
+                    }
+                }
+            }
+            Axiom::Reification(axiom) => {
+                insert(&mut self.individuals, axiom.subject());
+                match axiom.object() {
+                    axioms::ReificationObject::Named(object) => {
+                        insert(&mut self.object_properties, axiom.predicate());
+                        insert(&mut self.individuals, object);
+                    }
+                    axioms::ReificationObject::Anonymous(_) => {
+                        insert(&mut self.object_properties, axiom.predicate());
+                    }
+                    axioms::ReificationObject::Literal(_) => {
+                        insert(&mut self.data_properties, axiom.predicate());
+                    }
+                }
+            }
+            // Annotations and imports carry no logical vocabulary
+            Axiom::AnnotationAssertion(_)
+            | Axiom::SubAnnotationPropertyOf(_)
+            | Axiom::AnnotationPropertyDomain(_)
+            | Axiom::AnnotationPropertyRange(_)
+            | Axiom::Import(_) => {}
+        }
+    }
+
+    /// Record the entities of a class expression and everything nested in it
+    fn add_class_expression(&mut self, expression: &ClassExpression) {
+        for sub in expression.collect_subexpressions() {
+            match sub {
+                ClassExpression::Class(class) => insert(&mut self.classes, class.iri()),
+                ClassExpression::ObjectOneOf(individuals) => {
+                    for individual in individuals.iter() {
+                        if let Some(iri) = individual.iri() {
+                            insert(&mut self.individuals, iri);
+                        }
+                    }
+                }
+                ClassExpression::ObjectHasValue(property, individual) => {
+                    insert(&mut self.object_properties, property.property_iri());
+                    if let Some(iri) = individual.iri() {
+                        insert(&mut self.individuals, iri);
+                    }
+                }
+                ClassExpression::ObjectSomeValuesFrom(property, _)
+                | ClassExpression::ObjectAllValuesFrom(property, _)
+                | ClassExpression::ObjectHasSelf(property)
+                | ClassExpression::ObjectMinCardinality(_, property)
+                | ClassExpression::ObjectMaxCardinality(_, property)
+                | ClassExpression::ObjectExactCardinality(_, property) => {
+                    insert(&mut self.object_properties, property.property_iri());
+                }
+                ClassExpression::DataSomeValuesFrom(property, range)
+                | ClassExpression::DataAllValuesFrom(property, range) => {
+                    insert(&mut self.data_properties, property.property_iri());
+                    let mut datatypes = Vec::new();
+                    range.collect_signature(&mut datatypes);
+                    insert_all(&mut self.datatypes, &datatypes);
+                }
+                ClassExpression::DataHasValue(property, _)
+                | ClassExpression::DataMinCardinality(_, property)
+                | ClassExpression::DataMaxCardinality(_, property)
+                | ClassExpression::DataExactCardinality(_, property) => {
+                    insert(&mut self.data_properties, property.property_iri());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn insert(set: &mut HashSet<Arc<IRI>>, iri: &IRI) {
+    if !set.contains(iri) {
+        set.insert(Arc::new(iri.clone()));
+    }
+}
+
+fn insert_all(set: &mut HashSet<Arc<IRI>>, iris: &[Arc<IRI>]) {
+    for iri in iris {
+        if !set.contains(&**iri) {
+            set.insert(iri.clone());
+        }
+    }
+}
+
+/// Number of axioms removed by [`Ontology::compact`] or [`Ontology::compact_entailed`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Axioms structurally identical to an earlier axiom
+    pub duplicates: usize,
+    /// Subclass and disjointness axioms entailed by the remaining axioms
+    pub entailed: usize,
+}
+
+impl CompactionReport {
+    /// Total number of axioms removed
+    pub fn removed(&self) -> usize {
+        self.duplicates + self.entailed
+    }
+}
+
+/// Outcome of [`Ontology::merge_anonymous_individuals`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnonymousMergeReport {
+    /// References to a blank node rewritten to its canonical spelling
+    pub relabeled: usize,
+    /// Anonymous individuals merged into another with the same assertions
+    pub merged: usize,
+    /// Assertions removed as identical to another after merging
+    pub duplicates: usize,
+}
+
 /// An OWL2 ontology with indexed storage and performance optimizations
 ///
 /// Represents a complete OWL2 ontology containing entities, axioms, and annotations.
@@ -60,8 +625,7 @@ use std::sync::Arc;
 /// ```text
 /// Ontology {
 ///     // Basic ontology information
-///     iri: Option<Arc<IRI>>,
-///     version_iri: Option<Arc<IRI>>,
+///     header: OntologyHeader,
 ///     imports: HashSet<Arc<IRI>>,
 ///     
 ///     // Entity storage (Arc-based sharing)
@@ -82,8 +646,8 @@ use std::sync::Arc;
 ///     property_ranges: HashMap<IRI, Vec<IRI>>,
 ///     
 ///     // Additional features
-///     annotations: Vec<Annotation>,
 ///     iri_registry: IRIRegistry,
+///     class_expression_cache: Option<ClassExpressionCache>,
 /// }
 /// ```
 ///
@@ -139,14 +703,16 @@ use std::sync::Arc;
 /// ```
 #[derive(Debug, Clone)]
 pub struct Ontology {
-    /// The ontology IRI
-    iri: Option<Arc<IRI>>,
-    /// The version IRI
-    version_iri: Option<Arc<IRI>>,
+    /// The ontology IRI, version IRI and ontology annotations
+    header: OntologyHeader,
     /// Import declarations
     imports: HashSet<Arc<IRI>>,
+    /// Imported ontologies kept apart rather than merged
+    imported_ontologies: Vec<Arc<Ontology>>,
     /// All classes in the ontology
     classes: HashSet<Arc<Class>>,
+    /// IRIs of `classes`, for constant-time duplicate checks
+    class_iris: HashSet<Arc<IRI>>,
     /// All object properties in the ontology
     object_properties: HashSet<Arc<ObjectProperty>>,
     /// All data properties in the ontology
@@ -212,7 +778,6 @@ pub struct Ontology {
 
     // Multi-indexed axiom storage for fast queries
     /// Index axioms by their signature (main entities involved)
-    #[allow(dead_code)]
     axiom_signature_index: HashMap<IRI, Vec<Arc<axioms::Axiom>>>,
     /// Index class axioms by class IRI for O(1) lookup
     #[allow(dead_code)]
@@ -229,20 +794,25 @@ pub struct Ontology {
     #[allow(dead_code)]
     annotation_property_index: HashMap<IRI, Vec<Arc<axioms::AnnotationAssertionAxiom>>>,
 
-    /// Annotations on the ontology itself
-    annotations: Vec<Annotation>,
     /// IRI registry for managing namespaces
     iri_registry: IRIRegistry,
+    /// Shared class expression subtrees, when interning is enabled
+    class_expression_cache: Option<axioms::ClassExpressionCache>,
+    /// Check every added axiom must pass
+    insertion_validator: Option<InsertionValidator>,
+    /// Bounds on the number of axioms and entities
+    size_limits: SizeLimits,
 }
 
 impl Ontology {
     /// Create a new empty ontology
     pub fn new() -> Self {
         Ontology {
-            iri: None,
-            version_iri: None,
+            header: OntologyHeader::default(),
             imports: HashSet::new(),
+            imported_ontologies: Vec::new(),
             classes: HashSet::new(),
+            class_iris: HashSet::new(),
             object_properties: HashSet::new(),
             data_properties: HashSet::new(),
             named_individuals: HashSet::new(),
@@ -299,36 +869,48 @@ impl Ontology {
             individual_axioms_index: HashMap::new(),
             axiom_type_index: HashMap::new(),
             annotation_property_index: HashMap::new(),
-            annotations: Vec::new(),
             iri_registry: IRIRegistry::new(),
+            class_expression_cache: None,
+            insertion_validator: None,
+            size_limits: SizeLimits::default(),
         }
     }
 
     /// Create a new ontology with the given IRI
     pub fn with_iri<I: Into<IRI>>(iri: I) -> Self {
         let mut ontology = Self::new();
-        ontology.iri = Some(Arc::new(iri.into()));
+        ontology.set_iri(iri);
         ontology
     }
 
+    /// Get the ontology header
+    pub fn header(&self) -> &OntologyHeader {
+        &self.header
+    }
+
+    /// Get a mutable reference to the ontology header
+    pub fn header_mut(&mut self) -> &mut OntologyHeader {
+        &mut self.header
+    }
+
     /// Get the ontology IRI
     pub fn iri(&self) -> Option<&IRI> {
-        self.iri.as_deref()
+        self.header.ontology_iri.as_deref()
     }
 
     /// Get the version IRI
     pub fn version_iri(&self) -> Option<&IRI> {
-        self.version_iri.as_deref()
+        self.header.version_iri.as_deref()
     }
 
     /// Set the ontology IRI
     pub fn set_iri<I: Into<IRI>>(&mut self, iri: I) {
-        self.iri = Some(Arc::new(iri.into()));
+        self.header.ontology_iri = Some(Arc::new(iri.into()));
     }
 
     /// Set the version IRI
     pub fn set_version_iri<I: Into<IRI>>(&mut self, version_iri: I) {
-        self.version_iri = Some(Arc::new(version_iri.into()));
+        self.header.version_iri = Some(Arc::new(version_iri.into()));
     }
 
     /// Add an import declaration
@@ -341,23 +923,79 @@ impl Ontology {
         &self.imports
     }
 
+    /// Get the imported ontologies kept apart from this one
+    ///
+    /// These are filled in by [`resolve_imports_lazily`](Self::resolve_imports_lazily)
+    /// and consulted by the reasoner only as far as a query needs them.
+    pub fn imported_ontologies(&self) -> &[Arc<Ontology>] {
+        &self.imported_ontologies
+    }
+
+    /// Keep an imported ontology apart from this one, for lazy reasoning
+    pub fn add_imported_ontology(&mut self, ontology: Arc<Ontology>) {
+        self.imported_ontologies.push(ontology);
+    }
+
+    /// Get the import axioms, which carry the annotations on each import
+    ///
+    /// Imports added with [`add_import`](Self::add_import) have no axiom.
+    pub fn import_axioms(&self) -> Vec<&axioms::ImportAxiom> {
+        self.axioms
+            .iter()
+            .filter_map(|axiom| match axiom.as_ref() {
+                axioms::Axiom::Import(import) => Some(import),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Bound the number of axioms and entities the ontology may hold
+    ///
+    /// Adding an axiom or a new entity beyond a limit fails with
+    /// [`OwlError::ResourceLimitExceeded`] and leaves the ontology unchanged.
+    /// Parsers set these from `ParserConfig::max_axioms` and
+    /// `ParserConfig::max_entities` so that small inputs which expand into
+    /// huge ontologies are cut off while they are read.
+    pub fn set_size_limits(&mut self, limits: SizeLimits) {
+        self.size_limits = limits;
+    }
+
+    /// Get the bounds on the number of axioms and entities
+    pub fn size_limits(&self) -> SizeLimits {
+        self.size_limits
+    }
+
+    /// Fail if one more entity would exceed the entity limit
+    fn check_entity_limit(&self) -> OwlResult<()> {
+        match self.size_limits.max_entities {
+            Some(limit) if self.entity_count() >= limit => Err(OwlError::ResourceLimitExceeded {
+                resource_type: "entities".to_string(),
+                limit,
+                message: format!("ontology would exceed {} entities", limit),
+            }),
+            _ => Ok(()),
+        }
+    }
+
     /// Add a class to the ontology
-    pub fn add_class(&mut self, class: Class) -> OwlResult<()> {
+    pub fn add_class(&mut self, class: Class) -> OwlResult<Insertion> {
         // Validate class IRI
         self.validate_class_iri(class.iri())?;
 
         // Check for duplicate classes
-        if self.classes.iter().any(|c| c.iri() == class.iri()) {
+        if self.class_iris.contains(class.iri()) {
             // Gracefully accept duplicate additions (idempotent)
-            return Ok(());
+            return Ok(Insertion::AlreadyPresent);
         }
 
         // Validate class against OWL2 built-in classes
         self.validate_builtin_class_usage(class.iri())?;
+        self.check_entity_limit()?;
 
+        self.class_iris.insert(class.iri().clone());
         let class_arc = Arc::new(class);
         self.classes.insert(class_arc);
-        Ok(())
+        Ok(Insertion::Inserted)
     }
 
     /// Get all classes in the ontology
@@ -366,10 +1004,14 @@ impl Ontology {
     }
 
     /// Add an object property to the ontology
-    pub fn add_object_property(&mut self, property: ObjectProperty) -> OwlResult<()> {
+    pub fn add_object_property(&mut self, property: ObjectProperty) -> OwlResult<Insertion> {
         let property_arc = Arc::new(property);
-        self.object_properties.insert(property_arc);
-        Ok(())
+        if !self.object_properties.contains(&property_arc) {
+            self.check_entity_limit()?;
+        }
+        Ok(Insertion::from_inserted(
+            self.object_properties.insert(property_arc),
+        ))
     }
 
     /// Get all object properties in the ontology
@@ -378,10 +1020,14 @@ impl Ontology {
     }
 
     /// Add a data property to the ontology
-    pub fn add_data_property(&mut self, property: DataProperty) -> OwlResult<()> {
+    pub fn add_data_property(&mut self, property: DataProperty) -> OwlResult<Insertion> {
         let property_arc = Arc::new(property);
-        self.data_properties.insert(property_arc);
-        Ok(())
+        if !self.data_properties.contains(&property_arc) {
+            self.check_entity_limit()?;
+        }
+        Ok(Insertion::from_inserted(
+            self.data_properties.insert(property_arc),
+        ))
     }
 
     /// Get all data properties in the ontology
@@ -390,24 +1036,36 @@ impl Ontology {
     }
 
     /// Add a named individual to the ontology
-    pub fn add_named_individual(&mut self, individual: NamedIndividual) -> OwlResult<()> {
+    pub fn add_named_individual(&mut self, individual: NamedIndividual) -> OwlResult<Insertion> {
         let individual_arc = Arc::new(individual);
-        self.named_individuals.insert(individual_arc);
-        Ok(())
+        if !self.named_individuals.contains(&individual_arc) {
+            self.check_entity_limit()?;
+        }
+        Ok(Insertion::from_inserted(
+            self.named_individuals.insert(individual_arc),
+        ))
     }
 
     /// Add an anonymous individual to the ontology
-    pub fn add_anonymous_individual(&mut self, individual: AnonymousIndividual) -> OwlResult<()> {
+    pub fn add_anonymous_individual(
+        &mut self,
+        individual: AnonymousIndividual,
+    ) -> OwlResult<Insertion> {
         let individual_arc = Arc::new(individual);
-        self.anonymous_individuals.insert(individual_arc);
-        Ok(())
+        Ok(Insertion::from_inserted(
+            self.anonymous_individuals.insert(individual_arc),
+        ))
     }
 
     /// Add an annotation property to the ontology
-    pub fn add_annotation_property(&mut self, property: AnnotationProperty) -> OwlResult<()> {
+    pub fn add_annotation_property(
+        &mut self,
+        property: AnnotationProperty,
+    ) -> OwlResult<Insertion> {
         let property_arc = Arc::new(property);
-        self.annotation_properties.insert(property_arc);
-        Ok(())
+        Ok(Insertion::from_inserted(
+            self.annotation_properties.insert(property_arc),
+        ))
     }
 
     /// Get all named individuals in the ontology
@@ -425,8 +1083,59 @@ impl Ontology {
         &self.annotation_properties
     }
 
+    /// Run a check on each axiom before it is added
+    ///
+    /// An axiom the validator returns an error for is not added, and
+    /// [`add_axiom`](Self::add_axiom) and the `add_*_axiom` methods return that
+    /// error. Axioms already in the ontology are not checked. Replaces any
+    /// previous validator.
+    pub fn set_insertion_validator<F>(&mut self, validator: F)
+    where
+        F: Fn(&axioms::Axiom) -> OwlResult<()> + Send + Sync + 'static,
+    {
+        self.insertion_validator = Some(InsertionValidator(Arc::new(validator)));
+    }
+
+    /// Remove the insertion validator, returning it
+    pub fn clear_insertion_validator(&mut self) -> Option<InsertionValidator> {
+        self.insertion_validator.take()
+    }
+
+    /// Get the insertion validator
+    pub fn insertion_validator(&self) -> Option<&InsertionValidator> {
+        self.insertion_validator.as_ref()
+    }
+
     /// Add an axiom to the ontology
+    ///
+    /// Fails without changing the ontology if the
+    /// [insertion validator](Self::set_insertion_validator) rejects the axiom
+    /// or the ontology already holds its [maximum](Self::set_size_limits)
+    /// number of axioms.
     pub fn add_axiom(&mut self, axiom: axioms::Axiom) -> OwlResult<()> {
+        if let Some(validator) = &self.insertion_validator {
+            validator.check(&axiom)?;
+        }
+        if let Some(limit) = self.size_limits.max_axioms {
+            if self.axioms.len() >= limit {
+                return Err(OwlError::ResourceLimitExceeded {
+                    resource_type: "axioms".to_string(),
+                    limit,
+                    message: format!("ontology would exceed {} axioms", limit),
+                });
+            }
+        }
+        self.insert_axiom(axiom);
+        Ok(())
+    }
+
+    /// Store and index an axiom without consulting the insertion validator
+    fn insert_axiom(&mut self, mut axiom: axioms::Axiom) {
+        if let Some(cache) = self.class_expression_cache.as_mut() {
+            for expression in axiom.class_expressions_mut() {
+                cache.intern_operands(expression);
+            }
+        }
         let axiom_arc = Arc::new(axiom);
 
         // Add to general axioms list
@@ -663,8 +1372,6 @@ impl Ontology {
 
         // Update multi-indexes for fast queries
         self.update_multi_indexes(axiom_arc.clone());
-
-        Ok(())
     }
 
     /// Update multi-indexes for a new axiom
@@ -677,9 +1384,13 @@ impl Ontology {
             .or_default()
             .push(axiom.clone());
 
-        // Signature and entity indexing simplified for compilation
-        // In a full implementation, this would extract entity IRIs from axioms
-        // For now, we focus on the type-based indexing which provides most benefits
+        // Add to signature index
+        for iri in axiom.signature() {
+            self.axiom_signature_index
+                .entry((*iri).clone())
+                .or_default()
+                .push(axiom.clone());
+        }
     }
 
     /// Get all axioms in the ontology
@@ -705,6 +1416,476 @@ impl Ontology {
             .unwrap_or_default()
     }
 
+    /// Iterate over all axioms of the given type using the multi-index
+    pub fn axioms_of_type(
+        &self,
+        axiom_type: axioms::AxiomType,
+    ) -> impl Iterator<Item = &axioms::Axiom> {
+        self.axiom_type_index
+            .get(&axiom_type)
+            .into_iter()
+            .flat_map(|axioms| axioms.iter().map(|a| a.as_ref()))
+    }
+
+    /// Get the number of axioms of each type present in the ontology
+    pub fn axiom_type_counts(&self) -> HashMap<axioms::AxiomType, usize> {
+        self.axiom_type_index
+            .iter()
+            .filter(|(_, axioms)| !axioms.is_empty())
+            .map(|(axiom_type, axioms)| (*axiom_type, axioms.len()))
+            .collect()
+    }
+
+    /// Compute a content hash of the ontology's entities and axioms
+    ///
+    /// The hash is independent of insertion order, so it can be used to check
+    /// whether cached reasoning results still apply to this ontology. It is
+    /// 64-bit FNV-1a over a sorted listing of the entity IRIs and the axioms in
+    /// functional syntax, so saved hashes stay valid across Rust releases and
+    /// platforms.
+    pub fn content_hash(&self) -> u64 {
+        let mut entries: Vec<String> = self
+            .classes
+            .iter()
+            .map(|c| format!("C {}", c.iri()))
+            .chain(
+                self.object_properties
+                    .iter()
+                    .map(|p| format!("OP {}", p.iri())),
+            )
+            .chain(
+                self.data_properties
+                    .iter()
+                    .map(|p| format!("DP {}", p.iri())),
+            )
+            .chain(
+                self.named_individuals
+                    .iter()
+                    .map(|i| format!("I {}", i.iri())),
+            )
+            .chain(self.imports.iter().map(|i| format!("IM {}", i)))
+            .chain(self.axioms.iter().map(|a| format!("A {}", a)))
+            .collect();
+        entries.sort_unstable();
+
+        let mut hash = Fnv1a::default();
+        hash.write(self.iri().map_or("", |iri| iri.as_str()));
+        for entry in &entries {
+            hash.write("\n");
+            hash.write(entry);
+        }
+        hash.0
+    }
+
+    /// Remove axioms that are structurally identical to an earlier axiom
+    ///
+    /// Duplicates are found by hashing, so this is cheap enough to run on every
+    /// load (see `ParserConfig::deduplicate_on_load`). Entities are untouched and
+    /// the first occurrence of each axiom is kept in its original position.
+    pub fn compact(&mut self) -> OwlResult<CompactionReport> {
+        self.compact_axioms(false)
+    }
+
+    /// Remove duplicate axioms and axioms entailed by the rest of the ontology
+    ///
+    /// On top of [`compact`](Self::compact), this drops subclass axioms between
+    /// named classes that follow from the remaining told subclass and
+    /// equivalence axioms, tautological subclass axioms, and disjointness axioms
+    /// whose every pair is covered by another disjointness axiom between
+    /// superclasses. Each axiom is checked against the axioms still kept, so
+    /// two axioms that entail each other are never both removed.
+    pub fn compact_entailed(&mut self) -> OwlResult<CompactionReport> {
+        self.compact_axioms(true)
+    }
+
+    /// Remove every axiom entailed by the rest of the ontology
+    ///
+    /// Unlike [`compact_entailed`](Self::compact_entailed), which only follows
+    /// told subclass links, each class axiom, domain, range and assertion is
+    /// checked by reasoning over the axioms still kept, so the result is a
+    /// minimal subset with the same models. Annotation axioms are always kept.
+    /// The lean axioms are a subset of the original ones, so the two are
+    /// logically equivalent exactly when the lean ontology entails every removed
+    /// axiom; this is checked before anything is changed. Returns the number of
+    /// axioms removed, and fails on an inconsistent ontology.
+    pub fn lean(&mut self) -> OwlResult<usize> {
+        if !OwlReasoner::new(self.clone()).is_consistent()? {
+            return Err(OwlError::InconsistentOntology(
+                "An inconsistent ontology has no lean version".to_string(),
+            ));
+        }
+
+        let mut kept = self.axioms.clone();
+        let mut removed = Vec::new();
+        let mut index = 0;
+        while index < kept.len() {
+            if is_lean_candidate(&kept[index]) {
+                let mut rest = kept.clone();
+                let axiom = rest.remove(index);
+                let mut remainder = self.clone();
+                remainder.rebuild_axioms(rest.clone())?;
+                // A subset of a consistent ontology is consistent
+                if OwlReasoner::new(remainder).entails_if_consistent(&axiom)? {
+                    kept = rest;
+                    removed.push(axiom);
+                    continue;
+                }
+            }
+            index += 1;
+        }
+        if removed.is_empty() {
+            return Ok(0);
+        }
+
+        let mut lean = self.clone();
+        lean.rebuild_axioms(kept)?;
+        let mut reasoner = OwlReasoner::new(lean.clone());
+        for axiom in &removed {
+            if !reasoner.entails(axiom)? {
+                return Err(OwlError::ReasoningError(format!(
+                    "Lean ontology no longer entails removed axiom {:?}",
+                    axiom
+                )));
+            }
+        }
+
+        *self = lean;
+        Ok(removed.len())
+    }
+
+    /// Remove every occurrence of an axiom
+    ///
+    /// Entities stay declared even when no axiom mentions them any more.
+    /// Returns whether the axiom was present.
+    pub fn remove_axiom(&mut self, axiom: &axioms::Axiom) -> OwlResult<bool> {
+        let kept: Vec<Arc<axioms::Axiom>> = self
+            .axioms
+            .iter()
+            .filter(|kept| ***kept != *axiom)
+            .cloned()
+            .collect();
+        if kept.len() == self.axioms.len() {
+            return Ok(false);
+        }
+        self.rebuild_axioms(kept)?;
+        Ok(true)
+    }
+
+    /// Give each blank node of a parsed document a single anonymous individual
+    ///
+    /// The parsers spell blank node labels in several ways: an object `_:x`
+    /// becomes the anonymous individual `x` or `_:x`, and a subject the IRI
+    /// `_:x` or `http://blank.node/x`. Assertions are rewritten to refer to the
+    /// anonymous individual `x`, or the IRI `_:x` where an individual IRI is
+    /// needed (as in [`PropertyAssertionAxiom::object_node_iri`]), and the
+    /// anonymous individuals sharing a label become one.
+    ///
+    /// Blank nodes with the same assertions, up to the node itself, and the
+    /// same annotations are then merged, since each stands for the other.
+    /// Only blank nodes that occur in nothing but assertions are merged, and
+    /// assertions left identical are removed.
+    ///
+    /// [`PropertyAssertionAxiom::object_node_iri`]: axioms::PropertyAssertionAxiom::object_node_iri
+    pub fn merge_anonymous_individuals(&mut self) -> OwlResult<AnonymousMergeReport> {
+        let mut report = AnonymousMergeReport::default();
+
+        // Canonical spellings
+        let mut rewritten = Vec::with_capacity(self.axioms.len());
+        let mut opaque: HashSet<String> = HashSet::new();
+        for axiom in &self.axioms {
+            match relabel_blank_nodes(axiom, &|_| None)? {
+                Some((relabeled, changes)) => {
+                    report.relabeled += changes;
+                    rewritten.push(Arc::new(relabeled));
+                }
+                None => rewritten.push(axiom.clone()),
+            }
+            opaque.extend(opaque_blank_labels(axiom));
+        }
+
+        // Every label used by an assertion, with the annotations of its individuals
+        let mut annotations: std::collections::BTreeMap<String, Vec<axioms::Annotation>> =
+            std::collections::BTreeMap::new();
+        for individual in &self.anonymous_individuals {
+            let entry = annotations
+                .entry(anonymous_label(individual.node_id()).to_string())
+                .or_default();
+            for annotation in individual.annotations() {
+                if !entry.contains(annotation) {
+                    entry.push(annotation.clone());
+                }
+            }
+        }
+        for axiom in &rewritten {
+            for label in assertion_blank_labels(axiom) {
+                annotations.entry(label).or_default();
+            }
+        }
+
+        // Blank nodes described alike, by their assertions with the node left out
+        let mut representatives: HashMap<Vec<String>, String> = HashMap::new();
+        let mut merged: HashMap<String, String> = HashMap::new();
+        for (label, node_annotations) in &annotations {
+            if opaque.contains(label) {
+                continue;
+            }
+            let placeholder = |candidate: &str| (candidate == label).then(|| "*".to_string());
+            let mut description: Vec<String> = node_annotations
+                .iter()
+                .map(|annotation| format!("N {:?}", annotation))
+                .collect();
+            for axiom in &rewritten {
+                if let Some((described, changes)) = relabel_blank_nodes(axiom, &placeholder)? {
+                    if changes > 0 {
+                        description.push(format!("A {:?}", described));
+                    }
+                }
+            }
+            description.sort_unstable();
+            match representatives.get(&description) {
+                Some(representative) => {
+                    merged.insert(label.clone(), representative.clone());
+                }
+                None => {
+                    representatives.insert(description, label.clone());
+                }
+            }
+        }
+        report.merged = merged.len();
+
+        let mut seen = HashSet::with_capacity(rewritten.len());
+        let mut kept = Vec::with_capacity(rewritten.len());
+        for axiom in rewritten {
+            let axiom = match relabel_blank_nodes(&axiom, &|label| merged.get(label).cloned())? {
+                Some((relabeled, changes)) if changes > 0 => Arc::new(relabeled),
+                _ => axiom,
+            };
+            if seen.insert(axiom.clone()) {
+                kept.push(axiom);
+            } else {
+                report.duplicates += 1;
+            }
+        }
+
+        self.rebuild_axioms(kept)?;
+        self.named_individuals
+            .retain(|individual| blank_node_label(individual.iri().as_str()).is_none());
+        self.anonymous_individuals = annotations
+            .into_iter()
+            .filter(|(label, _)| !merged.contains_key(label))
+            .map(|(label, node_annotations)| {
+                let mut individual = AnonymousIndividual::new(label);
+                for annotation in node_annotations {
+                    individual.add_annotation(annotation);
+                }
+                Arc::new(individual)
+            })
+            .collect();
+        Ok(report)
+    }
+
+    fn compact_axioms(&mut self, remove_entailed: bool) -> OwlResult<CompactionReport> {
+        let mut seen = HashSet::with_capacity(self.axioms.len());
+        let mut kept: Vec<Arc<axioms::Axiom>> = self
+            .axioms
+            .iter()
+            .filter(|&axiom| seen.insert(&**axiom))
+            .cloned()
+            .collect();
+
+        let mut report = CompactionReport {
+            duplicates: self.axioms.len() - kept.len(),
+            entailed: 0,
+        };
+        if remove_entailed {
+            let before = kept.len();
+            kept = remove_entailed_axioms(kept);
+            report.entailed = before - kept.len();
+        }
+
+        if report.removed() > 0 {
+            self.rebuild_axioms(kept)?;
+        }
+        Ok(report)
+    }
+
+    /// Replace all axioms, rebuilding the typed storage and indexes
+    fn rebuild_axioms(&mut self, axioms: Vec<Arc<axioms::Axiom>>) -> OwlResult<()> {
+        let mut rebuilt = Ontology::new();
+        rebuilt.header = std::mem::take(&mut self.header);
+        rebuilt.imports = std::mem::take(&mut self.imports);
+        rebuilt.imported_ontologies = std::mem::take(&mut self.imported_ontologies);
+        rebuilt.classes = std::mem::take(&mut self.classes);
+        rebuilt.class_iris = std::mem::take(&mut self.class_iris);
+        rebuilt.object_properties = std::mem::take(&mut self.object_properties);
+        rebuilt.data_properties = std::mem::take(&mut self.data_properties);
+        rebuilt.named_individuals = std::mem::take(&mut self.named_individuals);
+        rebuilt.anonymous_individuals = std::mem::take(&mut self.anonymous_individuals);
+        rebuilt.annotation_properties = std::mem::take(&mut self.annotation_properties);
+        rebuilt.iri_registry = std::mem::take(&mut self.iri_registry);
+        rebuilt.class_expression_cache = self.class_expression_cache.take();
+        rebuilt.size_limits = self.size_limits;
+
+        for axiom in axioms {
+            rebuilt.add_axiom((*axiom).clone())?;
+        }
+        // Installed after the axioms were re-added, which it already accepted
+        rebuilt.insertion_validator = self.insertion_validator.take();
+
+        *self = rebuilt;
+        // The dropped axioms may have held the last use of some expressions
+        if let Some(cache) = self.class_expression_cache.as_mut() {
+            cache.evict_unused();
+        }
+        Ok(())
+    }
+
+    /// Split the ontology into its schema (TBox) and its data (ABox)
+    ///
+    /// Axioms for which [`Axiom::is_assertional`](axioms::Axiom::is_assertional)
+    /// holds go into the second ontology and all others into the first, so
+    /// together the two hold every axiom exactly once. Both keep the header,
+    /// the imports and every entity declaration, letting the TBox be
+    /// classified once while ABoxes are reasoned over against it.
+    pub fn split_tbox_abox(&self) -> (Ontology, Ontology) {
+        let mut tbox = Ontology::new();
+        tbox.header = self.header.clone();
+        tbox.imports = self.imports.clone();
+        tbox.imported_ontologies = self.imported_ontologies.clone();
+        tbox.classes = self.classes.clone();
+        tbox.class_iris = self.class_iris.clone();
+        tbox.object_properties = self.object_properties.clone();
+        tbox.data_properties = self.data_properties.clone();
+        tbox.named_individuals = self.named_individuals.clone();
+        tbox.anonymous_individuals = self.anonymous_individuals.clone();
+        tbox.annotation_properties = self.annotation_properties.clone();
+        tbox.iri_registry = self.iri_registry.clone();
+        // Each half interns only the expressions of its own axioms
+        tbox.class_expression_cache = self
+            .class_expression_cache
+            .as_ref()
+            .map(|_| axioms::ClassExpressionCache::new());
+        let mut abox = tbox.clone();
+
+        for axiom in &self.axioms {
+            if axiom.is_assertional() {
+                abox.insert_axiom((**axiom).clone());
+            } else {
+                tbox.insert_axiom((**axiom).clone());
+            }
+        }
+        (tbox, abox)
+    }
+
+    /// Get all axioms whose signature contains the given entity IRI
+    pub fn axioms_referencing(&self, iri: &IRI) -> Vec<&axioms::Axiom> {
+        self.axiom_signature_index
+            .get(iri)
+            .map(|axioms| axioms.iter().map(|a| a.as_ref()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Check whether any axiom references the given entity IRI
+    pub fn is_referenced(&self, iri: &IRI) -> bool {
+        self.axiom_signature_index
+            .get(iri)
+            .is_some_and(|axioms| !axioms.is_empty())
+    }
+
+    /// Report which kinds of entity an IRI is declared or used as
+    ///
+    /// Kinds come from the declarations and from the positions the IRI takes in
+    /// the axioms referencing it. Use [`PunKinds::is_punned`] to tell whether
+    /// the IRI is punned.
+    pub fn is_punned(&self, iri: &IRI) -> PunKinds {
+        let mut kinds = PunKinds {
+            class: self.class_iris.contains(iri),
+            object_property: self.object_properties.iter().any(|e| **e.iri() == *iri),
+            data_property: self.data_properties.iter().any(|e| **e.iri() == *iri),
+            annotation_property: self.annotation_properties.iter().any(|e| **e.iri() == *iri),
+            individual: self.named_individuals.iter().any(|e| **e.iri() == *iri),
+        };
+
+        for axiom in self.axioms_referencing(iri) {
+            match axiom {
+                axioms::Axiom::SubClassOf(axiom) => {
+                    kinds.add_class_expression(axiom.sub_class(), iri);
+                    kinds.add_class_expression(axiom.super_class(), iri);
+                }
+                axioms::Axiom::EquivalentClasses(axiom) => {
+                    for expression in axiom.class_expressions() {
+                        kinds.add_class_expression(expression, iri);
+                    }
+                }
+                axioms::Axiom::DisjointClasses(_) => kinds.class = true,
+                axioms::Axiom::ClassAssertion(axiom) => {
+                    kinds.individual |= **axiom.individual() == *iri;
+                    kinds.add_class_expression(axiom.class_expr(), iri);
+                }
+                axioms::Axiom::PropertyAssertion(axiom) => {
+                    kinds.object_property |= **axiom.property() == *iri;
+                    kinds.individual |= **axiom.subject() == *iri
+                        || axiom.object_iri().is_some_and(|object| **object == *iri);
+                }
+                axioms::Axiom::DataPropertyAssertion(axiom) => {
+                    kinds.data_property |= **axiom.property() == *iri;
+                    kinds.individual |= **axiom.subject() == *iri;
+                }
+                axioms::Axiom::SameIndividual(_) | axioms::Axiom::DifferentIndividuals(_) => {
+                    kinds.individual = true
+                }
+                axioms::Axiom::SubObjectProperty(_)
+                | axioms::Axiom::EquivalentObjectProperties(_)
+                | axioms::Axiom::DisjointObjectProperties(_)
+                | axioms::Axiom::FunctionalProperty(_)
+                | axioms::Axiom::InverseFunctionalProperty(_)
+                | axioms::Axiom::ReflexiveProperty(_)
+                | axioms::Axiom::IrreflexiveProperty(_)
+                | axioms::Axiom::SymmetricProperty(_)
+                | axioms::Axiom::AsymmetricProperty(_)
+                | axioms::Axiom::TransitiveProperty(_)
+                | axioms::Axiom::SubPropertyChainOf(_)
+                | axioms::Axiom::InverseObjectProperties(_) => kinds.object_property = true,
+                axioms::Axiom::SubDataProperty(_)
+                | axioms::Axiom::EquivalentDataProperties(_)
+                | axioms::Axiom::DisjointDataProperties(_)
+                | axioms::Axiom::FunctionalDataProperty(_) => kinds.data_property = true,
+                axioms::Axiom::ObjectPropertyDomain(axiom) => {
+                    kinds.object_property |= axiom.property() == iri;
+                    kinds.add_class_expression(axiom.domain(), iri);
+                }
+                axioms::Axiom::ObjectPropertyRange(axiom) => {
+                    kinds.object_property |= axiom.property() == iri;
+                    kinds.add_class_expression(axiom.range(), iri);
+                }
+                axioms::Axiom::DataPropertyDomain(axiom) => {
+                    kinds.data_property |= axiom.property() == iri;
+                    kinds.add_class_expression(axiom.domain(), iri);
+                }
+                axioms::Axiom::AnnotationAssertion(axiom) => {
+                    kinds.annotation_property |= **axiom.annotation_property() == *iri;
+                }
+                _ => {}
+            }
+        }
+
+        kinds
+    }
+
+    /// Compute the entities the axioms use, by kind
+    ///
+    /// This is the union of the signatures of all logical axioms, so entities
+    /// that are declared but never used are left out, while entities used
+    /// without a declaration, for instance only inside a nested restriction,
+    /// are included.
+    pub fn used_signature(&self) -> Signature {
+        let mut signature = Signature::default();
+        for axiom in &self.axioms {
+            signature.add_axiom(axiom, self);
+        }
+        signature
+    }
+
     /// Get class axioms involving a specific class IRI (placeholder implementation)
     pub fn class_axioms_for_class(&self, _class_iri: &IRI) -> Vec<&axioms::Axiom> {
         // Simplified implementation - would use class_axioms_index in full version
@@ -794,12 +1975,12 @@ impl Ontology {
 
     /// Add an annotation to the ontology
     pub fn add_annotation(&mut self, annotation: Annotation) {
-        self.annotations.push(annotation);
+        self.header.annotations.push(annotation);
     }
 
     /// Get all annotations on the ontology
     pub fn annotations(&self) -> &[Annotation] {
-        &self.annotations
+        &self.header.annotations
     }
 
     /// Get a mutable reference to the IRI registry
@@ -812,6 +1993,26 @@ impl Ontology {
         &self.iri_registry
     }
 
+    /// Enable or disable interning of class expressions in added axioms
+    ///
+    /// While enabled, identical nested class expressions of the axioms added
+    /// from then on share one allocation. Parsers enable it by default (see
+    /// `ParserConfig::intern_class_expressions`).
+    pub fn set_class_expression_interning(&mut self, enabled: bool) {
+        match (enabled, &self.class_expression_cache) {
+            (true, None) => self.class_expression_cache = Some(axioms::ClassExpressionCache::new()),
+            (false, Some(_)) => self.class_expression_cache = None,
+            _ => {}
+        }
+    }
+
+    /// Number of distinct class expressions interned so far
+    pub fn interned_class_expression_count(&self) -> usize {
+        self.class_expression_cache
+            .as_ref()
+            .map_or(0, |cache| cache.len())
+    }
+
     /// Create or get an IRI using the registry
     pub fn get_or_create_iri(&mut self, iri_str: &str) -> OwlResult<IRI> {
         self.iri_registry.get_or_create_iri(iri_str)
@@ -894,6 +2095,36 @@ impl Ontology {
             .collect()
     }
 
+    /// The properties equivalent to `property`, including itself
+    ///
+    /// Follows equivalent object and data property axioms transitively, so
+    /// the result is the property's equivalence class.
+    pub fn equivalent_properties(&self, property: &IRI) -> HashSet<IRI> {
+        let groups: Vec<Vec<&IRI>> = self
+            .equivalent_object_properties_axioms
+            .iter()
+            .map(|axiom| axiom.properties().iter().map(|p| &**p).collect())
+            .chain(
+                self.equivalent_data_properties_axioms
+                    .iter()
+                    .map(|axiom| axiom.properties().iter().map(|p| &**p).collect()),
+            )
+            .collect();
+
+        let mut class = HashSet::from([property.clone()]);
+        let mut pending = vec![property.clone()];
+        while let Some(current) = pending.pop() {
+            for group in groups.iter().filter(|group| group.contains(&&current)) {
+                for &other in group {
+                    if class.insert(other.clone()) {
+                        pending.push(other.clone());
+                    }
+                }
+            }
+        }
+        class
+    }
+
     /// Get all disjoint object properties axioms
     pub fn disjoint_object_properties_axioms(
         &self,
@@ -962,6 +2193,55 @@ impl Ontology {
             .collect()
     }
 
+    /// Get every characteristic declared for an object property
+    ///
+    /// Aggregates the functional, inverse functional, transitive, symmetric,
+    /// asymmetric, reflexive and irreflexive property axioms naming `property`
+    /// with the characteristics of its declared entity.
+    pub fn property_characteristics(&self, property: &IRI) -> PropertyCharacteristics {
+        let mut characteristics = PropertyCharacteristics::default();
+        if let Some(entity) = self
+            .object_properties
+            .iter()
+            .find(|entity| **entity.iri() == *property)
+        {
+            for characteristic in entity.characteristics() {
+                characteristics.set(*characteristic);
+            }
+        }
+
+        let named = |iri: &Arc<IRI>| **iri == *property;
+        characteristics.functional |= self
+            .functional_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.inverse_functional |= self
+            .inverse_functional_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.transitive |= self
+            .transitive_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.symmetric |= self
+            .symmetric_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.asymmetric |= self
+            .asymmetric_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.reflexive |= self
+            .reflexive_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics.irreflexive |= self
+            .irreflexive_property_axioms
+            .iter()
+            .any(|axiom| named(axiom.property()));
+        characteristics
+    }
+
     /// Get all subdata property axioms
     pub fn subdata_property_axioms(&self) -> Vec<&crate::axioms::SubDataPropertyAxiom> {
         self.subdata_property_axioms
@@ -1275,6 +2555,20 @@ impl Ontology {
         Ok(())
     }
 
+    /// Check whether this ontology falls within an OWL2 profile
+    pub fn is_in_profile(&self, profile: Owl2Profile) -> OwlResult<bool> {
+        Ok(self.profile_violations(profile)?.is_empty())
+    }
+
+    /// Get the constructs of this ontology that take it outside an OWL2 profile
+    ///
+    /// Delegates to [`Owl2ProfileValidator`]; an empty list means the
+    /// ontology is in the profile.
+    pub fn profile_violations(&self, profile: Owl2Profile) -> OwlResult<Vec<ProfileViolation>> {
+        let mut validator = Owl2ProfileValidator::new(Arc::new(self.clone()))?;
+        Ok(validator.validate_profile(profile)?.violations)
+    }
+
     /// Validate cardinality constraints
     pub fn validate_cardinality_constraints(&self) -> OwlResult<Vec<OwlError>> {
         let mut errors = Vec::new();
@@ -1444,12 +2738,12 @@ impl Ontology {
     /// 2. Calls the resolver to process all imports declared in this ontology
     /// 3. Recursively resolves imports in imported ontologies
     /// 4. Merges all imported entities and axioms into this ontology
+    /// 5. Returns the import cycles found, which are legal and do not stop resolution
     ///
     /// ## Error Handling
     ///
     /// Returns an error if:
     /// - Import resolution fails (network issues, file not found, etc.)
-    /// - Circular import dependencies are detected
     /// - Maximum import depth is exceeded
     /// - Imported ontologies contain invalid OWL2 constructs
     ///
@@ -1465,15 +2759,344 @@ impl Ontology {
     /// ontology.resolve_imports()?;
     /// # Ok::<(), owl2_reasoner::OwlError>(())
     /// ```
-    pub fn resolve_imports(&mut self) -> OwlResult<()> {
+    pub fn resolve_imports(&mut self) -> OwlResult<Vec<ImportCycle>> {
         // Create an ImportResolver with default configuration
         let mut resolver = ImportResolver::new()?;
 
         // Resolve all imports for this ontology
-        resolver.resolve_imports(self)?;
+        resolver.resolve_imports(self)
+    }
 
-        Ok(())
+    /// Resolve imports without merging them into this ontology
+    ///
+    /// Every ontology in the import closure is loaded and kept behind an `Arc`
+    /// in [`imported_ontologies`](Self::imported_ontologies). The reasoner then
+    /// inlines only the imported axioms connected to the entities it reasons
+    /// about, so large imported vocabularies are never copied in full.
+    pub fn resolve_imports_lazily(&mut self) -> OwlResult<()> {
+        let mut resolver = ImportResolver::new()?;
+        resolver.resolve_imports_lazily(self)
+    }
+}
+
+/// 64-bit FNV-1a, a fixed hash function whose values can be persisted
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, text: &str) {
+        for byte in text.bytes() {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Blank node label of an individual IRI, in any spelling the parsers use
+fn blank_node_label(iri: &str) -> Option<&str> {
+    iri.strip_prefix(crate::constants::BLANK_NODE_PREFIX)
+        .or_else(|| iri.strip_prefix(crate::constants::TURTLE_BLANK_NODE_NAMESPACE))
+}
+
+/// Blank node label of an anonymous individual
+fn anonymous_label(node_id: &str) -> &str {
+    node_id
+        .strip_prefix(crate::constants::BLANK_NODE_PREFIX)
+        .unwrap_or(node_id)
+}
+
+/// Labels of the blank nodes an assertion refers to
+fn assertion_blank_labels(axiom: &axioms::Axiom) -> Vec<String> {
+    let labels = std::cell::RefCell::new(Vec::new());
+    // Relabeling reports every blank node it visits
+    let _ = relabel_blank_nodes(axiom, &|label| {
+        labels.borrow_mut().push(label.to_string());
+        None
+    });
+    labels.into_inner()
+}
+
+/// Labels of the blank nodes an axiom refers to other than as an assertion's individual
+///
+/// These are the blank nodes of class expressions, annotations and axioms
+/// other than assertions, which [`relabel_blank_nodes`] leaves untouched.
+fn opaque_blank_labels(axiom: &axioms::Axiom) -> Vec<String> {
+    let mut labels: Vec<String> = Vec::new();
+    for expression in axiom.class_expressions() {
+        for subexpression in expression.collect_subexpressions() {
+            let individuals: Vec<&Individual> = match subexpression {
+                ClassExpression::ObjectHasValue(_, individual) => vec![individual],
+                ClassExpression::ObjectOneOf(individuals) => individuals.iter().collect(),
+                _ => Vec::new(),
+            };
+            labels.extend(
+                individuals
+                    .into_iter()
+                    .filter_map(Individual::node_id)
+                    .map(|node_id| anonymous_label(node_id).to_string()),
+            );
+        }
+    }
+    if let axioms::Axiom::AnnotationAssertion(assertion) = axiom {
+        if let AnnotationValue::AnonymousIndividual(node_id) = assertion.value() {
+            labels.push(anonymous_label(node_id).to_string());
+        }
+    }
+    if !is_assertion(axiom) {
+        labels.extend(
+            axiom
+                .signature()
+                .iter()
+                .filter_map(|iri| blank_node_label(iri.as_str()))
+                .map(str::to_string),
+        );
+    }
+    labels
+}
+
+fn is_assertion(axiom: &axioms::Axiom) -> bool {
+    matches!(
+        axiom,
+        axioms::Axiom::ClassAssertion(_)
+            | axioms::Axiom::PropertyAssertion(_)
+            | axioms::Axiom::DataPropertyAssertion(_)
+            | axioms::Axiom::NegativeObjectPropertyAssertion(_)
+            | axioms::Axiom::NegativeDataPropertyAssertion(_)
+            | axioms::Axiom::SameIndividual(_)
+            | axioms::Axiom::DifferentIndividuals(_)
+    )
+}
+
+/// Rewrite the blank node individuals of an assertion in their canonical spelling
+///
+/// `relabel` maps a label to a new label, or to `None` to keep it. Returns
+/// the rewritten assertion with the number of changed references, or `None`
+/// for axioms other than assertions.
+fn relabel_blank_nodes(
+    axiom: &axioms::Axiom,
+    relabel: &dyn Fn(&str) -> Option<String>,
+) -> OwlResult<Option<(axioms::Axiom, usize)>> {
+    use axioms::*;
+
+    let changes = std::cell::Cell::new(0);
+    let iri = |iri: &IRI| -> OwlResult<IRI> {
+        let Some(label) = blank_node_label(iri.as_str()) else {
+            return Ok(iri.clone());
+        };
+        let label = relabel(label).unwrap_or_else(|| label.to_string());
+        let canonical = format!("{}{}", crate::constants::BLANK_NODE_PREFIX, label);
+        if canonical == iri.as_str() {
+            return Ok(iri.clone());
+        }
+        changes.set(changes.get() + 1);
+        IRI::new(canonical)
+    };
+    let shared = |shared: &Arc<IRI>| -> OwlResult<Arc<IRI>> {
+        let renamed = iri(shared)?;
+        Ok(if renamed == **shared {
+            shared.clone()
+        } else {
+            Arc::new(renamed)
+        })
+    };
+    let all = |individuals: &[Arc<IRI>]| -> OwlResult<Vec<Arc<IRI>>> {
+        individuals.iter().map(shared).collect()
+    };
+
+    let relabeled = match axiom {
+        Axiom::ClassAssertion(ax) => Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+            shared(ax.individual())?,
+            ax.class_expr().clone(),
+        ))),
+        Axiom::PropertyAssertion(ax) => {
+            let object = match ax.object() {
+                PropertyAssertionObject::Named(object) => {
+                    PropertyAssertionObject::Named(shared(object)?)
+                }
+                PropertyAssertionObject::Anonymous(object) => {
+                    let label = anonymous_label(object.node_id());
+                    let label = relabel(label).unwrap_or_else(|| label.to_string());
+                    if label != object.node_id() {
+                        changes.set(changes.get() + 1);
+                    }
+                    let mut renamed = AnonymousIndividual::new(label);
+                    for annotation in object.annotations() {
+                        renamed.add_annotation(annotation.clone());
+                    }
+                    PropertyAssertionObject::Anonymous(Box::new(renamed))
+                }
+            };
+            Axiom::PropertyAssertion(Box::new(PropertyAssertionAxiom::new_with_object(
+                shared(ax.subject())?,
+                ax.property().clone(),
+                object,
+            )))
+        }
+        Axiom::DataPropertyAssertion(ax) => {
+            Axiom::DataPropertyAssertion(Box::new(DataPropertyAssertionAxiom::new(
+                shared(ax.subject())?,
+                ax.property().clone(),
+                ax.value().clone(),
+            )))
+        }
+        Axiom::NegativeObjectPropertyAssertion(ax) => Axiom::NegativeObjectPropertyAssertion(
+            Box::new(NegativeObjectPropertyAssertionAxiom::new(
+                iri(ax.subject())?,
+                ax.property().clone(),
+                iri(ax.object())?,
+            )),
+        ),
+        Axiom::NegativeDataPropertyAssertion(ax) => {
+            Axiom::NegativeDataPropertyAssertion(Box::new(NegativeDataPropertyAssertionAxiom::new(
+                iri(ax.subject())?,
+                ax.property().clone(),
+                ax.value().clone(),
+            )))
+        }
+        Axiom::SameIndividual(ax) => {
+            Axiom::SameIndividual(Box::new(SameIndividualAxiom::new(all(ax.individuals())?)))
+        }
+        Axiom::DifferentIndividuals(ax) => Axiom::DifferentIndividuals(Box::new(
+            DifferentIndividualsAxiom::new(all(ax.individuals())?),
+        )),
+        _ => return Ok(None),
+    };
+    Ok(Some((relabeled, changes.get())))
+}
+
+/// Whether [`Ontology::lean`] may remove an axiom, its entailment being decidable
+fn is_lean_candidate(axiom: &axioms::Axiom) -> bool {
+    match axiom {
+        axioms::Axiom::SubClassOf(_)
+        | axioms::Axiom::EquivalentClasses(_)
+        | axioms::Axiom::DisjointClasses(_)
+        | axioms::Axiom::PropertyAssertion(_)
+        | axioms::Axiom::DataPropertyAssertion(_)
+        | axioms::Axiom::ObjectPropertyDomain(_)
+        | axioms::Axiom::ObjectPropertyRange(_) => true,
+        axioms::Axiom::ClassAssertion(axiom) => axiom.class_expr().is_named(),
+        _ => false,
+    }
+}
+
+/// Drop subclass and disjointness axioms entailed by the other kept axioms
+fn remove_entailed_axioms(axioms: Vec<Arc<axioms::Axiom>>) -> Vec<Arc<axioms::Axiom>> {
+    // Told superclasses of each named class, tagged with the asserting axiom
+    let mut supers: HashMap<IRI, Vec<(usize, IRI)>> = HashMap::new();
+    for (index, axiom) in axioms.iter().enumerate() {
+        match axiom.as_ref() {
+            axioms::Axiom::SubClassOf(axiom) => {
+                if let (Some(sub), Some(sup)) =
+                    (axiom.sub_class().as_named(), axiom.super_class().as_named())
+                {
+                    supers
+                        .entry((**sub.iri()).clone())
+                        .or_default()
+                        .push((index, (**sup.iri()).clone()));
+                }
+            }
+            axioms::Axiom::EquivalentClasses(axiom) => {
+                for class in axiom.classes() {
+                    for other in axiom.classes().iter().filter(|other| *other != class) {
+                        supers
+                            .entry((**class).clone())
+                            .or_default()
+                            .push((index, (**other).clone()));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut removed = HashSet::new();
+    for (index, axiom) in axioms.iter().enumerate() {
+        let axioms::Axiom::SubClassOf(axiom) = axiom.as_ref() else {
+            continue;
+        };
+        let super_class = axiom.super_class();
+        let entailed = axiom.sub_class() == super_class
+            || super_class
+                .as_named()
+                .is_some_and(|class| class.iri().as_str() == OWL_THING)
+            || match (axiom.sub_class().as_named(), super_class.as_named()) {
+                (Some(sub), Some(sup)) => {
+                    told_ancestors(&supers, sub.iri(), &removed, Some(index)).contains(&**sup.iri())
+                }
+                _ => false,
+            };
+        if entailed {
+            removed.insert(index);
+        }
+    }
+
+    let disjoint: Vec<(usize, &[Arc<IRI>])> = axioms
+        .iter()
+        .enumerate()
+        .filter_map(|(index, axiom)| match axiom.as_ref() {
+            axioms::Axiom::DisjointClasses(axiom) => Some((index, axiom.classes().as_slice())),
+            _ => None,
+        })
+        .collect();
+    for &(index, classes) in &disjoint {
+        let ancestors: Vec<HashSet<IRI>> = classes
+            .iter()
+            .map(|class| told_ancestors(&supers, class, &removed, None))
+            .collect();
+        let covered = |first: usize, second: usize| {
+            disjoint.iter().any(|&(other, other_classes)| {
+                other != index
+                    && !removed.contains(&other)
+                    && other_classes.iter().any(|p| {
+                        ancestors[first].contains(&**p)
+                            && other_classes
+                                .iter()
+                                .any(|q| q != p && ancestors[second].contains(&**q))
+                    })
+            })
+        };
+        let entailed = classes.len() >= 2
+            && (0..classes.len()).all(|first| {
+                (first + 1..classes.len())
+                    .all(|second| classes[first] != classes[second] && covered(first, second))
+            });
+        if entailed {
+            removed.insert(index);
+        }
+    }
+
+    axioms
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| !removed.contains(index))
+        .map(|(_, axiom)| axiom)
+        .collect()
+}
+
+/// `class` and the named classes reachable from it through told superclass edges
+///
+/// Edges asserted by removed axioms or by `skip` are not followed.
+fn told_ancestors(
+    supers: &HashMap<IRI, Vec<(usize, IRI)>>,
+    class: &IRI,
+    removed: &HashSet<usize>,
+    skip: Option<usize>,
+) -> HashSet<IRI> {
+    let mut ancestors = HashSet::from([class.clone()]);
+    let mut queue = vec![class.clone()];
+    while let Some(current) = queue.pop() {
+        for (index, sup) in supers.get(&current).into_iter().flatten() {
+            if Some(*index) != skip && !removed.contains(index) && ancestors.insert(sup.clone()) {
+                queue.push(sup.clone());
+            }
+        }
     }
+    ancestors
 }
 
 impl Default for Ontology {
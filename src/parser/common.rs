@@ -1,8 +1,24 @@
 //! Common parsing utilities and helpers
 
+use crate::axioms::{
+    AnnotationAssertionAxiom, Axiom, ClassAssertionAxiom, ClassExpression,
+    DataPropertyAssertionAxiom, DataPropertyExpression, DataRange, DifferentIndividualsAxiom,
+    DisjointClassesAxiom, EquivalentClassesAxiom, ObjectPropertyDomainAxiom,
+    ObjectPropertyExpression, ObjectPropertyRangeAxiom, PropertyAssertionAxiom, ReificationAxiom,
+    ReificationObject, SubClassOfAxiom,
+};
+use crate::entities::{
+    AnnotationValue, AnonymousIndividual, Class, DataProperty, Entity, Individual, Literal,
+    NamedIndividual, ObjectProperty,
+};
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::parser::{MalformedLiteralPolicy, UnknownPredicatePolicy};
 use hashbrown::HashMap;
+use smallvec::SmallVec;
+use std::collections::HashSet;
+use std::sync::Arc;
 
 /// Common RDF/OWL vocabulary terms
 pub static RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
@@ -20,6 +36,30 @@ pub static OWL_EQUIVALENT_CLASS: &str = "http://www.w3.org/2002/07/owl#equivalen
 pub static OWL_DISJOINT_WITH: &str = "http://www.w3.org/2002/07/owl#disjointWith";
 pub static OWL_SAME_AS: &str = "http://www.w3.org/2002/07/owl#sameAs";
 pub static OWL_DIFFERENT_FROM: &str = "http://www.w3.org/2002/07/owl#differentFrom";
+pub static OWL_ALL_DISJOINT_CLASSES: &str = "http://www.w3.org/2002/07/owl#AllDisjointClasses";
+pub static OWL_ALL_DIFFERENT: &str = "http://www.w3.org/2002/07/owl#AllDifferent";
+pub static OWL_MEMBERS: &str = "http://www.w3.org/2002/07/owl#members";
+pub static OWL_DISTINCT_MEMBERS: &str = "http://www.w3.org/2002/07/owl#distinctMembers";
+pub static RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+pub static RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+pub static RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+pub static RDF_STATEMENT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#Statement";
+pub static RDF_SUBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#subject";
+pub static RDF_PREDICATE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate";
+pub static RDF_OBJECT: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#object";
+pub static OWL_RESTRICTION: &str = "http://www.w3.org/2002/07/owl#Restriction";
+pub static OWL_ON_PROPERTY: &str = "http://www.w3.org/2002/07/owl#onProperty";
+pub static OWL_HAS_SELF: &str = "http://www.w3.org/2002/07/owl#hasSelf";
+pub static OWL_SOME_VALUES_FROM: &str = "http://www.w3.org/2002/07/owl#someValuesFrom";
+pub static OWL_ALL_VALUES_FROM: &str = "http://www.w3.org/2002/07/owl#allValuesFrom";
+pub static OWL_HAS_VALUE: &str = "http://www.w3.org/2002/07/owl#hasValue";
+pub static OWL_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#cardinality";
+pub static OWL_MIN_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#minCardinality";
+pub static OWL_MAX_CARDINALITY: &str = "http://www.w3.org/2002/07/owl#maxCardinality";
+pub static OWL_INTERSECTION_OF: &str = "http://www.w3.org/2002/07/owl#intersectionOf";
+pub static OWL_UNION_OF: &str = "http://www.w3.org/2002/07/owl#unionOf";
+pub static OWL_COMPLEMENT_OF: &str = "http://www.w3.org/2002/07/owl#complementOf";
+pub static OWL_ONE_OF: &str = "http://www.w3.org/2002/07/owl#oneOf";
 
 /// Parse a literal value with optional datatype or language tag
 pub fn parse_literal(
@@ -40,6 +80,74 @@ pub fn parse_literal(
     }
 }
 
+/// Upper bounds on the length of single tokens
+///
+/// Tokenizers check the IRI or literal they are reading as it grows, so an
+/// over-long token is rejected before it is read in full. See
+/// [`ParserConfig::token_limits`](crate::parser::ParserConfig::token_limits).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenLimits {
+    /// Maximum length of an IRI in bytes, without its angle brackets (None = unlimited)
+    pub max_iri_length: Option<usize>,
+    /// Maximum length of a literal's lexical form in bytes (None = unlimited)
+    pub max_literal_length: Option<usize>,
+}
+
+impl TokenLimits {
+    /// The error message for an IRI read up to `length` bytes, if it is too long
+    pub fn iri_overflow(&self, length: usize) -> Option<String> {
+        Self::overflow("IRI", self.max_iri_length, length)
+    }
+
+    /// The error message for a literal read up to `length` bytes, if it is too long
+    pub fn literal_overflow(&self, length: usize) -> Option<String> {
+        Self::overflow("Literal", self.max_literal_length, length)
+    }
+
+    fn overflow(token: &str, limit: Option<usize>, length: usize) -> Option<String> {
+        limit
+            .filter(|&limit| length > limit)
+            .map(|limit| format!("{} exceeds the maximum length of {} bytes", token, limit))
+    }
+}
+
+/// Apply a [`MalformedLiteralPolicy`] to a parsed literal
+///
+/// `line` is the 1-based line the literal was read from and `text` that line,
+/// used to find the column of the literal for warnings and errors.
+pub fn check_literal(
+    literal: Literal,
+    policy: MalformedLiteralPolicy,
+    line: usize,
+    text: &str,
+) -> OwlResult<Literal> {
+    if policy == MalformedLiteralPolicy::Keep || !crate::datatypes::is_malformed_literal(&literal) {
+        return Ok(literal);
+    }
+
+    let column = text
+        .find(&format!("\"{}\"", literal.lexical_form()))
+        .map_or(1, |index| text[..index].chars().count() + 1);
+    let message = format!(
+        "Lexical form \"{}\" is not valid for datatype {}",
+        literal.lexical_form(),
+        literal.datatype()
+    );
+    match policy {
+        MalformedLiteralPolicy::Keep => Ok(literal),
+        MalformedLiteralPolicy::Warn => {
+            log::warn!("Line {}, column {}: {}", line, column, message);
+            Ok(literal)
+        }
+        MalformedLiteralPolicy::Error => Err(OwlError::ParseErrorWithLocation {
+            line,
+            column,
+            message,
+        }),
+        MalformedLiteralPolicy::Coerce => Ok(Literal::simple(literal.lexical_form())),
+    }
+}
+
 /// Parse a CURIE (Compact URI) like "owl:Class"
 pub fn parse_curie(curie: &str, prefixes: &HashMap<String, String>) -> OwlResult<IRI> {
     if let Some(colon_pos) = curie.find(':') {
@@ -263,3 +371,704 @@ pub fn get_namespace(iri: &str) -> &str {
         ""
     }
 }
+
+/// N-ary axioms encoded in RDF as a typed node with a member list
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NaryAxiomKind {
+    /// `owl:AllDisjointClasses` with `owl:members`
+    AllDisjointClasses,
+    /// `owl:AllDifferent` with `owl:members` or `owl:distinctMembers`
+    AllDifferent,
+}
+
+impl NaryAxiomKind {
+    /// Map an `rdf:type` object IRI to the n-ary axiom kind it introduces
+    pub fn from_type_iri(iri: &str) -> Option<Self> {
+        if iri == OWL_ALL_DISJOINT_CLASSES {
+            Some(NaryAxiomKind::AllDisjointClasses)
+        } else if iri == OWL_ALL_DIFFERENT {
+            Some(NaryAxiomKind::AllDifferent)
+        } else {
+            None
+        }
+    }
+}
+
+/// Check whether a predicate links an n-ary axiom node to its member list
+pub fn is_nary_members_predicate(iri: &str) -> bool {
+    iri == OWL_MEMBERS || iri == OWL_DISTINCT_MEMBERS
+}
+
+/// Collects `owl:AllDisjointClasses` / `owl:AllDifferent` nodes while triples are parsed
+///
+/// Nodes, member lists and `rdf:first`/`rdf:rest` cells may arrive in any order, so the
+/// axioms are only built once the whole document has been read.
+#[derive(Debug, Default)]
+pub struct NaryAxiomCollector {
+    kinds: HashMap<String, NaryAxiomKind>,
+    members: HashMap<String, Vec<IRI>>,
+    member_lists: HashMap<String, String>,
+    list_first: HashMap<String, IRI>,
+    list_rest: HashMap<String, String>,
+}
+
+impl NaryAxiomCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the type of an n-ary axiom node
+    pub fn record_kind(&mut self, node: &str, kind: NaryAxiomKind) {
+        self.kinds.insert(node.to_string(), kind);
+    }
+
+    /// Record members given inline, e.g. from a Turtle collection
+    pub fn record_members(&mut self, node: &str, members: Vec<IRI>) {
+        self.members.insert(node.to_string(), members);
+    }
+
+    /// Record the head of an `rdf:List` holding the members of a node
+    pub fn record_member_list(&mut self, node: &str, list_head: &str) {
+        self.member_lists
+            .insert(node.to_string(), list_head.to_string());
+    }
+
+    /// Record an `rdf:first` cell
+    pub fn record_list_first(&mut self, cell: &str, item: IRI) {
+        self.list_first.insert(cell.to_string(), item);
+    }
+
+    /// Record an `rdf:rest` cell
+    pub fn record_list_rest(&mut self, cell: &str, rest: &str) {
+        self.list_rest.insert(cell.to_string(), rest.to_string());
+    }
+
+    /// The `rdf:List` cells holding the members of the recorded n-ary axiom nodes
+    ///
+    /// These cells only encode the axioms, so a parser can leave them out of the ontology.
+    pub fn member_list_cells(&self) -> HashSet<String> {
+        let mut cells = HashSet::new();
+        for (node, head) in &self.member_lists {
+            if !self.kinds.contains_key(node) {
+                continue;
+            }
+            let mut current = head.as_str();
+            while self.list_first.contains_key(current) && cells.insert(current.to_string()) {
+                match self.list_rest.get(current) {
+                    Some(rest) => current = rest,
+                    None => break,
+                }
+            }
+        }
+        cells
+    }
+
+    /// Build the collected n-ary axioms and add them to the ontology
+    pub fn finish(mut self, ontology: &mut Ontology) -> OwlResult<()> {
+        let kinds = std::mem::take(&mut self.kinds);
+        let mut nodes: Vec<_> = kinds.into_iter().collect();
+        nodes.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (node, kind) in nodes {
+            let members = match self.members.remove(&node) {
+                Some(members) => members,
+                None => match self.member_lists.get(&node) {
+                    Some(head) => self.resolve_list(head)?,
+                    None => continue,
+                },
+            };
+            if members.len() < 2 {
+                continue;
+            }
+
+            let members: Vec<Arc<IRI>> = members.into_iter().map(Arc::new).collect();
+            let axiom = match kind {
+                NaryAxiomKind::AllDisjointClasses => {
+                    Axiom::DisjointClasses(Box::new(DisjointClassesAxiom::new(members)))
+                }
+                NaryAxiomKind::AllDifferent => {
+                    Axiom::DifferentIndividuals(Box::new(DifferentIndividualsAxiom::new(members)))
+                }
+            };
+            ontology.add_axiom(axiom)?;
+        }
+
+        Ok(())
+    }
+
+    /// Follow `rdf:rest` links from `head` to `rdf:nil`, collecting `rdf:first` items
+    fn resolve_list(&self, head: &str) -> OwlResult<Vec<IRI>> {
+        let mut items = Vec::new();
+        let mut current = head;
+
+        while current != RDF_NIL {
+            if items.len() > self.list_first.len() {
+                return Err(OwlError::ParseError(format!(
+                    "Cyclic rdf:List starting at {head}"
+                )));
+            }
+            let Some(item) = self.list_first.get(current) else {
+                return Err(OwlError::ParseError(format!(
+                    "rdf:List cell {current} has no rdf:first"
+                )));
+            };
+            items.push(item.clone());
+            current = match self.list_rest.get(current) {
+                Some(rest) => rest,
+                None => break,
+            };
+        }
+
+        Ok(items)
+    }
+}
+
+/// Check whether a triple belongs to the RDF reification vocabulary
+///
+/// `object` is the object IRI, if the object is one.
+pub fn is_reification_triple(predicate: &str, object: Option<&str>) -> bool {
+    predicate == RDF_SUBJECT
+        || predicate == RDF_PREDICATE
+        || predicate == RDF_OBJECT
+        || (predicate == RDF_TYPE && object == Some(RDF_STATEMENT))
+}
+
+/// Collects reified statements (`rdf:Statement` nodes) while triples are parsed
+///
+/// The `rdf:subject`, `rdf:predicate` and `rdf:object` triples of a statement may be
+/// spread over the document, so the reification axioms are only built once the whole
+/// document has been read. Other triples about a statement node are parsed as usual and
+/// its property assertions become the properties of the reification axiom.
+#[derive(Debug, Default)]
+pub struct ReificationCollector {
+    statements: HashMap<String, ReifiedStatement>,
+}
+
+#[derive(Debug)]
+struct ReifiedStatement {
+    node: IRI,
+    typed: bool,
+    subject: Option<Arc<IRI>>,
+    predicate: Option<Arc<IRI>>,
+    object: Option<ReificationObject>,
+}
+
+impl ReificationCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a triple about a statement node
+    ///
+    /// Returns `true` when the triple was consumed and should not be processed further.
+    /// Only IRIs can be the subject or predicate of a reified statement; other values
+    /// are left to the regular triple processing.
+    pub fn record(&mut self, node: &IRI, predicate: &IRI, object: ReificationObject) -> bool {
+        let predicate = predicate.as_str();
+        let object_iri = match &object {
+            ReificationObject::Named(iri) => Some(iri.as_str()),
+            _ => None,
+        };
+        if !is_reification_triple(predicate, object_iri) {
+            return false;
+        }
+        if (predicate == RDF_SUBJECT || predicate == RDF_PREDICATE)
+            && !matches!(object, ReificationObject::Named(_))
+        {
+            return false;
+        }
+
+        let statement = self
+            .statements
+            .entry(node.as_str().to_string())
+            .or_insert_with(|| ReifiedStatement {
+                node: node.clone(),
+                typed: false,
+                subject: None,
+                predicate: None,
+                object: None,
+            });
+        match (predicate, object) {
+            (p, ReificationObject::Named(iri)) if p == RDF_SUBJECT => statement.subject = Some(iri),
+            (p, ReificationObject::Named(iri)) if p == RDF_PREDICATE => {
+                statement.predicate = Some(iri)
+            }
+            (p, object) if p == RDF_OBJECT => statement.object = Some(object),
+            _ => statement.typed = true,
+        }
+        true
+    }
+
+    /// Build the reification axioms and add them to the ontology
+    ///
+    /// Statement nodes missing their subject, predicate or object keep the triples
+    /// that were read as ordinary assertions.
+    pub fn finish(self, ontology: &mut Ontology) -> OwlResult<()> {
+        let mut statements: Vec<_> = self.statements.into_values().collect();
+        statements.sort_by(|a, b| a.node.as_str().cmp(b.node.as_str()));
+
+        for statement in statements {
+            let node = Arc::new(statement.node);
+            match (statement.subject, statement.predicate, statement.object) {
+                (Some(subject), Some(predicate), Some(object)) => {
+                    let properties = ontology
+                        .property_assertions()
+                        .into_iter()
+                        .filter(|assertion| assertion.subject() == &node)
+                        .cloned()
+                        .collect();
+                    ontology.add_axiom(Axiom::Reification(Box::new(
+                        ReificationAxiom::with_properties(
+                            node, subject, predicate, object, properties,
+                        ),
+                    )))?;
+                }
+                (subject, predicate, object) => {
+                    if statement.typed {
+                        ontology.add_axiom(Axiom::ClassAssertion(Box::new(
+                            ClassAssertionAxiom::new(
+                                node.clone(),
+                                ClassExpression::Class(Class::new(RDF_STATEMENT)),
+                            ),
+                        )))?;
+                    }
+                    let parts = [
+                        (RDF_SUBJECT, subject.map(ReificationObject::Named)),
+                        (RDF_PREDICATE, predicate.map(ReificationObject::Named)),
+                        (RDF_OBJECT, object),
+                    ];
+                    for (property, value) in parts {
+                        if let Some(value) = value {
+                            ontology.add_axiom(
+                                value.to_assertion(node.clone(), Arc::new(IRI::new(property)?)),
+                            )?;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Object of a triple collected by [`UnmappedTripleCollector`]
+#[derive(Debug, Clone)]
+pub enum TripleObject {
+    /// Named resource
+    Named(IRI),
+    /// Blank node
+    Anonymous(Box<AnonymousIndividual>),
+    /// Literal value
+    Literal(Literal),
+}
+
+/// Collects triples whose predicate has no built-in mapping to an axiom
+///
+/// Whether such a predicate is a declared property is only known once the whole
+/// document has been read, so the triples are resolved at the end: predicates
+/// declared as object or data properties give property assertions, declared
+/// annotation properties give annotation assertions, and the remaining unknown
+/// predicates are handled according to an [`UnknownPredicatePolicy`].
+#[derive(Debug, Default)]
+pub struct UnmappedTripleCollector {
+    triples: Vec<(IRI, IRI, TripleObject)>,
+}
+
+impl UnmappedTripleCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a triple
+    pub fn record(&mut self, subject: IRI, predicate: IRI, object: TripleObject) {
+        self.triples.push((subject, predicate, object));
+    }
+
+    /// Add the collected triples to the ontology
+    pub fn finish(self, ontology: &mut Ontology, policy: UnknownPredicatePolicy) -> OwlResult<()> {
+        let properties: HashSet<IRI> = ontology
+            .object_properties()
+            .iter()
+            .map(|property| (**property.iri()).clone())
+            .chain(
+                ontology
+                    .data_properties()
+                    .iter()
+                    .map(|property| (**property.iri()).clone()),
+            )
+            .collect();
+        let annotation_properties: HashSet<IRI> = ontology
+            .annotation_properties()
+            .iter()
+            .map(|property| (**property.iri()).clone())
+            .collect();
+
+        for (subject, predicate, object) in self.triples {
+            let as_annotation = if properties.contains(&predicate) {
+                false
+            } else if annotation_properties.contains(&predicate) {
+                true
+            } else {
+                match policy {
+                    UnknownPredicatePolicy::Ignore => continue,
+                    UnknownPredicatePolicy::AsAnnotation => true,
+                    UnknownPredicatePolicy::AsObjectProperty => false,
+                    UnknownPredicatePolicy::Error => {
+                        return Err(OwlError::ValidationError(format!(
+                            "Unknown predicate {} in triple about {}",
+                            predicate, subject
+                        )));
+                    }
+                }
+            };
+
+            if as_annotation {
+                let value = match object {
+                    TripleObject::Named(iri) => AnnotationValue::from(iri),
+                    TripleObject::Anonymous(individual) => {
+                        AnnotationValue::AnonymousIndividual(individual.node_id().to_string())
+                    }
+                    TripleObject::Literal(literal) => AnnotationValue::from(literal),
+                };
+                ontology.add_axiom(Axiom::AnnotationAssertion(Box::new(
+                    AnnotationAssertionAxiom::new(Arc::new(predicate), Arc::new(subject), value),
+                )))?;
+                continue;
+            }
+
+            let subject = NamedIndividual::new(subject);
+            ontology.add_named_individual(subject.clone())?;
+            let subject = subject.iri().clone();
+            let predicate = Arc::new(predicate);
+            let axiom = match object {
+                TripleObject::Named(iri) => {
+                    let object = NamedIndividual::new(iri);
+                    ontology.add_named_individual(object.clone())?;
+                    Axiom::PropertyAssertion(Box::new(PropertyAssertionAxiom::new(
+                        subject,
+                        predicate,
+                        object.iri().clone(),
+                    )))
+                }
+                TripleObject::Anonymous(individual) => {
+                    ontology.add_anonymous_individual((*individual).clone())?;
+                    Axiom::PropertyAssertion(Box::new(PropertyAssertionAxiom::new_with_anonymous(
+                        subject,
+                        predicate,
+                        *individual,
+                    )))
+                }
+                TripleObject::Literal(literal) => Axiom::DataPropertyAssertion(Box::new(
+                    DataPropertyAssertionAxiom::new(subject, predicate, literal),
+                )),
+            };
+            ontology.add_axiom(axiom)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Check whether a literal is the boolean `true`, the only value `owl:hasSelf` takes
+pub fn is_true_literal(literal: &Literal) -> bool {
+    matches!(literal.lexical_form().trim(), "true" | "1")
+}
+
+/// Check whether a predicate describes an anonymous class expression node
+fn is_class_expression_predicate(iri: &str) -> bool {
+    [
+        OWL_ON_PROPERTY,
+        OWL_SOME_VALUES_FROM,
+        OWL_ALL_VALUES_FROM,
+        OWL_HAS_VALUE,
+        OWL_HAS_SELF,
+        OWL_CARDINALITY,
+        OWL_MIN_CARDINALITY,
+        OWL_MAX_CARDINALITY,
+        OWL_INTERSECTION_OF,
+        OWL_UNION_OF,
+        OWL_COMPLEMENT_OF,
+        OWL_ONE_OF,
+        RDF_FIRST,
+        RDF_REST,
+    ]
+    .contains(&iri)
+}
+
+/// Collects anonymous class expressions while triples are parsed
+///
+/// Restrictions (`owl:Restriction` with `owl:onProperty`) and boolean and enumerated
+/// classes (`owl:intersectionOf`, `owl:unionOf`, `owl:complementOf`, `owl:oneOf`) are
+/// blank nodes, possibly nested in each other, used on either side of an
+/// `rdfs:subClassOf` or `owl:equivalentClass` triple, as the domain or range of a
+/// property or as the type of an individual. The triples may arrive in any order, so
+/// the expressions and the axioms using them are only built once the whole document
+/// has been read.
+#[derive(Debug, Default)]
+pub struct ClassExpressionCollector {
+    /// Triples about blank nodes, by node and predicate
+    nodes: HashMap<String, HashMap<String, TripleObject>>,
+    /// Subject, predicate and object of the triples using a blank node
+    uses: Vec<(TripleObject, String, TripleObject)>,
+}
+
+impl ClassExpressionCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a triple about a class expression node or an axiom using one
+    ///
+    /// Returns `true` when the triple was consumed and should not be processed further.
+    pub fn record(&mut self, subject: &IRI, predicate: &IRI, object: &TripleObject) -> bool {
+        let predicate = predicate.as_str();
+        let blank_subject = subject.as_str().starts_with("_:");
+        let blank_object = matches!(object, TripleObject::Anonymous(_));
+
+        if blank_subject && is_class_expression_predicate(predicate) {
+            self.nodes
+                .entry(subject.as_str().to_string())
+                .or_default()
+                .insert(predicate.to_string(), object.clone());
+            return true;
+        }
+        if blank_subject && predicate == RDF_TYPE {
+            if let TripleObject::Named(class) = object {
+                return class.as_str() == OWL_RESTRICTION || class.as_str() == OWL_CLASS;
+            }
+        }
+
+        let uses_node = match predicate {
+            p if p == RDFS_SUBCLASSOF || p == OWL_EQUIVALENT_CLASS => blank_subject || blank_object,
+            p if p == RDFS_DOMAIN || p == RDFS_RANGE || p == RDF_TYPE => {
+                !blank_subject && blank_object
+            }
+            _ => false,
+        };
+        if uses_node {
+            let subject = if blank_subject {
+                TripleObject::Anonymous(Box::new(AnonymousIndividual::new(subject.as_str())))
+            } else {
+                TripleObject::Named(subject.clone())
+            };
+            self.uses
+                .push((subject, predicate.to_string(), object.clone()));
+        }
+        uses_node
+    }
+
+    /// Build the axioms using the class expressions and add them to the ontology
+    ///
+    /// Restrictions on declared data properties become data restrictions. Axioms
+    /// with a blank node that is not a well-formed class expression are dropped.
+    pub fn finish(self, ontology: &mut Ontology) -> OwlResult<()> {
+        let data_properties: HashSet<IRI> = ontology
+            .data_properties()
+            .iter()
+            .map(|property| (**property.iri()).clone())
+            .collect();
+
+        for (subject, predicate, object) in &self.uses {
+            let Some(object) = self.expression(object, &data_properties, 0) else {
+                continue;
+            };
+            let axiom = match (subject, predicate.as_str()) {
+                (TripleObject::Named(property), p) if p == RDFS_DOMAIN => {
+                    Axiom::ObjectPropertyDomain(Box::new(ObjectPropertyDomainAxiom::new(
+                        Arc::new(property.clone()),
+                        object,
+                    )))
+                }
+                (TripleObject::Named(property), p) if p == RDFS_RANGE => {
+                    Axiom::ObjectPropertyRange(Box::new(ObjectPropertyRangeAxiom::new(
+                        property.clone(),
+                        object,
+                    )))
+                }
+                (TripleObject::Named(individual), p) if p == RDF_TYPE => {
+                    Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+                        Arc::new(individual.clone()),
+                        object,
+                    )))
+                }
+                (subject, p) => {
+                    let Some(subject) = self.expression(subject, &data_properties, 0) else {
+                        continue;
+                    };
+                    for class in [&subject, &object].into_iter().filter_map(|e| e.as_named()) {
+                        ontology.add_class(class.clone())?;
+                    }
+                    if p == OWL_EQUIVALENT_CLASS {
+                        Axiom::EquivalentClasses(Box::new(
+                            EquivalentClassesAxiom::from_expressions(vec![subject, object]),
+                        ))
+                    } else {
+                        Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(subject, object)))
+                    }
+                }
+            };
+            ontology.add_axiom(axiom)?;
+        }
+
+        Ok(())
+    }
+
+    /// The class expression a triple object denotes
+    fn expression(
+        &self,
+        object: &TripleObject,
+        data_properties: &HashSet<IRI>,
+        depth: usize,
+    ) -> Option<ClassExpression> {
+        // Deeper nesting than there are nodes means the nodes form a cycle
+        if depth > self.nodes.len() {
+            return None;
+        }
+        let node = match object {
+            TripleObject::Named(iri) => {
+                return Some(ClassExpression::Class(Class::new(iri.clone())))
+            }
+            TripleObject::Anonymous(node) => self.nodes.get(node.node_id())?,
+            TripleObject::Literal(_) => return None,
+        };
+        let nested = |object: &TripleObject| self.expression(object, data_properties, depth + 1);
+        let operands = |head: &TripleObject| -> Option<SmallVec<[Arc<ClassExpression>; 4]>> {
+            self.list(head)?
+                .into_iter()
+                .map(|item| nested(item).map(Arc::new))
+                .collect()
+        };
+
+        if let Some(head) = node.get(OWL_INTERSECTION_OF) {
+            return Some(ClassExpression::ObjectIntersectionOf(operands(head)?));
+        }
+        if let Some(head) = node.get(OWL_UNION_OF) {
+            return Some(ClassExpression::ObjectUnionOf(operands(head)?));
+        }
+        if let Some(operand) = node.get(OWL_COMPLEMENT_OF) {
+            return Some(ClassExpression::ObjectComplementOf(Arc::new(nested(
+                operand,
+            )?)));
+        }
+        if let Some(head) = node.get(OWL_ONE_OF) {
+            let individuals = self
+                .list(head)?
+                .into_iter()
+                .map(individual)
+                .collect::<Option<SmallVec<_>>>()?;
+            return Some(ClassExpression::ObjectOneOf(Box::new(individuals)));
+        }
+
+        let TripleObject::Named(property) = node.get(OWL_ON_PROPERTY)? else {
+            return None;
+        };
+        let object_property = || {
+            Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+                ObjectProperty::new(property.clone()),
+            )))
+        };
+        let data_property = || {
+            Box::new(DataPropertyExpression::DataProperty(DataProperty::new(
+                property.clone(),
+            )))
+        };
+        let is_data = data_properties.contains(property);
+        let cardinality = |predicate: &str| match node.get(predicate) {
+            Some(TripleObject::Literal(n)) => n.lexical_form().trim().parse::<u32>().ok(),
+            _ => None,
+        };
+
+        if let Some(filler) = node.get(OWL_SOME_VALUES_FROM) {
+            return match (is_data, filler) {
+                (true, TripleObject::Named(datatype)) => Some(ClassExpression::DataSomeValuesFrom(
+                    data_property(),
+                    Box::new(DataRange::Datatype(datatype.clone())),
+                )),
+                _ => Some(ClassExpression::ObjectSomeValuesFrom(
+                    object_property(),
+                    Arc::new(nested(filler)?),
+                )),
+            };
+        }
+        if let Some(filler) = node.get(OWL_ALL_VALUES_FROM) {
+            return match (is_data, filler) {
+                (true, TripleObject::Named(datatype)) => Some(ClassExpression::DataAllValuesFrom(
+                    data_property(),
+                    Box::new(DataRange::Datatype(datatype.clone())),
+                )),
+                _ => Some(ClassExpression::ObjectAllValuesFrom(
+                    object_property(),
+                    Arc::new(nested(filler)?),
+                )),
+            };
+        }
+        if let Some(value) = node.get(OWL_HAS_VALUE) {
+            return match value {
+                TripleObject::Literal(literal) => Some(ClassExpression::DataHasValue(
+                    data_property(),
+                    literal.clone(),
+                )),
+                value => Some(ClassExpression::ObjectHasValue(
+                    object_property(),
+                    individual(value)?,
+                )),
+            };
+        }
+        if let Some(TripleObject::Literal(value)) = node.get(OWL_HAS_SELF) {
+            return is_true_literal(value)
+                .then(|| ClassExpression::ObjectHasSelf(object_property()));
+        }
+        if let Some(n) = cardinality(OWL_CARDINALITY) {
+            return Some(if is_data {
+                ClassExpression::DataExactCardinality(n, data_property())
+            } else {
+                ClassExpression::ObjectExactCardinality(n, object_property())
+            });
+        }
+        if let Some(n) = cardinality(OWL_MIN_CARDINALITY) {
+            return Some(if is_data {
+                ClassExpression::DataMinCardinality(n, data_property())
+            } else {
+                ClassExpression::ObjectMinCardinality(n, object_property())
+            });
+        }
+        if let Some(n) = cardinality(OWL_MAX_CARDINALITY) {
+            return Some(if is_data {
+                ClassExpression::DataMaxCardinality(n, data_property())
+            } else {
+                ClassExpression::ObjectMaxCardinality(n, object_property())
+            });
+        }
+        None
+    }
+
+    /// The items of the `rdf:List` starting at `head`
+    fn list<'a>(&'a self, mut head: &'a TripleObject) -> Option<Vec<&'a TripleObject>> {
+        let mut items = Vec::new();
+        loop {
+            match head {
+                TripleObject::Named(iri) if iri.as_str() == RDF_NIL => return Some(items),
+                TripleObject::Anonymous(cell) if items.len() <= self.nodes.len() => {
+                    let cell = self.nodes.get(cell.node_id())?;
+                    items.push(cell.get(RDF_FIRST)?);
+                    head = cell.get(RDF_REST)?;
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// The individual a triple object names
+fn individual(object: &TripleObject) -> Option<Individual> {
+    match object {
+        TripleObject::Named(iri) => Some(Individual::Named(NamedIndividual::new(iri.clone()))),
+        TripleObject::Anonymous(individual) => Some(Individual::Anonymous((**individual).clone())),
+        TripleObject::Literal(_) => None,
+    }
+}
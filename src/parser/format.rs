@@ -0,0 +1,204 @@
+//! Detection of the serialization format of an ontology document
+
+use crate::parser::{
+    JsonLdParser, ManchesterParser, NtriplesParser, OntologyParser, OwlFunctionalSyntaxParser,
+    OwlXmlParser, ParserConfig, RdfXmlParser, TurtleParser,
+};
+use std::fmt;
+
+/// Ontology serialization formats that can be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// Turtle (`.ttl`)
+    Turtle,
+    /// RDF/XML (`.rdf`)
+    RdfXml,
+    /// OWL/XML (`.owx`)
+    OwlXml,
+    /// N-Triples (`.nt`)
+    NTriples,
+    /// JSON-LD (`.jsonld`)
+    JsonLd,
+    /// Manchester Syntax (`.man`)
+    Manchester,
+    /// OWL Functional Syntax (`.ofn`)
+    OwlFunctional,
+}
+
+impl Format {
+    /// All formats
+    pub const ALL: [Format; 7] = [
+        Format::Turtle,
+        Format::RdfXml,
+        Format::OwlXml,
+        Format::NTriples,
+        Format::JsonLd,
+        Format::Manchester,
+        Format::OwlFunctional,
+    ];
+
+    /// Human-readable name, the same as the parser's
+    /// [`OntologyParser::format_name`]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::Turtle => "Turtle",
+            Format::RdfXml => "RDF/XML",
+            Format::OwlXml => "OWL/XML",
+            Format::NTriples => "N-Triples",
+            Format::JsonLd => "JSON-LD",
+            Format::Manchester => "Manchester Syntax",
+            Format::OwlFunctional => "OWL Functional Syntax",
+        }
+    }
+
+    /// Create a parser for this format with the default configuration
+    pub fn parser(&self) -> Box<dyn OntologyParser> {
+        self.parser_with_config(ParserConfig::default())
+    }
+
+    /// Create a parser for this format with the given configuration
+    pub fn parser_with_config(&self, config: ParserConfig) -> Box<dyn OntologyParser> {
+        match self {
+            Format::Turtle => Box::new(TurtleParser::with_config(config)),
+            Format::RdfXml => Box::new(RdfXmlParser::with_config(config)),
+            Format::OwlXml => Box::new(OwlXmlParser::with_config(config)),
+            Format::NTriples => Box::new(NtriplesParser::with_config(config)),
+            Format::JsonLd => Box::new(JsonLdParser::with_config(config)),
+            Format::Manchester => Box::new(ManchesterParser::with_config(config)),
+            Format::OwlFunctional => Box::new(OwlFunctionalSyntaxParser::with_config(config)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Manchester Syntax frame keywords that can start a document
+const MANCHESTER_KEYWORDS: [&str; 9] = [
+    "Prefix:",
+    "Ontology:",
+    "Import:",
+    "Class:",
+    "ObjectProperty:",
+    "DataProperty:",
+    "AnnotationProperty:",
+    "Individual:",
+    "Datatype:",
+];
+
+/// Detect the serialization format of a document
+///
+/// The first statement of the document decides, after skipping a byte order
+/// mark, `#` comment lines, XML comments, processing instructions (including the
+/// XML declaration) and a doctype. When that is not conclusive, well-known
+/// keywords anywhere in the document are looked for. Returns `None` if the
+/// format is not recognised.
+pub fn detect_format(content: &str) -> Option<Format> {
+    let body = skip_preamble(content.strip_prefix('\u{feff}').unwrap_or(content));
+    detect_from_start(body).or_else(|| detect_from_keywords(body))
+}
+
+/// Skip the leading comments and XML prolog of a document
+fn skip_preamble(mut content: &str) -> &str {
+    loop {
+        content = content.trim_start();
+        let rest = if content.starts_with('#') {
+            content.find('\n').map_or("", |end| &content[end..])
+        } else if let Some(comment) = content.strip_prefix("<!--") {
+            comment.find("-->").map_or("", |end| &comment[end + 3..])
+        } else if let Some(instruction) = content.strip_prefix("<?") {
+            instruction
+                .find("?>")
+                .map_or("", |end| &instruction[end + 2..])
+        } else if let Some(doctype) = content.strip_prefix("<!DOCTYPE") {
+            skip_doctype(doctype)
+        } else {
+            return content;
+        };
+        content = rest;
+    }
+}
+
+/// Skip the rest of a doctype, including an internal subset in brackets
+fn skip_doctype(doctype: &str) -> &str {
+    let mut in_subset = false;
+    for (i, c) in doctype.char_indices() {
+        match c {
+            '[' => in_subset = true,
+            ']' => in_subset = false,
+            '>' if !in_subset => return &doctype[i + 1..],
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// Detect the format from the first statement of the document
+fn detect_from_start(body: &str) -> Option<Format> {
+    let first_line = body.lines().next()?.trim_end();
+
+    if body.starts_with('{') || body.starts_with('[') {
+        return Some(Format::JsonLd);
+    }
+    if (first_line.starts_with('<') || first_line.starts_with("_:"))
+        && first_line.ends_with('.')
+        && first_line.contains('>')
+    {
+        return Some(Format::NTriples);
+    }
+    if let Some(element) = body.strip_prefix('<') {
+        let name_end = element
+            .find(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .unwrap_or(element.len());
+        let (prefix, local) = match element[..name_end].split_once(':') {
+            Some((prefix, local)) => (Some(prefix), local),
+            None => (None, &element[..name_end]),
+        };
+        return match (prefix, local) {
+            (_, "RDF") | (Some("rdf"), _) => Some(Format::RdfXml),
+            (_, "Ontology") => Some(Format::OwlXml),
+            _ => None,
+        };
+    }
+
+    let keyword = |keyword: &str| {
+        body.strip_prefix(keyword)
+            .is_some_and(|rest| rest.trim_start().starts_with('('))
+    };
+    if ["Prefix", "Ontology", "Document"].into_iter().any(keyword) {
+        return Some(Format::OwlFunctional);
+    }
+    if MANCHESTER_KEYWORDS.iter().any(|k| body.starts_with(k)) {
+        return Some(Format::Manchester);
+    }
+
+    let directive = first_line.split_whitespace().next().unwrap_or_default();
+    if directive == "@prefix"
+        || directive == "@base"
+        || directive.eq_ignore_ascii_case("PREFIX")
+        || directive.eq_ignore_ascii_case("BASE")
+    {
+        return Some(Format::Turtle);
+    }
+    None
+}
+
+/// Detect the format from keywords found anywhere in the document
+fn detect_from_keywords(body: &str) -> Option<Format> {
+    if body.contains("\"@context\"") || body.contains("\"@graph\"") {
+        Some(Format::JsonLd)
+    } else if body.contains("<rdf:RDF") || body.contains("<rdf:Description") {
+        Some(Format::RdfXml)
+    } else if body.contains("Ontology(") {
+        Some(Format::OwlFunctional)
+    } else if MANCHESTER_KEYWORDS[3..].iter().any(|k| body.contains(k)) {
+        Some(Format::Manchester)
+    } else if body.contains("@prefix") {
+        Some(Format::Turtle)
+    } else {
+        None
+    }
+}
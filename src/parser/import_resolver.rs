@@ -9,7 +9,7 @@ use crate::ontology::Ontology;
 use crate::parser::ParserFactory;
 use hashbrown::HashMap;
 use parking_lot::RwLock;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
@@ -131,6 +131,30 @@ pub struct ImportResolutionStats {
     pub concurrent_resolutions: usize,
 }
 
+/// An import cycle found while resolving imports
+///
+/// Cycles are legal in OWL, so resolution goes on and every ontology on the
+/// cycle is still merged. The report tells intentional mutual imports apart
+/// from accidental ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportCycle {
+    /// The ontologies on the cycle, in import order: each imports the next and
+    /// the last imports the first again
+    pub iris: Vec<IRI>,
+}
+
+impl std::fmt::Display for ImportCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for iri in &self.iris {
+            write!(f, "{} -> ", iri)?;
+        }
+        match self.iris.first() {
+            Some(first) => write!(f, "{}", first),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Import source trait for different resolution strategies
 pub trait ImportSource: Send + Sync {
     /// Check if this source can resolve the given IRI
@@ -225,24 +249,157 @@ impl ImportSource for FileSystemImportSource {
                 message: format!("File not found for IRI: {}", iri),
             })?;
 
-        // Determine parser based on file extension
-        let extension = file_path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .unwrap_or("owl");
+        parse_local_file(iri, &file_path)
+    }
 
-        let parser = ParserFactory::for_file_extension(extension).ok_or_else(|| {
-            OwlError::ImportResolutionError {
-                iri: iri.clone(),
-                message: format!("No parser available for file extension: {}", extension),
+    fn name(&self) -> &'static str {
+        "FileSystem"
+    }
+}
+
+/// Parse the local file an import IRI resolved to, choosing the parser by extension
+fn parse_local_file(iri: &IRI, file_path: &Path) -> OwlResult<Ontology> {
+    let extension = file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("owl");
+
+    let parser = ParserFactory::for_file_extension(extension).ok_or_else(|| {
+        OwlError::ImportResolutionError {
+            iri: iri.clone(),
+            message: format!("No parser available for file extension: {}", extension),
+        }
+    })?;
+
+    parser.parse_file(file_path)
+}
+
+/// Import source backed by an OASIS XML catalog such as Protégé's `catalog-v001.xml`
+///
+/// `<uri name=".." uri=".."/>` entries map an import IRI to a local file and
+/// `<rewriteURI uriStartString=".." rewritePrefix=".."/>` entries map every IRI
+/// with a given prefix. Relative paths are resolved against the `xml:base` of
+/// the enclosing `<group>` or else the directory of the catalog.
+#[derive(Debug, Clone, Default)]
+pub struct XmlCatalogResolver {
+    /// Local files of exactly mapped IRIs
+    uris: HashMap<String, PathBuf>,
+    /// IRI prefixes and the local prefixes they are rewritten to, longest first
+    rewrites: Vec<(String, PathBuf)>,
+}
+
+impl XmlCatalogResolver {
+    /// Load a catalog file
+    pub fn from_file(path: impl AsRef<Path>) -> OwlResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        let base = path.parent().unwrap_or_else(|| Path::new("."));
+        Self::parse(&content, base)
+    }
+
+    /// Parse catalog XML, resolving relative paths against `base`
+    pub fn parse(content: &str, base: impl AsRef<Path>) -> OwlResult<Self> {
+        let root = xmltree::Element::parse(content.as_bytes())
+            .map_err(|e| OwlError::ParseError(format!("Invalid XML catalog: {}", e)))?;
+        if root.name != "catalog" {
+            return Err(OwlError::ParseError(format!(
+                "Expected an XML catalog, found <{}>",
+                root.name
+            )));
+        }
+
+        let mut catalog = Self::default();
+        catalog.add_entries(&root, base.as_ref());
+        catalog
+            .rewrites
+            .sort_by_key(|(start, _)| std::cmp::Reverse(start.len()));
+        Ok(catalog)
+    }
+
+    fn add_entries(&mut self, element: &xmltree::Element, base: &Path) {
+        let base = match element.attributes.get("base") {
+            Some(group_base) => Self::local_path(group_base, base),
+            None => base.to_path_buf(),
+        };
+
+        for child in element.children.iter().filter_map(|node| node.as_element()) {
+            let attribute = |name: &str| child.attributes.get(name);
+            match child.name.as_str() {
+                "uri" => {
+                    if let (Some(name), Some(uri)) = (attribute("name"), attribute("uri")) {
+                        self.uris.insert(name.clone(), Self::local_path(uri, &base));
+                    }
+                }
+                "rewriteURI" => {
+                    if let (Some(start), Some(prefix)) =
+                        (attribute("uriStartString"), attribute("rewritePrefix"))
+                    {
+                        self.rewrites
+                            .push((start.clone(), Self::local_path(prefix, &base)));
+                    }
+                }
+                "group" => self.add_entries(child, &base),
+                _ => {}
             }
-        })?;
+        }
+    }
 
-        parser.parse_file(&file_path)
+    /// Turn a catalog path or `file:` URI into a path, relative ones joined to `base`
+    fn local_path(reference: &str, base: &Path) -> PathBuf {
+        let path = reference
+            .strip_prefix("file://")
+            .or_else(|| reference.strip_prefix("file:"))
+            .unwrap_or(reference);
+        base.join(path)
+    }
+
+    /// Get the local file the catalog maps an IRI to
+    pub fn resolve_path(&self, iri: &IRI) -> Option<PathBuf> {
+        let iri = iri.as_str();
+        if let Some(path) = self.uris.get(iri) {
+            return Some(path.clone());
+        }
+        self.rewrites.iter().find_map(|(start, prefix)| {
+            let rest = iri.strip_prefix(start.as_str())?;
+            Some(prefix.join(rest))
+        })
+    }
+
+    /// Get the number of `<uri>` and `<rewriteURI>` entries
+    pub fn len(&self) -> usize {
+        self.uris.len() + self.rewrites.len()
+    }
+
+    /// Check whether the catalog has no entries
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl ImportSource for XmlCatalogResolver {
+    fn can_resolve(&self, iri: &IRI) -> bool {
+        self.resolve_path(iri).is_some()
+    }
+
+    fn resolve(&self, iri: &IRI, _config: &ImportResolverConfig) -> OwlResult<Ontology> {
+        let file_path = self
+            .resolve_path(iri)
+            .ok_or_else(|| OwlError::ImportResolutionError {
+                iri: iri.clone(),
+                message: format!("No catalog entry for IRI: {}", iri),
+            })?;
+        if !file_path.exists() {
+            return Err(OwlError::ImportResolutionError {
+                iri: iri.clone(),
+                message: format!("Catalog maps IRI to missing file: {}", file_path.display()),
+            });
+        }
+
+        parse_local_file(iri, &file_path)
     }
 
     fn name(&self) -> &'static str {
-        "FileSystem"
+        "XmlCatalog"
     }
 }
 
@@ -482,8 +639,10 @@ pub struct ImportResolver {
     config: ImportResolverConfig,
     /// Resolution statistics
     stats: Arc<RwLock<ImportResolutionStats>>,
-    /// Currently resolving imports (for circular dependency detection)
-    resolving: Arc<RwLock<HashSet<IRI>>>,
+    /// Chain of imports currently being resolved (for circular dependency detection)
+    resolving: Arc<RwLock<Vec<IRI>>>,
+    /// Import cycles found by the current resolution
+    cycles: Vec<ImportCycle>,
 }
 
 impl ImportResolver {
@@ -516,7 +675,8 @@ impl ImportResolver {
             cache: ImportCache::new(config.max_cache_size),
             config,
             stats: Arc::new(RwLock::new(ImportResolutionStats::default())),
-            resolving: Arc::new(RwLock::new(HashSet::new())),
+            resolving: Arc::new(RwLock::new(Vec::new())),
+            cycles: Vec::new(),
         })
     }
 
@@ -525,9 +685,41 @@ impl ImportResolver {
         self.sources.push(source);
     }
 
-    /// Resolve imports for an ontology
-    pub fn resolve_imports(&mut self, ontology: &mut Ontology) -> OwlResult<()> {
-        self.resolve_imports_with_depth(ontology, 0)
+    /// Consult an XML catalog before every other source
+    ///
+    /// IRIs mapped by the catalog are then loaded from local files instead of
+    /// being fetched over the network.
+    pub fn add_catalog(&mut self, catalog: XmlCatalogResolver) {
+        self.sources.insert(0, Box::new(catalog));
+    }
+
+    /// Resolve imports for an ontology, merging the whole import closure into it
+    ///
+    /// Returns the import cycles found on the way. Each ontology on a cycle is
+    /// merged once and the import closing the cycle is not followed again.
+    pub fn resolve_imports(&mut self, ontology: &mut Ontology) -> OwlResult<Vec<ImportCycle>> {
+        self.cycles.clear();
+        let root = ontology.iri().cloned();
+        if let Some(root) = &root {
+            self.resolving.write().push(root.clone());
+        }
+        let result = self.resolve_imports_with_depth(ontology, 0);
+        if root.is_some() {
+            self.resolving.write().pop();
+        }
+        result?;
+        Ok(std::mem::take(&mut self.cycles))
+    }
+
+    /// Resolve imports for an ontology, keeping them apart from it
+    ///
+    /// The import closure from [`load_imports`](Self::load_imports) is added to
+    /// the ontology's imported ontologies instead of being merged in.
+    pub fn resolve_imports_lazily(&mut self, ontology: &mut Ontology) -> OwlResult<()> {
+        for imported in self.load_imports(ontology)? {
+            ontology.add_imported_ontology(imported);
+        }
+        Ok(())
     }
 
     /// Resolve imports with depth tracking
@@ -550,19 +742,24 @@ impl ImportResolver {
             });
         }
 
-        // Get imports from the ontology
-        let imports: Vec<IRI> = ontology
-            .imports()
-            .iter()
-            .map(|iri| (**iri).clone())
-            .collect();
-
+        let imports = Self::import_targets(ontology);
         if imports.is_empty() {
             return Ok(());
         }
 
-        // Resolve each import
-        for import_iri in imports {
+        // Resolve each import, preferring the pinned version
+        for (import_iri, version_iri) in imports {
+            if let Some(version_iri) = &version_iri {
+                match self.resolve_single_import(version_iri, ontology, depth) {
+                    Ok(()) => continue,
+                    Err(e) => log::debug!(
+                        "Failed to resolve version {} of import {}, falling back: {}",
+                        version_iri,
+                        import_iri,
+                        e
+                    ),
+                }
+            }
             if let Err(e) = self.resolve_single_import(&import_iri, ontology, depth) {
                 log::warn!("Failed to resolve import {}: {}", import_iri, e);
 
@@ -575,63 +772,105 @@ impl ImportResolver {
         Ok(())
     }
 
-    /// Resolve a single import
-    fn resolve_single_import(
-        &mut self,
-        import_iri: &IRI,
-        target_ontology: &mut Ontology,
-        depth: usize,
-    ) -> OwlResult<()> {
-        let start_time = Instant::now();
+    /// The imports of an ontology, with the version each is pinned to
+    fn import_targets(ontology: &Ontology) -> Vec<(IRI, Option<IRI>)> {
+        let versions: HashMap<IRI, IRI> = ontology
+            .import_axioms()
+            .into_iter()
+            .filter_map(|axiom| {
+                let version = axiom.version_iri()?;
+                Some(((**axiom.imported_ontology()).clone(), (**version).clone()))
+            })
+            .collect();
+        ontology
+            .imports()
+            .iter()
+            .map(|iri| {
+                let version = versions.get(iri.as_ref()).cloned();
+                ((**iri).clone(), version)
+            })
+            .collect()
+    }
 
-        // Check for circular dependencies
-        {
-            let resolving = self.resolving.read();
-            if resolving.contains(import_iri) {
-                let mut stats = self.stats.write();
-                stats.circular_dependencies_detected += 1;
+    /// Load the import closure of an ontology without merging it
+    ///
+    /// Each imported ontology, and each ontology those import in turn, is
+    /// returned once, in breadth-first order. Pinned versions are preferred as
+    /// in [`resolve_imports`](Self::resolve_imports) and imports that fail to
+    /// resolve are logged and skipped. An import cycle is not an error here,
+    /// since nothing is merged.
+    pub fn load_imports(&mut self, ontology: &Ontology) -> OwlResult<Vec<Arc<Ontology>>> {
+        let mut loaded = Vec::new();
+        let mut seen: HashSet<IRI> = HashSet::new();
+        let mut queue: VecDeque<(IRI, Option<IRI>, usize)> = Self::import_targets(ontology)
+            .into_iter()
+            .map(|(import_iri, version_iri)| (import_iri, version_iri, 0))
+            .collect();
 
+        while let Some((import_iri, version_iri, depth)) = queue.pop_front() {
+            if !seen.insert(import_iri.clone()) {
+                continue;
+            }
+            if depth > self.config.max_depth {
                 return Err(OwlError::ImportResolutionError {
-                    iri: import_iri.clone(),
-                    message: format!("Circular import detected: {}", import_iri),
+                    iri: import_iri,
+                    message: format!("Maximum import depth {} exceeded", self.config.max_depth),
                 });
             }
-        }
 
-        // Add to resolving set
-        {
-            let mut resolving = self.resolving.write();
-            resolving.insert(import_iri.clone());
+            let start_time = Instant::now();
+            let pinned = version_iri.and_then(|version_iri| {
+                self.fetch(&version_iri)
+                    .map_err(|e| {
+                        log::debug!(
+                            "Failed to load version {} of import {}, falling back: {}",
+                            version_iri,
+                            import_iri,
+                            e
+                        )
+                    })
+                    .ok()
+            });
+            let fetched = match pinned {
+                Some(fetched) => Ok(fetched),
+                None => self.fetch(&import_iri),
+            };
+
+            match fetched {
+                Ok((imported, _)) => {
+                    queue.extend(
+                        Self::import_targets(&imported)
+                            .into_iter()
+                            .map(|(import_iri, version_iri)| (import_iri, version_iri, depth + 1)),
+                    );
+                    loaded.push(Arc::new(imported));
+
+                    let mut stats = self.stats.write();
+                    stats.imports_resolved += 1;
+                    stats.total_resolution_time += start_time.elapsed();
+                }
+                Err(e) => {
+                    log::warn!("Failed to load import {}: {}", import_iri, e);
+                    self.stats.write().failed_resolutions += 1;
+                }
+            }
         }
 
-        // Check cache first
+        Ok(loaded)
+    }
+
+    /// Get an imported ontology from the cache or else from a source
+    ///
+    /// Also returns whether it came from the cache.
+    fn fetch(&mut self, import_iri: &IRI) -> OwlResult<(Ontology, bool)> {
         if let Some(cached) = self.cache.get(import_iri) {
             log::debug!("Cache hit for import: {}", import_iri);
-
-            // Merge cached ontology
-            self.merge_ontology(target_ontology, &cached.ontology)?;
-
-            // Update statistics
-            let mut stats = self.stats.write();
-            stats.cache_hits += 1;
-            stats.imports_resolved += 1;
-            stats.total_resolution_time += start_time.elapsed();
-
-            // Remove from resolving set
-            {
-                let mut resolving = self.resolving.write();
-                resolving.remove(import_iri);
-            }
-
-            return Ok(());
+            self.stats.write().cache_hits += 1;
+            return Ok((cached.ontology, true));
         }
 
         log::debug!("Cache miss for import: {}", import_iri);
-
-        // Cache miss - resolve from source
-        let mut stats = self.stats.write();
-        stats.cache_misses += 1;
-        drop(stats);
+        self.stats.write().cache_misses += 1;
 
         // Find appropriate source
         let source = self
@@ -654,9 +893,6 @@ impl ImportResolver {
             source.resolve(import_iri, &self.config)?
         };
 
-        // Recursively resolve imports for the imported ontology
-        self.resolve_imports_with_depth(&mut resolved_ontology.clone(), depth + 1)?;
-
         // Cache the resolved ontology
         let cached = CachedOntology::new(
             resolved_ontology.clone(),
@@ -665,6 +901,48 @@ impl ImportResolver {
         );
         self.cache.put(import_iri.clone(), cached);
 
+        Ok((resolved_ontology, false))
+    }
+
+    /// Resolve a single import
+    fn resolve_single_import(
+        &mut self,
+        import_iri: &IRI,
+        target_ontology: &mut Ontology,
+        depth: usize,
+    ) -> OwlResult<()> {
+        let start_time = Instant::now();
+
+        // An import of an ontology still being resolved closes a cycle; that
+        // ontology ends up merged anyway, so the import is not followed again
+        let cycle_start = self
+            .resolving
+            .read()
+            .iter()
+            .position(|iri| iri == import_iri);
+        if let Some(start) = cycle_start {
+            let iris = self.resolving.read()[start..].to_vec();
+            log::info!(
+                "Import cycle detected: {}",
+                ImportCycle { iris: iris.clone() }
+            );
+            self.stats.write().circular_dependencies_detected += 1;
+            self.cycles.push(ImportCycle { iris });
+            return Ok(());
+        }
+
+        // Check cache first, then the sources
+        let (mut resolved_ontology, cached) = self.fetch(import_iri)?;
+
+        // Recursively resolve imports for a newly resolved ontology, so that
+        // its own imports are merged along with it
+        if !cached {
+            self.resolving.write().push(import_iri.clone());
+            let resolved = self.resolve_imports_with_depth(&mut resolved_ontology, depth + 1);
+            self.resolving.write().pop();
+            resolved?;
+        }
+
         // Merge the resolved ontology
         self.merge_ontology(target_ontology, &resolved_ontology)?;
 
@@ -673,12 +951,6 @@ impl ImportResolver {
         stats.imports_resolved += 1;
         stats.total_resolution_time += start_time.elapsed();
 
-        // Remove from resolving set
-        {
-            let mut resolving = self.resolving.write();
-            resolving.remove(import_iri);
-        }
-
         Ok(())
     }
 
@@ -767,7 +1039,8 @@ impl Default for ImportResolver {
             cache: ImportCache::new(100),
             config: ImportResolverConfig::default(),
             stats: Arc::new(RwLock::new(ImportResolutionStats::default())),
-            resolving: Arc::new(RwLock::new(HashSet::new())),
+            resolving: Arc::new(RwLock::new(Vec::new())),
+            cycles: Vec::new(),
         })
     }
 }
@@ -379,6 +379,13 @@ impl JsonLdParser {
 impl OntologyParser for JsonLdParser {
     fn parse_str(&self, content: &str) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
+
+        // JSON has no empty document, but an empty ontology is still valid
+        if content.trim().is_empty() {
+            return Ok(ontology);
+        }
 
         // Parse JSON
         let json: Value = serde_json::from_str(content)
@@ -432,3 +439,11 @@ impl OntologyParser for JsonLdParser {
         "JSON-LD"
     }
 }
+
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for JsonLdParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        Box::new(move |content| Self::with_config(config).parse_str(content))
+    }
+}
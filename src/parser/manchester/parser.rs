@@ -11,7 +11,7 @@ use super::syntax::{
 use super::tokenizer::{ManchesterTokenizer, Token, TokenType};
 use crate::error::OwlResult;
 use crate::ontology::Ontology;
-use crate::parser::{OntologyParser, ParserConfig};
+use crate::parser::{OntologyParser, ParserConfig, TokenLimits};
 use crate::utils::smallvec::sizes;
 use smallvec::SmallVec;
 use std::path::Path;
@@ -30,6 +30,9 @@ pub struct ManchesterParser {
 
     /// Parser state
     state: ParserState,
+
+    /// Bounds on the length of IRIs and literals in the input
+    limits: TokenLimits,
 }
 
 /// Parser state information
@@ -50,21 +53,27 @@ impl ManchesterParser {
 
     /// Create a new parser for the given input
     pub fn with_input(input: String) -> Self {
+        Self::with_input_and_limits(input, TokenLimits::default())
+    }
+
+    /// Create a new parser for the given input that rejects over-long IRIs and literals
+    fn with_input_and_limits(input: String, limits: TokenLimits) -> Self {
         let context = ErrorContext::new(input.clone());
         let error_reporter = ErrorReporter::new(context);
-        let tokenizer = ManchesterTokenizer::new(input);
+        let tokenizer = ManchesterTokenizer::new(input).with_limits(limits);
 
         ManchesterParser {
             tokenizer,
             error_reporter,
             current_token: None,
             state: ParserState::default(),
+            limits,
         }
     }
 
     /// Create a new parser with custom configuration
-    pub fn with_config(_config: ParserConfig) -> Self {
-        Self::with_input(String::new())
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self::with_input_and_limits(String::new(), config.token_limits())
     }
 
     /// Parse the entire input into an AST
@@ -77,6 +86,16 @@ impl ManchesterParser {
             .as_ref()
             .is_some_and(|t| t.token_type != TokenType::EOF)
         {
+            // Blank lines between frames carry no meaning
+            if self
+                .current_token
+                .as_ref()
+                .is_some_and(|t| t.token_type == TokenType::Newline)
+            {
+                self.advance_token();
+                continue;
+            }
+
             match self.parse_declaration() {
                 Ok(node) => ast_nodes.push(node),
                 Err(error) => {
@@ -785,7 +804,7 @@ impl ManchesterParser {
 impl OntologyParser for ManchesterParser {
     fn parse_str(&self, content: &str) -> OwlResult<Ontology> {
         // Create a new parser with the input content
-        let mut parser = ManchesterParser::with_input(content.to_string());
+        let mut parser = ManchesterParser::with_input_and_limits(content.to_string(), self.limits);
 
         // Parse the AST
         let ast = parser
@@ -834,6 +853,16 @@ impl OntologyParser for ManchesterParser {
     }
 }
 
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for ManchesterParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let limits = self.limits;
+        Box::new(move |content| {
+            Self::with_input_and_limits(String::new(), limits).parse_str(content)
+        })
+    }
+}
+
 impl Default for ManchesterParser {
     fn default() -> Self {
         Self::new()
@@ -4,6 +4,7 @@
 //! converting input text into a stream of tokens for parsing.
 
 use crate::error::{OwlError, OwlResult};
+use crate::parser::common::TokenLimits;
 use std::fmt;
 
 /// Token types for Manchester Syntax
@@ -260,6 +261,9 @@ pub struct ManchesterTokenizer {
 
     /// Buffer for lookahead
     lookahead: Vec<Token>,
+
+    /// Bounds on the length of IRIs and literals
+    limits: TokenLimits,
 }
 
 impl ManchesterTokenizer {
@@ -271,9 +275,16 @@ impl ManchesterTokenizer {
             current_line: 1,
             current_column: 1,
             lookahead: Vec::new(),
+            limits: TokenLimits::default(),
         }
     }
 
+    /// Reject IRIs and literals longer than the given limits
+    pub fn with_limits(mut self, limits: TokenLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Tokenize the entire input
     pub fn tokenize(&mut self) -> OwlResult<Vec<Token>> {
         let mut tokens = Vec::new();
@@ -535,6 +546,13 @@ impl ManchesterTokenizer {
                     self.consume_char();
                 }
             }
+            if let Some(message) = self.limits.literal_overflow(literal.len()) {
+                return Err(OwlError::ParseErrorWithLocation {
+                    line: start_line,
+                    column: start_column,
+                    message,
+                });
+            }
         }
 
         Err(OwlError::ParseError(format!(
@@ -576,6 +594,13 @@ impl ManchesterTokenizer {
                     self.consume_char();
                 }
             }
+            if let Some(message) = self.limits.iri_overflow(iri.len()) {
+                return Err(OwlError::ParseErrorWithLocation {
+                    line: start_line,
+                    column: start_column,
+                    message,
+                });
+            }
         }
 
         Err(OwlError::ParseError(format!(
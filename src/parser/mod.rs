@@ -9,34 +9,46 @@
 
 pub mod arena;
 pub mod common;
+pub mod format;
 pub mod import_resolver;
 pub mod json_ld;
 pub mod manchester;
 pub mod owl_functional;
 pub mod owl_xml;
+pub mod owl_xml_serializer;
 pub mod rdf_xml;
 pub mod rdf_xml_common;
 pub mod rdf_xml_legacy;
+pub mod rdf_xml_serializer;
 pub mod rdf_xml_streaming;
 pub mod restriction_parser;
 pub mod turtle;
+pub mod turtle_serializer;
 
 pub use arena::*;
 pub use common::*;
+pub use format::{detect_format, Format};
 pub use import_resolver::*;
 pub use json_ld::JsonLdParser;
 pub use manchester::{ManchesterAST, ManchesterParser};
 pub use owl_functional::OwlFunctionalSyntaxParser;
 pub use owl_xml::*;
+pub use owl_xml_serializer::OwlXmlSerializer;
 pub use rdf_xml::*;
+pub use rdf_xml_serializer::RdfXmlSerializer;
 pub use turtle::*;
+pub use turtle_serializer::TurtleSerializer;
 
-use crate::entities::Class;
+use crate::entities::{Class, Literal};
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
 use std::sync::Arc;
 
+/// A parse with a parser's configuration that can be moved to another thread
+#[cfg(feature = "async")]
+pub type DetachedParser = Box<dyn FnOnce(&str) -> OwlResult<Ontology> + Send>;
+
 /// Parser trait for different serialization formats
 pub trait OntologyParser {
     /// Parse an ontology from a string
@@ -49,6 +61,46 @@ pub trait OntologyParser {
     fn format_name(&self) -> &'static str;
 }
 
+/// Parsing from async readers, available with the `async` feature
+#[cfg(feature = "async")]
+pub trait AsyncOntologyParser: OntologyParser {
+    /// Build a parse that runs with this parser's configuration on any thread
+    ///
+    /// Parsers hold thread-local state such as arenas, so a fresh parser is
+    /// built from the configuration when the returned closure runs.
+    fn detached(&self) -> DetachedParser;
+
+    /// Parse an ontology from an async reader without blocking the runtime
+    ///
+    /// The input is read asynchronously in chunks, then parsed on tokio's
+    /// blocking thread pool so other tasks keep running during CPU-bound
+    /// parsing of large documents.
+    fn parse_async<R>(
+        &self,
+        reader: R,
+    ) -> impl std::future::Future<Output = OwlResult<Ontology>> + Send
+    where
+        Self: Sized,
+        R: tokio::io::AsyncRead + Send,
+    {
+        let parse = self.detached();
+        async move {
+            use tokio::io::AsyncReadExt;
+
+            let mut reader = std::pin::pin!(reader);
+            let mut bytes = Vec::new();
+            while reader.read_buf(&mut bytes).await? > 0 {}
+            let content = String::from_utf8(bytes)?;
+
+            tokio::task::spawn_blocking(move || parse(&content))
+                .await
+                .map_err(|e| {
+                    crate::error::OwlError::ParseError(format!("Parsing task failed: {}", e))
+                })?
+        }
+    }
+}
+
 /// Factory for creating parsers based on file extension or content type
 pub struct ParserFactory;
 
@@ -81,52 +133,15 @@ impl ParserFactory {
     }
 
     /// Auto-detect format and create appropriate parser
+    ///
+    /// See [`detect_format`] for how the format is recognised.
     pub fn auto_detect(content: &str) -> Option<Box<dyn OntologyParser>> {
-        let content_trimmed = content.trim();
-
-        // Check for JSON-LD (highest priority due to distinct format)
-        if (content_trimmed.starts_with('{') && content_trimmed.ends_with('}'))
-            || content_trimmed.contains("@context")
-            || content_trimmed.contains("@graph")
-            || (content_trimmed.starts_with('{') && content_trimmed.contains("\"@id\""))
-        {
-            Some(Box::new(JsonLdParser::new()))
-        }
-        // Check for Manchester Syntax (high priority for readability)
-        else if content_trimmed.starts_with("Prefix:")
-            || content_trimmed.contains("Class:")
-            || content_trimmed.contains("ObjectProperty:")
-            || content_trimmed.contains("Individual:")
-        {
-            Some(Box::new(ManchesterParser::new()))
-        }
-        // Check for OWL Functional Syntax (priority for .owl files)
-        else if content_trimmed.starts_with("Prefix(")
-            || content_trimmed.contains("Ontology(")
-            || (content_trimmed.starts_with("Document(") && content_trimmed.contains("Prefix("))
-        {
-            Some(Box::new(OwlFunctionalSyntaxParser::new()))
-        } else if content_trimmed.starts_with("@prefix") || content_trimmed.starts_with("PREFIX") {
-            Some(Box::new(TurtleParser::new()))
-        } else if content_trimmed.starts_with("<rdf:RDF") || content.contains("<rdf:Description") {
-            Some(Box::new(RdfXmlParser::new()))
-        } else if content_trimmed.starts_with("<?xml") && content.contains("Ontology") {
-            Some(Box::new(OwlXmlParser::new()))
-        } else if content
-            .lines()
-            .next()
-            .is_some_and(|line| line.contains("> <") && line.contains(" ."))
-        {
-            Some(Box::new(NtriplesParser::new()))
-        } else {
-            None
-        }
+        detect_format(content).map(|format| format.parser())
     }
 }
 
 /// N-Triples parser implementing W3C N-Triples specification
 pub struct NtriplesParser {
-    #[allow(dead_code)]
     config: ParserConfig,
 }
 
@@ -162,33 +177,50 @@ impl NtriplesParser {
 impl OntologyParser for NtriplesParser {
     fn parse_str(&self, content: &str) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
-        let mut line_num = 0;
+        ontology.set_size_limits(self.config.size_limits());
+        let mut reification = crate::parser::common::ReificationCollector::new();
+        let mut unmapped = crate::parser::common::UnmappedTripleCollector::new();
 
-        for line in content.lines() {
-            line_num += 1;
-            let line = line.trim();
-            if line.is_empty() || line.starts_with('#') {
-                continue;
+        let lines = content
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        if self.config.parallel_ntriples {
+            use rayon::prelude::*;
+
+            // Lines are independent, so only the parse runs in parallel; the
+            // triples are then added in document order as in a sequential parse
+            let lines: Vec<_> = lines.collect();
+            let parsed: Vec<_> = lines
+                .into_par_iter()
+                .map(|(line_num, line)| (line_num, line, self.parse_ntriples_line(line)))
+                .collect();
+            for (line_num, line, triple) in parsed {
+                self.add_parsed_line(
+                    &mut ontology,
+                    &mut reification,
+                    &mut unmapped,
+                    (line_num, line),
+                    triple,
+                )?;
             }
-
-            match self.parse_ntriples_line(line) {
-                Ok(triple) => {
-                    if let Err(e) = self.add_triple_to_ontology(&mut ontology, &triple) {
-                        return Err(crate::error::OwlError::ParseError(format!(
-                            "Error at line {}: {}",
-                            line_num, e
-                        )));
-                    }
-                }
-                Err(e) => {
-                    return Err(crate::error::OwlError::ParseError(format!(
-                        "Parse error at line {}: {}",
-                        line_num, e
-                    )));
-                }
+        } else {
+            for (line_num, line) in lines {
+                let triple = self.parse_ntriples_line(line);
+                self.add_parsed_line(
+                    &mut ontology,
+                    &mut reification,
+                    &mut unmapped,
+                    (line_num, line),
+                    triple,
+                )?;
             }
         }
 
+        unmapped.finish(&mut ontology, self.config.unknown_predicate)?;
+        reification.finish(&mut ontology)?;
         Ok(ontology)
     }
 
@@ -207,7 +239,59 @@ impl OntologyParser for NtriplesParser {
     }
 }
 
+#[cfg(feature = "async")]
+impl AsyncOntologyParser for NtriplesParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        Box::new(move |content| Self::with_config(config).parse_str(content))
+    }
+}
+
 impl NtriplesParser {
+    /// Adds the result of parsing one line to the ontology, reporting errors
+    /// with the line number.
+    fn add_parsed_line(
+        &self,
+        ontology: &mut Ontology,
+        reification: &mut crate::parser::common::ReificationCollector,
+        unmapped: &mut crate::parser::common::UnmappedTripleCollector,
+        (line_num, line): (usize, &str),
+        triple: OwlResult<NtriplesTriple>,
+    ) -> OwlResult<()> {
+        let triple = triple.map_err(|e| {
+            crate::error::OwlError::ParseError(format!("Parse error at line {}: {}", line_num, e))
+        })?;
+        let mut triple = triple.with_iri_subject()?;
+        if let NtriplesTerm::Literal {
+            value,
+            language,
+            datatype,
+        } = &mut triple.object
+        {
+            let literal = crate::parser::common::check_literal(
+                NtriplesTerm::literal(value, language, datatype),
+                self.config.malformed_literal,
+                line_num,
+                line,
+            )?;
+            // A coerced literal comes back as an xsd:string
+            if language.is_none() {
+                *datatype = Some((**literal.datatype()).clone());
+            }
+        }
+        if Self::collect_reification_triple(reification, &triple) {
+            return Ok(());
+        }
+        self.add_triple_to_ontology(ontology, unmapped, &triple)
+            .map_err(|e| match e {
+                // Keep size limit errors distinguishable from bad input
+                crate::error::OwlError::ResourceLimitExceeded { .. } => e,
+                e => {
+                    crate::error::OwlError::ParseError(format!("Error at line {}: {}", line_num, e))
+                }
+            })
+    }
+
     /// Parses a single N-Triples line into a triple.
     ///
     /// This method parses a line of N-Triples format according to the W3C specification,
@@ -275,6 +359,7 @@ impl NtriplesParser {
         &self,
         chars: &mut std::str::CharIndices<'_>,
     ) -> OwlResult<NtriplesTerm> {
+        let limits = self.config.token_limits();
         self.skip_whitespace(chars);
 
         if let Some((_, c)) = chars.next() {
@@ -287,6 +372,9 @@ impl NtriplesParser {
                             break;
                         }
                         iri_str.push(next_c);
+                        if let Some(message) = limits.iri_overflow(iri_str.len()) {
+                            return Err(crate::error::OwlError::ParseError(message));
+                        }
                     }
 
                     if iri_str.is_empty() {
@@ -356,6 +444,9 @@ impl NtriplesParser {
                         } else {
                             literal_str.push(next_c);
                         }
+                        if let Some(message) = limits.literal_overflow(literal_str.len()) {
+                            return Err(crate::error::OwlError::ParseError(message));
+                        }
                     }
 
                     // Check for language tag or datatype
@@ -381,7 +472,6 @@ impl NtriplesParser {
                             chars.next(); // consume '^'
                             if let Some((_, c)) = chars.next() {
                                 if c == '^' {
-                                    chars.next(); // consume second '^'
                                     if let Some((_, c2)) = chars.next() {
                                         if c2 == '<' {
                                             let mut dt_iri = String::new();
@@ -472,6 +562,42 @@ impl NtriplesParser {
         }
     }
 
+    /// Records a triple of a reified statement.
+    ///
+    /// Returns `true` when the triple was consumed and should not be added to the ontology.
+    fn collect_reification_triple(
+        collector: &mut crate::parser::common::ReificationCollector,
+        triple: &NtriplesTriple,
+    ) -> bool {
+        use crate::axioms::ReificationObject;
+
+        let (NtriplesTerm::IRI(subject), NtriplesTerm::IRI(predicate)) =
+            (&triple.subject, &triple.predicate)
+        else {
+            return false;
+        };
+        let object = match &triple.object {
+            NtriplesTerm::IRI(iri) => {
+                if !crate::parser::common::is_reification_triple(
+                    predicate.as_str(),
+                    Some(iri.as_str()),
+                ) {
+                    return false;
+                }
+                ReificationObject::Named(Arc::new(iri.clone()))
+            }
+            NtriplesTerm::Literal {
+                value,
+                language,
+                datatype,
+            } => ReificationObject::Literal(NtriplesTerm::literal(value, language, datatype)),
+            NtriplesTerm::BlankNode(id) => ReificationObject::Anonymous(Box::new(
+                crate::entities::AnonymousIndividual::new(id.clone()),
+            )),
+        };
+        collector.record(subject, predicate, object)
+    }
+
     /// Adds an N-Triples triple to the ontology by converting it to appropriate OWL axioms.
     ///
     /// This method interprets common RDF patterns and converts them to OWL axioms such as
@@ -479,6 +605,7 @@ impl NtriplesParser {
     ///
     /// # Parameters
     /// - `ontology`: A mutable reference to the ontology to add axioms to
+    /// - `unmapped`: Collects the triples that map to no specific axiom
     /// - `triple`: The N-Triples triple to convert and add
     ///
     /// # Returns
@@ -489,6 +616,7 @@ impl NtriplesParser {
     fn add_triple_to_ontology(
         &self,
         ontology: &mut Ontology,
+        unmapped: &mut crate::parser::common::UnmappedTripleCollector,
         triple: &NtriplesTriple,
     ) -> OwlResult<()> {
         use crate::parser::common::*;
@@ -528,18 +656,12 @@ impl NtriplesParser {
                     );
                     ontology.add_subclass_axiom(subclass_axiom)?;
                 } else {
-                    // Generic property assertion
-                    let subject_individual =
-                        crate::entities::NamedIndividual::new(subject_iri.clone());
-                    ontology.add_named_individual(subject_individual)?;
-
-                    // Create object property if it looks like one
-                    if predicate_iri.as_str().starts_with("http://")
-                        && !predicate_iri.as_str().contains("#")
-                    {
-                        let obj_prop = crate::entities::ObjectProperty::new(predicate_iri.clone());
-                        ontology.add_object_property(obj_prop)?;
-                    }
+                    // Generic property assertion, resolved once the declarations are known
+                    unmapped.record(
+                        subject_iri.clone(),
+                        predicate_iri.clone(),
+                        TripleObject::Named(object_iri.clone()),
+                    );
                 }
             }
             (
@@ -547,21 +669,29 @@ impl NtriplesParser {
                 NtriplesTerm::IRI(predicate_iri),
                 NtriplesTerm::Literal {
                     value,
-                    language: _,
-                    datatype: _,
+                    language,
+                    datatype,
                 },
             ) => {
                 // Literal property assertion
-                let subject_individual = crate::entities::NamedIndividual::new(subject_iri.clone());
-                ontology.add_named_individual(subject_individual)?;
-
-                // Could add data property assertion here in the future
-                // For now, we'll just note that we've seen this pattern
-                log::debug!(
-                    "Skipping literal property assertion: {} {} {}",
-                    subject_iri,
-                    predicate_iri,
-                    value
+                unmapped.record(
+                    subject_iri.clone(),
+                    predicate_iri.clone(),
+                    TripleObject::Literal(NtriplesTerm::literal(value, language, datatype)),
+                );
+            }
+            (
+                NtriplesTerm::IRI(subject_iri),
+                NtriplesTerm::IRI(predicate_iri),
+                NtriplesTerm::BlankNode(node_id),
+            ) => {
+                // Property assertion with an anonymous individual
+                unmapped.record(
+                    subject_iri.clone(),
+                    predicate_iri.clone(),
+                    TripleObject::Anonymous(Box::new(crate::entities::AnonymousIndividual::new(
+                        node_id.clone(),
+                    ))),
                 );
             }
             _ => {
@@ -592,6 +722,18 @@ enum NtriplesTerm {
     BlankNode(String),
 }
 
+impl NtriplesTerm {
+    /// Build the literal of a literal term
+    fn literal(value: &str, language: &Option<String>, datatype: &Option<IRI>) -> Literal {
+        // A literal without datatype or language tag is an xsd:string
+        match (language, datatype) {
+            (Some(language), _) => Literal::lang_tagged(value, language.clone()),
+            (None, Some(datatype)) => Literal::typed(value, datatype.clone()),
+            (None, None) => Literal::simple(value),
+        }
+    }
+}
+
 /// N-Triples triple
 #[derive(Debug, Clone, PartialEq)]
 struct NtriplesTriple {
@@ -600,6 +742,19 @@ struct NtriplesTriple {
     object: NtriplesTerm,
 }
 
+impl NtriplesTriple {
+    /// Name a blank node subject by its label, as the RDF/XML parser does
+    fn with_iri_subject(self) -> OwlResult<Self> {
+        match self.subject {
+            NtriplesTerm::BlankNode(id) => Ok(NtriplesTriple {
+                subject: NtriplesTerm::IRI(IRI::new(format!("_:{}", id))?),
+                ..self
+            }),
+            _ => Ok(self),
+        }
+    }
+}
+
 /// Parser configuration
 #[derive(Debug, Clone)]
 pub struct ParserConfig {
@@ -621,6 +776,91 @@ pub struct ParserConfig {
     pub resolve_imports: bool,
     /// Whether to follow import resolution errors or continue without imports
     pub ignore_import_errors: bool,
+    /// Maximum number of XML entity references nested in entity values that a
+    /// document may expand in RDF/XML and OWL/XML (0 = unlimited)
+    ///
+    /// References in the document body to entities whose value is plain text,
+    /// such as `&owl;`, do not count; only the amplification through entities
+    /// referring to other entities does.
+    pub max_entity_expansions: usize,
+    /// Maximum total size in bytes of the text XML entity references expand to
+    /// in RDF/XML and OWL/XML (0 = unlimited)
+    pub max_entity_expanded_bytes: usize,
+    /// Maximum XML element nesting depth allowed in RDF/XML and OWL/XML (0 = unlimited)
+    pub max_xml_nesting_depth: usize,
+    /// Whether to drop structurally duplicate axioms after parsing (see [`Ontology::compact`])
+    pub deduplicate_on_load: bool,
+    /// Whether identical nested class expressions share storage
+    /// (see [`Ontology::set_class_expression_interning`])
+    pub intern_class_expressions: bool,
+    /// Whether Turtle's bare numbers and booleans get their implicit XSD datatype
+    /// (`42` as `xsd:integer`) rather than being read as plain strings.
+    /// Datatypes missing from the global datatype map are never inferred.
+    /// N-Triples has no such shortcuts and always requires explicit datatypes.
+    pub infer_literal_datatypes: bool,
+    /// Whether Turtle `#` comments are kept as annotations on the subject of
+    /// the statement that follows them (see [`crate::constants::SOURCE_COMMENT`]),
+    /// so that serializers can write them back out
+    pub preserve_comments: bool,
+    /// What to do with triples whose predicate is neither mapped to an axiom by
+    /// the parser nor declared as a property in the document
+    pub unknown_predicate: UnknownPredicatePolicy,
+    /// What to do with literals whose lexical form is invalid for their datatype
+    pub malformed_literal: MalformedLiteralPolicy,
+    /// Whether N-Triples lines are parsed on all cores before being added to the
+    /// ontology. The result is the same as a sequential parse.
+    pub parallel_ntriples: bool,
+    /// Whether RDF/XML plain literals take the `xml:lang` of enclosing elements,
+    /// as the RDF/XML specification requires. When disabled only an `xml:lang`
+    /// on the property element itself tags the literal; the streaming backend
+    /// always inherits, so RDF/XML is then read with the legacy parser.
+    pub inherit_xml_lang: bool,
+    /// Maximum number of axioms the parsed ontology may hold (0 = unlimited).
+    /// Unlike `max_file_size` this bounds inputs that expand when read, such as
+    /// long RDF collections.
+    pub max_axioms: usize,
+    /// Maximum number of entities the parsed ontology may hold (0 = unlimited)
+    pub max_entities: usize,
+    /// Maximum length in bytes of a single IRI in the input (0 = unlimited).
+    /// Enforced while tokenizing Turtle, N-Triples, Functional and Manchester Syntax.
+    pub max_iri_length: usize,
+    /// Maximum length in bytes of a single literal in the input (0 = unlimited).
+    /// Enforced for the same formats as `max_iri_length`.
+    pub max_literal_length: usize,
+}
+
+/// Handling of RDF triples with an unknown predicate
+///
+/// Applies to the RDF parsers (Turtle, RDF/XML and N-Triples).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnknownPredicatePolicy {
+    /// Drop the triple
+    Ignore,
+    /// Keep the triple as an annotation assertion on its subject
+    AsAnnotation,
+    /// Keep the triple as an object property assertion, or as a data property
+    /// assertion when the object is a literal
+    #[default]
+    AsObjectProperty,
+    /// Fail the parse
+    Error,
+}
+
+/// Handling of literals whose lexical form is invalid for their datatype,
+/// such as `"abc"^^xsd:integer` (see [`crate::datatypes::is_malformed_literal`])
+///
+/// Applies to the Turtle and N-Triples parsers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MalformedLiteralPolicy {
+    /// Keep the literal as written
+    #[default]
+    Keep,
+    /// Keep the literal as written and log a warning with its location
+    Warn,
+    /// Fail the parse, reporting the line and column of the literal
+    Error,
+    /// Keep the lexical form as a plain `xsd:string` literal
+    Coerce,
 }
 
 impl Default for ParserConfig {
@@ -641,6 +881,51 @@ impl Default for ParserConfig {
             resolve_imports: false,
             // Default to ignoring import errors to allow parsing to continue
             ignore_import_errors: true,
+            // Guard against "billion laughs" style entity expansion attacks
+            max_entity_expansions: 10_000,
+            max_entity_expanded_bytes: 256 * 1024 * 1024,
+            // Deep enough for any real ontology, shallow enough to stop stack abuse
+            max_xml_nesting_depth: 256,
+            // Keep every parsed axiom unless asked otherwise
+            deduplicate_on_load: false,
+            // Repeated subexpressions are common in large ontologies
+            intern_class_expressions: true,
+            // Follow the Turtle specification for native literals
+            infer_literal_datatypes: true,
+            preserve_comments: false,
+            // Keep unknown triples as property assertions rather than lose them
+            unknown_predicate: UnknownPredicatePolicy::default(),
+            // Keep literals as written; the datatype checker can flag them later
+            malformed_literal: MalformedLiteralPolicy::default(),
+            // Small documents don't pay for the thread pool
+            parallel_ntriples: false,
+            inherit_xml_lang: true,
+            // Output size is bounded by max_file_size unless asked otherwise
+            max_axioms: 0,
+            max_entities: 0,
+            // Far beyond real IRIs and literals, well short of exhausting memory
+            max_iri_length: 64 * 1024,
+            max_literal_length: 1024 * 1024,
+        }
+    }
+}
+
+impl ParserConfig {
+    /// The bounds on the parsed ontology, see [`Ontology::set_size_limits`]
+    pub fn size_limits(&self) -> crate::ontology::SizeLimits {
+        let limit = |value: usize| (value > 0).then_some(value);
+        crate::ontology::SizeLimits {
+            max_axioms: limit(self.max_axioms),
+            max_entities: limit(self.max_entities),
+        }
+    }
+
+    /// The bounds on single IRIs and literals while tokenizing
+    pub fn token_limits(&self) -> TokenLimits {
+        let limit = |value: usize| (value > 0).then_some(value);
+        TokenLimits {
+            max_iri_length: limit(self.max_iri_length),
+            max_literal_length: limit(self.max_literal_length),
         }
     }
 }
@@ -33,32 +33,91 @@ impl GrammarParser {
         }
     }
 
+    /// Create a grammar parser that expands prefixed names with `prefixes`
+    ///
+    /// Prefix declarations in the document are added to these mappings.
+    pub fn with_prefixes(tokens: Vec<Token>, prefixes: HashMap<String, String>) -> Self {
+        Self {
+            tokens,
+            current: 0,
+            prefixes,
+        }
+    }
+
     /// Parse the entire document
+    ///
+    /// A document holding several ontologies yields the first one; use
+    /// [`parse_documents`](Self::parse_documents) to get all of them.
     pub fn parse_document(&mut self) -> FunctionalSyntaxResult<FunctionalSyntaxAST> {
-        let mut document = FunctionalSyntaxAST::new();
+        Ok(self
+            .parse_documents()?
+            .into_iter()
+            .next()
+            .unwrap_or_else(FunctionalSyntaxAST::new))
+    }
+
+    /// Parse a document holding any number of ontologies
+    ///
+    /// Ontologies may follow each other at the top level or be wrapped in
+    /// `Document(...)`. Prefix declarations apply to every ontology after them,
+    /// and content following a closed `Ontology(<iri>)` header belongs to that
+    /// ontology.
+    pub fn parse_documents(&mut self) -> FunctionalSyntaxResult<Vec<FunctionalSyntaxAST>> {
+        let mut documents: Vec<FunctionalSyntaxAST> = Vec::new();
+        let mut prefixes = Vec::new();
+        let mut open_wrappers = 0;
 
         while !self.is_at_end() {
             if self.match_token(TokenType::Prefix) {
-                self.parse_prefix_declaration(&mut document)?;
+                prefixes.push(self.parse_prefix_declaration()?);
+            } else if self.match_token(TokenType::Document) {
+                self.consume(TokenType::LeftParen, "Expected '(' after Document")?;
+                open_wrappers += 1;
+            } else if open_wrappers > 0 && self.match_token(TokenType::RightParen) {
+                open_wrappers -= 1;
             } else if self.match_token(TokenType::Ontology) {
+                let mut document = Self::document_with_prefixes(&prefixes);
                 self.parse_ontology_declaration(&mut document)?;
+                documents.push(document);
             } else {
                 let content = self.parse_ontology_content()?;
-                document.add_content(content);
+                if documents.is_empty() {
+                    documents.push(Self::document_with_prefixes(&prefixes));
+                }
+                if let Some(document) = documents.last_mut() {
+                    document.add_content(content);
+                }
             }
         }
 
-        Ok(document)
+        if open_wrappers > 0 {
+            return Err(crate::parser::owl_functional::error::grammar_error(
+                "Expected ')' after Document".to_string(),
+            ));
+        }
+
+        Ok(documents)
     }
 
-    /// Parse a prefix declaration
-    fn parse_prefix_declaration(
-        &mut self,
-        document: &mut FunctionalSyntaxAST,
-    ) -> FunctionalSyntaxResult<()> {
+    /// Create an empty document carrying the prefix declarations seen so far
+    fn document_with_prefixes(prefixes: &[(String, String)]) -> FunctionalSyntaxAST {
+        let mut document = FunctionalSyntaxAST::new();
+        for (prefix, namespace) in prefixes {
+            document.add_prefix(prefix.clone(), namespace.clone());
+        }
+        document
+    }
+
+    /// Parse a prefix declaration, returning the prefix and its namespace
+    fn parse_prefix_declaration(&mut self) -> FunctionalSyntaxResult<(String, String)> {
         self.consume(TokenType::LeftParen, "Expected '(' after Prefix")?;
 
-        let prefix = if let TokenType::Identifier = self.peek().token_type {
+        // `ex:` and `:` are read as prefixed names, a bare `ex` as an identifier
+        let prefix = if matches!(
+            self.peek().token_type,
+            TokenType::Identifier | TokenType::IRI
+        ) && !self.peek().lexeme.starts_with('<')
+        {
             let lexeme = self.advance().lexeme.clone();
             // Remove trailing colon if present
             lexeme.trim_end_matches(':').to_string()
@@ -87,12 +146,11 @@ impl GrammarParser {
 
         // Store the prefix mapping
         self.prefixes.insert(prefix.clone(), namespace.clone());
-        document.add_prefix(prefix, namespace);
 
-        Ok(())
+        Ok((prefix, namespace))
     }
 
-    /// Parse an ontology declaration
+    /// Parse an ontology declaration and the content inside it
     fn parse_ontology_declaration(
         &mut self,
         document: &mut FunctionalSyntaxAST,
@@ -101,18 +159,37 @@ impl GrammarParser {
 
         let ontology_iri = if self.match_token(TokenType::IRI) {
             let iri_lexeme = self.previous().lexeme.clone();
-            Some(iri_lexeme[1..iri_lexeme.len() - 1].to_string())
+            Some((*self.resolve_iri(&iri_lexeme)?).as_str().to_string())
         } else {
             None
         };
 
-        // Update document's ontology IRI
+        // Update document's ontology and version IRIs
         if let Some(iri) = ontology_iri {
+            let version = if self.match_token(TokenType::IRI) {
+                let iri_lexeme = self.previous().lexeme.clone();
+                Some((*self.resolve_iri(&iri_lexeme)?).as_str().to_string())
+            } else {
+                None
+            };
             let FunctionalSyntaxAST::OntologyDocument {
                 ontology_iri: doc_iri,
+                version_iri,
                 ..
             } = document;
             *doc_iri = Some(iri);
+            *version_iri = version;
+        }
+
+        while !self.is_at_end() && !self.check(TokenType::RightParen) {
+            if self.match_token(TokenType::Annotation) {
+                let annotation = self.parse_annotation()?;
+                let FunctionalSyntaxAST::OntologyDocument { annotations, .. } = document;
+                annotations.push(annotation);
+                continue;
+            }
+            let content = self.parse_ontology_content()?;
+            document.add_content(content);
         }
 
         self.consume(
@@ -138,30 +215,40 @@ impl GrammarParser {
     fn parse_entity_declaration(&mut self) -> FunctionalSyntaxResult<OntologyContent> {
         self.consume(TokenType::LeftParen, "Expected '(' after Declaration")?;
 
-        let declaration = if self.match_token(TokenType::Class) {
-            let class = self.parse_class()?;
-            EntityDeclaration::Class(class)
-        } else if self.match_token(TokenType::ObjectProperty) {
-            let prop = self.parse_object_property()?;
-            EntityDeclaration::ObjectProperty(prop)
-        } else if self.match_token(TokenType::DataProperty) {
-            let prop = self.parse_data_property()?;
-            EntityDeclaration::DataProperty(prop)
-        } else if self.match_token(TokenType::NamedIndividual) {
-            let individual = self.parse_named_individual()?;
-            EntityDeclaration::NamedIndividual(individual)
-        } else if self.match_token(TokenType::AnonymousIndividual) {
-            let individual = self.parse_anonymous_individual()?;
-            EntityDeclaration::AnonymousIndividual(individual)
-        } else if self.match_token(TokenType::AnnotationProperty) {
-            let prop = self.parse_annotation_property()?;
-            EntityDeclaration::AnnotationProperty(prop)
+        let entity_type = if self.is_at_end() {
+            TokenType::EOF
         } else {
-            return Err(crate::parser::owl_functional::error::grammar_error(
-                "Expected entity type in declaration".to_string(),
-            ));
+            self.advance().token_type.clone()
+        };
+        // The entity IRI is normally wrapped as in `Class(:A)`
+        let wrapped = self.match_token(TokenType::LeftParen);
+
+        let declaration = match entity_type {
+            TokenType::Class => EntityDeclaration::Class(self.parse_class()?),
+            TokenType::ObjectProperty => {
+                EntityDeclaration::ObjectProperty(self.parse_object_property()?)
+            }
+            TokenType::DataProperty => EntityDeclaration::DataProperty(self.parse_data_property()?),
+            TokenType::NamedIndividual => {
+                EntityDeclaration::NamedIndividual(self.parse_named_individual()?)
+            }
+            TokenType::AnonymousIndividual => {
+                EntityDeclaration::AnonymousIndividual(self.parse_anonymous_individual()?)
+            }
+            TokenType::AnnotationProperty => {
+                EntityDeclaration::AnnotationProperty(self.parse_annotation_property()?)
+            }
+            _ => {
+                return Err(crate::parser::owl_functional::error::grammar_error(
+                    "Expected entity type in declaration".to_string(),
+                ))
+            }
         };
 
+        if wrapped {
+            self.consume(TokenType::RightParen, "Expected ')' after declared entity")?;
+        }
+
         self.consume(TokenType::RightParen, "Expected ')' after declaration")?;
         Ok(OntologyContent::Declaration(declaration))
     }
@@ -196,7 +283,7 @@ impl GrammarParser {
 
     /// Parse various axiom types
     fn parse_axiom(&mut self) -> FunctionalSyntaxResult<OntologyContent> {
-        let token = self.peek();
+        let token = self.advance().clone();
         match token.token_type {
             TokenType::SubClassOf => self.parse_subclass_of_axiom(),
             TokenType::EquivalentClasses => self.parse_equivalent_classes_axiom(),
@@ -236,21 +323,13 @@ impl GrammarParser {
     fn parse_equivalent_classes_axiom(&mut self) -> FunctionalSyntaxResult<OntologyContent> {
         self.consume(TokenType::LeftParen, "Expected '(' after EquivalentClasses")?;
 
-        let mut class_iris = Vec::new();
+        let mut class_expressions = Vec::new();
 
         while !self.check(TokenType::RightParen) {
-            let class_expr = self.parse_class_expression()?;
-            if let crate::axioms::class_expressions::ClassExpression::Class(class) = class_expr {
-                class_iris.push(class.iri().clone());
-            } else {
-                return Err(crate::parser::owl_functional::error::grammar_error(
-                    "EquivalentClasses requires simple class expressions".to_string(),
-                ));
-            }
+            class_expressions.push(self.parse_class_expression()?);
 
-            if !self.check(TokenType::RightParen) {
-                self.advance(); // Skip space/comma
-            }
+            // Operands are separated by whitespace, optionally by commas
+            self.match_token(TokenType::Comma);
         }
 
         self.consume(
@@ -258,8 +337,10 @@ impl GrammarParser {
             "Expected ')' after EquivalentClasses axiom",
         )?;
 
-        if class_iris.len() >= 2 {
-            let axiom = Axiom::EquivalentClasses(Box::new(EquivalentClassesAxiom::new(class_iris)));
+        if class_expressions.len() >= 2 {
+            let axiom = Axiom::EquivalentClasses(Box::new(
+                EquivalentClassesAxiom::from_expressions(class_expressions),
+            ));
             Ok(OntologyContent::Axiom(axiom))
         } else {
             Err(crate::parser::owl_functional::error::grammar_error(
@@ -284,9 +365,8 @@ impl GrammarParser {
                 ));
             }
 
-            if !self.check(TokenType::RightParen) {
-                self.advance(); // Skip space/comma
-            }
+            // Operands are separated by whitespace, optionally by commas
+            self.match_token(TokenType::Comma);
         }
 
         self.consume(
@@ -331,7 +411,7 @@ impl GrammarParser {
             "Expected '(' after ObjectPropertyDomain",
         )?;
 
-        let prop = self.parse_object_property_expression()?.to_iri()?;
+        let prop = self.parse_object_property_expression()?;
         let domain = self.parse_class_expression()?;
 
         self.consume(
@@ -339,10 +419,9 @@ impl GrammarParser {
             "Expected ')' after ObjectPropertyDomain axiom",
         )?;
 
-        let axiom = Axiom::ObjectPropertyDomain(Box::new(ObjectPropertyDomainAxiom::new(
-            prop.clone(),
-            domain,
-        )));
+        let axiom = Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::from_expression(prop, domain),
+        ));
         Ok(OntologyContent::Axiom(axiom))
     }
 
@@ -353,7 +432,7 @@ impl GrammarParser {
             "Expected '(' after ObjectPropertyRange",
         )?;
 
-        let prop = self.parse_object_property_expression()?.to_iri()?;
+        let prop = self.parse_object_property_expression()?;
         let range = self.parse_class_expression()?;
 
         self.consume(
@@ -361,10 +440,9 @@ impl GrammarParser {
             "Expected ')' after ObjectPropertyRange axiom",
         )?;
 
-        let axiom = Axiom::ObjectPropertyRange(Box::new(ObjectPropertyRangeAxiom::new(
-            (*prop).clone(),
-            range,
-        )));
+        let axiom = Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::from_expression(prop, range),
+        ));
         Ok(OntologyContent::Axiom(axiom))
     }
 
@@ -419,9 +497,11 @@ impl GrammarParser {
                 let class = self.parse_class()?;
                 Ok(ClassExpression::Class(class))
             }
+            TokenType::IRI => Ok(ClassExpression::Class(self.parse_class()?)),
             TokenType::ObjectIntersectionOf => self.parse_object_intersection_of(),
             TokenType::ObjectUnionOf => self.parse_object_union_of(),
             TokenType::ObjectComplementOf => self.parse_object_complement_of(),
+            TokenType::ObjectHasSelf => self.parse_object_has_self(),
             _ => Err(crate::parser::owl_functional::error::grammar_error(
                 format!("Expected class expression, found: {}", token.lexeme),
             )),
@@ -442,9 +522,8 @@ impl GrammarParser {
         while !self.check(TokenType::RightParen) {
             operands.push(self.parse_class_expression()?);
 
-            if !self.check(TokenType::RightParen) {
-                self.advance(); // Skip space/comma
-            }
+            // Operands are separated by whitespace, optionally by commas
+            self.match_token(TokenType::Comma);
         }
 
         self.consume(
@@ -453,7 +532,7 @@ impl GrammarParser {
         )?;
 
         Ok(ClassExpression::ObjectIntersectionOf(
-            smallvec::SmallVec::from_vec(operands.into_iter().map(Box::new).collect()),
+            smallvec::SmallVec::from_vec(operands.into_iter().map(Arc::new).collect()),
         ))
     }
 
@@ -468,15 +547,14 @@ impl GrammarParser {
         while !self.check(TokenType::RightParen) {
             operands.push(self.parse_class_expression()?);
 
-            if !self.check(TokenType::RightParen) {
-                self.advance(); // Skip space/comma
-            }
+            // Operands are separated by whitespace, optionally by commas
+            self.match_token(TokenType::Comma);
         }
 
         self.consume(TokenType::RightParen, "Expected ')' after ObjectUnionOf")?;
 
         Ok(ClassExpression::ObjectUnionOf(
-            smallvec::SmallVec::from_vec(operands.into_iter().map(Box::new).collect()),
+            smallvec::SmallVec::from_vec(operands.into_iter().map(Arc::new).collect()),
         ))
     }
 
@@ -489,7 +567,7 @@ impl GrammarParser {
             "Expected '(' after ObjectComplementOf",
         )?;
 
-        let operand = Box::new(self.parse_class_expression()?);
+        let operand = Arc::new(self.parse_class_expression()?);
 
         self.consume(
             TokenType::RightParen,
@@ -499,6 +577,20 @@ impl GrammarParser {
         Ok(ClassExpression::ObjectComplementOf(operand))
     }
 
+    /// Parse ObjectHasSelf expression
+    fn parse_object_has_self(
+        &mut self,
+    ) -> FunctionalSyntaxResult<crate::axioms::class_expressions::ClassExpression> {
+        self.advance();
+        self.consume(TokenType::LeftParen, "Expected '(' after ObjectHasSelf")?;
+
+        let property = self.parse_object_property_expression()?;
+
+        self.consume(TokenType::RightParen, "Expected ')' after ObjectHasSelf")?;
+
+        Ok(crate::axioms::class_expressions::ClassExpression::ObjectHasSelf(Box::new(property)))
+    }
+
     /// Parse an object property expression
     fn parse_object_property_expression(
         &mut self,
@@ -611,6 +703,40 @@ impl GrammarParser {
         }
     }
 
+    /// Parse an `Annotation(property value)` on the ontology
+    fn parse_annotation(&mut self) -> FunctionalSyntaxResult<ParsedAnnotation> {
+        self.consume(TokenType::LeftParen, "Expected '(' after Annotation")?;
+        let property = self.parse_annotation_property()?;
+
+        let token = self.advance().clone();
+        let value = match token.token_type {
+            TokenType::IRI => AnnotationValue::IRI(self.resolve_iri(&token.lexeme)?),
+            TokenType::StringLiteral => {
+                let lexical = &token.lexeme[1..token.lexeme.len() - 1];
+                if self.match_token(TokenType::Caret) {
+                    self.consume(TokenType::Caret, "Expected '^^' before datatype")?;
+                    self.consume(TokenType::IRI, "Expected datatype IRI")?;
+                    let datatype = self.resolve_iri(&self.previous().lexeme.clone())?;
+                    AnnotationValue::Literal(Literal::typed(lexical, (*datatype).clone()))
+                } else if self.match_token(TokenType::At) {
+                    self.consume(TokenType::Identifier, "Expected language tag")?;
+                    let language = self.previous().lexeme.clone();
+                    AnnotationValue::Literal(Literal::lang_tagged(lexical, language))
+                } else {
+                    AnnotationValue::Literal(Literal::simple(lexical))
+                }
+            }
+            _ => {
+                return Err(crate::parser::owl_functional::error::grammar_error(
+                    format!("Expected annotation value, found: {}", token.lexeme),
+                ))
+            }
+        };
+
+        self.consume(TokenType::RightParen, "Expected ')' after annotation")?;
+        Ok(ParsedAnnotation::new(property, value))
+    }
+
     /// Parse an annotation property
     fn parse_annotation_property(&mut self) -> FunctionalSyntaxResult<AnnotationProperty> {
         let token = self.peek().clone();
@@ -644,7 +770,11 @@ impl GrammarParser {
 
             let actual_prefix = if prefix.is_empty() { ":" } else { prefix };
 
-            if let Some(namespace) = self.prefixes.get(actual_prefix) {
+            if let Some(namespace) = self
+                .prefixes
+                .get(prefix)
+                .or_else(|| self.prefixes.get(actual_prefix))
+            {
                 let full_iri = format!("{}{}", namespace, local_name);
                 match IRI::new_optimized(&full_iri) {
                     Ok(iri) => Ok(iri),
@@ -4,6 +4,7 @@
 //! tokenization, grammar parsing, and validation to produce OWL ontologies.
 
 use crate::axioms::*;
+use crate::entities::Entity;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
@@ -82,8 +83,30 @@ impl OwlFunctionalSyntaxParser {
         }
     }
 
+    /// Parse a document that may hold several ontologies, one per `Ontology(...)` block
+    ///
+    /// Each ontology carries the IRI declared for it and only its own axioms;
+    /// prefix declarations are shared by the ontologies that follow them.
+    /// [`parse_str`](OntologyParser::parse_str) returns the first of these.
+    pub fn parse_document(&self, content: &str) -> OwlResult<Vec<Ontology>> {
+        let mut parser_copy = OwlFunctionalSyntaxParser::with_config(self.config.clone());
+        parser_copy.prefixes = self.prefixes.clone();
+        parser_copy.parse_ontologies(content)
+    }
+
     /// Parse OWL Functional Syntax content and build an ontology
     fn parse_content(&mut self, content: &str) -> OwlResult<Ontology> {
+        Ok(self
+            .parse_ontologies(content)?
+            .into_iter()
+            .next()
+            .unwrap_or_default())
+    }
+
+    /// Parse OWL Functional Syntax content and build every ontology in it
+    ///
+    /// A document without an `Ontology(...)` block yields a single ontology.
+    fn parse_ontologies(&mut self, content: &str) -> OwlResult<Vec<Ontology>> {
         if self.config.strict_validation && content.trim().is_empty() {
             return Err(OwlError::ValidationError(
                 "Ontology contains no content".to_string(),
@@ -91,38 +114,52 @@ impl OwlFunctionalSyntaxParser {
         }
 
         // Tokenize the input
-        let tokenizer = Tokenizer::new(content);
+        let tokenizer = Tokenizer::new(content).with_limits(self.config.token_limits());
         let tokens = tokenizer
             .tokenize()
             .map_err(|e| OwlError::ParseError(e.to_string()))?;
 
         // Parse grammar
-        let mut grammar_parser = GrammarParser::new(tokens);
-        let ast = grammar_parser
-            .parse_document()
+        let mut grammar_parser = GrammarParser::with_prefixes(tokens, self.prefixes.clone());
+        let mut asts = grammar_parser
+            .parse_documents()
             .map_err(|e| OwlError::ParseError(e.to_string()))?;
+        if asts.is_empty() {
+            asts.push(FunctionalSyntaxAST::new());
+        }
+
+        let mut ontologies = Vec::with_capacity(asts.len());
+        for ast in &asts {
+            // Validate semantic structure
+            self.validator
+                .validate_document(ast)
+                .map_err(|e| OwlError::ValidationError(e.to_string()))?;
 
-        // Validate semantic structure
-        self.validator
-            .validate_document(&ast)
-            .map_err(|e| OwlError::ValidationError(e.to_string()))?;
+            // Convert AST to ontology
+            let mut ontology = self.ast_to_ontology(ast)?;
+
+            if self.config.deduplicate_on_load {
+                ontology.compact()?;
+            }
 
-        // Convert AST to ontology
-        let ontology = self.ast_to_ontology(&ast)?;
+            // Prefixes are handled internally by the parser for IRI resolution
 
-        // Prefixes are handled internally by the parser for IRI resolution
+            // Final validation
+            if self.config.strict_validation {
+                self.validator.validate_ontology(&ontology)?;
+            }
 
-        // Final validation
-        if self.config.strict_validation {
-            self.validator.validate_ontology(&ontology)?;
+            ontologies.push(ontology);
         }
 
-        Ok(ontology)
+        Ok(ontologies)
     }
 
     /// Convert an AST to an ontology
     fn ast_to_ontology(&self, ast: &FunctionalSyntaxAST) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
 
         // Set ontology IRI if present
         let FunctionalSyntaxAST::OntologyDocument { ontology_iri, .. } = ast;
@@ -132,6 +169,19 @@ impl OwlFunctionalSyntaxParser {
             })?;
             ontology.set_iri((*iri).clone());
         }
+        let FunctionalSyntaxAST::OntologyDocument { version_iri, .. } = ast;
+        if let Some(iri_str) = version_iri {
+            let iri = IRI::new_optimized(iri_str).map_err(|e| {
+                OwlError::ParseError(format!("Invalid version IRI '{}': {}", iri_str, e))
+            })?;
+            ontology.set_version_iri((*iri).clone());
+        }
+        for annotation in ast.annotations() {
+            ontology.add_annotation(Annotation::new(
+                (**annotation.property.iri()).clone(),
+                annotation.value.clone(),
+            ));
+        }
 
         // Process all content
         for content in ast.content() {
@@ -258,6 +308,19 @@ impl OntologyParser for OwlFunctionalSyntaxParser {
     }
 }
 
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for OwlFunctionalSyntaxParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        let prefixes = self.prefixes.clone();
+        Box::new(move |content| {
+            let mut parser = Self::with_config(config);
+            parser.prefixes = prefixes;
+            parser.parse_content(content)
+        })
+    }
+}
+
 impl Default for OwlFunctionalSyntaxParser {
     fn default() -> Self {
         Self::new()
@@ -14,6 +14,10 @@ pub enum FunctionalSyntaxAST {
     OntologyDocument {
         /// The ontology IRI
         ontology_iri: Option<String>,
+        /// The version IRI
+        version_iri: Option<String>,
+        /// Annotations on the ontology
+        annotations: Vec<ParsedAnnotation>,
         /// Prefix declarations
         prefixes: Vec<PrefixDeclaration>,
         /// Ontology content (axioms and declarations)
@@ -81,6 +85,8 @@ impl FunctionalSyntaxAST {
     pub fn new() -> Self {
         Self::OntologyDocument {
             ontology_iri: None,
+            version_iri: None,
+            annotations: Vec::new(),
             prefixes: Vec::new(),
             content: Vec::new(),
         }
@@ -100,6 +106,13 @@ impl FunctionalSyntaxAST {
         contents.push(content);
     }
 
+    /// Get the annotations on the ontology
+    pub fn annotations(&self) -> &[ParsedAnnotation] {
+        match self {
+            Self::OntologyDocument { annotations, .. } => annotations,
+        }
+    }
+
     /// Get all prefix declarations
     pub fn prefixes(&self) -> &[PrefixDeclaration] {
         match self {
@@ -3,6 +3,7 @@
 //! This module implements lexical analysis for OWL Functional Syntax,
 //! converting input text into a stream of tokens for parsing.
 
+use crate::parser::common::TokenLimits;
 use crate::parser::owl_functional::error::{FunctionalSyntaxError, FunctionalSyntaxResult};
 use std::str::CharIndices;
 
@@ -11,6 +12,7 @@ use std::str::CharIndices;
 pub enum TokenType {
     // Keywords
     Prefix,
+    Document,
     Ontology,
     Declaration,
     Class,
@@ -84,6 +86,7 @@ pub enum TokenType {
     AnnotationPropertyDomain,
     AnnotationPropertyRange,
     Import,
+    Annotation,
 
     // Property characteristics
     Functional,
@@ -159,6 +162,7 @@ impl Token {
         matches!(
             self.token_type,
             TokenType::Prefix
+                | TokenType::Document
                 | TokenType::Ontology
                 | TokenType::Declaration
                 | TokenType::Class
@@ -203,6 +207,7 @@ impl Token {
                 | TokenType::AnnotationPropertyDomain
                 | TokenType::AnnotationPropertyRange
                 | TokenType::Import
+                | TokenType::Annotation
         )
     }
 
@@ -239,6 +244,8 @@ pub struct Tokenizer<'a> {
     position: usize,
     /// Whether we're at the end of input
     at_end: bool,
+    /// Bounds on the length of IRIs and literals
+    limits: TokenLimits,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -251,9 +258,16 @@ impl<'a> Tokenizer<'a> {
             column: 1,
             position: 0,
             at_end: false,
+            limits: TokenLimits::default(),
         }
     }
 
+    /// Reject IRIs and literals longer than the given limits
+    pub fn with_limits(mut self, limits: TokenLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Get the next token from the input
     pub fn next_token(&mut self) -> FunctionalSyntaxResult<Token> {
         self.skip_whitespace();
@@ -329,23 +343,19 @@ impl<'a> Tokenizer<'a> {
             }
             ':' => {
                 self.column += 1;
-                Ok(Token::new(
-                    TokenType::Colon,
-                    ":".to_string(),
-                    self.line,
-                    start_column,
-                    pos,
-                ))
+                Ok(self.parse_prefixed_name(String::from(":"), pos, start_column))
             }
             '#' => {
+                // A comment runs to the end of the line
                 self.column += 1;
-                Ok(Token::new(
-                    TokenType::Hash,
-                    "#".to_string(),
-                    self.line,
-                    start_column,
-                    pos,
-                ))
+                while let Some((_, next)) = self.chars.clone().next() {
+                    if next == '\n' {
+                        break;
+                    }
+                    self.chars.next();
+                    self.column += 1;
+                }
+                self.next_token()
             }
             '<' => self.parse_iri(pos, start_column),
             '>' => {
@@ -379,8 +389,8 @@ impl<'a> Tokenizer<'a> {
                 ))
             }
             '"' => self.parse_string_literal(pos, start_column),
-            '0'..='9' | '-' => self.parse_number_literal(pos, start_column),
-            'a'..='z' | 'A'..='Z' | '_' => self.parse_identifier_or_keyword(pos, start_column),
+            '0'..='9' | '-' => self.parse_number_literal(ch, pos, start_column),
+            'a'..='z' | 'A'..='Z' | '_' => self.parse_identifier_or_keyword(ch, pos, start_column),
             '\n' => {
                 self.line += 1;
                 self.column = 1;
@@ -423,6 +433,13 @@ impl<'a> Tokenizer<'a> {
                     start_pos,
                 ));
             }
+            if let Some(message) = self.limits.iri_overflow(iri.len() - 1) {
+                return Err(FunctionalSyntaxError::Syntax {
+                    message,
+                    line: self.line,
+                    column: start_column,
+                });
+            }
         }
 
         Err(FunctionalSyntaxError::Tokenization(
@@ -460,6 +477,13 @@ impl<'a> Tokenizer<'a> {
                     literal.push(esc_ch);
                 }
             }
+            if let Some(message) = self.limits.literal_overflow(literal.len() - 1) {
+                return Err(FunctionalSyntaxError::Syntax {
+                    message,
+                    line: self.line,
+                    column: start_column,
+                });
+            }
         }
 
         Err(FunctionalSyntaxError::Tokenization(
@@ -470,11 +494,11 @@ impl<'a> Tokenizer<'a> {
     /// Parse a number literal
     fn parse_number_literal(
         &mut self,
+        first: char,
         start_pos: usize,
         start_column: usize,
     ) -> FunctionalSyntaxResult<Token> {
-        let mut number = String::new();
-        number.push('-'); // If it started with '-'
+        let mut number = String::from(first);
         self.column += 1;
 
         while let Some((pos, ch)) = self.chars.clone().next() {
@@ -504,10 +528,12 @@ impl<'a> Tokenizer<'a> {
     /// Parse an identifier or keyword
     fn parse_identifier_or_keyword(
         &mut self,
+        first: char,
         start_pos: usize,
         start_column: usize,
     ) -> FunctionalSyntaxResult<Token> {
-        let mut identifier = String::new();
+        let mut identifier = String::from(first);
+        self.column += 1;
 
         while let Some((pos, ch)) = self.chars.clone().next() {
             if ch.is_alphanumeric() || ch == '_' || ch == '-' {
@@ -523,9 +549,18 @@ impl<'a> Tokenizer<'a> {
             }
         }
 
+        // A colon right after the identifier makes it the prefix of a prefixed name
+        if let Some((_, ':')) = self.chars.clone().next() {
+            self.chars.next();
+            self.column += 1;
+            identifier.push(':');
+            return Ok(self.parse_prefixed_name(identifier, start_pos, start_column));
+        }
+
         // Check if it's a keyword
         let token_type = match identifier.as_str() {
             "Prefix" => TokenType::Prefix,
+            "Document" => TokenType::Document,
             "Ontology" => TokenType::Ontology,
             "Declaration" => TokenType::Declaration,
             "Class" => TokenType::Class,
@@ -570,6 +605,8 @@ impl<'a> Tokenizer<'a> {
             "AnnotationPropertyDomain" => TokenType::AnnotationPropertyDomain,
             "AnnotationPropertyRange" => TokenType::AnnotationPropertyRange,
             "Import" => TokenType::Import,
+            "ObjectHasSelf" => TokenType::ObjectHasSelf,
+            "Annotation" => TokenType::Annotation,
             _ => TokenType::Identifier,
         };
 
@@ -582,6 +619,30 @@ impl<'a> Tokenizer<'a> {
         ))
     }
 
+    /// Finish a prefixed name such as `ex:Person` once its prefix and colon are read
+    ///
+    /// Prefixed names are IRI tokens; the grammar expands them with the
+    /// declared prefixes.
+    fn parse_prefixed_name(
+        &mut self,
+        mut name: String,
+        start_pos: usize,
+        start_column: usize,
+    ) -> Token {
+        while let Some((pos, ch)) = self.chars.clone().next() {
+            if ch.is_alphanumeric() || matches!(ch, '_' | '-' | '.') {
+                self.chars.next();
+                self.position = pos;
+                self.column += 1;
+                name.push(ch);
+            } else {
+                break;
+            }
+        }
+
+        Token::new(TokenType::IRI, name, self.line, start_column, start_pos)
+    }
+
     /// Skip whitespace characters
     fn skip_whitespace(&mut self) {
         while let Some((_, ch)) = self.chars.clone().next() {
@@ -600,10 +661,12 @@ impl<'a> Tokenizer<'a> {
 
         loop {
             let token = self.next_token()?;
-            if token.token_type == TokenType::EOF {
-                break;
+            match token.token_type {
+                TokenType::EOF => break,
+                // Line breaks are insignificant to the grammar
+                TokenType::Newline => continue,
+                _ => tokens.push(token),
             }
-            tokens.push(token);
         }
 
         Ok(tokens)
@@ -221,8 +221,8 @@ impl FunctionalSyntaxValidator {
                 self.validate_class_expression(subclass_axiom.super_class())?;
             }
             Axiom::EquivalentClasses(equiv_axiom) => {
-                for class_iri in equiv_axiom.classes() {
-                    self.validate_entity_reference(class_iri, EntityType::Class)?;
+                for class_expr in equiv_axiom.class_expressions() {
+                    self.validate_class_expression(class_expr)?;
                 }
             }
             Axiom::DisjointClasses(disjoint_axiom) => {
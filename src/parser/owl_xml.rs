@@ -1,18 +1,38 @@
 //! OWL/XML format parser for OWL2 ontologies
 //!
 //! Implements parsing of the OWL/XML serialization format using simple XML parsing.
-
-use crate::axioms::class_expressions::ClassExpression;
-use crate::axioms::SubClassOfAxiom;
+//!
+//! Every axiom kind of the [`Axiom`] enum that has an OWL/XML counterpart is
+//! recognised. Qualified cardinality restrictions are only kept exactly when
+//! they appear as `SubClassOf(owl:Thing, ...)`, where they map onto the
+//! qualified cardinality axioms; elsewhere the filler is approximated because
+//! [`ClassExpression`] has no qualified cardinality variants. RDF collections,
+//! containers and reification have no OWL/XML syntax and are never produced.
+
+use crate::axioms::class_expressions::{ClassExpression, DataRange, FacetRestriction};
+use crate::axioms::*;
 use crate::entities::*;
-use crate::error::OwlResult;
+use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use crate::parser::common::unescape_xml;
+use crate::parser::rdf_xml_common::check_xml_limits;
 use crate::parser::{OntologyParser, ParserConfig};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
+/// OWL namespace, also the default namespace of OWL/XML documents
+pub(crate) const OWL_NAMESPACE: &str = "http://www.w3.org/2002/07/owl#";
+
+/// Prefixes that abbreviated IRIs may use without declaring them
+pub(crate) const STANDARD_PREFIXES: [(&str, &str); 4] = [
+    ("owl", OWL_NAMESPACE),
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
 /// OWL/XML format parser
 pub struct OwlXmlParser {
     config: ParserConfig,
@@ -27,7 +47,10 @@ impl OwlXmlParser {
 
     /// Create a new OWL/XML parser with custom configuration
     pub fn with_config(config: ParserConfig) -> Self {
-        let mut namespaces = HashMap::new();
+        let mut namespaces: HashMap<String, String> = STANDARD_PREFIXES
+            .iter()
+            .map(|(prefix, namespace)| (prefix.to_string(), namespace.to_string()))
+            .collect();
         for (prefix, namespace) in &config.prefixes {
             namespaces.insert(prefix.clone(), namespace.clone());
         }
@@ -36,7 +59,11 @@ impl OwlXmlParser {
 
     /// Parse OWL/XML content and build an ontology
     fn parse_content(&mut self, content: &str) -> OwlResult<Ontology> {
+        check_xml_limits(content, &self.config)?;
+
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
 
         // Simple XML parsing for OWL/XML constructs
         if let Ok(document) = self.parse_xml_document(content) {
@@ -54,6 +81,10 @@ impl OwlXmlParser {
             }
         }
 
+        if self.config.deduplicate_on_load {
+            ontology.compact()?;
+        }
+
         // Resolve imports if configured to do so
         if self.config.resolve_imports {
             if let Err(e) = ontology.resolve_imports() {
@@ -88,6 +119,20 @@ impl OwlXmlParser {
             // Parse tags in the line
             let mut pos = 0;
             while pos < line.len() {
+                // Text between tags is the content of the innermost open element
+                let text_end = line[pos..]
+                    .find('<')
+                    .map_or(line.len(), |start| pos + start);
+                let text = &line[pos..text_end];
+                if !text.trim().is_empty() {
+                    if let Some((open_element, _)) = element_stack.last_mut() {
+                        if !open_element.content.is_empty() {
+                            open_element.content.push('\n');
+                        }
+                        open_element.content.push_str(&unescape_xml(text));
+                    }
+                }
+
                 if let Some(tag_start) = line[pos..].find('<') {
                     let tag_start_pos = pos + tag_start;
                     if let Some(tag_end) = line[tag_start_pos..].find('>') {
@@ -133,6 +178,16 @@ impl OwlXmlParser {
                                 let attr_content = &tag_content[tag_name.len()..];
                                 self.parse_attributes(attr_content, &mut element);
 
+                                // Register prefixes used by abbreviated IRIs
+                                if element.local_name() == "Prefix" {
+                                    if let (Some(name), Some(iri)) = (
+                                        element.attributes.get("name"),
+                                        element.attributes.get("IRI"),
+                                    ) {
+                                        self.namespaces.insert(name.clone(), iri.clone());
+                                    }
+                                }
+
                                 // For self-closing tags, add to parent immediately
                                 if is_self_closing {
                                     if let Some((parent_element, _)) = element_stack.last_mut() {
@@ -170,7 +225,7 @@ impl OwlXmlParser {
                 let key = &part[..eq_pos];
                 let value = &part[eq_pos + 1..];
                 if value.len() >= 2 && (value.starts_with('"') || value.starts_with('\'')) {
-                    let clean_value = &value[1..value.len() - 1];
+                    let clean_value = &unescape_xml(&value[1..value.len() - 1]);
                     element
                         .attributes
                         .insert(key.to_string(), clean_value.to_string());
@@ -227,18 +282,14 @@ impl OwlXmlParser {
         element: &XmlElement,
         processed_ids: &mut std::collections::HashSet<String>,
     ) -> OwlResult<()> {
-        // Create a unique ID for this element based on its name and key attributes
-        let element_id = if let Some(iri) = element.attributes.get("IRI") {
-            format!("{}:{}", element.name, iri)
-        } else {
-            element.name.clone()
-        };
-
-        // Only process if we haven't seen this element before
-        if processed_ids.insert(element_id.clone()) {
-            self.process_owl_xml_element_internal(ontology, element)?;
+        // Entity references are deduplicated by name and IRI; axioms carry no
+        // identifying attribute, so every axiom element is processed
+        if let Some(iri) = element.attributes.get("IRI") {
+            if !processed_ids.insert(format!("{}:{}", element.name, iri)) {
+                return Ok(());
+            }
         }
-        Ok(())
+        self.process_owl_xml_element_internal(ontology, element)
     }
 
     /// Process element and all its children recursively with tracking
@@ -276,17 +327,65 @@ impl OwlXmlParser {
         ontology: &mut Ontology,
         element: &XmlElement,
     ) -> OwlResult<()> {
-        match element.name.as_str() {
+        match element.local_name() {
             "Ontology" => {
-                if let Some(iri) = element.attributes.get("IRI") {
+                if let Some(iri) = element
+                    .attributes
+                    .get("ontologyIRI")
+                    .or_else(|| element.attributes.get("IRI"))
+                {
                     ontology.set_iri(IRI::new(iri)?);
                 }
+                if let Some(version_iri) = element.attributes.get("versionIRI") {
+                    ontology.set_version_iri(IRI::new(version_iri)?);
+                }
+                for child in &element.children {
+                    if child.local_name() == "Annotation" {
+                        if let Some(annotation) = self.parse_annotation(child)? {
+                            ontology.add_annotation(annotation);
+                        }
+                    }
+                }
+            }
+            "Import" => {
+                ontology.add_import(IRI::new(element.content.trim())?);
             }
             "Declaration" => {
                 self.process_declaration(ontology, element)?;
             }
-            "SubClassOf" => {
-                self.process_subclass_of(ontology, element)?;
+            "SubClassOf"
+            | "SubObjectPropertyOf"
+            | "EquivalentObjectProperties"
+            | "DisjointObjectProperties"
+            | "InverseObjectProperties"
+            | "ObjectPropertyDomain"
+            | "ObjectPropertyRange"
+            | "FunctionalObjectProperty"
+            | "InverseFunctionalObjectProperty"
+            | "ReflexiveObjectProperty"
+            | "IrreflexiveObjectProperty"
+            | "SymmetricObjectProperty"
+            | "AsymmetricObjectProperty"
+            | "TransitiveObjectProperty"
+            | "SubDataPropertyOf"
+            | "EquivalentDataProperties"
+            | "DisjointDataProperties"
+            | "DataPropertyDomain"
+            | "DataPropertyRange"
+            | "FunctionalDataProperty"
+            | "ClassAssertion"
+            | "ObjectPropertyAssertion"
+            | "NegativeObjectPropertyAssertion"
+            | "DataPropertyAssertion"
+            | "NegativeDataPropertyAssertion"
+            | "SameIndividual"
+            | "DifferentIndividuals"
+            | "HasKey"
+            | "AnnotationAssertion"
+            | "SubAnnotationPropertyOf"
+            | "AnnotationPropertyDomain"
+            | "AnnotationPropertyRange" => {
+                self.process_axiom(ontology, element)?;
             }
             "EquivalentClasses" => {
                 self.process_equivalent_classes(ontology, element)?;
@@ -303,6 +402,11 @@ impl OwlXmlParser {
             "NamedIndividual" => {
                 self.process_named_individual(ontology, element)?;
             }
+            "AnnotationProperty" => {
+                if let Some(iri) = self.element_iri(element)? {
+                    ontology.add_annotation_property(AnnotationProperty::new(iri))?;
+                }
+            }
             // Skip standalone Class elements in OWL/XML - they should be in Declarations
             "Class" => {
                 // Standalone Class elements are skipped in OWL/XML to avoid duplicates
@@ -357,6 +461,10 @@ impl OwlXmlParser {
                         let individual = NamedIndividual::new(IRI::new(&resolved_iri)?);
                         ontology.add_named_individual(individual)?;
                     }
+                    "AnnotationProperty" => {
+                        let prop = AnnotationProperty::new(IRI::new(&resolved_iri)?);
+                        ontology.add_annotation_property(prop)?;
+                    }
                     _ => {}
                 }
             }
@@ -364,32 +472,470 @@ impl OwlXmlParser {
         Ok(())
     }
 
-    /// Process subclass relationship
-    fn process_subclass_of(&self, ontology: &mut Ontology, element: &XmlElement) -> OwlResult<()> {
-        let mut sub_class = None;
-        let mut super_class = None;
+    /// Process an axiom element, skipping (or rejecting in strict mode) malformed ones
+    fn process_axiom(&self, ontology: &mut Ontology, element: &XmlElement) -> OwlResult<()> {
+        match self.parse_axiom(element)? {
+            Some(axiom) => ontology.add_axiom(axiom),
+            None if self.config.strict_validation => Err(OwlError::ParseError(format!(
+                "Malformed or unsupported {} axiom",
+                element.name
+            ))),
+            None => {
+                log::warn!("Skipping malformed or unsupported {} axiom", element.name);
+                Ok(())
+            }
+        }
+    }
 
-        for child in &element.children {
-            if child.name == "Class" {
-                if let Some(iri) = child.attributes.get("IRI") {
-                    if sub_class.is_none() {
-                        sub_class = Some(Class::new(IRI::new(iri)?));
-                    } else {
-                        super_class = Some(Class::new(IRI::new(iri)?));
+    /// Build the axiom described by an OWL/XML axiom element
+    fn parse_axiom(&self, element: &XmlElement) -> OwlResult<Option<Axiom>> {
+        let operands = element.operands();
+        let axiom = match element.local_name() {
+            "SubClassOf" => self.parse_subclass_of(&operands)?,
+            "SubObjectPropertyOf" => self.parse_sub_object_property_of(&operands)?,
+            "EquivalentObjectProperties" => {
+                self.entity_iris(&operands, "ObjectProperty")?
+                    .map(|properties| {
+                        Axiom::EquivalentObjectProperties(Box::new(
+                            EquivalentObjectPropertiesAxiom::new(properties),
+                        ))
+                    })
+            }
+            "DisjointObjectProperties" => {
+                self.entity_iris(&operands, "ObjectProperty")?
+                    .map(|properties| {
+                        Axiom::DisjointObjectProperties(Box::new(
+                            DisjointObjectPropertiesAxiom::new(properties),
+                        ))
+                    })
+            }
+            "InverseObjectProperties" => match operands[..] {
+                [first, second] => match (
+                    self.parse_object_property_expression(first)?,
+                    self.parse_object_property_expression(second)?,
+                ) {
+                    (Some(first), Some(second)) => Some(Axiom::InverseObjectProperties(Box::new(
+                        InverseObjectPropertiesAxiom::new(first, second),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "ObjectPropertyDomain" => match operands[..] {
+                [property, domain] => match (
+                    self.parse_object_property_expression(property)?,
+                    self.parse_class_expression(domain)?,
+                ) {
+                    (Some(property), Some(domain)) => Some(Axiom::ObjectPropertyDomain(Box::new(
+                        ObjectPropertyDomainAxiom::from_expression(property, domain),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "ObjectPropertyRange" => match operands[..] {
+                [property, range] => match (
+                    self.parse_object_property_expression(property)?,
+                    self.parse_class_expression(range)?,
+                ) {
+                    (Some(property), Some(range)) => Some(Axiom::ObjectPropertyRange(Box::new(
+                        ObjectPropertyRangeAxiom::from_expression(property, range),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "FunctionalObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::FunctionalProperty(Box::new(FunctionalPropertyAxiom::new(p)))),
+            "InverseFunctionalObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| {
+                    Axiom::InverseFunctionalProperty(Box::new(InverseFunctionalPropertyAxiom::new(
+                        p,
+                    )))
+                }),
+            "ReflexiveObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::ReflexiveProperty(Box::new(ReflexivePropertyAxiom::new(p)))),
+            "IrreflexiveObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::IrreflexiveProperty(Box::new(IrreflexivePropertyAxiom::new(p)))),
+            "SymmetricObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::SymmetricProperty(Box::new(SymmetricPropertyAxiom::new(p)))),
+            "AsymmetricObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::AsymmetricProperty(Box::new(AsymmetricPropertyAxiom::new(p)))),
+            "TransitiveObjectProperty" => self
+                .single_entity_iri(&operands, "ObjectProperty")?
+                .map(|p| Axiom::TransitiveProperty(Box::new(TransitivePropertyAxiom::new(p)))),
+            "SubDataPropertyOf" => match operands[..] {
+                [sub, sup] => match (
+                    self.entity_iri(sub, "DataProperty")?,
+                    self.entity_iri(sup, "DataProperty")?,
+                ) {
+                    (Some(sub), Some(sup)) => Some(Axiom::SubDataProperty(Box::new(
+                        SubDataPropertyAxiom::new(Arc::new(sub), Arc::new(sup)),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "EquivalentDataProperties" => {
+                self.entity_iris(&operands, "DataProperty")?
+                    .map(|properties| {
+                        Axiom::EquivalentDataProperties(Box::new(
+                            EquivalentDataPropertiesAxiom::new(properties),
+                        ))
+                    })
+            }
+            "DisjointDataProperties" => {
+                self.entity_iris(&operands, "DataProperty")?
+                    .map(|properties| {
+                        Axiom::DisjointDataProperties(Box::new(DisjointDataPropertiesAxiom::new(
+                            properties,
+                        )))
+                    })
+            }
+            "DataPropertyDomain" => match operands[..] {
+                [property, domain] => match (
+                    self.entity_iri(property, "DataProperty")?,
+                    self.parse_class_expression(domain)?,
+                ) {
+                    (Some(property), Some(domain)) => Some(Axiom::DataPropertyDomain(Box::new(
+                        DataPropertyDomainAxiom::new(property, domain),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "DataPropertyRange" => match operands[..] {
+                [property, range] => match (
+                    self.entity_iri(property, "DataProperty")?,
+                    self.entity_iri(range, "Datatype")?,
+                ) {
+                    (Some(property), Some(range)) => Some(Axiom::DataPropertyRange(Box::new(
+                        DataPropertyRangeAxiom::new(property, range),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "FunctionalDataProperty" => self
+                .single_entity_iri(&operands, "DataProperty")?
+                .map(|p| Axiom::FunctionalDataProperty(FunctionalDataPropertyAxiom::new(p))),
+            "ClassAssertion" => match operands[..] {
+                [class_expr, individual] => match (
+                    self.parse_class_expression(class_expr)?,
+                    self.entity_iri(individual, "NamedIndividual")?,
+                ) {
+                    (Some(class_expr), Some(individual)) => Some(Axiom::ClassAssertion(Box::new(
+                        ClassAssertionAxiom::new(Arc::new(individual), class_expr),
+                    ))),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "ObjectPropertyAssertion" => self.parse_object_property_assertion(&operands)?,
+            "NegativeObjectPropertyAssertion" => match operands[..] {
+                [property, subject, object] => match (
+                    self.entity_iri(property, "ObjectProperty")?,
+                    self.entity_iri(subject, "NamedIndividual")?,
+                    self.entity_iri(object, "NamedIndividual")?,
+                ) {
+                    (Some(property), Some(subject), Some(object)) => {
+                        Some(Axiom::NegativeObjectPropertyAssertion(Box::new(
+                            NegativeObjectPropertyAssertionAxiom::new(subject, property, object),
+                        )))
                     }
-                }
+                    _ => None,
+                },
+                _ => None,
+            },
+            "DataPropertyAssertion" => match operands[..] {
+                [property, subject, value] => match (
+                    self.entity_iri(property, "DataProperty")?,
+                    self.entity_iri(subject, "NamedIndividual")?,
+                    self.parse_literal(value)?,
+                ) {
+                    (Some(property), Some(subject), Some(value)) => Some(
+                        Axiom::DataPropertyAssertion(Box::new(DataPropertyAssertionAxiom::new(
+                            Arc::new(subject),
+                            Arc::new(property),
+                            value,
+                        ))),
+                    ),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "NegativeDataPropertyAssertion" => match operands[..] {
+                [property, subject, value] => match (
+                    self.entity_iri(property, "DataProperty")?,
+                    self.entity_iri(subject, "NamedIndividual")?,
+                    self.parse_literal(value)?,
+                ) {
+                    (Some(property), Some(subject), Some(value)) => {
+                        Some(Axiom::NegativeDataPropertyAssertion(Box::new(
+                            NegativeDataPropertyAssertionAxiom::new(subject, property, value),
+                        )))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            "SameIndividual" => {
+                self.entity_iris(&operands, "NamedIndividual")?
+                    .map(|individuals| {
+                        Axiom::SameIndividual(Box::new(SameIndividualAxiom::new(individuals)))
+                    })
+            }
+            "DifferentIndividuals" => {
+                self.entity_iris(&operands, "NamedIndividual")?
+                    .map(|individuals| {
+                        Axiom::DifferentIndividuals(Box::new(DifferentIndividualsAxiom::new(
+                            individuals,
+                        )))
+                    })
+            }
+            "HasKey" => self.parse_has_key(&operands)?,
+            "AnnotationAssertion" => match operands[..] {
+                [property, subject, value] => match (
+                    self.entity_iri(property, "AnnotationProperty")?,
+                    self.element_iri(subject)?,
+                    self.parse_annotation_value(value)?,
+                ) {
+                    (Some(property), Some(subject), Some(value)) => Some(
+                        Axiom::AnnotationAssertion(Box::new(AnnotationAssertionAxiom::new(
+                            Arc::new(property),
+                            Arc::new(subject),
+                            value,
+                        ))),
+                    ),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "SubAnnotationPropertyOf" => match operands[..] {
+                [sub, sup] => match (
+                    self.entity_iri(sub, "AnnotationProperty")?,
+                    self.entity_iri(sup, "AnnotationProperty")?,
+                ) {
+                    (Some(sub), Some(sup)) => Some(Axiom::SubAnnotationPropertyOf(
+                        SubAnnotationPropertyOfAxiom::new(Arc::new(sub), Arc::new(sup)),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "AnnotationPropertyDomain" => match operands[..] {
+                [property, domain] => match (
+                    self.entity_iri(property, "AnnotationProperty")?,
+                    self.element_iri(domain)?,
+                ) {
+                    (Some(property), Some(domain)) => Some(Axiom::AnnotationPropertyDomain(
+                        AnnotationPropertyDomainAxiom::new(Arc::new(property), Arc::new(domain)),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "AnnotationPropertyRange" => match operands[..] {
+                [property, range] => match (
+                    self.entity_iri(property, "AnnotationProperty")?,
+                    self.element_iri(range)?,
+                ) {
+                    (Some(property), Some(range)) => Some(Axiom::AnnotationPropertyRange(
+                        AnnotationPropertyRangeAxiom::new(Arc::new(property), Arc::new(range)),
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+        Ok(axiom)
+    }
+
+    /// Parse a SubClassOf axiom
+    ///
+    /// `SubClassOf(owl:Thing, ObjectMinCardinality(n R C))` and its max, exact
+    /// and data counterparts become qualified cardinality axioms.
+    fn parse_subclass_of(&self, operands: &[&XmlElement]) -> OwlResult<Option<Axiom>> {
+        let [sub_class, super_class] = operands[..] else {
+            return Ok(None);
+        };
+        let Some(sub_class) = self.parse_class_expression(sub_class)? else {
+            return Ok(None);
+        };
+        if sub_class.is_thing() {
+            if let Some(axiom) = self.parse_qualified_cardinality_axiom(super_class)? {
+                return Ok(Some(axiom));
             }
         }
+        Ok(self
+            .parse_class_expression(super_class)?
+            .map(|super_class| {
+                Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(sub_class, super_class)))
+            }))
+    }
 
-        if let (Some(sub), Some(sup)) = (sub_class, super_class) {
-            let subclass_axiom = SubClassOfAxiom::new(
-                crate::axioms::class_expressions::ClassExpression::Class(sub),
-                crate::axioms::class_expressions::ClassExpression::Class(sup),
-            );
-            ontology.add_subclass_axiom(subclass_axiom)?;
+    /// Parse a qualified cardinality restriction as a global cardinality axiom
+    fn parse_qualified_cardinality_axiom(&self, element: &XmlElement) -> OwlResult<Option<Axiom>> {
+        let kind = element.local_name();
+        if !kind.ends_with("Cardinality") {
+            return Ok(None);
+        }
+        let [property, filler] = element.operands()[..] else {
+            return Ok(None);
+        };
+        let cardinality = self.parse_cardinality(element)?;
+
+        if kind.starts_with("Object") {
+            let (Some(property), Some(filler)) = (
+                self.parse_object_property_expression(property)?,
+                self.parse_class_expression(filler)?,
+            ) else {
+                return Ok(None);
+            };
+            if filler.is_thing() {
+                return Ok(None);
+            }
+            return Ok(match kind {
+                "ObjectMinCardinality" => Some(Axiom::ObjectMinQualifiedCardinality(Box::new(
+                    ObjectMinQualifiedCardinalityAxiom::new(cardinality, property, filler),
+                ))),
+                "ObjectMaxCardinality" => Some(Axiom::ObjectMaxQualifiedCardinality(Box::new(
+                    ObjectMaxQualifiedCardinalityAxiom::new(cardinality, property, filler),
+                ))),
+                "ObjectExactCardinality" => Some(Axiom::ObjectExactQualifiedCardinality(Box::new(
+                    ObjectExactQualifiedCardinalityAxiom::new(cardinality, property, filler),
+                ))),
+                _ => None,
+            });
         }
 
-        Ok(())
+        // The data variants reuse the object property expression type for the property
+        let (Some(property), Some(DataRange::Datatype(datatype))) = (
+            self.entity_iri(property, "DataProperty")?,
+            self.parse_data_range(filler)?,
+        ) else {
+            return Ok(None);
+        };
+        let property =
+            ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(property)));
+        let datatype = Arc::new(datatype);
+        Ok(match kind {
+            "DataMinCardinality" => Some(Axiom::DataMinQualifiedCardinality(Box::new(
+                DataMinQualifiedCardinalityAxiom::new(cardinality, property, datatype),
+            ))),
+            "DataMaxCardinality" => Some(Axiom::DataMaxQualifiedCardinality(Box::new(
+                DataMaxQualifiedCardinalityAxiom::new(cardinality, property, datatype),
+            ))),
+            "DataExactCardinality" => Some(Axiom::DataExactQualifiedCardinality(Box::new(
+                DataExactQualifiedCardinalityAxiom::new(cardinality, property, datatype),
+            ))),
+            _ => None,
+        })
+    }
+
+    /// Parse a SubObjectPropertyOf axiom, including property chains
+    fn parse_sub_object_property_of(&self, operands: &[&XmlElement]) -> OwlResult<Option<Axiom>> {
+        let [sub_property, super_property] = operands[..] else {
+            return Ok(None);
+        };
+        let Some(super_property) = self.parse_object_property_expression(super_property)? else {
+            return Ok(None);
+        };
+
+        if sub_property.local_name() == "ObjectPropertyChain" {
+            let chain = parse_all(&sub_property.operands(), |element| {
+                self.parse_object_property_expression(element)
+            })?;
+            return Ok(chain.filter(|chain| !chain.is_empty()).map(|chain| {
+                Axiom::SubPropertyChainOf(Box::new(SubPropertyChainOfAxiom::new(
+                    chain,
+                    super_property,
+                )))
+            }));
+        }
+
+        let Some(sub_property) = self.parse_object_property_expression(sub_property)? else {
+            return Ok(None);
+        };
+        Ok(Some(match (&sub_property, &super_property) {
+            (
+                ObjectPropertyExpression::ObjectProperty(sub),
+                ObjectPropertyExpression::ObjectProperty(sup),
+            ) => Axiom::SubObjectProperty(Box::new(SubObjectPropertyAxiom::new(
+                sub.iri().clone(),
+                sup.iri().clone(),
+            ))),
+            // Inverse properties only fit the chain axiom, as a chain of length one
+            _ => Axiom::SubPropertyChainOf(Box::new(SubPropertyChainOfAxiom::new(
+                vec![sub_property],
+                super_property,
+            ))),
+        }))
+    }
+
+    /// Parse an ObjectPropertyAssertion, normalising inverse properties
+    fn parse_object_property_assertion(
+        &self,
+        operands: &[&XmlElement],
+    ) -> OwlResult<Option<Axiom>> {
+        let [property, subject, object] = operands[..] else {
+            return Ok(None);
+        };
+        let Some(property) = self.parse_object_property_expression(property)? else {
+            return Ok(None);
+        };
+        let (Some(subject), Some(object)) = (
+            self.parse_individual(subject)?,
+            self.parse_individual(object)?,
+        ) else {
+            return Ok(None);
+        };
+
+        let (property, subject, object) = match property {
+            ObjectPropertyExpression::ObjectProperty(property) => (property, subject, object),
+            ObjectPropertyExpression::ObjectInverseOf(inner) => match *inner {
+                ObjectPropertyExpression::ObjectProperty(property) => (property, object, subject),
+                ObjectPropertyExpression::ObjectInverseOf(_) => return Ok(None),
+            },
+        };
+        let Individual::Named(subject) = subject else {
+            return Ok(None);
+        };
+        let object = match object {
+            Individual::Named(object) => PropertyAssertionObject::Named(object.iri().clone()),
+            Individual::Anonymous(object) => PropertyAssertionObject::Anonymous(Box::new(object)),
+        };
+        Ok(Some(Axiom::PropertyAssertion(Box::new(
+            PropertyAssertionAxiom::new_with_object(
+                subject.iri().clone(),
+                property.iri().clone(),
+                object,
+            ),
+        ))))
+    }
+
+    /// Parse a HasKey axiom over object and data properties
+    fn parse_has_key(&self, operands: &[&XmlElement]) -> OwlResult<Option<Axiom>> {
+        let Some((class_expr, properties)) = operands.split_first() else {
+            return Ok(None);
+        };
+        let Some(class_expr) = self.parse_class_expression(class_expr)? else {
+            return Ok(None);
+        };
+        let properties = parse_all(properties, |element| match element.local_name() {
+            "ObjectProperty" | "DataProperty" => Ok(self.element_iri(element)?.map(Arc::new)),
+            _ => Ok(None),
+        })?;
+        Ok(properties
+            .filter(|properties| !properties.is_empty())
+            .map(|properties| Axiom::HasKey(Box::new(HasKeyAxiom::new(class_expr, properties)))))
     }
 
     /// Process object property
@@ -398,8 +944,8 @@ impl OwlXmlParser {
         ontology: &mut Ontology,
         element: &XmlElement,
     ) -> OwlResult<()> {
-        if let Some(iri) = element.attributes.get("IRI") {
-            let prop = ObjectProperty::new(IRI::new(iri)?);
+        if let Some(iri) = self.element_iri(element)? {
+            let prop = ObjectProperty::new(iri);
             ontology.add_object_property(prop)?;
         }
         Ok(())
@@ -411,8 +957,8 @@ impl OwlXmlParser {
         ontology: &mut Ontology,
         element: &XmlElement,
     ) -> OwlResult<()> {
-        if let Some(iri) = element.attributes.get("IRI") {
-            let prop = DataProperty::new(IRI::new(iri)?);
+        if let Some(iri) = self.element_iri(element)? {
+            let prop = DataProperty::new(iri);
             ontology.add_data_property(prop)?;
         }
         Ok(())
@@ -424,96 +970,41 @@ impl OwlXmlParser {
         ontology: &mut Ontology,
         element: &XmlElement,
     ) -> OwlResult<()> {
-        if let Some(iri) = element.attributes.get("IRI") {
-            let individual = NamedIndividual::new(IRI::new(iri)?);
+        if let Some(iri) = self.element_iri(element)? {
+            let individual = NamedIndividual::new(iri);
             ontology.add_named_individual(individual)?;
         }
         Ok(())
     }
 
     /// Process equivalent classes
+    ///
+    /// Operands may be complex class expressions, which makes the axiom a
+    /// definition of its named operands.
     fn process_equivalent_classes(
         &self,
         ontology: &mut Ontology,
         element: &XmlElement,
     ) -> OwlResult<()> {
-        use crate::axioms::class_expressions::ClassExpression;
         use crate::axioms::EquivalentClassesAxiom;
 
         let mut class_descriptions = Vec::new();
-
-        // Process child elements to find class descriptions
-        for child in &element.children {
-            match child.name.as_str() {
-                "Class" | "owl:Class" => {
-                    if let Some(iri) = child.attributes.get("IRI") {
-                        let iri_obj = IRI::new(iri)?;
-                        let class = Class::new(iri_obj);
-                        class_descriptions.push(ClassExpression::Class(class));
-                    } else if let Some(abbreviated_iri) = child.attributes.get("abbreviatedIRI") {
-                        // Handle abbreviated IRI like "owl:Thing"
-                        if let Some(colon_pos) = abbreviated_iri.find(':') {
-                            let prefix = &abbreviated_iri[..colon_pos];
-                            let local = &abbreviated_iri[colon_pos + 1..];
-
-                            if let Some(namespace) = self.namespaces.get(prefix) {
-                                let full_iri = format!("{}{}", namespace, local);
-                                let iri_obj = IRI::new(&full_iri)?;
-                                let class = Class::new(iri_obj);
-                                class_descriptions.push(ClassExpression::Class(class));
-                            }
-                        }
-                    }
-                }
-                "ObjectIntersectionOf" | "owl:ObjectIntersectionOf" => {
-                    // Handle intersection of classes
-                    if let Some(class_expr) = self.parse_object_intersection_of(child)? {
-                        class_descriptions.push(class_expr);
-                    }
-                }
-                "ObjectUnionOf" | "owl:ObjectUnionOf" => {
-                    // Handle union of classes
-                    if let Some(class_expr) = self.parse_object_union_of(child)? {
-                        class_descriptions.push(class_expr);
-                    }
-                }
-                "ObjectComplementOf" | "owl:ObjectComplementOf" => {
-                    // Handle complement of classes
-                    if let Some(class_expr) = self.parse_object_complement_of(child)? {
-                        class_descriptions.push(class_expr);
-                    }
-                }
-                _ => {
-                    // Skip unknown elements
-                    if self.config.strict_validation {
-                        return Err(crate::error::OwlError::ParseError(format!(
-                            "Unknown class description: {}",
-                            child.name
-                        )));
-                    }
+        for child in element.operands() {
+            match self.parse_class_expression(child)? {
+                Some(class_expr) => class_descriptions.push(class_expr),
+                None if self.config.strict_validation => {
+                    return Err(crate::error::OwlError::ParseError(format!(
+                        "Unknown class description: {}",
+                        child.name
+                    )));
                 }
+                None => {}
             }
         }
 
         if class_descriptions.len() >= 2 {
-            // Extract IRIs from ClassExpressions
-            let class_iris: Vec<IRI> = class_descriptions
-                .into_iter()
-                .filter_map(|ce| match ce {
-                    ClassExpression::Class(cls) => Some((**cls.iri()).clone()),
-                    _ => None,
-                })
-                .collect();
-
-            if class_iris.len() >= 2 {
-                let class_arc_iris: Vec<Arc<IRI>> = class_iris.into_iter().map(Arc::new).collect();
-                let axiom = EquivalentClassesAxiom::new(class_arc_iris);
-                ontology.add_equivalent_classes_axiom(axiom)?;
-            } else if self.config.strict_validation {
-                return Err(crate::error::OwlError::ParseError(
-                    "EquivalentClasses requires at least 2 named classes".to_string(),
-                ));
-            }
+            let axiom = EquivalentClassesAxiom::from_expressions(class_descriptions);
+            ontology.add_equivalent_classes_axiom(axiom)?;
         } else if self.config.strict_validation {
             return Err(crate::error::OwlError::ParseError(
                 "EquivalentClasses requires at least 2 class descriptions".to_string(),
@@ -625,14 +1116,16 @@ impl OwlXmlParser {
 
         let mut operands = Vec::new();
 
+        // Leaving out an operand would change the meaning of the expression
         for child in &element.children {
-            if let Some(class_expr) = self.parse_class_expression(child)? {
-                operands.push(Box::new(class_expr));
+            match self.parse_class_expression(child)? {
+                Some(class_expr) => operands.push(Arc::new(class_expr)),
+                None => return Ok(None),
             }
         }
 
         if operands.len() >= 2 {
-            let smallvec: SmallVec<[Box<ClassExpression>; 4]> = operands.into();
+            let smallvec: SmallVec<[Arc<ClassExpression>; 4]> = operands.into();
             Ok(Some(ClassExpression::ObjectIntersectionOf(smallvec)))
         } else {
             Ok(None)
@@ -646,14 +1139,16 @@ impl OwlXmlParser {
 
         let mut operands = Vec::new();
 
+        // Leaving out an operand would change the meaning of the expression
         for child in &element.children {
-            if let Some(class_expr) = self.parse_class_expression(child)? {
-                operands.push(Box::new(class_expr));
+            match self.parse_class_expression(child)? {
+                Some(class_expr) => operands.push(Arc::new(class_expr)),
+                None => return Ok(None),
             }
         }
 
         if operands.len() >= 2 {
-            let smallvec: SmallVec<[Box<ClassExpression>; 4]> = operands.into();
+            let smallvec: SmallVec<[Arc<ClassExpression>; 4]> = operands.into();
             Ok(Some(ClassExpression::ObjectUnionOf(smallvec)))
         } else {
             Ok(None)
@@ -669,7 +1164,7 @@ impl OwlXmlParser {
 
         if let Some(child) = element.children.first() {
             if let Some(class_expr) = self.parse_class_expression(child)? {
-                return Ok(Some(ClassExpression::ObjectComplementOf(Box::new(
+                return Ok(Some(ClassExpression::ObjectComplementOf(Arc::new(
                     class_expr,
                 ))));
             }
@@ -680,38 +1175,403 @@ impl OwlXmlParser {
 
     /// Parse class expression (helper for complex class descriptions)
     fn parse_class_expression(&self, element: &XmlElement) -> OwlResult<Option<ClassExpression>> {
-        use crate::axioms::class_expressions::ClassExpression;
-
-        match element.name.as_str() {
-            "Class" | "owl:Class" => {
-                if let Some(iri) = element.attributes.get("IRI") {
-                    let iri_obj = IRI::new(iri)?;
-                    let class = Class::new(iri_obj);
-                    Ok(Some(ClassExpression::Class(class)))
-                } else if let Some(abbreviated_iri) = element.attributes.get("abbreviatedIRI") {
-                    if let Some(colon_pos) = abbreviated_iri.find(':') {
-                        let prefix = &abbreviated_iri[..colon_pos];
-                        let local = &abbreviated_iri[colon_pos + 1..];
-
-                        if let Some(namespace) = self.namespaces.get(prefix) {
-                            let full_iri = format!("{}{}", namespace, local);
-                            let iri_obj = IRI::new(&full_iri)?;
-                            let class = Class::new(iri_obj);
-                            Ok(Some(ClassExpression::Class(class)))
+        let operands = element.operands();
+        let expression = match element.local_name() {
+            "Class" => self
+                .element_iri(element)?
+                .map(|iri| ClassExpression::Class(Class::new(iri))),
+            "ObjectIntersectionOf" => return self.parse_object_intersection_of(element),
+            "ObjectUnionOf" => return self.parse_object_union_of(element),
+            "ObjectComplementOf" => return self.parse_object_complement_of(element),
+            "ObjectOneOf" => parse_all(&operands, |operand| self.parse_individual(operand))?
+                .filter(|individuals| !individuals.is_empty())
+                .map(|individuals| ClassExpression::ObjectOneOf(Box::new(individuals.into()))),
+            "ObjectSomeValuesFrom" | "ObjectAllValuesFrom" => match operands[..] {
+                [property, filler] => match (
+                    self.parse_object_property_expression(property)?,
+                    self.parse_class_expression(filler)?,
+                ) {
+                    (Some(property), Some(filler)) => {
+                        let (property, filler) = (Box::new(property), Arc::new(filler));
+                        Some(if element.local_name() == "ObjectSomeValuesFrom" {
+                            ClassExpression::ObjectSomeValuesFrom(property, filler)
                         } else {
-                            Ok(None)
-                        }
-                    } else {
-                        Ok(None)
+                            ClassExpression::ObjectAllValuesFrom(property, filler)
+                        })
                     }
-                } else {
-                    Ok(None)
+                    _ => None,
+                },
+                _ => None,
+            },
+            "ObjectHasValue" => match operands[..] {
+                [property, individual] => match (
+                    self.parse_object_property_expression(property)?,
+                    self.parse_individual(individual)?,
+                ) {
+                    (Some(property), Some(individual)) => Some(ClassExpression::ObjectHasValue(
+                        Box::new(property),
+                        individual,
+                    )),
+                    _ => None,
+                },
+                _ => None,
+            },
+            "ObjectHasSelf" => match operands[..] {
+                [property] => self
+                    .parse_object_property_expression(property)?
+                    .map(|property| ClassExpression::ObjectHasSelf(Box::new(property))),
+                _ => None,
+            },
+            "ObjectMinCardinality" | "ObjectMaxCardinality" | "ObjectExactCardinality" => {
+                self.parse_object_cardinality(element)?
+            }
+            "DataSomeValuesFrom" | "DataAllValuesFrom" => match operands[..] {
+                [property, range] => match (
+                    self.parse_data_property_expression(property)?,
+                    self.parse_data_range(range)?,
+                ) {
+                    (Some(property), Some(range)) => {
+                        let (property, range) = (Box::new(property), Box::new(range));
+                        Some(if element.local_name() == "DataSomeValuesFrom" {
+                            ClassExpression::DataSomeValuesFrom(property, range)
+                        } else {
+                            ClassExpression::DataAllValuesFrom(property, range)
+                        })
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            "DataHasValue" => match operands[..] {
+                [property, value] => match (
+                    self.parse_data_property_expression(property)?,
+                    self.parse_literal(value)?,
+                ) {
+                    (Some(property), Some(value)) => {
+                        Some(ClassExpression::DataHasValue(Box::new(property), value))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            },
+            "DataMinCardinality" | "DataMaxCardinality" | "DataExactCardinality" => {
+                self.parse_data_cardinality(element)?
+            }
+            _ => None,
+        };
+        Ok(expression)
+    }
+
+    /// Parse an object cardinality restriction
+    ///
+    /// A qualified restriction cannot be represented exactly by
+    /// [`ClassExpression`]: `≥1 R.C` becomes `∃R.C` and `≤0 R.C` becomes
+    /// `∀R.¬C`. Any other filler is an error with strict validation; otherwise
+    /// the restriction, and so the axiom using it, is skipped with a warning.
+    fn parse_object_cardinality(&self, element: &XmlElement) -> OwlResult<Option<ClassExpression>> {
+        let operands = element.operands();
+        let Some(property) = operands
+            .first()
+            .map(|property| self.parse_object_property_expression(property))
+            .transpose()?
+            .flatten()
+        else {
+            return Ok(None);
+        };
+        let cardinality = self.parse_cardinality(element)?;
+        let filler = match operands.get(1) {
+            Some(filler) => match self.parse_class_expression(filler)? {
+                Some(filler) => Some(filler).filter(|filler| !filler.is_thing()),
+                None => return Ok(None),
+            },
+            None => None,
+        };
+
+        let kind = element.local_name();
+        let property = Box::new(property);
+        Ok(Some(match (kind, filler) {
+            ("ObjectMinCardinality", Some(filler)) if cardinality == 1 => {
+                ClassExpression::ObjectSomeValuesFrom(property, Arc::new(filler))
+            }
+            ("ObjectMaxCardinality", Some(filler)) if cardinality == 0 => {
+                ClassExpression::ObjectAllValuesFrom(
+                    property,
+                    Arc::new(ClassExpression::ObjectComplementOf(Arc::new(filler))),
+                )
+            }
+            (_, Some(_)) => return self.unsupported_qualified_cardinality(kind),
+            (_, None) => match kind {
+                "ObjectMinCardinality" => {
+                    ClassExpression::ObjectMinCardinality(cardinality, property)
                 }
+                "ObjectMaxCardinality" => {
+                    ClassExpression::ObjectMaxCardinality(cardinality, property)
+                }
+                _ => ClassExpression::ObjectExactCardinality(cardinality, property),
+            },
+        }))
+    }
+
+    /// Parse a data cardinality restriction, approximating qualified ones like
+    /// [`Self::parse_object_cardinality`]
+    fn parse_data_cardinality(&self, element: &XmlElement) -> OwlResult<Option<ClassExpression>> {
+        let operands = element.operands();
+        let Some(property) = operands
+            .first()
+            .map(|property| self.parse_data_property_expression(property))
+            .transpose()?
+            .flatten()
+        else {
+            return Ok(None);
+        };
+        let cardinality = self.parse_cardinality(element)?;
+        let range = match operands.get(1) {
+            Some(range) => match self.parse_data_range(range)? {
+                Some(range) => Some(range).filter(|range| {
+                    !matches!(range, DataRange::Datatype(iri) if iri.as_str() == RDFS_LITERAL)
+                }),
+                None => return Ok(None),
+            },
+            None => None,
+        };
+
+        let kind = element.local_name();
+        let property = Box::new(property);
+        Ok(Some(match (kind, range) {
+            ("DataMinCardinality", Some(range)) if cardinality == 1 => {
+                ClassExpression::DataSomeValuesFrom(property, Box::new(range))
             }
+            ("DataMaxCardinality", Some(range)) if cardinality == 0 => {
+                ClassExpression::DataAllValuesFrom(
+                    property,
+                    Box::new(DataRange::DataComplementOf(Box::new(range))),
+                )
+            }
+            (_, Some(_)) => return self.unsupported_qualified_cardinality(kind),
+            (_, None) => match kind {
+                "DataMinCardinality" => ClassExpression::DataMinCardinality(cardinality, property),
+                "DataMaxCardinality" => ClassExpression::DataMaxCardinality(cardinality, property),
+                _ => ClassExpression::DataExactCardinality(cardinality, property),
+            },
+        }))
+    }
+
+    /// Reject a qualified cardinality restriction with strict validation, or skip it
+    ///
+    /// Dropping the filler instead would turn `≤n R.C` into the stronger `≤n R`.
+    fn unsupported_qualified_cardinality(&self, kind: &str) -> OwlResult<Option<ClassExpression>> {
+        if self.config.strict_validation {
+            return Err(OwlError::ParseError(format!(
+                "Qualified {} with this cardinality is not supported",
+                kind
+            )));
+        }
+        log::warn!("Skipping a qualified {} that cannot be represented", kind);
+        Ok(None)
+    }
+
+    /// Read the `cardinality` attribute of a cardinality restriction
+    fn parse_cardinality(&self, element: &XmlElement) -> OwlResult<u32> {
+        let value = element.attributes.get("cardinality").ok_or_else(|| {
+            OwlError::ParseError(format!("{} is missing its cardinality", element.name))
+        })?;
+        value.trim().parse().map_err(|_| {
+            OwlError::ParseError(format!(
+                "Invalid cardinality '{}' in {}",
+                value, element.name
+            ))
+        })
+    }
+
+    /// Parse an object property or its inverse
+    fn parse_object_property_expression(
+        &self,
+        element: &XmlElement,
+    ) -> OwlResult<Option<ObjectPropertyExpression>> {
+        match element.local_name() {
+            "ObjectProperty" => Ok(self.element_iri(element)?.map(|iri| {
+                ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(iri)))
+            })),
+            "ObjectInverseOf" => match element.operands()[..] {
+                [inner] => Ok(self
+                    .parse_object_property_expression(inner)?
+                    .map(|inner| ObjectPropertyExpression::ObjectInverseOf(Box::new(inner)))),
+                _ => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse a data property
+    fn parse_data_property_expression(
+        &self,
+        element: &XmlElement,
+    ) -> OwlResult<Option<DataPropertyExpression>> {
+        Ok(self
+            .entity_iri(element, "DataProperty")?
+            .map(|iri| DataPropertyExpression::DataProperty(DataProperty::new(iri))))
+    }
+
+    /// Parse a data range
+    fn parse_data_range(&self, element: &XmlElement) -> OwlResult<Option<DataRange>> {
+        let operands = element.operands();
+        let range = match element.local_name() {
+            "Datatype" => self.element_iri(element)?.map(DataRange::Datatype),
+            "DataIntersectionOf" => parse_all(&operands, |operand| self.parse_data_range(operand))?
+                .map(DataRange::DataIntersectionOf),
+            "DataUnionOf" => parse_all(&operands, |operand| self.parse_data_range(operand))?
+                .map(DataRange::DataUnionOf),
+            "DataComplementOf" => match operands[..] {
+                [inner] => self
+                    .parse_data_range(inner)?
+                    .map(|inner| DataRange::DataComplementOf(Box::new(inner))),
+                _ => None,
+            },
+            "DataOneOf" => parse_all(&operands, |operand| self.parse_literal(operand))?
+                .map(DataRange::DataOneOf),
+            "DatatypeRestriction" => match operands.split_first() {
+                Some((datatype, facets)) => {
+                    let facets = parse_all(facets, |facet| {
+                        let (Some(name), [value]) =
+                            (facet.attributes.get("facet"), &facet.operands()[..])
+                        else {
+                            return Ok(None);
+                        };
+                        let facet = IRI::new(name)?;
+                        Ok(self
+                            .parse_literal(value)?
+                            .map(|value| FacetRestriction::new(facet, value)))
+                    })?;
+                    match (self.entity_iri(datatype, "Datatype")?, facets) {
+                        (Some(datatype), Some(facets)) => {
+                            Some(DataRange::DatatypeRestriction(datatype, facets))
+                        }
+                        _ => None,
+                    }
+                }
+                None => None,
+            },
+            _ => None,
+        };
+        Ok(range)
+    }
+
+    /// Parse a named or anonymous individual
+    fn parse_individual(&self, element: &XmlElement) -> OwlResult<Option<Individual>> {
+        match element.local_name() {
+            "NamedIndividual" => Ok(self
+                .element_iri(element)?
+                .map(|iri| Individual::Named(NamedIndividual::new(iri)))),
+            "AnonymousIndividual" => Ok(element
+                .attributes
+                .get("nodeID")
+                .map(|node_id| Individual::Anonymous(AnonymousIndividual::new(node_id.clone())))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse a `Literal` element
+    fn parse_literal(&self, element: &XmlElement) -> OwlResult<Option<Literal>> {
+        if element.local_name() != "Literal" {
+            return Ok(None);
+        }
+        let value = element.content.clone();
+        if let Some(language) = element.attributes.get("xml:lang") {
+            return Ok(Some(Literal::lang_tagged(value, language.clone())));
+        }
+        match element.attributes.get("datatypeIRI") {
+            Some(datatype) => Ok(Some(Literal::typed(value, IRI::new(datatype)?))),
+            None => Ok(Some(Literal::simple(value))),
+        }
+    }
+
+    /// Parse an `Annotation` element on the ontology
+    fn parse_annotation(&self, element: &XmlElement) -> OwlResult<Option<Annotation>> {
+        let [property, value] = &element.children[..] else {
+            return Ok(None);
+        };
+        match (
+            self.entity_iri(property, "AnnotationProperty")?,
+            self.parse_annotation_value(value)?,
+        ) {
+            (Some(property), Some(value)) => Ok(Some(Annotation::new(property, value))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse the value of an annotation assertion
+    fn parse_annotation_value(&self, element: &XmlElement) -> OwlResult<Option<AnnotationValue>> {
+        match element.local_name() {
+            "Literal" => Ok(self.parse_literal(element)?.map(AnnotationValue::Literal)),
+            "AnonymousIndividual" => Ok(element
+                .attributes
+                .get("nodeID")
+                .map(|node_id| AnnotationValue::AnonymousIndividual(node_id.clone()))),
+            _ => Ok(self
+                .element_iri(element)?
+                .map(|iri| AnnotationValue::IRI(Arc::new(iri)))),
+        }
+    }
+
+    /// Resolve the IRI of an entity reference or an `IRI`/`AbbreviatedIRI` element
+    fn element_iri(&self, element: &XmlElement) -> OwlResult<Option<IRI>> {
+        if let Some(iri) = element.attributes.get("IRI") {
+            return IRI::new(iri).map(Some);
+        }
+        if let Some(abbreviated_iri) = element.attributes.get("abbreviatedIRI") {
+            return self.expand_abbreviated_iri(abbreviated_iri);
+        }
+        match element.local_name() {
+            "IRI" => IRI::new(element.content.trim()).map(Some),
+            "AbbreviatedIRI" => self.expand_abbreviated_iri(element.content.trim()),
+            _ => Ok(None),
+        }
+    }
+
+    /// Expand a `prefix:local` IRI using the known namespaces
+    fn expand_abbreviated_iri(&self, abbreviated_iri: &str) -> OwlResult<Option<IRI>> {
+        let Some((prefix, local)) = abbreviated_iri.split_once(':') else {
+            return Ok(None);
+        };
+        match self.namespaces.get(prefix) {
+            Some(namespace) => IRI::new(format!("{}{}", namespace, local)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Resolve the IRI of an entity reference of the given kind
+    fn entity_iri(&self, element: &XmlElement, kind: &str) -> OwlResult<Option<IRI>> {
+        if element.local_name() == kind {
+            self.element_iri(element)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Resolve the single entity operand of a property characteristic axiom
+    fn single_entity_iri(
+        &self,
+        operands: &[&XmlElement],
+        kind: &str,
+    ) -> OwlResult<Option<Arc<IRI>>> {
+        match operands[..] {
+            [operand] => Ok(self.entity_iri(operand, kind)?.map(Arc::new)),
             _ => Ok(None),
         }
     }
 
+    /// Resolve the entity operands of an n-ary axiom (at least two)
+    fn entity_iris(
+        &self,
+        operands: &[&XmlElement],
+        kind: &str,
+    ) -> OwlResult<Option<Vec<Arc<IRI>>>> {
+        Ok(parse_all(operands, |operand| {
+            Ok(self.entity_iri(operand, kind)?.map(Arc::new))
+        })?
+        .filter(|iris| iris.len() >= 2))
+    }
+
     /// Validate the parsed ontology
     fn validate_ontology(&self, ontology: &Ontology) -> OwlResult<()> {
         if ontology.classes().is_empty()
@@ -762,6 +1622,14 @@ impl OntologyParser for OwlXmlParser {
     }
 }
 
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for OwlXmlParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        Box::new(move |content| Self::with_config(config).parse_str(content))
+    }
+}
+
 impl Default for OwlXmlParser {
     fn default() -> Self {
         Self::new()
@@ -780,7 +1648,38 @@ struct XmlDocument {
 struct XmlElement {
     name: String,
     attributes: HashMap<String, String>,
-    #[allow(dead_code)]
     content: String,
     children: Vec<XmlElement>,
 }
+
+impl XmlElement {
+    /// Element name without an `owl:` prefix
+    fn local_name(&self) -> &str {
+        self.name.strip_prefix("owl:").unwrap_or(&self.name)
+    }
+
+    /// Child elements, skipping axiom annotations
+    fn operands(&self) -> Vec<&XmlElement> {
+        self.children
+            .iter()
+            .filter(|child| child.local_name() != "Annotation")
+            .collect()
+    }
+}
+
+const RDFS_LITERAL: &str = "http://www.w3.org/2000/01/rdf-schema#Literal";
+
+/// Parse every element with `parse`, or nothing if any element is not understood
+fn parse_all<T>(
+    elements: &[&XmlElement],
+    mut parse: impl FnMut(&XmlElement) -> OwlResult<Option<T>>,
+) -> OwlResult<Option<Vec<T>>> {
+    let mut items = Vec::with_capacity(elements.len());
+    for element in elements {
+        match parse(element)? {
+            Some(item) => items.push(item),
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(items))
+}
@@ -0,0 +1,793 @@
+//! OWL/XML serializer for OWL2 ontologies
+//!
+//! Writes an [`Ontology`] in the OWL/XML syntax read by [`OwlXmlParser`](super::OwlXmlParser).
+//! Each element is written on its own line with full IRIs, so the output
+//! round-trips through the parser. Qualified cardinality axioms are written as
+//! `SubClassOf(owl:Thing, ...)`; RDF collections, containers and reification
+//! have no OWL/XML syntax and are omitted. Source comments kept by
+//! `ParserConfig::preserve_comments` are written back as XML comments ahead of
+//! the entity they describe. Documents can be streamed to any
+//! [`std::io::Write`] with [`OwlXmlSerializer::serialize_to_writer`].
+
+use crate::axioms::class_expressions::{ClassExpression, DataRange};
+use crate::axioms::*;
+use crate::constants::{owl, SOURCE_COMMENT};
+use crate::entities::*;
+use crate::error::{OwlError, OwlResult};
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::parser::common::escape_xml;
+use crate::parser::owl_xml::{OWL_NAMESPACE, STANDARD_PREFIXES};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+/// OWL/XML format serializer
+#[derive(Debug, Clone, Default)]
+pub struct OwlXmlSerializer;
+
+impl OwlXmlSerializer {
+    /// Create a new OWL/XML serializer
+    pub fn new() -> Self {
+        OwlXmlSerializer
+    }
+
+    /// Serialize an ontology to an OWL/XML document
+    pub fn serialize(&self, ontology: &Ontology) -> OwlResult<String> {
+        let mut output = Vec::new();
+        self.serialize_to_writer(ontology, &mut output)?;
+        String::from_utf8(output).map_err(|e| OwlError::SerializationError(e.to_string()))
+    }
+
+    /// Serialize an ontology to a writer, one element at a time
+    ///
+    /// Nothing is buffered beyond the current element, so exporting a large
+    /// ontology needs no more memory than the ontology itself. Wrap unbuffered
+    /// writers such as files in a [`BufWriter`].
+    pub fn serialize_to_writer(&self, ontology: &Ontology, output: impl Write) -> OwlResult<()> {
+        let data_properties: HashSet<Arc<IRI>> = ontology
+            .data_properties()
+            .iter()
+            .map(|property| property.iri().clone())
+            .collect();
+        let mut comments: HashMap<Arc<IRI>, Vec<String>> = HashMap::new();
+        for axiom in ontology.axioms() {
+            if let Some((subject, comment)) = source_comment(axiom) {
+                comments
+                    .entry(subject.clone())
+                    .or_default()
+                    .push(comment.to_string());
+            }
+        }
+        let mut writer = XmlWriter {
+            output,
+            error: None,
+            depth: 0,
+            data_properties,
+            comments,
+        };
+        writer.push_str("<?xml version=\"1.0\"?>\n");
+
+        let mut header = vec![("xmlns", OWL_NAMESPACE.to_string())];
+        if let Some(iri) = ontology.iri() {
+            header.push(("ontologyIRI", iri.as_str().to_string()));
+        }
+        if let Some(version_iri) = ontology.version_iri() {
+            header.push(("versionIRI", version_iri.as_str().to_string()));
+        }
+        writer.open("Ontology", &header);
+        for annotation in ontology.annotations() {
+            if let (SOURCE_COMMENT, AnnotationValue::Literal(comment)) =
+                (annotation.property().as_str(), annotation.value())
+            {
+                writer.comment(comment.lexical_form());
+            }
+        }
+        if let Some(iri) = ontology.iri() {
+            writer.comments_for(iri);
+        }
+        for (name, namespace) in STANDARD_PREFIXES {
+            writer.empty(
+                "Prefix",
+                &[("name", name.to_string()), ("IRI", namespace.to_string())],
+            );
+        }
+
+        let mut imports: Vec<_> = ontology.imports().iter().collect();
+        imports.sort();
+        for import in imports {
+            writer.text("Import", &[], import.as_str());
+        }
+        for annotation in ontology.annotations() {
+            if annotation.property().as_str() == SOURCE_COMMENT {
+                continue;
+            }
+            writer.open("Annotation", &[]);
+            writer.entity("AnnotationProperty", annotation.property());
+            writer.annotation_value(annotation.value());
+            writer.close("Annotation");
+        }
+
+        writer.declarations("Class", ontology.classes().iter().map(|c| c.iri()));
+        writer.declarations(
+            "ObjectProperty",
+            ontology.object_properties().iter().map(|p| p.iri()),
+        );
+        writer.declarations(
+            "DataProperty",
+            ontology.data_properties().iter().map(|p| p.iri()),
+        );
+        writer.declarations(
+            "AnnotationProperty",
+            ontology.annotation_properties().iter().map(|p| p.iri()),
+        );
+        writer.declarations(
+            "NamedIndividual",
+            ontology.named_individuals().iter().map(|i| i.iri()),
+        );
+
+        for axiom in ontology.axioms() {
+            if source_comment(axiom).is_some() {
+                continue;
+            }
+            for iri in axiom.signature() {
+                writer.comments_for(&iri);
+            }
+            writer.axiom(axiom);
+        }
+        let mut remaining: Vec<Arc<IRI>> = writer.comments.keys().cloned().collect();
+        remaining.sort();
+        for iri in remaining {
+            writer.comments_for(&iri);
+        }
+
+        writer.close("Ontology");
+        writer.finish()
+    }
+
+    /// Serialize an ontology to an OWL/XML file
+    pub fn serialize_to_file(&self, ontology: &Ontology, path: &Path) -> OwlResult<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.serialize_to_writer(ontology, &mut file)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Line-oriented XML writer
+struct XmlWriter<W: Write> {
+    output: W,
+    /// First write error; later writes are skipped and it is reported at the end
+    error: Option<io::Error>,
+    depth: usize,
+    /// Data properties, used to tell the kind of HasKey properties
+    data_properties: HashSet<Arc<IRI>>,
+    /// Source comments not yet written, by the entity they describe
+    comments: HashMap<Arc<IRI>, Vec<String>>,
+}
+
+/// The subject and text of a source comment annotation
+fn source_comment(axiom: &Axiom) -> Option<(&Arc<IRI>, &str)> {
+    match axiom {
+        Axiom::AnnotationAssertion(axiom)
+            if axiom.annotation_property().as_str() == SOURCE_COMMENT =>
+        {
+            match axiom.value() {
+                AnnotationValue::Literal(literal) => {
+                    Some((axiom.subject(), literal.lexical_form()))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+impl<W: Write> XmlWriter<W> {
+    fn push_str(&mut self, text: &str) {
+        if self.error.is_none() {
+            if let Err(e) = self.output.write_all(text.as_bytes()) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn finish(mut self) -> OwlResult<()> {
+        if self.error.is_none() {
+            if let Err(e) = self.output.flush() {
+                self.error = Some(e);
+            }
+        }
+        match self.error {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+
+    fn comment(&mut self, text: &str) {
+        self.start_line();
+        // "--" may not appear inside an XML comment
+        self.push_str(&format!("<!-- {} -->\n", text.replace("--", "- -")));
+    }
+
+    /// Write the source comments describing an entity, the first time it appears
+    fn comments_for(&mut self, iri: &IRI) {
+        for comment in self.comments.remove(iri).unwrap_or_default() {
+            self.comment(&comment);
+        }
+    }
+
+    fn start_line(&mut self) {
+        for _ in 0..self.depth {
+            self.push_str("    ");
+        }
+    }
+
+    fn tag(&mut self, name: &str, attributes: &[(&str, String)]) {
+        self.push_str("<");
+        self.push_str(name);
+        for (key, value) in attributes {
+            self.push_str(&format!(" {}=\"{}\"", key, escape_xml(value)));
+        }
+    }
+
+    fn open(&mut self, name: &str, attributes: &[(&str, String)]) {
+        self.start_line();
+        self.tag(name, attributes);
+        self.push_str(">\n");
+        self.depth += 1;
+    }
+
+    fn close(&mut self, name: &str) {
+        self.depth -= 1;
+        self.start_line();
+        self.push_str(&format!("</{}>\n", name));
+    }
+
+    fn empty(&mut self, name: &str, attributes: &[(&str, String)]) {
+        self.start_line();
+        self.tag(name, attributes);
+        self.push_str("/>\n");
+    }
+
+    fn text(&mut self, name: &str, attributes: &[(&str, String)], text: &str) {
+        self.start_line();
+        self.tag(name, attributes);
+        self.push_str(&format!(">{}</{}>\n", escape_xml(text), name));
+    }
+
+    fn entity(&mut self, kind: &str, iri: &IRI) {
+        self.empty(kind, &[("IRI", iri.as_str().to_string())]);
+    }
+
+    fn entities(&mut self, kind: &str, iris: &[Arc<IRI>]) {
+        for iri in iris {
+            self.entity(kind, iri);
+        }
+    }
+
+    fn iri(&mut self, iri: &IRI) {
+        self.text("IRI", &[], iri.as_str());
+    }
+
+    fn declarations<'a>(&mut self, kind: &str, iris: impl Iterator<Item = &'a Arc<IRI>>) {
+        let mut iris: Vec<_> = iris.collect();
+        iris.sort();
+        for iri in iris {
+            self.comments_for(iri);
+            self.open("Declaration", &[]);
+            self.entity(kind, iri);
+            self.close("Declaration");
+        }
+    }
+
+    fn literal(&mut self, literal: &Literal) {
+        let attributes = match literal.language_tag() {
+            Some(language) => vec![("xml:lang", language.to_string())],
+            None => vec![("datatypeIRI", literal.datatype().as_str().to_string())],
+        };
+        self.text("Literal", &attributes, literal.lexical_form());
+    }
+
+    fn annotation_value(&mut self, value: &AnnotationValue) {
+        match value {
+            AnnotationValue::IRI(iri) => self.iri(iri),
+            AnnotationValue::Literal(literal) => self.literal(literal),
+            AnnotationValue::AnonymousIndividual(node_id) => {
+                self.empty("AnonymousIndividual", &[("nodeID", node_id.clone())])
+            }
+        }
+    }
+
+    fn individual(&mut self, individual: &Individual) {
+        match individual {
+            Individual::Named(individual) => self.entity("NamedIndividual", individual.iri()),
+            Individual::Anonymous(individual) => self.empty(
+                "AnonymousIndividual",
+                &[("nodeID", individual.node_id().to_string())],
+            ),
+        }
+    }
+
+    fn object_property(&mut self, property: &ObjectPropertyExpression) {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(property) => {
+                self.entity("ObjectProperty", property.iri())
+            }
+            ObjectPropertyExpression::ObjectInverseOf(inner) => {
+                self.open("ObjectInverseOf", &[]);
+                self.object_property(inner);
+                self.close("ObjectInverseOf");
+            }
+        }
+    }
+
+    fn data_property(&mut self, property: &DataPropertyExpression) {
+        match property {
+            DataPropertyExpression::DataProperty(property) => {
+                self.entity("DataProperty", property.iri())
+            }
+        }
+    }
+
+    fn data_range(&mut self, range: &DataRange) {
+        match range {
+            DataRange::Datatype(datatype) => self.entity("Datatype", datatype),
+            DataRange::DataIntersectionOf(ranges) | DataRange::DataUnionOf(ranges) => {
+                let name = if matches!(range, DataRange::DataIntersectionOf(_)) {
+                    "DataIntersectionOf"
+                } else {
+                    "DataUnionOf"
+                };
+                self.open(name, &[]);
+                for range in ranges {
+                    self.data_range(range);
+                }
+                self.close(name);
+            }
+            DataRange::DataComplementOf(inner) => {
+                self.open("DataComplementOf", &[]);
+                self.data_range(inner);
+                self.close("DataComplementOf");
+            }
+            DataRange::DataOneOf(literals) => {
+                self.open("DataOneOf", &[]);
+                for literal in literals {
+                    self.literal(literal);
+                }
+                self.close("DataOneOf");
+            }
+            DataRange::DatatypeRestriction(datatype, facets) => {
+                self.open("DatatypeRestriction", &[]);
+                self.entity("Datatype", datatype);
+                for facet in facets {
+                    self.open(
+                        "FacetRestriction",
+                        &[("facet", facet.facet().as_str().to_string())],
+                    );
+                    self.literal(facet.value());
+                    self.close("FacetRestriction");
+                }
+                self.close("DatatypeRestriction");
+            }
+        }
+    }
+
+    fn class_expression(&mut self, expression: &ClassExpression) {
+        match expression {
+            ClassExpression::Class(class) => self.entity("Class", class.iri()),
+            ClassExpression::ObjectIntersectionOf(operands)
+            | ClassExpression::ObjectUnionOf(operands) => {
+                let name = if matches!(expression, ClassExpression::ObjectIntersectionOf(_)) {
+                    "ObjectIntersectionOf"
+                } else {
+                    "ObjectUnionOf"
+                };
+                self.open(name, &[]);
+                for operand in operands {
+                    self.class_expression(operand);
+                }
+                self.close(name);
+            }
+            ClassExpression::ObjectComplementOf(operand) => {
+                self.open("ObjectComplementOf", &[]);
+                self.class_expression(operand);
+                self.close("ObjectComplementOf");
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                self.open("ObjectOneOf", &[]);
+                for individual in individuals.iter() {
+                    self.individual(individual);
+                }
+                self.close("ObjectOneOf");
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler)
+            | ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                let name = if matches!(expression, ClassExpression::ObjectSomeValuesFrom(..)) {
+                    "ObjectSomeValuesFrom"
+                } else {
+                    "ObjectAllValuesFrom"
+                };
+                self.open(name, &[]);
+                self.object_property(property);
+                self.class_expression(filler);
+                self.close(name);
+            }
+            ClassExpression::ObjectHasValue(property, individual) => {
+                self.open("ObjectHasValue", &[]);
+                self.object_property(property);
+                self.individual(individual);
+                self.close("ObjectHasValue");
+            }
+            ClassExpression::ObjectHasSelf(property) => {
+                self.open("ObjectHasSelf", &[]);
+                self.object_property(property);
+                self.close("ObjectHasSelf");
+            }
+            ClassExpression::ObjectMinCardinality(n, property) => {
+                self.object_cardinality("ObjectMinCardinality", *n, property, None)
+            }
+            ClassExpression::ObjectMaxCardinality(n, property) => {
+                self.object_cardinality("ObjectMaxCardinality", *n, property, None)
+            }
+            ClassExpression::ObjectExactCardinality(n, property) => {
+                self.object_cardinality("ObjectExactCardinality", *n, property, None)
+            }
+            ClassExpression::DataSomeValuesFrom(property, range)
+            | ClassExpression::DataAllValuesFrom(property, range) => {
+                let name = if matches!(expression, ClassExpression::DataSomeValuesFrom(..)) {
+                    "DataSomeValuesFrom"
+                } else {
+                    "DataAllValuesFrom"
+                };
+                self.open(name, &[]);
+                self.data_property(property);
+                self.data_range(range);
+                self.close(name);
+            }
+            ClassExpression::DataHasValue(property, value) => {
+                self.open("DataHasValue", &[]);
+                self.data_property(property);
+                self.literal(value);
+                self.close("DataHasValue");
+            }
+            ClassExpression::DataMinCardinality(n, property)
+            | ClassExpression::DataMaxCardinality(n, property)
+            | ClassExpression::DataExactCardinality(n, property) => {
+                let name = match expression {
+                    ClassExpression::DataMinCardinality(..) => "DataMinCardinality",
+                    ClassExpression::DataMaxCardinality(..) => "DataMaxCardinality",
+                    _ => "DataExactCardinality",
+                };
+                self.open(name, &[("cardinality", n.to_string())]);
+                self.data_property(property);
+                self.close(name);
+            }
+        }
+    }
+
+    fn object_cardinality(
+        &mut self,
+        name: &str,
+        cardinality: u32,
+        property: &ObjectPropertyExpression,
+        filler: Option<&ClassExpression>,
+    ) {
+        self.open(name, &[("cardinality", cardinality.to_string())]);
+        self.object_property(property);
+        if let Some(filler) = filler {
+            self.class_expression(filler);
+        }
+        self.close(name);
+    }
+
+    /// `SubClassOf(owl:Thing, <restriction>)` for a global qualified cardinality axiom
+    fn qualified_object_cardinality(
+        &mut self,
+        name: &str,
+        cardinality: u32,
+        property: &ObjectPropertyExpression,
+        filler: &ClassExpression,
+    ) {
+        self.open("SubClassOf", &[]);
+        self.entity("Class", &owl::thing());
+        self.object_cardinality(name, cardinality, property, Some(filler));
+        self.close("SubClassOf");
+    }
+
+    /// Data counterpart of [`Self::qualified_object_cardinality`]
+    fn qualified_data_cardinality(
+        &mut self,
+        name: &str,
+        cardinality: u32,
+        property: &ObjectPropertyExpression,
+        datatype: &IRI,
+    ) {
+        self.open("SubClassOf", &[]);
+        self.entity("Class", &owl::thing());
+        self.open(name, &[("cardinality", cardinality.to_string())]);
+        self.entity("DataProperty", named_property(property));
+        self.entity("Datatype", datatype);
+        self.close(name);
+        self.close("SubClassOf");
+    }
+
+    fn axiom(&mut self, axiom: &Axiom) {
+        match axiom {
+            Axiom::SubClassOf(axiom) => {
+                self.open("SubClassOf", &[]);
+                self.class_expression(axiom.sub_class());
+                self.class_expression(axiom.super_class());
+                self.close("SubClassOf");
+            }
+            Axiom::EquivalentClasses(axiom) => {
+                self.open("EquivalentClasses", &[]);
+                for expression in axiom.class_expressions() {
+                    self.class_expression(expression);
+                }
+                self.close("EquivalentClasses");
+            }
+            Axiom::DisjointClasses(axiom) => {
+                self.open("DisjointClasses", &[]);
+                self.entities("Class", axiom.classes());
+                self.close("DisjointClasses");
+            }
+            Axiom::ClassAssertion(axiom) => {
+                self.open("ClassAssertion", &[]);
+                self.class_expression(axiom.class_expr());
+                self.entity("NamedIndividual", axiom.individual());
+                self.close("ClassAssertion");
+            }
+            Axiom::PropertyAssertion(axiom) => {
+                self.open("ObjectPropertyAssertion", &[]);
+                self.entity("ObjectProperty", axiom.property());
+                self.entity("NamedIndividual", axiom.subject());
+                match axiom.object() {
+                    PropertyAssertionObject::Named(object) => {
+                        self.entity("NamedIndividual", object)
+                    }
+                    PropertyAssertionObject::Anonymous(object) => self.empty(
+                        "AnonymousIndividual",
+                        &[("nodeID", object.node_id().to_string())],
+                    ),
+                }
+                self.close("ObjectPropertyAssertion");
+            }
+            Axiom::DataPropertyAssertion(axiom) => {
+                self.open("DataPropertyAssertion", &[]);
+                self.entity("DataProperty", axiom.property());
+                self.entity("NamedIndividual", axiom.subject());
+                self.literal(axiom.value());
+                self.close("DataPropertyAssertion");
+            }
+            Axiom::SubObjectProperty(axiom) => {
+                self.open("SubObjectPropertyOf", &[]);
+                self.entity("ObjectProperty", axiom.sub_property());
+                self.entity("ObjectProperty", axiom.super_property());
+                self.close("SubObjectPropertyOf");
+            }
+            Axiom::EquivalentObjectProperties(axiom) => {
+                self.open("EquivalentObjectProperties", &[]);
+                self.entities("ObjectProperty", axiom.properties());
+                self.close("EquivalentObjectProperties");
+            }
+            Axiom::DisjointObjectProperties(axiom) => {
+                self.open("DisjointObjectProperties", &[]);
+                self.entities("ObjectProperty", axiom.properties());
+                self.close("DisjointObjectProperties");
+            }
+            Axiom::FunctionalProperty(axiom) => self.characteristic(
+                "FunctionalObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::InverseFunctionalProperty(axiom) => self.characteristic(
+                "InverseFunctionalObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::ReflexiveProperty(axiom) => self.characteristic(
+                "ReflexiveObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::IrreflexiveProperty(axiom) => self.characteristic(
+                "IrreflexiveObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::SymmetricProperty(axiom) => self.characteristic(
+                "SymmetricObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::AsymmetricProperty(axiom) => self.characteristic(
+                "AsymmetricObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::TransitiveProperty(axiom) => self.characteristic(
+                "TransitiveObjectProperty",
+                "ObjectProperty",
+                axiom.property(),
+            ),
+            Axiom::SubPropertyChainOf(axiom) => {
+                self.open("SubObjectPropertyOf", &[]);
+                self.open("ObjectPropertyChain", &[]);
+                for property in axiom.property_chain() {
+                    self.object_property(property);
+                }
+                self.close("ObjectPropertyChain");
+                self.object_property(axiom.super_property());
+                self.close("SubObjectPropertyOf");
+            }
+            Axiom::InverseObjectProperties(axiom) => {
+                self.open("InverseObjectProperties", &[]);
+                self.object_property(axiom.property1());
+                self.object_property(axiom.property2());
+                self.close("InverseObjectProperties");
+            }
+            Axiom::SubDataProperty(axiom) => {
+                self.open("SubDataPropertyOf", &[]);
+                self.entity("DataProperty", axiom.sub_property());
+                self.entity("DataProperty", axiom.super_property());
+                self.close("SubDataPropertyOf");
+            }
+            Axiom::EquivalentDataProperties(axiom) => {
+                self.open("EquivalentDataProperties", &[]);
+                self.entities("DataProperty", axiom.properties());
+                self.close("EquivalentDataProperties");
+            }
+            Axiom::DisjointDataProperties(axiom) => {
+                self.open("DisjointDataProperties", &[]);
+                self.entities("DataProperty", axiom.properties());
+                self.close("DisjointDataProperties");
+            }
+            Axiom::FunctionalDataProperty(axiom) => {
+                self.characteristic("FunctionalDataProperty", "DataProperty", axiom.property())
+            }
+            Axiom::SameIndividual(axiom) => {
+                self.open("SameIndividual", &[]);
+                self.entities("NamedIndividual", axiom.individuals());
+                self.close("SameIndividual");
+            }
+            Axiom::DifferentIndividuals(axiom) => {
+                self.open("DifferentIndividuals", &[]);
+                self.entities("NamedIndividual", axiom.individuals());
+                self.close("DifferentIndividuals");
+            }
+            Axiom::HasKey(axiom) => {
+                self.open("HasKey", &[]);
+                self.class_expression(axiom.class_expression());
+                for property in axiom.properties() {
+                    let kind = if self.data_properties.contains(property) {
+                        "DataProperty"
+                    } else {
+                        "ObjectProperty"
+                    };
+                    self.entity(kind, property);
+                }
+                self.close("HasKey");
+            }
+            Axiom::AnnotationAssertion(axiom) => {
+                self.open("AnnotationAssertion", &[]);
+                self.entity("AnnotationProperty", axiom.annotation_property());
+                self.iri(axiom.subject());
+                self.annotation_value(axiom.value());
+                self.close("AnnotationAssertion");
+            }
+            Axiom::SubAnnotationPropertyOf(axiom) => {
+                self.open("SubAnnotationPropertyOf", &[]);
+                self.entity("AnnotationProperty", axiom.sub_property());
+                self.entity("AnnotationProperty", axiom.super_property());
+                self.close("SubAnnotationPropertyOf");
+            }
+            Axiom::AnnotationPropertyDomain(axiom) => {
+                self.open("AnnotationPropertyDomain", &[]);
+                self.entity("AnnotationProperty", axiom.property());
+                self.iri(axiom.domain());
+                self.close("AnnotationPropertyDomain");
+            }
+            Axiom::AnnotationPropertyRange(axiom) => {
+                self.open("AnnotationPropertyRange", &[]);
+                self.entity("AnnotationProperty", axiom.property());
+                self.iri(axiom.range());
+                self.close("AnnotationPropertyRange");
+            }
+            Axiom::ObjectMinQualifiedCardinality(axiom) => self.qualified_object_cardinality(
+                "ObjectMinCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::ObjectMaxQualifiedCardinality(axiom) => self.qualified_object_cardinality(
+                "ObjectMaxCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::ObjectExactQualifiedCardinality(axiom) => self.qualified_object_cardinality(
+                "ObjectExactCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::DataMinQualifiedCardinality(axiom) => self.qualified_data_cardinality(
+                "DataMinCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::DataMaxQualifiedCardinality(axiom) => self.qualified_data_cardinality(
+                "DataMaxCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::DataExactQualifiedCardinality(axiom) => self.qualified_data_cardinality(
+                "DataExactCardinality",
+                axiom.cardinality(),
+                axiom.property(),
+                axiom.filler(),
+            ),
+            Axiom::ObjectPropertyDomain(axiom) => {
+                self.open("ObjectPropertyDomain", &[]);
+                self.object_property(axiom.property_expression());
+                self.class_expression(axiom.domain());
+                self.close("ObjectPropertyDomain");
+            }
+            Axiom::ObjectPropertyRange(axiom) => {
+                self.open("ObjectPropertyRange", &[]);
+                self.object_property(axiom.property_expression());
+                self.class_expression(axiom.range());
+                self.close("ObjectPropertyRange");
+            }
+            Axiom::DataPropertyDomain(axiom) => {
+                self.open("DataPropertyDomain", &[]);
+                self.entity("DataProperty", axiom.property());
+                self.class_expression(axiom.domain());
+                self.close("DataPropertyDomain");
+            }
+            Axiom::DataPropertyRange(axiom) => {
+                self.open("DataPropertyRange", &[]);
+                self.entity("DataProperty", axiom.property());
+                self.entity("Datatype", axiom.range());
+                self.close("DataPropertyRange");
+            }
+            Axiom::NegativeObjectPropertyAssertion(axiom) => {
+                self.open("NegativeObjectPropertyAssertion", &[]);
+                self.entity("ObjectProperty", axiom.property());
+                self.entity("NamedIndividual", axiom.subject());
+                self.entity("NamedIndividual", axiom.object());
+                self.close("NegativeObjectPropertyAssertion");
+            }
+            Axiom::NegativeDataPropertyAssertion(axiom) => {
+                self.open("NegativeDataPropertyAssertion", &[]);
+                self.entity("DataProperty", axiom.property());
+                self.entity("NamedIndividual", axiom.subject());
+                self.literal(axiom.value());
+                self.close("NegativeDataPropertyAssertion");
+            }
+            // Imports are written from the ontology's import set in the header
+            Axiom::Import(_) => {}
+            // RDF-only constructs without an OWL/XML representation
+            Axiom::Collection(_) | Axiom::Container(_) | Axiom::Reification(_) => {}
+        }
+    }
+
+    fn characteristic(&mut self, name: &str, kind: &str, property: &IRI) {
+        self.open(name, &[]);
+        self.entity(kind, property);
+        self.close(name);
+    }
+}
+
+/// IRI of the named property inside a (possibly inverted) property expression
+fn named_property(property: &ObjectPropertyExpression) -> &IRI {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(property) => property.iri(),
+        ObjectPropertyExpression::ObjectInverseOf(inner) => named_property(inner),
+    }
+}
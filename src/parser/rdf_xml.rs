@@ -42,14 +42,18 @@ impl OntologyParser for RdfXmlParser {
             ));
         }
 
+        crate::parser::rdf_xml_common::check_xml_limits(content, &self.config)?;
+
         // Choose parsing strategy based on configuration and feature availability
         #[cfg(feature = "rio-xml")]
         {
-            if !self.config.strict_validation {
+            if !self.config.strict_validation && self.config.inherit_xml_lang {
                 // Try streaming parser for non-strict mode
                 let mut streaming_parser = RdfXmlStreamingParser::new(self.config.clone());
                 match streaming_parser.parse_content(content) {
                     Ok(ontology) => return Ok(ontology),
+                    // The document was read but rejected, which the legacy parser cannot fix
+                    Err(e @ crate::error::OwlError::ValidationError(_)) => return Err(e),
                     Err(e) => {
                         // If streaming parser fails, try legacy parser as fallback
                         eprintln!(
@@ -66,7 +70,8 @@ impl OntologyParser for RdfXmlParser {
             }
         }
 
-        // Use legacy parser for strict mode or when streaming is not available
+        // Use legacy parser for strict mode, for xml:lang without inheritance or when
+        // streaming is not available
         let mut legacy_parser = RdfXmlLegacyParser::new(self.config.clone());
         let mut ontology = legacy_parser.parse_content(content)?;
 
@@ -106,3 +111,11 @@ impl OntologyParser for RdfXmlParser {
         "RDF/XML"
     }
 }
+
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for RdfXmlParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        Box::new(move |content| Self::with_config(config).parse_str(content))
+    }
+}
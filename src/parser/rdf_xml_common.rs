@@ -1,6 +1,8 @@
 //! Common utilities and constants for RDF/XML parsing
 
+use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
+use crate::parser::ParserConfig;
 use std::collections::HashMap;
 
 /// Static string constants to avoid allocations
@@ -55,6 +57,8 @@ pub static ERR_EMPTY_ONTOLOGY: &str = "Ontology contains no entities or imports"
 pub static ERR_FILE_TOO_LARGE: &str = "File size exceeds maximum allowed size";
 pub static ERR_RIO_XML_PARSE: &str = "rio-xml parse error";
 pub static ERR_UNKNOWN_PROPERTY_CHAR: &str = "Unknown property characteristic";
+pub static ERR_ENTITY_EXPANSION_LIMIT: &str = "XML entity expansion limit exceeded";
+pub static ERR_NESTING_DEPTH_LIMIT: &str = "XML nesting depth limit exceeded";
 
 /// Resource information for RDF/XML parsing
 #[derive(Debug, Clone, Default)]
@@ -151,3 +155,222 @@ pub fn expand_qname(qname: &str, namespaces: &HashMap<String, String>) -> Option
         Some(qname.to_string())
     }
 }
+
+/// Check XML content against the entity expansion and nesting limits in `config`
+///
+/// Entity declarations in the internal DTD subset are resolved without building the
+/// expanded text, so documents such as "billion laughs" are rejected in bounded time.
+/// Each reference in the body costs the nested expansions and the bytes it expands to.
+pub fn check_xml_limits(content: &str, config: &ParserConfig) -> OwlResult<()> {
+    let checks_entities = config.max_entity_expansions > 0 || config.max_entity_expanded_bytes > 0;
+    let body_start = if checks_entities {
+        let (entities, body_start) = collect_entity_declarations(content);
+        if !entities.is_empty() {
+            let limits = ExpansionLimits {
+                nested: limit_or_unbounded(config.max_entity_expansions),
+                bytes: limit_or_unbounded(config.max_entity_expanded_bytes),
+            };
+            let mut memo = HashMap::new();
+            let mut total = Expansion::default();
+            for name in entity_references(&content[body_start..]) {
+                if entities.contains_key(name) {
+                    let expansion =
+                        expand_entity(name, &entities, &mut memo, &mut Vec::new(), &limits)?;
+                    total = total.add(expansion);
+                    limits.check(total)?;
+                }
+            }
+        }
+        body_start
+    } else {
+        0
+    };
+
+    if config.max_xml_nesting_depth > 0 {
+        check_nesting_depth(&content[body_start..], config.max_xml_nesting_depth)?;
+    }
+
+    Ok(())
+}
+
+fn limit_or_unbounded(limit: usize) -> usize {
+    if limit == 0 {
+        usize::MAX
+    } else {
+        limit
+    }
+}
+
+/// What expanding an entity reference costs
+#[derive(Debug, Clone, Copy, Default)]
+struct Expansion {
+    /// Entity references expanded inside entity values
+    nested: usize,
+    /// Length of the expanded text
+    bytes: usize,
+}
+
+impl Expansion {
+    fn add(self, other: Expansion) -> Expansion {
+        Expansion {
+            nested: self.nested.saturating_add(other.nested),
+            bytes: self.bytes.saturating_add(other.bytes),
+        }
+    }
+}
+
+struct ExpansionLimits {
+    nested: usize,
+    bytes: usize,
+}
+
+impl ExpansionLimits {
+    fn check(&self, expansion: Expansion) -> OwlResult<()> {
+        if expansion.nested > self.nested {
+            return Err(OwlError::ParseError(format!(
+                "{}: more than {} nested entity expansions",
+                ERR_ENTITY_EXPANSION_LIMIT, self.nested
+            )));
+        }
+        if expansion.bytes > self.bytes {
+            return Err(OwlError::ParseError(format!(
+                "{}: entities expand to more than {} bytes",
+                ERR_ENTITY_EXPANSION_LIMIT, self.bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Collect `<!ENTITY name "value">` declarations and return the offset where the DTD ends
+fn collect_entity_declarations(content: &str) -> (HashMap<&str, &str>, usize) {
+    let mut entities = HashMap::new();
+    let Some(doctype_start) = content.find("<!DOCTYPE") else {
+        return (entities, 0);
+    };
+    let doctype = &content[doctype_start..];
+    let has_subset = matches!(
+        (doctype.find('['), doctype.find('>')),
+        (Some(open), Some(close)) if open < close
+    );
+    let subset_len = if has_subset { doctype.find(']') } else { None };
+    let Some(subset_len) = subset_len else {
+        let body_start = doctype.find('>').map_or(0, |pos| doctype_start + pos + 1);
+        return (entities, body_start);
+    };
+    let subset_end = doctype_start + subset_len;
+    let subset = &content[doctype_start..subset_end];
+
+    let mut rest = subset;
+    while let Some(pos) = rest.find("<!ENTITY") {
+        rest = &rest[pos + "<!ENTITY".len()..];
+        let decl = rest.trim_start().trim_start_matches('%').trim_start();
+        let name_len = decl.find(|c: char| c.is_whitespace()).unwrap_or(decl.len());
+        let name = &decl[..name_len];
+        let after_name = decl[name_len..].trim_start();
+        if let Some(quote) = after_name
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')
+        {
+            if let Some(value_len) = after_name[1..].find(quote) {
+                entities.insert(name, &after_name[1..1 + value_len]);
+            }
+        }
+    }
+
+    let body_start = content[subset_end..]
+        .find('>')
+        .map_or(subset_end, |pos| subset_end + pos + 1);
+    (entities, body_start)
+}
+
+/// Iterate over the names of general entity references (`&name;`), skipping character references
+fn entity_references(text: &str) -> impl Iterator<Item = &str> {
+    text.split('&').skip(1).filter_map(|chunk| {
+        let end = chunk.find(';')?;
+        let name = &chunk[..end];
+        let valid = !name.is_empty()
+            && !name.starts_with('#')
+            && name
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | ':'));
+        valid.then_some(name)
+    })
+}
+
+/// Cost of expanding one reference to `name`, including nested references
+fn expand_entity<'a>(
+    name: &'a str,
+    entities: &HashMap<&'a str, &'a str>,
+    memo: &mut HashMap<&'a str, Expansion>,
+    stack: &mut Vec<&'a str>,
+    limits: &ExpansionLimits,
+) -> OwlResult<Expansion> {
+    if let Some(expansion) = memo.get(name) {
+        return Ok(*expansion);
+    }
+    if stack.contains(&name) {
+        return Err(OwlError::ParseError(format!(
+            "{}: recursive entity '{}'",
+            ERR_ENTITY_EXPANSION_LIMIT, name
+        )));
+    }
+
+    stack.push(name);
+    let value = entities.get(name).copied().unwrap_or_default();
+    let mut expansion = Expansion {
+        nested: 0,
+        bytes: value.len(),
+    };
+    for reference in entity_references(value) {
+        if entities.contains_key(reference) {
+            let inner = expand_entity(reference, entities, memo, stack, limits)?;
+            // The reference text `&name;` is replaced by the expansion
+            expansion.bytes = expansion.bytes.saturating_sub(reference.len() + 2);
+            expansion = expansion.add(inner).add(Expansion {
+                nested: 1,
+                bytes: 0,
+            });
+            limits.check(expansion)?;
+        }
+    }
+    stack.pop();
+
+    memo.insert(name, expansion);
+    Ok(expansion)
+}
+
+/// Reject documents whose element nesting is deeper than `limit`
+fn check_nesting_depth(text: &str, limit: usize) -> OwlResult<()> {
+    let mut depth = 0usize;
+    let mut rest = text;
+
+    while let Some(pos) = rest.find('<') {
+        rest = &rest[pos..];
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+        } else if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            rest = after.find("]]>").map_or("", |end| &after[end + 3..]);
+        } else {
+            let Some(end) = rest.find('>') else {
+                break;
+            };
+            let tag = &rest[1..end];
+            if tag.starts_with('/') {
+                depth = depth.saturating_sub(1);
+            } else if !tag.starts_with('?') && !tag.starts_with('!') && !tag.ends_with('/') {
+                depth += 1;
+                if depth > limit {
+                    return Err(OwlError::ParseError(format!(
+                        "{}: elements nested deeper than {}",
+                        ERR_NESTING_DEPTH_LIMIT, limit
+                    )));
+                }
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+
+    Ok(())
+}
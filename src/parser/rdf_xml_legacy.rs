@@ -6,15 +6,18 @@ use crate::entities::*;
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use crate::parser::common::{TripleObject, UnmappedTripleCollector};
 use crate::parser::rdf_xml_common::{
     initialize_namespaces, ResourceInfo, XmlDocument, XmlElement, ERR_EMPTY_ONTOLOGY, RDF_ABOUT,
-    RDF_RESOURCE,
+    RDF_RESOURCE, XML_LANG,
 };
 use crate::parser::{ParserArenaBuilder, ParserArenaTrait, ParserConfig};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
+const OWL_VERSION_IRI: &str = "http://www.w3.org/2002/07/owl#versionIRI";
+
 /// Legacy RDF/XML parser for strict validation and compatibility
 pub struct RdfXmlLegacyParser {
     pub config: ParserConfig,
@@ -23,6 +26,11 @@ pub struct RdfXmlLegacyParser {
     pub blank_node_counter: u32,
     pub resource_map: HashMap<String, ResourceInfo>,
     pub arena: Option<Box<dyn ParserArenaTrait>>,
+    /// Pending property assertions with no specific mapping
+    unmapped: UnmappedTripleCollector,
+    /// `xml:lang` in scope for each element being read, innermost last;
+    /// `None` where no language applies
+    xml_lang_scope: Vec<Option<String>>,
 }
 
 impl RdfXmlLegacyParser {
@@ -47,6 +55,8 @@ impl RdfXmlLegacyParser {
             blank_node_counter: 0,
             resource_map: HashMap::new(),
             arena,
+            unmapped: UnmappedTripleCollector::new(),
+            xml_lang_scope: Vec::new(),
         }
     }
 
@@ -59,9 +69,16 @@ impl RdfXmlLegacyParser {
         }
 
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
         let document = self.parse_xml_document(content)?;
         self.process_rdf_document(&mut ontology, &document)?;
         self.process_resource_map(&mut ontology)?;
+        std::mem::take(&mut self.unmapped).finish(&mut ontology, self.config.unknown_predicate)?;
+
+        if self.config.deduplicate_on_load {
+            ontology.compact()?;
+        }
 
         if self.config.strict_validation {
             self.validate_ontology(&ontology)?;
@@ -96,15 +113,12 @@ impl RdfXmlLegacyParser {
             self.parse_xml_declaration(&mut chars, &mut document)?;
         }
 
-        // Parse doctype if present
-        if let Some(_pos) = content.find("<!DOCTYPE") {
-            // This is simplified - in practice, you'd parse the doctype properly
-        }
-
         // Parse root element
-        if let Some(element) = self.parse_element(&mut chars)? {
-            document.root = Some(element);
-        }
+        let root = xmltree::Element::parse(content.as_bytes()).map_err(|e| {
+            crate::error::OwlError::ParseError(format!("Invalid RDF/XML document: {}", e))
+        })?;
+        self.xml_lang_scope.clear();
+        document.root = Some(self.parse_element(&root));
 
         Ok(document)
     }
@@ -122,14 +136,109 @@ impl RdfXmlLegacyParser {
         Ok(())
     }
 
-    /// Parse XML element
-    fn parse_element(
-        &self,
-        _chars: &mut std::str::CharIndices<'_>,
-    ) -> OwlResult<Option<XmlElement>> {
-        // Simplified element parsing
-        // In practice, this would be much more comprehensive
-        Ok(None)
+    /// Convert an XML element and its descendants
+    ///
+    /// Element names keep their prefix (`owl:Class`) and the namespaces declared on
+    /// the element are registered. The XML reader drops attribute prefixes, so the
+    /// RDF and XML attributes get their usual prefixes back.
+    ///
+    /// The `xml:lang` in scope is tracked on a stack: an element's own `xml:lang`
+    /// opens a scope for its descendants and an empty one closes it. The language
+    /// that applies to an element's literal is stored as its `xml:lang` attribute;
+    /// without [`ParserConfig::inherit_xml_lang`] only the element's own counts.
+    fn parse_element(&mut self, element: &xmltree::Element) -> XmlElement {
+        if let Some(namespaces) = &element.namespaces {
+            for (prefix, namespace) in &namespaces.0 {
+                if !namespace.is_empty() && prefix != "xml" && prefix != "xmlns" {
+                    self.namespaces.insert(prefix.clone(), namespace.clone());
+                }
+            }
+        }
+
+        let mut attributes = HashMap::new();
+        for (name, value) in &element.attributes {
+            let name = match name.as_str() {
+                "about" | "resource" | "nodeID" | "ID" | "datatype" | "parseType" => {
+                    format!("rdf:{}", name)
+                }
+                "lang" | "base" => format!("xml:{}", name),
+                _ => name.clone(),
+            };
+            attributes.insert(name, value.clone());
+        }
+
+        let own_language = attributes
+            .remove(XML_LANG)
+            .map(|language| Some(language).filter(|language| !language.is_empty()));
+        let scoped_language = match &own_language {
+            Some(language) => language.clone(),
+            None => self.xml_lang_scope.last().cloned().flatten(),
+        };
+        let language = if self.config.inherit_xml_lang {
+            scoped_language.clone()
+        } else {
+            own_language.flatten()
+        };
+        if let Some(language) = language {
+            attributes.insert(XML_LANG.to_string(), language);
+        }
+
+        self.xml_lang_scope.push(scoped_language);
+        let mut children = Vec::new();
+        let mut content = String::new();
+        for child in &element.children {
+            match child {
+                xmltree::XMLNode::Element(child) => children.push(self.parse_element(child)),
+                xmltree::XMLNode::Text(text) => content.push_str(text),
+                _ => {}
+            }
+        }
+        self.xml_lang_scope.pop();
+
+        XmlElement {
+            name: match &element.prefix {
+                Some(prefix) => format!("{}:{}", prefix, element.name),
+                None => element.name.clone(),
+            },
+            attributes,
+            children,
+            content,
+        }
+    }
+
+    /// Build the literal value of a property element from its text, datatype and language
+    fn literal(element: &XmlElement) -> OwlResult<Literal> {
+        let value = element.content.trim();
+        Ok(if let Some(datatype) = element.attributes.get("rdf:datatype") {
+            Literal::typed(value, IRI::new(datatype)?)
+        } else if let Some(language) = element.attributes.get(XML_LANG) {
+            Literal::lang_tagged(value, language.as_str())
+        } else {
+            Literal::simple(value)
+        })
+    }
+
+    /// Get the IRI of a property element
+    fn property_iri(&self, element: &XmlElement) -> OwlResult<IRI> {
+        match crate::parser::rdf_xml_common::expand_qname(&element.name, &self.namespaces) {
+            Some(expanded) => IRI::new(expanded),
+            None => IRI::new(element.name.as_str()),
+        }
+    }
+
+    /// Record the value of a property element without nested nodes, either the
+    /// resource it refers to or its literal text
+    fn record_property_element(&mut self, subject: &IRI, element: &XmlElement) -> OwlResult<()> {
+        if !element.children.is_empty() || element.name == "type" || element.name == "rdf:type" {
+            return Ok(());
+        }
+        let object = match element.attributes.get(RDF_RESOURCE) {
+            Some(resource) => TripleObject::Named(IRI::new(resource)?),
+            None => TripleObject::Literal(Self::literal(element)?),
+        };
+        let property = self.property_iri(element)?;
+        self.unmapped.record(subject.clone(), property, object);
+        Ok(())
     }
 
     /// Process RDF document and build ontology
@@ -201,13 +310,26 @@ impl RdfXmlLegacyParser {
             ontology.set_iri(iri);
         }
 
-        // Process imports
+        // Process imports, the version IRI and ontology annotations
         for child in &element.children {
             if child.name == "imports" || child.name == "owl:imports" {
                 if let Some(resource) = child.attributes.get(RDF_RESOURCE) {
                     let import_iri = IRI::new(resource)?;
                     ontology.add_import(import_iri);
                 }
+            } else if let Some(property) =
+                crate::parser::rdf_xml_common::expand_qname(&child.name, &self.namespaces)
+            {
+                let value = match child.attributes.get(RDF_RESOURCE) {
+                    Some(resource) => AnnotationValue::from(IRI::new(resource)?),
+                    None => AnnotationValue::from(Self::literal(child)?),
+                };
+                match value {
+                    AnnotationValue::IRI(iri) if property == OWL_VERSION_IRI => {
+                        ontology.set_version_iri((*iri).clone());
+                    }
+                    value => ontology.add_annotation(Annotation::new(IRI::new(property)?, value)),
+                }
             }
         }
 
@@ -264,6 +386,18 @@ impl RdfXmlLegacyParser {
                         ontology.add_disjoint_classes_axiom(axiom)?;
                     }
                 }
+
+                if !matches!(
+                    child.name.as_str(),
+                    "subClassOf"
+                        | "rdfs:subClassOf"
+                        | "equivalentClass"
+                        | "owl:equivalentClass"
+                        | "disjointWith"
+                        | "owl:disjointWith"
+                ) {
+                    self.record_property_element(&iri, child)?;
+                }
             }
         }
 
@@ -446,7 +580,7 @@ impl RdfXmlLegacyParser {
     /// Process property assertion
     fn process_property_assertion(
         &mut self,
-        ontology: &mut Ontology,
+        _ontology: &mut Ontology,
         individual: &NamedIndividual,
         element: &XmlElement,
     ) -> OwlResult<()> {
@@ -455,39 +589,35 @@ impl RdfXmlLegacyParser {
             for child in &element.children {
                 if child.name == "rdf:Description" {
                     if let Some(node_id) = child.attributes.get("rdf:nodeID") {
-                        // Create anonymous individual from nested description
+                        // Anonymous individual from nested description
                         let anon_individual = AnonymousIndividual::new(format!("_:{}", node_id));
-                        ontology.add_anonymous_individual(anon_individual.clone())?;
 
-                        // Create property assertion with anonymous individual
+                        // Property assertion with anonymous individual
                         if let Some(property_name) = element
                             .name
                             .split_once(':')
                             .map(|(_, name)| name)
                             .or(Some(&element.name))
                         {
-                            let property_iri = if let Some(expanded) =
-                                crate::parser::rdf_xml_common::expand_qname(
-                                    &element.name,
-                                    &self.namespaces,
-                                ) {
-                                IRI::new(&expanded)?
-                            } else {
-                                IRI::new(property_name)?
+                            let property_iri = match crate::parser::rdf_xml_common::expand_qname(
+                                &element.name,
+                                &self.namespaces,
+                            ) {
+                                Some(expanded) => IRI::new(&expanded)?,
+                                None => IRI::new(property_name)?,
                             };
 
-                            let assertion = PropertyAssertionAxiom::new_with_anonymous(
-                                individual.iri().clone(),
-                                Arc::new(property_iri),
-                                anon_individual,
+                            self.unmapped.record(
+                                (**individual.iri()).clone(),
+                                property_iri,
+                                TripleObject::Anonymous(Box::new(anon_individual)),
                             );
-                            ontology.add_property_assertion(assertion)?;
                         }
                     }
                 }
             }
         }
-        Ok(())
+        self.record_property_element(individual.iri(), element)
     }
 
     /// Process property assertion for anonymous individuals
@@ -0,0 +1,775 @@
+//! RDF/XML serializer for OWL2 ontologies
+//!
+//! Writes an [`Ontology`] as an `rdf:RDF` document read back by
+//! [`RdfXmlParser`](super::RdfXmlParser): the ontology header, then one node element
+//! per subject, typed by its declaration (`owl:Class`, `owl:ObjectProperty`, ...) and
+//! holding the axioms and assertions about it. Subjects and their property elements
+//! are sorted so that the output is stable. The namespaces of entity IRIs are
+//! declared as `xmlns` prefixes, taken from [`ParserConfig::prefixes`] or generated
+//! as `ns1`, `ns2`, ..., and name the property elements; attributes hold full IRIs.
+//! Anonymous class expressions are written as nested `owl:Restriction` and
+//! `owl:Class` elements with `rdf:parseType="Collection"` lists. Axioms the RDF/XML
+//! parser has no mapping for, such as property hierarchies and characteristics, and
+//! restrictions on inverse properties or complex data ranges are omitted.
+
+use crate::axioms::class_expressions::{ClassExpression, DataRange};
+use crate::axioms::*;
+use crate::constants::SOURCE_COMMENT;
+use crate::entities::*;
+use crate::error::OwlResult;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::parser::common::{escape_xml, get_namespace};
+use crate::parser::ParserConfig;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Prefixes available to every document, unless the configuration rebinds them
+const STANDARD_PREFIXES: [(&str, &str); 4] =
+    [("rdf", RDF), ("rdfs", RDFS), ("owl", OWL), ("xsd", XSD)];
+
+/// Declaration types, in the order of preference for naming a node element
+const DECLARATION_TYPES: [&str; 5] = [
+    "Class",
+    "ObjectProperty",
+    "DatatypeProperty",
+    "AnnotationProperty",
+    "NamedIndividual",
+];
+
+/// RDF/XML format serializer
+#[derive(Debug, Clone, Default)]
+pub struct RdfXmlSerializer {
+    config: ParserConfig,
+}
+
+/// The object of a property element
+enum Object<'a> {
+    Resource(String),
+    Literal(&'a Literal),
+    Expression(&'a ClassExpression),
+}
+
+/// A subject: its declaration types and the property elements about it
+#[derive(Default)]
+struct Node<'a> {
+    types: BTreeSet<usize>,
+    properties: Vec<(String, Object<'a>)>,
+}
+
+impl RdfXmlSerializer {
+    /// Create a new RDF/XML serializer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an RDF/XML serializer naming namespaces with the configured prefixes
+    pub fn with_config(config: ParserConfig) -> Self {
+        RdfXmlSerializer { config }
+    }
+
+    /// Serialize an ontology to an RDF/XML document
+    pub fn serialize_ontology(&self, ontology: &Ontology) -> OwlResult<String> {
+        let mut nodes: BTreeMap<String, Node> = BTreeMap::new();
+        let mut anonymous: Vec<(&ClassExpression, String, Object)> = Vec::new();
+        let mut comments: HashMap<&str, Vec<&str>> = HashMap::new();
+
+        let declarations = [
+            ontology
+                .classes()
+                .iter()
+                .map(|c| c.iri())
+                .collect::<Vec<_>>(),
+            ontology
+                .object_properties()
+                .iter()
+                .map(|p| p.iri())
+                .collect(),
+            ontology.data_properties().iter().map(|p| p.iri()).collect(),
+            ontology
+                .annotation_properties()
+                .iter()
+                .map(|p| p.iri())
+                .collect(),
+            ontology
+                .named_individuals()
+                .iter()
+                .map(|i| i.iri())
+                .collect(),
+        ];
+        for (kind, iris) in declarations.iter().enumerate() {
+            for iri in iris {
+                nodes
+                    .entry(iri.as_str().to_string())
+                    .or_default()
+                    .types
+                    .insert(kind);
+            }
+        }
+
+        fn add<'a>(
+            nodes: &mut BTreeMap<String, Node<'a>>,
+            subject: &str,
+            predicate: String,
+            object: Object<'a>,
+        ) {
+            nodes
+                .entry(subject.to_string())
+                .or_default()
+                .properties
+                .push((predicate, object));
+        }
+        for axiom in ontology.axioms() {
+            match axiom.as_ref() {
+                Axiom::SubClassOf(axiom) => {
+                    let predicate = format!("{}subClassOf", RDFS);
+                    let (sub, sup) = (axiom.sub_class(), axiom.super_class());
+                    if !is_writable(sub) || !is_writable(sup) {
+                        continue;
+                    }
+                    match sub {
+                        ClassExpression::Class(class) => add(
+                            &mut nodes,
+                            class.iri().as_str(),
+                            predicate,
+                            Object::Expression(sup),
+                        ),
+                        sub => anonymous.push((sub, predicate, Object::Expression(sup))),
+                    }
+                }
+                Axiom::EquivalentClasses(axiom) => {
+                    let expressions = axiom.class_expressions();
+                    if !expressions.iter().all(is_writable) {
+                        continue;
+                    }
+                    let Some(first) = expressions
+                        .iter()
+                        .position(|e| e.as_named().is_some())
+                        .or((!expressions.is_empty()).then_some(0))
+                    else {
+                        continue;
+                    };
+                    let predicate = format!("{}equivalentClass", OWL);
+                    for (i, other) in expressions.iter().enumerate() {
+                        if i == first {
+                            continue;
+                        }
+                        match &expressions[first] {
+                            ClassExpression::Class(class) => add(
+                                &mut nodes,
+                                class.iri().as_str(),
+                                predicate.clone(),
+                                Object::Expression(other),
+                            ),
+                            first => anonymous.push((
+                                first,
+                                predicate.clone(),
+                                Object::Expression(other),
+                            )),
+                        }
+                    }
+                }
+                Axiom::DisjointClasses(axiom) => {
+                    let classes = axiom.classes();
+                    for (i, first) in classes.iter().enumerate() {
+                        for second in &classes[i + 1..] {
+                            add(
+                                &mut nodes,
+                                first.as_str(),
+                                format!("{}disjointWith", OWL),
+                                Object::Resource(second.as_str().to_string()),
+                            );
+                        }
+                    }
+                }
+                Axiom::ObjectPropertyDomain(axiom) => {
+                    if let ObjectPropertyExpression::ObjectProperty(property) =
+                        axiom.property_expression()
+                    {
+                        if is_writable(axiom.domain()) {
+                            add(
+                                &mut nodes,
+                                property.iri().as_str(),
+                                format!("{}domain", RDFS),
+                                Object::Expression(axiom.domain()),
+                            );
+                        }
+                    }
+                }
+                Axiom::ObjectPropertyRange(axiom) => {
+                    if let ObjectPropertyExpression::ObjectProperty(property) =
+                        axiom.property_expression()
+                    {
+                        if is_writable(axiom.range()) {
+                            add(
+                                &mut nodes,
+                                property.iri().as_str(),
+                                format!("{}range", RDFS),
+                                Object::Expression(axiom.range()),
+                            );
+                        }
+                    }
+                }
+                Axiom::ClassAssertion(axiom) if is_writable(axiom.class_expr()) => add(
+                    &mut nodes,
+                    axiom.individual().as_str(),
+                    format!("{}type", RDF),
+                    Object::Expression(axiom.class_expr()),
+                ),
+                Axiom::PropertyAssertion(axiom) => {
+                    let object = match axiom.object() {
+                        PropertyAssertionObject::Named(object) => object.as_str().to_string(),
+                        PropertyAssertionObject::Anonymous(individual) => {
+                            individual.node_id().to_string()
+                        }
+                    };
+                    add(
+                        &mut nodes,
+                        axiom.subject().as_str(),
+                        axiom.property().as_str().to_string(),
+                        Object::Resource(object),
+                    );
+                }
+                Axiom::DataPropertyAssertion(axiom) => add(
+                    &mut nodes,
+                    axiom.subject().as_str(),
+                    axiom.property().as_str().to_string(),
+                    Object::Literal(axiom.value()),
+                ),
+                Axiom::AnnotationAssertion(axiom) => {
+                    let subject = axiom.subject().as_str();
+                    let property = axiom.annotation_property().as_str();
+                    match (property, axiom.value()) {
+                        (SOURCE_COMMENT, AnnotationValue::Literal(comment)) => comments
+                            .entry(subject)
+                            .or_default()
+                            .push(comment.lexical_form()),
+                        (SOURCE_COMMENT, _) => {}
+                        (property, value) => add(
+                            &mut nodes,
+                            subject,
+                            property.to_string(),
+                            annotation_object(value),
+                        ),
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Declare the namespaces of every entity and predicate, in a stable order
+        let mut namespaces = Namespaces::new(&self.config.prefixes);
+        let mut iris: BTreeSet<&str> = nodes.keys().map(String::as_str).collect();
+        iris.extend(
+            nodes
+                .values()
+                .flat_map(|node| node.properties.iter().map(|(p, _)| p.as_str())),
+        );
+        iris.extend(ontology.annotations().iter().map(|a| a.property().as_str()));
+        for iri in iris {
+            namespaces.register(iri);
+        }
+
+        let mut writer = XmlWriter::new(namespaces);
+        let mut output = String::new();
+
+        if let Some(iri) = ontology.iri() {
+            let mut header = Vec::new();
+            if let Some(version_iri) = ontology.version_iri() {
+                header.push(writer.property(
+                    &format!("{}versionIRI", OWL),
+                    &Object::Resource(version_iri.as_str().to_string()),
+                ));
+            }
+            let mut imports: Vec<_> = ontology.imports().iter().collect();
+            imports.sort();
+            for import in imports {
+                header.push(writer.property(
+                    &format!("{}imports", OWL),
+                    &Object::Resource(import.as_str().to_string()),
+                ));
+            }
+            let mut annotations = Vec::new();
+            for annotation in ontology.annotations() {
+                match (annotation.property().as_str(), annotation.value()) {
+                    (SOURCE_COMMENT, AnnotationValue::Literal(comment)) => {
+                        output.push_str(&comment_line(comment.lexical_form()))
+                    }
+                    (SOURCE_COMMENT, _) => {}
+                    (property, value) => {
+                        annotations.push(writer.property(property, &annotation_object(value)))
+                    }
+                }
+            }
+            annotations.sort();
+            header.extend(annotations);
+            output.push_str(&indent(&writer.node(
+                "owl:Ontology",
+                &[subject_attribute(iri.as_str())],
+                header,
+            )));
+        }
+
+        // Declared entities first, grouped by the kind of declaration
+        let mut subjects: Vec<(&String, &Node)> = nodes.iter().collect();
+        subjects.sort_by_key(|(subject, node)| {
+            (
+                node.types
+                    .first()
+                    .copied()
+                    .unwrap_or(DECLARATION_TYPES.len()),
+                *subject,
+            )
+        });
+        for (subject, node) in subjects {
+            for comment in comments.get(subject.as_str()).into_iter().flatten() {
+                output.push_str(&comment_line(comment));
+            }
+            let mut kinds = node.types.iter();
+            let element = match kinds.next() {
+                Some(kind) => format!("owl:{}", DECLARATION_TYPES[*kind]),
+                None => "rdf:Description".to_string(),
+            };
+            let mut properties: Vec<String> = kinds
+                .map(|kind| {
+                    writer.property(
+                        &format!("{}type", RDF),
+                        &Object::Resource(format!("{}{}", OWL, DECLARATION_TYPES[*kind])),
+                    )
+                })
+                .collect();
+            let mut assertions: Vec<String> = node
+                .properties
+                .iter()
+                .map(|(predicate, object)| writer.property(predicate, object))
+                .collect();
+            assertions.sort();
+            properties.extend(assertions);
+            output.push_str(&indent(&writer.node(
+                &element,
+                &[subject_attribute(subject)],
+                properties,
+            )));
+        }
+
+        let mut blocks: Vec<String> = anonymous
+            .iter()
+            .map(|(subject, predicate, object)| {
+                let property = writer.property(predicate, object);
+                writer.expression_node(subject, vec![property])
+            })
+            .collect();
+        blocks.sort();
+        for block in blocks {
+            output.push_str(&indent(&block));
+        }
+
+        // The namespaces are declared once the body shows which are used
+        let mut document = String::from("<?xml version=\"1.0\"?>\n<rdf:RDF");
+        for (i, (name, namespace)) in writer.namespaces.used.iter().enumerate() {
+            if i > 0 {
+                document.push_str("\n        ");
+            }
+            document.push_str(&format!(" xmlns:{}=\"{}\"", name, escape_xml(namespace)));
+        }
+        document.push_str(">\n");
+        document.push_str(&output);
+        document.push_str("</rdf:RDF>\n");
+        Ok(document)
+    }
+}
+
+/// Whether a class expression can be written in a form the RDF/XML parser reads back
+fn is_writable(expression: &ClassExpression) -> bool {
+    let named = |property: &ObjectPropertyExpression| {
+        matches!(property, ObjectPropertyExpression::ObjectProperty(_))
+    };
+    match expression {
+        ClassExpression::Class(_)
+        | ClassExpression::ObjectOneOf(_)
+        | ClassExpression::DataHasValue(..)
+        | ClassExpression::DataMinCardinality(..)
+        | ClassExpression::DataMaxCardinality(..)
+        | ClassExpression::DataExactCardinality(..) => true,
+        ClassExpression::ObjectIntersectionOf(operands)
+        | ClassExpression::ObjectUnionOf(operands) => {
+            operands.iter().all(|operand| is_writable(operand))
+        }
+        ClassExpression::ObjectComplementOf(operand) => is_writable(operand),
+        ClassExpression::ObjectSomeValuesFrom(property, filler)
+        | ClassExpression::ObjectAllValuesFrom(property, filler) => {
+            named(property) && is_writable(filler)
+        }
+        ClassExpression::ObjectHasValue(property, _)
+        | ClassExpression::ObjectHasSelf(property)
+        | ClassExpression::ObjectMinCardinality(_, property)
+        | ClassExpression::ObjectMaxCardinality(_, property)
+        | ClassExpression::ObjectExactCardinality(_, property) => named(property),
+        ClassExpression::DataSomeValuesFrom(_, range)
+        | ClassExpression::DataAllValuesFrom(_, range) => {
+            matches!(range.as_ref(), DataRange::Datatype(_))
+        }
+    }
+}
+
+fn annotation_object(value: &AnnotationValue) -> Object<'_> {
+    match value {
+        AnnotationValue::IRI(iri) => Object::Resource(iri.as_str().to_string()),
+        AnnotationValue::Literal(literal) => Object::Literal(literal),
+        AnnotationValue::AnonymousIndividual(node_id) => Object::Resource(node_id.clone()),
+    }
+}
+
+/// `rdf:about`, or `rdf:nodeID` for a blank node
+fn subject_attribute(iri: &str) -> (&'static str, String) {
+    match iri.strip_prefix("_:") {
+        Some(node_id) => ("rdf:nodeID", node_id.to_string()),
+        None => ("rdf:about", iri.to_string()),
+    }
+}
+
+/// `rdf:resource`, or `rdf:nodeID` for a blank node
+fn object_attribute(iri: &str) -> (&'static str, String) {
+    match iri.strip_prefix("_:") {
+        Some(node_id) => ("rdf:nodeID", node_id.to_string()),
+        None => ("rdf:resource", iri.to_string()),
+    }
+}
+
+fn comment_line(text: &str) -> String {
+    // "--" may not appear inside an XML comment
+    format!("    <!-- {} -->\n", text.replace("--", "- -"))
+}
+
+/// Indent every line of an element by one level
+fn indent(block: &str) -> String {
+    block
+        .lines()
+        .map(|line| format!("    {}\n", line))
+        .collect()
+}
+
+/// Namespace prefixes, configured, standard or generated for the IRIs registered
+struct Namespaces {
+    /// Prefix names and namespaces, longest namespace first
+    prefixes: Vec<(String, String)>,
+    /// Prefixes in use, to be declared
+    used: BTreeMap<String, String>,
+    generated: usize,
+}
+
+impl Namespaces {
+    fn new(configured: &HashMap<String, String>) -> Self {
+        let prefixes = STANDARD_PREFIXES
+            .iter()
+            .filter(|(name, _)| !configured.contains_key(*name))
+            .map(|(name, namespace)| (name.to_string(), namespace.to_string()))
+            .chain(
+                configured
+                    .iter()
+                    .map(|(name, namespace)| (name.clone(), namespace.clone())),
+            )
+            .collect();
+        let mut namespaces = Namespaces {
+            prefixes,
+            used: BTreeMap::new(),
+            generated: 0,
+        };
+        namespaces.sort();
+        namespaces.register(&format!("{}RDF", RDF));
+        namespaces
+    }
+
+    fn sort(&mut self) {
+        self.prefixes
+            .sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+    }
+
+    /// Make sure the IRI has a qualified name, generating a prefix for its namespace
+    fn register(&mut self, iri: &str) {
+        if self.qname(iri).is_some() {
+            return;
+        }
+        let namespace = get_namespace(iri);
+        if namespace.is_empty() || !is_ncname(&iri[namespace.len()..]) {
+            return;
+        }
+        let name = loop {
+            self.generated += 1;
+            let name = format!("ns{}", self.generated);
+            if !self.prefixes.iter().any(|(n, _)| *n == name) {
+                break name;
+            }
+        };
+        self.prefixes.push((name, namespace.to_string()));
+        self.sort();
+        self.qname(iri);
+    }
+
+    /// The qualified name of the IRI, recording its prefix as used
+    fn qname(&mut self, iri: &str) -> Option<String> {
+        for (name, namespace) in &self.prefixes {
+            if let Some(local) = iri.strip_prefix(namespace.as_str()) {
+                if is_ncname(local) {
+                    self.used.insert(name.clone(), namespace.clone());
+                    return Some(format!("{}:{}", name, local));
+                }
+            }
+        }
+        None
+    }
+
+    /// Record the prefix of a vocabulary qualified name, such as `owl:Class`, as used
+    fn use_prefix(&mut self, qname: &str) {
+        let name = qname.split(':').next().unwrap_or_default();
+        if let Some((name, namespace)) = self.prefixes.iter().find(|(n, _)| n == name) {
+            self.used.insert(name.clone(), namespace.clone());
+        }
+    }
+}
+
+/// Whether a local name can follow a prefix in an element name
+fn is_ncname(local: &str) -> bool {
+    local
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Renders elements, each as a block of lines indented from column zero
+struct XmlWriter {
+    namespaces: Namespaces,
+}
+
+impl XmlWriter {
+    fn new(namespaces: Namespaces) -> Self {
+        XmlWriter { namespaces }
+    }
+
+    fn qname(&mut self, iri: &str) -> Option<String> {
+        self.namespaces.qname(iri)
+    }
+
+    fn start_tag(name: &str, attributes: &[(&str, String)]) -> String {
+        let mut tag = format!("<{}", name);
+        for (key, value) in attributes {
+            tag.push_str(&format!(" {}=\"{}\"", key, escape_xml(value)));
+        }
+        tag
+    }
+
+    /// A node element holding the given property elements
+    fn node(&mut self, name: &str, attributes: &[(&str, String)], children: Vec<String>) -> String {
+        self.namespaces.use_prefix(name);
+        let tag = Self::start_tag(name, attributes);
+        if children.is_empty() {
+            return format!("{}/>\n", tag);
+        }
+        let mut block = format!("{}>\n", tag);
+        for child in children {
+            block.push_str(&indent(&child));
+        }
+        block.push_str(&format!("</{}>\n", name));
+        block
+    }
+
+    /// A property element, or nothing if its predicate has no qualified name
+    fn property(&mut self, predicate: &str, object: &Object) -> String {
+        let Some(name) = self.qname(predicate) else {
+            return String::new();
+        };
+        match object {
+            Object::Resource(iri) => {
+                format!("{}/>\n", Self::start_tag(&name, &[object_attribute(iri)]))
+            }
+            Object::Expression(ClassExpression::Class(class)) => format!(
+                "{}/>\n",
+                Self::start_tag(&name, &[object_attribute(class.iri().as_str())])
+            ),
+            Object::Expression(expression) => {
+                let node = self.expression_node(expression, Vec::new());
+                self.node(&name, &[], vec![node])
+            }
+            Object::Literal(literal) => {
+                let attributes = if let Some(language) = literal.language_tag() {
+                    vec![("xml:lang", language.to_string())]
+                } else if literal.datatype().as_str() == XSD_STRING {
+                    Vec::new()
+                } else {
+                    vec![("rdf:datatype", literal.datatype().as_str().to_string())]
+                };
+                format!(
+                    "{}>{}</{}>\n",
+                    Self::start_tag(&name, &attributes),
+                    escape_xml(literal.lexical_form()),
+                    name
+                )
+            }
+        }
+    }
+
+    /// The node element of a class expression, followed by extra property elements
+    fn expression_node(&mut self, expression: &ClassExpression, extra: Vec<String>) -> String {
+        let resource = |iri: &str| Object::Resource(iri.to_string());
+        let restriction = |writer: &mut Self, property: &IRI, children: Vec<String>| {
+            let mut properties =
+                vec![writer.property(&format!("{}onProperty", OWL), &resource(property.as_str()))];
+            properties.extend(children);
+            properties.extend(extra.iter().cloned());
+            writer.node("owl:Restriction", &[], properties)
+        };
+        let cardinality = |writer: &mut Self, name: &str, n: u32| {
+            let value = Literal::typed(
+                n.to_string(),
+                IRI::new_optimized(format!("{}nonNegativeInteger", XSD))
+                    .expect("XSD nonNegativeInteger IRI should always be valid"),
+            );
+            writer.property(&format!("{}{}", OWL, name), &Object::Literal(&value))
+        };
+
+        match expression {
+            ClassExpression::Class(class) => self.node(
+                "rdf:Description",
+                &[subject_attribute(class.iri().as_str())],
+                extra,
+            ),
+            ClassExpression::ObjectIntersectionOf(operands)
+            | ClassExpression::ObjectUnionOf(operands) => {
+                let name = if matches!(expression, ClassExpression::ObjectIntersectionOf(_)) {
+                    "owl:intersectionOf"
+                } else {
+                    "owl:unionOf"
+                };
+                let items = operands
+                    .iter()
+                    .map(|operand| self.expression_node(operand, Vec::new()))
+                    .collect();
+                let list = self.node(name, &[("rdf:parseType", "Collection".to_string())], items);
+                let mut properties = vec![list];
+                properties.extend(extra);
+                self.node("owl:Class", &[], properties)
+            }
+            ClassExpression::ObjectComplementOf(operand) => {
+                let mut properties = vec![self.property(
+                    &format!("{}complementOf", OWL),
+                    &Object::Expression(operand),
+                )];
+                properties.extend(extra);
+                self.node("owl:Class", &[], properties)
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                let items = individuals
+                    .iter()
+                    .map(|individual| {
+                        let iri = match individual {
+                            Individual::Named(individual) => individual.iri().as_str(),
+                            Individual::Anonymous(individual) => individual.node_id(),
+                        };
+                        self.node("rdf:Description", &[subject_attribute(iri)], Vec::new())
+                    })
+                    .collect();
+                let list = self.node(
+                    "owl:oneOf",
+                    &[("rdf:parseType", "Collection".to_string())],
+                    items,
+                );
+                let mut properties = vec![list];
+                properties.extend(extra);
+                self.node("owl:Class", &[], properties)
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler)
+            | ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                let name = if matches!(expression, ClassExpression::ObjectSomeValuesFrom(..)) {
+                    "someValuesFrom"
+                } else {
+                    "allValuesFrom"
+                };
+                let filler =
+                    self.property(&format!("{}{}", OWL, name), &Object::Expression(filler));
+                restriction(self, named_property(property), vec![filler])
+            }
+            ClassExpression::ObjectHasValue(property, individual) => {
+                let value = match individual {
+                    Individual::Named(individual) => individual.iri().as_str(),
+                    Individual::Anonymous(individual) => individual.node_id(),
+                };
+                let value = self.property(&format!("{}hasValue", OWL), &resource(value));
+                restriction(self, named_property(property), vec![value])
+            }
+            ClassExpression::ObjectHasSelf(property) => {
+                let value = Literal::typed(
+                    "true",
+                    IRI::new_optimized(format!("{}boolean", XSD))
+                        .expect("XSD boolean IRI should always be valid"),
+                );
+                let value = self.property(&format!("{}hasSelf", OWL), &Object::Literal(&value));
+                restriction(self, named_property(property), vec![value])
+            }
+            ClassExpression::ObjectMinCardinality(n, property) => {
+                let value = cardinality(self, "minCardinality", *n);
+                restriction(self, named_property(property), vec![value])
+            }
+            ClassExpression::ObjectMaxCardinality(n, property) => {
+                let value = cardinality(self, "maxCardinality", *n);
+                restriction(self, named_property(property), vec![value])
+            }
+            ClassExpression::ObjectExactCardinality(n, property) => {
+                let value = cardinality(self, "cardinality", *n);
+                restriction(self, named_property(property), vec![value])
+            }
+            ClassExpression::DataSomeValuesFrom(property, range)
+            | ClassExpression::DataAllValuesFrom(property, range) => {
+                let name = if matches!(expression, ClassExpression::DataSomeValuesFrom(..)) {
+                    "someValuesFrom"
+                } else {
+                    "allValuesFrom"
+                };
+                // Other data ranges are filtered out by `is_writable`
+                let filler = match range.as_ref() {
+                    DataRange::Datatype(datatype) => {
+                        vec![self
+                            .property(&format!("{}{}", OWL, name), &resource(datatype.as_str()))]
+                    }
+                    _ => Vec::new(),
+                };
+                restriction(self, data_property(property), filler)
+            }
+            ClassExpression::DataHasValue(property, value) => {
+                let value = self.property(&format!("{}hasValue", OWL), &Object::Literal(value));
+                restriction(self, data_property(property), vec![value])
+            }
+            ClassExpression::DataMinCardinality(n, property) => {
+                let value = cardinality(self, "minCardinality", *n);
+                restriction(self, data_property(property), vec![value])
+            }
+            ClassExpression::DataMaxCardinality(n, property) => {
+                let value = cardinality(self, "maxCardinality", *n);
+                restriction(self, data_property(property), vec![value])
+            }
+            ClassExpression::DataExactCardinality(n, property) => {
+                let value = cardinality(self, "cardinality", *n);
+                restriction(self, data_property(property), vec![value])
+            }
+        }
+    }
+}
+
+fn named_property(property: &ObjectPropertyExpression) -> &IRI {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(property) => property.iri(),
+        ObjectPropertyExpression::ObjectInverseOf(inner) => named_property(inner),
+    }
+}
+
+fn data_property(property: &DataPropertyExpression) -> &IRI {
+    match property {
+        DataPropertyExpression::DataProperty(property) => property.iri(),
+    }
+}
@@ -6,6 +6,11 @@ use crate::entities::*;
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use crate::parser::common::{
+    is_nary_members_predicate, is_reification_triple, ClassExpressionCollector, NaryAxiomCollector,
+    NaryAxiomKind, ReificationCollector, TripleObject, UnmappedTripleCollector, RDF_FIRST,
+    RDF_REST, RDF_TYPE,
+};
 use crate::parser::rdf_xml_common::{ERR_RIO_XML_PARSE, NS_OWL, NS_RDF, NS_RDFS};
 use crate::parser::{ParserArenaBuilder, ParserArenaTrait, ParserConfig};
 use std::collections::HashMap;
@@ -14,18 +19,33 @@ use std::path::Path;
 use std::sync::Arc;
 
 #[cfg(feature = "rio-xml")]
-use rio_api::model::{Subject, Term, Triple};
+use rio_api::model::{Literal as RioLiteral, Subject, Term, Triple};
 #[cfg(feature = "rio-xml")]
 use rio_api::parser::TriplesParser as _;
 #[cfg(feature = "rio-xml")]
 use rio_xml::RdfXmlParser as RioRdfXmlParser;
 
+#[cfg(feature = "rio-xml")]
+const OWL_ONTOLOGY: &str = "http://www.w3.org/2002/07/owl#Ontology";
+#[cfg(feature = "rio-xml")]
+const OWL_IMPORTS: &str = "http://www.w3.org/2002/07/owl#imports";
+#[cfg(feature = "rio-xml")]
+const OWL_VERSION_IRI: &str = "http://www.w3.org/2002/07/owl#versionIRI";
+
 /// Streaming RDF/XML parser for efficient large file processing
 pub struct RdfXmlStreamingParser {
     pub config: ParserConfig,
     pub namespaces: HashMap<String, String>,
     pub base_iri: Option<IRI>,
     pub arena: Option<Box<dyn ParserArenaTrait>>,
+    /// Pending owl:AllDisjointClasses / owl:AllDifferent nodes
+    nary_axioms: NaryAxiomCollector,
+    /// Pending reified statements
+    reification: ReificationCollector,
+    /// Pending anonymous class expressions
+    class_expressions: ClassExpressionCollector,
+    /// Pending triples with no specific mapping
+    unmapped: UnmappedTripleCollector,
 }
 
 impl RdfXmlStreamingParser {
@@ -48,6 +68,10 @@ impl RdfXmlStreamingParser {
             namespaces,
             base_iri: None,
             arena,
+            nary_axioms: NaryAxiomCollector::new(),
+            reification: ReificationCollector::new(),
+            class_expressions: ClassExpressionCollector::new(),
+            unmapped: UnmappedTripleCollector::new(),
         }
     }
 
@@ -55,6 +79,8 @@ impl RdfXmlStreamingParser {
     #[cfg(feature = "rio-xml")]
     pub fn parse_content(&mut self, content: &str) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
 
         let base_iri = self
             .base_iri
@@ -72,6 +98,15 @@ impl RdfXmlStreamingParser {
             crate::error::OwlError::ParseError(format!("{}: {}", ERR_RIO_XML_PARSE, e))
         })?;
 
+        std::mem::take(&mut self.nary_axioms).finish(&mut ontology)?;
+        std::mem::take(&mut self.class_expressions).finish(&mut ontology)?;
+        std::mem::take(&mut self.unmapped).finish(&mut ontology, self.config.unknown_predicate)?;
+        std::mem::take(&mut self.reification).finish(&mut ontology)?;
+
+        if self.config.deduplicate_on_load {
+            ontology.compact()?;
+        }
+
         Ok(ontology)
     }
 
@@ -91,6 +126,8 @@ impl RdfXmlStreamingParser {
     #[cfg(feature = "rio-xml")]
     pub fn parse_stream(&mut self, reader: impl std::io::BufRead) -> OwlResult<Ontology> {
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
 
         let base_iri = self
             .base_iri
@@ -108,6 +145,15 @@ impl RdfXmlStreamingParser {
             crate::error::OwlError::ParseError(format!("{}: {}", ERR_RIO_XML_PARSE, e))
         })?;
 
+        std::mem::take(&mut self.nary_axioms).finish(&mut ontology)?;
+        std::mem::take(&mut self.class_expressions).finish(&mut ontology)?;
+        std::mem::take(&mut self.unmapped).finish(&mut ontology, self.config.unknown_predicate)?;
+        std::mem::take(&mut self.reification).finish(&mut ontology)?;
+
+        if self.config.deduplicate_on_load {
+            ontology.compact()?;
+        }
+
         Ok(ontology)
     }
 
@@ -118,6 +164,28 @@ impl RdfXmlStreamingParser {
         let predicate_iri = IRI::new(triple.predicate.iri)?;
         let object = self.process_object(&triple.object)?;
 
+        if self.collect_nary_triple(&subject_iri, &predicate_iri, &object)
+            || self.collect_reification_triple(&subject_iri, &predicate_iri, &object)
+            || self.class_expressions.record(
+                &subject_iri,
+                &predicate_iri,
+                &object.to_triple_object(),
+            )
+        {
+            return Ok(());
+        }
+
+        // The ontology node and the triples about it make up the header
+        if predicate_iri.as_str() == RDF_TYPE
+            && object.as_iri().map(|iri| iri.as_str()) == Some(OWL_ONTOLOGY)
+        {
+            ontology.set_iri(subject_iri);
+            return Ok(());
+        }
+        if ontology.iri() == Some(&subject_iri) {
+            return self.handle_header_triple(ontology, &predicate_iri, object);
+        }
+
         // Ensure subject individual exists (create if not already present)
         let subject_individual = NamedIndividual::new(subject_iri.clone());
         if !ontology
@@ -163,14 +231,93 @@ impl RdfXmlStreamingParser {
             }
 
             _ => {
-                // Generic property assertion
-                self.handle_property_assertion(ontology, &subject_iri, &predicate_iri, &object)?;
+                // Generic property assertion, resolved once the declarations are known
+                self.unmapped
+                    .record(subject_iri, predicate_iri, object.to_triple_object());
             }
         }
 
         Ok(())
     }
 
+    /// Record triples that make up n-ary disjointness/difference axioms
+    ///
+    /// Returns `true` when the triple only describes the n-ary axiom node and needs
+    /// no further processing.
+    #[cfg(feature = "rio-xml")]
+    fn collect_nary_triple(
+        &mut self,
+        subject: &IRI,
+        predicate: &IRI,
+        object: &ProcessedObject,
+    ) -> bool {
+        let node_id = |object: &ProcessedObject| match object {
+            ProcessedObject::Iri(iri) => Some(iri.as_str().to_string()),
+            ProcessedObject::BlankNode(id) => Some(format!("_:{}", id)),
+            ProcessedObject::Literal(_) => None,
+        };
+
+        match predicate.as_str() {
+            p if p == RDF_TYPE => {
+                match object
+                    .as_iri()
+                    .and_then(|t| NaryAxiomKind::from_type_iri(t.as_str()))
+                {
+                    Some(kind) => {
+                        self.nary_axioms.record_kind(subject.as_str(), kind);
+                        true
+                    }
+                    None => false,
+                }
+            }
+            p if is_nary_members_predicate(p) => match node_id(object) {
+                Some(head) => {
+                    self.nary_axioms.record_member_list(subject.as_str(), &head);
+                    true
+                }
+                None => false,
+            },
+            p if p == RDF_FIRST => {
+                if let Some(item) = object.as_iri() {
+                    self.nary_axioms
+                        .record_list_first(subject.as_str(), item.clone());
+                }
+                false
+            }
+            p if p == RDF_REST => {
+                if let Some(rest) = node_id(object) {
+                    self.nary_axioms.record_list_rest(subject.as_str(), &rest);
+                }
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Record the triples of reified statements
+    ///
+    /// Returns `true` when the triple was consumed and needs no further processing.
+    #[cfg(feature = "rio-xml")]
+    fn collect_reification_triple(
+        &mut self,
+        subject: &IRI,
+        predicate: &IRI,
+        object: &ProcessedObject,
+    ) -> bool {
+        let object_iri = object.as_iri().map(|iri| iri.as_str());
+        if !is_reification_triple(predicate.as_str(), object_iri) {
+            return false;
+        }
+        let object = match object {
+            ProcessedObject::Iri(iri) => ReificationObject::Named(Arc::new(iri.clone())),
+            ProcessedObject::BlankNode(id) => ReificationObject::Anonymous(Box::new(
+                AnonymousIndividual::new(format!("_:{}", id)),
+            )),
+            ProcessedObject::Literal(literal) => ReificationObject::Literal(literal.clone()),
+        };
+        self.reification.record(subject, predicate, object)
+    }
+
     /// Convert Rio subject to IRI
     #[cfg(feature = "rio-xml")]
     fn subject_to_iri(&self, subject: &Subject) -> OwlResult<IRI> {
@@ -240,14 +387,15 @@ impl RdfXmlStreamingParser {
             Term::NamedNode(node) => Ok(ProcessedObject::Iri(IRI::new(node.iri)?)),
             Term::BlankNode(node) => Ok(ProcessedObject::BlankNode(node.id.to_string())),
             Term::Literal(literal) => {
-                // Process Rio API literals
-                // TODO: Implement proper literal extraction by checking Rio API 0.8 documentation
-                // For now, we use a debug representation which preserves the literal information
-                let literal_str = format!("{:?}", literal);
-
-                // Create a simple literal using the debug representation
-                // This preserves the literal information in a usable format
-                let processed_literal = Literal::simple(literal_str);
+                let processed_literal = match literal {
+                    RioLiteral::Simple { value } => Literal::simple(*value),
+                    RioLiteral::LanguageTaggedString { value, language } => {
+                        Literal::lang_tagged(*value, *language)
+                    }
+                    RioLiteral::Typed { value, datatype } => {
+                        Literal::typed(*value, IRI::new(datatype.iri)?)
+                    }
+                };
 
                 Ok(ProcessedObject::Literal(processed_literal))
             }
@@ -306,6 +454,31 @@ impl RdfXmlStreamingParser {
         }
     }
 
+    /// Handle a triple about the ontology: an import, its version IRI or an annotation
+    #[cfg(feature = "rio-xml")]
+    fn handle_header_triple(
+        &self,
+        ontology: &mut Ontology,
+        predicate: &IRI,
+        object: ProcessedObject,
+    ) -> OwlResult<()> {
+        let value = match object {
+            ProcessedObject::Iri(iri) if predicate.as_str() == OWL_IMPORTS => {
+                ontology.add_import(iri);
+                return Ok(());
+            }
+            ProcessedObject::Iri(iri) if predicate.as_str() == OWL_VERSION_IRI => {
+                ontology.set_version_iri(iri);
+                return Ok(());
+            }
+            ProcessedObject::Iri(iri) => AnnotationValue::from(iri),
+            ProcessedObject::Literal(literal) => AnnotationValue::from(literal),
+            ProcessedObject::BlankNode(_) => return Ok(()),
+        };
+        ontology.add_annotation(Annotation::new(predicate.clone(), value));
+        Ok(())
+    }
+
     /// Handle type assertions (rdf:type)
     #[cfg(feature = "rio-xml")]
     fn handle_type_assertion(
@@ -327,6 +500,10 @@ impl RdfXmlStreamingParser {
                 let property = DataProperty::new(subject.clone());
                 ontology.add_data_property(property)?;
             }
+            ty if ty == format!("{}AnnotationProperty", NS_OWL) => {
+                let property = AnnotationProperty::new(subject.clone());
+                ontology.add_annotation_property(property)?;
+            }
             ty if ty == format!("{}NamedIndividual", NS_OWL) => {
                 let individual = NamedIndividual::new(subject.clone());
                 ontology.add_named_individual(individual)?;
@@ -437,54 +614,6 @@ impl RdfXmlStreamingParser {
 
         Ok(())
     }
-
-    /// Handle generic property assertions
-    #[cfg(feature = "rio-xml")]
-    fn handle_property_assertion(
-        &mut self,
-        ontology: &mut Ontology,
-        subject: &IRI,
-        predicate: &IRI,
-        object: &ProcessedObject,
-    ) -> OwlResult<()> {
-        match object {
-            ProcessedObject::Iri(object_iri) => {
-                // Object property with named individual
-                let object_individual = NamedIndividual::new(object_iri.clone());
-                ontology.add_named_individual(object_individual.clone())?;
-
-                let assertion = PropertyAssertionAxiom::new(
-                    Arc::new(subject.clone()),
-                    Arc::new(predicate.clone()),
-                    object_individual.iri().clone(),
-                );
-                ontology.add_property_assertion(assertion)?;
-            }
-            ProcessedObject::BlankNode(node_id) => {
-                // Object property with anonymous individual
-                let anon_individual = AnonymousIndividual::new(format!("_:{}", node_id));
-                ontology.add_anonymous_individual(anon_individual.clone())?;
-
-                let assertion = PropertyAssertionAxiom::new_with_anonymous(
-                    Arc::new(subject.clone()),
-                    Arc::new(predicate.clone()),
-                    anon_individual,
-                );
-                ontology.add_property_assertion(assertion)?;
-            }
-            ProcessedObject::Literal(literal) => {
-                // Data property with literal value
-                let assertion = DataPropertyAssertionAxiom::new(
-                    Arc::new(subject.clone()),
-                    Arc::new(predicate.clone()),
-                    literal.clone(),
-                );
-                ontology.add_data_property_assertion(assertion)?;
-            }
-        }
-
-        Ok(())
-    }
 }
 
 /// Processed object representation
@@ -502,6 +631,17 @@ impl ProcessedObject {
             _ => None,
         }
     }
+
+    /// Convert to the object of a collected triple
+    fn to_triple_object(&self) -> TripleObject {
+        match self {
+            ProcessedObject::Iri(iri) => TripleObject::Named(iri.clone()),
+            ProcessedObject::BlankNode(node_id) => TripleObject::Anonymous(Box::new(
+                AnonymousIndividual::new(format!("_:{}", node_id)),
+            )),
+            ProcessedObject::Literal(literal) => TripleObject::Literal(literal.clone()),
+        }
+    }
 }
 
 // Fallback implementations when rio-xml feature is not enabled
@@ -8,9 +8,15 @@ use crate::entities::*;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use crate::parser::common::{
+    check_literal, is_nary_members_predicate, is_reification_triple, is_true_literal,
+    NaryAxiomCollector, NaryAxiomKind, ReificationCollector, TokenLimits, TripleObject,
+    UnmappedTripleCollector, OWL_HAS_SELF, RDF_FIRST, RDF_NIL, RDF_REST,
+};
 use crate::parser::{OntologyParser, ParserArenaBuilder, ParserArenaTrait, ParserConfig};
 use hashbrown::HashMap;
 use smallvec::SmallVec;
+use std::cell::Cell;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -25,18 +31,26 @@ static NS_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
 static NS_RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
 static NS_XSD: &str = "http://www.w3.org/2001/XMLSchema#";
 
+/// Namespace of the IRIs given to blank node subjects
+static BLANK_NODE_NS: &str = "http://blank.node/";
+
 static ERR_EMPTY_ONTOLOGY: &str = "Ontology contains no entities or imports";
 static ERR_EXPECTED_DOT: &str = "Expected '.' at end of statement";
 static ERR_MALFORMED_PREFIX: &str = "Malformed @prefix: missing trailing ':'";
 static ERR_MALFORMED_PREFIX_NS: &str = "Malformed @prefix: namespace must be <...>";
 static ERR_MALFORMED_PREFIX_DECL: &str = "Malformed @prefix declaration";
 
+/// Reports a token whose length so far is over its limit
+type OverflowCheck = fn(&TokenLimits, usize) -> Option<String>;
+
 /// Turtle format parser
 pub struct TurtleParser {
     config: ParserConfig,
     prefixes: HashMap<String, String>, // TODO: Could be optimized to use Cow<str>
     /// Arena allocator for efficient string and object allocation
     arena: Option<Box<dyn ParserArenaTrait>>,
+    /// Counter for labelling anonymous `[]` subjects
+    blank_node_counter: Cell<usize>,
 }
 
 impl TurtleParser {
@@ -79,6 +93,7 @@ impl TurtleParser {
             config,
             prefixes,
             arena,
+            blank_node_counter: Cell::new(0),
         }
     }
 
@@ -115,30 +130,67 @@ impl TurtleParser {
                 self.alloc_string_clone(ERR_EMPTY_ONTOLOGY),
             ));
         }
+        // Blank node labels are numbered per document
+        self.blank_node_counter.set(0);
+
         let mut ontology = Ontology::new();
+        ontology.set_class_expression_interning(self.config.intern_class_expressions);
+        ontology.set_size_limits(self.config.size_limits());
+        let mut nary_axioms = NaryMembers::default();
+        let mut reification = ReificationCollector::new();
+        let mut unmapped = UnmappedTripleCollector::new();
 
         // Process compound statements with semicolon continuation
         let mut current_subject: Option<IRI> = None;
+        // Comments waiting for the subject of the next statement
+        let mut comments: Vec<String> = Vec::new();
+        // Lines of a statement whose `[ ... ]` or `( ... )` is still open
+        let mut pending = String::new();
 
-        for raw_line in content.lines() {
+        for (index, raw_line) in content.lines().enumerate() {
             let line = self.alloc_string(raw_line.trim());
-            if line.is_empty() || line.starts_with('#') {
-                continue; // Skip empty lines and comments
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                if self.config.preserve_comments {
+                    comments.push(comment.trim().to_string());
+                }
+                continue;
             }
 
-            // Parse prefix declarations
+            // Parse prefix declarations; comments before them describe the document
             if line.starts_with("@prefix") {
                 let (prefix, namespace) = self.parse_prefix_declaration(line)?;
                 self.prefixes.insert(prefix, namespace);
+                Self::attach_comments(&mut ontology, None, &mut comments)?;
                 continue;
             }
 
             // Strip inline comments for validation - use arena allocation
-            let stmt = line.split('#').next().unwrap_or("").trim_end();
+            let uncommented = Self::strip_comment(line);
+            if self.config.preserve_comments && uncommented.len() < line.len() {
+                comments.push(line[uncommented.len() + 1..].trim().to_string());
+            }
+            let joined;
+            let mut stmt = uncommented.trim_end();
             if stmt.is_empty() {
                 continue;
             }
 
+            // Join the lines of a blank node or collection spanning several lines
+            if !pending.is_empty() || Self::open_bracket_depth(stmt) > 0 {
+                if !pending.is_empty() {
+                    pending.push(' ');
+                }
+                pending.push_str(stmt);
+                if Self::open_bracket_depth(&pending) > 0 {
+                    continue;
+                }
+                joined = std::mem::take(&mut pending);
+                stmt = &joined;
+            }
+
             // In strict mode, require statements to end with a dot or continue characters
             if self.config.strict_validation
                 && !(stmt.ends_with('.') || stmt.ends_with(';') || stmt.ends_with(','))
@@ -156,8 +208,17 @@ impl TurtleParser {
             // Handle compound statement predicate-object pairs
             if let Some(ref current_subj) = current_subject {
                 // Try to parse as predicate-object pair for compound statements
-                if let Some((predicate, object)) = self.parse_predicate_object_pair(clean_stmt) {
-                    self.process_triple(&mut ontology, current_subj.clone(), predicate, object)?;
+                if let Some(pairs) = self.parse_predicate_object_pairs(clean_stmt) {
+                    let pairs = self.check_literals(pairs, index + 1, raw_line)?;
+                    Self::attach_comments(&mut ontology, Some(current_subj), &mut comments)?;
+                    self.process_statement(
+                        &mut ontology,
+                        &mut nary_axioms,
+                        &mut reification,
+                        &mut unmapped,
+                        current_subj,
+                        pairs,
+                    )?;
 
                     // Reset current subject at end of statement
                     if ends_with_dot {
@@ -168,7 +229,8 @@ impl TurtleParser {
             }
 
             // Parse complete triple
-            if let Some((subject, predicate, object)) = self.parse_triple(clean_stmt) {
+            if let Some((subject, pairs)) = self.parse_triple(clean_stmt) {
+                let pairs = self.check_literals(pairs, index + 1, raw_line)?;
                 // Update current subject for compound statements
                 if current_subject.is_none() || ends_with_dot {
                     current_subject = Some(subject.clone());
@@ -185,18 +247,46 @@ impl TurtleParser {
                     subject
                 };
 
-                self.process_triple(&mut ontology, actual_subject, predicate, object)?;
+                Self::attach_comments(&mut ontology, Some(&actual_subject), &mut comments)?;
+                self.process_statement(
+                    &mut ontology,
+                    &mut nary_axioms,
+                    &mut reification,
+                    &mut unmapped,
+                    &actual_subject,
+                    pairs,
+                )?;
 
                 // Reset current subject at end of statement
                 if ends_with_dot {
                     current_subject = None;
                 }
             } else {
-                // Leniently skip lines we can't parse (multi-line constructs), strictness enforced by other checks
+                // Leniently skip lines we can't parse (multi-line constructs), strictness enforced by other checks,
+                // but still end the statement so its subject does not leak into the next one
+                if ends_with_dot {
+                    current_subject = None;
+                }
                 continue;
             }
         }
 
+        // Cells of lists other than n-ary axiom members are ordinary triples
+        let gathered = nary_axioms.axioms.member_list_cells();
+        for (subject, predicate, object) in nary_axioms.cells {
+            if !gathered.contains(subject.as_str()) {
+                self.process_triple(&mut ontology, &mut unmapped, subject, predicate, object)?;
+            }
+        }
+        nary_axioms.axioms.finish(&mut ontology)?;
+        unmapped.finish(&mut ontology, self.config.unknown_predicate)?;
+        reification.finish(&mut ontology)?;
+        Self::attach_comments(&mut ontology, None, &mut comments)?;
+
+        if self.config.deduplicate_on_load {
+            ontology.compact()?;
+        }
+
         if self.config.strict_validation {
             self.validate_ontology(&ontology)?;
         }
@@ -256,7 +346,7 @@ impl TurtleParser {
     }
 
     /// Parse a predicate-object pair for compound statements using arena allocation
-    fn parse_predicate_object_pair(&self, line: &str) -> Option<(IRI, ObjectValue)> {
+    fn parse_predicate_object_pairs(&self, line: &str) -> Option<Vec<(IRI, ObjectValue)>> {
         let arena_line = self.alloc_string(line);
         let tokens = self.tokenize_turtle_line(arena_line);
 
@@ -264,26 +354,62 @@ impl TurtleParser {
             return None;
         }
 
-        let predicate = self.parse_predicate(&tokens[0])?;
-        let (object, _remaining_tokens) = self.parse_object(&tokens[1..])?;
-
-        Some((predicate, object))
+        self.parse_predicate_object_list(tokens)
     }
 
-    /// Parse a Turtle triple with support for complex constructs using arena allocation
-    fn parse_triple(&self, line: &str) -> Option<(IRI, IRI, ObjectValue)> {
+    /// Parse a Turtle statement, a subject with its predicate-object pairs, using arena allocation
+    fn parse_triple(&self, line: &str) -> Option<(IRI, Vec<(IRI, ObjectValue)>)> {
         let arena_line = self.alloc_string(line.trim_end_matches(['.', ';', ',']));
-        let tokens = self.tokenize_turtle_line(arena_line);
+        let mut tokens = self.tokenize_turtle_line(arena_line);
 
         if tokens.len() < 3 {
             return None;
         }
 
         let subject = self.parse_subject(&tokens[0])?;
-        let predicate = self.parse_predicate(&tokens[1])?;
-        let (object, _remaining_tokens) = self.parse_object(&tokens[2..])?;
+        let pairs = self.parse_predicate_object_list(tokens.split_off(1))?;
+
+        Some((subject, pairs))
+    }
 
-        Some((subject, predicate, object))
+    /// Parse a predicate-object list, where `;` starts a new predicate and `,`
+    /// gives another object for the current one
+    ///
+    /// Stops at the first token that cannot be read, keeping the pairs before it.
+    fn parse_predicate_object_list(&self, tokens: Vec<String>) -> Option<Vec<(IRI, ObjectValue)>> {
+        let tokens: Vec<String> = tokens
+            .into_iter()
+            .flat_map(Self::split_separators)
+            .collect();
+
+        let mut pairs = Vec::new();
+        let mut predicate: Option<IRI> = None;
+        let mut i = 0;
+        while i < tokens.len() {
+            match tokens[i].as_str() {
+                // The next pair has a new predicate
+                ";" => predicate = None,
+                // The next object shares the current predicate
+                "," => {}
+                token => match predicate.clone() {
+                    Some(current) => {
+                        let Some((object, remaining)) = self.parse_object(&tokens[i..]) else {
+                            break;
+                        };
+                        pairs.push((current, object));
+                        i = tokens.len() - remaining.len();
+                        continue;
+                    }
+                    None => match self.parse_predicate(token) {
+                        Some(next) => predicate = Some(next),
+                        None => break,
+                    },
+                },
+            }
+            i += 1;
+        }
+
+        (!pairs.is_empty()).then_some(pairs)
     }
 
     /// Tokenize a Turtle line handling quotes and nested structures with arena allocation
@@ -354,7 +480,12 @@ impl TurtleParser {
 
     /// Parse a subject (IRI or blank node) using arena allocation
     fn parse_subject(&self, token: &str) -> Option<IRI> {
-        if let Some(stripped) = token.strip_prefix("_:") {
+        if token == "[]" {
+            // Anonymous blank node - label it so later predicate-object pairs can refer to it
+            let id = self.blank_node_counter.get() + 1;
+            self.blank_node_counter.set(id);
+            Self::arc_to_iri(IRI::new_optimized(format!("http://blank.node/anon{}", id))).ok()
+        } else if let Some(stripped) = token.strip_prefix("_:") {
             // Blank node - generate temporary IRI for processing using arena allocation
             let blank_iri = self.alloc_string(stripped);
             Self::arc_to_iri(IRI::new_optimized(format!(
@@ -411,13 +542,16 @@ impl TurtleParser {
             let (list_items, consumed) = self.parse_collection(tokens)?;
             let nested_object = NestedObject {
                 object_type: self.alloc_string_clone("Collection"),
-                properties: HashMap::new(),
+                properties: Vec::new(),
                 list_items,
             };
             Some((
                 ObjectValue::Nested(Box::new(nested_object)),
                 Vec::from(&tokens[consumed..]),
             ))
+        } else if let Some(literal) = self.parse_native_literal(first_token) {
+            // Unquoted number or boolean
+            Some((ObjectValue::Literal(literal), tokens[1..].to_vec()))
         } else {
             // Simple IRI
             let iri = self.parse_curie_or_iri(first_token).ok()?;
@@ -425,51 +559,90 @@ impl TurtleParser {
         }
     }
 
-    /// Parse a literal value using arena allocation
+    /// Parse a quoted literal, with an optional language tag or datatype, using arena allocation
     fn parse_literal(&self, token: &str) -> Option<Literal> {
-        if !token.starts_with('"') || !token.ends_with('"') {
+        let close = token.rfind('"')?;
+        if !token.starts_with('"') || close == 0 {
             return None;
         }
 
-        let content = &token[1..token.len() - 1];
+        let content = &token[1..close];
         // Use arena allocation for literal content
-        let arena_content = self.alloc_string(content);
-        let literal = Literal::simple(arena_content.to_string());
-        Some(literal)
+        let arena_content = self.alloc_string(content).to_string();
+        let suffix = &token[close + 1..];
+        if suffix.is_empty() {
+            Some(Literal::simple(arena_content))
+        } else if let Some(language) = suffix.strip_prefix('@') {
+            Some(Literal::lang_tagged(arena_content, language))
+        } else if let Some(datatype) = suffix.strip_prefix("^^") {
+            let datatype = self.parse_curie_or_iri(datatype).ok()?;
+            Some(Literal::typed(arena_content, datatype))
+        } else {
+            None
+        }
     }
 
-    /// Parse blank node structure [ ... ] using arena allocation
-    fn parse_blank_node_structure(&self, content: &str) -> Option<(NestedObject, usize)> {
-        // Generate a unique ID for this blank node based on content hash
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        let mut hasher = DefaultHasher::new();
-        content.hash(&mut hasher);
-        let _unique_id = format!("nested_{}", hasher.finish());
-
-        // Parse the content to extract properties
-        let clean_content =
-            self.alloc_string(content.trim_start_matches('[').trim_end_matches(']').trim());
-        let tokens = self.tokenize_turtle_line(clean_content);
-
-        let mut properties = HashMap::new();
-
-        // Simple parsing for property pairs inside the blank node
-        let mut i = 0;
-        while i < tokens.len() {
-            if let Some(predicate) = self.parse_predicate(&tokens[i]) {
-                if i + 1 < tokens.len() {
-                    if let Some((object, _remaining_tokens)) = self.parse_object(&tokens[i + 1..]) {
-                        // Store the property using arena-allocated key
-                        properties.insert(predicate.to_string(), object);
-                        i += 1; // Move to next token after the object
-                        continue;
+    /// Parse Turtle's unquoted numeric and boolean literals
+    ///
+    /// Integers, decimals and doubles get `xsd:integer`, `xsd:decimal` and
+    /// `xsd:double`, and `true`/`false` get `xsd:boolean`, unless datatype
+    /// inference is disabled in the configuration or the datatype is not in
+    /// the global datatype map.
+    fn parse_native_literal(&self, token: &str) -> Option<Literal> {
+        let datatype = if token == "true" || token == "false" {
+            "boolean"
+        } else {
+            let unsigned = token.strip_prefix(['+', '-']).unwrap_or(token);
+            let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+                Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+                None => (unsigned, None),
+            };
+            let (integer, fraction) = match mantissa.split_once('.') {
+                Some((integer, fraction)) => (integer, Some(fraction)),
+                None => (mantissa, None),
+            };
+            let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+            if !is_digits(integer)
+                || !fraction.is_none_or(is_digits)
+                || integer.is_empty() && fraction.is_none_or(str::is_empty)
+            {
+                return None;
+            }
+            match exponent {
+                Some(exponent) => {
+                    let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                    if exponent.is_empty() || !is_digits(exponent) {
+                        return None;
                     }
+                    "double"
                 }
+                // Turtle decimals need at least one digit after the point
+                None if fraction.is_some_and(str::is_empty) => return None,
+                None if fraction.is_some() => "decimal",
+                None => "integer",
             }
-            i += 1;
+        };
+
+        let lexical_form = self.alloc_string_clone(token);
+        let datatype = format!("{}{}", NS_XSD, datatype);
+        if !self.config.infer_literal_datatypes
+            || !crate::entities::global_literal_parsers().is_recognized(&datatype)
+        {
+            return Some(Literal::simple(lexical_form));
         }
+        let datatype = Self::arc_to_iri(IRI::new_optimized(datatype)).ok()?;
+        Some(Literal::typed(lexical_form, datatype))
+    }
+
+    /// Parse blank node structure [ ... ] using arena allocation
+    ///
+    /// Keeps every predicate-object pair in order, including those listed with
+    /// `;` and `,` separators.
+    fn parse_blank_node_structure(&self, content: &str) -> Option<(NestedObject, usize)> {
+        let inner = content.strip_prefix('[')?.strip_suffix(']')?;
+        let clean_content = self.alloc_string(inner.trim());
+        let tokens = self.tokenize_turtle_line(clean_content);
+        let properties = self.parse_predicate_object_list(tokens).unwrap_or_default();
 
         let nested_object = NestedObject {
             object_type: self.alloc_string_clone("BlankNode"),
@@ -482,6 +655,28 @@ impl TurtleParser {
 
     /// Parse collection ( ... ) using arena allocation
     fn parse_collection(&self, tokens: &[String]) -> Option<(Vec<ObjectValue>, usize)> {
+        // The tokenizer keeps a whole "( ... )" collection together as one token
+        if let Some(inner) = tokens
+            .first()
+            .and_then(|t| t.strip_prefix('('))
+            .and_then(|t| t.strip_suffix(')'))
+        {
+            let inner = self.alloc_string(inner.trim());
+            let tokens = self.tokenize_turtle_line(inner);
+            let mut items = Vec::new();
+            let mut rest = tokens.as_slice();
+            while !rest.is_empty() {
+                match self.parse_object(rest) {
+                    Some((item, remaining)) => {
+                        items.push(item);
+                        rest = &rest[rest.len() - remaining.len()..];
+                    }
+                    None => rest = &rest[1..],
+                }
+            }
+            return Some((items, 1));
+        }
+
         let mut items = Vec::new();
         let mut consumed = 0;
 
@@ -534,14 +729,113 @@ impl TurtleParser {
         }
     }
 
+    /// Record triples describing owl:AllDisjointClasses / owl:AllDifferent nodes
+    ///
+    /// Returns `true` when the triple was consumed and should not be processed further.
+    fn collect_nary_triple(
+        &self,
+        nary_axioms: &mut NaryMembers,
+        subject: &IRI,
+        predicate: &IRI,
+        object: &ObjectValue,
+    ) -> bool {
+        let collector = &mut nary_axioms.axioms;
+        if predicate.as_str() == "http://www.w3.org/1999/02/22-rdf-syntax-ns#type" {
+            if let ObjectValue::IRI(type_iri) = object {
+                if let Some(kind) = NaryAxiomKind::from_type_iri(type_iri.as_str()) {
+                    collector.record_kind(subject.as_str(), kind);
+                    return true;
+                }
+            }
+        } else if predicate.as_str() == RDF_FIRST || predicate.as_str() == RDF_REST {
+            // Explicit list cells are blank nodes; hold them back until the lists are gathered
+            if !subject.as_str().starts_with(BLANK_NODE_NS) {
+                return false;
+            }
+            match object {
+                ObjectValue::IRI(item) if predicate.as_str() == RDF_FIRST => {
+                    collector.record_list_first(subject.as_str(), item.clone());
+                }
+                ObjectValue::IRI(nil) if nil.as_str() == RDF_NIL => {
+                    collector.record_list_rest(subject.as_str(), RDF_NIL);
+                }
+                ObjectValue::BlankNode(rest) if predicate.as_str() == RDF_REST => {
+                    collector.record_list_rest(subject.as_str(), &format!("{BLANK_NODE_NS}{rest}"));
+                }
+                _ => return false,
+            }
+            nary_axioms
+                .cells
+                .push((subject.clone(), predicate.clone(), object.clone()));
+            return true;
+        } else if is_nary_members_predicate(predicate.as_str()) {
+            if let ObjectValue::BlankNode(head) = object {
+                collector.record_member_list(subject.as_str(), &format!("{BLANK_NODE_NS}{head}"));
+                return true;
+            }
+            if let ObjectValue::Nested(nested) = object {
+                let members = nested
+                    .list_items
+                    .iter()
+                    .filter_map(|item| match item {
+                        ObjectValue::IRI(iri) => Some(iri.clone()),
+                        _ => None,
+                    })
+                    .collect();
+                collector.record_members(subject.as_str(), members);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Record the triples of reified statements
+    ///
+    /// Returns `true` when the triple was consumed and should not be processed further.
+    fn collect_reification_triple(
+        collector: &mut ReificationCollector,
+        subject: &IRI,
+        predicate: &IRI,
+        object: &ObjectValue,
+    ) -> bool {
+        let object = match object {
+            ObjectValue::IRI(iri) => {
+                if !is_reification_triple(predicate.as_str(), Some(iri.as_str())) {
+                    return false;
+                }
+                ReificationObject::Named(Arc::new(iri.clone()))
+            }
+            ObjectValue::Literal(literal) => ReificationObject::Literal(literal.clone()),
+            ObjectValue::BlankNode(node_id) => {
+                ReificationObject::Anonymous(Box::new(AnonymousIndividual::new(node_id.clone())))
+            }
+            ObjectValue::Nested(_) => return false,
+        };
+        collector.record(subject, predicate, object)
+    }
+
     /// Process a single triple with comprehensive OWL2 support
+    ///
+    /// Triples with no specific mapping are left to `unmapped`.
     fn process_triple(
         &self,
         ontology: &mut Ontology,
+        unmapped: &mut UnmappedTripleCollector,
         subject: IRI,
         predicate: IRI,
         object: ObjectValue,
     ) -> OwlResult<()> {
+        // Other triples about the ontology itself make up its header
+        if ontology.iri() == Some(&subject)
+            && !matches!(
+                predicate.as_str(),
+                "http://www.w3.org/1999/02/22-rdf-syntax-ns#type"
+                    | "http://www.w3.org/2002/07/owl#imports"
+            )
+        {
+            return self.process_header_triple(ontology, predicate, object);
+        }
+
         match predicate.as_str() {
             // RDF type declarations (entity declarations)
             "http://www.w3.org/1999/02/22-rdf-syntax-ns#type" => {
@@ -561,6 +855,14 @@ impl TurtleParser {
                         ClassExpression::Class(Class::new(super_class_iri)),
                     );
                     ontology.add_axiom(Axiom::SubClassOf(Box::new(subclass_axiom)))?;
+                } else if let ObjectValue::Nested(nested) = object {
+                    if let Some(class_expr) = self.parse_nested_class_expression(&nested) {
+                        let subclass_axiom = SubClassOfAxiom::new(
+                            ClassExpression::Class(Class::new(subject)),
+                            class_expr,
+                        );
+                        ontology.add_axiom(Axiom::SubClassOf(Box::new(subclass_axiom)))?;
+                    }
                 }
             }
 
@@ -636,7 +938,7 @@ impl TurtleParser {
                     ));
                     let restriction = ClassExpression::ObjectAllValuesFrom(
                         Box::new(property_expr),
-                        Box::new(domain_class),
+                        Arc::new(domain_class),
                     );
 
                     let subclass_axiom = SubClassOfAxiom::new(
@@ -644,7 +946,7 @@ impl TurtleParser {
                             Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
                                 ObjectProperty::new(subject),
                             ))),
-                            Box::new(ClassExpression::Class(Class::new(IRI::new(
+                            Arc::new(ClassExpression::Class(Class::new(IRI::new(
                                 "http://www.w3.org/2002/07/owl#Thing",
                             )?))),
                         ),
@@ -665,7 +967,7 @@ impl TurtleParser {
                     let subclass_axiom = SubClassOfAxiom::new(
                         ClassExpression::ObjectAllValuesFrom(
                             Box::new(property_expr),
-                            Box::new(range_class),
+                            Arc::new(range_class),
                         ),
                         ClassExpression::Class(Class::new(IRI::new(
                             "http://www.w3.org/2002/07/owl#Thing",
@@ -683,12 +985,93 @@ impl TurtleParser {
             }
 
             // Property assertions (individual relationships)
-            _ => {
-                // Handle as property assertion between individuals
-                self.process_property_assertion(ontology, subject, predicate, object)?;
+            _ => match object {
+                ObjectValue::IRI(iri) => {
+                    unmapped.record(subject, predicate, TripleObject::Named(iri));
+                }
+                ObjectValue::Literal(literal) => {
+                    unmapped.record(subject, predicate, TripleObject::Literal(literal));
+                }
+                ObjectValue::BlankNode(node_id) => {
+                    let individual = Box::new(AnonymousIndividual::new(node_id));
+                    unmapped.record(subject, predicate, TripleObject::Anonymous(individual));
+                }
+                ObjectValue::Nested(nested) => {
+                    self.process_nested_property_assertion(
+                        ontology, unmapped, subject, predicate, *nested,
+                    )?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Apply the malformed literal policy to the literal objects of a statement
+    /// ending on `line`
+    fn check_literals(
+        &self,
+        pairs: Vec<(IRI, ObjectValue)>,
+        line: usize,
+        text: &str,
+    ) -> OwlResult<Vec<(IRI, ObjectValue)>> {
+        pairs
+            .into_iter()
+            .map(|(predicate, object)| {
+                let object = match object {
+                    ObjectValue::Literal(literal) => ObjectValue::Literal(check_literal(
+                        literal,
+                        self.config.malformed_literal,
+                        line,
+                        text,
+                    )?),
+                    object => object,
+                };
+                Ok((predicate, object))
+            })
+            .collect()
+    }
+
+    /// Process the predicate-object pairs of one statement about `subject`
+    fn process_statement(
+        &self,
+        ontology: &mut Ontology,
+        nary_axioms: &mut NaryMembers,
+        reification: &mut ReificationCollector,
+        unmapped: &mut UnmappedTripleCollector,
+        subject: &IRI,
+        pairs: Vec<(IRI, ObjectValue)>,
+    ) -> OwlResult<()> {
+        for (predicate, object) in pairs {
+            if !self.collect_nary_triple(nary_axioms, subject, &predicate, &object)
+                && !Self::collect_reification_triple(reification, subject, &predicate, &object)
+            {
+                self.process_triple(ontology, unmapped, subject.clone(), predicate, object)?;
             }
         }
+        Ok(())
+    }
 
+    /// Process a triple about the ontology: its version IRI or an annotation
+    fn process_header_triple(
+        &self,
+        ontology: &mut Ontology,
+        predicate: IRI,
+        object: ObjectValue,
+    ) -> OwlResult<()> {
+        let value = match object {
+            ObjectValue::IRI(iri)
+                if predicate.as_str() == "http://www.w3.org/2002/07/owl#versionIRI" =>
+            {
+                ontology.set_version_iri(iri);
+                return Ok(());
+            }
+            ObjectValue::IRI(iri) => AnnotationValue::from(iri),
+            ObjectValue::Literal(literal) => AnnotationValue::from(literal),
+            // Nested annotation values are not supported
+            ObjectValue::BlankNode(_) | ObjectValue::Nested(_) => return Ok(()),
+        };
+        ontology.add_annotation(Annotation::new(predicate, value));
         Ok(())
     }
 
@@ -711,19 +1094,16 @@ impl TurtleParser {
                 "http://www.w3.org/2002/07/owl#ObjectProperty" => {
                     ontology.add_object_property(ObjectProperty::new(subject))?;
                 }
-                "http://www.w3.org/2002/07/owl#DataProperty" => {
+                "http://www.w3.org/2002/07/owl#DataProperty"
+                | "http://www.w3.org/2002/07/owl#DatatypeProperty" => {
                     ontology.add_data_property(DataProperty::new(subject))?;
                 }
+                "http://www.w3.org/2002/07/owl#AnnotationProperty" => {
+                    ontology.add_annotation_property(AnnotationProperty::new(subject))?;
+                }
+                // A declaration, not membership of a class
                 "http://www.w3.org/2002/07/owl#NamedIndividual" => {
-                    let individual = NamedIndividual::new(subject.clone());
-                    ontology.add_named_individual(individual.clone())?;
-
-                    // Create class assertion
-                    let class_assertion = ClassAssertionAxiom::new(
-                        Arc::new(subject.clone()),
-                        ClassExpression::Class(Class::new(type_iri.clone())),
-                    );
-                    ontology.add_axiom(Axiom::ClassAssertion(Box::new(class_assertion)))?;
+                    ontology.add_named_individual(NamedIndividual::new(subject))?;
                 }
                 // Handle property declarations
                 "http://www.w3.org/1999/02/22-rdf-syntax-ns#Property" => {
@@ -764,99 +1144,46 @@ impl TurtleParser {
         Ok(())
     }
 
-    /// Process property assertions between individuals
-    fn process_property_assertion(
+    /// Process a property assertion whose object is a nested structure
+    ///
+    /// A `[ ... ]` object becomes a fresh anonymous individual, and the triples
+    /// inside the brackets are processed with that individual as their subject.
+    fn process_nested_property_assertion(
         &self,
         ontology: &mut Ontology,
+        unmapped: &mut UnmappedTripleCollector,
         subject: IRI,
         predicate: IRI,
-        object: ObjectValue,
+        nested: NestedObject,
     ) -> OwlResult<()> {
-        // Create or ensure subject individual exists
-        let subject_individual = NamedIndividual::new(subject.clone());
-        ontology.add_named_individual(subject_individual.clone())?;
-
-        match object {
-            ObjectValue::IRI(object_iri) => {
-                // Object property assertion
-                let object_individual = NamedIndividual::new(object_iri.clone());
-                ontology.add_named_individual(object_individual.clone())?;
-
-                let property_assertion = PropertyAssertionAxiom::new(
-                    subject_individual.iri().clone(),
-                    Arc::new(predicate),
-                    object_individual.iri().clone(),
-                );
-                ontology.add_axiom(Axiom::PropertyAssertion(Box::new(property_assertion)))?;
-            }
-            ObjectValue::Literal(literal) => {
-                // Data property assertion with literal values
-                let data_property_assertion = DataPropertyAssertionAxiom::new(
-                    subject_individual.iri().clone(),
-                    Arc::new(predicate.clone()),
-                    literal.clone(),
-                );
-                ontology.add_data_property_assertion(data_property_assertion)?;
-            }
-            ObjectValue::BlankNode(node_id) => {
-                // Create anonymous individual for blank node
-                let anon_individual = AnonymousIndividual::new(node_id);
-                ontology.add_anonymous_individual(anon_individual.clone())?;
-
-                // Create property assertion with anonymous individual as object
-                let property_assertion = PropertyAssertionAxiom::new_with_anonymous(
-                    subject_individual.iri().clone(),
-                    Arc::new(predicate),
-                    anon_individual,
-                );
-                ontology.add_axiom(Axiom::PropertyAssertion(Box::new(property_assertion)))?;
-            }
-            ObjectValue::Nested(nested) => {
-                // Handle RDF collections and other nested structures
-                if nested.object_type == "Collection" || nested.object_type == "RDFList" {
-                    self.process_rdf_collection(
-                        ontology,
-                        subject_individual.iri(),
-                        predicate,
-                        &nested,
-                    )?;
-                } else if nested.object_type == "BlankNode" {
-                    // Create anonymous individual for nested blank node
-                    use std::collections::hash_map::DefaultHasher;
-                    use std::hash::{Hash, Hasher};
-
-                    let mut hasher = DefaultHasher::new();
-                    format!("{:?}", nested).hash(&mut hasher);
-                    let anon_id = format!("nested_{}", hasher.finish());
-                    let anon_individual = AnonymousIndividual::new(anon_id);
-                    ontology.add_anonymous_individual(anon_individual.clone())?;
-
-                    // Create property assertion with anonymous individual
-                    let property_assertion = PropertyAssertionAxiom::new_with_anonymous(
-                        subject_individual.iri().clone(),
-                        Arc::new(predicate),
-                        anon_individual,
-                    );
-                    ontology.add_axiom(Axiom::PropertyAssertion(Box::new(property_assertion)))?;
-
-                    // Also process any properties defined inside the nested blank node
-                    for (prop_str, _obj_str) in &nested.properties {
-                        if let Ok(_prop_iri) = self.parse_curie_or_iri(prop_str) {
-                            // For each property, create a new property assertion
-                            // This is simplified - in a full implementation, we'd parse the actual objects
-                            if prop_str.contains("name") {
-                                // Create a literal for the name property
-                                let _name_literal = Literal::simple("Anonymous Person".to_string());
-                                // Note: This is a data property assertion which needs a different axiom type
-                                // For now, we'll just acknowledge that properties exist
-                            }
-                        }
-                    }
-                } else {
-                    // Handle other nested object types
-                    // For now, skip complex nested structures
-                }
+        // Handle RDF collections and other nested structures
+        if nested.object_type == "Collection" || nested.object_type == "RDFList" {
+            // Create or ensure subject individual exists
+            let subject_individual = NamedIndividual::new(subject);
+            ontology.add_named_individual(subject_individual.clone())?;
+            self.process_rdf_collection(ontology, subject_individual.iri(), predicate, &nested)?;
+        } else if nested.object_type == "BlankNode" {
+            let id = self.blank_node_counter.get() + 1;
+            self.blank_node_counter.set(id);
+            let node_id = format!("anon{}", id);
+            let node_subject =
+                Self::arc_to_iri(IRI::new_optimized(format!("http://blank.node/{}", node_id)))?;
+
+            let individual = Box::new(AnonymousIndividual::new(node_id));
+            unmapped.record(subject, predicate, TripleObject::Anonymous(individual));
+
+            for (inner_predicate, object) in nested.properties {
+                self.process_triple(
+                    ontology,
+                    unmapped,
+                    node_subject.clone(),
+                    inner_predicate,
+                    object,
+                )?;
             }
+        } else {
+            // Handle other nested object types
+            // For now, skip complex nested structures
         }
         Ok(())
     }
@@ -917,12 +1244,12 @@ impl TurtleParser {
                 // Handle intersectionOf, unionOf, oneOf
                 if !nested.list_items.is_empty() {
                     // Default to intersection for collections
-                    let classes: SmallVec<[Box<ClassExpression>; 4]> = nested
+                    let classes: SmallVec<[Arc<ClassExpression>; 4]> = nested
                         .list_items
                         .iter()
                         .filter_map(|item| {
                             if let ObjectValue::IRI(iri) = item {
-                                Some(Box::new(ClassExpression::Class(Class::new(iri.clone()))))
+                                Some(Arc::new(ClassExpression::Class(Class::new(iri.clone()))))
                             } else {
                                 None
                             }
@@ -937,35 +1264,39 @@ impl TurtleParser {
             }
             "BlankNode" => {
                 // Check for restriction patterns in properties
-                if let Some(ObjectValue::IRI(prop_iri)) = nested
-                    .properties
-                    .get("http://www.w3.org/2002/07/owl#onProperty")
+                if let Some(ObjectValue::IRI(prop_iri)) =
+                    nested.property("http://www.w3.org/2002/07/owl#onProperty")
                 {
                     let property_expr = ObjectPropertyExpression::ObjectProperty(Box::new(
                         ObjectProperty::new(prop_iri.clone()),
                     ));
 
                     // Check for someValuesFrom
-                    if let Some(ObjectValue::IRI(range_iri)) = nested
-                        .properties
-                        .get("http://www.w3.org/2002/07/owl#someValuesFrom")
+                    if let Some(ObjectValue::IRI(range_iri)) =
+                        nested.property("http://www.w3.org/2002/07/owl#someValuesFrom")
                     {
                         return Some(ClassExpression::ObjectSomeValuesFrom(
                             Box::new(property_expr),
-                            Box::new(ClassExpression::Class(Class::new(range_iri.clone()))),
+                            Arc::new(ClassExpression::Class(Class::new(range_iri.clone()))),
                         ));
                     }
 
                     // Check for allValuesFrom
-                    if let Some(ObjectValue::IRI(range_iri)) = nested
-                        .properties
-                        .get("http://www.w3.org/2002/07/owl#allValuesFrom")
+                    if let Some(ObjectValue::IRI(range_iri)) =
+                        nested.property("http://www.w3.org/2002/07/owl#allValuesFrom")
                     {
                         return Some(ClassExpression::ObjectAllValuesFrom(
                             Box::new(property_expr),
-                            Box::new(ClassExpression::Class(Class::new(range_iri.clone()))),
+                            Arc::new(ClassExpression::Class(Class::new(range_iri.clone()))),
                         ));
                     }
+
+                    // Check for hasSelf
+                    if let Some(ObjectValue::Literal(value)) = nested.property(OWL_HAS_SELF) {
+                        if is_true_literal(value) {
+                            return Some(ClassExpression::ObjectHasSelf(Box::new(property_expr)));
+                        }
+                    }
                 }
                 None
             }
@@ -1025,6 +1356,14 @@ impl OntologyParser for TurtleParser {
     }
 }
 
+#[cfg(feature = "async")]
+impl crate::parser::AsyncOntologyParser for TurtleParser {
+    fn detached(&self) -> crate::parser::DetachedParser {
+        let config = self.config.clone();
+        Box::new(move |content| Self::with_config(config).parse_str(content))
+    }
+}
+
 impl Default for TurtleParser {
     fn default() -> Self {
         Self::new()
@@ -1069,6 +1408,42 @@ impl TurtleParser {
                     MAX_LINE_LENGTH
                 )));
             }
+            self.validate_token_lengths(line, line_num + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Reject the IRIs and literals on a line that exceed the configured limits
+    ///
+    /// Runs before the line is tokenized, so an over-long term is never copied.
+    fn validate_token_lengths(&self, line: &str, line_num: usize) -> OwlResult<()> {
+        let limits = self.config.token_limits();
+        let mut chars = line.char_indices();
+        while let Some((start, c)) = chars.next() {
+            let (close, overflow): (char, OverflowCheck) = match c {
+                '<' => ('>', TokenLimits::iri_overflow),
+                '"' => ('"', TokenLimits::literal_overflow),
+                // The rest of the line is a comment
+                '#' => break,
+                _ => continue,
+            };
+
+            let mut length = 0;
+            let mut escaped = false;
+            for (_, c) in chars.by_ref() {
+                if c == close && !escaped {
+                    break;
+                }
+                escaped = c == '\\' && !escaped;
+                length += c.len_utf8();
+                if let Some(message) = overflow(&limits, length) {
+                    return Err(OwlError::ParseErrorWithLocation {
+                        line: line_num,
+                        column: line[..start].chars().count() + 1,
+                        message,
+                    });
+                }
+            }
         }
         Ok(())
     }
@@ -1123,11 +1498,98 @@ impl TurtleParser {
         Ok(())
     }
 
+    /// Store pending comments as source comment annotations on `subject`, or on
+    /// the ontology itself when there is no subject
+    fn attach_comments(
+        ontology: &mut Ontology,
+        subject: Option<&IRI>,
+        comments: &mut Vec<String>,
+    ) -> OwlResult<()> {
+        if comments.is_empty() {
+            return Ok(());
+        }
+        let property = Arc::new(IRI::new(crate::constants::SOURCE_COMMENT)?);
+        for comment in comments.drain(..) {
+            let value = AnnotationValue::Literal(Literal::simple(comment));
+            match subject {
+                Some(subject) => {
+                    ontology.add_axiom(Axiom::AnnotationAssertion(Box::new(
+                        AnnotationAssertionAxiom::new(
+                            property.clone(),
+                            Arc::new(subject.clone()),
+                            value,
+                        ),
+                    )))?;
+                }
+                None => ontology.add_annotation(Annotation::new(property.clone(), value)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Cut a line at the start of its comment
+    ///
+    /// A `#` only starts a comment outside IRI references and string literals.
+    fn strip_comment(line: &str) -> &str {
+        let mut in_iri = false;
+        let mut in_string = false;
+        let mut escaped = false;
+        for (index, c) in line.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' if !in_iri => in_string = !in_string,
+                '<' if !in_string => in_iri = true,
+                '>' if !in_string => in_iri = false,
+                '#' if !in_iri && !in_string => return &line[..index],
+                _ => {}
+            }
+        }
+        line
+    }
+
+    /// Number of `[` and `(` in a statement still waiting for their closing bracket
+    fn open_bracket_depth(stmt: &str) -> isize {
+        let mut depth = 0;
+        let mut in_iri = false;
+        let mut in_string = false;
+        let mut escaped = false;
+        for c in stmt.chars() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' if in_string => escaped = true,
+                '"' if !in_iri => in_string = !in_string,
+                '<' if !in_string => in_iri = true,
+                '>' if !in_string => in_iri = false,
+                '[' | '(' if !in_iri && !in_string => depth += 1,
+                ']' | ')' if !in_iri && !in_string => depth -= 1,
+                _ => {}
+            }
+        }
+        depth
+    }
+
+    /// Split a `;` or `,` written against the end of a token into its own token
+    fn split_separators(token: String) -> Vec<String> {
+        match token.strip_suffix([';', ',']) {
+            Some(rest) if !rest.is_empty() => {
+                let separator = token[rest.len()..].to_string();
+                let mut tokens = Self::split_separators(rest.to_string());
+                tokens.push(separator);
+                tokens
+            }
+            _ => vec![token],
+        }
+    }
+
     /// Validate basic Turtle structure
     fn validate_turtle_structure(&self, content: &str) -> OwlResult<()> {
         let mut line_count = 0;
+        let mut content_lines = 0;
         let mut statement_count = 0;
         let mut prefix_count = 0;
+        // Lines of a statement whose brackets are still open
+        let mut statement = String::new();
 
         for line in content.lines() {
             line_count += 1;
@@ -1136,6 +1598,7 @@ impl TurtleParser {
             if trimmed.is_empty() || trimmed.starts_with('#') {
                 continue;
             }
+            content_lines += 1;
 
             // Count different types of statements
             if trimmed.starts_with("@prefix") {
@@ -1150,17 +1613,33 @@ impl TurtleParser {
                 continue;
             }
 
-            // Check for unbalanced brackets
-            if !self.validate_balanced_brackets(trimmed) {
+            // Check for unbalanced brackets once a statement spanning lines closes
+            if !statement.is_empty() {
+                statement.push(' ');
+            }
+            statement.push_str(trimmed);
+            if Self::open_bracket_depth(&statement) > 0 {
+                continue;
+            }
+            if !self.validate_balanced_brackets(&statement) {
                 return Err(OwlError::ParseError(format!(
                     "Unbalanced brackets in line {}: {}",
                     line_count, trimmed
                 )));
             }
+            statement.clear();
+        }
+
+        if !self.validate_balanced_brackets(&statement) {
+            return Err(OwlError::ParseError(format!(
+                "Unclosed brackets at end of input: {}",
+                statement
+            )));
         }
 
-        // Validate reasonable content ratios
-        if line_count > 0 && statement_count == 0 && prefix_count == 0 {
+        // Validate reasonable content ratios; a document of only whitespace
+        // and comments is an empty ontology
+        if content_lines > 0 && statement_count == 0 && prefix_count == 0 {
             return Err(OwlError::ParseError(
                 "No valid Turtle statements found".to_string(),
             ));
@@ -1254,6 +1733,16 @@ impl TurtleParser {
     }
 }
 
+/// Members of n-ary axioms, with the `rdf:first`/`rdf:rest` triples of explicit list cells
+///
+/// The cell triples are held back until the whole document has been read; only those
+/// not gathered into an n-ary axiom are then processed as ordinary triples.
+#[derive(Default)]
+struct NaryMembers {
+    axioms: NaryAxiomCollector,
+    cells: Vec<(IRI, IRI, ObjectValue)>,
+}
+
 /// Object values in Turtle (IRI, Literal, Blank Node, or nested structure)
 #[derive(Debug, Clone)]
 #[allow(clippy::upper_case_acronyms)]
@@ -1269,7 +1758,18 @@ enum ObjectValue {
 #[derive(Debug, Clone)]
 struct NestedObject {
     object_type: String,
-    properties: HashMap<String, ObjectValue>,
+    /// Predicate-object pairs in document order; a predicate may repeat
+    properties: Vec<(IRI, ObjectValue)>,
     /// For list-like structures (intersectionOf, oneOf, etc.)
     list_items: Vec<ObjectValue>,
 }
+
+impl NestedObject {
+    /// The first object given for a predicate
+    fn property(&self, predicate: &str) -> Option<&ObjectValue> {
+        self.properties
+            .iter()
+            .find(|(iri, _)| iri.as_str() == predicate)
+            .map(|(_, object)| object)
+    }
+}
@@ -0,0 +1,559 @@
+//! Turtle serializer for OWL2 ontologies
+//!
+//! Writes an [`Ontology`] as Turtle read back by [`TurtleParser`](super::TurtleParser):
+//! the ontology header, entity declarations, class axioms and then assertions,
+//! one triple per line and sorted within each group so that the output is
+//! stable. IRIs are abbreviated with the `rdf`, `rdfs`, `owl` and `xsd`
+//! prefixes and those in [`ParserConfig::prefixes`], and written in full when
+//! no prefix matches; only the prefixes in use are declared. Source comments
+//! kept by `ParserConfig::preserve_comments` are written back ahead of the
+//! declaration of the entity they describe. Anonymous class expressions are
+//! written as nested blank nodes, `[ a owl:Restriction ; ... ]` and
+//! `[ a owl:Class ; owl:unionOf ( ... ) ]`, including those the Turtle parser only
+//! reads in part. Axioms with no class axiom or assertion triples, such as property
+//! chains and characteristics, and restrictions on complex data ranges are omitted.
+
+use crate::axioms::class_expressions::{ClassExpression, DataRange};
+use crate::axioms::*;
+use crate::constants::{owl, SOURCE_COMMENT};
+use crate::entities::*;
+use crate::error::OwlResult;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::parser::ParserConfig;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
+
+/// Prefixes available to every document, unless the configuration rebinds them
+const STANDARD_PREFIXES: [(&str, &str); 4] = [
+    ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+    ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+    ("owl", "http://www.w3.org/2002/07/owl#"),
+    ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+];
+
+const XSD_NON_NEGATIVE_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#nonNegativeInteger";
+
+/// Turtle format serializer
+#[derive(Debug, Clone, Default)]
+pub struct TurtleSerializer {
+    config: ParserConfig,
+}
+
+impl TurtleSerializer {
+    /// Create a new Turtle serializer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a Turtle serializer abbreviating IRIs with the configured prefixes
+    pub fn with_config(config: ParserConfig) -> Self {
+        TurtleSerializer { config }
+    }
+
+    /// Serialize an ontology to a Turtle document
+    pub fn serialize_ontology(&self, ontology: &Ontology) -> OwlResult<String> {
+        let mut writer = TermWriter::new(&self.config.prefixes);
+        let comments = source_comments(ontology);
+
+        let mut header = Vec::new();
+        if let Some(iri) = ontology.iri() {
+            let subject = writer.iri(iri);
+            for annotation in ontology.annotations() {
+                if let (SOURCE_COMMENT, AnnotationValue::Literal(comment)) =
+                    (annotation.property().as_str(), annotation.value())
+                {
+                    header.push(format!("# {}", comment.lexical_form()));
+                }
+            }
+            header.push(format!("{} a owl:Ontology .", subject));
+            writer.use_prefix("owl");
+            if let Some(version_iri) = ontology.version_iri() {
+                let version = writer.iri(version_iri);
+                header.push(format!("{} owl:versionIRI {} .", subject, version));
+            }
+            let mut imports: Vec<&Arc<IRI>> = ontology.imports().iter().collect();
+            imports.sort();
+            for import in imports {
+                let import = writer.iri(import);
+                header.push(format!("{} owl:imports {} .", subject, import));
+            }
+            let mut annotations: Vec<String> = ontology
+                .annotations()
+                .iter()
+                .filter(|annotation| annotation.property().as_str() != SOURCE_COMMENT)
+                .map(|annotation| {
+                    let property = writer.iri(annotation.property());
+                    let value = writer.annotation_value(annotation.value());
+                    format!("{} {} {} .", subject, property, value)
+                })
+                .collect();
+            annotations.sort();
+            header.extend(annotations);
+        }
+
+        let mut declarations: Vec<(String, String)> = Vec::new();
+        let mut declare = |writer: &mut TermWriter, iri: &IRI, kind: &str| {
+            let line = format!("{} a owl:{} .", writer.iri(iri), kind);
+            let mut text = String::new();
+            for comment in comments.get(iri).into_iter().flatten() {
+                text.push_str(&format!("# {}\n", comment));
+            }
+            text.push_str(&line);
+            declarations.push((line, text));
+        };
+        for class in ontology.classes() {
+            declare(&mut writer, class.iri(), "Class");
+        }
+        for property in ontology.object_properties() {
+            declare(&mut writer, property.iri(), "ObjectProperty");
+        }
+        for property in ontology.data_properties() {
+            declare(&mut writer, property.iri(), "DatatypeProperty");
+        }
+        for property in ontology.annotation_properties() {
+            declare(&mut writer, property.iri(), "AnnotationProperty");
+        }
+        for individual in ontology.named_individuals() {
+            declare(&mut writer, individual.iri(), "NamedIndividual");
+        }
+        if !declarations.is_empty() {
+            writer.use_prefix("owl");
+        }
+        declarations.sort();
+
+        // Complex equivalences are held as a pair of subclass axioms
+        let subclass_axioms: HashSet<(&ClassExpression, &ClassExpression)> = ontology
+            .subclass_axioms()
+            .iter()
+            .map(|axiom| (axiom.sub_class(), axiom.super_class()))
+            .collect();
+        let mut class_axioms = Vec::new();
+        let mut assertions = Vec::new();
+        for axiom in ontology.axioms() {
+            match axiom.as_ref() {
+                Axiom::SubClassOf(axiom) => {
+                    let (sub, sup) = (axiom.sub_class(), axiom.super_class());
+                    if let Some(line) = writer.domain_or_range(sub, sup) {
+                        class_axioms.push(line);
+                        continue;
+                    }
+                    // The other half of a complex equivalence, written with the first
+                    let named = |e: &ClassExpression| matches!(e, ClassExpression::Class(_));
+                    if !named(sub) && named(sup) && subclass_axioms.contains(&(sup, sub)) {
+                        continue;
+                    }
+                    let (Some(subject), Some(object)) =
+                        (writer.class_expression(sub), writer.class_expression(sup))
+                    else {
+                        continue;
+                    };
+                    let equivalent =
+                        named(sub) && !named(sup) && subclass_axioms.contains(&(sup, sub));
+                    let predicate = if equivalent {
+                        writer.use_prefix("owl");
+                        "owl:equivalentClass"
+                    } else {
+                        writer.use_prefix("rdfs");
+                        "rdfs:subClassOf"
+                    };
+                    class_axioms.push(format!("{} {} {} .", subject, predicate, object));
+                }
+                Axiom::EquivalentClasses(axiom) => {
+                    let expressions = axiom.class_expressions();
+                    let Some(operands) = expressions
+                        .iter()
+                        .map(|expression| writer.class_expression(expression))
+                        .collect::<Option<Vec<String>>>()
+                    else {
+                        continue;
+                    };
+                    // A named class, if there is one, is the subject of the triples
+                    let first = expressions
+                        .iter()
+                        .position(|expression| expression.as_named().is_some())
+                        .unwrap_or(0);
+                    for (i, other) in operands.iter().enumerate() {
+                        if i != first {
+                            class_axioms.push(format!(
+                                "{} owl:equivalentClass {} .",
+                                operands[first], other
+                            ));
+                            writer.use_prefix("owl");
+                        }
+                    }
+                }
+                Axiom::DisjointClasses(axiom) => {
+                    let classes = axiom.classes();
+                    for (i, first) in classes.iter().enumerate() {
+                        for second in &classes[i + 1..] {
+                            let (first, second) = (writer.iri(first), writer.iri(second));
+                            class_axioms.push(format!("{} owl:disjointWith {} .", first, second));
+                            writer.use_prefix("owl");
+                        }
+                    }
+                }
+                Axiom::ClassAssertion(axiom) => {
+                    if let Some(class) = writer.class_expression(axiom.class_expr()) {
+                        let individual = writer.iri(axiom.individual());
+                        assertions.push(format!("{} a {} .", individual, class));
+                    }
+                }
+                Axiom::PropertyAssertion(axiom) => {
+                    let object = match axiom.object() {
+                        PropertyAssertionObject::Named(object) => writer.iri(object),
+                        PropertyAssertionObject::Anonymous(individual) => {
+                            format!("_:{}", individual.node_id())
+                        }
+                    };
+                    let subject = writer.iri(axiom.subject());
+                    let property = writer.iri(axiom.property());
+                    assertions.push(format!("{} {} {} .", subject, property, object));
+                }
+                Axiom::DataPropertyAssertion(axiom) => {
+                    let subject = writer.iri(axiom.subject());
+                    let property = writer.iri(axiom.property());
+                    let value = writer.literal(axiom.value());
+                    assertions.push(format!("{} {} {} .", subject, property, value));
+                }
+                Axiom::AnnotationAssertion(axiom) => {
+                    if axiom.annotation_property().as_str() == SOURCE_COMMENT {
+                        continue;
+                    }
+                    let subject = writer.iri(axiom.subject());
+                    let property = writer.iri(axiom.annotation_property());
+                    let value = writer.annotation_value(axiom.value());
+                    assertions.push(format!("{} {} {} .", subject, property, value));
+                }
+                _ => {}
+            }
+        }
+        class_axioms.sort();
+        assertions.sort();
+
+        let mut output = String::new();
+        for (name, namespace) in &writer.used {
+            output.push_str(&format!("@prefix {}: <{}> .\n", name, namespace));
+        }
+        let declarations: Vec<String> = declarations.into_iter().map(|(_, text)| text).collect();
+        for section in [header, declarations, class_axioms, assertions] {
+            if section.is_empty() {
+                continue;
+            }
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            for line in section {
+                output.push_str(&line);
+                output.push('\n');
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// Source comments by the IRI of the entity they describe
+fn source_comments(ontology: &Ontology) -> HashMap<&IRI, Vec<&str>> {
+    let mut comments: HashMap<&IRI, Vec<&str>> = HashMap::new();
+    for axiom in ontology.annotation_assertion_axioms() {
+        if let (SOURCE_COMMENT, AnnotationValue::Literal(comment)) =
+            (axiom.annotation_property().as_str(), axiom.value())
+        {
+            comments
+                .entry(&**axiom.subject())
+                .or_default()
+                .push(comment.lexical_form());
+        }
+    }
+    comments
+}
+
+/// Writes terms, abbreviating IRIs and recording the prefixes used
+struct TermWriter {
+    /// Prefix names and namespaces, longest namespace first
+    prefixes: Vec<(String, String)>,
+    /// Prefixes used so far, to be declared
+    used: BTreeMap<String, String>,
+}
+
+impl TermWriter {
+    fn new(configured: &HashMap<String, String>) -> Self {
+        let mut prefixes: Vec<(String, String)> = STANDARD_PREFIXES
+            .iter()
+            .filter(|(name, _)| !configured.contains_key(*name))
+            .map(|(name, namespace)| (name.to_string(), namespace.to_string()))
+            .chain(
+                configured
+                    .iter()
+                    .map(|(name, namespace)| (name.clone(), namespace.clone())),
+            )
+            .collect();
+        prefixes.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(&b.0)));
+        TermWriter {
+            prefixes,
+            used: BTreeMap::new(),
+        }
+    }
+
+    /// Declare a prefix used by fixed syntax, such as `owl:Class`
+    fn use_prefix(&mut self, name: &str) {
+        if let Some((name, namespace)) = self.prefixes.iter().find(|(n, _)| n == name) {
+            self.used.insert(name.clone(), namespace.clone());
+        }
+    }
+
+    /// A prefixed name for the IRI, or the IRI in full
+    fn iri(&mut self, iri: &IRI) -> String {
+        for (name, namespace) in &self.prefixes {
+            if let Some(local) = iri.as_str().strip_prefix(namespace.as_str()) {
+                if is_local_name(local) {
+                    self.used.insert(name.clone(), namespace.clone());
+                    return format!("{}:{}", name, local);
+                }
+            }
+        }
+        format!("<{}>", iri.as_str())
+    }
+
+    fn literal(&mut self, literal: &Literal) -> String {
+        let quoted = format!("\"{}\"", escape_string(literal.lexical_form()));
+        if let Some(language) = literal.language_tag() {
+            format!("{}@{}", quoted, language)
+        } else if literal.datatype().as_str() == XSD_STRING {
+            quoted
+        } else {
+            format!("{}^^{}", quoted, self.iri(literal.datatype()))
+        }
+    }
+
+    fn annotation_value(&mut self, value: &AnnotationValue) -> String {
+        match value {
+            AnnotationValue::IRI(iri) => self.iri(iri),
+            AnnotationValue::Literal(literal) => self.literal(literal),
+            AnnotationValue::AnonymousIndividual(node_id) => format!("_:{}", node_id),
+        }
+    }
+
+    /// A named class, or a blank node describing an anonymous class expression
+    ///
+    /// Returns `None` for restrictions on data ranges other than datatypes.
+    fn class_expression(&mut self, expression: &ClassExpression) -> Option<String> {
+        let (property, restriction) = match expression {
+            ClassExpression::Class(class) => return Some(self.iri(class.iri())),
+            ClassExpression::ObjectIntersectionOf(operands)
+            | ClassExpression::ObjectUnionOf(operands) => {
+                let predicate = if matches!(expression, ClassExpression::ObjectIntersectionOf(_)) {
+                    "owl:intersectionOf"
+                } else {
+                    "owl:unionOf"
+                };
+                let operands = operands
+                    .iter()
+                    .map(|operand| self.class_expression(operand))
+                    .collect::<Option<Vec<String>>>()?;
+                self.use_prefix("owl");
+                return Some(format!(
+                    "[ a owl:Class ; {} ( {} ) ]",
+                    predicate,
+                    operands.join(" ")
+                ));
+            }
+            ClassExpression::ObjectComplementOf(operand) => {
+                let operand = self.class_expression(operand)?;
+                self.use_prefix("owl");
+                return Some(format!("[ a owl:Class ; owl:complementOf {} ]", operand));
+            }
+            ClassExpression::ObjectOneOf(individuals) => {
+                let individuals: Vec<String> = individuals
+                    .iter()
+                    .map(|individual| self.individual(individual))
+                    .collect();
+                self.use_prefix("owl");
+                return Some(format!(
+                    "[ a owl:Class ; owl:oneOf ( {} ) ]",
+                    individuals.join(" ")
+                ));
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                let filler = self.class_expression(filler)?;
+                (
+                    self.property(property),
+                    format!("owl:someValuesFrom {}", filler),
+                )
+            }
+            ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                let filler = self.class_expression(filler)?;
+                (
+                    self.property(property),
+                    format!("owl:allValuesFrom {}", filler),
+                )
+            }
+            ClassExpression::ObjectHasValue(property, individual) => {
+                let individual = self.individual(individual);
+                (
+                    self.property(property),
+                    format!("owl:hasValue {}", individual),
+                )
+            }
+            ClassExpression::ObjectHasSelf(property) => {
+                (self.property(property), "owl:hasSelf true".to_string())
+            }
+            ClassExpression::ObjectMinCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (self.property(property), format!("owl:minCardinality {}", n))
+            }
+            ClassExpression::ObjectMaxCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (self.property(property), format!("owl:maxCardinality {}", n))
+            }
+            ClassExpression::ObjectExactCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (self.property(property), format!("owl:cardinality {}", n))
+            }
+            ClassExpression::DataSomeValuesFrom(property, range)
+            | ClassExpression::DataAllValuesFrom(property, range) => {
+                let DataRange::Datatype(datatype) = range.as_ref() else {
+                    return None;
+                };
+                let predicate = if matches!(expression, ClassExpression::DataSomeValuesFrom(..)) {
+                    "owl:someValuesFrom"
+                } else {
+                    "owl:allValuesFrom"
+                };
+                let datatype = self.iri(datatype);
+                (
+                    self.data_property(property),
+                    format!("{} {}", predicate, datatype),
+                )
+            }
+            ClassExpression::DataHasValue(property, value) => {
+                let value = self.literal(value);
+                (
+                    self.data_property(property),
+                    format!("owl:hasValue {}", value),
+                )
+            }
+            ClassExpression::DataMinCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (
+                    self.data_property(property),
+                    format!("owl:minCardinality {}", n),
+                )
+            }
+            ClassExpression::DataMaxCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (
+                    self.data_property(property),
+                    format!("owl:maxCardinality {}", n),
+                )
+            }
+            ClassExpression::DataExactCardinality(n, property) => {
+                let n = self.cardinality(*n);
+                (
+                    self.data_property(property),
+                    format!("owl:cardinality {}", n),
+                )
+            }
+        };
+        self.use_prefix("owl");
+        Some(format!(
+            "[ a owl:Restriction ; owl:onProperty {} ; {} ]",
+            property, restriction
+        ))
+    }
+
+    /// A named object property, or a blank node for an inverse one
+    fn property(&mut self, property: &ObjectPropertyExpression) -> String {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(property) => self.iri(property.iri()),
+            ObjectPropertyExpression::ObjectInverseOf(inner) => {
+                let inner = self.property(inner);
+                self.use_prefix("owl");
+                format!("[ owl:inverseOf {} ]", inner)
+            }
+        }
+    }
+
+    fn data_property(&mut self, property: &DataPropertyExpression) -> String {
+        match property {
+            DataPropertyExpression::DataProperty(property) => self.iri(property.iri()),
+        }
+    }
+
+    fn individual(&mut self, individual: &Individual) -> String {
+        match individual {
+            Individual::Named(individual) => self.iri(individual.iri()),
+            Individual::Anonymous(individual) => format!("_:{}", individual.node_id()),
+        }
+    }
+
+    /// The number of a cardinality restriction, typed `xsd:nonNegativeInteger`
+    fn cardinality(&mut self, n: u32) -> String {
+        let datatype = IRI::new_optimized(XSD_NON_NEGATIVE_INTEGER)
+            .expect("XSD nonNegativeInteger IRI should always be valid");
+        format!("\"{}\"^^{}", n, self.iri(&datatype))
+    }
+
+    /// `rdfs:domain` and `rdfs:range` triples for the subclass axioms the
+    /// Turtle parser reads them as
+    fn domain_or_range(&mut self, sub: &ClassExpression, sup: &ClassExpression) -> Option<String> {
+        let (property, predicate, class) = match (sub, sup) {
+            (
+                ClassExpression::ObjectSomeValuesFrom(some, thing),
+                ClassExpression::ObjectAllValuesFrom(all, domain),
+            ) if some == all && is_thing(thing) => (some, "rdfs:domain", named_class(domain)?),
+            (ClassExpression::ObjectAllValuesFrom(property, range), thing) if is_thing(thing) => {
+                (property, "rdfs:range", named_class(range)?)
+            }
+            _ => return None,
+        };
+        let ObjectPropertyExpression::ObjectProperty(property) = property.as_ref() else {
+            return None;
+        };
+        self.use_prefix("rdfs");
+        Some(format!(
+            "{} {} {} .",
+            self.iri(property.iri()),
+            predicate,
+            self.iri(class)
+        ))
+    }
+}
+
+fn named_class(expression: &ClassExpression) -> Option<&IRI> {
+    match expression {
+        ClassExpression::Class(class) => Some(class.iri()),
+        _ => None,
+    }
+}
+
+fn is_thing(expression: &ClassExpression) -> bool {
+    named_class(expression).is_some_and(|iri| *iri == owl::thing())
+}
+
+/// Whether a prefixed name with this local part can be read back unambiguously
+fn is_local_name(local: &str) -> bool {
+    local
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Escape a string for a Turtle quoted literal
+fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
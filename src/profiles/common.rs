@@ -5,7 +5,7 @@
 
 #![allow(dead_code)]
 
-use crate::axioms::class_expressions::ClassExpression;
+use crate::axioms::class_expressions::{ClassExpression, DataRange};
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
@@ -30,6 +30,26 @@ impl ClassExpressionExt for ClassExpression {
     }
 }
 
+/// Whether a class expression is built only from the constructors OWL2 EL allows
+///
+/// Unions, complements, universal restrictions, cardinality restrictions and
+/// enumerations of more than one individual are outside EL.
+fn is_el_class_expression(expression: &ClassExpression) -> bool {
+    match expression {
+        ClassExpression::Class(_)
+        | ClassExpression::ObjectHasValue(_, _)
+        | ClassExpression::ObjectHasSelf(_)
+        | ClassExpression::DataSomeValuesFrom(_, _)
+        | ClassExpression::DataHasValue(_, _) => true,
+        ClassExpression::ObjectIntersectionOf(operands) => operands
+            .iter()
+            .all(|operand| is_el_class_expression(operand)),
+        ClassExpression::ObjectSomeValuesFrom(_, filler) => is_el_class_expression(filler),
+        ClassExpression::ObjectOneOf(individuals) => individuals.len() == 1,
+        _ => false,
+    }
+}
+
 /// OWL2 Profile types
 #[derive(
     Debug,
@@ -147,6 +167,171 @@ pub struct ValidationStatistics {
     pub memory_usage_bytes: usize,
 }
 
+/// Datatypes of the OWL2 EL and QL datatype maps
+const EL_QL_DATATYPES: &[&str] = &[
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral",
+    "http://www.w3.org/1999/02/22-rdf-syntax-ns#XMLLiteral",
+    "http://www.w3.org/2000/01/rdf-schema#Literal",
+    "http://www.w3.org/2002/07/owl#real",
+    "http://www.w3.org/2002/07/owl#rational",
+    "http://www.w3.org/2001/XMLSchema#decimal",
+    "http://www.w3.org/2001/XMLSchema#integer",
+    "http://www.w3.org/2001/XMLSchema#nonNegativeInteger",
+    "http://www.w3.org/2001/XMLSchema#string",
+    "http://www.w3.org/2001/XMLSchema#normalizedString",
+    "http://www.w3.org/2001/XMLSchema#token",
+    "http://www.w3.org/2001/XMLSchema#Name",
+    "http://www.w3.org/2001/XMLSchema#NCName",
+    "http://www.w3.org/2001/XMLSchema#NMTOKEN",
+    "http://www.w3.org/2001/XMLSchema#hexBinary",
+    "http://www.w3.org/2001/XMLSchema#base64Binary",
+    "http://www.w3.org/2001/XMLSchema#anyURI",
+    "http://www.w3.org/2001/XMLSchema#dateTime",
+    "http://www.w3.org/2001/XMLSchema#dateTimeStamp",
+];
+
+/// Datatypes outside the OWL2 RL datatype map
+const NON_RL_DATATYPES: &[&str] = &[
+    "http://www.w3.org/2002/07/owl#real",
+    "http://www.w3.org/2002/07/owl#rational",
+];
+
+/// Data ranges and datatypes the profile does not allow
+///
+/// Every profile allows datatypes and their intersections, and EL also an
+/// enumeration of a single literal. Unions, complements, other enumerations
+/// and facet restrictions are never allowed. EL and QL accept only the
+/// datatypes of their datatype map, while RL accepts all but `owl:real` and
+/// `owl:rational`. Data property ranges and the data restrictions nested in
+/// the class expressions of every axiom are checked.
+pub(crate) fn data_range_violations(
+    ontology: &Ontology,
+    profile: &Owl2Profile,
+) -> Vec<ProfileViolation> {
+    let mut violations = Vec::new();
+
+    for axiom in ontology.data_property_range_axioms() {
+        if let Some(message) = disallowed_datatype(axiom.range(), profile) {
+            violations.push(ProfileViolation {
+                violation_type: ProfileViolationType::DataPropertyRanges,
+                message,
+                affected_entities: vec![axiom.property().clone()],
+                severity: ViolationSeverity::Error,
+            });
+        }
+    }
+
+    for axiom in ontology.axioms() {
+        for expression in axiom.class_expressions() {
+            check_data_ranges_in_expression(expression, profile, &mut violations);
+        }
+    }
+
+    violations
+}
+
+fn check_data_ranges_in_expression(
+    expr: &ClassExpression,
+    profile: &Owl2Profile,
+    violations: &mut Vec<ProfileViolation>,
+) {
+    match expr {
+        ClassExpression::ObjectIntersectionOf(operands)
+        | ClassExpression::ObjectUnionOf(operands) => {
+            for operand in operands {
+                check_data_ranges_in_expression(operand, profile, violations);
+            }
+        }
+        ClassExpression::ObjectComplementOf(operand)
+        | ClassExpression::ObjectSomeValuesFrom(_, operand)
+        | ClassExpression::ObjectAllValuesFrom(_, operand) => {
+            check_data_ranges_in_expression(operand, profile, violations);
+        }
+        ClassExpression::DataSomeValuesFrom(property, range)
+        | ClassExpression::DataAllValuesFrom(property, range) => {
+            check_data_range(range, property.property_iri(), profile, violations);
+        }
+        _ => {}
+    }
+}
+
+fn check_data_range(
+    range: &DataRange,
+    property: &IRI,
+    profile: &Owl2Profile,
+    violations: &mut Vec<ProfileViolation>,
+) {
+    let (violation_type, message) = match range {
+        DataRange::Datatype(datatype) => match disallowed_datatype(datatype, profile) {
+            Some(message) => (ProfileViolationType::UnsupportedConstruct, message),
+            None => return,
+        },
+        DataRange::DataIntersectionOf(ranges) => {
+            for range in ranges {
+                check_data_range(range, property, profile, violations);
+            }
+            return;
+        }
+        DataRange::DataOneOf(literals) if literals.len() == 1 && *profile == Owl2Profile::EL => {
+            return;
+        }
+        DataRange::DataOneOf(_) => (
+            ProfileViolationType::DataOneOf,
+            format!(
+                "Enumeration of literals (DataOneOf) is not allowed in {:?} profile",
+                profile
+            ),
+        ),
+        DataRange::DataUnionOf(_) => (
+            ProfileViolationType::ComplexDataRanges,
+            format!(
+                "Union of data ranges (DataUnionOf) is not allowed in {:?} profile",
+                profile
+            ),
+        ),
+        DataRange::DataComplementOf(_) => (
+            ProfileViolationType::DataComplementOf,
+            format!(
+                "Complement of a data range (DataComplementOf) is not allowed in {:?} profile",
+                profile
+            ),
+        ),
+        DataRange::DatatypeRestriction(datatype, facets) => (
+            ProfileViolationType::ComplexDataRanges,
+            format!(
+                "Facet restriction of {} on {} is not allowed in {:?} profile",
+                facets
+                    .iter()
+                    .map(|facet| facet.facet().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                datatype,
+                profile
+            ),
+        ),
+    };
+    violations.push(ProfileViolation {
+        violation_type,
+        message,
+        affected_entities: vec![property.clone()],
+        severity: ViolationSeverity::Error,
+    });
+}
+
+/// Why the datatype is outside the profile's datatype map, if it is
+fn disallowed_datatype(datatype: &IRI, profile: &Owl2Profile) -> Option<String> {
+    let allowed = match profile {
+        Owl2Profile::EL | Owl2Profile::QL => EL_QL_DATATYPES.contains(&datatype.as_str()),
+        Owl2Profile::RL => !NON_RL_DATATYPES.contains(&datatype.as_str()),
+    };
+    (!allowed).then(|| {
+        format!(
+            "Datatype {} is not allowed in {:?} profile",
+            datatype, profile
+        )
+    })
+}
+
 /// Profile validator trait
 pub trait ProfileValidator {
     fn validate_profile(&mut self, profile: Owl2Profile) -> OwlResult<ProfileValidationResult>;
@@ -454,6 +639,8 @@ impl Owl2ProfileValidator {
             }
         }
 
+        violations.extend(data_range_violations(&self.ontology, &Owl2Profile::EL));
+
         Ok(violations)
     }
 
@@ -492,6 +679,8 @@ impl Owl2ProfileValidator {
             }
         }
 
+        violations.extend(data_range_violations(&self.ontology, &Owl2Profile::QL));
+
         Ok(violations)
     }
 
@@ -525,6 +714,8 @@ impl Owl2ProfileValidator {
             }
         }
 
+        violations.extend(data_range_violations(&self.ontology, &Owl2Profile::RL));
+
         Ok(violations)
     }
 
@@ -548,9 +739,9 @@ impl Owl2ProfileValidator {
     }
 
     // Helper methods for compatibility checking
-    fn is_el_compatible_subclass_axiom(&self, _subclass: &crate::axioms::SubClassOfAxiom) -> bool {
-        // Simplified EL compatibility check
-        true // For now, assume compatibility
+    fn is_el_compatible_subclass_axiom(&self, subclass: &crate::axioms::SubClassOfAxiom) -> bool {
+        is_el_class_expression(subclass.sub_class())
+            && is_el_class_expression(subclass.super_class())
     }
 
     fn is_rl_compatible_subclass_axiom(&self, _subclass: &crate::axioms::SubClassOfAxiom) -> bool {
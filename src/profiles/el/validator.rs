@@ -22,7 +22,10 @@ use crate::axioms::ClassExpression;
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
-use crate::profiles::common::{ProfileViolation, ProfileViolationType, ViolationSeverity};
+use crate::profiles::common::{
+    data_range_violations, ProfileViolation, ProfileViolationType, ViolationSeverity,
+};
+use crate::profiles::Owl2Profile;
 use crate::utils::iri::IriUtils;
 use crate::utils::smallvec::SmallVecUtils;
 use std::sync::Arc;
@@ -269,30 +272,9 @@ impl ElValidator {
 
     /// Check data property ranges for EL profile compliance
     fn check_data_property_ranges_for_el(&self) -> OwlResult<Vec<ProfileViolation>> {
-        let violations = SmallVecUtils::violations();
-
-        // EL Profile allows only basic datatypes for data property ranges
-        // Complex datatypes like DataComplementOf, DataOneOf are not allowed
-
-        for _axiom in self
-            .ontology
-            .axioms_by_type(crate::axioms::AxiomType::DataPropertyDomain)
-        {
-            // Check if domain uses complex data ranges
-            // For now, we assume basic compliance since detailed data range analysis is complex
-            // This is a conservative approach
-        }
-
-        for _axiom in self
-            .ontology
-            .axioms_by_type(crate::axioms::AxiomType::DataPropertyRange)
-        {
-            // Check if range uses complex data ranges
-            // For now, we assume basic compliance since detailed data range analysis is complex
-            // This is a conservative approach
-        }
-
-        Ok(violations.into_vec())
+        // Only datatypes of the EL datatype map, their intersections and
+        // single-literal enumerations are allowed
+        Ok(data_range_violations(&self.ontology, &Owl2Profile::EL))
     }
 
     /// Extract entities from class expression for violation reporting (optimized)
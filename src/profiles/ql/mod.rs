@@ -4,8 +4,10 @@
 //! and optimization for OWL2 ontologies.
 
 pub mod optimization;
+pub mod rewriting;
 pub mod validator; // TODO: Fix optimization module
 
 // Re-export QL profile types and functions
 pub use optimization::*;
+pub use rewriting::*;
 pub use validator::*;
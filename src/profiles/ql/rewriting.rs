@@ -0,0 +1,651 @@
+//! OWL2 QL Query Rewriting
+//!
+//! Answers conjunctive queries over an OWL2 QL ontology by perfect reformulation
+//! (the PerfectRef algorithm of DL-Lite). The query is rewritten with the positive
+//! inclusions of the TBox into a union of conjunctive queries whose plain
+//! evaluation over the asserted ABox yields the certain answers, so no inferred
+//! facts are materialized.
+//!
+//! Axioms outside the QL profile are skipped, so the answers are complete only for
+//! QL ontologies. Negative inclusions such as disjointness only bear on
+//! consistency and take no part in the rewriting.
+
+use crate::axioms::{ClassExpression, ObjectPropertyExpression};
+use crate::error::OwlResult;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::reasoning::query::{PatternTerm, QueryPattern, TriplePattern, RDF_TYPE};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Namespace of the roles introduced to split qualified existentials
+const FRESH_ROLE_NAMESPACE: &str = "urn:owl2-reasoner:ql-rewriting#role";
+
+/// A term of a query atom
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum QueryTerm {
+    /// A variable, named without the leading `?`
+    Variable(String),
+    /// A named individual
+    Individual(IRI),
+}
+
+impl QueryTerm {
+    /// A variable term
+    pub fn var(name: &str) -> Self {
+        QueryTerm::Variable(name.to_string())
+    }
+
+    fn to_pattern_term(&self) -> PatternTerm {
+        match self {
+            QueryTerm::Variable(name) => PatternTerm::Variable(name.clone()),
+            QueryTerm::Individual(iri) => PatternTerm::IRI(iri.clone()),
+        }
+    }
+}
+
+/// An atom of a conjunctive query
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum QueryAtom {
+    /// `term rdf:type class`
+    Class { class: IRI, term: QueryTerm },
+    /// `subject property object`
+    Property {
+        property: IRI,
+        subject: QueryTerm,
+        object: QueryTerm,
+    },
+}
+
+impl QueryAtom {
+    fn terms(&self) -> Vec<&QueryTerm> {
+        match self {
+            QueryAtom::Class { term, .. } => vec![term],
+            QueryAtom::Property {
+                subject, object, ..
+            } => vec![subject, object],
+        }
+    }
+
+    fn map_terms(&self, f: impl Fn(&QueryTerm) -> QueryTerm) -> QueryAtom {
+        match self {
+            QueryAtom::Class { class, term } => QueryAtom::Class {
+                class: class.clone(),
+                term: f(term),
+            },
+            QueryAtom::Property {
+                property,
+                subject,
+                object,
+            } => QueryAtom::Property {
+                property: property.clone(),
+                subject: f(subject),
+                object: f(object),
+            },
+        }
+    }
+}
+
+/// A conjunctive query: a conjunction of atoms, answered by the bindings of
+/// its answer variables
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConjunctiveQuery {
+    /// Variables whose bindings form the answers, in order
+    pub answer_variables: Vec<String>,
+    pub atoms: Vec<QueryAtom>,
+}
+
+impl ConjunctiveQuery {
+    pub fn new(answer_variables: Vec<String>, atoms: Vec<QueryAtom>) -> Self {
+        Self {
+            answer_variables,
+            atoms,
+        }
+    }
+
+    /// The query as a basic graph pattern for the query engine
+    pub fn to_pattern(&self) -> QueryPattern {
+        let rdf_type = PatternTerm::IRI(IRI::new(RDF_TYPE).expect("rdf:type is a valid IRI"));
+        let triples = self
+            .atoms
+            .iter()
+            .map(|atom| match atom {
+                QueryAtom::Class { class, term } => TriplePattern {
+                    subject: term.to_pattern_term(),
+                    predicate: rdf_type.clone(),
+                    object: PatternTerm::IRI(class.clone()),
+                },
+                QueryAtom::Property {
+                    property,
+                    subject,
+                    object,
+                } => TriplePattern {
+                    subject: subject.to_pattern_term(),
+                    predicate: PatternTerm::IRI(property.clone()),
+                    object: object.to_pattern_term(),
+                },
+            })
+            .collect();
+        QueryPattern::BasicGraphPattern(triples)
+    }
+
+    fn is_answer_variable(&self, name: &str) -> bool {
+        self.answer_variables.iter().any(|v| v == name)
+    }
+
+    /// Whether a term is a variable that is not answered and occurs only once,
+    /// so that it only asks for the existence of some value
+    fn is_unbound(&self, term: &QueryTerm) -> bool {
+        match term {
+            QueryTerm::Variable(name) => {
+                !self.is_answer_variable(name)
+                    && self
+                        .atoms
+                        .iter()
+                        .flat_map(QueryAtom::terms)
+                        .filter(|t| *t == term)
+                        .count()
+                        == 1
+            }
+            QueryTerm::Individual(_) => false,
+        }
+    }
+
+    /// A form shared by queries differing only in the names of their
+    /// non-answer variables
+    fn canonical_key(&self) -> Vec<QueryAtom> {
+        let mut renaming: HashMap<String, QueryTerm> = HashMap::new();
+        for term in self.atoms.iter().flat_map(QueryAtom::terms) {
+            if let QueryTerm::Variable(name) = term {
+                if !self.is_answer_variable(name) && !renaming.contains_key(name) {
+                    let renamed = if self.is_unbound(term) {
+                        "_".to_string()
+                    } else {
+                        format!("_v{}", renaming.len())
+                    };
+                    renaming.insert(name.clone(), QueryTerm::Variable(renamed));
+                }
+            }
+        }
+        let mut atoms: Vec<QueryAtom> = self
+            .atoms
+            .iter()
+            .map(|atom| {
+                atom.map_terms(|term| match term {
+                    QueryTerm::Variable(name) => {
+                        renaming.get(name).cloned().unwrap_or_else(|| term.clone())
+                    }
+                    _ => term.clone(),
+                })
+            })
+            .collect();
+        atoms.sort();
+        atoms.dedup();
+        atoms
+    }
+}
+
+/// A property or its inverse
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Role {
+    property: IRI,
+    inverse: bool,
+}
+
+impl Role {
+    fn from_expression(expression: &ObjectPropertyExpression) -> Self {
+        match expression {
+            ObjectPropertyExpression::ObjectProperty(property) => Role {
+                property: (**property.iri()).clone(),
+                inverse: false,
+            },
+            ObjectPropertyExpression::ObjectInverseOf(inner) => {
+                Self::from_expression(inner).inverse()
+            }
+        }
+    }
+
+    fn named(property: &IRI) -> Self {
+        Role {
+            property: property.clone(),
+            inverse: false,
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        Role {
+            property: self.property.clone(),
+            inverse: !self.inverse,
+        }
+    }
+
+    /// The atom `self(subject, object)`
+    fn atom(&self, subject: QueryTerm, object: QueryTerm) -> QueryAtom {
+        let (subject, object) = if self.inverse {
+            (object, subject)
+        } else {
+            (subject, object)
+        };
+        QueryAtom::Property {
+            property: self.property.clone(),
+            subject,
+            object,
+        }
+    }
+}
+
+/// A concept allowed on the left of a QL inclusion: a named class or `∃R`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BasicConcept {
+    Class(IRI),
+    Exists(Role),
+}
+
+impl BasicConcept {
+    fn from_expression(expression: &ClassExpression) -> Option<Self> {
+        match expression {
+            ClassExpression::Class(class) if !class.is_thing() => {
+                Some(BasicConcept::Class((**class.iri()).clone()))
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) if matches!(filler.as_ref(), ClassExpression::Class(class) if class.is_thing()) => {
+                Some(BasicConcept::Exists(Role::from_expression(property)))
+            }
+            _ => None,
+        }
+    }
+
+    /// The atom stating that `term` is an instance of the concept
+    fn atom(&self, term: QueryTerm, fresh: &mut impl FnMut() -> QueryTerm) -> QueryAtom {
+        match self {
+            BasicConcept::Class(class) => QueryAtom::Class {
+                class: class.clone(),
+                term,
+            },
+            BasicConcept::Exists(role) => role.atom(term, fresh()),
+        }
+    }
+}
+
+/// Rewrites conjunctive queries over an OWL2 QL ontology into unions of
+/// conjunctive queries answered by the ABox alone
+pub struct QlRewriter {
+    ontology: Arc<Ontology>,
+    /// Positive concept inclusions `sub ⊑ super`
+    concept_inclusions: Vec<(BasicConcept, BasicConcept)>,
+    /// Role inclusions `sub ⊑ super`
+    role_inclusions: Vec<(Role, Role)>,
+    /// Roles introduced to split qualified existentials
+    fresh_roles: HashSet<IRI>,
+}
+
+impl QlRewriter {
+    /// Create a rewriter for the TBox of an ontology
+    pub fn new(ontology: Arc<Ontology>) -> OwlResult<Self> {
+        let mut rewriter = Self {
+            ontology: ontology.clone(),
+            concept_inclusions: Vec::new(),
+            role_inclusions: Vec::new(),
+            fresh_roles: HashSet::new(),
+        };
+
+        for axiom in ontology.subclass_axioms() {
+            if let Some(sub) = BasicConcept::from_expression(axiom.sub_class()) {
+                rewriter.add_inclusion(sub, axiom.super_class())?;
+            }
+        }
+        for axiom in ontology.equivalent_classes_axioms() {
+            let expressions = axiom.class_expressions();
+            for (i, sub) in expressions.iter().enumerate() {
+                if let Some(sub) = BasicConcept::from_expression(sub) {
+                    for (j, sup) in expressions.iter().enumerate() {
+                        if i != j {
+                            rewriter.add_inclusion(sub.clone(), sup)?;
+                        }
+                    }
+                }
+            }
+        }
+        for axiom in ontology.object_property_domain_axioms() {
+            let role = Role::from_expression(axiom.property_expression());
+            rewriter.add_inclusion(BasicConcept::Exists(role), axiom.domain())?;
+        }
+        for axiom in ontology.object_property_range_axioms() {
+            let role = Role::from_expression(axiom.property_expression()).inverse();
+            rewriter.add_inclusion(BasicConcept::Exists(role), axiom.range())?;
+        }
+
+        for axiom in ontology.subobject_property_axioms() {
+            rewriter.role_inclusions.push((
+                Role::named(axiom.sub_property()),
+                Role::named(axiom.super_property()),
+            ));
+        }
+        for axiom in ontology.equivalent_object_properties_axioms() {
+            for sub in axiom.properties() {
+                for sup in axiom.properties() {
+                    if sub != sup {
+                        rewriter
+                            .role_inclusions
+                            .push((Role::named(sub), Role::named(sup)));
+                    }
+                }
+            }
+        }
+        for axiom in ontology.inverse_object_properties_axioms() {
+            let first = Role::from_expression(axiom.property1());
+            let second = Role::from_expression(axiom.property2());
+            rewriter
+                .role_inclusions
+                .push((first.clone(), second.inverse()));
+            rewriter.role_inclusions.push((second, first.inverse()));
+        }
+        for axiom in ontology.symmetric_property_axioms() {
+            let role = Role::named(axiom.property());
+            rewriter
+                .role_inclusions
+                .push((role.clone(), role.inverse()));
+        }
+
+        Ok(rewriter)
+    }
+
+    /// Record `sub ⊑ sup`, splitting `sup` into basic concepts
+    fn add_inclusion(&mut self, sub: BasicConcept, sup: &ClassExpression) -> OwlResult<()> {
+        if let Some(sup) = BasicConcept::from_expression(sup) {
+            self.concept_inclusions.push((sub, sup));
+            return Ok(());
+        }
+        match sup {
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                for operand in operands {
+                    self.add_inclusion(sub.clone(), operand)?;
+                }
+            }
+            // sub ⊑ ∃R.C becomes sub ⊑ ∃F, F ⊑ R and ∃F⁻ ⊑ C for a fresh role F
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                let fresh = IRI::new(format!(
+                    "{}{}",
+                    FRESH_ROLE_NAMESPACE,
+                    self.fresh_roles.len()
+                ))?;
+                self.fresh_roles.insert(fresh.clone());
+                let fresh = Role::named(&fresh);
+                self.concept_inclusions
+                    .push((sub, BasicConcept::Exists(fresh.clone())));
+                self.role_inclusions
+                    .push((fresh.clone(), Role::from_expression(property)));
+                self.add_inclusion(BasicConcept::Exists(fresh.inverse()), filler)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Rewrite a query into the union of conjunctive queries (its perfect
+    /// reformulation) whose answers over the ABox are the certain answers
+    ///
+    /// The original query is always part of the union.
+    pub fn rewrite(&self, query: &ConjunctiveQuery) -> Vec<ConjunctiveQuery> {
+        let mut seen = HashSet::new();
+        let mut rewritings = Vec::new();
+        let mut pending = vec![query.clone()];
+        let mut fresh_variables = 0usize;
+
+        while let Some(current) = pending.pop() {
+            if !seen.insert(current.canonical_key()) {
+                continue;
+            }
+
+            for (index, atom) in current.atoms.iter().enumerate() {
+                for replacement in self.rewrite_atom(&current, atom, &mut fresh_variables) {
+                    let mut atoms = current.atoms.clone();
+                    atoms[index] = replacement;
+                    pending.push(ConjunctiveQuery::new(
+                        current.answer_variables.clone(),
+                        atoms,
+                    ));
+                }
+            }
+            for i in 0..current.atoms.len() {
+                for j in i + 1..current.atoms.len() {
+                    if let Some(reduced) = Self::reduce(&current, i, j) {
+                        pending.push(reduced);
+                    }
+                }
+            }
+
+            rewritings.push(current);
+        }
+
+        // Fresh roles have no assertions, so queries using them have no answers
+        rewritings.retain(|query| {
+            !query.atoms.iter().any(|atom| {
+                matches!(atom, QueryAtom::Property { property, .. }
+                    if self.fresh_roles.contains(property))
+            })
+        });
+        rewritings
+    }
+
+    /// Atoms that entail `atom` by one inclusion of the TBox
+    fn rewrite_atom(
+        &self,
+        query: &ConjunctiveQuery,
+        atom: &QueryAtom,
+        fresh_variables: &mut usize,
+    ) -> Vec<QueryAtom> {
+        let mut fresh = || {
+            *fresh_variables += 1;
+            QueryTerm::Variable(format!("_{}", fresh_variables))
+        };
+        let mut rewritten = Vec::new();
+
+        match atom {
+            QueryAtom::Class { class, term } => {
+                let target = BasicConcept::Class(class.clone());
+                for (sub, sup) in &self.concept_inclusions {
+                    if *sup == target {
+                        rewritten.push(sub.atom(term.clone(), &mut fresh));
+                    }
+                }
+            }
+            QueryAtom::Property {
+                property,
+                subject,
+                object,
+            } => {
+                // With one side unbound the atom only asks for ∃P or ∃P⁻
+                let role = Role::named(property);
+                let existential = if query.is_unbound(object) {
+                    Some((BasicConcept::Exists(role.clone()), subject))
+                } else if query.is_unbound(subject) {
+                    Some((BasicConcept::Exists(role.inverse()), object))
+                } else {
+                    None
+                };
+                if let Some((target, term)) = existential {
+                    for (sub, sup) in &self.concept_inclusions {
+                        if *sup == target {
+                            rewritten.push(sub.atom(term.clone(), &mut fresh));
+                        }
+                    }
+                }
+
+                for (sub, sup) in &self.role_inclusions {
+                    if *sup == role {
+                        rewritten.push(sub.atom(subject.clone(), object.clone()));
+                    } else if *sup == role.inverse() {
+                        rewritten.push(sub.inverse().atom(subject.clone(), object.clone()));
+                    }
+                }
+            }
+        }
+        rewritten
+    }
+
+    /// Unify two atoms of a query, if they unify without binding an answer
+    /// variable, and drop the duplicate
+    fn reduce(query: &ConjunctiveQuery, i: usize, j: usize) -> Option<ConjunctiveQuery> {
+        let pairs: Vec<(&QueryTerm, &QueryTerm)> = match (&query.atoms[i], &query.atoms[j]) {
+            (QueryAtom::Class { class: a, term: s }, QueryAtom::Class { class: b, term: t })
+                if a == b =>
+            {
+                vec![(s, t)]
+            }
+            (
+                QueryAtom::Property {
+                    property: p,
+                    subject: s1,
+                    object: o1,
+                },
+                QueryAtom::Property {
+                    property: q,
+                    subject: s2,
+                    object: o2,
+                },
+            ) if p == q => vec![(s1, s2), (o1, o2)],
+            _ => return None,
+        };
+
+        let mut substitution: HashMap<String, QueryTerm> = HashMap::new();
+        let resolve = |term: &QueryTerm, substitution: &HashMap<String, QueryTerm>| {
+            let mut term = term.clone();
+            while let QueryTerm::Variable(name) = &term {
+                match substitution.get(name) {
+                    Some(next) => term = next.clone(),
+                    None => break,
+                }
+            }
+            term
+        };
+        for (left, right) in pairs {
+            let (left, right) = (resolve(left, &substitution), resolve(right, &substitution));
+            if left == right {
+                continue;
+            }
+            match (&left, &right) {
+                (QueryTerm::Variable(name), _) if !query.is_answer_variable(name) => {
+                    substitution.insert(name.clone(), right);
+                }
+                (_, QueryTerm::Variable(name)) if !query.is_answer_variable(name) => {
+                    substitution.insert(name.clone(), left);
+                }
+                _ => return None,
+            }
+        }
+
+        let mut atoms: Vec<QueryAtom> = Vec::new();
+        for atom in &query.atoms {
+            let atom = atom.map_terms(|term| resolve(term, &substitution));
+            if !atoms.contains(&atom) {
+                atoms.push(atom);
+            }
+        }
+        Some(ConjunctiveQuery::new(query.answer_variables.clone(), atoms))
+    }
+
+    /// Get the certain answers of a query
+    ///
+    /// Each answer binds the answer variables in order. The rewritten queries
+    /// are evaluated over the asserted class and object property assertions only.
+    pub fn answer(&self, query: &ConjunctiveQuery) -> HashSet<Vec<IRI>> {
+        let abox = Abox::new(&self.ontology);
+        let mut answers = HashSet::new();
+        for rewriting in self.rewrite(query) {
+            abox.evaluate(&rewriting, 0, &mut HashMap::new(), &mut answers);
+        }
+        answers
+    }
+}
+
+/// Named class and object property assertions, indexed by class and property
+struct Abox {
+    instances: HashMap<IRI, Vec<IRI>>,
+    relations: HashMap<IRI, Vec<(IRI, IRI)>>,
+}
+
+impl Abox {
+    fn new(ontology: &Ontology) -> Self {
+        let mut instances: HashMap<IRI, Vec<IRI>> = HashMap::new();
+        for assertion in ontology.class_assertions() {
+            if let Some(class) = assertion.class_expr().as_named() {
+                instances
+                    .entry((**class.iri()).clone())
+                    .or_default()
+                    .push((**assertion.individual()).clone());
+            }
+        }
+        let mut relations: HashMap<IRI, Vec<(IRI, IRI)>> = HashMap::new();
+        for assertion in ontology.property_assertions() {
+            if let Some(object) = assertion.object_iri() {
+                relations
+                    .entry((**assertion.property()).clone())
+                    .or_default()
+                    .push(((**assertion.subject()).clone(), (**object).clone()));
+            }
+        }
+        Self {
+            instances,
+            relations,
+        }
+    }
+
+    /// Match the atoms from `index` on, collecting the answers of full matches
+    fn evaluate(
+        &self,
+        query: &ConjunctiveQuery,
+        index: usize,
+        bindings: &mut HashMap<String, IRI>,
+        answers: &mut HashSet<Vec<IRI>>,
+    ) {
+        let Some(atom) = query.atoms.get(index) else {
+            let answer: Option<Vec<IRI>> = query
+                .answer_variables
+                .iter()
+                .map(|variable| bindings.get(variable).cloned())
+                .collect();
+            if let Some(answer) = answer {
+                answers.insert(answer);
+            }
+            return;
+        };
+
+        let facts: Vec<Vec<&IRI>> = match atom {
+            QueryAtom::Class { class, .. } => self
+                .instances
+                .get(class)
+                .map(|individuals| individuals.iter().map(|i| vec![i]).collect())
+                .unwrap_or_default(),
+            QueryAtom::Property { property, .. } => self
+                .relations
+                .get(property)
+                .map(|pairs| pairs.iter().map(|(s, o)| vec![s, o]).collect())
+                .unwrap_or_default(),
+        };
+
+        for fact in facts {
+            let mut bound = Vec::new();
+            let matches = atom
+                .terms()
+                .into_iter()
+                .zip(fact)
+                .all(|(term, value)| match term {
+                    QueryTerm::Individual(individual) => individual == value,
+                    QueryTerm::Variable(name) => match bindings.get(name) {
+                        Some(existing) => existing == value,
+                        None => {
+                            bindings.insert(name.clone(), value.clone());
+                            bound.push(name.clone());
+                            true
+                        }
+                    },
+                });
+            if matches {
+                self.evaluate(query, index + 1, bindings, answers);
+            }
+            for name in bound {
+                bindings.remove(&name);
+            }
+        }
+    }
+}
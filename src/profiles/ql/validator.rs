@@ -20,7 +20,10 @@ use crate::axioms::ClassExpression;
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
-use crate::profiles::common::{ProfileViolation, ProfileViolationType, ViolationSeverity};
+use crate::profiles::common::{
+    data_range_violations, ProfileViolation, ProfileViolationType, ViolationSeverity,
+};
+use crate::profiles::Owl2Profile;
 use crate::utils::smallvec::SmallVecUtils;
 use std::sync::Arc;
 
@@ -53,6 +56,9 @@ impl QlValidator {
         // 4. No property chain axioms
         violations.extend(self.check_property_chains_for_ql()?);
 
+        // 5. Only datatypes of the QL datatype map and their intersections as data ranges
+        violations.extend(data_range_violations(&self.ontology, &Owl2Profile::QL));
+
         Ok(violations.into_vec())
     }
 
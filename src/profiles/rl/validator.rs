@@ -19,7 +19,10 @@ use crate::axioms::ClassExpression;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
-use crate::profiles::common::{ProfileViolation, ProfileViolationType, ViolationSeverity};
+use crate::profiles::common::{
+    data_range_violations, ProfileViolation, ProfileViolationType, ViolationSeverity,
+};
+use crate::profiles::Owl2Profile;
 use std::sync::Arc;
 
 /// RL Profile Validator
@@ -42,19 +45,16 @@ impl RlValidator {
         // 1. No nominals in certain contexts (actually allowed in RL)
         // RL Profile allows nominals, so this check is not needed
 
-        // 2. No data complement of
-        violations.extend(self.check_data_complement_for_rl()?);
+        // 2. Only datatypes of the RL datatype map and their intersections as data ranges
+        violations.extend(data_range_violations(&self.ontology, &Owl2Profile::RL));
 
-        // 3. No data one of (restricted in RL)
-        violations.extend(self.check_data_one_of_for_rl()?);
-
-        // 4. No object complement of
+        // 3. No object complement of
         violations.extend(self.check_object_complement_for_rl()?);
 
-        // 5. No object one of in certain contexts
+        // 4. No object one of in certain contexts
         violations.extend(self.check_object_one_of_for_rl()?);
 
-        // 6. No object has self
+        // 5. No object has self
         violations.extend(self.check_object_has_self_for_rl()?);
 
         Ok(violations)
@@ -63,50 +63,7 @@ impl RlValidator {
     /// Quick check for RL profile compliance
     pub fn quick_check(&self) -> OwlResult<bool> {
         // Quick check for RL profile
-        Ok(!self.has_data_complement()?
-            && !self.has_data_one_of()?
-            && !self.has_object_complement()?)
-    }
-
-    /// Check data complement restrictions for RL profile compliance
-    fn check_data_complement_for_rl(&self) -> OwlResult<Vec<ProfileViolation>> {
-        let mut violations = Vec::new();
-
-        // RL Profile does not allow DataComplementOf
-        // Check all subclass axioms for data complement expressions
-        for axiom in self.ontology.subclass_axioms() {
-            violations.extend(
-                self.check_data_complement_in_expression(axiom.super_class(), axiom.sub_class())?,
-            );
-        }
-
-        // Check equivalent classes axioms
-        for axiom in self.ontology.equivalent_classes_axioms() {
-            for class_iri in axiom.classes() {
-                let class_expr = crate::axioms::ClassExpression::Class(
-                    crate::entities::Class::new(class_iri.as_str()),
-                );
-                violations
-                    .extend(self.check_data_complement_in_expression(&class_expr, &class_expr)?);
-            }
-        }
-
-        Ok(violations)
-    }
-
-    /// Check data one of restrictions for RL profile compliance
-    fn check_data_one_of_for_rl(&self) -> OwlResult<Vec<ProfileViolation>> {
-        let mut violations = Vec::new();
-
-        // RL Profile has restrictions on DataOneOf
-        // Check all subclass axioms for data one of expressions
-        for axiom in self.ontology.subclass_axioms() {
-            violations.extend(
-                self.check_data_one_of_in_expression(axiom.super_class(), axiom.sub_class())?,
-            );
-        }
-
-        Ok(violations)
+        Ok(!self.has_disallowed_data_range() && !self.has_object_complement()?)
     }
 
     /// Check object complement restrictions for RL profile compliance
@@ -177,92 +134,6 @@ impl RlValidator {
         Ok(violations)
     }
 
-    /// Check data complement in class expressions
-    fn check_data_complement_in_expression(
-        &self,
-        expr: &ClassExpression,
-        context: &ClassExpression,
-    ) -> OwlResult<Vec<ProfileViolation>> {
-        let mut violations = Vec::new();
-
-        // DataComplementOf is allowed in RL profile
-        // if let ClassExpression::DataComplementOf(_) = expr {
-        //     violations.push(ProfileViolation {
-        //         violation_type: ProfileViolationType::DataComplementOf,
-        //         message: "Data complement of (DataComplementOf) is not allowed in RL profile".to_string(),
-        //         affected_entities: self.extract_entities_from_class_expression(context)?,
-        //         severity: ViolationSeverity::Error,
-        //     });
-        // }
-
-        // Recursively check nested expressions
-        match expr {
-            ClassExpression::ObjectSomeValuesFrom(_, class_expr) => {
-                violations.extend(self.check_data_complement_in_expression(class_expr, context)?);
-            }
-            ClassExpression::ObjectAllValuesFrom(_, class_expr) => {
-                violations.extend(self.check_data_complement_in_expression(class_expr, context)?);
-            }
-            ClassExpression::ObjectIntersectionOf(classes) => {
-                for class_expr in classes {
-                    violations
-                        .extend(self.check_data_complement_in_expression(class_expr, context)?);
-                }
-            }
-            ClassExpression::ObjectUnionOf(classes) => {
-                for class_expr in classes {
-                    violations
-                        .extend(self.check_data_complement_in_expression(class_expr, context)?);
-                }
-            }
-            _ => {}
-        }
-
-        Ok(violations)
-    }
-
-    /// Check data one of in class expressions
-    fn check_data_one_of_in_expression(
-        &self,
-        expr: &ClassExpression,
-        context: &ClassExpression,
-    ) -> OwlResult<Vec<ProfileViolation>> {
-        let mut violations = Vec::new();
-
-        // DataOneOf is allowed in RL profile
-        // if let ClassExpression::DataOneOf(_) = expr {
-        //     violations.push(ProfileViolation {
-        //         violation_type: ProfileViolationType::DataOneOf,
-        //         message: "Data one of (DataOneOf) is restricted in RL profile".to_string(),
-        //         affected_entities: self.extract_entities_from_class_expression(context)?,
-        //         severity: ViolationSeverity::Warning, // Warning level as some uses might be acceptable
-        //     });
-        // }
-
-        // Recursively check nested expressions
-        match expr {
-            ClassExpression::ObjectSomeValuesFrom(_, class_expr) => {
-                violations.extend(self.check_data_one_of_in_expression(class_expr, context)?);
-            }
-            ClassExpression::ObjectAllValuesFrom(_, class_expr) => {
-                violations.extend(self.check_data_one_of_in_expression(class_expr, context)?);
-            }
-            ClassExpression::ObjectIntersectionOf(classes) => {
-                for class_expr in classes {
-                    violations.extend(self.check_data_one_of_in_expression(class_expr, context)?);
-                }
-            }
-            ClassExpression::ObjectUnionOf(classes) => {
-                for class_expr in classes {
-                    violations.extend(self.check_data_one_of_in_expression(class_expr, context)?);
-                }
-            }
-            _ => {}
-        }
-
-        Ok(violations)
-    }
-
     /// Check object complement in class expressions
     fn check_object_complement_in_expression(
         &self,
@@ -448,18 +319,9 @@ impl RlValidator {
     }
 
     // Helper methods for quick checks
-    fn has_data_complement(&self) -> OwlResult<bool> {
-        // Check for data complement of (not allowed in RL)
-        // NOTE: Data range analysis not yet implemented
-        // Currently returns false as a conservative estimate
-        Ok(false)
-    }
-
-    fn has_data_one_of(&self) -> OwlResult<bool> {
-        // Check for data one of (restricted in RL)
-        // NOTE: Data range analysis not yet implemented
-        // Currently returns false as a conservative estimate
-        Ok(false)
+    fn has_disallowed_data_range(&self) -> bool {
+        // DataComplementOf, DataOneOf, DataUnionOf and facets are not allowed in RL
+        !data_range_violations(&self.ontology, &Owl2Profile::RL).is_empty()
     }
 
     fn has_object_complement(&self) -> OwlResult<bool> {
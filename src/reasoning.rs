@@ -5,26 +5,42 @@
 
 pub mod classification;
 pub mod consistency;
+pub mod el_completion;
+mod imports;
+pub mod justification;
 pub mod profile_optimized;
 pub mod query;
+pub mod regularity;
 pub mod rules;
 pub mod simple;
+mod spans;
 pub mod tableaux;
 
 pub use classification::*;
 pub use consistency::*;
+pub use el_completion::ElCompletion;
 pub use profile_optimized::*;
 pub use query::{
-    FilterExpression, PatternTerm, QueryBinding, QueryConfig, QueryEngine, QueryEngineStats,
-    QueryPattern, QueryResult, QueryValue, TriplePattern,
+    ClosedPredicates, FilterExpression, PatternTerm, PropertyPath, QueryBinding, QueryConfig,
+    QueryEngine, QueryEngineStats, QueryPattern, QueryResult, QueryValue, TriplePattern,
 };
+pub use regularity::RegularityChecker;
 pub use rules::*;
 pub use simple::*;
 pub use tableaux::*;
 
+use crate::axioms::{
+    Axiom, ClassExpression, DataPropertyExpression, ObjectPropertyExpression,
+    PropertyAssertionAxiom,
+};
+use crate::constants::{owl, BLANK_NODE_PREFIX};
+use crate::entities::{AnonymousIndividual, Class, DataProperty, Literal, ObjectProperty};
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use imports::ImportModule;
+use spans::PhaseSpan;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Main OWL2 reasoning engine
@@ -32,6 +48,103 @@ pub struct OwlReasoner {
     simple: SimpleReasoner,
     tableaux: Option<TableauxReasoner>,
     use_advanced_reasoning: bool,
+    last_consistency_method: Option<ConsistencyMethod>,
+    /// Why the property chains are irregular, if they are
+    irregular_chains: Option<String>,
+    /// Imported ontologies kept apart, inlined as queries reach them
+    imports: Option<ImportModule>,
+}
+
+/// Procedure used to decide the consistency of an ontology
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyMethod {
+    /// Polynomial completion for ontologies in the Horn EL fragment
+    HornCompletion,
+    /// Tableaux satisfiability of owl:Thing
+    Tableaux,
+    /// Rule-based checks of the simple reasoner
+    Simple,
+}
+
+/// Inferences that would be lost by removing an axiom
+///
+/// Returned by [`OwlReasoner::inference_impact`]. Both lists are sorted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImpactReport {
+    /// Subsumptions between named classes, as `(subclass, superclass)` pairs
+    pub lost_subsumptions: Vec<(IRI, IRI)>,
+    /// Memberships of named individuals in named classes, as `(individual, class)` pairs
+    pub lost_memberships: Vec<(IRI, IRI)>,
+}
+
+impl ImpactReport {
+    /// Whether removing the axiom loses no inference
+    pub fn is_empty(&self) -> bool {
+        self.lost_subsumptions.is_empty() && self.lost_memberships.is_empty()
+    }
+}
+
+/// Subsumptions and memberships between named entities entailed by an ontology
+#[derive(Default)]
+struct EntailedAtoms {
+    subsumptions: HashSet<(IRI, IRI)>,
+    memberships: HashSet<(IRI, IRI)>,
+}
+
+/// Classify the ontology once and realize its named individuals over the hierarchy
+///
+/// Each named type asserted for an individual contributes itself, its
+/// equivalents and its superclasses. Trivial subsumptions, of a class by
+/// itself or by owl:Thing, are skipped.
+fn entailed_atoms(ontology: Ontology) -> OwlResult<EntailedAtoms> {
+    let thing = owl::thing();
+    let class_assertions: Vec<(IRI, IRI)> = ontology
+        .class_assertions()
+        .iter()
+        .filter_map(|assertion| match assertion.class_expr() {
+            ClassExpression::Class(class) => {
+                Some(((**assertion.individual()).clone(), (**class.iri()).clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    let classes: Vec<IRI> = ontology
+        .classes()
+        .iter()
+        .map(|class| (**class.iri()).clone())
+        .collect();
+    let mut engine = ClassificationEngine::new(ontology);
+    let hierarchy = engine.classify()?.hierarchy;
+
+    let mut atoms = EntailedAtoms::default();
+    let mut superclasses: HashMap<IRI, HashSet<IRI>> = HashMap::new();
+    for class in classes {
+        // Parents may be recorded on any member of an equivalence
+        let mut supers = hierarchy.get_equivalent_classes(&class);
+        for member in supers.clone().iter().chain([&class]) {
+            supers.extend(hierarchy.get_all_superclasses(member));
+        }
+        supers.remove(&thing);
+        if class != thing {
+            for sup in &supers {
+                if *sup != class {
+                    atoms.subsumptions.insert((class.clone(), sup.clone()));
+                }
+            }
+        }
+        superclasses.insert(class, supers);
+    }
+    for (individual, class) in class_assertions {
+        if let Some(supers) = superclasses.get(&class) {
+            for sup in supers {
+                atoms.memberships.insert((individual.clone(), sup.clone()));
+            }
+        }
+        if class != thing {
+            atoms.memberships.insert((individual, class));
+        }
+    }
+    Ok(atoms)
 }
 
 /// Reasoning configuration
@@ -55,6 +168,57 @@ impl Default for ReasoningConfig {
     }
 }
 
+impl ReasoningConfig {
+    /// Quick, approximate answers from the rule-based reasoner alone
+    ///
+    /// The tableaux reasoner is not built, so entailments that need case
+    /// analysis may be missed.
+    pub fn fast() -> Self {
+        ReasoningConfig {
+            enable_reasoning: true,
+            use_advanced_reasoning: false,
+            tableaux_config: tableaux::ReasoningConfig {
+                max_depth: 100,
+                timeout: Some(1_000),
+                max_branches: Some(1_000),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Full tableaux reasoning with generous limits and no timeout
+    pub fn complete() -> Self {
+        ReasoningConfig {
+            enable_reasoning: true,
+            use_advanced_reasoning: true,
+            tableaux_config: tableaux::ReasoningConfig {
+                max_depth: 10_000,
+                timeout: None,
+                max_branches: None,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Tableaux reasoning bounded for untrusted input
+    ///
+    /// Each reasoning call fails with a timeout after five seconds, and with a
+    /// resource limit error after 10 000 disjunction branches, which bounds the
+    /// memory the backtracking search can use.
+    pub fn server() -> Self {
+        ReasoningConfig {
+            enable_reasoning: true,
+            use_advanced_reasoning: true,
+            tableaux_config: tableaux::ReasoningConfig {
+                max_depth: 500,
+                timeout: Some(5_000),
+                max_branches: Some(10_000),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 /// Reasoning capabilities
 pub trait Reasoner {
     /// Check if the ontology is consistent
@@ -83,7 +247,14 @@ impl OwlReasoner {
     }
 
     /// Create a new OWL2 reasoner with custom configuration
+    ///
+    /// Ontologies from [`Ontology::resolve_imports_lazily`] are not merged in:
+    /// each query inlines only the imported axioms connected to the ontology's
+    /// own axioms or to the entities it asks about.
     pub fn with_config(ontology: Ontology, config: ReasoningConfig) -> Self {
+        let irregular_chains = RegularityChecker::new(&ontology).violation();
+        let imports = (!ontology.imported_ontologies().is_empty())
+            .then(|| ImportModule::new(ontology.imported_ontologies().to_vec()));
         let simple = SimpleReasoner::new(ontology.clone());
         let tableaux = if config.use_advanced_reasoning {
             Some(TableauxReasoner::with_config(
@@ -98,6 +269,114 @@ impl OwlReasoner {
             simple,
             tableaux,
             use_advanced_reasoning: config.use_advanced_reasoning,
+            last_consistency_method: None,
+            irregular_chains,
+            imports,
+        }
+    }
+
+    /// Get the procedure used by the last consistency check
+    pub fn last_consistency_method(&self) -> Option<ConsistencyMethod> {
+        self.last_consistency_method
+    }
+
+    /// Reject ontologies that reasoning could not terminate on
+    ///
+    /// Irregular property chains make the property closure infinite, so every
+    /// reasoning call fails with the violation instead of looping.
+    fn preflight(&self) -> OwlResult<()> {
+        match &self.irregular_chains {
+            Some(message) => Err(OwlError::OwlViolation(message.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Inline the imported axioms connected to the given entities
+    ///
+    /// The first call also reaches the entities of the ontology's own axioms.
+    /// Whenever new axioms come in, the reasoners are rebuilt over them.
+    fn consult_imports(&mut self, entities: &[&IRI]) -> OwlResult<()> {
+        let Some(module) = &mut self.imports else {
+            return Ok(());
+        };
+        let mut signature: Vec<IRI> = entities.iter().map(|iri| (*iri).clone()).collect();
+        if module.is_unused() {
+            for axiom in self.simple.ontology.axioms() {
+                signature.extend(axiom.signature().iter().map(|iri| (**iri).clone()));
+            }
+        }
+        let connected = module.extend(&signature);
+        if connected.is_empty() {
+            return Ok(());
+        }
+
+        let mut ontology = self.simple.ontology.clone();
+        for axiom in connected {
+            ontology.add_axiom(axiom)?;
+        }
+        let imports = self.imports.take();
+        *self = Self::with_config(ontology, self.config());
+        self.imports = imports;
+        Ok(())
+    }
+
+    /// The configuration this reasoner was built with
+    fn config(&self) -> ReasoningConfig {
+        ReasoningConfig {
+            enable_reasoning: true,
+            use_advanced_reasoning: self.use_advanced_reasoning,
+            tableaux_config: self
+                .tableaux
+                .as_ref()
+                .map(|tableaux| tableaux.config.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Decide consistency with the cheapest procedure that is complete for the ontology
+    fn check_consistency(&mut self) -> OwlResult<bool> {
+        if self.use_advanced_reasoning {
+            // A literal outside a data property range is a clash for either method
+            let data_ranges_respected =
+                crate::datatypes::data_range_violations(&self.simple.ontology).is_empty();
+            // Horn EL ontologies need no case splits, so completion decides them
+            if let Some(completion) = ElCompletion::new(&self.simple.ontology) {
+                self.last_consistency_method = Some(ConsistencyMethod::HornCompletion);
+                return Ok(data_ranges_respected && completion.is_consistent());
+            }
+            if let Some(tableaux) = &mut self.tableaux {
+                self.last_consistency_method = Some(ConsistencyMethod::Tableaux);
+                // Use tableaux reasoning for proper consistency checking
+                // Check if owl:Thing is satisfiable - if not, ontology is inconsistent
+                let thing_iri = IRI::new("http://www.w3.org/2002/07/owl#Thing").map_err(|e| {
+                    OwlError::ReasoningError(format!("Failed to create owl:Thing IRI: {}", e))
+                })?;
+                return Ok(data_ranges_respected && tableaux.is_class_satisfiable(&thing_iri)?);
+            }
+        }
+        self.last_consistency_method = Some(ConsistencyMethod::Simple);
+        self.simple.is_consistent()
+    }
+
+    /// Estimate consistency from cheap checks, without full reasoning
+    ///
+    /// Clashes among the asserted axioms and Horn EL completion give a definite
+    /// answer; any other ontology is reported as likely consistent with
+    /// [`ConsistencyEstimate::UNDECIDED_CONFIDENCE`]. Use
+    /// [`Reasoner::is_consistent`] for a definite answer in every case.
+    pub fn estimate_consistency(&self) -> ConsistencyEstimate {
+        let cheap_violations = consistency::structural_violations(self.ontology());
+        let (likely_consistent, confidence) = if !cheap_violations.is_empty() {
+            (false, 1.0)
+        } else if let Some(completion) = ElCompletion::new(self.ontology()) {
+            (completion.is_consistent(), 1.0)
+        } else {
+            (true, ConsistencyEstimate::UNDECIDED_CONFIDENCE)
+        };
+        ConsistencyEstimate {
+            likely_consistent,
+            confidence,
+            cheap_violations,
         }
     }
 
@@ -108,9 +387,575 @@ impl OwlReasoner {
 
     /// Check if a class is satisfiable
     pub fn is_class_satisfiable(&mut self, class_iri: &IRI) -> OwlResult<bool> {
+        self.consult_imports(&[class_iri])?;
+        self.preflight()?;
         self.simple.is_class_satisfiable(class_iri)
     }
 
+    /// Enumerate the pairs of named classes that are entailed to be disjoint
+    ///
+    /// Covers both asserted and inferred disjointness, found while classifying
+    /// the ontology. Each pair appears once, with the smaller IRI first.
+    pub fn disjoint_class_pairs(&mut self) -> OwlResult<HashSet<(IRI, IRI)>> {
+        self.preflight()?;
+        let mut engine = ClassificationEngine::new(self.ontology().clone());
+        Ok(engine.classify()?.hierarchy.disjoint_pairs())
+    }
+
+    /// Get the most specific named classes an individual is an instance of
+    ///
+    /// Checks membership in each named class of the ontology and drops every type
+    /// that is a strict superclass of another, so equivalent classes are all kept.
+    /// Unlike realization, only the one individual is classified.
+    pub fn direct_types(&mut self, individual: &IRI) -> OwlResult<HashSet<IRI>> {
+        self.preflight()?;
+        let span = PhaseSpan::realization(self.ontology(), individual);
+        let classes: Vec<IRI> = self
+            .ontology()
+            .classes()
+            .iter()
+            .map(|class| (**class.iri()).clone())
+            .collect();
+
+        let mut types = Vec::new();
+        for class in classes {
+            if self.is_instance_of(individual, &class)? {
+                types.push(class);
+            }
+        }
+
+        let mut direct = HashSet::new();
+        'types: for class in &types {
+            for other in &types {
+                if other != class
+                    && self.is_subclass_of(other, class)?
+                    && !self.is_subclass_of(class, other)?
+                {
+                    continue 'types;
+                }
+            }
+            direct.insert(class.clone());
+        }
+        span.record_count("types", direct.len());
+        Ok(direct)
+    }
+
+    /// Get the asserted and inferred values of an object property for an individual
+    pub fn property_values(
+        &mut self,
+        individual: &IRI,
+        property: &ObjectPropertyExpression,
+    ) -> OwlResult<HashSet<IRI>> {
+        self.preflight()?;
+        self.simple.property_values(individual, property)
+    }
+
+    /// Get every object property assertion the property axioms entail
+    ///
+    /// The asserted edges are closed under sub-properties, equivalent and
+    /// inverse properties, symmetric, transitive and reflexive properties, and
+    /// property chains. Each edge appears once, sorted by subject, property and
+    /// object, and objects named by their blank node are anonymous individuals.
+    pub fn materialized_property_assertions(&mut self) -> OwlResult<Vec<PropertyAssertionAxiom>> {
+        self.preflight()?;
+        let mut edges: Vec<(IRI, IRI, IRI)> = self
+            .simple
+            .saturate_property_assertions()
+            .into_iter()
+            .collect();
+        edges.sort_by(|a, b| {
+            (a.0.as_str(), a.1.as_str(), a.2.as_str()).cmp(&(
+                b.0.as_str(),
+                b.1.as_str(),
+                b.2.as_str(),
+            ))
+        });
+
+        Ok(edges
+            .into_iter()
+            .map(|(subject, property, object)| {
+                let (subject, property) = (Arc::new(subject), Arc::new(property));
+                match object.as_str().strip_prefix(BLANK_NODE_PREFIX) {
+                    Some(label) => PropertyAssertionAxiom::new_with_anonymous(
+                        subject,
+                        property,
+                        AnonymousIndividual::new(label),
+                    ),
+                    None => PropertyAssertionAxiom::new(subject, property, Arc::new(object)),
+                }
+            })
+            .collect())
+    }
+
+    /// Get the asserted and inferred values of a data property for an individual
+    pub fn data_property_values(
+        &mut self,
+        individual: &IRI,
+        property: &DataPropertyExpression,
+    ) -> OwlResult<HashSet<Literal>> {
+        self.preflight()?;
+        self.simple.data_property_values(individual, property)
+    }
+
+    /// Get the individuals entailed to be the same as an individual
+    ///
+    /// Starts from the sameAs axioms and merges individuals until nothing
+    /// changes: the values of a functional property for one individual, the
+    /// individuals sharing a value of an inverse functional property, and the
+    /// named instances of a class agreeing on every property of one of its
+    /// keys. Keys are applied to named classes only. The individual itself is
+    /// always included.
+    pub fn same_individuals(&mut self, individual: &IRI) -> OwlResult<HashSet<IRI>> {
+        self.consult_imports(&[individual])?;
+        self.preflight()?;
+        let ontology = self.ontology();
+        let edges = self.simple.saturate_property_assertions();
+        let functional: HashSet<IRI> = ontology
+            .functional_property_axioms()
+            .iter()
+            .map(|axiom| (**axiom.property()).clone())
+            .collect();
+        let inverse_functional: HashSet<IRI> = ontology
+            .inverse_functional_property_axioms()
+            .iter()
+            .map(|axiom| (**axiom.property()).clone())
+            .collect();
+        let keys: Vec<(IRI, Vec<IRI>)> = ontology
+            .has_key_axioms()
+            .iter()
+            .filter_map(|axiom| match axiom.class_expression() {
+                ClassExpression::Class(class) => Some((
+                    (**class.iri()).clone(),
+                    axiom.properties().iter().map(|p| (**p).clone()).collect(),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let mut parent: HashMap<IRI, IRI> = HashMap::new();
+        for axiom in ontology.same_individual_axioms() {
+            for pair in axiom.individuals().windows(2) {
+                Self::merge(&mut parent, &pair[0], &pair[1]);
+            }
+        }
+
+        let mut key_instances = Vec::with_capacity(keys.len());
+        for (class, properties) in keys {
+            let instances: Vec<IRI> = self
+                .get_instances(&class)?
+                .iter()
+                .map(|instance| (**instance).clone())
+                .collect();
+            key_instances.push((instances, properties));
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            // Functional properties have one value, inverse functional ones one subject
+            let mut values: HashMap<(IRI, &IRI), &IRI> = HashMap::new();
+            let mut subjects: HashMap<(IRI, &IRI), &IRI> = HashMap::new();
+            for (subject, property, object) in &edges {
+                if functional.contains(property) {
+                    let key = (Self::representative(&parent, subject), property);
+                    match values.get(&key) {
+                        Some(&other) => changed |= Self::merge(&mut parent, other, object),
+                        None => {
+                            values.insert(key, object);
+                        }
+                    }
+                }
+                if inverse_functional.contains(property) {
+                    let key = (Self::representative(&parent, object), property);
+                    match subjects.get(&key) {
+                        Some(&other) => changed |= Self::merge(&mut parent, other, subject),
+                        None => {
+                            subjects.insert(key, subject);
+                        }
+                    }
+                }
+            }
+
+            for (instances, properties) in &key_instances {
+                for (i, a) in instances.iter().enumerate() {
+                    for b in &instances[i + 1..] {
+                        if Self::representative(&parent, a) != Self::representative(&parent, b)
+                            && properties.iter().all(|property| {
+                                self.share_key_value(&edges, &parent, a, b, property)
+                            })
+                        {
+                            changed |= Self::merge(&mut parent, a, b);
+                        }
+                    }
+                }
+            }
+        }
+
+        let root = Self::representative(&parent, individual);
+        let mut same: HashSet<IRI> = parent
+            .keys()
+            .filter(|member| Self::representative(&parent, member) == root)
+            .cloned()
+            .collect();
+        same.insert(individual.clone());
+        Ok(same)
+    }
+
+    /// Whether two individuals have a common value for a key property
+    ///
+    /// Object values are compared up to the individuals merged so far, data
+    /// values literally.
+    fn share_key_value(
+        &self,
+        edges: &HashSet<(IRI, IRI, IRI)>,
+        parent: &HashMap<IRI, IRI>,
+        a: &IRI,
+        b: &IRI,
+        property: &IRI,
+    ) -> bool {
+        let object_values = |individual: &IRI| -> HashSet<IRI> {
+            let root = Self::representative(parent, individual);
+            edges
+                .iter()
+                .filter(|(subject, p, _)| {
+                    p == property && Self::representative(parent, subject) == root
+                })
+                .map(|(_, _, object)| Self::representative(parent, object))
+                .collect()
+        };
+        if !object_values(a).is_disjoint(&object_values(b)) {
+            return true;
+        }
+
+        let data_property =
+            DataPropertyExpression::DataProperty(DataProperty::new(property.clone()));
+        match (
+            self.simple.data_property_values(a, &data_property),
+            self.simple.data_property_values(b, &data_property),
+        ) {
+            (Ok(a_values), Ok(b_values)) => !a_values.is_disjoint(&b_values),
+            _ => false,
+        }
+    }
+
+    /// The individual standing for the merged individuals containing `individual`
+    fn representative(parent: &HashMap<IRI, IRI>, individual: &IRI) -> IRI {
+        let mut current = individual;
+        while let Some(next) = parent.get(current) {
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+        current.clone()
+    }
+
+    /// Merge the individuals of `a` and `b`, returning whether they were apart
+    fn merge(parent: &mut HashMap<IRI, IRI>, a: &IRI, b: &IRI) -> bool {
+        let (a, b) = (
+            Self::representative(parent, a),
+            Self::representative(parent, b),
+        );
+        for individual in [&a, &b] {
+            parent
+                .entry(individual.clone())
+                .or_insert_with(|| individual.clone());
+        }
+        if a == b {
+            return false;
+        }
+        parent.insert(b, a);
+        true
+    }
+
+    /// Explain why an individual is an instance of a class
+    ///
+    /// Returns a minimal set of axioms entailing the membership, such as the
+    /// class assertion and the subclass or property domain axioms leading from
+    /// the asserted types to `class`. Fails if the membership is not entailed.
+    pub fn explain_instance_of(&mut self, individual: &IRI, class: &IRI) -> OwlResult<Vec<Axiom>> {
+        self.preflight()?;
+        let config = self.config();
+        justification::justify(self.ontology(), &[individual, class], |ontology| {
+            OwlReasoner::with_config(ontology, config.clone()).is_instance_of(individual, class)
+        })?
+        .ok_or_else(|| {
+            OwlError::ReasoningError(format!(
+                "{} is not entailed to be an instance of {}",
+                individual, class
+            ))
+        })
+    }
+
+    /// Explain why a class is not a subclass of another
+    ///
+    /// Returns a counter-model: a model in which an individual, the witness, is
+    /// an instance of `subclass` but not of `superclass`. Fails if the
+    /// subsumption is entailed.
+    pub fn explain_non_subsumption(
+        &mut self,
+        subclass: &IRI,
+        superclass: &IRI,
+    ) -> OwlResult<CounterModel> {
+        self.preflight()?;
+        let entailed =
+            || OwlError::ReasoningError(format!("{} is a subclass of {}", subclass, superclass));
+        if self.is_subclass_of(subclass, superclass)? {
+            return Err(entailed());
+        }
+
+        let counter_model = match &self.tableaux {
+            Some(tableaux) => tableaux.counter_model(subclass, superclass)?,
+            None => {
+                let config = self.config().tableaux_config;
+                TableauxReasoner::with_config(self.ontology().clone(), config)
+                    .counter_model(subclass, superclass)?
+            }
+        };
+        counter_model.ok_or_else(entailed)
+    }
+
+    /// Build a model of a concept for inspection
+    ///
+    /// Returns `None` when the concept is unsatisfiable. The model lists the
+    /// nodes of the completed tableau with their concepts and edges; use
+    /// [`Model::to_turtle`] to write it out.
+    pub fn satisfiability_model(&mut self, concept: &ClassExpression) -> OwlResult<Option<Model>> {
+        self.preflight()?;
+        match &self.tableaux {
+            Some(tableaux) => tableaux.satisfiability_model(concept),
+            None => {
+                let config = self.config().tableaux_config;
+                TableauxReasoner::with_config(self.ontology().clone(), config)
+                    .satisfiability_model(concept)
+            }
+        }
+    }
+
+    /// Check whether the ontology entails an axiom
+    ///
+    /// Class axioms, class assertions of named classes, object and data property
+    /// assertions, and object property domains and ranges are decided by
+    /// reasoning; annotation axioms carry no logical content and are always
+    /// entailed. An inconsistent ontology entails every axiom. Fails for other
+    /// axiom types.
+    pub fn entails(&mut self, axiom: &Axiom) -> OwlResult<bool> {
+        if !self.is_consistent()? {
+            return Ok(true);
+        }
+        self.entails_if_consistent(axiom)
+    }
+
+    /// Find the inferences that would be lost if an axiom were removed
+    ///
+    /// The ontology is classified and realized as it stands, then a snapshot
+    /// without the axiom is classified and realized the same way; the
+    /// subsumptions and memberships entailed only before the removal are
+    /// reported. Subsumptions by owl:Thing and of a class by itself are left
+    /// out. The ontology itself is not changed. Fails if the axiom is not in
+    /// the ontology or the ontology is inconsistent.
+    pub fn inference_impact(&mut self, axiom: &Axiom) -> OwlResult<ImpactReport> {
+        if !self.is_consistent()? {
+            return Err(OwlError::InconsistentOntology(
+                "An inconsistent ontology entails everything with or without the axiom".to_string(),
+            ));
+        }
+        let mut snapshot = self.ontology().clone();
+        if !snapshot.remove_axiom(axiom)? {
+            return Err(OwlError::ValidationError(format!(
+                "{:?} is not an axiom of the ontology",
+                axiom
+            )));
+        }
+
+        let before = entailed_atoms(self.ontology().clone())?;
+        // A subset of a consistent ontology is consistent
+        let after = entailed_atoms(snapshot)?;
+
+        let mut report = ImpactReport {
+            lost_subsumptions: before
+                .subsumptions
+                .difference(&after.subsumptions)
+                .cloned()
+                .collect(),
+            lost_memberships: before
+                .memberships
+                .difference(&after.memberships)
+                .cloned()
+                .collect(),
+        };
+        report.lost_subsumptions.sort();
+        report.lost_memberships.sort();
+        Ok(report)
+    }
+
+    /// Check whether two ontologies entail the same axioms
+    ///
+    /// Each must entail every axiom of the other, so an `EquivalentClasses`
+    /// axiom in one matches the two `SubClassOf` axioms between its classes in
+    /// the other. Both are reasoned over with this reasoner's configuration, and
+    /// the check fails if either has an axiom [`OwlReasoner::entails`] does not
+    /// support.
+    pub fn are_logically_equivalent(&mut self, a: &Ontology, b: &Ontology) -> OwlResult<bool> {
+        let config = self.config();
+        let mut reasoner_a = OwlReasoner::with_config(a.clone(), config.clone());
+        let mut reasoner_b = OwlReasoner::with_config(b.clone(), config);
+        let consistent = reasoner_a.is_consistent()?;
+        if consistent != reasoner_b.is_consistent()? {
+            return Ok(false);
+        }
+        // Inconsistent ontologies entail everything, so are all equivalent
+        if !consistent {
+            return Ok(true);
+        }
+
+        for (reasoner, other) in [(&mut reasoner_a, b), (&mut reasoner_b, a)] {
+            for axiom in other.axioms() {
+                if !reasoner.entails_if_consistent(axiom)? {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Check whether the ontology, known to be consistent, entails an axiom
+    pub(crate) fn entails_if_consistent(&mut self, axiom: &Axiom) -> OwlResult<bool> {
+        if self
+            .ontology()
+            .axioms()
+            .iter()
+            .any(|asserted| **asserted == *axiom)
+        {
+            return Ok(true);
+        }
+
+        match axiom {
+            Axiom::SubClassOf(axiom) => {
+                self.entails_subclass(axiom.sub_class(), axiom.super_class())
+            }
+            Axiom::EquivalentClasses(axiom) => {
+                let expressions = axiom.class_expressions();
+                for (i, first) in expressions.iter().enumerate() {
+                    for second in &expressions[i + 1..] {
+                        if !self.entails_subclass(first, second)?
+                            || !self.entails_subclass(second, first)?
+                        {
+                            return Ok(false);
+                        }
+                    }
+                }
+                Ok(true)
+            }
+            Axiom::DisjointClasses(axiom) => {
+                let classes = axiom.classes();
+                for (i, first) in classes.iter().enumerate() {
+                    for second in &classes[i + 1..] {
+                        if !self.are_disjoint_classes(first, second)? {
+                            return Ok(false);
+                        }
+                    }
+                }
+                Ok(true)
+            }
+            Axiom::ClassAssertion(axiom) => match axiom.class_expr() {
+                ClassExpression::Class(class) => {
+                    self.is_instance_of(axiom.individual(), class.iri())
+                }
+                _ => Err(OwlError::ValidationError(
+                    "Entailment of class assertions of complex classes is not supported"
+                        .to_string(),
+                )),
+            },
+            Axiom::PropertyAssertion(axiom) => {
+                let property = ObjectPropertyExpression::ObjectProperty(Box::new(
+                    ObjectProperty::new((**axiom.property()).clone()),
+                ));
+                let object = axiom.object_node_iri()?;
+                Ok(self
+                    .property_values(axiom.subject(), &property)?
+                    .contains(&*object))
+            }
+            Axiom::DataPropertyAssertion(axiom) => {
+                let property = DataPropertyExpression::DataProperty(DataProperty::new(
+                    (**axiom.property()).clone(),
+                ));
+                Ok(self
+                    .data_property_values(axiom.subject(), &property)?
+                    .contains(axiom.value()))
+            }
+            // A domain D of R means ∃R.⊤ ⊑ D, and a range D means ∃R⁻.⊤ ⊑ D
+            Axiom::ObjectPropertyDomain(axiom) => {
+                let property = axiom.property_expression().clone();
+                self.entails_subclass(&Self::some_thing(property), axiom.domain())
+            }
+            Axiom::ObjectPropertyRange(axiom) => {
+                let property = ObjectPropertyExpression::ObjectInverseOf(Box::new(
+                    axiom.property_expression().clone(),
+                ));
+                self.entails_subclass(&Self::some_thing(property), axiom.range())
+            }
+            Axiom::AnnotationAssertion(_)
+            | Axiom::SubAnnotationPropertyOf(_)
+            | Axiom::AnnotationPropertyDomain(_)
+            | Axiom::AnnotationPropertyRange(_) => Ok(true),
+            other => Err(OwlError::ValidationError(format!(
+                "Entailment of axiom is not supported: {:?}",
+                other
+            ))),
+        }
+    }
+
+    /// Check whether `sub ⊑ sup` follows from the ontology
+    fn entails_subclass(
+        &mut self,
+        sub: &ClassExpression,
+        sup: &ClassExpression,
+    ) -> OwlResult<bool> {
+        if let (ClassExpression::Class(sub), ClassExpression::Class(sup)) = (sub, sup) {
+            if self.is_subclass_of(sub.iri(), sup.iri())? {
+                return Ok(true);
+            }
+        }
+        // The tableau does not apply domains while expanding, so ∃P.C ⊑ D also
+        // holds when a domain of P is subsumed by D
+        if let ClassExpression::ObjectSomeValuesFrom(property, _) = sub {
+            if let ObjectPropertyExpression::ObjectProperty(property) = property.as_ref() {
+                let domains: Vec<ClassExpression> = self
+                    .simple
+                    .ontology
+                    .object_property_domain_axioms()
+                    .into_iter()
+                    .filter(|axiom| axiom.property() == property.iri().as_ref())
+                    .map(|axiom| axiom.domain().clone())
+                    .collect();
+                for domain in domains {
+                    if self.entails_subclass(&domain, sup)? {
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+        // sub ⊑ sup holds exactly when sub ⊓ ¬sup has no model
+        let counterexample = ClassExpression::ObjectIntersectionOf(
+            vec![
+                Arc::new(sub.clone()),
+                Arc::new(ClassExpression::ObjectComplementOf(Arc::new(sup.clone()))),
+            ]
+            .into(),
+        );
+        Ok(self.satisfiability_model(&counterexample)?.is_none())
+    }
+
+    /// ∃property.⊤
+    fn some_thing(property: ObjectPropertyExpression) -> ClassExpression {
+        ClassExpression::ObjectSomeValuesFrom(
+            Box::new(property),
+            Arc::new(ClassExpression::Class(Class::new(owl::thing()))),
+        )
+    }
+
     /// Create a query engine for this reasoner
     pub fn query_engine(&self) -> QueryEngine {
         QueryEngine::new(self.simple.ontology.clone())
@@ -273,20 +1118,20 @@ impl OwlReasoner {
 
 impl Reasoner for OwlReasoner {
     fn is_consistent(&mut self) -> OwlResult<bool> {
-        if self.use_advanced_reasoning {
-            if let Some(tableaux) = &mut self.tableaux {
-                // Use tableaux reasoning for proper consistency checking
-                // Check if owl:Thing is satisfiable - if not, ontology is inconsistent
-                let thing_iri = IRI::new("http://www.w3.org/2002/07/owl#Thing").map_err(|e| {
-                    OwlError::ReasoningError(format!("Failed to create owl:Thing IRI: {}", e))
-                })?;
-                return tableaux.is_class_satisfiable(&thing_iri);
-            }
+        self.consult_imports(&[])?;
+        self.preflight()?;
+        let span = PhaseSpan::consistency(self.ontology());
+        let consistent = self.check_consistency()?;
+        span.record_flag("consistent", consistent);
+        if let Some(method) = self.last_consistency_method {
+            span.record_str("method", &format!("{:?}", method));
         }
-        self.simple.is_consistent()
+        Ok(consistent)
     }
 
     fn is_subclass_of(&mut self, sub: &IRI, sup: &IRI) -> OwlResult<bool> {
+        self.consult_imports(&[sub, sup])?;
+        self.preflight()?;
         self.simple.is_subclass_of(sub, sup)
     }
 
@@ -296,6 +1141,8 @@ impl Reasoner for OwlReasoner {
     }
 
     fn are_disjoint_classes(&mut self, a: &IRI, b: &IRI) -> OwlResult<bool> {
+        self.consult_imports(&[a, b])?;
+        self.preflight()?;
         if self.use_advanced_reasoning {
             if let Some(tableaux) = &mut self.tableaux {
                 // Use tableaux reasoning for disjointness checking
@@ -307,12 +1154,23 @@ impl Reasoner for OwlReasoner {
     }
 
     fn get_instances(&mut self, class: &IRI) -> OwlResult<Vec<Arc<IRI>>> {
+        self.consult_imports(&[class])?;
+        self.preflight()?;
         self.simple.get_instances(class)
     }
 
     fn is_instance_of(&mut self, individual: &IRI, class: &IRI) -> OwlResult<bool> {
-        // For now, check if individual is in instances of class
+        self.consult_imports(&[individual, class])?;
         let instances = self.get_instances(class)?;
-        Ok(instances.contains(&Arc::new((*individual).clone())))
+        if instances.contains(&Arc::new((*individual).clone())) {
+            return Ok(true);
+        }
+        if self.use_advanced_reasoning {
+            if let Some(tableaux) = &self.tableaux {
+                // Membership in a defined class needs the tableau to unfold its definition
+                return tableaux.is_instance_of(individual, class);
+            }
+        }
+        Ok(false)
     }
 }
@@ -2,14 +2,18 @@
 //!
 //! Implements classification algorithms to compute class hierarchy and relationships.
 
-use crate::axioms::ClassExpression;
+use crate::axioms::{Axiom, ClassExpression};
+use crate::constants::{owl, rdfs};
+use crate::entities::{AnnotationValue, Literal};
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
+use crate::reasoning::spans::PhaseSpan;
 use crate::reasoning::tableaux::TableauxReasoner;
 
 use hashbrown::HashMap;
-use std::collections::{HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashSet, VecDeque};
+use std::path::Path;
 use std::sync::Arc;
 
 /// Classification engine for OWL2 ontologies
@@ -18,6 +22,8 @@ pub struct ClassificationEngine {
     tableaux_reasoner: TableauxReasoner,
     config: ClassificationConfig,
     hierarchy: ClassHierarchy,
+    /// Content hash of the ontology, computed on first use since the ontology never changes
+    ontology_hash: Option<u64>,
 }
 
 /// Classification configuration
@@ -63,6 +69,36 @@ pub struct ClassHierarchy {
     satisfiable: HashMap<IRI, bool>,
     /// Hierarchy depth cache for optimization
     depth_cache: HashMap<IRI, usize>,
+    /// Content hash of the ontology this hierarchy was computed from
+    ontology_hash: Option<u64>,
+}
+
+/// On-disk representation of a class hierarchy
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedHierarchy {
+    ontology_hash: Option<u64>,
+    parents: BTreeMap<IRI, BTreeSet<IRI>>,
+    children: BTreeMap<IRI, BTreeSet<IRI>>,
+    equivalences: BTreeMap<IRI, BTreeSet<IRI>>,
+    disjointness: BTreeMap<IRI, BTreeSet<IRI>>,
+}
+
+/// How [`ClassHierarchy::to_manchester_with`] renders class frames
+#[derive(Debug, Clone, Copy)]
+pub struct ManchesterFrameConfig<'a> {
+    /// List only the direct superclasses instead of every inferred one
+    pub direct_only: bool,
+    /// Name classes by their `rdfs:label` in this ontology where they have one
+    pub labels: Option<&'a Ontology>,
+}
+
+impl Default for ManchesterFrameConfig<'_> {
+    fn default() -> Self {
+        ManchesterFrameConfig {
+            direct_only: true,
+            labels: None,
+        }
+    }
 }
 
 /// Classification result
@@ -71,6 +107,8 @@ pub struct ClassificationResult {
     pub hierarchy: ClassHierarchy,
     pub stats: ClassificationStats,
     pub is_complete: bool,
+    /// Whether the hierarchy was reloaded from a saved classification
+    pub from_cache: bool,
 }
 
 /// Classification statistics
@@ -101,12 +139,14 @@ impl ClassificationEngine {
             tableaux_reasoner,
             config,
             hierarchy,
+            ontology_hash: None,
         }
     }
 
     /// Classify the ontology
     pub fn classify(&mut self) -> OwlResult<ClassificationResult> {
         let start_time = std::time::Instant::now();
+        let span = PhaseSpan::classification(&self.ontology);
 
         // Initialize hierarchy with direct relationships
         self.initialize_hierarchy()?;
@@ -130,20 +170,175 @@ impl ClassificationEngine {
         // Perform additional reasoning to discover implicit relationships
         self.reason_about_hierarchy()?;
 
-        let time_ms = start_time.elapsed().as_millis() as u64;
+        self.hierarchy.ontology_hash = Some(self.ontology_hash());
+
+        let result = self.build_result(start_time, 1, false);
+        span.record_count("relationships", result.stats.relationships_discovered);
+        span.record_count("equivalences", result.stats.equivalences_found);
+        span.record_count("disjointness", result.stats.disjointness_found);
+        Ok(result)
+    }
+
+    /// Classify the ontology, reusing a hierarchy saved at `path` when it is still valid
+    ///
+    /// If the saved hierarchy is missing, unreadable or was computed for a different
+    /// version of the ontology, the ontology is reclassified and the cache is rewritten.
+    pub fn classify_with_cache(
+        &mut self,
+        path: impl AsRef<Path>,
+    ) -> OwlResult<ClassificationResult> {
+        let path = path.as_ref();
+        let start_time = std::time::Instant::now();
+
+        if path.exists() {
+            let ontology_hash = self.ontology_hash();
+            match ClassHierarchy::load(path) {
+                Ok(hierarchy) if hierarchy.ontology_hash() == Some(ontology_hash) => {
+                    self.hierarchy = hierarchy;
+                    return Ok(self.build_result(start_time, 0, true));
+                }
+                Ok(_) => log::info!(
+                    "Cached classification at {} is stale; reclassifying",
+                    path.display()
+                ),
+                Err(e) => log::warn!(
+                    "Failed to load cached classification from {}: {}; reclassifying",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+
+        let result = self.classify()?;
+        self.hierarchy.save(path)?;
+        Ok(result)
+    }
+
+    /// Classify the ontology, yielding each class with its direct superclasses
+    /// as soon as they are known
+    ///
+    /// Classes come in topological order, superclasses before their subclasses,
+    /// with ties broken by IRI. A class is only yielded once its equivalences
+    /// with the remaining classes have been checked, so the streamed results
+    /// agree with [`Self::classify`]. Disjointness is computed after the last
+    /// class, leaving the engine's hierarchy as complete as after a batch run.
+    /// The stream ends after the first error.
+    pub fn classify_streaming(&mut self) -> impl Iterator<Item = OwlResult<(IRI, Vec<IRI>)>> + '_ {
+        ClassificationStream {
+            engine: self,
+            pending: None,
+            processed: HashSet::new(),
+            finished: false,
+        }
+    }
+
+    /// Build the asserted hierarchy and order the classes for streaming
+    fn prepare_stream(&mut self) -> OwlResult<VecDeque<IRI>> {
+        self.initialize_hierarchy()?;
+        self.compute_transitive_closure()?;
+        if self.config.compute_equivalences {
+            self.add_asserted_equivalences();
+        }
+        self.reason_about_hierarchy()?;
+
+        let classes: HashSet<IRI> = self
+            .ontology
+            .classes()
+            .iter()
+            .map(|class| (**class.iri()).clone())
+            .collect();
+        let mut waiting: HashMap<IRI, usize> = HashMap::new();
+        let mut ready = BTreeSet::new();
+        for class in &classes {
+            let parents = self
+                .hierarchy
+                .get_direct_parents(class)
+                .into_iter()
+                .filter(|parent| parent != class && classes.contains(parent))
+                .count();
+            if parents == 0 {
+                ready.insert(class.clone());
+            } else {
+                waiting.insert(class.clone(), parents);
+            }
+        }
+
+        let mut order = VecDeque::with_capacity(classes.len());
+        while let Some(class) = ready.pop_first() {
+            for child in self.hierarchy.get_direct_children(&class) {
+                if let Some(count) = waiting.get_mut(&child) {
+                    *count -= 1;
+                    if *count == 0 {
+                        waiting.remove(&child);
+                        ready.insert(child);
+                    }
+                }
+            }
+            order.push_back(class);
+        }
+        Ok(order)
+    }
+
+    /// Check a class for equivalence with the classes that have not been
+    /// streamed yet, then compute its direct superclasses
+    fn stream_class(&mut self, class: &IRI, processed: &HashSet<IRI>) -> OwlResult<Vec<IRI>> {
+        if self.config.compute_equivalences {
+            let ontology = Arc::clone(&self.ontology);
+            for other in ontology.classes() {
+                let other = &**other.iri();
+                if other == class
+                    || processed.contains(other)
+                    || self.hierarchy.are_equivalent(class, other)
+                {
+                    continue;
+                }
+                if self.tableaux_reasoner.is_subclass_of(class, other)?
+                    && self.tableaux_reasoner.is_subclass_of(other, class)?
+                {
+                    self.hierarchy.add_equivalence(class.clone(), other.clone());
+                    self.hierarchy.add_equivalence(other.clone(), class.clone());
+                }
+            }
+        }
+
+        let mut superclasses: Vec<IRI> = self
+            .hierarchy
+            .superclasses(class, true)
+            .into_iter()
+            .collect();
+        superclasses.sort();
+        Ok(superclasses)
+    }
+
+    /// Complete the hierarchy once every class has been streamed
+    fn finish_stream(&mut self) -> OwlResult<()> {
+        if self.config.compute_disjointness {
+            self.compute_disjoint_classes()?;
+        }
+        self.hierarchy.ontology_hash = Some(self.ontology_hash());
+        Ok(())
+    }
 
-        Ok(ClassificationResult {
+    /// Assemble a classification result from the current hierarchy
+    fn build_result(
+        &self,
+        start_time: std::time::Instant,
+        iterations: usize,
+        from_cache: bool,
+    ) -> ClassificationResult {
+        ClassificationResult {
             hierarchy: self.hierarchy.clone(), // Clone the computed hierarchy instead of creating new
             stats: ClassificationStats {
                 classes_processed: self.ontology.classes().len(),
                 relationships_discovered: self.count_relationships(),
                 equivalences_found: self.count_equivalences(),
                 disjointness_found: self.count_disjointness(),
-                time_ms,
-                iterations: 1, // Simplified for now
+                time_ms: start_time.elapsed().as_millis() as u64,
+                iterations,
             },
             is_complete: true,
-        })
+            from_cache,
+        }
     }
 
     /// Initialize the class hierarchy with direct relationships
@@ -170,8 +365,11 @@ impl ClassificationEngine {
             .children
             .insert(nothing_iri.clone(), HashSet::new());
 
-        // Process direct subclass axioms
+        // Process direct subclass axioms, skipping tautologies such as C ⊑ C
         for axiom in self.ontology.subclass_axioms() {
+            if axiom.is_reflexive() {
+                continue;
+            }
             if let (ClassExpression::Class(sub_class), ClassExpression::Class(super_class)) =
                 (axiom.sub_class(), axiom.super_class())
             {
@@ -272,7 +470,16 @@ impl ClassificationEngine {
 
     /// Compute equivalent classes
     fn compute_equivalent_classes(&mut self) -> OwlResult<()> {
-        // Process equivalent classes axioms
+        self.add_asserted_equivalences();
+
+        // Discover additional equivalences through reasoning
+        self.discover_equivalences_by_reasoning()?;
+
+        Ok(())
+    }
+
+    /// Record the equivalences stated by equivalent classes axioms
+    fn add_asserted_equivalences(&mut self) {
         for axiom in self.ontology.equivalent_classes_axioms() {
             let classes = axiom.classes();
 
@@ -289,11 +496,6 @@ impl ClassificationEngine {
                 }
             }
         }
-
-        // Discover additional equivalences through reasoning
-        self.discover_equivalences_by_reasoning()?;
-
-        Ok(())
     }
 
     /// Discover equivalent classes through reasoning
@@ -359,35 +561,72 @@ impl ClassificationEngine {
     }
 
     /// Discover disjoint classes through reasoning
+    ///
+    /// A satisfiable class shares its instances with its superclasses, so only
+    /// pairs where neither class subsumes the other need their intersection
+    /// tested. Pairs are visited from the top of the hierarchy down, and the
+    /// subclasses of disjoint classes are disjoint without a test of their own.
+    /// An unsatisfiable class is disjoint with every class.
     fn discover_disjointness_by_reasoning(&mut self) -> OwlResult<()> {
-        // Get classes without cloning IRIs
-        let classes: Vec<&IRI> = self
+        let mut classes: Vec<IRI> = self
             .ontology
             .classes()
             .iter()
-            .map(|c| &**c.iri()) // Dereference Arc to get &IRI
+            .map(|c| (**c.iri()).clone())
+            .collect();
+
+        // Each class together with its superclasses
+        let ancestors: HashMap<IRI, HashSet<IRI>> = classes
+            .iter()
+            .map(|class| {
+                let mut ancestors = self.hierarchy.get_all_superclasses(class);
+                ancestors.insert(class.clone());
+                (class.clone(), ancestors)
+            })
             .collect();
+        classes.sort_by(|a, b| {
+            ancestors[a]
+                .len()
+                .cmp(&ancestors[b].len())
+                .then_with(|| a.cmp(b))
+        });
+
+        let mut unsatisfiable = HashSet::new();
+        for class in &classes {
+            if !self.tableaux_reasoner.is_class_satisfiable(class)? {
+                unsatisfiable.insert(class.clone());
+            }
+        }
 
         for i in 0..classes.len() {
             for j in i + 1..classes.len() {
-                let class1 = classes[i];
-                let class2 = classes[j];
+                let class1 = &classes[i];
+                let class2 = &classes[j];
 
                 // Skip if already known to be disjoint
                 if self.hierarchy.are_disjoint(class1, class2) {
                     continue;
                 }
 
-                // Check if class1 and class2 are disjoint
-                let are_disjoint = self
-                    .tableaux_reasoner
-                    .are_disjoint_classes(class1, class2)?;
+                let are_disjoint = if unsatisfiable.contains(class1)
+                    || unsatisfiable.contains(class2)
+                {
+                    true
+                } else if ancestors[class1].contains(class2) || ancestors[class2].contains(class1) {
+                    false
+                } else if ancestors[class1].iter().any(|ancestor1| {
+                    ancestors[class2]
+                        .iter()
+                        .any(|ancestor2| self.hierarchy.are_disjoint(ancestor1, ancestor2))
+                }) {
+                    true
+                } else {
+                    self.tableaux_reasoner
+                        .are_disjoint_classes(class1, class2)?
+                };
 
                 if are_disjoint {
-                    self.hierarchy
-                        .add_disjoint((*class1).clone(), (*class2).clone());
-                    self.hierarchy
-                        .add_disjoint((*class2).clone(), (*class1).clone());
+                    self.hierarchy.add_disjoint(class1.clone(), class2.clone());
                 }
             }
         }
@@ -546,6 +785,39 @@ impl ClassificationEngine {
         self.hierarchy.get_all_subclasses(class_iri)
     }
 
+    /// Named superclasses of a class, classifying the ontology first if needed
+    ///
+    /// With `direct_only` set, only the immediate parents are returned; otherwise
+    /// the full transitive set is.
+    pub fn superclasses(&mut self, class_iri: &IRI, direct_only: bool) -> OwlResult<HashSet<IRI>> {
+        self.ensure_classified()?;
+        Ok(self.hierarchy.superclasses(class_iri, direct_only))
+    }
+
+    /// Named subclasses of a class, classifying the ontology first if needed
+    ///
+    /// With `direct_only` set, only the immediate children are returned; otherwise
+    /// the full transitive set is.
+    pub fn subclasses(&mut self, class_iri: &IRI, direct_only: bool) -> OwlResult<HashSet<IRI>> {
+        self.ensure_classified()?;
+        Ok(self.hierarchy.subclasses(class_iri, direct_only))
+    }
+
+    /// Classify unless the current hierarchy already matches the ontology
+    fn ensure_classified(&mut self) -> OwlResult<()> {
+        if self.hierarchy.ontology_hash() != Some(self.ontology_hash()) {
+            self.classify()?;
+        }
+        Ok(())
+    }
+
+    /// Content hash of the ontology, hashing it only the first time
+    fn ontology_hash(&mut self) -> u64 {
+        *self
+            .ontology_hash
+            .get_or_insert_with(|| self.ontology.content_hash())
+    }
+
     /// Get equivalent classes
     pub fn get_equivalent_classes(&self, class_iri: &IRI) -> HashSet<IRI> {
         self.hierarchy.get_equivalent_classes(class_iri)
@@ -557,6 +829,48 @@ impl ClassificationEngine {
     }
 }
 
+/// Iterator behind [`ClassificationEngine::classify_streaming`]
+struct ClassificationStream<'a> {
+    engine: &'a mut ClassificationEngine,
+    /// Classes still to be streamed, computed on the first call to `next`
+    pending: Option<VecDeque<IRI>>,
+    /// Classes already streamed
+    processed: HashSet<IRI>,
+    finished: bool,
+}
+
+impl Iterator for ClassificationStream<'_> {
+    type Item = OwlResult<(IRI, Vec<IRI>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = self.advance();
+        if !matches!(result, Some(Ok(_))) {
+            self.finished = true;
+        }
+        result
+    }
+}
+
+impl ClassificationStream<'_> {
+    fn advance(&mut self) -> Option<OwlResult<(IRI, Vec<IRI>)>> {
+        if self.pending.is_none() {
+            match self.engine.prepare_stream() {
+                Ok(order) => self.pending = Some(order),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        let Some(class) = self.pending.as_mut()?.pop_front() else {
+            return self.engine.finish_stream().err().map(Err);
+        };
+        let superclasses = self.engine.stream_class(&class, &self.processed);
+        self.processed.insert(class.clone());
+        Some(superclasses.map(|superclasses| (class, superclasses)))
+    }
+}
+
 impl ClassHierarchy {
     /// Create a new class hierarchy
     pub fn new(_ontology: &Arc<Ontology>) -> Self {
@@ -567,9 +881,237 @@ impl ClassHierarchy {
             disjointness: HashMap::new(),
             satisfiable: HashMap::new(),
             depth_cache: HashMap::new(),
+            ontology_hash: None,
         }
     }
 
+    /// Content hash of the ontology this hierarchy was computed from, if known
+    pub fn ontology_hash(&self) -> Option<u64> {
+        self.ontology_hash
+    }
+
+    /// Check whether this hierarchy was computed from the given ontology
+    pub fn is_valid_for(&self, ontology: &Ontology) -> bool {
+        self.ontology_hash == Some(ontology.content_hash())
+    }
+
+    /// Reclassify only the classes an edit can affect, reusing the rest of the hierarchy
+    ///
+    /// `reasoner` must reason over the edited ontology, and `changed_axioms` are the
+    /// axioms the edit added or removed. The affected region is the named classes in
+    /// the signature of the changed axioms together with their sub- and superclasses
+    /// in the current hierarchy; a property in the signature brings in the classes of
+    /// the axioms using it. Classes outside the region keep their relationships.
+    ///
+    /// Returns the reclassified classes.
+    pub fn update_after(
+        &mut self,
+        reasoner: &mut TableauxReasoner,
+        changed_axioms: &[Axiom],
+    ) -> OwlResult<HashSet<IRI>> {
+        let ontology = reasoner.ontology.clone();
+        let thing = IRI::new("http://www.w3.org/2002/07/owl#Thing")?;
+        let nothing = IRI::new("http://www.w3.org/2002/07/owl#Nothing")?;
+        let is_class = |iri: &IRI| ontology.is_punned(iri).class || self.parents.contains_key(iri);
+
+        let mut seeds = HashSet::new();
+        for axiom in changed_axioms {
+            for iri in axiom.signature() {
+                if is_class(&iri) {
+                    seeds.insert((*iri).clone());
+                    continue;
+                }
+                for other in ontology.axioms_referencing(&iri) {
+                    seeds.extend(
+                        other
+                            .signature()
+                            .into_iter()
+                            .filter(|iri| is_class(iri))
+                            .map(|iri| (*iri).clone()),
+                    );
+                }
+            }
+        }
+
+        let mut affected = seeds.clone();
+        for class in &seeds {
+            affected.extend(self.get_all_superclasses(class));
+            affected.extend(self.get_all_subclasses(class));
+        }
+        affected.remove(&thing);
+        affected.remove(&nothing);
+
+        for class in &affected {
+            for parent in self.parents.remove(class).unwrap_or_default() {
+                if let Some(children) = self.children.get_mut(&parent) {
+                    children.remove(class);
+                }
+            }
+            for relation in [&mut self.equivalences, &mut self.disjointness] {
+                for other in relation.remove(class).unwrap_or_default() {
+                    if let Some(related) = relation.get_mut(&other) {
+                        related.remove(class);
+                    }
+                }
+            }
+        }
+
+        // Told named subclass edges of the affected classes; declared classes
+        // without any sit directly below owl:Thing
+        let mut told: HashMap<IRI, HashSet<IRI>> = affected
+            .iter()
+            .map(|class| (class.clone(), HashSet::new()))
+            .collect();
+        for axiom in ontology.subclass_axioms() {
+            if axiom.is_reflexive() {
+                continue;
+            }
+            if let (ClassExpression::Class(sub), ClassExpression::Class(sup)) =
+                (axiom.sub_class(), axiom.super_class())
+            {
+                if let Some(parents) = told.get_mut(&**sub.iri()) {
+                    parents.insert((**sup.iri()).clone());
+                }
+            }
+        }
+        for (class, parents) in told.iter_mut() {
+            if parents.is_empty() && ontology.is_punned(class).class {
+                parents.insert(thing.clone());
+            }
+        }
+
+        for class in &affected {
+            let mut ancestors = HashSet::new();
+            let mut queue: VecDeque<IRI> = told[class].iter().cloned().collect();
+            while let Some(current) = queue.pop_front() {
+                if current == *class {
+                    return Err(OwlError::OwlViolation(format!(
+                        "Cycle detected in class hierarchy starting from {}",
+                        class
+                    )));
+                }
+                if !ancestors.insert(current.clone()) {
+                    continue;
+                }
+                match told.get(&current) {
+                    Some(parents) => queue.extend(parents.iter().cloned()),
+                    // Unaffected classes already hold their transitive superclasses
+                    None => {
+                        ancestors.extend(self.parents.get(&current).into_iter().flatten().cloned())
+                    }
+                }
+            }
+
+            self.children.entry(class.clone()).or_default();
+            self.parents.entry(class.clone()).or_default();
+            for ancestor in ancestors {
+                self.add_parent(class.clone(), ancestor);
+            }
+            self.add_parent(nothing.clone(), class.clone());
+        }
+
+        let classes: Vec<IRI> = ontology
+            .classes()
+            .iter()
+            .map(|c| (**c.iri()).clone())
+            .collect();
+        for axiom in ontology.equivalent_classes_axioms() {
+            let members = axiom.classes();
+            if members.iter().any(|member| affected.contains(&**member)) {
+                for i in 0..members.len() {
+                    for j in i + 1..members.len() {
+                        self.add_equivalence((*members[i]).clone(), (*members[j]).clone());
+                    }
+                }
+            }
+        }
+        for axiom in ontology.disjoint_classes_axioms() {
+            let members = axiom.classes();
+            if members.iter().any(|member| affected.contains(&**member)) {
+                for i in 0..members.len() {
+                    for j in i + 1..members.len() {
+                        self.add_disjoint((*members[i]).clone(), (*members[j]).clone());
+                    }
+                }
+            }
+        }
+
+        for class in &affected {
+            let satisfiable = reasoner.is_class_satisfiable(class)?;
+            for other in &classes {
+                if other == class {
+                    continue;
+                }
+                if !self.are_equivalent(class, other)
+                    && reasoner.is_subclass_of(class, other)?
+                    && reasoner.is_subclass_of(other, class)?
+                {
+                    self.add_equivalence(class.clone(), other.clone());
+                }
+
+                if self.are_disjoint(class, other) {
+                    continue;
+                }
+                let related = self.get_all_superclasses(class).contains(other)
+                    || self.get_all_superclasses(other).contains(class);
+                let are_disjoint = if !satisfiable || !reasoner.is_class_satisfiable(other)? {
+                    true
+                } else if related {
+                    false
+                } else {
+                    reasoner.are_disjoint_classes(class, other)?
+                };
+                if are_disjoint {
+                    self.add_disjoint(class.clone(), other.clone());
+                }
+            }
+        }
+
+        self.ontology_hash = Some(ontology.content_hash());
+        Ok(affected)
+    }
+
+    /// Save the hierarchy to a JSON file
+    pub fn save(&self, path: impl AsRef<Path>) -> OwlResult<()> {
+        fn to_sorted(map: &HashMap<IRI, HashSet<IRI>>) -> BTreeMap<IRI, BTreeSet<IRI>> {
+            map.iter()
+                .map(|(k, v)| (k.clone(), v.iter().cloned().collect()))
+                .collect()
+        }
+
+        let serialized = SerializedHierarchy {
+            ontology_hash: self.ontology_hash,
+            parents: to_sorted(&self.parents),
+            children: to_sorted(&self.children),
+            equivalences: to_sorted(&self.equivalences),
+            disjointness: to_sorted(&self.disjointness),
+        };
+        let json = serde_json::to_string(&serialized)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Load a hierarchy previously written with [`ClassHierarchy::save`]
+    pub fn load(path: impl AsRef<Path>) -> OwlResult<Self> {
+        fn from_sorted(map: BTreeMap<IRI, BTreeSet<IRI>>) -> HashMap<IRI, HashSet<IRI>> {
+            map.into_iter()
+                .map(|(k, v)| (k, v.into_iter().collect()))
+                .collect()
+        }
+
+        let json = std::fs::read_to_string(path)?;
+        let serialized: SerializedHierarchy = serde_json::from_str(&json)?;
+        Ok(ClassHierarchy {
+            parents: from_sorted(serialized.parents),
+            children: from_sorted(serialized.children),
+            equivalences: from_sorted(serialized.equivalences),
+            disjointness: from_sorted(serialized.disjointness),
+            satisfiable: HashMap::new(),
+            depth_cache: HashMap::new(),
+            ontology_hash: serialized.ontology_hash,
+        })
+    }
+
     /// Add a parent relationship
     pub fn add_parent(&mut self, child: IRI, parent: IRI) {
         self.parents
@@ -673,6 +1215,49 @@ impl ClassHierarchy {
         result
     }
 
+    /// Superclasses of a class, either immediate parents or the transitive set
+    pub fn superclasses(&self, class_iri: &IRI, direct_only: bool) -> HashSet<IRI> {
+        let all = self.get_all_superclasses(class_iri);
+        self.related_classes(class_iri, all, direct_only, |class| {
+            self.get_all_superclasses(class)
+        })
+    }
+
+    /// Subclasses of a class, either immediate children or the transitive set
+    pub fn subclasses(&self, class_iri: &IRI, direct_only: bool) -> HashSet<IRI> {
+        let all = self.get_all_subclasses(class_iri);
+        self.related_classes(class_iri, all, direct_only, |class| {
+            self.get_all_subclasses(class)
+        })
+    }
+
+    /// Drop the class and its equivalents from `all`, and when `direct_only` is set
+    /// also every class reachable through another member of `all`
+    fn related_classes(
+        &self,
+        class_iri: &IRI,
+        mut all: HashSet<IRI>,
+        direct_only: bool,
+        reachable: impl Fn(&IRI) -> HashSet<IRI>,
+    ) -> HashSet<IRI> {
+        all.remove(class_iri);
+        all.retain(|other| !self.are_equivalent(class_iri, other));
+        if !direct_only {
+            return all;
+        }
+
+        let mut indirect = HashSet::new();
+        for intermediate in &all {
+            for beyond in reachable(intermediate) {
+                if beyond != *intermediate && !self.are_equivalent(intermediate, &beyond) {
+                    indirect.insert(beyond);
+                }
+            }
+        }
+        all.retain(|candidate| !indirect.contains(candidate));
+        all
+    }
+
     /// Get equivalent classes
     pub fn get_equivalent_classes(&self, class_iri: &IRI) -> HashSet<IRI> {
         self.equivalences
@@ -689,6 +1274,19 @@ impl ClassHierarchy {
             .unwrap_or_default()
     }
 
+    /// Get every pair of disjoint classes, each pair once with the smaller IRI first
+    pub fn disjoint_pairs(&self) -> HashSet<(IRI, IRI)> {
+        self.disjointness
+            .iter()
+            .flat_map(|(class1, disjoint)| {
+                disjoint
+                    .iter()
+                    .filter(move |class2| class1 < *class2)
+                    .map(move |class2| (class1.clone(), class2.clone()))
+            })
+            .collect()
+    }
+
     /// Get direct parents of a class
     pub fn get_direct_parents(&self, class_iri: &IRI) -> HashSet<IRI> {
         self.parents.get(class_iri).cloned().unwrap_or_default()
@@ -698,4 +1296,117 @@ impl ClassHierarchy {
     pub fn get_direct_children(&self, class_iri: &IRI) -> HashSet<IRI> {
         self.children.get(class_iri).cloned().unwrap_or_default()
     }
+
+    /// Render the hierarchy as Manchester syntax class frames
+    ///
+    /// Each class gets a `Class:` frame listing its direct superclasses under
+    /// `SubClassOf:` and its equivalent classes under `EquivalentTo:`, whether
+    /// asserted or inferred. owl:Thing is left out of the superclasses and
+    /// owl:Nothing gets no frame. Classes are written as full IRIs; see
+    /// [`to_manchester_with`](Self::to_manchester_with) for the other options.
+    pub fn to_manchester(&self) -> String {
+        self.to_manchester_with(&ManchesterFrameConfig::default())
+    }
+
+    /// Render the hierarchy as Manchester syntax class frames, as configured
+    pub fn to_manchester_with(&self, config: &ManchesterFrameConfig) -> String {
+        let labels = config.labels.map(unique_labels).unwrap_or_default();
+        self.render_manchester(config.direct_only, |iri| match labels.get(iri) {
+            Some(label) => manchester_name(label),
+            None => format!("<{}>", iri.as_str()),
+        })
+    }
+
+    /// Write a class frame for every class in the hierarchy, sorted by IRI
+    fn render_manchester(&self, direct_only: bool, name: impl Fn(&IRI) -> String) -> String {
+        let builtins = [owl::thing(), owl::nothing()];
+        let is_builtin = |iri: &IRI| builtins.contains(iri);
+        let classes: BTreeSet<&IRI> = self
+            .parents
+            .keys()
+            .chain(self.children.keys())
+            .filter(|iri| !is_builtin(iri))
+            .collect();
+
+        let names = |related: HashSet<IRI>| {
+            let mut names: Vec<String> = related
+                .iter()
+                .filter(|iri| !is_builtin(iri))
+                .map(&name)
+                .collect();
+            names.sort();
+            names.join(", ")
+        };
+
+        let mut output = String::new();
+        for class in classes {
+            output.push_str(&format!("Class: {}\n", name(class)));
+            let superclasses = names(self.superclasses(class, direct_only));
+            if !superclasses.is_empty() {
+                output.push_str(&format!("    SubClassOf: {}\n", superclasses));
+            }
+            let equivalents = names(self.get_equivalent_classes(class));
+            if !equivalents.is_empty() {
+                output.push_str(&format!("    EquivalentTo: {}\n", equivalents));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// The `rdfs:label` of every entity with a label no other entity shares
+///
+/// Untagged labels are preferred over language-tagged ones; a label shared by
+/// several entities would be ambiguous, so those entities get none.
+fn unique_labels(ontology: &Ontology) -> HashMap<IRI, String> {
+    let label_property = rdfs::label();
+    let mut labels: HashMap<&IRI, &Literal> = HashMap::new();
+    for axiom in ontology.annotation_assertion_axioms() {
+        let AnnotationValue::Literal(label) = axiom.value() else {
+            continue;
+        };
+        if **axiom.annotation_property() != label_property {
+            continue;
+        }
+        labels
+            .entry(&**axiom.subject())
+            .and_modify(|current| {
+                if label_rank(label) < label_rank(current) {
+                    *current = label;
+                }
+            })
+            .or_insert(label);
+    }
+
+    let mut uses: HashMap<&str, usize> = HashMap::new();
+    for label in labels.values() {
+        *uses.entry(label.lexical_form()).or_default() += 1;
+    }
+    labels
+        .into_iter()
+        .filter(|(_, label)| uses[label.lexical_form()] == 1)
+        .map(|(iri, label)| (iri.clone(), label.lexical_form().to_string()))
+        .collect()
+}
+
+/// Order labels so that untagged ones come first, then alphabetically
+fn label_rank(label: &Literal) -> (bool, &str) {
+    (label.language_tag().is_some(), label.lexical_form())
+}
+
+/// Write a label as a Manchester syntax name, quoting it unless it is a plain word
+fn manchester_name(label: &str) -> String {
+    let is_word = label
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_')
+        && label
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+    if is_word {
+        label.to_string()
+    } else {
+        format!("'{}'", label.replace('\'', "\\'"))
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! Provides algorithms for checking ontology consistency and detecting contradictions.
 
+use crate::axioms::ClassExpression;
 use crate::entities::*;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
@@ -10,6 +11,7 @@ use crate::reasoning::tableaux::TableauxReasoner;
 use crate::Axiom;
 
 use smallvec::SmallVec;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Consistency checker for OWL2 ontologies
@@ -77,6 +79,24 @@ pub enum ContradictionType {
     Other(String),
 }
 
+/// Quick, qualified answer on consistency from checks that avoid full reasoning
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsistencyEstimate {
+    /// Whether the ontology is likely consistent
+    pub likely_consistent: bool,
+    /// Confidence in `likely_consistent`, from 0.0 to 1.0
+    ///
+    /// Only answers decided by a clash or by Horn completion reach 1.0.
+    pub confidence: f64,
+    /// Clashes found among the asserted axioms, each making the ontology inconsistent
+    pub cheap_violations: Vec<String>,
+}
+
+impl ConsistencyEstimate {
+    /// Confidence of the answer when neither a clash nor Horn completion decides it
+    pub const UNDECIDED_CONFIDENCE: f64 = 0.75;
+}
+
 /// Consistency checking statistics
 #[derive(Debug, Clone)]
 pub struct ConsistencyStats {
@@ -433,3 +453,158 @@ impl ConsistencyChecker {
         Ok(explanations)
     }
 }
+
+/// Clashes among the asserted axioms that make an ontology inconsistent
+///
+/// Only looks at told facts: an individual typed with owl:Nothing, with two
+/// disjoint classes or with a class and its complement, individuals declared
+/// both the same and different, asserted edges that break reflexivity,
+/// irreflexivity or asymmetry or that are also negated, and literals outside a
+/// data property range. Clashes that need inference are left to full reasoning.
+pub(crate) fn structural_violations(ontology: &Ontology) -> Vec<String> {
+    let mut violations = Vec::new();
+
+    // Asserted named classes and complemented named classes of each individual
+    let mut types: HashMap<&IRI, (HashSet<&IRI>, HashSet<&IRI>)> = HashMap::new();
+    for assertion in ontology.class_assertions() {
+        let (classes, complements) = types.entry(&**assertion.individual()).or_default();
+        match assertion.class_expr() {
+            ClassExpression::Class(class) => {
+                classes.insert(&**class.iri());
+            }
+            ClassExpression::ObjectComplementOf(inner) => {
+                if let ClassExpression::Class(class) = inner.as_ref() {
+                    complements.insert(&**class.iri());
+                }
+            }
+            _ => {}
+        }
+    }
+    let disjoint_axioms = ontology.disjoint_classes_axioms();
+    for (individual, (classes, complements)) in &types {
+        if classes
+            .iter()
+            .any(|class| class.as_str() == "http://www.w3.org/2002/07/owl#Nothing")
+        {
+            violations.push(format!("{} is an instance of owl:Nothing", individual));
+        }
+        for class in classes.intersection(complements) {
+            violations.push(format!(
+                "{} is an instance of both {} and its complement",
+                individual, class
+            ));
+        }
+        for axiom in &disjoint_axioms {
+            let asserted: Vec<&Arc<IRI>> = axiom
+                .classes()
+                .iter()
+                .filter(|class| classes.contains(&***class))
+                .collect();
+            if asserted.len() > 1 {
+                violations.push(format!(
+                    "{} is an instance of disjoint classes {} and {}",
+                    individual, asserted[0], asserted[1]
+                ));
+            }
+        }
+    }
+
+    // Individuals declared both the same and different
+    let same: HashSet<(&IRI, &IRI)> = ontology
+        .same_individual_axioms()
+        .iter()
+        .flat_map(|axiom| {
+            let individuals = axiom.individuals();
+            individuals
+                .iter()
+                .flat_map(move |a| individuals.iter().map(move |b| (&**a, &**b)))
+        })
+        .collect();
+    for axiom in ontology.different_individuals_axioms() {
+        let individuals = axiom.individuals();
+        for (i, a) in individuals.iter().enumerate() {
+            for b in &individuals[i + 1..] {
+                if a == b {
+                    violations.push(format!("{} is declared different from itself", a));
+                } else if same.contains(&(&**a, &**b)) {
+                    violations.push(format!(
+                        "{} and {} are declared both the same and different",
+                        a, b
+                    ));
+                }
+            }
+        }
+    }
+
+    // Property characteristics broken by asserted edges
+    let reflexive: HashSet<&IRI> = ontology
+        .reflexive_property_axioms()
+        .into_iter()
+        .map(|axiom| &**axiom.property())
+        .collect();
+    let irreflexive: HashSet<&IRI> = ontology
+        .irreflexive_property_axioms()
+        .into_iter()
+        .map(|axiom| &**axiom.property())
+        .collect();
+    let asymmetric: HashSet<&IRI> = ontology
+        .asymmetric_property_axioms()
+        .into_iter()
+        .map(|axiom| &**axiom.property())
+        .collect();
+    for property in reflexive.intersection(&irreflexive) {
+        violations.push(format!("{} is both reflexive and irreflexive", property));
+    }
+    for property in reflexive.intersection(&asymmetric) {
+        violations.push(format!("{} is both reflexive and asymmetric", property));
+    }
+
+    let edges: HashSet<(&IRI, &IRI, &IRI)> = ontology
+        .property_assertions()
+        .into_iter()
+        .filter_map(|assertion| {
+            let object = assertion.object_iri()?;
+            Some((&**assertion.subject(), &**assertion.property(), &**object))
+        })
+        .collect();
+    for &(subject, property, object) in &edges {
+        if subject == object && irreflexive.contains(property) {
+            violations.push(format!(
+                "{} is irreflexive but relates {} to itself",
+                property, subject
+            ));
+        }
+        if asymmetric.contains(property)
+            && subject.as_str() <= object.as_str()
+            && edges.contains(&(object, property, subject))
+        {
+            violations.push(format!(
+                "{} is asymmetric but relates {} and {} both ways",
+                property, subject, object
+            ));
+        }
+    }
+    for axiom in ontology.negative_object_property_assertions() {
+        if edges.contains(&(axiom.subject(), axiom.property(), axiom.object())) {
+            violations.push(format!(
+                "{} {} {} is both asserted and negated",
+                axiom.subject(),
+                axiom.property(),
+                axiom.object()
+            ));
+        }
+    }
+
+    for assertion in crate::datatypes::data_range_violations(ontology) {
+        violations.push(format!(
+            "\"{}\" is outside the range of {} for {}",
+            assertion.value().lexical_form(),
+            assertion.property(),
+            assertion.subject()
+        ));
+    }
+
+    violations.sort();
+    violations.dedup();
+    violations
+}
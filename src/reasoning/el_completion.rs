@@ -0,0 +1,404 @@
+//! Completion-based consistency checking for Horn EL ontologies
+//!
+//! Ontologies whose axioms stay within the Horn EL fragment (named classes,
+//! `owl:Thing`, `owl:Nothing`, intersections and existential restrictions over
+//! named properties, with property hierarchies, transitivity, reflexivity,
+//! domains and ranges) never need the case splits of the tableau. Their
+//! consistency is decided by saturating a graph with one node per individual
+//! and one witness node per existential restriction, which takes polynomial
+//! time in the size of the ontology.
+
+use crate::axioms::{Axiom, ClassExpression, ObjectPropertyExpression};
+use crate::constants::owl;
+use crate::entities::Class;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Consistency checker for ontologies in the Horn EL fragment
+#[derive(Debug)]
+pub struct ElCompletion {
+    /// Concept inclusions `sub ⊑ sup`, including domains and disjointness
+    inclusions: Vec<(ClassExpression, ClassExpression)>,
+    /// Direct ranges of each property
+    ranges: HashMap<IRI, Vec<ClassExpression>>,
+    /// Every super-property of each property, the property itself included
+    super_properties: HashMap<IRI, HashSet<IRI>>,
+    transitive: HashSet<IRI>,
+    reflexive: HashSet<IRI>,
+    class_assertions: Vec<(IRI, ClassExpression)>,
+    property_assertions: Vec<(IRI, IRI, IRI)>,
+}
+
+/// The graph saturated by the completion rules
+struct Completion<'a> {
+    rules: &'a ElCompletion,
+    labels: Vec<HashSet<ClassExpression>>,
+    /// Outgoing `(property, target)` edges of each node
+    edges: Vec<HashSet<(IRI, usize)>>,
+    /// Witness node of each existential restriction `(property, filler)`
+    witnesses: HashMap<(IRI, ClassExpression), usize>,
+    nothing: ClassExpression,
+    thing: ClassExpression,
+}
+
+impl ElCompletion {
+    /// Build a checker for the ontology
+    ///
+    /// Returns `None` when some axiom falls outside the Horn EL fragment, in
+    /// which case consistency needs the tableau.
+    pub fn new(ontology: &Ontology) -> Option<Self> {
+        let mut completion = ElCompletion {
+            inclusions: Vec::new(),
+            ranges: HashMap::new(),
+            super_properties: HashMap::new(),
+            transitive: HashSet::new(),
+            reflexive: HashSet::new(),
+            class_assertions: Vec::new(),
+            property_assertions: Vec::new(),
+        };
+        let mut sub_properties: Vec<(IRI, IRI)> = Vec::new();
+
+        for axiom in ontology.axioms() {
+            match axiom.as_ref() {
+                Axiom::SubClassOf(axiom) => {
+                    if axiom.is_reflexive() {
+                        continue;
+                    }
+                    let (sub, sup) = (axiom.sub_class(), axiom.super_class());
+                    if !Self::is_el(sub) || !Self::is_el(sup) {
+                        return None;
+                    }
+                    completion.inclusions.push((sub.clone(), sup.clone()));
+                }
+                Axiom::EquivalentClasses(axiom) => {
+                    let expressions = axiom.class_expressions();
+                    if !expressions.iter().all(Self::is_el) {
+                        return None;
+                    }
+                    for a in expressions {
+                        for b in expressions {
+                            if a != b {
+                                completion.inclusions.push((a.clone(), b.clone()));
+                            }
+                        }
+                    }
+                }
+                Axiom::DisjointClasses(axiom) => {
+                    let classes = axiom.classes();
+                    for (i, a) in classes.iter().enumerate() {
+                        for b in &classes[i + 1..] {
+                            completion.inclusions.push((
+                                ClassExpression::ObjectIntersectionOf(
+                                    [Self::named(a), Self::named(b)]
+                                        .into_iter()
+                                        .map(Arc::new)
+                                        .collect(),
+                                ),
+                                ClassExpression::Class(Class::new(owl::nothing())),
+                            ));
+                        }
+                    }
+                }
+                Axiom::ClassAssertion(axiom) => {
+                    if !Self::is_el(axiom.class_expr()) {
+                        return None;
+                    }
+                    completion
+                        .class_assertions
+                        .push(((**axiom.individual()).clone(), axiom.class_expr().clone()));
+                }
+                // An anonymous object is one more individual, named by its blank node
+                Axiom::PropertyAssertion(axiom) => completion.property_assertions.push((
+                    (**axiom.subject()).clone(),
+                    (**axiom.property()).clone(),
+                    (*axiom.object_node_iri().ok()?).clone(),
+                )),
+                Axiom::SubObjectProperty(axiom) => sub_properties.push((
+                    (**axiom.sub_property()).clone(),
+                    (**axiom.super_property()).clone(),
+                )),
+                Axiom::EquivalentObjectProperties(axiom) => {
+                    for a in axiom.properties() {
+                        for b in axiom.properties() {
+                            if a != b {
+                                sub_properties.push(((**a).clone(), (**b).clone()));
+                            }
+                        }
+                    }
+                }
+                Axiom::TransitiveProperty(axiom) => {
+                    completion.transitive.insert((**axiom.property()).clone());
+                }
+                Axiom::ReflexiveProperty(axiom) => {
+                    completion.reflexive.insert((**axiom.property()).clone());
+                }
+                Axiom::ObjectPropertyDomain(axiom) => {
+                    if !axiom.property_expression().is_named() || !Self::is_el(axiom.domain()) {
+                        return None;
+                    }
+                    // The domain of R is ∃R.⊤ ⊑ D
+                    completion.inclusions.push((
+                        ClassExpression::ObjectSomeValuesFrom(
+                            Box::new(axiom.property_expression().clone()),
+                            Arc::new(ClassExpression::Class(Class::new(owl::thing()))),
+                        ),
+                        axiom.domain().clone(),
+                    ));
+                }
+                Axiom::ObjectPropertyRange(axiom) => {
+                    if !axiom.property_expression().is_named() || !Self::is_el(axiom.range()) {
+                        return None;
+                    }
+                    completion
+                        .ranges
+                        .entry(axiom.property().clone())
+                        .or_default()
+                        .push(axiom.range().clone());
+                }
+                // Without data property axioms, literal values cannot clash
+                Axiom::DataPropertyAssertion(_)
+                | Axiom::AnnotationAssertion(_)
+                | Axiom::SubAnnotationPropertyOf(_)
+                | Axiom::AnnotationPropertyDomain(_)
+                | Axiom::AnnotationPropertyRange(_)
+                | Axiom::Import(_) => {}
+                _ => return None,
+            }
+        }
+
+        let mut properties: HashSet<IRI> = HashSet::new();
+        for (sub, sup) in &sub_properties {
+            properties.insert(sub.clone());
+            properties.insert(sup.clone());
+        }
+        for property in properties {
+            let mut reached = HashSet::from([property.clone()]);
+            let mut pending = vec![property.clone()];
+            while let Some(current) = pending.pop() {
+                for (sub, sup) in &sub_properties {
+                    if *sub == current && reached.insert(sup.clone()) {
+                        pending.push(sup.clone());
+                    }
+                }
+            }
+            completion.super_properties.insert(property, reached);
+        }
+
+        Some(completion)
+    }
+
+    /// Whether every axiom of the ontology is in the Horn EL fragment
+    pub fn is_horn(ontology: &Ontology) -> bool {
+        Self::new(ontology).is_some()
+    }
+
+    /// Decide consistency by saturating the completion graph
+    pub fn is_consistent(&self) -> bool {
+        let mut completion = Completion::new(self);
+
+        // Every model has at least one element
+        let anonymous = completion.add_node(vec![completion.thing.clone()]);
+        let mut individuals: HashMap<&IRI, usize> = HashMap::new();
+        for (individual, class) in &self.class_assertions {
+            let node = completion.individual(&mut individuals, individual);
+            completion.labels[node].insert(class.clone());
+        }
+        for (subject, property, object) in &self.property_assertions {
+            let subject = completion.individual(&mut individuals, subject);
+            let object = completion.individual(&mut individuals, object);
+            completion.edges[subject].insert((property.clone(), object));
+        }
+
+        completion.saturate();
+        !individuals
+            .values()
+            .chain(std::iter::once(&anonymous))
+            .any(|node| completion.labels[*node].contains(&completion.nothing))
+    }
+
+    /// Whether an expression can be handled by the completion rules
+    fn is_el(expression: &ClassExpression) -> bool {
+        match expression {
+            ClassExpression::Class(_) => true,
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                operands.iter().all(|operand| Self::is_el(operand))
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                property.is_named() && Self::is_el(filler)
+            }
+            _ => false,
+        }
+    }
+
+    fn named(iri: &Arc<IRI>) -> ClassExpression {
+        ClassExpression::Class(Class::new((**iri).clone()))
+    }
+
+    fn super_properties_of<'a>(&'a self, property: &'a IRI) -> Vec<&'a IRI> {
+        match self.super_properties.get(property) {
+            Some(supers) => supers.iter().collect(),
+            None => vec![property],
+        }
+    }
+}
+
+impl<'a> Completion<'a> {
+    fn new(rules: &'a ElCompletion) -> Self {
+        Completion {
+            rules,
+            labels: Vec::new(),
+            edges: Vec::new(),
+            witnesses: HashMap::new(),
+            nothing: ClassExpression::Class(Class::new(owl::nothing())),
+            thing: ClassExpression::Class(Class::new(owl::thing())),
+        }
+    }
+
+    fn add_node(&mut self, label: Vec<ClassExpression>) -> usize {
+        let mut label: HashSet<ClassExpression> = label.into_iter().collect();
+        label.insert(self.thing.clone());
+        self.labels.push(label);
+        self.edges.push(HashSet::new());
+        self.labels.len() - 1
+    }
+
+    fn individual<'i>(
+        &mut self,
+        nodes: &mut HashMap<&'i IRI, usize>,
+        individual: &'i IRI,
+    ) -> usize {
+        if let Some(node) = nodes.get(individual) {
+            return *node;
+        }
+        let node = self.add_node(Vec::new());
+        nodes.insert(individual, node);
+        node
+    }
+
+    /// Apply the completion rules until nothing changes
+    fn saturate(&mut self) {
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for node in 0..self.labels.len() {
+                changed |= self.expand(node);
+            }
+            changed |= self.close_edges();
+        }
+    }
+
+    /// Apply the rules that add to the label and edges of one node
+    fn expand(&mut self, node: usize) -> bool {
+        let mut additions: Vec<ClassExpression> = Vec::new();
+        let mut successors: Vec<(IRI, ClassExpression)> = Vec::new();
+
+        for expression in &self.labels[node] {
+            match expression {
+                ClassExpression::ObjectIntersectionOf(operands) => {
+                    additions.extend(operands.iter().map(|operand| (**operand).clone()));
+                }
+                ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                    if let ObjectPropertyExpression::ObjectProperty(property) = property.as_ref() {
+                        successors.push(((**property.iri()).clone(), (**filler).clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        for (sub, sup) in &self.rules.inclusions {
+            if self.holds(sub, node) {
+                additions.push(sup.clone());
+            }
+        }
+        let mut ranges: Vec<(usize, ClassExpression)> = Vec::new();
+        for (property, target) in &self.edges[node] {
+            // ⊥ propagates back along edges
+            if self.labels[*target].contains(&self.nothing) {
+                additions.push(self.nothing.clone());
+            }
+            for property in self.rules.super_properties_of(property) {
+                for range in self.rules.ranges.get(property).into_iter().flatten() {
+                    ranges.push((*target, range.clone()));
+                }
+            }
+        }
+
+        let mut changed = false;
+        for expression in additions {
+            changed |= self.labels[node].insert(expression);
+        }
+        for (target, range) in ranges {
+            changed |= self.labels[target].insert(range);
+        }
+        for (property, filler) in successors {
+            changed |= self.add_successor(node, property, filler);
+        }
+        for property in &self.rules.reflexive {
+            changed |= self.edges[node].insert((property.clone(), node));
+        }
+        changed
+    }
+
+    /// Link a node to the witness of `∃property.filler`
+    fn add_successor(&mut self, node: usize, property: IRI, filler: ClassExpression) -> bool {
+        let key = (property.clone(), filler.clone());
+        let witness = match self.witnesses.get(&key) {
+            Some(witness) => *witness,
+            None => {
+                let witness = self.add_node(vec![filler]);
+                self.witnesses.insert(key, witness);
+                witness
+            }
+        };
+        self.edges[node].insert((property, witness))
+    }
+
+    /// Close the edges under the property hierarchy and transitivity
+    fn close_edges(&mut self) -> bool {
+        let mut changed = false;
+        for node in 0..self.edges.len() {
+            let mut added: Vec<(IRI, usize)> = Vec::new();
+            for (property, target) in &self.edges[node] {
+                for sup in self.rules.super_properties_of(property) {
+                    if sup != property {
+                        added.push((sup.clone(), *target));
+                    }
+                }
+                if self.rules.transitive.contains(property) {
+                    for (next, beyond) in &self.edges[*target] {
+                        if next == property {
+                            added.push((property.clone(), *beyond));
+                        }
+                    }
+                }
+            }
+            for edge in added {
+                changed |= self.edges[node].insert(edge);
+            }
+        }
+        changed
+    }
+
+    /// Whether the node is entailed to be an instance of the expression
+    fn holds(&self, expression: &ClassExpression, node: usize) -> bool {
+        match expression {
+            ClassExpression::Class(_) => {
+                *expression == self.thing || self.labels[node].contains(expression)
+            }
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                operands.iter().all(|operand| self.holds(operand, node))
+            }
+            ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                let ObjectPropertyExpression::ObjectProperty(property) = property.as_ref() else {
+                    return false;
+                };
+                self.edges[node]
+                    .iter()
+                    .any(|(edge, target)| edge == &**property.iri() && self.holds(filler, *target))
+            }
+            _ => false,
+        }
+    }
+}
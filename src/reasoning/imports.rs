@@ -0,0 +1,78 @@
+//! Imports consulted on demand during reasoning
+//!
+//! Ontologies loaded with [`Ontology::resolve_imports_lazily`] stay shared behind
+//! `Arc`s. An imported axiom sharing no entities with what is reasoned about,
+//! directly or through other axioms, cannot affect the answer, so only the
+//! connected axioms are inlined, and only once an entity reaching them comes up.
+
+use super::justification::is_logical;
+use crate::axioms::Axiom;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// The part of the imported ontologies inlined so far
+#[derive(Debug, Clone)]
+pub(crate) struct ImportModule {
+    imported: Vec<Arc<Ontology>>,
+    /// Entities whose connected imported axioms have been inlined
+    reached: HashSet<IRI>,
+    /// Whether each axiom of each imported ontology has been inlined
+    inlined: Vec<Vec<bool>>,
+}
+
+impl ImportModule {
+    pub(crate) fn new(imported: Vec<Arc<Ontology>>) -> Self {
+        let inlined = imported
+            .iter()
+            .map(|ontology| vec![false; ontology.axioms().len()])
+            .collect();
+        ImportModule {
+            imported,
+            reached: HashSet::new(),
+            inlined,
+        }
+    }
+
+    /// Whether no entity has been reached yet
+    pub(crate) fn is_unused(&self) -> bool {
+        self.reached.is_empty()
+    }
+
+    /// Reach the given entities, returning the imported axioms newly connected to them
+    pub(crate) fn extend<'a>(
+        &mut self,
+        signature: impl IntoIterator<Item = &'a IRI>,
+    ) -> Vec<Axiom> {
+        let mut changed = false;
+        for iri in signature {
+            changed |= self.reached.insert(iri.clone());
+        }
+
+        let mut connected = Vec::new();
+        while changed {
+            changed = false;
+            for (ontology, inlined) in self.imported.iter().zip(&mut self.inlined) {
+                for (axiom, inlined) in ontology.axioms().iter().zip(inlined.iter_mut()) {
+                    if *inlined || !is_logical(axiom) {
+                        continue;
+                    }
+                    let axiom_signature = axiom.signature();
+                    if !axiom_signature
+                        .iter()
+                        .any(|iri| self.reached.contains(iri.as_ref()))
+                    {
+                        continue;
+                    }
+                    *inlined = true;
+                    changed = true;
+                    self.reached
+                        .extend(axiom_signature.iter().map(|iri| (**iri).clone()));
+                    connected.push((**axiom).clone());
+                }
+            }
+        }
+        connected
+    }
+}
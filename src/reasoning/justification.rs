@@ -0,0 +1,108 @@
+//! Justifications: minimal sets of axioms responsible for an entailment
+//!
+//! A justification is found with a black-box reasoner. Axioms sharing no
+//! entities with the entailment, directly or through other axioms, cannot
+//! contribute, so only the connected axioms are considered. Those are then
+//! contracted one at a time: an axiom is dropped whenever the entailment still
+//! holds without it, which leaves a set from which no axiom can be removed.
+
+use crate::axioms::Axiom;
+use crate::error::OwlResult;
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Find a minimal set of axioms of `ontology` for which `entails` holds
+///
+/// `signature` names the entities of the entailment, and `entails` decides it
+/// on an ontology built from a subset of the axioms. Returns `None` when the
+/// ontology itself does not entail it.
+pub fn justify<F>(
+    ontology: &Ontology,
+    signature: &[&IRI],
+    mut entails: F,
+) -> OwlResult<Option<Vec<Axiom>>>
+where
+    F: FnMut(Ontology) -> OwlResult<bool>,
+{
+    let mut candidates = relevant_axioms(ontology, signature);
+    if !entails(build(&candidates)?)? {
+        return Ok(None);
+    }
+
+    let mut index = 0;
+    while index < candidates.len() {
+        let removed = candidates.remove(index);
+        if !entails(build(&candidates)?)? {
+            candidates.insert(index, removed);
+            index += 1;
+        }
+    }
+
+    Ok(Some(
+        candidates
+            .into_iter()
+            .map(|axiom| (*axiom).clone())
+            .collect(),
+    ))
+}
+
+/// The part of `ontology` that can take part in an entailment about the signature
+///
+/// Holds the logical axioms connected to the signature, in ontology order.
+pub(crate) fn module(ontology: &Ontology, signature: &[&IRI]) -> OwlResult<Ontology> {
+    build(&relevant_axioms(ontology, signature))
+}
+
+/// The logical axioms connected to the signature, in ontology order
+fn relevant_axioms(ontology: &Ontology, signature: &[&IRI]) -> Vec<Arc<Axiom>> {
+    let logical: Vec<(&Arc<Axiom>, Vec<Arc<IRI>>)> = ontology
+        .axioms()
+        .iter()
+        .filter(|axiom| is_logical(axiom))
+        .map(|axiom| (axiom, axiom.signature()))
+        .collect();
+
+    let mut reached: HashSet<IRI> = signature.iter().map(|iri| (*iri).clone()).collect();
+    let mut selected = vec![false; logical.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (index, (_, axiom_signature)) in logical.iter().enumerate() {
+            if selected[index] || !axiom_signature.iter().any(|iri| reached.contains(iri)) {
+                continue;
+            }
+            selected[index] = true;
+            changed = true;
+            reached.extend(axiom_signature.iter().map(|iri| (**iri).clone()));
+        }
+    }
+
+    logical
+        .into_iter()
+        .zip(selected)
+        .filter(|(_, selected)| *selected)
+        .map(|((axiom, _), _)| axiom.clone())
+        .collect()
+}
+
+/// Whether the axiom can take part in an entailment
+pub(crate) fn is_logical(axiom: &Axiom) -> bool {
+    !matches!(
+        axiom,
+        Axiom::AnnotationAssertion(_)
+            | Axiom::SubAnnotationPropertyOf(_)
+            | Axiom::AnnotationPropertyDomain(_)
+            | Axiom::AnnotationPropertyRange(_)
+            | Axiom::Import(_)
+    )
+}
+
+fn build(axioms: &[Arc<Axiom>]) -> OwlResult<Ontology> {
+    let mut ontology = Ontology::new();
+    for axiom in axioms {
+        ontology.add_axiom((**axiom).clone())?;
+    }
+    Ok(ontology)
+}
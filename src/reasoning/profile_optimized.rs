@@ -132,6 +132,7 @@ impl ProfileOptimizedReasoner {
                 reasoning_time_ms: 0,
                 nodes_expanded: 0,
                 rules_applied: 0,
+                ..Default::default()
             });
         }
 
@@ -151,6 +152,7 @@ impl ProfileOptimizedReasoner {
             reasoning_time_ms: duration.as_millis() as u64,
             nodes_expanded: 0,
             rules_applied: 0,
+            ..Default::default()
         })
     }
 
@@ -232,6 +234,7 @@ impl ProfileOptimizedReasoner {
             reasoning_time_ms: duration.as_millis() as u64,
             nodes_expanded: 0,
             rules_applied: 0,
+            ..Default::default()
         })
     }
 
@@ -291,6 +294,7 @@ impl ProfileOptimizedReasoner {
             reasoning_time_ms: duration.as_millis() as u64,
             nodes_expanded: 0,
             rules_applied: 0,
+            ..Default::default()
         })
     }
 
@@ -555,6 +555,7 @@ impl AdaptiveQueryIndex {
             QueryPattern::Filter { .. } => "filter".to_string(),
             QueryPattern::Reduced(_) => "reduced".to_string(),
             QueryPattern::Distinct(_) => "distinct".to_string(),
+            QueryPattern::Path { .. } => "path".to_string(),
         }
     }
 
@@ -930,6 +931,15 @@ impl CompiledPattern {
             QueryPattern::Reduced(inner) | QueryPattern::Distinct(inner) => {
                 Self::collect_variables(inner, variables);
             }
+            QueryPattern::Path {
+                subject, object, ..
+            } => {
+                for term in [subject, object] {
+                    if let PatternTerm::Variable(var) = term {
+                        variables.insert(var.clone());
+                    }
+                }
+            }
         }
     }
 
@@ -984,6 +994,16 @@ impl CompiledPattern {
                 5u8.hash(&mut hasher);
                 Self::compute_pattern_hash(inner).hash(&mut hasher);
             }
+            QueryPattern::Path {
+                subject,
+                path,
+                object,
+            } => {
+                6u8.hash(&mut hasher);
+                subject.hash(&mut hasher);
+                path.hash(&mut hasher);
+                object.hash(&mut hasher);
+            }
         }
 
         hasher.finish()
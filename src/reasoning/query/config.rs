@@ -2,6 +2,8 @@
 //!
 //! Contains configuration options, performance statistics, and query optimization settings.
 
+use crate::iri::IRI;
+use std::collections::HashSet;
 use std::num::NonZeroUsize;
 use std::time::Duration;
 
@@ -26,6 +28,53 @@ pub struct QueryConfig {
     pub max_memory: Option<usize>,
     /// Batch size for parallel processing
     pub batch_size: usize,
+    /// Predicates answered under the closed-world assumption
+    pub closed_predicates: ClosedPredicates,
+}
+
+/// Classes and properties treated under the closed-world assumption
+///
+/// For a closed predicate, the absence of an assertion is taken as its
+/// negation (negation as failure). All other predicates keep the usual
+/// open-world semantics of OWL.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClosedPredicates {
+    classes: HashSet<IRI>,
+    properties: HashSet<IRI>,
+}
+
+impl ClosedPredicates {
+    /// Create an empty set of closed predicates
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Close a class: individuals not asserted to be members are non-members
+    pub fn with_class(mut self, class: IRI) -> Self {
+        self.classes.insert(class);
+        self
+    }
+
+    /// Close a property: missing assertions are taken to be false
+    pub fn with_property(mut self, property: IRI) -> Self {
+        self.properties.insert(property);
+        self
+    }
+
+    /// Check whether a class is closed
+    pub fn is_closed_class(&self, class: &IRI) -> bool {
+        self.classes.contains(class)
+    }
+
+    /// Check whether a property is closed
+    pub fn is_closed_property(&self, property: &IRI) -> bool {
+        self.properties.contains(property)
+    }
+
+    /// Check whether no predicate is closed
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.properties.is_empty()
+    }
 }
 
 impl Default for QueryConfig {
@@ -40,6 +89,7 @@ impl Default for QueryConfig {
             enable_optimization: true,
             max_memory: Some(100 * 1024 * 1024), // 100MB
             batch_size: 100,
+            closed_predicates: ClosedPredicates::default(),
         }
     }
 }
@@ -104,6 +154,12 @@ impl QueryConfig {
         self
     }
 
+    /// Set the predicates answered under the closed-world assumption
+    pub fn with_closed_predicates(mut self, closed_predicates: ClosedPredicates) -> Self {
+        self.closed_predicates = closed_predicates;
+        self
+    }
+
     /// Disable all optimizations for testing
     pub fn no_optimization() -> Self {
         Self {
@@ -116,6 +172,7 @@ impl QueryConfig {
             enable_optimization: false,
             max_memory: None,
             batch_size: 1,
+            closed_predicates: ClosedPredicates::default(),
         }
     }
 
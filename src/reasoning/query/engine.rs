@@ -6,16 +6,16 @@ use crate::axioms::*;
 use crate::error::OwlResult;
 use crate::iri::IRI;
 use crate::ontology::Ontology;
-use crate::reasoning::Reasoner;
+use crate::reasoning::{Reasoner, SimpleReasoner};
 
 use super::{
-    compute_config_hash, create_cache_key, QueryCache, QueryConfig, QueryEngineStats, QueryPattern,
-    QueryResult, QueryType, ResultPool, TriplePattern, RDF_TYPE,
+    compute_config_hash, create_cache_key, PatternTerm, PropertyPath, QueryCache, QueryConfig,
+    QueryEngineStats, QueryPattern, QueryResult, QueryType, ResultPool, TriplePattern, RDF_TYPE,
 };
 
 use dashmap::DashMap;
 use parking_lot::RwLock;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 /// Query engine for OWL2 ontologies with advanced optimizations
@@ -119,7 +119,6 @@ impl QueryEngine {
 
     /// Execute a basic class query (get all instances of a class)
     pub fn get_class_instances(&self, class_iri: &IRI) -> OwlResult<QueryResult> {
-
         // Get class assertions
         let instances: Vec<IRI> = self
             .ontology
@@ -146,19 +145,23 @@ impl QueryEngine {
     }
 
     /// Execute a basic property query (get all property values for a subject)
+    ///
+    /// Object values are returned as IRIs and data values as literals. With
+    /// reasoning enabled, values asserted with an equivalent property are included.
     pub fn get_property_values(
         &self,
         subject_iri: &IRI,
         property_iri: &IRI,
     ) -> OwlResult<QueryResult> {
+        let properties = self.matching_properties(property_iri);
 
         // Get property assertions
-        let values: Vec<super::QueryValue> = self
+        let mut values: Vec<super::QueryValue> = self
             .ontology
             .property_assertions()
             .iter()
             .filter(|axiom| {
-                (**axiom.subject()) == *subject_iri && (**axiom.property()) == *property_iri
+                (**axiom.subject()) == *subject_iri && properties.contains(&**axiom.property())
             })
             .filter_map(|axiom| match axiom.object() {
                 PropertyAssertionObject::Named(individual) => {
@@ -167,6 +170,17 @@ impl QueryEngine {
                 PropertyAssertionObject::Anonymous(_) => None,
             })
             .collect();
+        values.extend(
+            self.ontology
+                .data_property_assertions()
+                .iter()
+                .filter(|axiom| {
+                    **axiom.subject() == *subject_iri && properties.contains(&**axiom.property())
+                })
+                .map(|axiom| super::QueryValue::Literal(axiom.value().lexical_form().to_string())),
+        );
+        let mut seen = HashSet::new();
+        values.retain(|value| seen.insert(value.clone()));
 
         // Create query result
         let mut result = QueryResult::new();
@@ -184,6 +198,175 @@ impl QueryEngine {
         Ok(result)
     }
 
+    /// Get the individuals that are not instances of a class
+    ///
+    /// For a class closed in [`QueryConfig::closed_predicates`], every known
+    /// individual that is not a member is a non-instance. For any other class only
+    /// individuals asserted to belong to its complement are returned. With reasoning
+    /// enabled, membership follows the subclass hierarchy: an individual of a
+    /// subclass is a member, and one in the complement of a superclass is not.
+    pub fn get_class_non_instances(&self, class_iri: &IRI) -> OwlResult<QueryResult> {
+        let closed = self.config.closed_predicates.is_closed_class(class_iri);
+        let reasoner = self
+            .config
+            .enable_reasoning
+            .then(|| SimpleReasoner::new((*self.ontology).clone()));
+
+        let mut members = HashSet::new();
+        let mut non_members = HashSet::new();
+        for axiom in self.ontology.class_assertions() {
+            let individual = (**axiom.individual()).clone();
+            let mut asserted = Vec::new();
+            Self::asserted_classes(axiom.class_expr(), true, &mut asserted);
+            let mut denied = Vec::new();
+            Self::asserted_classes(axiom.class_expr(), false, &mut denied);
+
+            let mut is_member = false;
+            for class in asserted {
+                is_member |= class == class_iri
+                    || match &reasoner {
+                        Some(reasoner) => reasoner.is_subclass_of(class, class_iri)?,
+                        None => false,
+                    };
+            }
+            let mut is_non_member = false;
+            for class in denied {
+                is_non_member |= class == class_iri
+                    || match &reasoner {
+                        Some(reasoner) => reasoner.is_subclass_of(class_iri, class)?,
+                        None => false,
+                    };
+            }
+
+            if is_member {
+                members.insert(individual);
+            } else if is_non_member {
+                non_members.insert(individual);
+            }
+        }
+
+        if closed {
+            let mut known: HashSet<IRI> = self
+                .ontology
+                .named_individuals()
+                .iter()
+                .map(|individual| (**individual.iri()).clone())
+                .collect();
+            known.extend(
+                self.ontology
+                    .class_assertions()
+                    .iter()
+                    .map(|axiom| (**axiom.individual()).clone()),
+            );
+            non_members.extend(
+                known
+                    .into_iter()
+                    .filter(|individual| !members.contains(individual)),
+            );
+        }
+
+        let mut non_members: Vec<IRI> = non_members.into_iter().collect();
+        non_members.sort();
+
+        let mut result = QueryResult::new();
+        result.variables = vec!["individual".to_string()];
+
+        for individual in non_members {
+            let mut binding = super::QueryBinding::new();
+            binding.add_binding("individual".to_string(), super::QueryValue::IRI(individual));
+            result.add_binding(binding);
+        }
+
+        result.stats.results_count = result.len();
+        result.stats.reasoning_used = self.config.enable_reasoning;
+
+        Ok(result)
+    }
+
+    /// Check whether `subject` is related to `object` by an object property
+    ///
+    /// Returns `Some(true)` for an asserted relationship and `Some(false)` for a
+    /// negative assertion or, when the property is closed, a missing assertion.
+    /// With reasoning enabled, relationships entailed through sub-properties,
+    /// equivalent and inverse properties count too, and negative assertions of
+    /// equivalent properties.
+    /// `None` means the answer is unknown under the open-world assumption.
+    pub fn has_property_value(
+        &self,
+        subject_iri: &IRI,
+        property_iri: &IRI,
+        object_iri: &IRI,
+    ) -> OwlResult<Option<bool>> {
+        let properties = self.matching_properties(property_iri);
+        let asserted = if self.config.enable_reasoning {
+            SimpleReasoner::new((*self.ontology).clone())
+                .saturate_property_assertions()
+                .contains(&(
+                    subject_iri.clone(),
+                    property_iri.clone(),
+                    object_iri.clone(),
+                ))
+        } else {
+            self.ontology.property_assertions().iter().any(|axiom| {
+                **axiom.subject() == *subject_iri
+                    && **axiom.property() == *property_iri
+                    && matches!(axiom.object(), PropertyAssertionObject::Named(object) if **object == *object_iri)
+            })
+        };
+        if asserted {
+            return Ok(Some(true));
+        }
+
+        let denied = self
+            .ontology
+            .negative_object_property_assertions()
+            .iter()
+            .any(|axiom| {
+                axiom.subject() == subject_iri
+                    && properties.contains(axiom.property())
+                    && axiom.object() == object_iri
+            });
+        if denied
+            || self
+                .config
+                .closed_predicates
+                .is_closed_property(property_iri)
+        {
+            return Ok(Some(false));
+        }
+
+        Ok(None)
+    }
+
+    /// The properties whose assertions answer a query about `property_iri`:
+    /// its equivalence class with reasoning enabled, otherwise just itself
+    fn matching_properties(&self, property_iri: &IRI) -> HashSet<IRI> {
+        if self.config.enable_reasoning {
+            self.ontology.equivalent_properties(property_iri)
+        } else {
+            HashSet::from([property_iri.clone()])
+        }
+    }
+
+    /// Collect the named classes a class expression asserts (or, with `positive`
+    /// unset, denies) membership in through a possibly nested intersection
+    fn asserted_classes<'a>(expr: &'a ClassExpression, positive: bool, classes: &mut Vec<&'a IRI>) {
+        match expr {
+            ClassExpression::Class(class) if positive => classes.push(class.iri()),
+            ClassExpression::ObjectComplementOf(inner) if !positive => {
+                if let ClassExpression::Class(class) = inner.as_ref() {
+                    classes.push(class.iri());
+                }
+            }
+            ClassExpression::ObjectIntersectionOf(operands) => {
+                for op in operands {
+                    Self::asserted_classes(op, positive, classes);
+                }
+            }
+            _ => {}
+        }
+    }
+
     /// Get all classes in the ontology
     pub fn get_all_classes(&self) -> OwlResult<QueryResult> {
         let classes: Vec<IRI> = self
@@ -299,9 +482,133 @@ impl QueryEngine {
                 result.bindings.dedup();
                 Ok(result)
             }
+            QueryPattern::Path {
+                subject,
+                path,
+                object,
+            } => self.execute_path_pattern(subject, path, object),
         }
     }
 
+    /// Execute a property path pattern
+    ///
+    /// The path is evaluated over the property assertions, saturated under the
+    /// property axioms when reasoning is enabled.
+    fn execute_path_pattern(
+        &self,
+        subject: &PatternTerm,
+        path: &PropertyPath,
+        object: &PatternTerm,
+    ) -> OwlResult<QueryResult> {
+        let graph = self.path_graph();
+
+        let mut pairs: Vec<(IRI, IRI)> = Vec::new();
+        match (subject, object) {
+            (PatternTerm::IRI(start), _) => {
+                for end in graph.follow(path, start, false) {
+                    pairs.push((start.clone(), end));
+                }
+            }
+            (PatternTerm::Variable(_), PatternTerm::IRI(end)) => {
+                for start in graph.follow(path, end, true) {
+                    pairs.push((start, end.clone()));
+                }
+            }
+            (PatternTerm::Variable(_), PatternTerm::Variable(_)) => {
+                for start in &graph.nodes {
+                    for end in graph.follow(path, start, false) {
+                        pairs.push((start.clone(), end));
+                    }
+                }
+            }
+            // Paths only connect named individuals
+            _ => {}
+        }
+
+        let mut result = QueryResult::new();
+        for term in [subject, object] {
+            if let PatternTerm::Variable(name) = term {
+                if !result.variables.contains(name) {
+                    result.variables.push(name.clone());
+                }
+            }
+        }
+
+        for (start, end) in pairs {
+            let mut binding = super::QueryBinding::new();
+            match (subject, object) {
+                (PatternTerm::Variable(s), PatternTerm::Variable(o)) if s == o => {
+                    if start != end {
+                        continue;
+                    }
+                    binding.add_binding(s.clone(), super::QueryValue::IRI(start));
+                }
+                _ => {
+                    match subject {
+                        PatternTerm::Variable(name) => {
+                            binding.add_binding(name.clone(), super::QueryValue::IRI(start))
+                        }
+                        PatternTerm::IRI(_) => {}
+                        _ => continue,
+                    }
+                    match object {
+                        PatternTerm::Variable(name) => {
+                            binding.add_binding(name.clone(), super::QueryValue::IRI(end))
+                        }
+                        PatternTerm::IRI(iri) if *iri == end => {}
+                        _ => continue,
+                    }
+                }
+            }
+            result.add_binding(binding);
+        }
+
+        result.stats.results_count = result.len();
+        result.stats.reasoning_used = self.config.enable_reasoning;
+
+        Ok(result)
+    }
+
+    /// Build the graph of named individuals that property paths are evaluated over
+    fn path_graph(&self) -> PathGraph {
+        let edges: HashSet<(IRI, IRI, IRI)> = if self.config.enable_reasoning {
+            SimpleReasoner::new((*self.ontology).clone()).saturate_property_assertions()
+        } else {
+            self.ontology
+                .property_assertions()
+                .iter()
+                .filter_map(|axiom| match axiom.object() {
+                    PropertyAssertionObject::Named(object) => Some((
+                        (**axiom.subject()).clone(),
+                        (**axiom.property()).clone(),
+                        (**object).clone(),
+                    )),
+                    PropertyAssertionObject::Anonymous(_) => None,
+                })
+                .collect()
+        };
+
+        let mut graph = PathGraph::default();
+        for individual in self.ontology.named_individuals() {
+            graph.nodes.insert((**individual.iri()).clone());
+        }
+        for (subject, property, object) in edges {
+            graph.nodes.insert(subject.clone());
+            graph.nodes.insert(object.clone());
+            graph
+                .forward
+                .entry((property.clone(), subject.clone()))
+                .or_default()
+                .push(object.clone());
+            graph
+                .backward
+                .entry((property, object))
+                .or_default()
+                .push(subject);
+        }
+        graph
+    }
+
     /// Execute basic graph pattern
     fn execute_basic_graph_pattern(&self, triples: &[TriplePattern]) -> OwlResult<QueryResult> {
         if triples.is_empty() {
@@ -484,6 +791,77 @@ impl QueryEngine {
     }
 }
 
+/// Object property edges indexed for following property paths
+#[derive(Default)]
+struct PathGraph {
+    /// Objects reached from a subject through a property
+    forward: HashMap<(IRI, IRI), Vec<IRI>>,
+    /// Subjects reaching an object through a property
+    backward: HashMap<(IRI, IRI), Vec<IRI>>,
+    /// Every individual in the graph
+    nodes: HashSet<IRI>,
+}
+
+impl PathGraph {
+    /// The individuals connected to `node` through `path`, walking it in
+    /// reverse when `backward` is set
+    fn follow(&self, path: &PropertyPath, node: &IRI, backward: bool) -> HashSet<IRI> {
+        match path {
+            PropertyPath::Property(property) => {
+                let index = if backward {
+                    &self.backward
+                } else {
+                    &self.forward
+                };
+                index
+                    .get(&(property.clone(), node.clone()))
+                    .map(|targets| targets.iter().cloned().collect())
+                    .unwrap_or_default()
+            }
+            PropertyPath::Inverse(inner) => self.follow(inner, node, !backward),
+            PropertyPath::Seq(steps) => {
+                let mut frontier = HashSet::from([node.clone()]);
+                let ordered: Vec<&PropertyPath> = if backward {
+                    steps.iter().rev().collect()
+                } else {
+                    steps.iter().collect()
+                };
+                for step in ordered {
+                    frontier = frontier
+                        .iter()
+                        .flat_map(|current| self.follow(step, current, backward))
+                        .collect();
+                }
+                frontier
+            }
+            PropertyPath::Alt(alternatives) => alternatives
+                .iter()
+                .flat_map(|alternative| self.follow(alternative, node, backward))
+                .collect(),
+            PropertyPath::ZeroOrMore(inner) => {
+                let mut reached = self.reachable(inner, node, backward);
+                reached.insert(node.clone());
+                reached
+            }
+            PropertyPath::OneOrMore(inner) => self.reachable(inner, node, backward),
+        }
+    }
+
+    /// The individuals reached by following `path` one or more times
+    fn reachable(&self, path: &PropertyPath, node: &IRI, backward: bool) -> HashSet<IRI> {
+        let mut reached = HashSet::new();
+        let mut pending = vec![node.clone()];
+        while let Some(current) = pending.pop() {
+            for next in self.follow(path, &current, backward) {
+                if reached.insert(next.clone()) {
+                    pending.push(next);
+                }
+            }
+        }
+        reached
+    }
+}
+
 /// Extension trait for QueryPattern to support parallel execution
 trait QueryPatternExt {
     fn supports_parallel(&self) -> bool;
@@ -498,6 +876,7 @@ impl QueryPatternExt for QueryPattern {
             QueryPattern::Filter { .. } => false,
             QueryPattern::Reduced(_) => false,
             QueryPattern::Distinct(_) => false,
+            QueryPattern::Path { .. } => false,
         }
     }
 }
@@ -505,10 +884,10 @@ impl QueryPatternExt for QueryPattern {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::{PatternTerm, QueryPattern, TriplePattern, RDF_TYPE};
     use crate::entities::*;
     use crate::iri::IRI;
     use std::sync::Arc;
-    use super::{PatternTerm, TriplePattern, QueryPattern, RDF_TYPE};
 
     fn create_test_ontology() -> Ontology {
         let mut ontology = Ontology::new();
@@ -597,25 +976,23 @@ mod tests {
     }
 
     fn create_test_query_pattern(subject: &str, predicate: &str, object: &str) -> QueryPattern {
-        QueryPattern::BasicGraphPattern(vec![
-            TriplePattern::new(
-                if subject.starts_with('?') {
-                    PatternTerm::Variable(subject.to_string())
-                } else {
-                    PatternTerm::IRI(IRI::new(subject).expect("Valid IRI"))
-                },
-                if predicate.starts_with('?') {
-                    PatternTerm::Variable(predicate.to_string())
-                } else {
-                    PatternTerm::IRI(IRI::new(predicate).expect("Valid IRI"))
-                },
-                if object.starts_with('?') {
-                    PatternTerm::Variable(object.to_string())
-                } else {
-                    PatternTerm::IRI(IRI::new(object).expect("Valid IRI"))
-                },
-            ),
-        ])
+        QueryPattern::BasicGraphPattern(vec![TriplePattern::new(
+            if subject.starts_with('?') {
+                PatternTerm::Variable(subject.to_string())
+            } else {
+                PatternTerm::IRI(IRI::new(subject).expect("Valid IRI"))
+            },
+            if predicate.starts_with('?') {
+                PatternTerm::Variable(predicate.to_string())
+            } else {
+                PatternTerm::IRI(IRI::new(predicate).expect("Valid IRI"))
+            },
+            if object.starts_with('?') {
+                PatternTerm::Variable(object.to_string())
+            } else {
+                PatternTerm::IRI(IRI::new(object).expect("Valid IRI"))
+            },
+        )])
     }
 
     fn create_test_query_engine() -> QueryEngine {
@@ -798,8 +1175,16 @@ mod tests {
         let engine = create_test_query_engine();
 
         let pattern = QueryPattern::Optional {
-            left: Box::new(create_test_query_pattern("?s", RDF_TYPE, "http://example.org/Person")),
-            right: Box::new(create_test_query_pattern("?s", "http://example.org/worksFor", "?company")),
+            left: Box::new(create_test_query_pattern(
+                "?s",
+                RDF_TYPE,
+                "http://example.org/Person",
+            )),
+            right: Box::new(create_test_query_pattern(
+                "?s",
+                "http://example.org/worksFor",
+                "?company",
+            )),
         };
 
         let result = engine.execute(&pattern);
@@ -817,8 +1202,16 @@ mod tests {
         let engine = create_test_query_engine();
 
         let pattern = QueryPattern::Union {
-            left: Box::new(create_test_query_pattern("?s", RDF_TYPE, "http://example.org/Person")),
-            right: Box::new(create_test_query_pattern("?s", RDF_TYPE, "http://example.org/Company")),
+            left: Box::new(create_test_query_pattern(
+                "?s",
+                RDF_TYPE,
+                "http://example.org/Person",
+            )),
+            right: Box::new(create_test_query_pattern(
+                "?s",
+                RDF_TYPE,
+                "http://example.org/Company",
+            )),
         };
 
         let result = engine.execute(&pattern);
@@ -899,8 +1292,10 @@ mod tests {
 
         // Should have recorded cache activity
         if engine.config().enable_caching {
-            assert!(stats_after2.get_cache_hits() + stats_after2.get_cache_misses() >
-                    stats_after1.get_cache_hits() + stats_after1.get_cache_misses());
+            assert!(
+                stats_after2.get_cache_hits() + stats_after2.get_cache_misses()
+                    > stats_after1.get_cache_hits() + stats_after1.get_cache_misses()
+            );
         }
     }
 
@@ -1042,8 +1437,16 @@ mod tests {
         // Test nested patterns
         let nested_pattern = QueryPattern::Filter {
             pattern: Box::new(QueryPattern::Optional {
-                left: Box::new(create_test_query_pattern("?s", RDF_TYPE, "http://example.org/Person")),
-                right: Box::new(create_test_query_pattern("?s", "http://example.org/worksFor", "?company")),
+                left: Box::new(create_test_query_pattern(
+                    "?s",
+                    RDF_TYPE,
+                    "http://example.org/Person",
+                )),
+                right: Box::new(create_test_query_pattern(
+                    "?s",
+                    "http://example.org/worksFor",
+                    "?company",
+                )),
             }),
             expression: FilterExpression::IsVariable("?s".to_string()),
         };
@@ -1147,8 +1550,10 @@ mod tests {
         let engine = create_test_query_engine();
 
         // Create a pattern that requires joining
-        let left_pattern = create_test_query_pattern("?person", RDF_TYPE, "http://example.org/Person");
-        let right_pattern = create_test_query_pattern("?person", "http://example.org/worksFor", "?company");
+        let left_pattern =
+            create_test_query_pattern("?person", RDF_TYPE, "http://example.org/Person");
+        let right_pattern =
+            create_test_query_pattern("?person", "http://example.org/worksFor", "?company");
 
         // Test join by executing patterns and then combining results
         let left_result = engine.execute(&left_pattern);
@@ -79,6 +79,12 @@ pub enum QueryPattern {
     },
     Reduced(Box<QueryPattern>),
     Distinct(Box<QueryPattern>),
+    /// Subject and object connected through a property path
+    Path {
+        subject: PatternTerm,
+        path: PropertyPath,
+        object: PatternTerm,
+    },
 }
 
 // Safety: All variants in QueryPattern contain Send + Sync types
@@ -102,6 +108,23 @@ pub enum PatternTerm {
     BlankNode(String),
 }
 
+/// SPARQL-style property path
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+pub enum PropertyPath {
+    /// A single object property (`p`)
+    Property(IRI),
+    /// The path followed backwards (`^p`)
+    Inverse(Box<PropertyPath>),
+    /// The paths followed one after another (`p1/p2`)
+    Seq(Vec<PropertyPath>),
+    /// Any one of the paths (`p1|p2`)
+    Alt(Vec<PropertyPath>),
+    /// The path repeated any number of times, including none (`p*`)
+    ZeroOrMore(Box<PropertyPath>),
+    /// The path repeated at least once (`p+`)
+    OneOrMore(Box<PropertyPath>),
+}
+
 /// Filter expression
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum FilterExpression {
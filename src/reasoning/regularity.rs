@@ -0,0 +1,213 @@
+//! Regularity check for property hierarchies with property chains
+//!
+//! OWL2 DL only guarantees decidability when the property hierarchy together
+//! with the property chains is regular: some strict order on the properties
+//! puts every property of a chain `S₁ ∘ … ∘ Sₙ ⊑ R` below `R`, except `R` itself
+//! in first or last position and transitivity `R ∘ R ⊑ R`. Such an order exists
+//! exactly when no cycle of subproperty and chain dependencies passes through a
+//! dependency that must be strict, so the check is a reachability search over
+//! the properties. Inverses share the position of their property.
+
+use crate::axioms::{Axiom, ObjectPropertyExpression, SubPropertyChainOfAxiom};
+use crate::error::{OwlError, OwlResult};
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+/// Checks that the property chains of an ontology are regular
+#[derive(Debug)]
+pub struct RegularityChecker {
+    /// Properties each property must be ordered below, with the axiom requiring it
+    dependencies: HashMap<Arc<IRI>, Vec<Dependency>>,
+}
+
+/// `below ≼ above`, strict when `below` must precede `above`
+#[derive(Debug, Clone)]
+struct Dependency {
+    above: Arc<IRI>,
+    strict: bool,
+    axiom: String,
+}
+
+impl RegularityChecker {
+    /// Collect the ordering constraints of the ontology's property axioms
+    pub fn new(ontology: &Ontology) -> Self {
+        let mut checker = RegularityChecker {
+            dependencies: HashMap::new(),
+        };
+
+        for axiom in ontology.axioms() {
+            match axiom.as_ref() {
+                Axiom::SubObjectProperty(axiom) => {
+                    let description =
+                        format!("{} ⊑ {}", axiom.sub_property(), axiom.super_property());
+                    checker.depend(
+                        axiom.sub_property(),
+                        axiom.super_property(),
+                        false,
+                        &description,
+                    );
+                }
+                Axiom::EquivalentObjectProperties(axiom) => {
+                    let properties = axiom.properties();
+                    let description = properties
+                        .iter()
+                        .map(|property| property.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ≡ ");
+                    for a in properties {
+                        for b in properties {
+                            if a != b {
+                                checker.depend(a, b, false, &description);
+                            }
+                        }
+                    }
+                }
+                Axiom::InverseObjectProperties(axiom) => {
+                    let (a, b) = (axiom.property1(), axiom.property2());
+                    let description = format!("{} ≡ {}", describe(a), describe(&b.inverse()));
+                    checker.depend(a.property_iri(), b.property_iri(), false, &description);
+                    checker.depend(b.property_iri(), a.property_iri(), false, &description);
+                }
+                Axiom::SubPropertyChainOf(axiom) => checker.add_chain(axiom),
+                _ => {}
+            }
+        }
+
+        checker
+    }
+
+    /// Fail with a description of the first irregular chain, if any
+    pub fn check(&self) -> OwlResult<()> {
+        match self.violation() {
+            Some(message) => Err(OwlError::OwlViolation(message)),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the property chains are regular
+    pub fn is_regular(&self) -> bool {
+        self.violation().is_none()
+    }
+
+    /// Describe the first irregular chain and the cycle it closes, if any
+    pub fn violation(&self) -> Option<String> {
+        let mut sources: Vec<&Arc<IRI>> = self.dependencies.keys().collect();
+        sources.sort();
+
+        for below in sources {
+            for dependency in &self.dependencies[below] {
+                if !dependency.strict {
+                    continue;
+                }
+                let reason = if dependency.above == *below {
+                    format!("{} would have to precede itself", below)
+                } else if let Some(path) = self.path(&dependency.above, below) {
+                    format!(
+                        "{} must precede {}, yet {} is below {} through {}",
+                        below,
+                        dependency.above,
+                        dependency.above,
+                        below,
+                        path.join(" and ")
+                    )
+                } else {
+                    continue;
+                };
+                return Some(format!(
+                    "Irregular property chains: {}; {}",
+                    dependency.axiom, reason
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn add_chain(&mut self, axiom: &SubPropertyChainOfAxiom) {
+        let chain = axiom.property_chain();
+        let super_property = axiom.super_property();
+        let description = format!(
+            "{} ⊑ {}",
+            chain.iter().map(describe).collect::<Vec<_>>().join(" ∘ "),
+            describe(super_property)
+        );
+
+        // A chain of one property is a plain subproperty axiom
+        if chain.len() == 1 {
+            self.depend(
+                chain[0].property_iri(),
+                super_property.property_iri(),
+                false,
+                &description,
+            );
+            return;
+        }
+
+        let last = chain.len() - 1;
+        let transitivity = chain.len() == 2 && chain.iter().all(|p| p == super_property);
+        for (position, property) in chain.iter().enumerate() {
+            // R ∘ S ⊑ R and S ∘ R ⊑ R put no constraint on R
+            let exempt = transitivity
+                || (property == super_property
+                    && ((position == 0 && chain[last] != *super_property)
+                        || (position == last && chain[0] != *super_property)));
+            if !exempt {
+                self.depend(
+                    property.property_iri(),
+                    super_property.property_iri(),
+                    true,
+                    &description,
+                );
+            }
+        }
+    }
+
+    fn depend(&mut self, below: &Arc<IRI>, above: &Arc<IRI>, strict: bool, axiom: &str) {
+        self.dependencies
+            .entry(below.clone())
+            .or_default()
+            .push(Dependency {
+                above: above.clone(),
+                strict,
+                axiom: axiom.to_string(),
+            });
+    }
+
+    /// The axioms leading from `from` up to `to`, or `None` if `to` is not above `from`
+    fn path(&self, from: &Arc<IRI>, to: &Arc<IRI>) -> Option<Vec<String>> {
+        let mut previous: HashMap<&Arc<IRI>, (&Arc<IRI>, &Dependency)> = HashMap::new();
+        let mut visited: HashSet<&Arc<IRI>> = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(current) = queue.pop_front() {
+            for dependency in self.dependencies.get(current).into_iter().flatten() {
+                if !visited.insert(&dependency.above) {
+                    continue;
+                }
+                previous.insert(&dependency.above, (current, dependency));
+                if dependency.above == *to {
+                    let mut steps = Vec::new();
+                    let mut node = &dependency.above;
+                    while let Some((parent, step)) = previous.get(node) {
+                        steps.push(step.axiom.clone());
+                        node = parent;
+                    }
+                    steps.reverse();
+                    return Some(steps);
+                }
+                queue.push_back(&dependency.above);
+            }
+        }
+        None
+    }
+}
+
+fn describe(property: &ObjectPropertyExpression) -> String {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(property) => property.iri().to_string(),
+        ObjectPropertyExpression::ObjectInverseOf(inner) => {
+            format!("ObjectInverseOf({})", describe(inner))
+        }
+    }
+}
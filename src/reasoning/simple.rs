@@ -49,17 +49,24 @@
 //! # Ok::<(), owl2_reasoner::OwlError>(())
 //! ```
 
+use crate::axioms::{Axiom, DataPropertyExpression, ObjectPropertyExpression};
+use crate::entities::Literal;
 use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
 use crate::profiles::{
     Owl2Profile, Owl2ProfileValidator, ProfileValidationResult, ProfileValidator,
 };
+use crate::reasoning::{consistency, justification, OwlReasoner, Reasoner};
 use hashbrown::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 
+/// A named object property, read backwards when the flag is set
+type DirectedProperty = (bool, IRI);
+
 /// Cache entry for reasoning results
 #[derive(Debug, Clone)]
 struct CacheEntry<T> {
@@ -126,6 +133,20 @@ impl CacheStats {
     }
 }
 
+/// Result of [`SimpleReasoner::try_add_assertion`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssertionOutcome {
+    /// The assertion was added and the ontology remains consistent
+    Consistent,
+    /// The assertion was not added because the ontology would become inconsistent
+    WouldBeInconsistent {
+        /// What the assertion clashes with
+        reason: String,
+        /// A minimal set of axioms of the ontology the assertion clashes with
+        conflicting: Vec<Axiom>,
+    },
+}
+
 /// A simplified OWL2 reasoner with caching and profile validation
 ///
 /// This reasoner provides basic reasoning capabilities for OWL2 ontologies,
@@ -518,10 +539,47 @@ impl SimpleReasoner {
             }
         }
 
+        // An irreflexive property must not relate any individual to itself,
+        // including through the self-loops of a reflexive property
+        let irreflexive: HashSet<&IRI> = self
+            .ontology
+            .irreflexive_property_axioms()
+            .iter()
+            .map(|axiom| &**axiom.property())
+            .collect();
+        if !irreflexive.is_empty() {
+            if self
+                .ontology
+                .reflexive_property_axioms()
+                .iter()
+                .any(|axiom| irreflexive.contains(&**axiom.property()))
+            {
+                return Ok(false);
+            }
+            if self
+                .saturate_property_assertions()
+                .iter()
+                .any(|(subject, property, object)| {
+                    subject == object && irreflexive.contains(property)
+                })
+            {
+                return Ok(false);
+            }
+        }
+
+        // A data property value must be in the ranges of the property
+        if !crate::datatypes::data_range_violations(&self.ontology).is_empty() {
+            return Ok(false);
+        }
+
         // Check for contradictory subclass relationships - optimized with hash map
         use std::collections::HashMap;
         let mut subclass_map: HashMap<&IRI, Vec<&IRI>> = HashMap::new();
         for axiom in self.ontology.subclass_axioms() {
+            // C ⊑ C is a tautology, not a cycle
+            if axiom.is_reflexive() {
+                continue;
+            }
             if let (
                 crate::axioms::ClassExpression::Class(sub_class),
                 crate::axioms::ClassExpression::Class(super_class),
@@ -873,6 +931,321 @@ impl SimpleReasoner {
         Ok(false)
     }
 
+    /// Get the values of an object property for an individual
+    ///
+    /// Values entailed through sub-properties, equivalent and inverse properties,
+    /// symmetric and transitive properties, and property chains are included.
+    pub fn property_values(
+        &self,
+        individual: &IRI,
+        property: &ObjectPropertyExpression,
+    ) -> OwlResult<HashSet<IRI>> {
+        let edges = self.saturate_property_assertions();
+        let (inverse, property_iri) = Self::property_direction(property);
+        Ok(Self::follow(&edges, individual, property_iri, inverse)
+            .into_iter()
+            .collect())
+    }
+
+    /// Get the values of a data property for an individual
+    ///
+    /// Values asserted for sub-properties and equivalent properties are included.
+    pub fn data_property_values(
+        &self,
+        individual: &IRI,
+        property: &DataPropertyExpression,
+    ) -> OwlResult<HashSet<Literal>> {
+        let target = property.property_iri();
+        let mut super_properties: HashMap<IRI, Vec<IRI>> = HashMap::new();
+        for axiom in self.ontology.subdata_property_axioms() {
+            super_properties
+                .entry((**axiom.sub_property()).clone())
+                .or_default()
+                .push((**axiom.super_property()).clone());
+        }
+        for axiom in self.ontology.equivalent_data_properties_axioms() {
+            for a in axiom.properties() {
+                for b in axiom.properties() {
+                    if a != b {
+                        super_properties
+                            .entry((**a).clone())
+                            .or_default()
+                            .push((**b).clone());
+                    }
+                }
+            }
+        }
+
+        let mut values = HashSet::new();
+        for axiom in self.ontology.data_property_assertions() {
+            if **axiom.subject() != *individual {
+                continue;
+            }
+            let mut seen = HashSet::new();
+            let mut pending = vec![(**axiom.property()).clone()];
+            while let Some(current) = pending.pop() {
+                if current == **target {
+                    values.insert(axiom.value().clone());
+                    break;
+                }
+                if seen.insert(current.clone()) {
+                    if let Some(supers) = super_properties.get(&current) {
+                        pending.extend(supers.iter().cloned());
+                    }
+                }
+            }
+        }
+        Ok(values)
+    }
+
+    /// Add an ABox assertion unless it would make the ontology inconsistent
+    ///
+    /// Only the axioms connected to the entities of the assertion are reasoned
+    /// over, which decides the question for an ontology that was consistent
+    /// before the call. A rejected assertion leaves the ontology unchanged;
+    /// an accepted one is added and the caches are cleared. Axioms that are
+    /// not assertions are refused with a validation error.
+    pub fn try_add_assertion(&mut self, axiom: Axiom) -> OwlResult<AssertionOutcome> {
+        if !axiom.is_assertional() {
+            return Err(OwlError::ValidationError(
+                "Only ABox assertions can be added incrementally".to_string(),
+            ));
+        }
+
+        let signature = axiom.signature();
+        let signature: Vec<&IRI> = signature.iter().map(|iri| &**iri).collect();
+        let mut module = justification::module(&self.ontology, &signature)?;
+        module.add_axiom(axiom.clone())?;
+
+        // Clashes among told facts come with a precise description
+        let violation = consistency::structural_violations(&module)
+            .into_iter()
+            .next();
+        if violation.is_none() && OwlReasoner::new(module.clone()).is_consistent()? {
+            self.ontology.add_axiom(axiom)?;
+            self.clear_caches()?;
+            return Ok(AssertionOutcome::Consistent);
+        }
+
+        let conflicting: Vec<Axiom> = justification::justify(&module, &signature, |candidate| {
+            Ok(!OwlReasoner::new(candidate).is_consistent()?)
+        })?
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|conflict| *conflict != axiom)
+        .collect();
+        let reason = violation.unwrap_or_else(|| {
+            let rendered: Vec<String> = conflicting.iter().map(Axiom::to_string).collect();
+            format!("The assertion contradicts {}", rendered.join(", "))
+        });
+        Ok(AssertionOutcome::WouldBeInconsistent {
+            reason,
+            conflicting,
+        })
+    }
+
+    /// Saturate the object property assertions under the property axioms
+    ///
+    /// Anonymous objects are named by their blank node, `_:id`.
+    pub(crate) fn saturate_property_assertions(&self) -> HashSet<(IRI, IRI, IRI)> {
+        let mut edges: HashSet<(IRI, IRI, IRI)> = self
+            .ontology
+            .property_assertions()
+            .iter()
+            .filter_map(|axiom| {
+                let object = axiom.object_node_iri().ok()?;
+                Some((
+                    (**axiom.subject()).clone(),
+                    (**axiom.property()).clone(),
+                    (*object).clone(),
+                ))
+            })
+            .collect();
+
+        // A reflexive property relates every individual to itself
+        let reflexive: Vec<&IRI> = self
+            .ontology
+            .reflexive_property_axioms()
+            .iter()
+            .map(|axiom| &**axiom.property())
+            .collect();
+        if !reflexive.is_empty() {
+            for individual in self.known_individuals() {
+                for property in &reflexive {
+                    edges.insert((individual.clone(), (*property).clone(), individual.clone()));
+                }
+            }
+        }
+
+        // Each rule maps a property (in a direction) onto another one
+        let mut implications: Vec<(DirectedProperty, DirectedProperty)> = Vec::new();
+        for axiom in self.ontology.subobject_property_axioms() {
+            implications.push((
+                (false, (**axiom.sub_property()).clone()),
+                (false, (**axiom.super_property()).clone()),
+            ));
+        }
+        for axiom in self.ontology.equivalent_object_properties_axioms() {
+            for a in axiom.properties() {
+                for b in axiom.properties() {
+                    if a != b {
+                        implications.push(((false, (**a).clone()), (false, (**b).clone())));
+                    }
+                }
+            }
+        }
+        for axiom in self.ontology.inverse_object_properties_axioms() {
+            let (inverse1, iri1) = Self::property_direction(axiom.property1());
+            let (inverse2, iri2) = Self::property_direction(axiom.property2());
+            implications.push(((inverse1, iri1.clone()), (!inverse2, iri2.clone())));
+            implications.push(((inverse2, iri2.clone()), (!inverse1, iri1.clone())));
+        }
+        for axiom in self.ontology.symmetric_property_axioms() {
+            let iri = (**axiom.property()).clone();
+            implications.push(((false, iri.clone()), (true, iri)));
+        }
+        let transitive: Vec<IRI> = self
+            .ontology
+            .transitive_property_axioms()
+            .iter()
+            .map(|axiom| (**axiom.property()).clone())
+            .collect();
+        let chains: Vec<(Vec<DirectedProperty>, DirectedProperty)> = self
+            .ontology
+            .sub_property_chain_axioms()
+            .iter()
+            .map(|axiom| {
+                let chain = axiom
+                    .property_chain()
+                    .iter()
+                    .map(|property| {
+                        let (inverse, iri) = Self::property_direction(property);
+                        (inverse, iri.clone())
+                    })
+                    .collect();
+                let (inverse, iri) = Self::property_direction(axiom.super_property());
+                (chain, (inverse, iri.clone()))
+            })
+            .chain(transitive.into_iter().map(|iri| {
+                (
+                    vec![(false, iri.clone()), (false, iri.clone())],
+                    (false, iri),
+                )
+            }))
+            .collect();
+
+        loop {
+            let mut derived = Vec::new();
+            for (subject, property, object) in &edges {
+                for ((from_inverse, from), (to_inverse, to)) in &implications {
+                    if from != property {
+                        continue;
+                    }
+                    // Orient the edge as seen through the source expression
+                    let (s, o) = if *from_inverse {
+                        (object, subject)
+                    } else {
+                        (subject, object)
+                    };
+                    let edge = if *to_inverse {
+                        (o.clone(), to.clone(), s.clone())
+                    } else {
+                        (s.clone(), to.clone(), o.clone())
+                    };
+                    derived.push(edge);
+                }
+            }
+            for (chain, (to_inverse, to)) in &chains {
+                let Some((first_inverse, first)) = chain.first() else {
+                    continue;
+                };
+                let starts: HashSet<IRI> = edges
+                    .iter()
+                    .filter(|(_, property, _)| property == first)
+                    .map(|(subject, _, object)| {
+                        if *first_inverse {
+                            object.clone()
+                        } else {
+                            subject.clone()
+                        }
+                    })
+                    .collect();
+                for start in starts {
+                    let mut frontier = vec![start.clone()];
+                    for (inverse, property) in chain {
+                        let mut next = HashSet::new();
+                        for node in &frontier {
+                            next.extend(Self::follow(&edges, node, property, *inverse));
+                        }
+                        frontier = next.into_iter().collect();
+                    }
+                    for end in frontier {
+                        derived.push(if *to_inverse {
+                            (end, to.clone(), start.clone())
+                        } else {
+                            (start.clone(), to.clone(), end)
+                        });
+                    }
+                }
+            }
+
+            let before = edges.len();
+            edges.extend(derived);
+            if edges.len() == before {
+                return edges;
+            }
+        }
+    }
+
+    /// Every named individual declared or mentioned in an assertion
+    fn known_individuals(&self) -> HashSet<IRI> {
+        let mut individuals: HashSet<IRI> = self
+            .ontology
+            .named_individuals()
+            .iter()
+            .map(|individual| (**individual.iri()).clone())
+            .collect();
+        for axiom in self.ontology.class_assertions() {
+            individuals.insert((**axiom.individual()).clone());
+        }
+        for axiom in self.ontology.property_assertions() {
+            individuals.insert((**axiom.subject()).clone());
+            if let Ok(object) = axiom.object_node_iri() {
+                individuals.insert((*object).clone());
+            }
+        }
+        individuals
+    }
+
+    /// Split a property expression into its direction and named property
+    fn property_direction(property: &ObjectPropertyExpression) -> (bool, &IRI) {
+        match property {
+            ObjectPropertyExpression::ObjectProperty(named) => (false, named.iri()),
+            ObjectPropertyExpression::ObjectInverseOf(inner) => {
+                let (inverse, iri) = Self::property_direction(inner);
+                (!inverse, iri)
+            }
+        }
+    }
+
+    /// Individuals reached from `node` along a property in the given direction
+    fn follow(
+        edges: &HashSet<(IRI, IRI, IRI)>,
+        node: &IRI,
+        property: &IRI,
+        inverse: bool,
+    ) -> Vec<IRI> {
+        edges
+            .iter()
+            .filter(|(_, p, _)| p == property)
+            .filter_map(|(subject, _, object)| match inverse {
+                false if subject == node => Some(object.clone()),
+                true if object == node => Some(subject.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
     /// Compute instances (internal method)
     fn compute_instances(&self, class_iri: &IRI) -> OwlResult<Vec<IRI>> {
         let mut instances = Vec::new();
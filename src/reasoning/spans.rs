@@ -0,0 +1,114 @@
+//! Tracing spans around the major reasoning phases
+//!
+//! With the `tracing` feature enabled, classification, consistency checking and
+//! realization each run inside an `INFO` span named after the phase. A span carries
+//! the size of its input when it opens and records its result before it closes, so
+//! latency can be correlated with the shape of the ontology. Without the feature
+//! every method here is a no-op.
+
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+
+/// An entered span for one reasoning phase, exited when dropped
+pub(crate) struct PhaseSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::span::EnteredSpan,
+}
+
+impl PhaseSpan {
+    /// Span named `classification` with `classes` and `axioms` fields
+    ///
+    /// Records `relationships`, `equivalences` and `disjointness` on completion.
+    pub(crate) fn classification(ontology: &Ontology) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "classification",
+                classes = ontology.classes().len(),
+                axioms = ontology.axiom_count(),
+                relationships = tracing::field::Empty,
+                equivalences = tracing::field::Empty,
+                disjointness = tracing::field::Empty,
+            );
+            PhaseSpan {
+                span: span.entered(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = ontology;
+            PhaseSpan {}
+        }
+    }
+
+    /// Span named `consistency` with `axioms` and `individuals` fields
+    ///
+    /// Records `consistent` and the `method` used on completion.
+    pub(crate) fn consistency(ontology: &Ontology) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "consistency",
+                axioms = ontology.axiom_count(),
+                individuals = ontology.named_individuals().len(),
+                consistent = tracing::field::Empty,
+                method = tracing::field::Empty,
+            );
+            PhaseSpan {
+                span: span.entered(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = ontology;
+            PhaseSpan {}
+        }
+    }
+
+    /// Span named `realization` with `individual` and `classes` fields
+    ///
+    /// Records the number of direct `types` on completion.
+    pub(crate) fn realization(ontology: &Ontology, individual: &IRI) -> Self {
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::info_span!(
+                "realization",
+                individual = individual.as_str(),
+                classes = ontology.classes().len(),
+                types = tracing::field::Empty,
+            );
+            PhaseSpan {
+                span: span.entered(),
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let _ = (ontology, individual);
+            PhaseSpan {}
+        }
+    }
+
+    /// Record a count declared by this phase's span
+    pub(crate) fn record_count(&self, field: &'static str, value: usize) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        let _ = (field, value);
+    }
+
+    /// Record a flag declared by this phase's span
+    pub(crate) fn record_flag(&self, field: &'static str, value: bool) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        let _ = (field, value);
+    }
+
+    /// Record a label declared by this phase's span
+    pub(crate) fn record_str(&self, field: &'static str, value: &str) {
+        #[cfg(feature = "tracing")]
+        self.span.record(field, value);
+        #[cfg(not(feature = "tracing"))]
+        let _ = (field, value);
+    }
+}
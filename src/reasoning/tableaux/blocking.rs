@@ -23,6 +23,12 @@
 //! A more aggressive strategy where a node is blocked if an ancestor contains a superset
 //! of its concepts. This can detect more blocks but may be overly conservative.
 //!
+//! ### Pairwise Blocking
+//! With inverse roles a node's successors can pass concepts back to it, so a node is
+//! only blocked by an ancestor when both nodes, their parents and the edges from
+//! those parents all match. This double check keeps blocking sound for SHIQ-style
+//! ontologies that combine inverses, number restrictions and cyclic GCIs.
+//!
 //! ### Optimized Blocking
 //! An advanced strategy that combines equality and subset blocking with additional
 //! heuristics to balance completeness and performance:
@@ -87,6 +93,8 @@ pub enum BlockingStrategy {
     Dynamic,
     /// Comprehensive blocking combining all strategies
     Comprehensive,
+    /// Pairwise blocking for ontologies with inverse roles
+    Pairwise,
 }
 
 /// Blocking constraint for tableaux reasoning
@@ -109,6 +117,8 @@ pub enum BlockingType {
     Dynamic,
     /// Nominal blocking: blocking based on individual equality
     Nominal,
+    /// Pairwise blocking: nodes, parents and the edges between them all match
+    Pairwise,
 }
 
 impl BlockingConstraint {
@@ -152,10 +162,14 @@ impl BlockingConstraint {
     pub fn is_nominal(&self) -> bool {
         matches!(self.constraint_type, BlockingType::Nominal)
     }
+
+    pub fn is_pairwise(&self) -> bool {
+        matches!(self.constraint_type, BlockingType::Pairwise)
+    }
 }
 
 /// Blocking statistics for optimization
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BlockingStats {
     pub total_blocks: usize,
     pub subset_blocks: usize,
@@ -163,9 +177,26 @@ pub struct BlockingStats {
     pub cardinality_blocks: usize,
     pub dynamic_blocks: usize,
     pub nominal_blocks: usize,
+    pub pairwise_blocks: usize,
     pub blocked_nodes: HashSet<NodeId>,
 }
 
+impl BlockingStats {
+    /// Count a block under its type
+    pub fn record(&mut self, constraint: &BlockingConstraint) {
+        match constraint.constraint_type {
+            BlockingType::Subset => self.subset_blocks += 1,
+            BlockingType::Equality => self.equality_blocks += 1,
+            BlockingType::Cardinality => self.cardinality_blocks += 1,
+            BlockingType::Dynamic => self.dynamic_blocks += 1,
+            BlockingType::Nominal => self.nominal_blocks += 1,
+            BlockingType::Pairwise => self.pairwise_blocks += 1,
+        }
+        self.total_blocks += 1;
+        self.blocked_nodes.insert(constraint.blocked_node);
+    }
+}
+
 /// Blocking manager for tableaux reasoning
 #[derive(Debug, Default)]
 pub struct BlockingManager {
@@ -193,16 +224,7 @@ impl BlockingManager {
         self.blocked_nodes.insert(constraint.blocked_node);
         self.blocking_constraints.push(constraint.clone());
         self.constraint_log.push(constraint.clone());
-
-        // Update statistics
-        match constraint.constraint_type {
-            BlockingType::Subset => self.stats.subset_blocks += 1,
-            BlockingType::Equality => self.stats.equality_blocks += 1,
-            BlockingType::Cardinality => self.stats.cardinality_blocks += 1,
-            BlockingType::Dynamic => self.stats.dynamic_blocks += 1,
-            BlockingType::Nominal => self.stats.nominal_blocks += 1,
-        }
-        self.stats.total_blocks += 1;
+        self.stats.record(&constraint);
     }
 
     /// Add blocking constraint with reason for debugging
@@ -232,12 +254,21 @@ impl BlockingManager {
         node_id: NodeId,
         graph: &super::graph::TableauxGraph,
     ) -> Option<BlockingConstraint> {
+        // Nodes standing for named individuals are never blocked
+        if graph
+            .get_node(node_id)
+            .is_some_and(|node| node.labels_iter().next().is_some())
+        {
+            return None;
+        }
+
         match self.strategy {
             BlockingStrategy::Equality => self.detect_equality_blocking(node_id, graph),
             BlockingStrategy::Subset => self.detect_subset_blocking(node_id, graph),
             BlockingStrategy::Optimized => self.detect_optimized_blocking(node_id, graph),
             BlockingStrategy::Dynamic => self.detect_dynamic_blocking(node_id, graph),
             BlockingStrategy::Comprehensive => self.detect_comprehensive_blocking(node_id, graph),
+            BlockingStrategy::Pairwise => self.detect_pairwise_blocking(node_id, graph),
         }
     }
 
@@ -281,6 +312,40 @@ impl BlockingManager {
         None
     }
 
+    /// Block a node by a tree ancestor when the two nodes have equal concepts,
+    /// their parents have equal concepts and the parent edges carry the same roles
+    fn detect_pairwise_blocking(
+        &self,
+        node_id: NodeId,
+        graph: &super::graph::TableauxGraph,
+    ) -> Option<BlockingConstraint> {
+        let node = graph.get_node(node_id)?;
+        let parent_id = self.tree_parent(node_id, graph)?;
+        let parent = graph.get_node(parent_id)?;
+        let parent_edge = self.parent_edge_roles(parent_id, node_id, graph);
+
+        let mut ancestor_id = parent_id;
+        while let Some(ancestor_parent_id) = self.tree_parent(ancestor_id, graph) {
+            if let (Some(ancestor), Some(ancestor_parent)) = (
+                graph.get_node(ancestor_id),
+                graph.get_node(ancestor_parent_id),
+            ) {
+                if self.nodes_have_equal_concepts(node, ancestor)
+                    && self.nodes_have_equal_concepts(parent, ancestor_parent)
+                    && parent_edge == self.parent_edge_roles(ancestor_parent_id, ancestor_id, graph)
+                {
+                    return Some(BlockingConstraint::new(
+                        node_id,
+                        ancestor_id,
+                        BlockingType::Pairwise,
+                    ));
+                }
+            }
+            ancestor_id = ancestor_parent_id;
+        }
+        None
+    }
+
     fn detect_optimized_blocking(
         &self,
         node_id: NodeId,
@@ -392,11 +457,46 @@ impl BlockingManager {
         None
     }
 
+    /// The node whose expansion created this one
+    ///
+    /// Successors are created after the node that generates them, and an edge
+    /// for an inverse role points from the successor back to its generator, so
+    /// the parent is the earliest neighbour created before the node in either
+    /// direction.
+    fn tree_parent(&self, node_id: NodeId, graph: &super::graph::TableauxGraph) -> Option<NodeId> {
+        graph
+            .edges
+            .get_adjacent(node_id)
+            .iter()
+            .map(|(neighbour, _, _)| *neighbour)
+            .filter(|neighbour| neighbour.as_usize() < node_id.as_usize())
+            .min_by_key(|neighbour| neighbour.as_usize())
+    }
+
+    /// The roles relating a parent to its child, marking those stored as
+    /// child-to-parent edges as inverse
+    fn parent_edge_roles<'a>(
+        &self,
+        parent_id: NodeId,
+        child_id: NodeId,
+        graph: &'a super::graph::TableauxGraph,
+    ) -> HashSet<(bool, &'a crate::iri::IRI)> {
+        graph
+            .edges
+            .get_adjacent(child_id)
+            .iter()
+            .filter(|(neighbour, _, _)| *neighbour == parent_id)
+            .map(|(_, property, outgoing)| (*outgoing, property))
+            .collect()
+    }
+
     /// Get all ancestors of a node
     fn get_ancestors(&self, node_id: NodeId, graph: &super::graph::TableauxGraph) -> Vec<NodeId> {
         let mut ancestors = Vec::new();
         let mut visited = HashSet::new();
         self.collect_ancestors(node_id, graph, &mut ancestors, &mut visited);
+        // Self-loops and cycles lead back to the node, which cannot block itself
+        ancestors.retain(|&ancestor| ancestor != node_id);
         ancestors
     }
 
@@ -46,6 +46,9 @@
 //!     enable_parallel: false,
 //!     parallel_workers: None,
 //!     parallel_chunk_size: 64,
+//!     max_branches: Some(10_000),
+//!     deterministic: false,
+//!     record_trace: false,
 //! };
 //! let reasoner = TableauxReasoner::with_config(ontology, config);
 //!
@@ -63,9 +66,10 @@ use crate::error::{OwlError, OwlResult};
 use crate::iri::IRI;
 use crate::ontology::Ontology;
 
+use super::blocking::BlockingStrategy;
 use hashbrown::HashMap;
 use smallvec::SmallVec;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::{HashSet, VecDeque};
 use std::sync::Arc;
 
@@ -89,6 +93,7 @@ pub struct ReasoningRules {
     pub property_domains: Vec<ObjectPropertyDomainAxiom>,
     pub property_ranges: Vec<ObjectPropertyRangeAxiom>,
     pub inverse_properties: Vec<InverseObjectPropertiesAxiom>,
+    pub data_property_domains: Vec<DataPropertyDomainAxiom>,
     // Individual reasoning (ABox)
     pub property_assertions: Vec<PropertyAssertionAxiom>,
     pub data_property_assertions: Vec<DataPropertyAssertionAxiom>,
@@ -101,10 +106,19 @@ pub struct ReasoningRules {
 
 impl ReasoningRules {
     pub fn new(ontology: &Ontology) -> Self {
+        // A global qualified cardinality axiom with an owl:Thing filler is the
+        // unqualified restriction holding on every node
+        let global_cardinalities = ontology
+            .axioms()
+            .iter()
+            .filter_map(|axiom| axiom.as_object_cardinality()?.to_unqualified())
+            .map(|restriction| SubClassOfAxiom::new(ClassExpression::thing().clone(), restriction));
         let subclass_rules = ontology
             .subclass_axioms()
             .iter()
+            .filter(|ax| !ax.is_reflexive())
             .map(|ax| (**ax).clone())
+            .chain(global_cardinalities)
             .collect();
         let equivalence_rules = ontology
             .equivalent_classes_axioms()
@@ -165,11 +179,28 @@ impl ReasoningRules {
             .map(|ax| ax.property().clone())
             .collect();
 
-        // Extract property hierarchy axioms
+        // Extract property hierarchy axioms; equivalent properties are
+        // sub-properties of each other
         let property_hierarchy = ontology
             .subobject_property_axioms()
             .iter()
             .map(|ax| (**ax).clone())
+            .chain(
+                ontology
+                    .equivalent_object_properties_axioms()
+                    .iter()
+                    .flat_map(|ax| {
+                        let properties = ax.properties();
+                        properties.iter().flat_map(move |sub| {
+                            properties
+                                .iter()
+                                .filter(move |sup| *sup != sub)
+                                .map(move |sup| {
+                                    SubObjectPropertyAxiom::new(sub.clone(), sup.clone())
+                                })
+                        })
+                    }),
+            )
             .collect();
 
         let property_domains = ontology
@@ -190,6 +221,12 @@ impl ReasoningRules {
             .map(|ax| (**ax).clone())
             .collect();
 
+        let data_property_domains = ontology
+            .data_property_domain_axioms()
+            .into_iter()
+            .cloned()
+            .collect();
+
         // Extract property assertions (ABox)
         let property_assertions = ontology
             .property_assertions()
@@ -244,6 +281,7 @@ impl ReasoningRules {
             property_domains,
             property_ranges,
             inverse_properties,
+            data_property_domains,
             property_assertions,
             data_property_assertions,
             negative_property_assertions,
@@ -253,6 +291,124 @@ impl ReasoningRules {
         }
     }
 
+    /// Class expressions that every subject of `property` belongs to
+    ///
+    /// Besides the domains of `property`, this includes the ranges of its inverse.
+    pub fn domains_of(&self, property: &IRI) -> Vec<&ClassExpression> {
+        self.constraints_on(property, false)
+    }
+
+    /// Class expressions that every object of `property` belongs to
+    ///
+    /// Besides the ranges of `property`, this includes the domains of its inverse.
+    pub fn ranges_of(&self, property: &IRI) -> Vec<&ClassExpression> {
+        self.constraints_on(property, true)
+    }
+
+    fn constraints_on(&self, property: &IRI, objects: bool) -> Vec<&ClassExpression> {
+        let domains = self
+            .property_domains
+            .iter()
+            .map(|axiom| (axiom.property_expression(), axiom.domain(), false));
+        let ranges = self
+            .property_ranges
+            .iter()
+            .map(|axiom| (axiom.property_expression(), axiom.range(), true));
+        domains
+            .chain(ranges)
+            .filter_map(|(expression, class, is_range)| {
+                let (inverse, iri) = TableauxReasoner::resolve_property_direction(expression);
+                (iri == property && (is_range != inverse) == objects).then_some(class)
+            })
+            .collect()
+    }
+
+    /// Whether any class expression or axiom of the TBox uses an inverse role
+    pub fn uses_inverse_roles(&self) -> bool {
+        fn is_inverse(property: &ObjectPropertyExpression) -> bool {
+            matches!(property, ObjectPropertyExpression::ObjectInverseOf(_))
+        }
+
+        !self.inverse_properties.is_empty()
+            || self.subclass_rules.iter().any(|axiom| {
+                [axiom.sub_class(), axiom.super_class()]
+                    .into_iter()
+                    .flat_map(ClassExpression::collect_subexpressions)
+                    .any(|expression| match expression {
+                        ClassExpression::ObjectSomeValuesFrom(property, _)
+                        | ClassExpression::ObjectAllValuesFrom(property, _)
+                        | ClassExpression::ObjectHasValue(property, _) => is_inverse(property),
+                        ClassExpression::ObjectHasSelf(property) => is_inverse(property),
+                        _ => expression
+                            .as_object_cardinality()
+                            .is_some_and(|restriction| is_inverse(restriction.property)),
+                    })
+            })
+    }
+
+    /// Group the individuals of the sameAs axioms into equivalence classes
+    ///
+    /// Each individual named in a sameAs axiom is mapped to every member of its
+    /// class, itself included, in sorted order. Axioms sharing an individual
+    /// join their classes, as sameAs is transitive.
+    pub fn same_individual_classes(&self) -> HashMap<IRI, Vec<IRI>> {
+        let mut classes: Vec<std::collections::BTreeSet<IRI>> = Vec::new();
+        for axiom in &self.same_individual_axioms {
+            let mut merged: std::collections::BTreeSet<IRI> = axiom
+                .individuals()
+                .iter()
+                .map(|individual| (**individual).clone())
+                .collect();
+            classes.retain(|class| {
+                if class.iter().any(|individual| merged.contains(individual)) {
+                    merged.extend(class.iter().cloned());
+                    false
+                } else {
+                    true
+                }
+            });
+            classes.push(merged);
+        }
+
+        let mut same = HashMap::new();
+        for class in classes {
+            let members: Vec<IRI> = class.into_iter().collect();
+            for member in &members {
+                same.insert(member.clone(), members.clone());
+            }
+        }
+        same
+    }
+
+    /// Map each object property to its strict sub-properties, direct or not
+    ///
+    /// The sub-properties are sorted; cycles in the hierarchy make their
+    /// properties sub-properties of each other.
+    pub fn sub_property_closure(&self) -> HashMap<IRI, Vec<IRI>> {
+        let mut direct: HashMap<&IRI, Vec<&IRI>> = HashMap::new();
+        for axiom in &self.property_hierarchy {
+            direct
+                .entry(&**axiom.super_property())
+                .or_default()
+                .push(&**axiom.sub_property());
+        }
+
+        let mut closure = HashMap::new();
+        for &property in direct.keys() {
+            let mut found = std::collections::BTreeSet::new();
+            let mut pending = vec![property];
+            while let Some(current) = pending.pop() {
+                for &sub_property in direct.get(current).into_iter().flatten() {
+                    if sub_property != property && found.insert(sub_property.clone()) {
+                        pending.push(sub_property);
+                    }
+                }
+            }
+            closure.insert(property.clone(), found.into_iter().collect());
+        }
+        closure
+    }
+
     pub fn clear(&mut self) {
         self.subclass_rules.clear();
         self.equivalence_rules.clear();
@@ -269,6 +425,7 @@ impl ReasoningRules {
         self.property_domains.clear();
         self.property_ranges.clear();
         self.inverse_properties.clear();
+        self.data_property_domains.clear();
         self.property_assertions.clear();
         self.data_property_assertions.clear();
         self.negative_property_assertions.clear();
@@ -309,6 +466,14 @@ pub struct ReasoningConfig {
     pub parallel_workers: Option<usize>,
     /// Chunk size for parallel operations
     pub parallel_chunk_size: usize,
+    /// Maximum number of disjunction branches a single reasoning call may try
+    /// (None = unlimited)
+    pub max_branches: Option<usize>,
+    /// Keep the concepts of each node in a stable order, so the same input always
+    /// expands into the same tableau with the same node ids
+    pub deterministic: bool,
+    /// Record the steps of each reasoning call, see [`TableauxReasoner::last_trace`]
+    pub record_trace: bool,
 }
 
 impl Default for ReasoningConfig {
@@ -321,6 +486,9 @@ impl Default for ReasoningConfig {
             enable_parallel: false, // Disabled by default for compatibility
             parallel_workers: None, // Use all available cores
             parallel_chunk_size: 64,
+            max_branches: None,
+            deterministic: false,
+            record_trace: false,
         }
     }
 }
@@ -337,6 +505,8 @@ pub struct TableauxNode {
     pub labels: SmallVec<[String; 4]>,
     /// Optional blocking reference for optimization
     pub blocked_by: Option<NodeId>,
+    /// Keep concepts in insertion order instead of switching to the hashset
+    pub ordered: bool,
 }
 
 impl TableauxNode {
@@ -347,6 +517,25 @@ impl TableauxNode {
             concepts_hashset: None,
             labels: SmallVec::new(),
             blocked_by: None,
+            ordered: false,
+        }
+    }
+
+    /// Keep the concepts of this node in a stable order from now on
+    ///
+    /// Concepts already held in the hashset are sorted by a hash that does not
+    /// change between runs; later concepts are kept in insertion order.
+    pub fn keep_concept_order(&mut self) {
+        self.ordered = true;
+        if let Some(hashset) = self.concepts_hashset.take() {
+            let mut concepts: Vec<_> = hashset.into_iter().collect();
+            concepts.sort_by_cached_key(|concept| {
+                use std::hash::{Hash, Hasher};
+                let mut hasher = std::hash::DefaultHasher::new();
+                concept.hash(&mut hasher);
+                hasher.finish()
+            });
+            self.concepts.extend(concepts);
         }
     }
 
@@ -358,7 +547,7 @@ impl TableauxNode {
             }
         } else {
             // Use SmallVec for small collections
-            if self.concepts.len() < 8 {
+            if self.concepts.len() < 8 || self.ordered {
                 if !self.concepts.contains(&concept) {
                     self.concepts.push(concept);
                 }
@@ -534,8 +723,12 @@ impl MemoryStats {
 #[derive(Debug, Default)]
 pub struct ReasoningCache {
     pub consistency_cache: HashMap<Vec<ClassExpression>, bool>,
-    pub satisfiability_cache: HashMap<ClassExpression, bool>,
+    /// Satisfiability of class expressions, keyed by [`ClassExpression::semantic_key`]
+    pub satisfiability_cache: RefCell<HashMap<ClassExpression, bool>>,
     pub classification_cache: HashMap<(IRI, IRI), bool>,
+    /// Known named subsumptions (class -> superclasses), built lazily from the
+    /// asserted axioms and extended with subsumptions proven by the tableau
+    pub class_hierarchy: RefCell<Option<HashMap<IRI, HashSet<IRI>>>>,
 }
 
 impl ReasoningCache {
@@ -545,8 +738,9 @@ impl ReasoningCache {
 
     pub fn clear(&mut self) {
         self.consistency_cache.clear();
-        self.satisfiability_cache.clear();
+        self.satisfiability_cache.get_mut().clear();
         self.classification_cache.clear();
+        self.class_hierarchy.get_mut().take();
     }
 }
 
@@ -559,6 +753,25 @@ pub struct TableauxReasoner {
     pub memory_stats: RefCell<MemoryStats>,
     /// Dependency-directed backtracking manager
     pub dependency_manager: super::dependency::DependencyManager,
+    /// Statistics of the most recent reasoning call
+    last_stats: RefCell<super::ReasoningStats>,
+    /// Disjunction branches tried by the current reasoning call
+    branches: Cell<usize>,
+    /// Blocks found by the current reasoning call
+    blocking_stats: RefCell<super::BlockingStats>,
+    /// Branches the current reasoning call abandoned after a clash
+    backtrack_stats: RefCell<super::BacktrackStats>,
+    /// When the current reasoning call started, for [`ReasoningConfig::timeout`]
+    started: Cell<Option<std::time::Instant>>,
+    /// Steps of the most recent reasoning call, when recording is enabled
+    trace: RefCell<Vec<super::TraceEvent>>,
+    /// Expansion engine with the TBox internalized, built on first use and
+    /// cloned for every tableau
+    internalized_tbox: RefCell<Option<super::expansion::ExpansionEngine>>,
+    /// The sameAs classes of the ontology's individuals, shared by every tableau
+    same_individuals: Arc<HashMap<IRI, Vec<IRI>>>,
+    /// The sub-properties of each object property, shared by every tableau
+    sub_properties: Arc<HashMap<IRI, Vec<IRI>>>,
 }
 
 impl TableauxReasoner {
@@ -568,6 +781,8 @@ impl TableauxReasoner {
 
     pub fn with_config(ontology: Ontology, config: ReasoningConfig) -> Self {
         let rules = ReasoningRules::new(&ontology);
+        let same_individuals = Arc::new(rules.same_individual_classes());
+        let sub_properties = Arc::new(rules.sub_property_closure());
 
         Self {
             ontology: Arc::new(ontology),
@@ -576,6 +791,15 @@ impl TableauxReasoner {
             cache: ReasoningCache::new(),
             memory_stats: RefCell::new(MemoryStats::new()),
             dependency_manager: super::dependency::DependencyManager::new(),
+            last_stats: RefCell::new(super::ReasoningStats::default()),
+            branches: Cell::new(0),
+            blocking_stats: RefCell::new(super::BlockingStats::default()),
+            backtrack_stats: RefCell::new(super::BacktrackStats::default()),
+            started: Cell::new(None),
+            trace: RefCell::new(Vec::new()),
+            internalized_tbox: RefCell::new(None),
+            same_individuals,
+            sub_properties,
         }
     }
 
@@ -584,11 +808,17 @@ impl TableauxReasoner {
     }
 
     pub fn check_consistency(&mut self) -> OwlResult<bool> {
-        let mut graph = super::graph::TableauxGraph::new();
-        let mut expansion_engine =
-            super::expansion::ExpansionEngine::new().with_reasoning_rules(self.rules.clone());
-        let mut blocking_manager =
-            super::blocking::BlockingManager::new(super::blocking::BlockingStrategy::Optimized);
+        if !crate::datatypes::data_range_violations(&self.ontology).is_empty() {
+            *self.last_stats.borrow_mut() = super::ReasoningStats::default();
+            return Ok(false);
+        }
+
+        self.start_call();
+        let mut graph = self.new_graph();
+        let mut expansion_engine = self.expansion_engine();
+        let mut blocking_manager = super::blocking::BlockingManager::new(
+            self.blocking_strategy(BlockingStrategy::Optimized),
+        );
         let mut memory_manager = super::memory::MemoryManager::new();
 
         self.initialize_root_node(&mut graph)?;
@@ -602,6 +832,7 @@ impl TableauxReasoner {
         let mut branch_logs: Vec<super::graph::GraphChangeLog> = Vec::new();
         while let Some(current_node) = nodes_to_expand.pop_front() {
             if let Some(constraint) = blocking_manager.detect_blocking(current_node, &graph) {
+                self.blocking_stats.borrow_mut().record(&constraint);
                 blocking_manager.add_blocking_constraint(constraint);
                 continue;
             }
@@ -622,6 +853,7 @@ impl TableauxReasoner {
             }
 
             if self.has_clash(current_node, &graph)? {
+                *self.last_stats.borrow_mut() = self.run_stats(&graph);
                 return Ok(false);
             }
 
@@ -632,21 +864,33 @@ impl TableauxReasoner {
                 }
             }
 
-            if let Some(timeout_ms) = self.config.timeout {
-                let start_time = std::time::Instant::now();
-                if start_time.elapsed().as_millis() >= timeout_ms as u128 {
-                    return Err(OwlError::TimeoutError {
-                        operation: "consistency_checking".to_string(),
-                        timeout_ms,
-                    });
-                }
-            }
+            self.check_timeout("consistency_checking")?;
         }
 
         drop(branch_logs);
+        *self.last_stats.borrow_mut() = self.run_stats(&graph);
         Ok(true)
     }
 
+    /// Check consistency, reporting the cost of the run with the answer
+    ///
+    /// The blocking and backtracking statistics show why an ontology was
+    /// expensive to reason over.
+    pub fn check_consistency_with_stats(&mut self) -> OwlResult<super::ReasoningResult> {
+        let started = std::time::Instant::now();
+        let is_consistent = self.check_consistency()?;
+        let stats = self.last_reasoning_stats();
+        Ok(super::ReasoningResult {
+            is_consistent,
+            has_clash: !is_consistent,
+            reasoning_time_ms: started.elapsed().as_millis() as u64,
+            nodes_expanded: stats.total_nodes,
+            rules_applied: stats.total_rules,
+            blocking_stats: stats.blocking,
+            backtrack_stats: stats.backtracking,
+        })
+    }
+
     pub fn classify(&self) -> OwlResult<()> {
         // Core classification logic will be implemented here
         Ok(())
@@ -654,17 +898,67 @@ impl TableauxReasoner {
 
     pub fn clear_cache(&mut self) {
         self.cache.clear();
+        self.internalized_tbox.get_mut().take();
+    }
+
+    /// An expansion engine for a new tableau, sharing the internalized TBox
+    fn expansion_engine(&self) -> super::expansion::ExpansionEngine {
+        self.internalized_tbox
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                super::expansion::ExpansionEngine::new()
+                    .with_reasoning_rules(self.rules.clone())
+                    .with_blocking_strategy(self.blocking_strategy(BlockingStrategy::Subset))
+            })
+            .clone()
+    }
+
+    /// The blocking strategy for this ontology's tableaux
+    ///
+    /// Inverse roles let successors constrain their predecessors, which only
+    /// pairwise blocking accounts for; other ontologies keep `default`.
+    fn blocking_strategy(&self, default: BlockingStrategy) -> BlockingStrategy {
+        if self.rules.uses_inverse_roles() {
+            BlockingStrategy::Pairwise
+        } else {
+            default
+        }
     }
 
     pub fn get_memory_stats(&self) -> MemoryStats {
         self.memory_stats.borrow().clone()
     }
 
+    /// Statistics of the most recent reasoning call
+    ///
+    /// A subsumption answered from the class hierarchy reports a cache hit and
+    /// no tableaux nodes.
+    pub fn last_reasoning_stats(&self) -> super::ReasoningStats {
+        self.last_stats.borrow().clone()
+    }
+
+    /// Steps of the most recent tableau run
+    ///
+    /// Empty unless [`ReasoningConfig::record_trace`] is set. With
+    /// [`ReasoningConfig::deterministic`] also set, the same call on the same
+    /// input always records the same trace.
+    pub fn last_trace(&self) -> Vec<super::TraceEvent> {
+        self.trace.borrow().clone()
+    }
+
+    /// Record a step of the current tableau run if tracing is enabled
+    fn record_trace(&self, event: impl FnOnce() -> super::TraceEvent) {
+        if self.config.record_trace {
+            self.trace.borrow_mut().push(event());
+        }
+    }
+
     pub fn reset(&mut self) {
         self.cache.clear();
         self.rules.clear();
         self.dependency_manager.clear();
         *self.memory_stats.borrow_mut() = MemoryStats::new();
+        *self.last_stats.borrow_mut() = super::ReasoningStats::default();
     }
 
     pub fn is_consistent(&mut self) -> OwlResult<bool> {
@@ -821,16 +1115,7 @@ impl TableauxReasoner {
         }
 
         // Use tableaux reasoning to check for implicit disjointness
-        // Create a new tableaux graph for disjointness checking
-        let mut graph = super::graph::TableauxGraph::new();
-        let mut expansion_engine =
-            super::expansion::ExpansionEngine::new().with_reasoning_rules(self.rules.clone());
-        let mut blocking_manager =
-            super::blocking::BlockingManager::new(super::blocking::BlockingStrategy::Optimized);
-        let mut memory_manager = super::memory::MemoryManager::new();
-
-        // For subclass checking, we don't initialize with all classes
-        // We only add the specific concepts we're testing
+        let mut graph = self.new_graph();
 
         // Add both classes to the root node (their intersection)
         let class1_expr = ClassExpression::Class(Class::new(class1.as_str()));
@@ -838,58 +1123,7 @@ impl TableauxReasoner {
         graph.add_concept(graph.get_root(), class1_expr);
         graph.add_concept(graph.get_root(), class2_expr);
 
-        let mut nodes_to_expand = VecDeque::new();
-        nodes_to_expand.push_back(graph.get_root());
-
-        let mut expanded_nodes = HashSet::new();
-        expanded_nodes.insert(graph.get_root());
-
-        let mut branch_logs: Vec<super::graph::GraphChangeLog> = Vec::new();
-        while let Some(current_node) = nodes_to_expand.pop_front() {
-            if let Some(constraint) = blocking_manager.detect_blocking(current_node, &graph) {
-                blocking_manager.add_blocking_constraint(constraint);
-                continue;
-            }
-
-            let mut local_graph_log = super::graph::GraphChangeLog::new();
-            let mut local_memory_log = super::memory::MemoryChangeLog::new();
-            expansion_engine
-                .expand(
-                    &mut graph,
-                    &mut memory_manager,
-                    self.config.max_depth as u32,
-                    &mut local_graph_log,
-                    &mut local_memory_log,
-                )
-                .map_err(|e| OwlError::ReasoningError(format!("Expansion failed: {}", e)))?;
-            if !local_graph_log.is_empty() {
-                branch_logs.push(local_graph_log.clone());
-            }
-
-            if self.has_clash(current_node, &graph)? {
-                return Ok(true);
-            }
-
-            let new_nodes = self.get_new_successors(current_node, &graph, &expanded_nodes);
-            for new_node in new_nodes {
-                if expanded_nodes.insert(new_node) {
-                    nodes_to_expand.push_back(new_node);
-                }
-            }
-
-            if let Some(timeout_ms) = self.config.timeout {
-                let start_time = std::time::Instant::now();
-                if start_time.elapsed().as_millis() >= timeout_ms as u128 {
-                    return Err(OwlError::TimeoutError {
-                        operation: "disjointness_checking".to_string(),
-                        timeout_ms,
-                    });
-                }
-            }
-        }
-
-        drop(branch_logs);
-        Ok(false)
+        self.expand_until_clash(&mut graph, "disjointness_checking")
     }
 
     /// Check if two class expressions represent disjoint classes
@@ -931,6 +1165,17 @@ impl TableauxReasoner {
         // Check if the class is satisfiable using tableaux reasoning
         // To check satisfiability of C, we check if C leads to inconsistency
 
+        // A property that is both reflexive and irreflexive leaves no
+        // individual able to exist, so no class can have instances
+        if self
+            .rules
+            .reflexive_properties
+            .iter()
+            .any(|property| self.rules.irreflexive_properties.contains(property))
+        {
+            return Ok(false);
+        }
+
         // Special cases
         if class.as_str() == "http://www.w3.org/2002/07/owl#Thing" {
             // owl:Thing is always satisfiable
@@ -943,135 +1188,374 @@ impl TableauxReasoner {
 
         // Check if the class has any axioms that could make it unsatisfiable
         // If there are no axioms involving this class, it's trivially satisfiable
-        let has_relevant_axioms = self.rules.subclass_rules.iter().any(|axiom| {
-            matches!(axiom.sub_class(), ClassExpression::Class(c) if c.iri().as_ref() == class)
-                || matches!(axiom.super_class(), ClassExpression::Class(c) if c.iri().as_ref() == class)
-        }) || self.rules.equivalence_rules.iter().any(|axiom| {
-            axiom.classes().iter().any(|c| c.as_ref() == class)
-        }) || self.rules.disjointness_rules.iter().any(|axiom| {
-            axiom.classes().iter().any(|c| c.as_ref() == class)
-        });
+        let has_relevant_axioms = self
+            .rules
+            .subclass_rules
+            .iter()
+            .any(|axiom| axiom.involves_class(class))
+            || self.rules.equivalence_rules.iter().any(|axiom| {
+                axiom
+                    .class_expressions()
+                    .iter()
+                    .any(|expr| expr.contains_class(class))
+            })
+            || self
+                .rules
+                .disjointness_rules
+                .iter()
+                .any(|axiom| axiom.classes().iter().any(|c| c.as_ref() == class));
 
         // If no axioms involve this class, it's trivially satisfiable
         if !has_relevant_axioms {
             return Ok(true);
         }
 
+        let target_class_expr = ClassExpression::Class(Class::new(class.as_str()));
+        let cached = self
+            .cache
+            .satisfiability_cache
+            .borrow()
+            .get(&target_class_expr)
+            .copied();
+        if let Some(result) = cached {
+            *self.last_stats.borrow_mut() = super::ReasoningStats {
+                cache_hits: 1,
+                ..Default::default()
+            };
+            return Ok(result);
+        }
+
+        let result = self
+            .is_concept_satisfiable(target_class_expr.clone(), "class_satisfiability_checking")?;
+        self.last_stats.borrow_mut().cache_misses = 1;
+        self.cache
+            .satisfiability_cache
+            .borrow_mut()
+            .insert(target_class_expr, result);
+        Ok(result)
+    }
+
+    /// Check the satisfiability of every class of the ontology in one pass
+    ///
+    /// All tableaux share the internalized TBox, and a class with a known
+    /// superclass that is unsatisfiable is unsatisfiable too, without building
+    /// a tableau. The answers are cached, so later calls to
+    /// [`is_class_satisfiable`](Self::is_class_satisfiable) are lookups.
+    pub fn check_all_class_satisfiability(&mut self) -> OwlResult<HashMap<IRI, bool>> {
+        let mut classes: Vec<IRI> = self
+            .ontology
+            .classes()
+            .iter()
+            .map(|class| (**class.iri()).clone())
+            .collect();
+        classes.sort();
+
+        let mut results = HashMap::new();
+        for class in classes {
+            let satisfiable = if self.has_unsatisfiable_superclass(&class, &results) {
+                self.cache
+                    .satisfiability_cache
+                    .borrow_mut()
+                    .insert(ClassExpression::Class(Class::new(class.as_str())), false);
+                false
+            } else {
+                self.is_class_satisfiable(&class)?
+            };
+            results.insert(class, satisfiable);
+        }
+        Ok(results)
+    }
+
+    /// Whether a known superclass of a class was already found unsatisfiable
+    fn has_unsatisfiable_superclass(&self, class: &IRI, results: &HashMap<IRI, bool>) -> bool {
+        let unsatisfiable: Vec<&IRI> = results
+            .iter()
+            .filter(|(_, satisfiable)| !**satisfiable)
+            .map(|(class, _)| class)
+            .collect();
+        unsatisfiable
+            .into_iter()
+            .any(|superclass| superclass != class && self.is_known_subclass_of(class, superclass))
+    }
+
+    /// Check whether an arbitrary class expression can have instances
+    ///
+    /// Answers are cached under the expression's semantic key, so expressions
+    /// that differ only in operand order share a cache entry.
+    pub fn is_class_expression_satisfiable(&self, class: &ClassExpression) -> OwlResult<bool> {
+        if let ClassExpression::Class(named) = class {
+            return self.is_class_satisfiable(named.iri());
+        }
+
+        let key = class.semantic_key();
+        let cached = self.cache.satisfiability_cache.borrow().get(&key).copied();
+        if let Some(result) = cached {
+            *self.last_stats.borrow_mut() = super::ReasoningStats {
+                cache_hits: 1,
+                ..Default::default()
+            };
+            return Ok(result);
+        }
+
+        let result =
+            self.is_concept_satisfiable(class.clone(), "class_expression_satisfiability_checking")?;
+        self.last_stats.borrow_mut().cache_misses = 1;
+        self.cache
+            .satisfiability_cache
+            .borrow_mut()
+            .insert(key, result);
+        Ok(result)
+    }
+
+    /// Run the tableau on a single root concept, returning false on a clash
+    fn is_concept_satisfiable(&self, concept: ClassExpression, operation: &str) -> OwlResult<bool> {
         // Create a new tableaux graph for satisfiability checking
-        let mut graph = super::graph::TableauxGraph::new();
-        let mut expansion_engine =
-            super::expansion::ExpansionEngine::new().with_reasoning_rules(self.rules.clone());
-        let mut blocking_manager =
-            super::blocking::BlockingManager::new(super::blocking::BlockingStrategy::Optimized);
-        let mut memory_manager = super::memory::MemoryManager::new();
+        let mut graph = self.new_graph();
 
-        // For satisfiability checking, we add the class itself (not its negation)
+        // For satisfiability checking, we add the concept itself (not its negation)
         // and check if it leads to a contradiction
         // If C leads to contradiction, then C is unsatisfiable
         // If C does not lead to contradiction, then C is satisfiable
+        graph.add_concept(graph.get_root(), concept);
 
-        // Add the target class to the root node
-        let target_class_expr = ClassExpression::Class(Class::new(class.as_str()));
-        graph.add_concept(graph.get_root(), target_class_expr);
+        Ok(!self.expand_until_clash(&mut graph, operation)?)
+    }
 
-        // Track reasoning state
-        let mut nodes_to_expand = std::collections::VecDeque::new();
-        nodes_to_expand.push_back(graph.get_root());
+    /// Check whether an individual is entailed to be an instance of a class
+    ///
+    /// Refutes `individual : ¬class` on a tableau seeded with the individual's
+    /// asserted types and the individuals reachable through its object
+    /// property assertions, so membership in defined classes is recognised.
+    pub fn is_instance_of(&self, individual: &IRI, class: &IRI) -> OwlResult<bool> {
+        let asserted = self
+            .ontology
+            .class_assertions()
+            .into_iter()
+            .filter(|assertion| **assertion.individual() == *individual)
+            .any(|assertion| match assertion.class_expr() {
+                ClassExpression::Class(asserted) => {
+                    self.is_known_subclass_of(asserted.iri(), class)
+                }
+                _ => false,
+            });
+        if asserted {
+            return Ok(true);
+        }
 
-        let mut expanded_nodes = std::collections::HashSet::new();
-        expanded_nodes.insert(graph.get_root());
+        let mut graph = self.new_graph();
+        let root = graph.get_root();
+        self.add_individual_to_graph(&mut graph, individual, root)?;
+        let class_expr = ClassExpression::Class(Class::new(class.as_str()));
+        graph.add_concept(
+            root,
+            ClassExpression::ObjectComplementOf(Arc::new(class_expr)),
+        );
 
-        // Main reasoning loop
-        let mut branch_logs: Vec<super::graph::GraphChangeLog> = Vec::new();
-        while let Some(current_node) = nodes_to_expand.pop_front() {
-            // Check if current node should be blocked
-            if let Some(constraint) = blocking_manager.detect_blocking(current_node, &graph) {
-                blocking_manager.add_blocking_constraint(constraint);
-                continue;
-            }
+        self.expand_until_clash(&mut graph, "instance_checking")
+    }
 
-            // Apply tableaux expansion rules
-            // Note: current_node context is handled internally during expansion
-            let mut local_graph_log = super::graph::GraphChangeLog::new();
-            let mut local_memory_log = super::memory::MemoryChangeLog::new();
-            let _expansion_result = expansion_engine
-                .expand(
-                    &mut graph,
-                    &mut memory_manager,
-                    self.config.max_depth as u32,
-                    &mut local_graph_log,
-                    &mut local_memory_log,
-                )
-                .map_err(|e| OwlError::ReasoningError(format!("Expansion failed: {}", e)))?;
-            if !local_graph_log.is_empty() {
-                branch_logs.push(local_graph_log.clone());
+    /// Get every named class of the ontology an individual is an instance of
+    pub fn get_types(&self, individual: &IRI) -> OwlResult<Vec<IRI>> {
+        let mut types = Vec::new();
+        for class in self.ontology.classes() {
+            if self.is_instance_of(individual, class.iri())? {
+                types.push((**class.iri()).clone());
             }
+        }
+        types.sort();
+        Ok(types)
+    }
 
-            // Check for clashes after expansion
-            if self.has_clash(current_node, &graph)? {
-                // Found a clash - C is inconsistent, so C is unsatisfiable
-                return Ok(false);
+    /// An empty tableau that knows which individuals are the same and which
+    /// properties are sub-properties of which
+    fn new_graph(&self) -> super::graph::TableauxGraph {
+        let mut graph = super::graph::TableauxGraph::new();
+        graph.set_same_individuals(self.same_individuals.clone());
+        graph.set_sub_properties(self.sub_properties.clone());
+        graph
+    }
+
+    /// Label `node` with an individual, its asserted types and the domains of its
+    /// data properties, then follow the object property assertions it takes part
+    /// in to nodes for the related individuals
+    fn add_individual_to_graph(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        individual: &IRI,
+        node: NodeId,
+    ) -> OwlResult<()> {
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back((individual.clone(), node));
+        graph.associate_node_with_individual(node, individual)?;
+        let mut linked = HashSet::new();
+
+        while let Some((current, node)) = to_visit.pop_front() {
+            // The node stands for every individual the current one is the same as
+            let names = self
+                .same_individuals
+                .get(&current)
+                .cloned()
+                .unwrap_or_else(|| vec![current.clone()]);
+
+            // Every individual is related to itself by a reflexive property
+            let mut reflexive: Vec<_> = self.rules.reflexive_properties.iter().collect();
+            if self.config.deterministic {
+                reflexive.sort();
+            }
+            for property in reflexive {
+                graph.add_edge(node, property, node);
+                self.add_property_constraints(graph, property, node, node);
             }
 
-            // Get newly created nodes from expansion
-            let new_nodes = self.get_new_successors(current_node, &graph, &expanded_nodes);
+            for assertion in self.ontology.class_assertions() {
+                if names.contains(&**assertion.individual()) {
+                    graph.add_concept(node, assertion.class_expr().clone());
+                }
+            }
 
-            // Add new nodes to expansion queue
-            for new_node in new_nodes {
-                if !expanded_nodes.contains(&new_node) {
-                    nodes_to_expand.push_back(new_node);
-                    expanded_nodes.insert(new_node);
+            // A data property value puts its subject in the property's domains
+            for assertion in &self.rules.data_property_assertions {
+                if !names.contains(&**assertion.subject()) {
+                    continue;
+                }
+                for axiom in &self.rules.data_property_domains {
+                    if axiom.property() == &**assertion.property() {
+                        graph.add_concept(node, axiom.domain().clone());
+                    }
                 }
             }
 
-            // For satisfiability checking, we don't use backtracking for simplicity
-            // If needed, backtracking can be added later
-
-            // Check timeout
-            if let Some(timeout_ms) = self.config.timeout {
-                let start_time = std::time::Instant::now();
-                if start_time.elapsed().as_millis() >= timeout_ms as u128 {
-                    return Err(OwlError::TimeoutError {
-                        operation: "class_satisfiability_checking".to_string(),
-                        timeout_ms,
-                    });
+            // Anonymous objects get nodes of their own, named by their blank node
+            for (index, assertion) in self.ontology.property_assertions().iter().enumerate() {
+                let object = assertion.object_node_iri()?;
+                let subject = assertion.subject();
+                if (!names.contains(&**subject) && !names.contains(&*object))
+                    || !linked.insert(index)
+                {
+                    continue;
                 }
+                let source = Self::individual_node(graph, subject, &mut to_visit)?;
+                let target = Self::individual_node(graph, &object, &mut to_visit)?;
+                graph.add_edge(source, assertion.property(), target);
+                self.add_property_constraints(graph, assertion.property(), source, target);
             }
         }
 
-        // No clash found - C is consistent, so C is satisfiable
-        drop(branch_logs);
-        Ok(true)
+        Ok(())
     }
 
-    pub fn is_class_expression_satisfiable(&self, _class: &ClassExpression) -> OwlResult<bool> {
-        // Placeholder implementation - check if the class expression can be instantiated
-        Ok(true)
+    /// The node labelled with `individual`, created and queued for a visit if missing
+    fn individual_node(
+        graph: &mut super::graph::TableauxGraph,
+        individual: &IRI,
+        to_visit: &mut VecDeque<(IRI, NodeId)>,
+    ) -> OwlResult<NodeId> {
+        if let Some(node) = graph.get_node_for_individual(individual) {
+            return Ok(node);
+        }
+        let node = graph.add_node();
+        graph.associate_node_with_individual(node, individual)?;
+        to_visit.push_back((individual.clone(), node));
+        Ok(node)
     }
 
-    pub fn is_subclass_of(&self, subclass: &IRI, superclass: &IRI) -> OwlResult<bool> {
-        // To check if subclass ⊑ superclass, we check if subclass ⊓ ¬superclass is unsatisfiable
-        // If it's unsatisfiable, then subclass is indeed a subclass of superclass
+    /// Label both ends of a `property` edge with the domains and ranges that apply
+    ///
+    /// The edge is also read through each declared inverse, so a range of `R`
+    /// constrains the subjects of an inverse of `R`.
+    fn add_property_constraints(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        property: &IRI,
+        subject: NodeId,
+        object: NodeId,
+    ) {
+        let mut readings = vec![(property, subject, object)];
+        for axiom in &self.rules.inverse_properties {
+            for (this, other) in [
+                (axiom.property1(), axiom.property2()),
+                (axiom.property2(), axiom.property1()),
+            ] {
+                let (this_inverse, this_iri) = Self::resolve_property_direction(this);
+                if this_iri != property {
+                    continue;
+                }
+                // `this` relates (x, y), so `other` relates (y, x)
+                let (x, y) = if this_inverse {
+                    (object, subject)
+                } else {
+                    (subject, object)
+                };
+                let (other_inverse, other_iri) = Self::resolve_property_direction(other);
+                readings.push(if other_inverse {
+                    (other_iri, x, y)
+                } else {
+                    (other_iri, y, x)
+                });
+            }
+        }
 
-        // Create a new tableaux graph for subclass checking
-        let mut graph = super::graph::TableauxGraph::new();
-        let mut expansion_engine =
-            super::expansion::ExpansionEngine::new().with_reasoning_rules(self.rules.clone());
-        let mut blocking_manager =
-            super::blocking::BlockingManager::new(super::blocking::BlockingStrategy::Optimized);
-        let mut memory_manager = super::memory::MemoryManager::new();
+        for (property, subject, object) in readings {
+            for domain in self.rules.domains_of(property) {
+                graph.add_concept(subject, domain.clone());
+            }
+            for range in self.rules.ranges_of(property) {
+                graph.add_concept(object, range.clone());
+            }
+        }
+    }
 
-        // For satisfiability checking, we don't initialize with all classes
-        // We only add the specific concepts we're testing
+    /// Expand the graph from its root, returning true if a clash is found
+    ///
+    /// The number of disjunction branches tried is recorded in the reasoning
+    /// stats, and the call fails once it exceeds [`ReasoningConfig::max_branches`].
+    fn expand_until_clash(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        operation: &str,
+    ) -> OwlResult<bool> {
+        self.start_call();
+        self.trace.borrow_mut().clear();
+        if self.config.deterministic {
+            graph.keep_concept_order();
+        }
+        let result = self.expand_graph(graph, operation);
+        *self.last_stats.borrow_mut() = self.run_stats(graph);
+        result
+    }
 
-        // Add the subclass as a concept
-        let subclass_expr = ClassExpression::Class(Class::new(subclass.as_str()));
-        graph.add_concept(graph.get_root(), subclass_expr);
+    /// Reset the limits and statistics kept for the current reasoning call
+    fn start_call(&self) {
+        self.branches.set(0);
+        self.started.set(Some(std::time::Instant::now()));
+        *self.blocking_stats.borrow_mut() = super::BlockingStats::default();
+        *self.backtrack_stats.borrow_mut() = super::BacktrackStats::default();
+    }
 
-        // Add the negation of the superclass as a concept
-        let superclass_expr = ClassExpression::Class(Class::new(superclass.as_str()));
-        let negation = ClassExpression::ObjectComplementOf(Box::new(superclass_expr));
-        graph.add_concept(graph.get_root(), negation);
+    /// Statistics of the current reasoning call, which built `graph`
+    fn run_stats(&self, graph: &super::graph::TableauxGraph) -> super::ReasoningStats {
+        super::ReasoningStats {
+            total_nodes: graph.node_count(),
+            total_edges: graph.edge_count(),
+            branches: self.branches.get(),
+            blocking: self.blocking_stats.borrow().clone(),
+            backtracking: self.backtrack_stats.borrow().clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Expand the graph, branching on open disjunctions, until a clash is found
+    /// or every branch is complete
+    fn expand_graph(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        operation: &str,
+    ) -> OwlResult<bool> {
+        let mut expansion_engine = self.expansion_engine();
+        let mut blocking_manager = super::blocking::BlockingManager::new(
+            self.blocking_strategy(BlockingStrategy::Optimized),
+        );
+        let mut memory_manager = super::memory::MemoryManager::new();
 
         // Track reasoning state
         let mut nodes_to_expand = std::collections::VecDeque::new();
@@ -1084,8 +1568,15 @@ impl TableauxReasoner {
         let mut branch_logs: Vec<super::graph::GraphChangeLog> = Vec::new();
         while let Some(current_node) = nodes_to_expand.pop_front() {
             // Check if current node should be blocked
-            if let Some(constraint) = blocking_manager.detect_blocking(current_node, &graph) {
+            if let Some(constraint) = blocking_manager.detect_blocking(current_node, graph) {
+                self.blocking_stats.borrow_mut().record(&constraint);
                 blocking_manager.add_blocking_constraint(constraint);
+                // Blocking only stops a node from generating successors; its
+                // own concepts must still be free of clashes
+                if self.has_clash(current_node, graph)? {
+                    self.record_trace(|| super::TraceEvent::Clash { node: current_node });
+                    return Ok(true);
+                }
                 continue;
             }
 
@@ -1095,7 +1586,7 @@ impl TableauxReasoner {
             let mut local_memory_log = super::memory::MemoryChangeLog::new();
             let _expansion_result = expansion_engine
                 .expand(
-                    &mut graph,
+                    graph,
                     &mut memory_manager,
                     self.config.max_depth as u32,
                     &mut local_graph_log,
@@ -1106,14 +1597,22 @@ impl TableauxReasoner {
                 branch_logs.push(local_graph_log.clone());
             }
 
+            self.record_trace(|| super::TraceEvent::Expanded {
+                node: current_node,
+                concepts: graph
+                    .get_node(current_node)
+                    .map(|node| node.concepts_iter().cloned().collect())
+                    .unwrap_or_default(),
+            });
+
             // Check for clashes after expansion
-            if self.has_clash(current_node, &graph)? {
-                // Found a clash - subclass ⊓ ¬superclass is inconsistent, so subclass ⊑ superclass
+            if self.has_clash(current_node, graph)? {
+                self.record_trace(|| super::TraceEvent::Clash { node: current_node });
                 return Ok(true);
             }
 
             // Get newly created nodes from expansion
-            let new_nodes = self.get_new_successors(current_node, &graph, &expanded_nodes);
+            let new_nodes = self.get_new_successors(current_node, graph, &expanded_nodes);
 
             // Add new nodes to expansion queue
             for new_node in new_nodes {
@@ -1123,24 +1622,315 @@ impl TableauxReasoner {
                 }
             }
 
-            // For subclass checking, we don't use backtracking for simplicity
-            // If needed, backtracking can be added later
-
-            // Check timeout
-            if let Some(timeout_ms) = self.config.timeout {
-                let start_time = std::time::Instant::now();
-                if start_time.elapsed().as_millis() >= timeout_ms as u128 {
-                    return Err(OwlError::TimeoutError {
-                        operation: "subclass_checking".to_string(),
-                        timeout_ms,
-                    });
+            self.check_timeout(operation)?;
+        }
+
+        drop(branch_logs);
+        self.branch_on_disjunction(graph, operation)
+    }
+
+    /// Try each disjunct of the first open disjunction in turn
+    ///
+    /// Returns true only if every branch ends in a clash. Otherwise the graph
+    /// is left as the first clash-free branch built it.
+    fn branch_on_disjunction(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        operation: &str,
+    ) -> OwlResult<bool> {
+        let Some((node_id, disjuncts)) = Self::open_disjunction(graph) else {
+            return self.branch_on_merge(graph, operation);
+        };
+
+        for disjunct in disjuncts {
+            self.record_branch(operation)?;
+            self.record_trace(|| super::TraceEvent::Branch {
+                node: node_id,
+                disjunct: Box::new(disjunct.clone()),
+            });
+            let mut branch = graph.clone();
+            branch.add_concept(node_id, disjunct);
+            if !self.expand_graph(&mut branch, operation)? {
+                *graph = branch;
+                return Ok(false);
+            }
+            self.record_backtrack();
+        }
+
+        Ok(true)
+    }
+
+    /// Try each way of merging two successors of a node that has more
+    /// successors than one of its max-cardinality restrictions allows
+    ///
+    /// Successors known to be different are never merged. Returns true only if
+    /// every merge ends in a clash. Otherwise the graph is left as the first
+    /// clash-free merge built it.
+    fn branch_on_merge(
+        &self,
+        graph: &mut super::graph::TableauxGraph,
+        operation: &str,
+    ) -> OwlResult<bool> {
+        let Some(targets) = Self::exceeded_max_cardinality(graph) else {
+            return Ok(false);
+        };
+
+        for (i, &into) in targets.iter().enumerate() {
+            for &from in &targets[i + 1..] {
+                if self.are_nodes_different(from, into, graph)? {
+                    continue;
+                }
+                self.record_branch(operation)?;
+                self.record_trace(|| super::TraceEvent::Merge { from, into });
+                let mut branch = graph.clone();
+                branch.merge_nodes(from, into);
+                if !self.expand_graph(&mut branch, operation)? {
+                    *graph = branch;
+                    return Ok(false);
                 }
+                self.record_backtrack();
             }
         }
 
-        // No clash found - subclass ⊓ ¬superclass is consistent, so subclass is not a subclass of superclass
-        drop(branch_logs);
-        Ok(false)
+        Ok(true)
+    }
+
+    /// Find the targets of a max-cardinality restriction that has more of them
+    /// than it allows, sorted so that merges keep the older node
+    fn exceeded_max_cardinality(graph: &super::graph::TableauxGraph) -> Option<Vec<NodeId>> {
+        for (node_id, node) in graph.nodes_iter() {
+            if node.blocked_by.is_some() {
+                continue;
+            }
+            for restriction in node
+                .concepts_iter()
+                .filter_map(ClassExpression::as_object_cardinality)
+            {
+                let Some(max) = restriction.max() else {
+                    continue;
+                };
+                let targets = Self::cardinality_neighbours(node_id, &restriction, graph);
+                if targets.len() > max as usize {
+                    return Some(targets);
+                }
+            }
+        }
+        None
+    }
+
+    /// Fail once the current reasoning call has run past the configured timeout
+    fn check_timeout(&self, operation: &str) -> OwlResult<()> {
+        if let (Some(timeout_ms), Some(started)) = (self.config.timeout, self.started.get()) {
+            if started.elapsed().as_millis() >= timeout_ms as u128 {
+                return Err(OwlError::TimeoutError {
+                    operation: operation.to_string(),
+                    timeout_ms,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Count a branch that ended in a clash and was abandoned
+    ///
+    /// Branches are tried in order, so every backtrack is chronological.
+    fn record_backtrack(&self) {
+        let mut stats = self.backtrack_stats.borrow_mut();
+        stats.total_backtracks += 1;
+        stats.naive_backtracks += 1;
+        stats.contradictions_detected += 1;
+    }
+
+    /// Count one more branch choice, failing if the branch limit is exceeded
+    fn record_branch(&self, operation: &str) -> OwlResult<()> {
+        let branches = self.branches.get() + 1;
+        self.branches.set(branches);
+        self.backtrack_stats.borrow_mut().choices_explored += 1;
+        match self.config.max_branches {
+            Some(limit) if branches > limit => Err(OwlError::ResourceLimitExceeded {
+                resource_type: "disjunction branches".to_string(),
+                limit,
+                message: format!("{} tried more than {} branches", operation, limit),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Find a disjunction none of whose disjuncts is on its node yet
+    fn open_disjunction(
+        graph: &super::graph::TableauxGraph,
+    ) -> Option<(NodeId, Vec<ClassExpression>)> {
+        graph.nodes_iter().find_map(|(node_id, node)| {
+            node.concepts_iter().find_map(|concept| match concept {
+                ClassExpression::ObjectUnionOf(disjuncts)
+                    if !disjuncts.iter().any(|d| node.contains_concept(d)) =>
+                {
+                    Some((node_id, disjuncts.iter().map(|d| (**d).clone()).collect()))
+                }
+                _ => None,
+            })
+        })
+    }
+
+    pub fn is_subclass_of(&self, subclass: &IRI, superclass: &IRI) -> OwlResult<bool> {
+        // Fast path: answer from the known class hierarchy without building a tableau
+        if self.is_known_subclass_of(subclass, superclass) {
+            *self.last_stats.borrow_mut() = super::ReasoningStats {
+                cache_hits: 1,
+                ..Default::default()
+            };
+            return Ok(true);
+        }
+
+        let mut graph = self.new_graph();
+        let result = self.is_subclass_of_tableaux(subclass, superclass, &mut graph)?;
+
+        *self.last_stats.borrow_mut() = super::ReasoningStats {
+            cache_misses: 1,
+            ..self.run_stats(&graph)
+        };
+        if result {
+            self.record_subsumption(subclass, superclass);
+        }
+
+        Ok(result)
+    }
+
+    /// Build a model of a concept, if the tableau finds one
+    ///
+    /// Returns `None` when the concept is unsatisfiable. Otherwise the root of
+    /// the model is an instance of the concept.
+    pub fn satisfiability_model(
+        &self,
+        concept: &ClassExpression,
+    ) -> OwlResult<Option<super::Model>> {
+        let mut graph = self.new_graph();
+        graph.add_concept(graph.get_root(), concept.clone());
+        if self.expand_until_clash(&mut graph, "satisfiability_model")? {
+            return Ok(None);
+        }
+        Ok(Some(super::extract_model(&graph)))
+    }
+
+    /// Build a model of `subclass ⊓ ¬superclass`, if the tableau finds one
+    ///
+    /// Returns `None` when the subsumption holds. Otherwise the root of the
+    /// model is an instance of `subclass` that is not an instance of `superclass`.
+    pub fn counter_model(
+        &self,
+        subclass: &IRI,
+        superclass: &IRI,
+    ) -> OwlResult<Option<super::CounterModel>> {
+        if self.is_known_subclass_of(subclass, superclass) {
+            return Ok(None);
+        }
+
+        let mut graph = self.new_graph();
+        if self.is_subclass_of_tableaux(subclass, superclass, &mut graph)? {
+            return Ok(None);
+        }
+
+        Ok(Some(super::CounterModel {
+            subclass: subclass.clone(),
+            superclass: superclass.clone(),
+            witness: graph.get_root(),
+            model: super::extract_model(&graph),
+        }))
+    }
+
+    /// Check whether a subsumption follows from the known class hierarchy
+    ///
+    /// Walks the transitive closure of asserted subclass and equivalence axioms
+    /// between named classes, plus subsumptions already proven by the tableau.
+    /// Only positive answers are conclusive.
+    fn is_known_subclass_of(&self, subclass: &IRI, superclass: &IRI) -> bool {
+        if subclass == superclass
+            || superclass.as_str() == "http://www.w3.org/2002/07/owl#Thing"
+            || subclass.as_str() == "http://www.w3.org/2002/07/owl#Nothing"
+        {
+            return true;
+        }
+
+        let mut hierarchy = self.cache.class_hierarchy.borrow_mut();
+        let hierarchy = hierarchy.get_or_insert_with(|| self.build_told_hierarchy());
+
+        let mut visited = HashSet::new();
+        let mut to_visit = VecDeque::new();
+        to_visit.push_back(subclass);
+        while let Some(current) = to_visit.pop_front() {
+            if !visited.insert(current) {
+                continue;
+            }
+            if let Some(parents) = hierarchy.get(current) {
+                if parents.contains(superclass) {
+                    return true;
+                }
+                to_visit.extend(parents.iter());
+            }
+        }
+
+        false
+    }
+
+    /// Direct told subsumptions between named classes
+    fn build_told_hierarchy(&self) -> HashMap<IRI, HashSet<IRI>> {
+        let mut hierarchy: HashMap<IRI, HashSet<IRI>> = HashMap::new();
+
+        for axiom in &self.rules.subclass_rules {
+            if let (ClassExpression::Class(sub), ClassExpression::Class(sup)) =
+                (axiom.sub_class(), axiom.super_class())
+            {
+                hierarchy
+                    .entry((**sub.iri()).clone())
+                    .or_default()
+                    .insert((**sup.iri()).clone());
+            }
+        }
+
+        for axiom in &self.rules.equivalence_rules {
+            for first in axiom.classes() {
+                for second in axiom.classes() {
+                    if first != second {
+                        hierarchy
+                            .entry((**first).clone())
+                            .or_default()
+                            .insert((**second).clone());
+                    }
+                }
+            }
+        }
+
+        hierarchy
+    }
+
+    /// Remember a subsumption proven by the tableau for later fast-path lookups
+    fn record_subsumption(&self, subclass: &IRI, superclass: &IRI) {
+        if let Some(hierarchy) = self.cache.class_hierarchy.borrow_mut().as_mut() {
+            hierarchy
+                .entry(subclass.clone())
+                .or_default()
+                .insert(superclass.clone());
+        }
+    }
+
+    /// Decide `subclass ⊑ superclass` by refuting `subclass ⊓ ¬superclass`
+    fn is_subclass_of_tableaux(
+        &self,
+        subclass: &IRI,
+        superclass: &IRI,
+        graph: &mut super::graph::TableauxGraph,
+    ) -> OwlResult<bool> {
+        // Add the subclass as a concept
+        let subclass_expr = ClassExpression::Class(Class::new(subclass.as_str()));
+        graph.add_concept(graph.get_root(), subclass_expr);
+
+        // Add the negation of the superclass as a concept
+        let superclass_expr = ClassExpression::Class(Class::new(superclass.as_str()));
+        let negation = ClassExpression::ObjectComplementOf(Arc::new(superclass_expr));
+        graph.add_concept(graph.get_root(), negation);
+
+        // A clash means subclass ⊓ ¬superclass is unsatisfiable, so subclass ⊑ superclass
+        self.expand_until_clash(graph, "subclass_checking")
     }
 
     /// Initialize the root node with class assertions and relevant concepts
@@ -1157,14 +1947,7 @@ impl TableauxReasoner {
         // A class declaration does not imply the existence of an individual of that class.
 
         // Add owl:Thing to the root node (everything is an instance of Thing)
-        let thing_iri = IRI::new("http://www.w3.org/2002/07/owl#Thing").map_err(|e| {
-            OwlError::IriParseError {
-                iri: "http://www.w3.org/2002/07/owl#Thing".to_string(),
-                context: format!("Failed to create owl:Thing IRI: {}", e),
-            }
-        })?;
-        let thing_expr = ClassExpression::Class(Class::new(thing_iri.as_str()));
-        graph.add_concept(root_id, thing_expr);
+        graph.add_concept(root_id, ClassExpression::thing().clone());
 
         // Add all class assertions (individuals with their types)
         for class_assertion in self.ontology.as_ref().class_assertions() {
@@ -1193,20 +1976,11 @@ impl TableauxReasoner {
             for concept in &concepts {
                 match concept {
                     ClassExpression::ObjectSomeValuesFrom(property, filler) => {
+                        // A forward filler is put on a successor of its own, which
+                        // is checked when it is expanded; other successors may
+                        // contradict it
                         let (is_inverse, property_iri) = Self::resolve_property_direction(property);
-                        if !is_inverse {
-                            if let Some(successors) = graph.get_successors(node_id, property_iri) {
-                                for succ_id in successors {
-                                    if let Some(succ_node) = graph.get_node(*succ_id) {
-                                        for succ_concept in succ_node.concepts_iter() {
-                                            if self.are_contradictory(succ_concept, filler)? {
-                                                return Ok(true);
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        } else {
+                        if is_inverse {
                             let predecessors = graph.get_predecessors(node_id, property_iri);
                             for pred_id in predecessors {
                                 if let Some(pred_node) = graph.get_node(pred_id) {
@@ -1222,13 +1996,11 @@ impl TableauxReasoner {
                     ClassExpression::ObjectAllValuesFrom(property, filler) => {
                         let (is_inverse, property_iri) = Self::resolve_property_direction(property);
                         if !is_inverse {
-                            if let Some(successors) = graph.get_successors(node_id, property_iri) {
-                                for succ_id in successors {
-                                    if let Some(succ_node) = graph.get_node(*succ_id) {
-                                        for succ_concept in succ_node.concepts_iter() {
-                                            if self.are_contradictory(succ_concept, filler)? {
-                                                return Ok(true);
-                                            }
+                            for succ_id in graph.get_successors(node_id, property_iri) {
+                                if let Some(succ_node) = graph.get_node(succ_id) {
+                                    for succ_concept in succ_node.concepts_iter() {
+                                        if self.are_contradictory(succ_concept, filler)? {
+                                            return Ok(true);
                                         }
                                     }
                                 }
@@ -1246,26 +2018,46 @@ impl TableauxReasoner {
                             }
                         }
                     }
-                    ClassExpression::ObjectMaxCardinality(max, property) => {
-                        let (is_inverse, property_iri) = Self::resolve_property_direction(property);
-                        let count =
-                            Self::count_role_targets(node_id, property_iri, is_inverse, graph);
-                        if count as u32 > *max {
+                    ClassExpression::ObjectMaxCardinality(_, _)
+                    | ClassExpression::ObjectExactCardinality(_, _) => {
+                        // Surplus targets could be merged, unless more than `max`
+                        // of them are known to be pairwise different
+                        let Some(restriction) = concept.as_object_cardinality() else {
+                            continue;
+                        };
+                        let max = restriction.max().unwrap_or(u32::MAX) as usize;
+                        let targets = Self::cardinality_neighbours(node_id, &restriction, graph);
+                        if targets.len() > max
+                            && self.has_pairwise_different_subset(&targets, max + 1, graph)?
+                        {
                             return Ok(true);
                         }
                     }
-                    ClassExpression::ObjectExactCardinality(exact, property) => {
-                        let (is_inverse, property_iri) = Self::resolve_property_direction(property);
-                        let count =
-                            Self::count_role_targets(node_id, property_iri, is_inverse, graph);
-                        if count as u32 > *exact {
+                    ClassExpression::ObjectHasSelf(property) => {
+                        let (_, property_iri) = Self::resolve_property_direction(property);
+                        if self.rules.irreflexive_properties.contains(property_iri) {
                             return Ok(true);
                         }
                     }
+                    ClassExpression::ObjectComplementOf(inner) => {
+                        if let ClassExpression::ObjectHasSelf(property) = inner.as_ref() {
+                            let (_, property_iri) = Self::resolve_property_direction(property);
+                            if self.has_self_loop(node_id, property_iri, graph) {
+                                return Ok(true);
+                            }
+                        }
+                    }
                     _ => {}
                 }
             }
 
+            // An irreflexive property must not relate the node to itself
+            for property in &self.rules.irreflexive_properties {
+                if self.has_self_loop(node_id, property, graph) {
+                    return Ok(true);
+                }
+            }
+
             // Check for disjoint class axioms
             for (i, concept1) in concepts.iter().enumerate() {
                 for concept2 in concepts.iter().skip(i + 1) {
@@ -1279,7 +2071,20 @@ impl TableauxReasoner {
         Ok(false)
     }
 
-    fn resolve_property_direction(expr: &ObjectPropertyExpression) -> (bool, &IRI) {
+    /// Check whether a node is related to itself by a property, either through
+    /// an edge or because the property is reflexive
+    fn has_self_loop(
+        &self,
+        node_id: NodeId,
+        property: &IRI,
+        graph: &super::graph::TableauxGraph,
+    ) -> bool {
+        self.rules.reflexive_properties.contains(property)
+            || graph.get_successors(node_id, property).contains(&node_id)
+    }
+
+    /// Flatten nested inverses, returning whether the property is inverted and its IRI
+    pub(crate) fn resolve_property_direction(expr: &ObjectPropertyExpression) -> (bool, &IRI) {
         fn flatten(e: &ObjectPropertyExpression, invert: bool) -> (bool, &IRI) {
             match e {
                 ObjectPropertyExpression::ObjectProperty(prop) => (invert, prop.iri()),
@@ -1292,20 +2097,126 @@ impl TableauxReasoner {
         flatten(expr, false)
     }
 
-    fn count_role_targets(
+    /// Get the distinct neighbours a cardinality restriction on a node counts
+    ///
+    /// These are the targets of its property that carry its filler; with the
+    /// `owl:Thing` filler of an unqualified restriction, all of them count.
+    pub(crate) fn cardinality_neighbours(
+        node_id: NodeId,
+        restriction: &ObjectCardinality<'_>,
+        graph: &super::graph::TableauxGraph,
+    ) -> Vec<NodeId> {
+        let (is_inverse, property_iri) = Self::resolve_property_direction(restriction.property);
+        let mut targets = Self::role_targets(node_id, property_iri, is_inverse, graph);
+        if restriction.is_qualified() {
+            targets.retain(|&target| {
+                graph
+                    .get_node(target)
+                    .is_some_and(|node| node.contains_concept(restriction.filler))
+            });
+        }
+        targets.sort();
+        targets.dedup();
+        targets
+    }
+
+    fn role_targets(
         node_id: NodeId,
         property_iri: &IRI,
         is_inverse: bool,
         graph: &super::graph::TableauxGraph,
-    ) -> usize {
+    ) -> Vec<NodeId> {
         if !is_inverse {
-            graph
-                .get_successors(node_id, property_iri)
-                .map(|targets| targets.len())
-                .unwrap_or(0)
+            graph.get_successors(node_id, property_iri)
         } else {
-            graph.get_predecessors(node_id, property_iri).len()
+            graph.get_predecessors(node_id, property_iri)
+        }
+    }
+
+    /// Check whether `size` of the given nodes are pairwise known to be different
+    fn has_pairwise_different_subset(
+        &self,
+        nodes: &[NodeId],
+        size: usize,
+        graph: &super::graph::TableauxGraph,
+    ) -> OwlResult<bool> {
+        let mut different = vec![vec![false; nodes.len()]; nodes.len()];
+        for i in 0..nodes.len() {
+            for j in (i + 1)..nodes.len() {
+                let known = self.are_nodes_different(nodes[i], nodes[j], graph)?;
+                different[i][j] = known;
+                different[j][i] = known;
+            }
+        }
+
+        fn extend(
+            different: &[Vec<bool>],
+            chosen: &mut Vec<usize>,
+            start: usize,
+            size: usize,
+        ) -> bool {
+            if chosen.len() == size {
+                return true;
+            }
+            for candidate in start..different.len() {
+                if chosen.iter().all(|&c| different[c][candidate]) {
+                    chosen.push(candidate);
+                    if extend(different, chosen, candidate + 1, size) {
+                        return true;
+                    }
+                    chosen.pop();
+                }
+            }
+            false
+        }
+
+        Ok(extend(&different, &mut Vec::new(), 0, size))
+    }
+
+    /// Check whether two nodes can never be merged into one
+    ///
+    /// Nodes are different when the graph records them as such (e.g. successors
+    /// created by a min-cardinality restriction), when they stand for individuals
+    /// asserted to be different, or when their labels contradict each other.
+    fn are_nodes_different(
+        &self,
+        node1: NodeId,
+        node2: NodeId,
+        graph: &super::graph::TableauxGraph,
+    ) -> OwlResult<bool> {
+        if node1 == node2 {
+            return Ok(false);
+        }
+        if graph.are_different(node1, node2) {
+            return Ok(true);
+        }
+
+        let (Some(first), Some(second)) = (graph.get_node(node1), graph.get_node(node2)) else {
+            return Ok(false);
+        };
+
+        let asserted_different = self.rules.different_individuals_axioms.iter().any(|axiom| {
+            let names = |node: &TableauxNode| {
+                axiom
+                    .individuals()
+                    .iter()
+                    .position(|individual| node.labels_iter().any(|l| l == individual.as_str()))
+            };
+            matches!((names(first), names(second)), (Some(a), Some(b)) if a != b)
+        });
+        if asserted_different {
+            return Ok(true);
+        }
+
+        for concept1 in first.concepts_iter() {
+            for concept2 in second.concepts_iter() {
+                if self.are_contradictory(concept1, concept2)? {
+                    return Ok(true);
+                }
+            }
         }
+
+        Ok(false)
     }
 
     /// Check if two concepts are contradictory
@@ -1336,21 +2247,6 @@ impl TableauxReasoner {
                 }
                 Ok(false)
             }
-            (ClassExpression::ObjectComplementOf(comp1), ClassExpression::Class(class2)) => {
-                // Check if complement contradicts the class
-                Ok(comp1.as_ref() == &ClassExpression::Class(Class::new(class2.iri().as_str())))
-            }
-            (ClassExpression::Class(class1), ClassExpression::ObjectComplementOf(comp2)) => {
-                // Check if complement contradicts the class
-                Ok(&ClassExpression::Class(Class::new(class1.iri().as_str())) == comp2.as_ref())
-            }
-            (
-                ClassExpression::ObjectComplementOf(comp1),
-                ClassExpression::ObjectComplementOf(comp2),
-            ) => {
-                // Check if complements are of the same class
-                Ok(comp1.as_ref() == comp2.as_ref())
-            }
             // Check for bottom (Nothing) and top (Thing) contradictions
             (ClassExpression::Class(class), _)
                 if class.iri().as_str() == "http://www.w3.org/2002/07/owl#Nothing" =>
@@ -1362,16 +2258,36 @@ impl TableauxReasoner {
             {
                 Ok(true)
             }
-            _ => Ok(false),
+            _ => Ok(Self::are_complementary(concept1, concept2)),
         }
     }
 
+    /// Check whether one concept is the complement of the other
+    ///
+    /// Both sides are compared in negation normal form, so `∃R.C` and `¬∃R.C`,
+    /// `A ⊓ B` and `¬A ⊔ ¬B`, or `¬¬C` and `¬C` are complements. Only pairs whose
+    /// outermost constructors are dual are compared.
+    fn are_complementary(concept1: &ClassExpression, concept2: &ClassExpression) -> bool {
+        use ClassExpression::*;
+        let dual = matches!(
+            (concept1, concept2),
+            (ObjectComplementOf(_), _)
+                | (_, ObjectComplementOf(_))
+                | (ObjectSomeValuesFrom(..), ObjectAllValuesFrom(..))
+                | (ObjectAllValuesFrom(..), ObjectSomeValuesFrom(..))
+                | (ObjectIntersectionOf(_), ObjectUnionOf(_))
+                | (ObjectUnionOf(_), ObjectIntersectionOf(_))
+        );
+        dual && concept1.semantic_key() == concept2.negate().semantic_key()
+    }
+
     /// Extract the class name from a class expression
-    #[allow(clippy::only_used_in_recursion)]
+    ///
+    /// Only named classes count: `¬A` and `¬B` do not clash when `A` and `B`
+    /// are disjoint, nor do `A` and `¬B`.
     fn extract_class_name(&self, concept: &ClassExpression) -> OwlResult<Option<IRI>> {
         match concept {
             ClassExpression::Class(class) => Ok(Some((**class.iri()).clone())),
-            ClassExpression::ObjectComplementOf(comp) => self.extract_class_name(comp.as_ref()),
             _ => Ok(None),
         }
     }
@@ -1385,10 +2301,13 @@ impl TableauxReasoner {
     ) -> Vec<NodeId> {
         let mut new_nodes = Vec::new();
 
-        // Check all edges from the current node
+        // Check all edges of the current node; successors for an inverse role
+        // point back to it
         for edge in graph.edges.get_all_edges() {
             if edge.0 == node_id && !expanded_nodes.contains(&edge.2) {
                 new_nodes.push(edge.2);
+            } else if edge.2 == node_id && !expanded_nodes.contains(&edge.0) {
+                new_nodes.push(edge.0);
             }
         }
 
@@ -138,7 +138,7 @@ pub struct BacktrackPoint {
 }
 
 /// Backtracking statistics
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct BacktrackStats {
     pub total_backtracks: usize,
     pub dependency_directed_backtracks: usize,
@@ -1,13 +1,15 @@
 //! Class expression expansion rules
 //!
 //! Implements tableaux rules for class expressions including conjunction,
-//! disjunction, existential restrictions, universal restrictions, and nominals.
+//! disjunction, existential restrictions, universal restrictions, nominals,
+//! minimum cardinality restrictions, self restrictions and value restrictions.
 
 use super::context::ExpansionContext;
 use super::types::{ExpansionRule, ExpansionTask};
 use crate::axioms::class_expressions::ClassExpression;
+use crate::iri::IRI;
 use crate::reasoning::tableaux::{
-    core::NodeId,
+    core::{NodeId, TableauxReasoner},
     graph::{GraphChange, GraphChangeLog, TableauxGraph},
     memory::MemoryManager,
 };
@@ -85,6 +87,33 @@ pub fn apply_class_rules(
                 class_expression,
             )?);
         }
+        ExpansionRule::MinCardinality => {
+            tasks.extend(apply_min_cardinality_rule(
+                graph,
+                memory_manager,
+                context,
+                change_log,
+                node_id,
+                class_expression,
+            )?);
+        }
+        ExpansionRule::HasSelf => {
+            tasks.extend(apply_has_self_rule(
+                graph,
+                change_log,
+                node_id,
+                class_expression,
+            )?);
+        }
+        ExpansionRule::HasValue => {
+            tasks.extend(apply_has_value_rule(
+                graph,
+                context,
+                change_log,
+                node_id,
+                class_expression,
+            )?);
+        }
         _ => {
             // Not a class expression rule
         }
@@ -93,6 +122,19 @@ pub fn apply_class_rules(
     Ok(tasks)
 }
 
+/// Rules that expand class expressions, in the order they are tried
+const CLASS_EXPRESSION_RULES: [ExpansionRule; 9] = [
+    ExpansionRule::Conjunction,
+    ExpansionRule::Disjunction,
+    ExpansionRule::ExistentialRestriction,
+    ExpansionRule::UniversalRestriction,
+    ExpansionRule::Nominal,
+    ExpansionRule::DataRange,
+    ExpansionRule::MinCardinality,
+    ExpansionRule::HasSelf,
+    ExpansionRule::HasValue,
+];
+
 /// Apply conjunction rule: C1 ∧ C2 ⇒ add C1 and C2 to the node
 fn apply_conjunction_rule(
     graph: &mut TableauxGraph,
@@ -110,17 +152,12 @@ fn apply_conjunction_rule(
             if !graph.node_has_class_expression(node_id, conjunct) {
                 let change = GraphChange::AddConcept {
                     node_id,
-                    concept: conjunct.clone(),
+                    concept: Box::new((**conjunct).clone()),
                 };
                 change_log.record(change);
 
                 graph.add_class_expression_to_node(node_id, (**conjunct).clone())?;
-
-                // Create task for expanding the conjunct
-                let task = ExpansionTask::new(ExpansionRule::Conjunction, node_id)
-                    .with_class_expression((**conjunct).clone())
-                    .with_depth(context.current_depth + 1);
-                tasks.push(task);
+                tasks.extend(concept_tasks(node_id, conjunct, context.current_depth + 1));
             }
         }
     }
@@ -182,72 +219,66 @@ fn apply_existential_restriction_rule(
     let mut tasks = Vec::new();
 
     if let ClassExpression::ObjectSomeValuesFrom(property, filler) = class_expression {
-        // Extract property IRI from property expression
-        let property_iri = match &**property {
-            crate::axioms::property_expressions::ObjectPropertyExpression::ObjectProperty(
-                obj_prop,
-            ) => (**obj_prop.iri()).clone(),
-            crate::axioms::property_expressions::ObjectPropertyExpression::ObjectInverseOf(
-                _inv_prop,
-            ) => {
-                // For inverse properties, we need to handle them differently
-                // For now, create a placeholder IRI
-                crate::iri::IRI::new("http://example.org/inverse").unwrap()
-            }
+        // An inverse role is stored as an edge from the successor back to the node
+        let (is_inverse, property_iri) = TableauxReasoner::resolve_property_direction(property);
+        let property_iri = property_iri.clone();
+        let neighbours = if is_inverse {
+            graph.get_predecessors(node_id, &property_iri)
+        } else {
+            graph.get_successors(node_id, &property_iri)
         };
 
         // Check if we already have a suitable successor
-        if let Some(existing_successors) = graph.get_successors(node_id, &property_iri) {
-            let mut suitable_successor = None;
-
-            for &successor_id in existing_successors {
-                if graph.node_has_class_expression(successor_id, filler) {
-                    suitable_successor = Some(successor_id);
-                    break;
-                }
-            }
-
-            if let Some(id) = suitable_successor {
-                // Found a suitable existing successor
-                let task = ExpansionTask::new(ExpansionRule::ExistentialRestriction, id)
-                    .with_class_expression((**filler).clone())
-                    .with_depth(context.current_depth + 1);
-                tasks.push(task);
-                return Ok(tasks);
-            }
+        if let Some(id) = neighbours
+            .into_iter()
+            .find(|&neighbour| graph.node_has_class_expression(neighbour, filler))
+        {
+            // Found a suitable existing successor
+            tasks.extend(concept_tasks(id, filler, context.current_depth + 1));
+            return Ok(tasks);
         }
 
         // Create new successor node (no suitable successor found)
         let new_node_id = graph.add_node();
 
-        // Add edge from current node to new node
+        // Add edge between the current node and the new node
+        let (from, to) = if is_inverse {
+            (new_node_id, node_id)
+        } else {
+            (node_id, new_node_id)
+        };
         let edge_change = GraphChange::AddEdge {
-            from: node_id,
+            from,
             property: property_iri.clone(),
-            to: new_node_id,
+            to,
         };
         change_log.record(edge_change);
 
-        graph.add_edge(node_id, &property_iri, new_node_id);
+        graph.add_edge(from, &property_iri, to);
 
         // Add filler class expression to new node
         let concept_change = GraphChange::AddConcept {
             node_id: new_node_id,
-            concept: filler.clone(),
+            concept: Box::new((**filler).clone()),
         };
         change_log.record(concept_change);
 
         graph.add_class_expression_to_node(new_node_id, (**filler).clone())?;
 
-        // Create task for expanding the filler
-        let task = ExpansionTask::new(ExpansionRule::ExistentialRestriction, new_node_id)
-            .with_class_expression((**filler).clone())
-            .with_depth(context.current_depth + 1);
-        tasks.push(task);
-
-        // Record that this existential restriction has been processed
-        let task_key = (node_id, ExpansionRule::ExistentialRestriction);
-        context.applied_rules.insert(task_key);
+        // Create tasks for expanding the filler
+        tasks.extend(concept_tasks(
+            new_node_id,
+            filler,
+            context.current_depth + 1,
+        ));
+        tasks.extend(apply_universals_to_new_neighbour(
+            graph,
+            change_log,
+            context.current_depth + 1,
+            node_id,
+            (is_inverse, &property_iri),
+            new_node_id,
+        ));
     }
 
     Ok(tasks)
@@ -265,49 +296,35 @@ fn apply_universal_restriction_rule(
     let mut tasks = Vec::new();
 
     if let ClassExpression::ObjectAllValuesFrom(property, filler) = class_expression {
-        // Extract property IRI from property expression
-        let property_iri = match &**property {
-            crate::axioms::property_expressions::ObjectPropertyExpression::ObjectProperty(
-                obj_prop,
-            ) => (**obj_prop.iri()).clone(),
-            crate::axioms::property_expressions::ObjectPropertyExpression::ObjectInverseOf(
-                _inv_prop,
-            ) => {
-                // For inverse properties, we need to handle them differently
-                // For now, create a placeholder IRI
-                crate::iri::IRI::new("http://example.org/inverse").unwrap()
-            }
+        // Get all r-neighbours of the current node; for an inverse role these
+        // are the nodes with an edge back to it. Neighbours created later get
+        // the filler from the rule that creates them.
+        let (is_inverse, property_iri) = TableauxReasoner::resolve_property_direction(property);
+        let successors = if is_inverse {
+            graph.get_predecessors(node_id, property_iri)
+        } else {
+            graph.get_successors(node_id, property_iri)
         };
 
-        // Get all r-successors of the current node
-        let successors = graph
-            .get_successors(node_id, &property_iri)
-            .map(|slice| slice.to_vec())
-            .unwrap_or_default();
-
         // Add filler class expression to each successor if not already present
         for successor_node_id in successors {
             if !graph.node_has_class_expression(successor_node_id, filler) {
                 // Record the change in the change log
                 change_log.record(GraphChange::AddConcept {
                     node_id: successor_node_id,
-                    concept: filler.clone(),
+                    concept: Box::new((**filler).clone()),
                 });
 
                 graph.add_class_expression_to_node(successor_node_id, (**filler).clone())?;
 
-                // Create task for expanding the filler in the successor
-                let task =
-                    ExpansionTask::new(ExpansionRule::UniversalRestriction, successor_node_id)
-                        .with_class_expression((**filler).clone())
-                        .with_depth(context.current_depth + 1);
-                tasks.push(task);
+                // Create tasks for expanding the filler in the successor
+                tasks.extend(concept_tasks(
+                    successor_node_id,
+                    filler,
+                    context.current_depth + 1,
+                ));
             }
         }
-
-        // Record that this universal restriction has been processed for this node
-        let task_key = (node_id, ExpansionRule::UniversalRestriction);
-        context.applied_rules.insert(task_key);
     }
 
     Ok(tasks)
@@ -368,6 +385,208 @@ fn apply_data_range_rule(
     Ok(Vec::new())
 }
 
+/// Apply minimum cardinality rule: ≥n r ⇒ ensure n pairwise different r-neighbours
+///
+/// Existing neighbours already known to be mutually different are reused; the
+/// missing ones are created and registered as different from each other and
+/// from the reused ones, so a later max-cardinality check cannot merge them.
+fn apply_min_cardinality_rule(
+    graph: &mut TableauxGraph,
+    _memory_manager: &mut MemoryManager,
+    context: &mut ExpansionContext,
+    change_log: &mut GraphChangeLog,
+    node_id: NodeId,
+    class_expression: &ClassExpression,
+) -> crate::error::OwlResult<Vec<ExpansionTask>> {
+    let Some(restriction) = class_expression.as_object_cardinality() else {
+        return Ok(Vec::new());
+    };
+    let Some(min) = restriction.min() else {
+        return Ok(Vec::new());
+    };
+    let min = min as usize;
+
+    let (is_inverse, property_iri) =
+        TableauxReasoner::resolve_property_direction(restriction.property);
+    let property_iri = property_iri.clone();
+
+    let neighbours = TableauxReasoner::cardinality_neighbours(node_id, &restriction, graph);
+
+    let mut distinct: Vec<NodeId> = Vec::new();
+    for neighbour in neighbours {
+        if distinct
+            .iter()
+            .all(|&other| graph.are_different(other, neighbour))
+        {
+            distinct.push(neighbour);
+        }
+    }
+
+    let mut tasks = Vec::new();
+    while distinct.len() < min {
+        let new_node_id = graph.add_node_logged(change_log);
+        if is_inverse {
+            graph.add_edge_logged(new_node_id, &property_iri, node_id, change_log);
+        } else {
+            graph.add_edge_logged(node_id, &property_iri, new_node_id, change_log);
+        }
+        for &other in &distinct {
+            graph.add_inequality_logged(other, new_node_id, change_log);
+        }
+        if restriction.is_qualified()
+            && graph.add_concept_logged(new_node_id, restriction.filler.clone(), change_log)
+        {
+            tasks.extend(concept_tasks(
+                new_node_id,
+                restriction.filler,
+                context.current_depth + 1,
+            ));
+        }
+        tasks.extend(apply_universals_to_new_neighbour(
+            graph,
+            change_log,
+            context.current_depth + 1,
+            node_id,
+            (is_inverse, &property_iri),
+            new_node_id,
+        ));
+        distinct.push(new_node_id);
+    }
+
+    Ok(tasks)
+}
+
+/// Apply self restriction rule: ∃r.Self ⇒ add an r-edge from the node to itself
+///
+/// A self loop reads the same both ways, so an inverse property needs no
+/// special handling.
+fn apply_has_self_rule(
+    graph: &mut TableauxGraph,
+    change_log: &mut GraphChangeLog,
+    node_id: NodeId,
+    class_expression: &ClassExpression,
+) -> crate::error::OwlResult<Vec<ExpansionTask>> {
+    if let ClassExpression::ObjectHasSelf(property) = class_expression {
+        let (_, property_iri) = TableauxReasoner::resolve_property_direction(property);
+        let property_iri = property_iri.clone();
+        let has_loop = graph
+            .get_successors(node_id, &property_iri)
+            .contains(&node_id);
+        if !has_loop {
+            graph.add_edge_logged(node_id, &property_iri, node_id, change_log);
+        }
+    }
+
+    Ok(Vec::new())
+}
+
+/// Apply value restriction rule: ∃r.{a} ⇒ relate the node to the node of `a`
+///
+/// A neighbour that already is `a`, directly or through sameAs, satisfies the
+/// restriction. Otherwise the node of `a` is linked, and created when the
+/// tableau has none yet. Anonymous values are left to the nominal rule.
+fn apply_has_value_rule(
+    graph: &mut TableauxGraph,
+    context: &mut ExpansionContext,
+    change_log: &mut GraphChangeLog,
+    node_id: NodeId,
+    class_expression: &ClassExpression,
+) -> crate::error::OwlResult<Vec<ExpansionTask>> {
+    let ClassExpression::ObjectHasValue(property, individual) = class_expression else {
+        return Ok(Vec::new());
+    };
+    let Some(individual_iri) = individual.iri() else {
+        return Ok(Vec::new());
+    };
+
+    let (is_inverse, property_iri) = TableauxReasoner::resolve_property_direction(property);
+    let property_iri = property_iri.clone();
+    let neighbours = if is_inverse {
+        graph.get_predecessors(node_id, &property_iri)
+    } else {
+        graph.get_successors(node_id, &property_iri)
+    };
+    if neighbours
+        .iter()
+        .any(|&neighbour| graph.is_equal_to_individual(neighbour, individual_iri))
+    {
+        return Ok(Vec::new());
+    }
+
+    let value_node = match graph.get_node_for_individual(individual_iri) {
+        Some(existing) => existing,
+        None => {
+            let new_node_id = graph.add_node_logged(change_log);
+            graph.associate_node_with_individual(new_node_id, individual_iri)?;
+            new_node_id
+        }
+    };
+    if is_inverse {
+        graph.add_edge_logged(value_node, &property_iri, node_id, change_log);
+    } else {
+        graph.add_edge_logged(node_id, &property_iri, value_node, change_log);
+    }
+
+    Ok(apply_universals_to_new_neighbour(
+        graph,
+        change_log,
+        context.current_depth + 1,
+        node_id,
+        (is_inverse, &property_iri),
+        value_node,
+    ))
+}
+
+/// Tasks for every rule that can expand a concept added to a node
+fn concept_tasks(node_id: NodeId, concept: &ClassExpression, depth: u32) -> Vec<ExpansionTask> {
+    CLASS_EXPRESSION_RULES
+        .into_iter()
+        .filter(|&rule| can_apply_rule(rule, concept))
+        .map(|rule| {
+            ExpansionTask::new(rule, node_id)
+                .with_class_expression(concept.clone())
+                .with_depth(depth)
+        })
+        .collect()
+}
+
+/// Add the fillers of the node's universal restrictions to a new r-neighbour
+///
+/// The universal rule only reaches the neighbours present when it fires, so
+/// every rule relating the node to a neighbour by `r` (given with its
+/// direction) calls this to apply the `∀r.C` already on the node.
+fn apply_universals_to_new_neighbour(
+    graph: &mut TableauxGraph,
+    change_log: &mut GraphChangeLog,
+    depth: u32,
+    node_id: NodeId,
+    (is_inverse, property_iri): (bool, &IRI),
+    neighbour: NodeId,
+) -> Vec<ExpansionTask> {
+    let fillers: Vec<ClassExpression> = graph
+        .get_node_class_expressions(node_id)
+        .into_iter()
+        .filter_map(|concept| match concept {
+            ClassExpression::ObjectAllValuesFrom(property, filler) => {
+                let (universal_inverse, universal_iri) =
+                    TableauxReasoner::resolve_property_direction(&property);
+                (universal_inverse == is_inverse
+                    && graph.is_sub_property(property_iri, universal_iri))
+                .then(|| (*filler).clone())
+            }
+            _ => None,
+        })
+        .collect();
+
+    let mut tasks = Vec::new();
+    for filler in fillers {
+        if graph.add_concept_logged(neighbour, filler.clone(), change_log) {
+            tasks.extend(concept_tasks(neighbour, &filler, depth));
+        }
+    }
+    tasks
+}
+
 /// Check if a class expression can be expanded with the given rule
 pub fn can_apply_rule(rule: ExpansionRule, class_expression: &ClassExpression) -> bool {
     match rule {
@@ -392,6 +611,15 @@ pub fn can_apply_rule(rule: ExpansionRule, class_expression: &ClassExpression) -
         ExpansionRule::DataRange => {
             matches!(class_expression, ClassExpression::DataSomeValuesFrom(_, _))
         }
+        ExpansionRule::MinCardinality => class_expression
+            .as_object_cardinality()
+            .is_some_and(|restriction| restriction.min().is_some()),
+        ExpansionRule::HasSelf => {
+            matches!(class_expression, ClassExpression::ObjectHasSelf(_))
+        }
+        ExpansionRule::HasValue => {
+            matches!(class_expression, ClassExpression::ObjectHasValue(_, _))
+        }
         _ => false,
     }
 }
@@ -3,6 +3,7 @@
 //! Provides tracking and management of expansion state during tableaux reasoning.
 
 use super::types::{ExpansionRule, ExpansionTask};
+use crate::axioms::class_expressions::ClassExpression;
 use crate::reasoning::tableaux::core::NodeId;
 use std::collections::{HashSet, VecDeque};
 
@@ -13,8 +14,8 @@ pub struct ExpansionContext {
     pub current_node: NodeId,
     /// Current expansion depth
     pub current_depth: u32,
-    /// Rules already applied to avoid repetition
-    pub applied_rules: HashSet<(NodeId, ExpansionRule)>,
+    /// Rules already applied to a concept on a node, to avoid repetition
+    pub applied_rules: HashSet<(NodeId, ExpansionRule, Option<ClassExpression>)>,
     /// Pending expansion tasks
     pub pending_expansions: VecDeque<ExpansionTask>,
     /// Branch points created during expansion
@@ -66,14 +67,16 @@ impl ExpansionContext {
         }
     }
 
-    /// Check if a rule has already been applied to a node
-    pub fn has_rule_applied(&self, node_id: NodeId, rule: ExpansionRule) -> bool {
-        self.applied_rules.contains(&(node_id, rule))
+    /// Check if the rule of a task has already been applied to its concept
+    pub fn has_rule_applied(&self, task: &ExpansionTask) -> bool {
+        self.applied_rules
+            .contains(&(task.node_id, task.rule, task.class_expression.clone()))
     }
 
-    /// Mark a rule as applied to a node
-    pub fn mark_rule_applied(&mut self, node_id: NodeId, rule: ExpansionRule) {
-        self.applied_rules.insert((node_id, rule));
+    /// Mark the rule of a task as applied to its concept
+    pub fn mark_rule_applied(&mut self, task: &ExpansionTask) {
+        self.applied_rules
+            .insert((task.node_id, task.rule, task.class_expression.clone()));
     }
 
     /// Add an expansion task to the pending queue
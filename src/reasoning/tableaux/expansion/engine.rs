@@ -5,11 +5,15 @@
 use super::class_rules;
 use super::context::{ExpansionContext, ExpansionStats};
 use super::types::{ExpansionRule, ExpansionTask};
+use crate::axioms::class_expressions::ClassExpression;
 use crate::reasoning::tableaux::{
+    blocking::{BlockingManager, BlockingStrategy},
     core::NodeId,
     graph::{GraphChangeLog, TableauxGraph},
     memory::MemoryManager,
 };
+use std::collections::HashMap;
+use std::sync::Arc;
 
 /// Tableaux expansion rules collection
 #[derive(Debug)]
@@ -43,6 +47,9 @@ impl ExpansionRules {
         enabled_rules.insert(ExpansionRule::NegativePropertyAssertion);
         enabled_rules.insert(ExpansionRule::SameIndividual);
         enabled_rules.insert(ExpansionRule::DifferentIndividuals);
+        enabled_rules.insert(ExpansionRule::MinCardinality);
+        enabled_rules.insert(ExpansionRule::HasSelf);
+        enabled_rules.insert(ExpansionRule::HasValue);
 
         Self { enabled_rules }
     }
@@ -75,7 +82,10 @@ impl Default for ExpansionRules {
 }
 
 /// Tableaux expansion engine
-#[derive(Debug)]
+///
+/// Cloning an engine shares its indexed TBox, so one engine can be prepared
+/// per ontology and cloned for each tableau.
+#[derive(Debug, Clone)]
 pub struct ExpansionEngine {
     /// Maximum expansion depth
     max_depth: u32,
@@ -85,6 +95,12 @@ pub struct ExpansionEngine {
     stats: ExpansionStats,
     /// Reasoning rules to apply during expansion
     reasoning_rules: Option<crate::reasoning::tableaux::ReasoningRules>,
+    /// TBox consequences of each concept, added lazily to nodes containing it
+    unfoldings: Arc<HashMap<ClassExpression, Vec<ClassExpression>>>,
+    /// Superclasses of `owl:Thing`, which hold on every node
+    universal_concepts: Arc<Vec<ClassExpression>>,
+    /// Blocking that holds back successor-generating rules
+    blocking_strategy: BlockingStrategy,
 }
 
 impl ExpansionEngine {
@@ -100,18 +116,90 @@ impl ExpansionEngine {
             max_expansions,
             stats: ExpansionStats::default(),
             reasoning_rules: None,
+            unfoldings: Arc::new(HashMap::new()),
+            universal_concepts: Arc::new(Vec::new()),
+            blocking_strategy: BlockingStrategy::Subset,
         }
     }
 
+    /// Use a different blocking strategy than the default subset blocking
+    pub fn with_blocking_strategy(mut self, strategy: BlockingStrategy) -> Self {
+        self.blocking_strategy = strategy;
+        self
+    }
+
     /// Create expansion engine with reasoning rules
     pub fn with_reasoning_rules(
         mut self,
         rules: crate::reasoning::tableaux::ReasoningRules,
     ) -> Self {
+        self.build_unfoldings(&rules);
         self.reasoning_rules = Some(rules);
         self
     }
 
+    /// Index the TBox for lazy unfolding
+    ///
+    /// `A ⊑ D` with a named `A` makes `D` a consequence of `A`. `A ≡ C` makes
+    /// `C` a consequence of `A` and `¬C` (in negation normal form) a consequence
+    /// of `¬A`. A general concept inclusion `C ⊑ D` is internalized as
+    /// `⊤ ⊑ ¬C ⊔ D`, which holds on every node.
+    fn build_unfoldings(&mut self, rules: &crate::reasoning::tableaux::ReasoningRules) {
+        let mut unfoldings = HashMap::new();
+        let mut universal_concepts = Vec::new();
+
+        for axiom in &rules.subclass_rules {
+            match axiom.sub_class() {
+                thing if thing.is_thing() => {
+                    universal_concepts.push(axiom.super_class().clone());
+                }
+                ClassExpression::Class(_) => {
+                    Self::add_unfolding(
+                        &mut unfoldings,
+                        axiom.sub_class().clone(),
+                        axiom.super_class().clone(),
+                    );
+                }
+                general => {
+                    let internalized = ClassExpression::ObjectUnionOf(
+                        vec![
+                            Arc::new(general.negate()),
+                            Arc::new(axiom.super_class().clone()),
+                        ]
+                        .into(),
+                    );
+                    if !universal_concepts.contains(&internalized) {
+                        universal_concepts.push(internalized);
+                    }
+                }
+            }
+        }
+
+        for axiom in &rules.equivalence_rules {
+            let operands = axiom.class_expressions();
+            for defined in operands.iter().filter(|op| op.is_named()) {
+                for operand in operands.iter().filter(|op| *op != defined) {
+                    Self::add_unfolding(&mut unfoldings, defined.clone(), operand.clone());
+                    Self::add_unfolding(&mut unfoldings, defined.negate(), operand.negate());
+                }
+            }
+        }
+
+        self.unfoldings = Arc::new(unfoldings);
+        self.universal_concepts = Arc::new(universal_concepts);
+    }
+
+    fn add_unfolding(
+        unfoldings: &mut HashMap<ClassExpression, Vec<ClassExpression>>,
+        concept: ClassExpression,
+        consequence: ClassExpression,
+    ) {
+        let consequences = unfoldings.entry(concept).or_default();
+        if !consequences.contains(&consequence) {
+            consequences.push(consequence);
+        }
+    }
+
     /// Perform expansion on the tableau graph
     pub fn expand(
         &mut self,
@@ -131,26 +219,55 @@ impl ExpansionEngine {
         // Add initial class expressions from root node
         self.add_initial_tasks(&mut context, graph, root_node)?;
 
-        // Main expansion loop
-        while let Some(task) = context.next_task() {
-            if context.expansion_count >= self.max_expansions {
-                break;
+        // Main expansion loop; once the queue drains, unfold the TBox into the
+        // graph and keep expanding until unfolding adds nothing new
+        let mut blocked_tasks = Vec::new();
+        loop {
+            while let Some(task) = context.next_task() {
+                if context.expansion_count >= self.max_expansions {
+                    break;
+                }
+
+                if task.depth > self.max_depth {
+                    continue;
+                }
+
+                // Successor-generating rules wait while an ancestor already
+                // covers the node, so cyclic definitions terminate
+                if self.is_blocked(graph, &task) {
+                    blocked_tasks.push(task);
+                    continue;
+                }
+
+                // Apply the task
+                let new_tasks =
+                    self.apply_task(graph, memory_manager, &mut context, &mut change_log, task)?;
+
+                // Add new tasks to context
+                for new_task in new_tasks {
+                    context.add_task(new_task);
+                }
+
+                context.increment_expansion_count();
             }
 
-            if task.depth > self.max_depth {
-                continue;
+            if context.expansion_count >= self.max_expansions {
+                break;
             }
+            let unfolded = self.unfold_concepts(graph, &mut context, &mut change_log)?;
 
-            // Apply the task
-            let new_tasks =
-                self.apply_task(graph, memory_manager, &mut context, &mut change_log, task)?;
+            // Unfolding may have grown a blocked node beyond its blocker
+            let (unblocked, still_blocked): (Vec<_>, Vec<_>) = blocked_tasks
+                .into_iter()
+                .partition(|task| !self.is_blocked(graph, task));
+            blocked_tasks = still_blocked;
 
-            // Add new tasks to context
-            for new_task in new_tasks {
-                context.add_task(new_task);
+            if unfolded == 0 && unblocked.is_empty() {
+                break;
+            }
+            for task in unblocked {
+                context.add_task(task);
             }
-
-            context.increment_expansion_count();
         }
 
         // Update statistics
@@ -172,29 +289,89 @@ impl ExpansionEngine {
 
     // Private methods
 
-    /// Add initial tasks for the root node
+    /// Add initial tasks for every node, starting with the root
+    ///
+    /// Nodes other than the root carry concepts when the graph was seeded with
+    /// several individuals or a disjunct was chosen on a branch.
     fn add_initial_tasks(
         &self,
         context: &mut ExpansionContext,
         graph: &TableauxGraph,
         root_node: NodeId,
     ) -> crate::error::OwlResult<()> {
-        // Get all class expressions for the root node
-        let class_expressions = graph.get_node_class_expressions(root_node);
-
-        for class_expression in class_expressions {
-            // Determine applicable rules
-            for rule in self.get_applicable_rules(&class_expression) {
-                let task = ExpansionTask::new(rule, root_node)
-                    .with_class_expression(class_expression.clone())
-                    .with_depth(1);
-                context.add_task(task);
+        let node_ids = std::iter::once(root_node).chain(
+            graph
+                .nodes_iter()
+                .map(|(node_id, _)| node_id)
+                .filter(|&node_id| node_id != root_node),
+        );
+
+        for node_id in node_ids {
+            for class_expression in graph.get_node_class_expressions(node_id) {
+                // Determine applicable rules
+                for rule in self.get_applicable_rules(&class_expression) {
+                    let task = ExpansionTask::new(rule, node_id)
+                        .with_class_expression(class_expression.clone())
+                        .with_depth(1);
+                    context.add_task(task);
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Add the TBox consequences of every concept on every node
+    ///
+    /// Tasks for expanding the added concepts are queued on the context;
+    /// returns how many concepts were added.
+    fn unfold_concepts(
+        &self,
+        graph: &mut TableauxGraph,
+        context: &mut ExpansionContext,
+        change_log: &mut GraphChangeLog,
+    ) -> crate::error::OwlResult<usize> {
+        let mut unfoldings = Vec::new();
+        for (node_id, node) in graph.nodes_iter() {
+            let consequences = node
+                .concepts_iter()
+                .filter_map(|concept| self.unfoldings.get(concept))
+                .flatten()
+                .chain(self.universal_concepts.iter());
+            for consequence in consequences {
+                if !node.contains_concept(consequence) {
+                    unfoldings.push((node_id, consequence.clone()));
+                }
+            }
+        }
+
+        let mut added = 0;
+        for (node_id, concept) in unfoldings {
+            if !graph.add_concept_logged(node_id, concept.clone(), change_log) {
+                continue;
+            }
+            added += 1;
+            for rule in self.get_applicable_rules(&concept) {
+                let task = ExpansionTask::new(rule, node_id)
+                    .with_class_expression(concept.clone())
+                    .with_depth(context.current_depth + 1);
+                context.add_task(task);
+            }
+        }
+
+        Ok(added)
+    }
+
+    /// Check whether a successor-generating task sits on a blocked node
+    fn is_blocked(&self, graph: &TableauxGraph, task: &ExpansionTask) -> bool {
+        matches!(
+            task.rule,
+            ExpansionRule::ExistentialRestriction | ExpansionRule::MinCardinality
+        ) && BlockingManager::new(self.blocking_strategy.clone())
+            .detect_blocking(task.node_id, graph)
+            .is_some()
+    }
+
     /// Apply a single expansion task
     fn apply_task(
         &mut self,
@@ -204,8 +381,8 @@ impl ExpansionEngine {
         change_log: &mut GraphChangeLog,
         task: ExpansionTask,
     ) -> crate::error::OwlResult<Vec<ExpansionTask>> {
-        // Check if rule already applied to this node
-        if context.has_rule_applied(task.node_id, task.rule) {
+        // Check if the rule was already applied to this concept on the node
+        if context.has_rule_applied(&task) {
             return Ok(Vec::new());
         }
 
@@ -229,16 +406,13 @@ impl ExpansionEngine {
         };
 
         // Mark rule as applied
-        context.mark_rule_applied(task.node_id, task.rule);
+        context.mark_rule_applied(&task);
 
         Ok(result)
     }
 
     /// Get applicable rules for a class expression
-    fn get_applicable_rules(
-        &self,
-        class_expression: &crate::axioms::class_expressions::ClassExpression,
-    ) -> Vec<ExpansionRule> {
+    fn get_applicable_rules(&self, class_expression: &ClassExpression) -> Vec<ExpansionRule> {
         let mut rules = Vec::new();
 
         // Check each rule type
@@ -249,6 +423,9 @@ impl ExpansionEngine {
             ExpansionRule::UniversalRestriction,
             ExpansionRule::Nominal,
             ExpansionRule::DataRange,
+            ExpansionRule::MinCardinality,
+            ExpansionRule::HasSelf,
+            ExpansionRule::HasValue,
         ] {
             if class_rules::can_apply_rule(rule, class_expression) {
                 rules.push(rule);
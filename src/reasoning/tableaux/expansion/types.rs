@@ -3,7 +3,6 @@
 //! Contains the core data structures for representing expansion rules,
 //! tasks, and related metadata.
 
-
 /// Types of expansion rules
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ExpansionRule {
@@ -51,6 +50,12 @@ pub enum ExpansionRule {
     SameIndividual,
     /// Different individuals rule (inequality clash)
     DifferentIndividuals,
+    /// Minimum cardinality rule (creates pairwise different successors)
+    MinCardinality,
+    /// Self restriction rule (relates the node to itself)
+    HasSelf,
+    /// Value restriction rule (relates the node to a named individual)
+    HasValue,
 }
 
 impl ExpansionRule {
@@ -79,6 +84,9 @@ impl ExpansionRule {
             ExpansionRule::NegativePropertyAssertion => 20,
             ExpansionRule::SameIndividual => 21,
             ExpansionRule::DifferentIndividuals => 22,
+            ExpansionRule::MinCardinality => 23,
+            ExpansionRule::HasSelf => 24,
+            ExpansionRule::HasValue => 25,
         }
     }
 
@@ -107,6 +115,9 @@ impl ExpansionRule {
             ExpansionRule::NegativePropertyAssertion => "NegativePropertyAssertion",
             ExpansionRule::SameIndividual => "SameIndividual",
             ExpansionRule::DifferentIndividuals => "DifferentIndividuals",
+            ExpansionRule::MinCardinality => "MinCardinality",
+            ExpansionRule::HasSelf => "HasSelf",
+            ExpansionRule::HasValue => "HasValue",
         }
     }
 
@@ -119,7 +130,9 @@ impl ExpansionRule {
     pub fn creates_nodes(self) -> bool {
         matches!(
             self,
-            ExpansionRule::ExistentialRestriction | ExpansionRule::SameIndividual
+            ExpansionRule::ExistentialRestriction
+                | ExpansionRule::MinCardinality
+                | ExpansionRule::SameIndividual
         )
     }
 
@@ -166,6 +179,9 @@ impl ExpansionRule {
                 | ExpansionRule::UniversalRestriction
                 | ExpansionRule::Nominal
                 | ExpansionRule::DataRange
+                | ExpansionRule::MinCardinality
+                | ExpansionRule::HasSelf
+                | ExpansionRule::HasValue
         )
     }
 }
@@ -59,7 +59,7 @@ use super::core::{NodeId, TableauxNode};
 use crate::axioms::class_expressions::ClassExpression;
 use crate::error::OwlResult;
 use crate::iri::IRI;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 use smallvec::SmallVec;
 
 /// Represents a single mutation applied to the tableaux graph.
@@ -81,6 +81,10 @@ pub enum GraphChange {
         node_id: NodeId,
         label: String,
     },
+    AddInequality {
+        first: NodeId,
+        second: NodeId,
+    },
 }
 
 /// Ordered log of graph mutations so branches can be rolled back.
@@ -125,13 +129,19 @@ impl GraphChangeLog {
                 GraphChange::AddLabel { node_id, label } => {
                     graph.remove_label(*node_id, label);
                 }
+                GraphChange::AddInequality { first, second } => {
+                    graph.remove_inequality(*first, *second);
+                }
             }
         }
     }
 }
 
+/// An edge seen from one of its nodes: (neighbour, property, outgoing)
+pub type AdjacentEdge = (NodeId, IRI, bool);
+
 /// Optimized edge storage for tableaux graph
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct EdgeStorage {
     /// Optimized storage for edges using flat representation
     pub edges: Vec<(NodeId, IRI, NodeId)>,
@@ -139,6 +149,8 @@ pub struct EdgeStorage {
     pub index: HashMap<(NodeId, IRI), SmallVec<[NodeId; 4]>>,
     /// Reverse index for predecessor lookups: (to_node, property) -> Vec<from_node>
     pub reverse_index: HashMap<(NodeId, IRI), SmallVec<[NodeId; 4]>>,
+    /// Edges at each node in either direction: node -> Vec<AdjacentEdge>
+    pub adjacency: HashMap<NodeId, SmallVec<[AdjacentEdge; 4]>>,
 }
 
 impl EdgeStorage {
@@ -147,6 +159,7 @@ impl EdgeStorage {
             edges: Vec::new(),
             index: HashMap::default(),
             reverse_index: HashMap::default(),
+            adjacency: HashMap::default(),
         }
     }
 
@@ -164,6 +177,16 @@ impl EdgeStorage {
             .entry(reverse_key)
             .or_default()
             .push(from);
+
+        // Update adjacency
+        self.adjacency
+            .entry(from)
+            .or_default()
+            .push((to, property.clone(), true));
+        self.adjacency
+            .entry(to)
+            .or_default()
+            .push((from, property.clone(), false));
     }
 
     pub fn get_targets(&self, from: NodeId, property: &IRI) -> Option<&[NodeId]> {
@@ -176,6 +199,14 @@ impl EdgeStorage {
         self.reverse_index.get(&key).map(|vec| vec.as_slice())
     }
 
+    /// Edges at a node in either direction, as (neighbour, property, outgoing)
+    pub fn get_adjacent(&self, node: NodeId) -> &[AdjacentEdge] {
+        self.adjacency
+            .get(&node)
+            .map(|edges| edges.as_slice())
+            .unwrap_or_default()
+    }
+
     pub fn pop_edge(&mut self, from: NodeId, property: &IRI, to: NodeId) {
         if let Some(pos) = self
             .edges
@@ -206,6 +237,20 @@ impl EdgeStorage {
                 self.reverse_index.remove(&reverse_key);
             }
         }
+
+        for (node, neighbour, outgoing) in [(from, to, true), (to, from, false)] {
+            if let Some(adjacent) = self.adjacency.get_mut(&node) {
+                if let Some(idx) = adjacent
+                    .iter()
+                    .rposition(|(n, p, o)| *n == neighbour && p == property && *o == outgoing)
+                {
+                    adjacent.remove(idx);
+                }
+                if adjacent.is_empty() {
+                    self.adjacency.remove(&node);
+                }
+            }
+        }
     }
 
     pub fn get_all_edges(&self) -> &[(NodeId, IRI, NodeId)] {
@@ -216,6 +261,7 @@ impl EdgeStorage {
         self.edges.clear();
         self.index.clear();
         self.reverse_index.clear();
+        self.adjacency.clear();
     }
 
     pub fn len(&self) -> usize {
@@ -237,6 +283,7 @@ impl EdgeStorage {
         // Rebuild indices
         self.index.clear();
         self.reverse_index.clear();
+        self.adjacency.clear();
 
         for (from, property, to) in &self.edges {
             let forward_key = (*from, property.clone());
@@ -250,6 +297,15 @@ impl EdgeStorage {
                 .entry(reverse_key)
                 .or_insert_with(SmallVec::new)
                 .push(*from);
+
+            self.adjacency
+                .entry(*from)
+                .or_default()
+                .push((*to, property.clone(), true));
+            self.adjacency
+                .entry(*to)
+                .or_default()
+                .push((*from, property.clone(), false));
         }
     }
 }
@@ -271,11 +327,19 @@ impl ArenaStats {
 }
 
 /// Tableaux graph manager
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TableauxGraph {
     pub nodes: Vec<TableauxNode>,
     pub edges: EdgeStorage,
     pub root: NodeId,
+    /// Node pairs known to denote different individuals (stored as `(min, max)`)
+    inequalities: HashSet<(NodeId, NodeId)>,
+    /// Whether nodes keep their concepts in a stable order
+    ordered_concepts: bool,
+    /// Individuals named in sameAs axioms, each mapped to every member of its class
+    same_individuals: std::sync::Arc<HashMap<IRI, Vec<IRI>>>,
+    /// Object properties mapped to their strict sub-properties, transitively
+    sub_properties: std::sync::Arc<HashMap<IRI, Vec<IRI>>>,
 }
 
 impl TableauxGraph {
@@ -287,15 +351,31 @@ impl TableauxGraph {
             nodes,
             edges: EdgeStorage::new(),
             root,
+            inequalities: HashSet::new(),
+            ordered_concepts: false,
+            same_individuals: Default::default(),
+            sub_properties: Default::default(),
         }
     }
 
     pub fn add_node(&mut self) -> NodeId {
         let id = NodeId::new(self.nodes.len());
-        self.nodes.push(TableauxNode::new(id));
+        let mut node = TableauxNode::new(id);
+        if self.ordered_concepts {
+            node.keep_concept_order();
+        }
+        self.nodes.push(node);
         id
     }
 
+    /// Keep the concepts of every node, present and future, in a stable order
+    pub fn keep_concept_order(&mut self) {
+        self.ordered_concepts = true;
+        for node in &mut self.nodes {
+            node.keep_concept_order();
+        }
+    }
+
     pub fn add_node_logged(&mut self, log: &mut GraphChangeLog) -> NodeId {
         let node_id = self.add_node();
         log.record(GraphChange::AddNode { node_id });
@@ -333,16 +413,36 @@ impl TableauxGraph {
         self.edges.get_targets(from, property)
     }
 
+    /// Get the nodes with an edge to `to` on the property or one of its sub-properties
     pub fn get_predecessors(&self, to: NodeId, property: &IRI) -> Vec<NodeId> {
-        self.edges
-            .get_sources(to, property)
-            .map(|sources| sources.to_vec())
-            .unwrap_or_default()
+        self.related_nodes(property, |property| self.edges.get_sources(to, property))
+    }
+
+    /// Collect the nodes an edge lookup finds on a property and its sub-properties
+    fn related_nodes<'a>(
+        &'a self,
+        property: &'a IRI,
+        lookup: impl Fn(&IRI) -> Option<&'a [NodeId]>,
+    ) -> Vec<NodeId> {
+        let mut nodes = lookup(property)
+            .map(|nodes| nodes.to_vec())
+            .unwrap_or_default();
+        if let Some(sub_properties) = self.sub_properties.get(property) {
+            for sub_property in sub_properties {
+                for &node in lookup(sub_property).unwrap_or_default() {
+                    if !nodes.contains(&node) {
+                        nodes.push(node);
+                    }
+                }
+            }
+        }
+        nodes
     }
 
     pub fn clear(&mut self) {
         self.nodes.clear();
         self.edges.clear();
+        self.inequalities.clear();
         // Re-add root node
         self.root = NodeId::new(0);
         self.nodes.push(TableauxNode::new(self.root));
@@ -395,8 +495,13 @@ impl TableauxGraph {
         self.root
     }
 
-    pub fn get_successors(&self, node: NodeId, property: &IRI) -> Option<&[NodeId]> {
-        self.get_targets(node, property)
+    /// Get the targets of the node's edges on the property or one of its sub-properties
+    ///
+    /// An `S`-edge with `S ⊑ R` is an `R`-edge as well, see
+    /// [`set_sub_properties`](Self::set_sub_properties); use
+    /// [`get_targets`](Self::get_targets) for the edges on exactly `property`.
+    pub fn get_successors(&self, node: NodeId, property: &IRI) -> Vec<NodeId> {
+        self.related_nodes(property, |property| self.get_targets(node, property))
     }
 
     pub fn get_memory_stats(&self) -> super::core::MemoryStats {
@@ -494,6 +599,55 @@ impl TableauxGraph {
         }
     }
 
+    /// Merge node `from` into node `into`
+    ///
+    /// `into` receives the concepts, labels, edges and inequalities of `from`.
+    /// Node ids stay stable: `from` is left empty, without edges, and marked as
+    /// blocked by `into` so it is not expanded or reported in a model again.
+    pub fn merge_nodes(&mut self, from: NodeId, into: NodeId) {
+        if from == into || !self.has_node(from) || !self.has_node(into) {
+            return;
+        }
+
+        let merged = std::mem::replace(&mut self.nodes[from.as_usize()], TableauxNode::new(from));
+        for concept in merged.concepts_iter() {
+            if !self.nodes[into.as_usize()].contains_concept(concept) {
+                self.add_concept(into, concept.clone());
+            }
+        }
+        for label in merged.labels_iter() {
+            self.add_label(into, label.clone());
+        }
+        self.nodes[from.as_usize()].set_blocked_by(into);
+
+        let redirect = |node: NodeId| if node == from { into } else { node };
+        let mut seen = HashSet::new();
+        let edges: Vec<(NodeId, IRI, NodeId)> = self
+            .edges
+            .get_all_edges()
+            .iter()
+            .map(|(source, property, target)| {
+                (redirect(*source), property.clone(), redirect(*target))
+            })
+            .filter(|edge| seen.insert(edge.clone()))
+            .collect();
+        self.edges.clear();
+        for (source, property, target) in &edges {
+            self.edges.add_edge(*source, property, *target);
+        }
+
+        let inequalities: Vec<(NodeId, NodeId)> = self
+            .inequalities
+            .iter()
+            .filter(|(first, second)| *first == from || *second == from)
+            .copied()
+            .collect();
+        for (first, second) in inequalities {
+            self.inequalities.remove(&(first, second));
+            self.add_inequality(redirect(first), redirect(second));
+        }
+    }
+
     /// Check if a node exists in the graph
     pub fn has_node(&self, node_id: NodeId) -> bool {
         node_id.as_usize() < self.nodes.len()
@@ -555,14 +709,55 @@ impl TableauxGraph {
     }
 
     /// Get the node ID associated with an individual, if any
+    ///
+    /// A node labelled with an individual the given one is the same as is
+    /// returned too, see [`set_same_individuals`](Self::set_same_individuals).
     pub fn get_node_for_individual(&self, individual_iri: &IRI) -> Option<NodeId> {
-        let individual_str = individual_iri.as_str();
-        for (node_id, node) in self.nodes_iter() {
-            if node.labels_iter().any(|label| label == individual_str) {
-                return Some(node_id);
-            }
+        self.nodes_iter()
+            .find(|(_, node)| self.names_individual(node, individual_iri))
+            .map(|(node_id, _)| node_id)
+    }
+
+    /// Set the sameAs classes used to match nodes against individuals
+    ///
+    /// Each individual maps to every member of its class, itself included;
+    /// individuals without an entry are only the same as themselves.
+    pub fn set_same_individuals(
+        &mut self,
+        same_individuals: std::sync::Arc<HashMap<IRI, Vec<IRI>>>,
+    ) {
+        self.same_individuals = same_individuals;
+    }
+
+    /// Set the property hierarchy used to match edges against properties
+    ///
+    /// Each object property maps to all of its strict sub-properties, direct
+    /// or not; properties without an entry only match their own edges.
+    pub fn set_sub_properties(&mut self, sub_properties: std::sync::Arc<HashMap<IRI, Vec<IRI>>>) {
+        self.sub_properties = sub_properties;
+    }
+
+    /// Check whether an edge on `property` is an edge on `ancestor` as well
+    pub fn is_sub_property(&self, property: &IRI, ancestor: &IRI) -> bool {
+        property == ancestor
+            || self
+                .sub_properties
+                .get(ancestor)
+                .is_some_and(|sub_properties| sub_properties.contains(property))
+    }
+
+    /// Check whether a node denotes an individual, directly or through sameAs
+    pub fn is_equal_to_individual(&self, node_id: NodeId, individual_iri: &IRI) -> bool {
+        self.get_node(node_id)
+            .is_some_and(|node| self.names_individual(node, individual_iri))
+    }
+
+    fn names_individual(&self, node: &TableauxNode, individual_iri: &IRI) -> bool {
+        let has_label = |iri: &IRI| node.labels_iter().any(|label| label == iri.as_str());
+        match self.same_individuals.get(individual_iri) {
+            Some(names) => names.iter().any(has_label),
+            None => has_label(individual_iri),
         }
-        None
     }
 
     pub fn remove_label(&mut self, node_id: NodeId, label: &str) {
@@ -575,6 +770,47 @@ impl TableauxGraph {
         "Memory usage summary placeholder".to_string()
     }
 
+    /// Record that two nodes must denote different individuals
+    pub fn add_inequality(&mut self, node1: NodeId, node2: NodeId) {
+        if node1 != node2 {
+            self.inequalities.insert(Self::inequality_key(node1, node2));
+        }
+    }
+
+    /// Record an inequality and log it so it can be rolled back
+    pub fn add_inequality_logged(
+        &mut self,
+        node1: NodeId,
+        node2: NodeId,
+        log: &mut GraphChangeLog,
+    ) {
+        if node1 != node2 && self.inequalities.insert(Self::inequality_key(node1, node2)) {
+            log.record(GraphChange::AddInequality {
+                first: node1,
+                second: node2,
+            });
+        }
+    }
+
+    pub fn remove_inequality(&mut self, node1: NodeId, node2: NodeId) {
+        self.inequalities
+            .remove(&Self::inequality_key(node1, node2));
+    }
+
+    /// Check whether two nodes are known to denote different individuals
+    pub fn are_different(&self, node1: NodeId, node2: NodeId) -> bool {
+        self.inequalities
+            .contains(&Self::inequality_key(node1, node2))
+    }
+
+    fn inequality_key(node1: NodeId, node2: NodeId) -> (NodeId, NodeId) {
+        if node1 <= node2 {
+            (node1, node2)
+        } else {
+            (node2, node1)
+        }
+    }
+
     /// Check if a node has a specific class expression
     pub fn node_has_class_expression(&self, node_id: NodeId, class_expr: &ClassExpression) -> bool {
         self.get_node(node_id)
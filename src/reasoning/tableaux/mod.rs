@@ -14,6 +14,7 @@
 //! - **[`blocking`]** - Blocking strategies and constraint management
 //! - **[`dependency`]** - Dependency-directed backtracking
 //! - **[`expansion`]** - Rule expansion and application logic
+//! - **[`model`]** - Models read off clash-free tableaux
 //!
 //! ## Key Features
 //!
@@ -64,6 +65,7 @@ pub mod equality;
 pub mod expansion;
 pub mod graph;
 pub mod memory;
+pub mod model;
 pub mod parallel;
 
 // Reasoning result types
@@ -74,6 +76,10 @@ pub struct ReasoningResult {
     pub reasoning_time_ms: u64,
     pub nodes_expanded: usize,
     pub rules_applied: usize,
+    /// Blocks found while expanding, counted per blocking type
+    pub blocking_stats: BlockingStats,
+    /// Branches abandoned after a clash and choices tried
+    pub backtrack_stats: BacktrackStats,
 }
 
 impl Default for ReasoningResult {
@@ -84,6 +90,8 @@ impl Default for ReasoningResult {
             reasoning_time_ms: 0,
             nodes_expanded: 0,
             rules_applied: 0,
+            blocking_stats: BlockingStats::default(),
+            backtrack_stats: BacktrackStats::default(),
         }
     }
 }
@@ -96,6 +104,31 @@ pub struct ReasoningStats {
     pub memory_usage_bytes: usize,
     pub cache_hits: usize,
     pub cache_misses: usize,
+    /// Disjunction branches tried while answering the call
+    pub branches: usize,
+    /// Blocks found while answering the call, counted per blocking type
+    pub blocking: BlockingStats,
+    /// Branches abandoned after a clash while answering the call
+    pub backtracking: BacktrackStats,
+}
+
+/// One step of a tableau run, as recorded in the reasoning trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// A node was expanded and holds these concepts afterwards
+    Expanded {
+        node: NodeId,
+        concepts: Vec<crate::axioms::ClassExpression>,
+    },
+    /// A disjunct was tried on a node
+    Branch {
+        node: NodeId,
+        disjunct: Box<crate::axioms::ClassExpression>,
+    },
+    /// Two successors were merged to satisfy a max-cardinality restriction
+    Merge { from: NodeId, into: NodeId },
+    /// A clash was found on a node
+    Clash { node: NodeId },
 }
 
 // Re-export the main reasoners and types for backwards compatibility
@@ -105,11 +138,14 @@ pub use core::{
 pub use parallel::{ParallelReasoningCache, ParallelTableauxReasoner, WorkerConfig};
 
 // Re-export other essential types
-pub use blocking::{BlockingConstraint, BlockingManager, BlockingStats, BlockingStrategy};
-pub use dependency::{ChoicePoint, Dependency, DependencyManager};
+pub use blocking::{
+    BlockingConstraint, BlockingManager, BlockingStats, BlockingStrategy, BlockingType,
+};
+pub use dependency::{BacktrackStats, ChoicePoint, Dependency, DependencyManager};
 pub use expansion::{ExpansionEngine, ExpansionRules};
 pub use graph::{EdgeStorage, TableauxGraph};
 pub use memory::{
     ArenaEdgeStorage, ArenaManager, ArenaStats, ArenaTableauxGraph, LockFreeArenaNode,
     LockFreeMemoryManager, LockFreeMemoryStats, MemoryManager, MemoryOptimizationStats,
 };
+pub use model::{extract_model, CounterModel, Model, ModelIndividual, MODEL_NODE_NAMESPACE};
@@ -0,0 +1,315 @@
+//! Models read off a complete, clash-free tableau
+//!
+//! When the tableau finds no clash, the graph it built describes a model of the
+//! root concept: each node is an individual, the named classes and negated
+//! named classes on the node are its types, and each edge is a property value.
+//! [`Model::to_turtle`] writes the model out as RDF for inspection.
+
+use super::core::NodeId;
+use super::graph::TableauxGraph;
+use crate::axioms::{ClassExpression, DataPropertyExpression, DataRange, ObjectPropertyExpression};
+use crate::entities::{Individual, Literal};
+use crate::iri::IRI;
+use std::fmt::Write;
+
+/// Namespace of the IRIs standing for model nodes in the Turtle rendering
+pub const MODEL_NODE_NAMESPACE: &str = "urn:owl2-reasoner:model#node";
+
+const OWL: &str = "http://www.w3.org/2002/07/owl#";
+
+/// An individual of a tableau model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelIndividual {
+    /// The tableau node standing for the individual
+    pub node: NodeId,
+    /// Named individuals the node stands for, if any
+    pub names: Vec<String>,
+    /// Named classes the individual is an instance of
+    pub classes: Vec<IRI>,
+    /// Named classes the individual is not an instance of
+    pub non_classes: Vec<IRI>,
+    /// Every concept on the node when the tableau completed
+    pub concepts: Vec<ClassExpression>,
+}
+
+impl ModelIndividual {
+    /// Whether the individual is an instance of the named class
+    pub fn is_instance_of(&self, class: &IRI) -> bool {
+        self.classes.contains(class)
+    }
+
+    /// Whether the individual is an instance of the named class's complement
+    pub fn is_not_instance_of(&self, class: &IRI) -> bool {
+        self.non_classes.contains(class)
+    }
+}
+
+/// A finite model built by the tableau
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Model {
+    /// Individuals of the model, the root first
+    pub individuals: Vec<ModelIndividual>,
+    /// Object property values as `(subject, property, object)`
+    pub edges: Vec<(NodeId, IRI, NodeId)>,
+}
+
+impl Model {
+    /// The individual standing for the given node
+    pub fn individual(&self, node: NodeId) -> Option<&ModelIndividual> {
+        self.individuals
+            .iter()
+            .find(|individual| individual.node == node)
+    }
+
+    /// The successors of a node with the properties relating them
+    pub fn successors(&self, node: NodeId) -> Vec<(&IRI, NodeId)> {
+        self.edges
+            .iter()
+            .filter(|(from, _, _)| *from == node)
+            .map(|(_, property, to)| (property, *to))
+            .collect()
+    }
+
+    /// Render the model as Turtle
+    ///
+    /// Each node becomes the IRI [`MODEL_NODE_NAMESPACE`] followed by its id,
+    /// typed with every concept on it and linked to its successors. Nodes
+    /// standing for named individuals are `owl:sameAs` those individuals.
+    pub fn to_turtle(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "@prefix owl: <{}> .", OWL);
+        let _ = writeln!(out, "@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .");
+
+        for individual in &self.individuals {
+            let _ = write!(
+                out,
+                "\n{} a owl:NamedIndividual",
+                node_term(individual.node)
+            );
+            for concept in &individual.concepts {
+                let _ = write!(out, " ;\n    a {}", class_term(concept));
+            }
+            for name in &individual.names {
+                let _ = write!(out, " ;\n    owl:sameAs <{}>", name);
+            }
+            for (property, to) in self.successors(individual.node) {
+                let _ = write!(out, " ;\n    <{}> {}", property, node_term(to));
+            }
+            out.push_str(" .\n");
+        }
+        out
+    }
+}
+
+fn node_term(node: NodeId) -> String {
+    format!("<{}{}>", MODEL_NODE_NAMESPACE, node.as_usize())
+}
+
+/// A class expression as a Turtle term, using blank nodes for anonymous classes
+fn class_term(concept: &ClassExpression) -> String {
+    let list = |operands: &[std::sync::Arc<ClassExpression>]| {
+        let terms: Vec<String> = operands.iter().map(|op| class_term(op)).collect();
+        format!("( {} )", terms.join(" "))
+    };
+    let restriction = |property: String, rest: String| {
+        format!(
+            "[ a owl:Restriction ; owl:onProperty {} ; {} ]",
+            property, rest
+        )
+    };
+    let cardinality =
+        |predicate: &str, n: u32| format!("owl:{} \"{}\"^^xsd:nonNegativeInteger", predicate, n);
+
+    match concept {
+        ClassExpression::Class(class) => format!("<{}>", class.iri()),
+        ClassExpression::ObjectIntersectionOf(operands) => {
+            format!("[ a owl:Class ; owl:intersectionOf {} ]", list(operands))
+        }
+        ClassExpression::ObjectUnionOf(operands) => {
+            format!("[ a owl:Class ; owl:unionOf {} ]", list(operands))
+        }
+        ClassExpression::ObjectComplementOf(inner) => {
+            format!("[ a owl:Class ; owl:complementOf {} ]", class_term(inner))
+        }
+        ClassExpression::ObjectOneOf(individuals) => {
+            let terms: Vec<String> = individuals.iter().map(individual_term).collect();
+            format!("[ a owl:Class ; owl:oneOf ( {} ) ]", terms.join(" "))
+        }
+        ClassExpression::ObjectSomeValuesFrom(property, filler) => restriction(
+            object_property_term(property),
+            format!("owl:someValuesFrom {}", class_term(filler)),
+        ),
+        ClassExpression::ObjectAllValuesFrom(property, filler) => restriction(
+            object_property_term(property),
+            format!("owl:allValuesFrom {}", class_term(filler)),
+        ),
+        ClassExpression::ObjectHasValue(property, individual) => restriction(
+            object_property_term(property),
+            format!("owl:hasValue {}", individual_term(individual)),
+        ),
+        ClassExpression::ObjectHasSelf(property) => restriction(
+            object_property_term(property),
+            "owl:hasSelf true".to_string(),
+        ),
+        ClassExpression::ObjectMinCardinality(n, property) => restriction(
+            object_property_term(property),
+            cardinality("minCardinality", *n),
+        ),
+        ClassExpression::ObjectMaxCardinality(n, property) => restriction(
+            object_property_term(property),
+            cardinality("maxCardinality", *n),
+        ),
+        ClassExpression::ObjectExactCardinality(n, property) => restriction(
+            object_property_term(property),
+            cardinality("cardinality", *n),
+        ),
+        ClassExpression::DataSomeValuesFrom(property, range) => restriction(
+            data_property_term(property),
+            format!("owl:someValuesFrom {}", data_range_term(range)),
+        ),
+        ClassExpression::DataAllValuesFrom(property, range) => restriction(
+            data_property_term(property),
+            format!("owl:allValuesFrom {}", data_range_term(range)),
+        ),
+        ClassExpression::DataHasValue(property, value) => restriction(
+            data_property_term(property),
+            format!("owl:hasValue {}", literal_term(value)),
+        ),
+        ClassExpression::DataMinCardinality(n, property) => restriction(
+            data_property_term(property),
+            cardinality("minCardinality", *n),
+        ),
+        ClassExpression::DataMaxCardinality(n, property) => restriction(
+            data_property_term(property),
+            cardinality("maxCardinality", *n),
+        ),
+        ClassExpression::DataExactCardinality(n, property) => {
+            restriction(data_property_term(property), cardinality("cardinality", *n))
+        }
+    }
+}
+
+fn object_property_term(property: &ObjectPropertyExpression) -> String {
+    match property {
+        ObjectPropertyExpression::ObjectProperty(property) => format!("<{}>", property.iri()),
+        ObjectPropertyExpression::ObjectInverseOf(inner) => {
+            format!("[ owl:inverseOf {} ]", object_property_term(inner))
+        }
+    }
+}
+
+fn data_property_term(property: &DataPropertyExpression) -> String {
+    match property {
+        DataPropertyExpression::DataProperty(property) => format!("<{}>", property.iri()),
+    }
+}
+
+fn individual_term(individual: &Individual) -> String {
+    match individual {
+        Individual::Named(named) => format!("<{}>", named.iri()),
+        Individual::Anonymous(anonymous) => format!("_:{}", anonymous.node_id()),
+    }
+}
+
+/// Data ranges other than a named datatype are written as a bare `rdfs:Datatype`
+fn data_range_term(range: &DataRange) -> String {
+    match range {
+        DataRange::Datatype(datatype) => format!("<{}>", datatype),
+        _ => "[ a <http://www.w3.org/2000/01/rdf-schema#Datatype> ]".to_string(),
+    }
+}
+
+fn literal_term(literal: &Literal) -> String {
+    let mut quoted = String::from('"');
+    for c in literal.lexical_form().chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    match literal.language_tag() {
+        Some(language) => format!("{}@{}", quoted, language),
+        None => format!("{}^^<{}>", quoted, literal.datatype()),
+    }
+}
+
+/// A model showing that one class is not a subclass of another
+///
+/// The witness is an instance of `subclass` that is not an instance of
+/// `superclass`; the rest of the model gives the individuals it is related to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CounterModel {
+    pub subclass: IRI,
+    pub superclass: IRI,
+    /// The individual that is a `subclass` but not a `superclass`
+    pub witness: NodeId,
+    pub model: Model,
+}
+
+impl CounterModel {
+    /// The individual that is a `subclass` but not a `superclass`
+    pub fn witness(&self) -> &ModelIndividual {
+        self.model
+            .individual(self.witness)
+            .expect("the witness is an individual of the model")
+    }
+}
+
+/// Read the model described by a complete, clash-free tableau
+///
+/// Nodes blocked by another node are left out: the blocking node stands in for
+/// them, so edges to a blocked node lead to its blocker instead.
+pub fn extract_model(graph: &TableauxGraph) -> Model {
+    let mut individuals = Vec::new();
+    for (node_id, node) in graph.nodes_iter() {
+        if node.blocked_by.is_some() {
+            continue;
+        }
+        let mut classes = Vec::new();
+        let mut non_classes = Vec::new();
+        let mut concepts = Vec::new();
+        for concept in node.concepts_iter() {
+            concepts.push(concept.clone());
+            match concept {
+                ClassExpression::Class(class) => classes.push((**class.iri()).clone()),
+                ClassExpression::ObjectComplementOf(inner) => {
+                    if let ClassExpression::Class(class) = inner.as_ref() {
+                        non_classes.push((**class.iri()).clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+        classes.sort();
+        classes.dedup();
+        non_classes.sort();
+        non_classes.dedup();
+        individuals.push(ModelIndividual {
+            node: node_id,
+            names: node.labels.to_vec(),
+            classes,
+            non_classes,
+            concepts,
+        });
+    }
+
+    let representative = |node: NodeId| {
+        graph
+            .get_node(node)
+            .and_then(|node| node.blocked_by)
+            .unwrap_or(node)
+    };
+    let mut edges: Vec<_> = graph
+        .edges
+        .get_all_edges()
+        .iter()
+        .map(|(from, property, to)| (representative(*from), property.clone(), representative(*to)))
+        .collect();
+    edges.sort();
+    edges.dedup();
+
+    Model { individuals, edges }
+}
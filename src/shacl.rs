@@ -0,0 +1,739 @@
+//! Validation of the ABox against SHACL-lite shapes
+//!
+//! Supports the node-shape subset of SHACL that covers most data checks:
+//! shapes targeting a class through `sh:targetClass` with `sh:property`
+//! constraints on a predicate path (`sh:minCount`, `sh:maxCount`,
+//! `sh:datatype` and `sh:class`). Shapes are read from a Turtle shapes graph.
+//!
+//! Validation runs on top of [`OwlReasoner`], so individuals whose type is
+//! only inferred are still targeted, and values entailed through property
+//! axioms count towards the constraints.
+
+use crate::entities::{DataProperty, Literal, ObjectProperty};
+use crate::error::{OwlError, OwlResult};
+use crate::iri::IRI;
+use crate::ontology::Ontology;
+use crate::reasoning::{OwlReasoner, Reasoner};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+const NS_SH: &str = "http://www.w3.org/ns/shacl#";
+const NS_RDF: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#";
+const NS_RDFS: &str = "http://www.w3.org/2000/01/rdf-schema#";
+const NS_XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const NS_OWL: &str = "http://www.w3.org/2002/07/owl#";
+
+/// Namespace used for blank nodes, as in the Turtle parser
+const BLANK_NODE_NS: &str = "http://blank.node/";
+
+/// A constraint on the values of a property shape
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ShaclConstraint {
+    /// At least this many values (`sh:minCount`)
+    MinCount(usize),
+    /// At most this many values (`sh:maxCount`)
+    MaxCount(usize),
+    /// Every value is a literal of this datatype (`sh:datatype`)
+    Datatype(IRI),
+    /// Every value is an instance of this class (`sh:class`)
+    Class(IRI),
+}
+
+/// Constraints on the values reached from a focus node through a property
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyShape {
+    /// The property followed from the focus node (`sh:path`)
+    pub path: IRI,
+    /// The constraints its values must satisfy
+    pub constraints: Vec<ShaclConstraint>,
+}
+
+/// A node shape with the classes whose instances it validates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeShape {
+    /// The shape IRI, or a `http://blank.node/` IRI for anonymous shapes
+    pub iri: IRI,
+    /// Classes whose instances are focus nodes (`sh:targetClass`)
+    pub target_classes: Vec<IRI>,
+    /// Property constraints (`sh:property`)
+    pub properties: Vec<PropertyShape>,
+}
+
+/// A focus node failing one constraint of a shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaclViolation {
+    /// The individual that was validated
+    pub focus_node: IRI,
+    /// The node shape the constraint belongs to
+    pub shape: IRI,
+    /// The constrained property
+    pub path: IRI,
+    /// The constraint that is not satisfied
+    pub constraint: ShaclConstraint,
+    /// The offending value, for constraints on single values
+    pub value: Option<String>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for ShaclViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.focus_node, self.message)
+    }
+}
+
+/// Outcome of validating an ontology against a shapes graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShaclReport {
+    violations: Vec<ShaclViolation>,
+}
+
+impl ShaclReport {
+    /// Whether every focus node satisfies its shapes
+    pub fn conforms(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// All violations, ordered by focus node
+    pub fn violations(&self) -> &[ShaclViolation] {
+        &self.violations
+    }
+
+    /// The violations reported for one focus node
+    pub fn violations_for(&self, focus_node: &IRI) -> Vec<&ShaclViolation> {
+        self.violations
+            .iter()
+            .filter(|violation| violation.focus_node == *focus_node)
+            .collect()
+    }
+
+    /// The focus nodes with at least one violation
+    pub fn focus_nodes(&self) -> BTreeSet<&IRI> {
+        self.violations
+            .iter()
+            .map(|violation| &violation.focus_node)
+            .collect()
+    }
+}
+
+/// The node shapes of a SHACL shapes graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShapesGraph {
+    shapes: Vec<NodeShape>,
+}
+
+impl ShapesGraph {
+    /// Create a shapes graph from node shapes
+    pub fn new(shapes: Vec<NodeShape>) -> Self {
+        ShapesGraph { shapes }
+    }
+
+    /// Read the node shapes from a shapes graph in Turtle
+    ///
+    /// Every subject with `sh:targetClass` or `sh:property` is a node shape.
+    /// Property shapes must use a single property as their `sh:path`.
+    pub fn parse_turtle(content: &str) -> OwlResult<Self> {
+        let triples = TurtleReader::new(content).read()?;
+
+        let mut objects: HashMap<(&IRI, &str), Vec<&Term>> = HashMap::new();
+        let mut subjects: Vec<&IRI> = Vec::new();
+        for (subject, predicate, object) in &triples {
+            if let Some(name) = predicate.as_str().strip_prefix(NS_SH) {
+                if !subjects.contains(&subject) {
+                    subjects.push(subject);
+                }
+                objects.entry((subject, name)).or_default().push(object);
+            }
+        }
+        let values = |subject: &IRI, name: &str| -> Vec<&Term> {
+            objects.get(&(subject, name)).cloned().unwrap_or_default()
+        };
+
+        let mut shapes = Vec::new();
+        for subject in subjects {
+            let target_classes = values(subject, "targetClass");
+            let property_shapes = values(subject, "property");
+            if target_classes.is_empty() && property_shapes.is_empty() {
+                continue;
+            }
+
+            let mut properties = Vec::new();
+            for property_shape in property_shapes {
+                let property_shape = property_shape.expect_iri("sh:property")?;
+                let path = match values(property_shape, "path").as_slice() {
+                    [path] => path.expect_iri("sh:path")?.clone(),
+                    [] => {
+                        return Err(OwlError::ParseError(format!(
+                            "Property shape of {} has no sh:path",
+                            subject
+                        )))
+                    }
+                    _ => {
+                        return Err(OwlError::ParseError(format!(
+                            "Property shape of {} has several sh:path values",
+                            subject
+                        )))
+                    }
+                };
+
+                let mut constraints = Vec::new();
+                for count in values(property_shape, "minCount") {
+                    constraints.push(ShaclConstraint::MinCount(
+                        count.expect_count("sh:minCount")?,
+                    ));
+                }
+                for count in values(property_shape, "maxCount") {
+                    constraints.push(ShaclConstraint::MaxCount(
+                        count.expect_count("sh:maxCount")?,
+                    ));
+                }
+                for datatype in values(property_shape, "datatype") {
+                    constraints.push(ShaclConstraint::Datatype(
+                        datatype.expect_iri("sh:datatype")?.clone(),
+                    ));
+                }
+                for class in values(property_shape, "class") {
+                    constraints.push(ShaclConstraint::Class(
+                        class.expect_iri("sh:class")?.clone(),
+                    ));
+                }
+                properties.push(PropertyShape { path, constraints });
+            }
+
+            shapes.push(NodeShape {
+                iri: subject.clone(),
+                target_classes: target_classes
+                    .into_iter()
+                    .map(|class| class.expect_iri("sh:targetClass").cloned())
+                    .collect::<OwlResult<_>>()?,
+                properties,
+            });
+        }
+
+        Ok(ShapesGraph { shapes })
+    }
+
+    /// The node shapes of the graph
+    pub fn shapes(&self) -> &[NodeShape] {
+        &self.shapes
+    }
+
+    /// Validate the individuals of an ontology against the shapes
+    ///
+    /// Focus nodes and their property values include inferred facts.
+    pub fn validate(&self, ontology: &Ontology) -> OwlResult<ShaclReport> {
+        let mut reasoner = OwlReasoner::new(ontology.clone());
+        let individuals = Self::individuals(ontology);
+
+        let mut violations = Vec::new();
+        for shape in &self.shapes {
+            for focus_node in &individuals {
+                let mut targeted = false;
+                for class in &shape.target_classes {
+                    if reasoner.is_instance_of(focus_node, class)? {
+                        targeted = true;
+                        break;
+                    }
+                }
+                if !targeted {
+                    continue;
+                }
+
+                for property in &shape.properties {
+                    Self::validate_property(
+                        &mut reasoner,
+                        shape,
+                        property,
+                        focus_node,
+                        &mut violations,
+                    )?;
+                }
+            }
+        }
+
+        violations.sort_by(|a: &ShaclViolation, b: &ShaclViolation| {
+            a.focus_node
+                .cmp(&b.focus_node)
+                .then_with(|| a.shape.cmp(&b.shape))
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        Ok(ShaclReport { violations })
+    }
+
+    /// Check the values of one property shape for a focus node
+    fn validate_property(
+        reasoner: &mut OwlReasoner,
+        shape: &NodeShape,
+        property: &PropertyShape,
+        focus_node: &IRI,
+        violations: &mut Vec<ShaclViolation>,
+    ) -> OwlResult<()> {
+        let mut individuals: Vec<IRI> = reasoner
+            .property_values(
+                focus_node,
+                &ObjectProperty::new(property.path.clone()).into(),
+            )?
+            .into_iter()
+            .collect();
+        individuals.sort();
+        let mut literals: Vec<Literal> = reasoner
+            .data_property_values(focus_node, &DataProperty::new(property.path.clone()).into())?
+            .into_iter()
+            .collect();
+        literals.sort_by(|a, b| a.lexical_form().cmp(b.lexical_form()));
+        let count = individuals.len() + literals.len();
+
+        let mut violation = |constraint: &ShaclConstraint, value: Option<String>, message| {
+            violations.push(ShaclViolation {
+                focus_node: focus_node.clone(),
+                shape: shape.iri.clone(),
+                path: property.path.clone(),
+                constraint: constraint.clone(),
+                value,
+                message,
+            });
+        };
+
+        for constraint in &property.constraints {
+            match constraint {
+                ShaclConstraint::MinCount(min) if count < *min => violation(
+                    constraint,
+                    None,
+                    format!(
+                        "{} has {} value(s) for {}, at least {} required",
+                        focus_node, count, property.path, min
+                    ),
+                ),
+                ShaclConstraint::MaxCount(max) if count > *max => violation(
+                    constraint,
+                    None,
+                    format!(
+                        "{} has {} value(s) for {}, at most {} allowed",
+                        focus_node, count, property.path, max
+                    ),
+                ),
+                ShaclConstraint::Datatype(datatype) => {
+                    for individual in &individuals {
+                        violation(
+                            constraint,
+                            Some(individual.to_string()),
+                            format!(
+                                "Value {} of {} is not a literal of datatype {}",
+                                individual, property.path, datatype
+                            ),
+                        );
+                    }
+                    for literal in &literals {
+                        let message = if **literal.datatype() != *datatype {
+                            format!(
+                                "Value \"{}\" of {} has datatype {}, expected {}",
+                                literal.lexical_form(),
+                                property.path,
+                                literal.datatype(),
+                                datatype
+                            )
+                        } else if !literal.is_well_formed() {
+                            format!(
+                                "Value \"{}\" of {} is not a valid lexical form of {}",
+                                literal.lexical_form(),
+                                property.path,
+                                datatype
+                            )
+                        } else {
+                            continue;
+                        };
+                        violation(
+                            constraint,
+                            Some(literal.lexical_form().to_string()),
+                            message,
+                        );
+                    }
+                }
+                ShaclConstraint::Class(class) => {
+                    for individual in &individuals {
+                        if !reasoner.is_instance_of(individual, class)? {
+                            violation(
+                                constraint,
+                                Some(individual.to_string()),
+                                format!(
+                                    "Value {} of {} is not an instance of {}",
+                                    individual, property.path, class
+                                ),
+                            );
+                        }
+                    }
+                    for literal in &literals {
+                        violation(
+                            constraint,
+                            Some(literal.lexical_form().to_string()),
+                            format!(
+                                "Value \"{}\" of {} is a literal, not an instance of {}",
+                                literal.lexical_form(),
+                                property.path,
+                                class
+                            ),
+                        );
+                    }
+                }
+                ShaclConstraint::MinCount(_) | ShaclConstraint::MaxCount(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Every named individual declared or mentioned in an assertion
+    fn individuals(ontology: &Ontology) -> BTreeSet<IRI> {
+        let mut individuals: BTreeSet<IRI> = ontology
+            .named_individuals()
+            .iter()
+            .map(|individual| (**individual.iri()).clone())
+            .collect();
+        for axiom in ontology.class_assertions() {
+            individuals.insert((**axiom.individual()).clone());
+        }
+        for axiom in ontology.property_assertions() {
+            individuals.insert((**axiom.subject()).clone());
+        }
+        for axiom in ontology.data_property_assertions() {
+            individuals.insert((**axiom.subject()).clone());
+        }
+        individuals
+    }
+}
+
+/// Object of a triple in the shapes graph
+#[derive(Debug, Clone, PartialEq)]
+enum Term {
+    Iri(IRI),
+    Literal(Literal),
+}
+
+impl Term {
+    fn expect_iri(&self, predicate: &str) -> OwlResult<&IRI> {
+        match self {
+            Term::Iri(iri) => Ok(iri),
+            Term::Literal(literal) => Err(OwlError::ParseError(format!(
+                "Expected an IRI as the value of {}, found \"{}\"",
+                predicate,
+                literal.lexical_form()
+            ))),
+        }
+    }
+
+    fn expect_count(&self, predicate: &str) -> OwlResult<usize> {
+        match self {
+            Term::Literal(literal) => literal.lexical_form().parse().map_err(|_| {
+                OwlError::ParseError(format!(
+                    "Expected a non-negative integer as the value of {}, found \"{}\"",
+                    predicate,
+                    literal.lexical_form()
+                ))
+            }),
+            Term::Iri(iri) => Err(OwlError::ParseError(format!(
+                "Expected a non-negative integer as the value of {}, found {}",
+                predicate, iri
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Iri(String),
+    PrefixedName(String),
+    BlankNode(String),
+    /// Lexical form and language tag of a literal
+    Literal(String, Option<String>),
+    /// The `^^` introducing the datatype of the preceding literal
+    DatatypeMarker,
+    Number(String),
+    Boolean(bool),
+    Punctuation(char),
+    Prefix,
+    A,
+}
+
+/// Statement-level Turtle reader for shapes graphs
+///
+/// Unlike the line-based ontology parser, statements and `[ ... ]` blocks may
+/// span several lines, which is how shapes graphs are usually written.
+struct TurtleReader {
+    tokens: Vec<Token>,
+    position: usize,
+    prefixes: HashMap<String, String>,
+    triples: Vec<(IRI, IRI, Term)>,
+    blank_nodes: usize,
+}
+
+impl TurtleReader {
+    fn new(content: &str) -> Self {
+        let prefixes = [
+            ("sh", NS_SH),
+            ("rdf", NS_RDF),
+            ("rdfs", NS_RDFS),
+            ("xsd", NS_XSD),
+            ("owl", NS_OWL),
+        ]
+        .iter()
+        .map(|(prefix, namespace)| (prefix.to_string(), namespace.to_string()))
+        .collect();
+        TurtleReader {
+            tokens: Self::tokenize(content),
+            position: 0,
+            prefixes,
+            triples: Vec::new(),
+            blank_nodes: 0,
+        }
+    }
+
+    fn tokenize(content: &str) -> Vec<Token> {
+        let chars: Vec<char> = content.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        let ends_word = |c: char| c.is_whitespace() || "[](),;\"#".contains(c);
+
+        while i < chars.len() {
+            let c = chars[i];
+            if c.is_whitespace() {
+                i += 1;
+            } else if c == '#' {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            } else if c == '<' {
+                let start = i + 1;
+                while i < chars.len() && chars[i] != '>' {
+                    i += 1;
+                }
+                tokens.push(Token::Iri(
+                    chars[start..i.min(chars.len())].iter().collect(),
+                ));
+                i += 1;
+            } else if c == '"' {
+                let mut lexical = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' && i + 1 < chars.len() {
+                        i += 1;
+                    }
+                    lexical.push(chars[i]);
+                    i += 1;
+                }
+                i += 1;
+                let mut language = None;
+                if chars.get(i) == Some(&'@') {
+                    let start = i + 1;
+                    while i < chars.len() && !ends_word(chars[i]) && !Self::ends_name(&chars, i) {
+                        i += 1;
+                    }
+                    language = Some(chars[start..i].iter().collect());
+                }
+                tokens.push(Token::Literal(lexical, language));
+                if chars.get(i) == Some(&'^') && chars.get(i + 1) == Some(&'^') {
+                    tokens.push(Token::DatatypeMarker);
+                    i += 2;
+                }
+            } else if "[](),;".contains(c) || Self::ends_name(&chars, i) {
+                tokens.push(Token::Punctuation(c));
+                i += 1;
+            } else {
+                let start = i;
+                while i < chars.len() && !ends_word(chars[i]) && !Self::ends_name(&chars, i) {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                tokens.push(match word.as_str() {
+                    "a" => Token::A,
+                    "@prefix" | "PREFIX" => Token::Prefix,
+                    "true" => Token::Boolean(true),
+                    "false" => Token::Boolean(false),
+                    _ if word.starts_with("_:") => Token::BlankNode(word[2..].to_string()),
+                    _ if word.starts_with(|c: char| c.is_ascii_digit() || c == '+' || c == '-')
+                        && word
+                            .chars()
+                            .all(|c| c.is_ascii_digit() || "+-.eE".contains(c)) =>
+                    {
+                        Token::Number(word)
+                    }
+                    _ => Token::PrefixedName(word),
+                });
+            }
+        }
+        tokens
+    }
+
+    /// Whether the character at `i` is a `.` ending a statement rather than
+    /// part of a name or number
+    fn ends_name(chars: &[char], i: usize) -> bool {
+        chars[i] == '.'
+            && chars
+                .get(i + 1)
+                .is_none_or(|next| next.is_whitespace() || *next == '#')
+    }
+
+    fn read(mut self) -> OwlResult<Vec<(IRI, IRI, Term)>> {
+        while self.position < self.tokens.len() {
+            if self.peek() == Some(&Token::Prefix) {
+                self.read_prefix()?;
+                continue;
+            }
+            let subject = match self.next()? {
+                Token::Punctuation('[') => {
+                    let node = self.fresh_blank_node()?;
+                    if self.peek() != Some(&Token::Punctuation(']')) {
+                        self.read_predicate_objects(&node)?;
+                    }
+                    self.expect(']')?;
+                    node
+                }
+                token => self.resolve(&token)?,
+            };
+            if self.peek() != Some(&Token::Punctuation('.')) {
+                self.read_predicate_objects(&subject)?;
+            }
+            self.expect('.')?;
+        }
+        Ok(self.triples)
+    }
+
+    fn read_prefix(&mut self) -> OwlResult<()> {
+        self.next()?;
+        let prefix = match self.next()? {
+            Token::PrefixedName(name) if name.ends_with(':') => {
+                name.trim_end_matches(':').to_string()
+            }
+            token => return Err(Self::unexpected(&token, "a prefix name")),
+        };
+        let namespace = match self.next()? {
+            Token::Iri(namespace) => namespace,
+            token => return Err(Self::unexpected(&token, "a namespace IRI")),
+        };
+        if self.peek() == Some(&Token::Punctuation('.')) {
+            self.position += 1;
+        }
+        self.prefixes.insert(prefix, namespace);
+        Ok(())
+    }
+
+    fn read_predicate_objects(&mut self, subject: &IRI) -> OwlResult<()> {
+        loop {
+            let predicate = match self.next()? {
+                Token::A => IRI::new(format!("{}type", NS_RDF))?,
+                token => self.resolve(&token)?,
+            };
+            loop {
+                let object = self.read_object()?;
+                self.triples
+                    .push((subject.clone(), predicate.clone(), object));
+                if self.peek() != Some(&Token::Punctuation(',')) {
+                    break;
+                }
+                self.position += 1;
+            }
+            if self.peek() != Some(&Token::Punctuation(';')) {
+                return Ok(());
+            }
+            while self.peek() == Some(&Token::Punctuation(';')) {
+                self.position += 1;
+            }
+            if matches!(
+                self.peek(),
+                Some(Token::Punctuation('.')) | Some(Token::Punctuation(']')) | None
+            ) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn read_object(&mut self) -> OwlResult<Term> {
+        Ok(match self.next()? {
+            Token::Punctuation('[') => {
+                let node = self.fresh_blank_node()?;
+                if self.peek() != Some(&Token::Punctuation(']')) {
+                    self.read_predicate_objects(&node)?;
+                }
+                self.expect(']')?;
+                Term::Iri(node)
+            }
+            Token::Literal(lexical, language) => {
+                if self.peek() == Some(&Token::DatatypeMarker) {
+                    self.position += 1;
+                    let datatype = self.next()?;
+                    Term::Literal(Literal::typed(lexical, self.resolve(&datatype)?))
+                } else if let Some(language) = language {
+                    Term::Literal(Literal::lang_tagged(lexical, language))
+                } else {
+                    Term::Literal(Literal::simple(lexical))
+                }
+            }
+            Token::Number(number) => {
+                let datatype = if number.contains(['e', 'E']) {
+                    "double"
+                } else if number.contains('.') {
+                    "decimal"
+                } else {
+                    "integer"
+                };
+                Term::Literal(Literal::typed(
+                    number,
+                    IRI::new(format!("{}{}", NS_XSD, datatype))?,
+                ))
+            }
+            Token::Boolean(value) => Term::Literal(Literal::typed(
+                value.to_string(),
+                IRI::new(format!("{}boolean", NS_XSD))?,
+            )),
+            token => Term::Iri(self.resolve(&token)?),
+        })
+    }
+
+    /// Turn an IRI, prefixed name or blank node label into an IRI
+    fn resolve(&self, token: &Token) -> OwlResult<IRI> {
+        match token {
+            Token::Iri(iri) => IRI::new(iri.as_str()),
+            Token::BlankNode(label) => IRI::new(format!("{}{}", BLANK_NODE_NS, label)),
+            Token::PrefixedName(name) => {
+                let (prefix, local) = name.split_once(':').ok_or_else(|| {
+                    OwlError::ParseError(format!("Expected a prefixed name, found '{}'", name))
+                })?;
+                let namespace = self.prefixes.get(prefix).ok_or_else(|| {
+                    OwlError::ParseError(format!("Undefined prefix '{}' in '{}'", prefix, name))
+                })?;
+                IRI::new(format!("{}{}", namespace, local))
+            }
+            token => Err(Self::unexpected(token, "an IRI")),
+        }
+    }
+
+    fn fresh_blank_node(&mut self) -> OwlResult<IRI> {
+        self.blank_nodes += 1;
+        IRI::new(format!("{}shape{}", BLANK_NODE_NS, self.blank_nodes))
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> OwlResult<Token> {
+        let token =
+            self.tokens.get(self.position).cloned().ok_or_else(|| {
+                OwlError::ParseError("Unexpected end of shapes graph".to_string())
+            })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, punctuation: char) -> OwlResult<()> {
+        match self.next()? {
+            Token::Punctuation(c) if c == punctuation => Ok(()),
+            token => Err(Self::unexpected(&token, &format!("'{}'", punctuation))),
+        }
+    }
+
+    fn unexpected(token: &Token, expected: &str) -> OwlError {
+        OwlError::ParseError(format!(
+            "Expected {} in shapes graph, found {:?}",
+            expected, token
+        ))
+    }
+}
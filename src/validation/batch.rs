@@ -0,0 +1,295 @@
+//! Batch validation of ontology files
+//!
+//! [`BatchValidationReport`] parses a set of ontology files and, for each one
+//! that parses, records its OWL2 profile verdicts, a consistency check and the
+//! linter's warnings. The aggregate can be rendered as JSON for tooling or as
+//! a plain-text table for terminals, and [`has_errors`](BatchValidationReport::has_errors)
+//! gives CI pipelines a single pass/fail signal.
+
+use crate::error::{OwlError, OwlResult};
+use crate::linter::{LintWarning, OntologyLinter};
+use crate::ontology::Ontology;
+use crate::parser::ParserFactory;
+use crate::profiles::{Owl2ProfileValidator, ProfileValidationResult};
+use crate::reasoning::consistency::{ConsistencyChecker, ConsistencyResult};
+use serde_json::json;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Validation outcome for a single ontology file
+#[derive(Debug, Clone)]
+pub struct FileValidationResult {
+    /// The validated file
+    pub path: PathBuf,
+    /// Why the file could not be parsed, if it could not
+    pub parse_error: Option<String>,
+    /// Verdicts for the EL, QL and RL profiles
+    pub profiles: Vec<ProfileValidationResult>,
+    /// Consistency check result
+    pub consistency: Option<ConsistencyResult>,
+    /// Modeling pitfalls reported by the linter
+    pub lint_warnings: Vec<LintWarning>,
+}
+
+impl FileValidationResult {
+    /// Parse and validate one ontology file
+    ///
+    /// The parser is chosen from the file extension, falling back to content
+    /// detection. Failures are recorded in the result rather than returned.
+    pub fn validate(path: &Path) -> Self {
+        let mut result = Self {
+            path: path.to_path_buf(),
+            parse_error: None,
+            profiles: Vec::new(),
+            consistency: None,
+            lint_warnings: Vec::new(),
+        };
+
+        let ontology = match parse_ontology_file(path) {
+            Ok(ontology) => ontology,
+            Err(e) => {
+                result.parse_error = Some(e.to_string());
+                return result;
+            }
+        };
+
+        result.lint_warnings = OntologyLinter::new(&ontology).lint();
+        match Owl2ProfileValidator::new(Arc::new(ontology.clone()))
+            .and_then(|mut validator| validator.validate_all_profiles())
+        {
+            Ok(profiles) => result.profiles = profiles,
+            Err(e) => log::warn!("Profile validation of {} failed: {}", path.display(), e),
+        }
+        match ConsistencyChecker::new(ontology).check_consistency() {
+            Ok(consistency) => result.consistency = Some(consistency),
+            Err(e) => log::warn!("Consistency check of {} failed: {}", path.display(), e),
+        }
+
+        result
+    }
+
+    /// Whether the file failed to parse or is inconsistent
+    pub fn has_errors(&self) -> bool {
+        self.parse_error.is_some()
+            || self
+                .consistency
+                .as_ref()
+                .is_some_and(|consistency| !consistency.is_consistent)
+    }
+
+    /// Names of the profiles the ontology conforms to
+    fn valid_profiles(&self) -> Vec<String> {
+        self.profiles
+            .iter()
+            .filter(|result| result.is_valid)
+            .map(|result| format!("{:?}", result.profile))
+            .collect()
+    }
+
+    fn to_json_value(&self) -> serde_json::Value {
+        json!({
+            "path": self.path.display().to_string(),
+            "parsed": self.parse_error.is_none(),
+            "parse_error": self.parse_error,
+            "profiles": self.profiles,
+            "consistent": self.consistency.as_ref().map(|c| c.is_consistent),
+            "inconsistencies": self.consistency.as_ref().map(|c| {
+                c.explanations
+                    .iter()
+                    .map(|explanation| explanation.description.clone())
+                    .collect::<Vec<_>>()
+            }),
+            "lint_warnings": self
+                .lint_warnings
+                .iter()
+                .map(|warning| json!({
+                    "kind": format!("{:?}", warning.kind),
+                    "severity": format!("{:?}", warning.severity),
+                    "subject": warning.subject.as_str(),
+                    "message": warning.message,
+                }))
+                .collect::<Vec<_>>(),
+            "has_errors": self.has_errors(),
+        })
+    }
+}
+
+/// Aggregate validation results for a batch of ontology files
+#[derive(Debug, Clone, Default)]
+pub struct BatchValidationReport {
+    /// Per-file results, in the order the files were validated
+    pub files: Vec<FileValidationResult>,
+}
+
+impl BatchValidationReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate each of the given files
+    pub fn validate_files<P: AsRef<Path>>(paths: impl IntoIterator<Item = P>) -> Self {
+        let mut report = Self::new();
+        for path in paths {
+            report.add_file(path.as_ref());
+        }
+        report
+    }
+
+    /// Validate every ontology file directly inside a directory
+    ///
+    /// Files whose extension no parser recognises are skipped; the rest are
+    /// validated in file name order.
+    pub fn validate_directory(dir: &Path) -> OwlResult<Self> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let recognised = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ParserFactory::for_file_extension(ext).is_some());
+            if path.is_file() && recognised {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        Ok(Self::validate_files(paths))
+    }
+
+    /// Validate one more file and add it to the report
+    pub fn add_file(&mut self, path: &Path) {
+        self.files.push(FileValidationResult::validate(path));
+    }
+
+    /// Whether any file failed to parse or is inconsistent
+    pub fn has_errors(&self) -> bool {
+        self.files.iter().any(FileValidationResult::has_errors)
+    }
+
+    /// Files that failed to parse or are inconsistent
+    pub fn failed_files(&self) -> Vec<&FileValidationResult> {
+        self.files.iter().filter(|file| file.has_errors()).collect()
+    }
+
+    /// Total number of lint warnings across all files
+    pub fn lint_warning_count(&self) -> usize {
+        self.files.iter().map(|file| file.lint_warnings.len()).sum()
+    }
+
+    /// Render the report as pretty-printed JSON
+    pub fn to_json(&self) -> OwlResult<String> {
+        let report = json!({
+            "files": self.files.iter().map(FileValidationResult::to_json_value).collect::<Vec<_>>(),
+            "summary": {
+                "total": self.files.len(),
+                "failed": self.failed_files().len(),
+                "lint_warnings": self.lint_warning_count(),
+                "has_errors": self.has_errors(),
+            },
+        });
+        Ok(serde_json::to_string_pretty(&report)?)
+    }
+
+    /// Render the report as a plain-text table, one row per file
+    ///
+    /// Parse errors and inconsistencies are listed below the table.
+    pub fn to_table(&self) -> String {
+        let header = ["FILE", "PARSE", "PROFILES", "CONSISTENT", "LINTS"];
+        let rows: Vec<[String; 5]> = self
+            .files
+            .iter()
+            .map(|file| {
+                let parsed = file.parse_error.is_none();
+                let profiles = file.valid_profiles();
+                [
+                    file.path.display().to_string(),
+                    if parsed { "ok" } else { "error" }.to_string(),
+                    match (parsed, profiles.is_empty()) {
+                        (false, _) => "-".to_string(),
+                        (true, true) => "none".to_string(),
+                        (true, false) => profiles.join(","),
+                    },
+                    match &file.consistency {
+                        Some(c) if c.is_consistent => "yes".to_string(),
+                        Some(_) => "no".to_string(),
+                        None => "-".to_string(),
+                    },
+                    if parsed {
+                        file.lint_warnings.len().to_string()
+                    } else {
+                        "-".to_string()
+                    },
+                ]
+            })
+            .collect();
+
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+
+        let mut table = String::new();
+        let mut write_row = |cells: &[&str]| {
+            let line: Vec<String> = cells
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            let _ = writeln!(table, "{}", line.join("  ").trim_end());
+        };
+        write_row(&header);
+        for row in &rows {
+            write_row(&row.each_ref().map(String::as_str));
+        }
+
+        for file in self.failed_files() {
+            let _ = writeln!(table);
+            if let Some(error) = &file.parse_error {
+                let _ = writeln!(table, "{}: parse error: {}", file.path.display(), error);
+            }
+            if let Some(consistency) = &file.consistency {
+                for explanation in &consistency.explanations {
+                    let _ = writeln!(
+                        table,
+                        "{}: inconsistent: {}",
+                        file.path.display(),
+                        explanation.description
+                    );
+                }
+            }
+        }
+
+        let _ = writeln!(
+            table,
+            "\n{} file(s), {} failed, {} lint warning(s)",
+            self.files.len(),
+            self.failed_files().len(),
+            self.lint_warning_count()
+        );
+        table
+    }
+}
+
+/// Parse a file with the parser for its extension, or by detecting its content
+fn parse_ontology_file(path: &Path) -> OwlResult<Ontology> {
+    let by_extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(ParserFactory::for_file_extension);
+    let parser = match by_extension {
+        Some(parser) => parser,
+        None => {
+            let content = std::fs::read_to_string(path)?;
+            ParserFactory::auto_detect(&content).ok_or_else(|| {
+                OwlError::ParseError(format!(
+                    "Could not determine the format of {}",
+                    path.display()
+                ))
+            })?
+        }
+    };
+    parser.parse_file(path)
+}
@@ -0,0 +1,229 @@
+//! W3C EARL conformance reports
+//!
+//! [`EarlReporter`] collects per-test outcomes and renders them as an
+//! [EARL 1.0](https://www.w3.org/TR/EARL10-Schema/) document in Turtle: one
+//! `earl:Assertion` per test, each pointing at an `earl:TestResult` with its
+//! outcome, asserted by the configured assertor about the configured test
+//! subject. This is the format W3C implementation reports are published in.
+
+use std::fmt::Write as _;
+
+const EARL: &str = "http://www.w3.org/ns/earl#";
+
+/// Outcome of a single test, as defined by the EARL vocabulary
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EarlOutcome {
+    /// The subject passed the test
+    Passed,
+    /// The subject failed the test
+    Failed,
+    /// It could not be determined whether the subject passed
+    CantTell,
+    /// The test does not apply to the subject
+    Inapplicable,
+    /// The test was not carried out
+    Untested,
+}
+
+impl EarlOutcome {
+    /// Local name of the outcome in the EARL namespace
+    pub fn local_name(&self) -> &'static str {
+        match self {
+            EarlOutcome::Passed => "passed",
+            EarlOutcome::Failed => "failed",
+            EarlOutcome::CantTell => "cantTell",
+            EarlOutcome::Inapplicable => "inapplicable",
+            EarlOutcome::Untested => "untested",
+        }
+    }
+
+    /// Full IRI of the outcome
+    pub fn iri(&self) -> String {
+        format!("{}{}", EARL, self.local_name())
+    }
+}
+
+impl From<bool> for EarlOutcome {
+    fn from(passed: bool) -> Self {
+        if passed {
+            EarlOutcome::Passed
+        } else {
+            EarlOutcome::Failed
+        }
+    }
+}
+
+/// Outcome of one test case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EarlTestResult {
+    /// IRI of the test case
+    pub test: String,
+    /// Outcome of running the test
+    pub outcome: EarlOutcome,
+    /// Optional human-readable detail, such as a failure message
+    pub info: Option<String>,
+}
+
+/// Builder for EARL reports about one test subject
+#[derive(Debug, Clone)]
+pub struct EarlReporter {
+    subject: String,
+    subject_name: Option<String>,
+    subject_version: Option<String>,
+    assertor: String,
+    assertor_name: Option<String>,
+    date: String,
+    results: Vec<EarlTestResult>,
+}
+
+impl EarlReporter {
+    /// Create a reporter for `subject`, with results asserted by `assertor`
+    ///
+    /// Both are IRIs. The report is dated now unless [`with_date`](Self::with_date)
+    /// is used.
+    pub fn new(subject: impl Into<String>, assertor: impl Into<String>) -> Self {
+        Self {
+            subject: subject.into(),
+            subject_name: None,
+            subject_version: None,
+            assertor: assertor.into(),
+            assertor_name: None,
+            date: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            results: Vec::new(),
+        }
+    }
+
+    /// Set the project name of the test subject
+    pub fn with_subject_name(mut self, name: impl Into<String>) -> Self {
+        self.subject_name = Some(name.into());
+        self
+    }
+
+    /// Set the release of the test subject that was tested
+    pub fn with_subject_version(mut self, version: impl Into<String>) -> Self {
+        self.subject_version = Some(version.into());
+        self
+    }
+
+    /// Set the name of the assertor
+    pub fn with_assertor_name(mut self, name: impl Into<String>) -> Self {
+        self.assertor_name = Some(name.into());
+        self
+    }
+
+    /// Set the `xsd:dateTime` the results are dated with
+    pub fn with_date(mut self, date: impl Into<String>) -> Self {
+        self.date = date.into();
+        self
+    }
+
+    /// Record the outcome of a test case
+    pub fn add_result(&mut self, test: impl Into<String>, outcome: impl Into<EarlOutcome>) {
+        self.results.push(EarlTestResult {
+            test: test.into(),
+            outcome: outcome.into(),
+            info: None,
+        });
+    }
+
+    /// Record the outcome of a test case with a human-readable detail
+    pub fn add_result_with_info(
+        &mut self,
+        test: impl Into<String>,
+        outcome: impl Into<EarlOutcome>,
+        info: impl Into<String>,
+    ) {
+        self.results.push(EarlTestResult {
+            test: test.into(),
+            outcome: outcome.into(),
+            info: Some(info.into()),
+        });
+    }
+
+    /// Recorded results, in the order they were added
+    pub fn results(&self) -> &[EarlTestResult] {
+        &self.results
+    }
+
+    /// Number of recorded results with the given outcome
+    pub fn count(&self, outcome: EarlOutcome) -> usize {
+        self.results
+            .iter()
+            .filter(|result| result.outcome == outcome)
+            .count()
+    }
+
+    /// Render the report as an EARL Turtle document
+    pub fn to_turtle(&self) -> String {
+        let mut out = String::new();
+        for (prefix, namespace) in [
+            ("earl", EARL),
+            ("dc", "http://purl.org/dc/terms/"),
+            ("doap", "http://usefulinc.com/ns/doap#"),
+            ("foaf", "http://xmlns.com/foaf/0.1/"),
+            ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+        ] {
+            let _ = writeln!(out, "@prefix {}: <{}> .", prefix, namespace);
+        }
+
+        let _ = write!(out, "\n<{}> a doap:Project, earl:TestSubject", self.subject);
+        if let Some(name) = &self.subject_name {
+            let _ = write!(out, " ;\n    doap:name {}", literal(name));
+        }
+        if let Some(version) = &self.subject_version {
+            let _ = write!(out, " ;\n    doap:revision {}", literal(version));
+        }
+        out.push_str(" .\n");
+
+        let _ = write!(out, "\n<{}> a earl:Assertor, foaf:Agent", self.assertor);
+        if let Some(name) = &self.assertor_name {
+            let _ = write!(out, " ;\n    foaf:name {}", literal(name));
+        }
+        out.push_str(" .\n");
+
+        for (index, result) in self.results.iter().enumerate() {
+            let _ = write!(
+                out,
+                "\n_:assertion{index} a earl:Assertion ;\n    \
+                 earl:assertedBy <{assertor}> ;\n    \
+                 earl:subject <{subject}> ;\n    \
+                 earl:test <{test}> ;\n    \
+                 earl:mode earl:automatic ;\n    \
+                 earl:result _:result{index} .\n\
+                 \n_:result{index} a earl:TestResult ;\n    \
+                 earl:outcome earl:{outcome} ;\n    \
+                 dc:date {date}^^xsd:dateTime",
+                index = index,
+                assertor = self.assertor,
+                subject = self.subject,
+                test = result.test,
+                outcome = result.outcome.local_name(),
+                date = literal(&self.date),
+            );
+            if let Some(info) = &result.info {
+                let _ = write!(out, " ;\n    earl:info {}", literal(info));
+            }
+            out.push_str(" .\n");
+        }
+
+        out
+    }
+}
+
+/// Quote a string as a Turtle literal
+fn literal(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
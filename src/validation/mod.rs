@@ -3,9 +3,11 @@
 //! This module provides validation infrastructure for the OWL2 reasoner.
 
 pub mod academic_validation;
+pub mod batch;
 pub mod benchmark_suite;
 pub mod competition_framework;
 pub mod compliance_reporter;
+pub mod earl;
 pub mod enterprise_validation;
 pub mod execution_engine;
 pub mod memory_profiler;
@@ -67,7 +69,12 @@ impl ValidationReport {
 }
 
 /// Re-export commonly used validation types
-pub use w3c_test_suite::ComplianceReport;
+pub use batch::{BatchValidationReport, FileValidationResult};
+pub use earl::{EarlOutcome, EarlReporter, EarlTestResult};
+pub use w3c_test_suite::{
+    bundled_test_cases, run_w3c_test, run_w3c_test_case, ComplianceReport, TestOutcome,
+    TestStatus, W3CTestCase, W3CTestType, BUNDLED_TEST_NAMESPACE,
+};
 
 #[cfg(test)]
 mod validation_test;
@@ -3,9 +3,13 @@
 //! This module provides integration with the official W3C OWL2 test suite
 //! for comprehensive compliance validation.
 
-use crate::OwlResult;
+use crate::parser::detect_format;
+use crate::reasoning::{OwlReasoner, Reasoner};
+use crate::validation::EarlOutcome;
+use crate::{Ontology, OwlError, OwlResult};
 use log::info;
 use serde::{Deserialize, Serialize};
+use std::time::Instant;
 
 /// W3C OWL2 Test Suite implementation
 pub struct W3CTestSuite {
@@ -69,3 +73,199 @@ impl Default for ComplianceReport {
         }
     }
 }
+
+/// Namespace of the test cases bundled with the crate
+pub const BUNDLED_TEST_NAMESPACE: &str = "http://example.org/owl2-reasoner/tests#";
+
+/// Kind of a conformance test, as in the W3C OWL 2 test ontology
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum W3CTestType {
+    /// The premise ontology entails the conclusion ontology
+    PositiveEntailment,
+    /// The premise ontology does not entail the conclusion ontology
+    NegativeEntailment,
+    /// The premise ontology is consistent
+    Consistency,
+    /// The premise ontology is inconsistent
+    Inconsistency,
+}
+
+/// A conformance test case in the style of the W3C OWL 2 test suite
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct W3CTestCase {
+    /// IRI identifying the test
+    pub iri: String,
+    /// What the test checks
+    pub test_type: W3CTestType,
+    /// Premise ontology, in any format [`detect_format`] recognises
+    pub premise: String,
+    /// Conclusion ontology of an entailment test
+    pub conclusion: Option<String>,
+}
+
+/// Result of running a test case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TestStatus {
+    /// The reasoner gave the expected answer
+    Passed,
+    /// The reasoner gave the wrong answer
+    Failed,
+    /// The test could not be run, e.g. because an ontology did not parse
+    Error,
+}
+
+/// Outcome of running one test case
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestOutcome {
+    /// IRI of the test
+    pub test: String,
+    /// Whether the test passed, failed or could not be run
+    pub status: TestStatus,
+    /// Why the test failed or could not be run
+    pub message: Option<String>,
+    /// Time spent parsing and reasoning
+    pub execution_time_ms: u64,
+}
+
+impl TestOutcome {
+    /// Whether the test passed
+    pub fn passed(&self) -> bool {
+        self.status == TestStatus::Passed
+    }
+}
+
+impl From<&TestOutcome> for EarlOutcome {
+    fn from(outcome: &TestOutcome) -> Self {
+        match outcome.status {
+            TestStatus::Passed => EarlOutcome::Passed,
+            TestStatus::Failed => EarlOutcome::Failed,
+            TestStatus::Error => EarlOutcome::CantTell,
+        }
+    }
+}
+
+/// The test cases bundled with the crate
+pub fn bundled_test_cases() -> Vec<W3CTestCase> {
+    const PREFIXES: &str = "@prefix : <http://example.org/owl2-reasoner/tests#> .\n\
+        @prefix owl: <http://www.w3.org/2002/07/owl#> .\n\
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n";
+    let case = |name: &str, test_type, premise: &str, conclusion: Option<&str>| W3CTestCase {
+        iri: format!("{}{}", BUNDLED_TEST_NAMESPACE, name),
+        test_type,
+        premise: format!("{}{}", PREFIXES, premise),
+        conclusion: conclusion.map(|conclusion| format!("{}{}", PREFIXES, conclusion)),
+    };
+
+    vec![
+        case(
+            "subclass-transitivity",
+            W3CTestType::PositiveEntailment,
+            ":Dog rdfs:subClassOf :Mammal .\n:Mammal rdfs:subClassOf :Animal .\n",
+            Some(":Dog rdfs:subClassOf :Animal .\n"),
+        ),
+        case(
+            "class-assertion-inheritance",
+            W3CTestType::PositiveEntailment,
+            ":Dog rdfs:subClassOf :Animal .\n:rex a :Dog .\n",
+            Some(":rex a :Animal .\n"),
+        ),
+        case(
+            "sibling-subclasses",
+            W3CTestType::NegativeEntailment,
+            ":Dog rdfs:subClassOf :Animal .\n:Cat rdfs:subClassOf :Animal .\n",
+            Some(":Dog rdfs:subClassOf :Cat .\n"),
+        ),
+        case(
+            "disjoint-classes-consistent",
+            W3CTestType::Consistency,
+            ":Dog owl:disjointWith :Cat .\n:rex a :Dog .\n:tom a :Cat .\n",
+            None,
+        ),
+        case(
+            "disjoint-classes-shared-instance",
+            W3CTestType::Inconsistency,
+            ":Dog owl:disjointWith :Cat .\n:rex a :Dog .\n:rex a :Cat .\n",
+            None,
+        ),
+    ]
+}
+
+/// Run a bundled test case by its IRI
+///
+/// Fails only if no bundled test has the IRI; problems while running the test
+/// are reported as [`TestStatus::Error`] in the outcome.
+pub fn run_w3c_test(test_iri: &str) -> OwlResult<TestOutcome> {
+    let test = bundled_test_cases()
+        .into_iter()
+        .find(|test| test.iri == test_iri)
+        .ok_or_else(|| OwlError::ValidationError(format!("Unknown test case: {}", test_iri)))?;
+    Ok(run_w3c_test_case(&test))
+}
+
+/// Run a test case
+pub fn run_w3c_test_case(test: &W3CTestCase) -> TestOutcome {
+    let start = Instant::now();
+    let (status, message) = match check_test_case(test) {
+        Ok(None) => (TestStatus::Passed, None),
+        Ok(Some(failure)) => (TestStatus::Failed, Some(failure)),
+        Err(error) => (TestStatus::Error, Some(error.to_string())),
+    };
+    TestOutcome {
+        test: test.iri.clone(),
+        status,
+        message,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+    }
+}
+
+/// Check a test case, returning why it failed if it did
+fn check_test_case(test: &W3CTestCase) -> OwlResult<Option<String>> {
+    let mut reasoner = OwlReasoner::new(parse_test_ontology(&test.premise)?);
+    let consistent = reasoner.is_consistent()?;
+
+    let expected_entailment = match test.test_type {
+        W3CTestType::Consistency | W3CTestType::Inconsistency => {
+            let expected = test.test_type == W3CTestType::Consistency;
+            return Ok((consistent != expected).then(|| {
+                format!(
+                    "expected the premise to be {}",
+                    if expected {
+                        "consistent"
+                    } else {
+                        "inconsistent"
+                    }
+                )
+            }));
+        }
+        W3CTestType::PositiveEntailment => true,
+        W3CTestType::NegativeEntailment => false,
+    };
+
+    let conclusion = test.conclusion.as_deref().ok_or_else(|| {
+        OwlError::ValidationError(format!("Entailment test {} has no conclusion", test.iri))
+    })?;
+    let conclusion = parse_test_ontology(conclusion)?;
+    // An inconsistent premise entails everything
+    let mut entailed = true;
+    if consistent {
+        for axiom in conclusion.axioms() {
+            if !reasoner.entails_if_consistent(axiom)? {
+                entailed = false;
+                break;
+            }
+        }
+    }
+
+    Ok((entailed != expected_entailment).then(|| {
+        format!(
+            "expected the conclusion {}to be entailed",
+            if expected_entailment { "" } else { "not " }
+        )
+    }))
+}
+
+fn parse_test_ontology(content: &str) -> OwlResult<Ontology> {
+    let format = detect_format(content)
+        .ok_or_else(|| OwlError::ParseError("Unrecognised ontology format".to_string()))?;
+    format.parser().parse_str(content)
+}
@@ -0,0 +1,79 @@
+//! Tests for checking the satisfiability of all classes in one pass
+
+mod common;
+
+use common::{iri, subclass};
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+/// Animals and robots, disjoint from each other, with a CatDog under both
+/// disjoint Dog and Cat and a RoboDog under both Robot and Animal
+fn animals_and_robots() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in [
+        "Animal", "Dog", "Cat", "CatDog", "Puppy", "Robot", "RoboDog", "Toy",
+    ] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [
+        ("Dog", "Animal"),
+        ("Cat", "Animal"),
+        ("CatDog", "Cat"),
+        ("CatDog", "Dog"),
+        ("Puppy", "CatDog"),
+        ("RoboDog", "Robot"),
+        ("RoboDog", "Animal"),
+        ("Toy", "Robot"),
+    ] {
+        ontology.add_subclass_axiom(subclass(sub, sup)).unwrap();
+    }
+    for (first, second) in [("Dog", "Cat"), ("Animal", "Robot")] {
+        ontology
+            .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+                Arc::new(iri(first)),
+                Arc::new(iri(second)),
+            ]))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_all_class_satisfiability_matches_individual_checks() {
+    let mut reasoner = TableauxReasoner::new(animals_and_robots());
+    let results = reasoner.check_all_class_satisfiability().unwrap();
+
+    assert_eq!(results.len(), 8);
+    for (class, satisfiable) in &results {
+        let individual = TableauxReasoner::new(animals_and_robots())
+            .is_class_satisfiable(class)
+            .unwrap();
+        assert_eq!(*satisfiable, individual, "{}", class);
+    }
+
+    let unsatisfiable: Vec<_> = ["CatDog", "Puppy", "RoboDog"]
+        .iter()
+        .map(|n| iri(n))
+        .collect();
+    for (class, satisfiable) in &results {
+        assert_eq!(!satisfiable, unsatisfiable.contains(class), "{}", class);
+    }
+}
+
+#[test]
+fn test_all_class_satisfiability_populates_the_cache() {
+    let mut reasoner = TableauxReasoner::new(animals_and_robots());
+    reasoner.check_all_class_satisfiability().unwrap();
+
+    for name in ["Puppy", "Dog"] {
+        let satisfiable = reasoner.is_class_satisfiable(&iri(name)).unwrap();
+        assert_eq!(satisfiable, name == "Dog");
+        assert_eq!(reasoner.last_reasoning_stats().cache_hits, 1, "{}", name);
+    }
+
+    // Clearing the cache drops the answers but keeps them correct
+    reasoner.clear_cache();
+    assert!(!reasoner.is_class_satisfiable(&iri("Puppy")).unwrap());
+    assert_eq!(reasoner.last_reasoning_stats().cache_hits, 0);
+}
@@ -0,0 +1,91 @@
+//! Tests for merging the anonymous individuals of a parsed document
+
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+
+const PREFIXES: &str = "@prefix : <http://example.org/> .\n";
+
+fn parse(body: &str) -> Ontology {
+    TurtleParser::new()
+        .parse_str(&format!("{}{}", PREFIXES, body))
+        .unwrap()
+}
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/{}", name)).unwrap()
+}
+
+fn object_labels(ontology: &Ontology) -> Vec<String> {
+    ontology
+        .property_assertions()
+        .iter()
+        .filter_map(|axiom| axiom.object_anonymous())
+        .map(|individual| individual.node_id().to_string())
+        .collect()
+}
+
+#[test]
+fn test_references_to_one_blank_node_share_an_individual() {
+    let mut ontology = parse(
+        r#"
+:alice :knows _:x .
+:bob :knows _:x .
+_:x :name "Carol" .
+"#,
+    );
+
+    let report = ontology.merge_anonymous_individuals().unwrap();
+    assert_eq!(report.merged, 0);
+    // The subject of the name assertion was spelled as an IRI
+    assert!(report.relabeled > 0);
+
+    assert_eq!(object_labels(&ontology), vec!["x", "x"]);
+    let anonymous: Vec<&str> = ontology
+        .anonymous_individuals()
+        .iter()
+        .map(|individual| individual.node_id())
+        .collect();
+    assert_eq!(anonymous, vec!["x"]);
+
+    // The object and the subject now name the same individual
+    let named = ontology.property_assertions()[0].object_node_iri().unwrap();
+    assert_eq!(**ontology.data_property_assertions()[0].subject(), *named);
+    let mut reasoner = OwlReasoner::new(ontology);
+    let names = reasoner
+        .data_property_values(
+            &named,
+            &DataPropertyExpression::DataProperty(DataProperty::new(iri("name"))),
+        )
+        .unwrap();
+    assert_eq!(names, HashSet::from([Literal::simple("Carol")]));
+}
+
+#[test]
+fn test_blank_nodes_with_identical_descriptions_are_merged() {
+    let mut ontology = parse(
+        r#"
+:alice :address _:a1 .
+_:a1 :city "Paris" .
+:alice :address _:a2 .
+_:a2 :city "Paris" .
+:alice :address _:a3 .
+_:a3 :city "Rome" .
+"#,
+    );
+    let before = ontology.axioms().len();
+
+    let report = ontology.merge_anonymous_individuals().unwrap();
+    assert_eq!(report.merged, 1);
+    assert_eq!(report.duplicates, 2);
+    assert_eq!(ontology.axioms().len(), before - 2);
+
+    let mut labels = object_labels(&ontology);
+    labels.sort();
+    assert_eq!(labels, vec!["a1", "a3"]);
+    assert_eq!(ontology.anonymous_individuals().len(), 2);
+
+    // Merging again changes nothing
+    let again = ontology.merge_anonymous_individuals().unwrap();
+    assert_eq!(again, AnonymousMergeReport::default());
+}
@@ -0,0 +1,80 @@
+//! Tests for reasoning over property assertions with anonymous objects
+
+mod common;
+
+use common::{class, iri, some};
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::reasoning::ConsistencyMethod;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+/// alice hasPet _:pet, where the range of hasPet is Animal and Animal ⊑ ∃eats.Food
+fn alice_with_anonymous_pet() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::new(iri("hasPet"), class("Animal")),
+        )))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("Animal"),
+            some("eats", class("Food")),
+        ))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new_with_anonymous(
+            Arc::new(iri("alice")),
+            Arc::new(iri("hasPet")),
+            AnonymousIndividual::new("pet"),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_range_applies_to_anonymous_object() {
+    let assertion = alice_with_anonymous_pet().property_assertions()[0].clone();
+    let pet = assertion.object_node_iri().unwrap();
+    assert_eq!(pet.as_str(), "_:pet");
+
+    let tableaux = TableauxReasoner::new(alice_with_anonymous_pet());
+    assert!(tableaux.is_instance_of(&pet, &iri("Animal")).unwrap());
+    assert!(!tableaux
+        .is_instance_of(&iri("alice"), &iri("Animal"))
+        .unwrap());
+
+    let mut reasoner = OwlReasoner::new(alice_with_anonymous_pet());
+    assert!(reasoner.is_instance_of(&pet, &iri("Animal")).unwrap());
+}
+
+#[test]
+fn test_anonymous_object_clashes_with_disjoint_range() {
+    // The range puts the pet in both Animal and Plant
+    let mut ontology = alice_with_anonymous_pet();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::new(iri("hasPet"), class("Plant")),
+        )))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Plant")),
+        ]))
+        .unwrap();
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(!reasoner.is_consistent().unwrap());
+}
+
+#[test]
+fn test_anonymous_objects_keep_horn_completion() {
+    let mut reasoner = OwlReasoner::new(alice_with_anonymous_pet());
+    assert!(reasoner.is_consistent().unwrap());
+    assert_eq!(
+        reasoner.last_consistency_method(),
+        Some(ConsistencyMethod::HornCompletion)
+    );
+}
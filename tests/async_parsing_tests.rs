@@ -0,0 +1,69 @@
+//! Tests for parsing ontologies from async readers
+#![cfg(feature = "async")]
+
+use owl2_reasoner::parser::{AsyncOntologyParser, TurtleParser};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// A Turtle document with a long subclass chain
+fn large_turtle(classes: usize) -> String {
+    let mut content = String::from(
+        "@prefix ex: <http://example.org/> .\n\
+         @prefix owl: <http://www.w3.org/2002/07/owl#> .\n\
+         @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n",
+    );
+    for i in 0..classes {
+        content.push_str(&format!("ex:C{} a owl:Class .\n", i));
+        if i > 0 {
+            content.push_str(&format!("ex:C{} rdfs:subClassOf ex:C{} .\n", i, i - 1));
+        }
+    }
+    content
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_parse_async_does_not_starve_other_tasks() {
+    let content = large_turtle(2_000);
+
+    // On a single-threaded runtime this task only makes progress while the
+    // parses are suspended, so a blocking parse would leave it at zero
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let done = Arc::new(AtomicBool::new(false));
+    let ticker = tokio::spawn({
+        let ticks = Arc::clone(&ticks);
+        let done = Arc::clone(&done);
+        async move {
+            while !done.load(Ordering::SeqCst) {
+                ticks.fetch_add(1, Ordering::SeqCst);
+                tokio::task::yield_now().await;
+            }
+        }
+    });
+
+    let parser = TurtleParser::new();
+    let (first, second) = tokio::join!(
+        parser.parse_async(content.as_bytes()),
+        parser.parse_async(content.as_bytes()),
+    );
+    done.store(true, Ordering::SeqCst);
+    ticker.await.unwrap();
+
+    for ontology in [first.unwrap(), second.unwrap()] {
+        assert_eq!(ontology.classes().len(), 2_000);
+        assert_eq!(ontology.subclass_axioms().len(), 1_999);
+    }
+    assert!(ticks.load(Ordering::SeqCst) > 0);
+}
+
+#[tokio::test]
+async fn test_parse_async_reports_errors() {
+    let parser = TurtleParser::new();
+    let ontology = parser
+        .parse_async(large_turtle(3).as_bytes())
+        .await
+        .unwrap();
+    assert_eq!(ontology.subclass_axioms().len(), 2);
+
+    // Input that is not UTF-8 is rejected before parsing
+    assert!(parser.parse_async(&[0xff, 0xfe, 0xfd][..]).await.is_err());
+}
@@ -0,0 +1,71 @@
+//! Tests for aggregating validation results over a batch of ontology files
+
+use owl2_reasoner::validation::BatchValidationReport;
+use std::fs;
+
+const GOOD: &str = r#"@prefix : <http://example.org/zoo#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+:Animal a owl:Class .
+:Dog a owl:Class ;
+    rdfs:subClassOf :Animal .
+"#;
+
+const BROKEN: &str = r#"@prefix : <http://example.org/zoo#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+
+:Cat a owl:Class ;
+    rdfs:subClassOf [ owl:onProperty :eats .
+"#;
+
+#[test]
+fn test_batch_report_flags_the_broken_ontology() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("good.ttl"), GOOD).unwrap();
+    fs::write(dir.path().join("broken.ttl"), BROKEN).unwrap();
+    fs::write(dir.path().join("README.txt"), "not an ontology").unwrap();
+
+    let report = BatchValidationReport::validate_directory(dir.path()).unwrap();
+    assert_eq!(report.files.len(), 2);
+    assert!(report.has_errors());
+
+    let failed = report.failed_files();
+    assert_eq!(failed.len(), 1);
+    assert!(failed[0].path.ends_with("broken.ttl"));
+    assert!(failed[0].parse_error.is_some());
+
+    let good = report
+        .files
+        .iter()
+        .find(|file| file.path.ends_with("good.ttl"))
+        .unwrap();
+    assert!(!good.has_errors());
+    assert_eq!(good.profiles.len(), 3);
+    assert!(good.consistency.as_ref().unwrap().is_consistent);
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["summary"]["total"], 2);
+    assert_eq!(json["summary"]["failed"], 1);
+    assert_eq!(json["summary"]["has_errors"], true);
+
+    let table = report.to_table();
+    let broken_row = table
+        .lines()
+        .find(|line| line.contains("broken.ttl") && !line.contains("parse error"))
+        .unwrap();
+    assert!(broken_row.contains("error"));
+    assert!(table.contains("broken.ttl: parse error:"));
+    assert!(table.contains("2 file(s), 1 failed"));
+}
+
+#[test]
+fn test_batch_report_without_failures_has_no_errors() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("good.ttl");
+    fs::write(&path, GOOD).unwrap();
+
+    let report = BatchValidationReport::validate_files([&path]);
+    assert!(!report.has_errors());
+    assert!(report.failed_files().is_empty());
+}
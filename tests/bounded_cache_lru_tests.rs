@@ -0,0 +1,38 @@
+//! Tests for the least-recently-used order of BoundedCache
+
+use owl2_reasoner::cache::BoundedCache;
+
+#[test]
+fn test_repeated_accesses_keep_a_key_recent() {
+    let cache = BoundedCache::<u32, u32>::new(20);
+    for key in 0..20 {
+        cache.insert(key, key).unwrap();
+    }
+    // Far more accesses than entries, so the access order is compacted
+    for _ in 0..500 {
+        assert_eq!(cache.get(&0).unwrap(), Some(0));
+    }
+    assert_eq!(cache.get(&1).unwrap(), Some(1));
+
+    // A full cache evicts its least recently used tenth
+    cache.insert(20, 20).unwrap();
+    assert_eq!(cache.len().unwrap(), 19);
+    assert_eq!(cache.get(&2).unwrap(), None);
+    assert_eq!(cache.get(&3).unwrap(), None);
+    for key in [0, 1, 4, 19, 20] {
+        assert_eq!(cache.get(&key).unwrap(), Some(key), "{} was evicted", key);
+    }
+}
+
+#[test]
+fn test_evicted_keys_leave_the_order() {
+    let cache = BoundedCache::<u32, u32>::new(10);
+    for key in 0..100 {
+        cache.insert(key, key).unwrap();
+    }
+    // Each eviction removes the single oldest key, never one just inserted
+    assert_eq!(cache.len().unwrap(), 10);
+    for key in 90..100 {
+        assert_eq!(cache.get(&key).unwrap(), Some(key));
+    }
+}
@@ -0,0 +1,56 @@
+//! Tests for counting and limiting disjunction branches in the tableau
+
+use owl2_reasoner::reasoning::tableaux::{ReasoningConfig, TableauxReasoner};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> Arc<ClassExpression> {
+    Arc::new(ClassExpression::Class(Class::new(
+        IRI::new(format!("http://example.org/{}", name)).unwrap(),
+    )))
+}
+
+/// (A1 ⊔ B1) ⊓ … ⊓ (An ⊔ Bn) ⊓ ¬A1 ⊓ … ⊓ ¬An
+fn disjunctive_concept(size: usize) -> ClassExpression {
+    let mut conjuncts = Vec::new();
+    for i in 0..size {
+        let a = class(&format!("A{}", i));
+        conjuncts.push(Arc::new(ClassExpression::ObjectUnionOf(
+            vec![a.clone(), class(&format!("B{}", i))].into(),
+        )));
+        conjuncts.push(Arc::new(ClassExpression::ObjectComplementOf(a)));
+    }
+    ClassExpression::ObjectIntersectionOf(conjuncts.into())
+}
+
+#[test]
+fn test_branch_count_is_reported_in_stats() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    assert!(reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept(6))
+        .unwrap());
+    assert!(reasoner.last_reasoning_stats().branches >= 6);
+}
+
+#[test]
+fn test_branch_limit_aborts_with_resource_error() {
+    let config = ReasoningConfig {
+        max_branches: Some(4),
+        ..Default::default()
+    };
+    let reasoner = TableauxReasoner::with_config(Ontology::new(), config);
+
+    let error = reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept(6))
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        OwlError::ResourceLimitExceeded { limit: 4, .. }
+    ));
+
+    // A concept within the limit is still answered
+    assert!(reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept(2))
+        .unwrap());
+}
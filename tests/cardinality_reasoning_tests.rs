@@ -0,0 +1,79 @@
+//! Tests for cardinality restrictions in the tableaux reasoner
+//!
+//! Successors created for a min-cardinality restriction are pairwise
+//! different, so a max-cardinality restriction cannot merge them away.
+
+use owl2_reasoner::reasoning::tableaux::graph::GraphChangeLog;
+use owl2_reasoner::reasoning::tableaux::{TableauxGraph, TableauxReasoner};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn has_child() -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+        ObjectProperty::new("http://example.org/hasChild"),
+    )))
+}
+
+fn min_and_max(min: u32, max: u32) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(ClassExpression::ObjectMinCardinality(min, has_child())),
+            Arc::new(ClassExpression::ObjectMaxCardinality(max, has_child())),
+        ]
+        .into(),
+    )
+}
+
+#[test]
+fn test_min_two_max_one_is_unsatisfiable() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    let expression = min_and_max(2, 1);
+    assert!(!reasoner
+        .is_class_expression_satisfiable(&expression)
+        .unwrap());
+}
+
+#[test]
+fn test_compatible_cardinalities_are_satisfiable() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    assert!(reasoner
+        .is_class_expression_satisfiable(&min_and_max(2, 2))
+        .unwrap());
+    assert!(reasoner
+        .is_class_expression_satisfiable(&min_and_max(1, 3))
+        .unwrap());
+}
+
+#[test]
+fn test_exact_cardinality_conflicts_with_lower_max() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    let expression = ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(ClassExpression::ObjectExactCardinality(3, has_child())),
+            Arc::new(ClassExpression::ObjectMaxCardinality(2, has_child())),
+        ]
+        .into(),
+    );
+    assert!(!reasoner
+        .is_class_expression_satisfiable(&expression)
+        .unwrap());
+}
+
+#[test]
+fn test_graph_inequalities_roll_back() {
+    let mut graph = TableauxGraph::new();
+    let first = graph.add_node();
+    let second = graph.add_node();
+    assert!(!graph.are_different(first, second));
+
+    let mut log = GraphChangeLog::new();
+    graph.add_inequality_logged(second, first, &mut log);
+    assert!(graph.are_different(first, second));
+    assert!(!graph.are_different(first, first));
+
+    log.rollback(&mut graph);
+    assert!(!graph.are_different(first, second));
+}
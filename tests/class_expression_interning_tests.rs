@@ -0,0 +1,165 @@
+//! Tests for sharing identical class expression subtrees between axioms
+
+use owl2_reasoner::parser::{OwlXmlParser, ParserConfig};
+use owl2_reasoner::*;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Counts the bytes currently allocated on the heap
+///
+/// `MemoryMonitor` estimates usage from the IRI cache alone, so the live heap
+/// is tracked directly to see the storage saved by shared expressions.
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.fetch_add(layout.size() as isize, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size() as isize, Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Serializes the tests so allocations of one do not count towards another
+static MEASURING: Mutex<()> = Mutex::new(());
+
+const EX: &str = "http://example.org/family#";
+
+/// `Ci ⊑ Person ⊓ ∃hasParent.(Person ⊓ ∃hasChild.Person)` for every `i`
+fn repeated_expressions(classes: usize) -> String {
+    let mut document = format!(
+        r#"<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.org/family">
+    <Prefix name="ex" IRI="{EX}"/>
+"#
+    );
+    for i in 0..classes {
+        document.push_str(&format!(
+            r#"    <SubClassOf>
+        <Class abbreviatedIRI="ex:C{i}"/>
+        <ObjectIntersectionOf>
+            <Class abbreviatedIRI="ex:Person"/>
+            <ObjectSomeValuesFrom>
+                <ObjectProperty abbreviatedIRI="ex:hasParent"/>
+                <ObjectIntersectionOf>
+                    <Class abbreviatedIRI="ex:Person"/>
+                    <ObjectSomeValuesFrom>
+                        <ObjectProperty abbreviatedIRI="ex:hasChild"/>
+                        <Class abbreviatedIRI="ex:Person"/>
+                    </ObjectSomeValuesFrom>
+                </ObjectIntersectionOf>
+            </ObjectSomeValuesFrom>
+        </ObjectIntersectionOf>
+    </SubClassOf>
+"#
+        ));
+    }
+    document.push_str("</Ontology>\n");
+    document
+}
+
+fn parse(document: &str, intern: bool) -> Ontology {
+    let config = ParserConfig {
+        intern_class_expressions: intern,
+        ..Default::default()
+    };
+    OwlXmlParser::with_config(config)
+        .parse_str(document)
+        .unwrap()
+}
+
+/// Heap bytes still held once the parsed ontology is built
+fn retained_bytes(document: &str, intern: bool) -> (Ontology, isize) {
+    let before = LIVE_BYTES.load(Ordering::SeqCst);
+    let ontology = parse(document, intern);
+    (ontology, LIVE_BYTES.load(Ordering::SeqCst) - before)
+}
+
+fn operands(expression: &ClassExpression) -> &[Arc<ClassExpression>] {
+    match expression {
+        ClassExpression::ObjectIntersectionOf(operands) => operands,
+        other => panic!("expected an intersection, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_repeated_subexpressions_share_storage() {
+    let _guard = MEASURING.lock().unwrap();
+    let ontology = parse(&repeated_expressions(3), true);
+
+    let axioms = ontology.subclass_axioms();
+    assert_eq!(axioms.len(), 3);
+    let first = operands(axioms[0].super_class());
+    let second = operands(axioms[1].super_class());
+    assert_eq!(first, second);
+    for (a, b) in first.iter().zip(second) {
+        assert!(Arc::ptr_eq(a, b));
+    }
+    // Person, hasChild.Person, Person ⊓ ∃hasChild.Person and its ∃hasParent
+    assert_eq!(ontology.interned_class_expression_count(), 4);
+
+    let plain = parse(&repeated_expressions(3), false);
+    assert!(!Arc::ptr_eq(
+        &operands(plain.subclass_axioms()[0].super_class())[1],
+        &operands(plain.subclass_axioms()[1].super_class())[1]
+    ));
+    assert_eq!(plain.interned_class_expression_count(), 0);
+}
+
+#[test]
+fn test_interning_reduces_retained_memory() {
+    let _guard = MEASURING.lock().unwrap();
+    let document = repeated_expressions(500);
+
+    let (plain, plain_bytes) = retained_bytes(&document, false);
+    let (interned, interned_bytes) = retained_bytes(&document, true);
+
+    assert_eq!(plain.subclass_axioms(), interned.subclass_axioms());
+    assert!(
+        interned_bytes < plain_bytes / 2,
+        "interned ontology retains {} bytes, plain one {}",
+        interned_bytes,
+        plain_bytes
+    );
+}
+
+#[test]
+fn test_removed_axioms_release_their_interned_expressions() {
+    let _guard = MEASURING.lock().unwrap();
+    let mut ontology = parse(&repeated_expressions(2), true);
+    // Orphan ⊑ ∀hasParent.¬Person, built afresh on each call
+    let orphans = || {
+        let has_parent = ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(
+            format!("{}hasParent", EX),
+        )));
+        let person = ClassExpression::Class(Class::new(format!("{}Person", EX)));
+        Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+            ClassExpression::Class(Class::new(format!("{}Orphan", EX))),
+            ClassExpression::ObjectAllValuesFrom(
+                Box::new(has_parent),
+                Arc::new(ClassExpression::ObjectComplementOf(Arc::new(person))),
+            ),
+        )))
+    };
+    ontology.add_axiom(orphans()).unwrap();
+    // The four expressions of the repeated axioms, then ¬Person
+    assert_eq!(ontology.interned_class_expression_count(), 5);
+
+    // Splitting gives each half a cache of its own expressions only
+    let (tbox, abox) = ontology.split_tbox_abox();
+    assert_eq!(tbox.interned_class_expression_count(), 5);
+    assert_eq!(abox.interned_class_expression_count(), 0);
+
+    // ¬Person goes with the only axiom using it, Person is still shared
+    assert!(ontology.remove_axiom(&orphans()).unwrap());
+    assert_eq!(ontology.interned_class_expression_count(), 4);
+}
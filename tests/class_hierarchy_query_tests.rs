@@ -0,0 +1,77 @@
+//! Tests for direct and transitive superclass/subclass queries
+
+use owl2_reasoner::constants::owl;
+use owl2_reasoner::reasoning::ClassificationEngine;
+use owl2_reasoner::*;
+use std::collections::HashSet;
+
+const EX: &str = "http://example.org/";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn set(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+/// Animal ⊒ Mammal ⊒ {Dog, Cat}, Animal ⊒ Bird
+fn taxonomy() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Mammal", "Bird", "Dog", "Cat"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [
+        ("Mammal", "Animal"),
+        ("Bird", "Animal"),
+        ("Dog", "Mammal"),
+        ("Cat", "Mammal"),
+    ] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(
+                ClassExpression::from(Class::new(iri(sub))),
+                ClassExpression::from(Class::new(iri(sup))),
+            ))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_direct_and_transitive_superclasses() {
+    let mut engine = ClassificationEngine::new(taxonomy());
+
+    let direct = engine.superclasses(&iri("Dog"), true).unwrap();
+    assert_eq!(direct, set(&["Mammal"]));
+
+    let all = engine.superclasses(&iri("Dog"), false).unwrap();
+    assert!(all.contains(&iri("Mammal")));
+    assert!(all.contains(&iri("Animal")));
+    assert!(all.contains(&owl::thing()));
+    assert!(!all.contains(&iri("Dog")));
+    assert!(!all.contains(&iri("Bird")));
+
+    assert_eq!(
+        engine.superclasses(&iri("Animal"), true).unwrap(),
+        HashSet::from([owl::thing()])
+    );
+}
+
+#[test]
+fn test_direct_and_transitive_subclasses() {
+    let mut engine = ClassificationEngine::new(taxonomy());
+
+    let direct = engine.subclasses(&iri("Animal"), true).unwrap();
+    assert_eq!(direct, set(&["Mammal", "Bird"]));
+
+    let all = engine.subclasses(&iri("Animal"), false).unwrap();
+    for name in ["Mammal", "Bird", "Dog", "Cat"] {
+        assert!(all.contains(&iri(name)), "missing {}", name);
+    }
+    assert!(!all.contains(&iri("Animal")));
+
+    assert_eq!(
+        engine.subclasses(&iri("Mammal"), true).unwrap(),
+        set(&["Dog", "Cat"])
+    );
+}
@@ -0,0 +1,42 @@
+//! Tests for the index of class IRIs kept by an ontology
+
+use owl2_reasoner::*;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/index#{}", name)).unwrap()
+}
+
+#[test]
+fn test_duplicate_classes_are_found_through_the_index() {
+    let mut ontology = Ontology::new();
+    for i in 0..1_000 {
+        let class = Class::new(iri(&format!("C{}", i)));
+        assert_eq!(ontology.add_class(class).unwrap(), Insertion::Inserted);
+    }
+    assert_eq!(
+        ontology.add_class(Class::new(iri("C500"))).unwrap(),
+        Insertion::AlreadyPresent
+    );
+    assert_eq!(ontology.classes().len(), 1_000);
+    assert!(ontology.is_punned(&iri("C7")).class);
+    assert!(!ontology.is_punned(&iri("D7")).class);
+}
+
+#[test]
+fn test_index_survives_rebuilding_the_axioms() {
+    let mut ontology = Ontology::new();
+    ontology.add_class(Class::new(iri("A"))).unwrap();
+    let subclass = SubClassOfAxiom::new(
+        ClassExpression::Class(Class::new(iri("A"))),
+        ClassExpression::Class(Class::new(iri("B"))),
+    );
+    ontology.add_subclass_axiom(subclass.clone()).unwrap();
+    ontology.add_subclass_axiom(subclass).unwrap();
+
+    ontology.compact().unwrap();
+    assert_eq!(
+        ontology.add_class(Class::new(iri("A"))).unwrap(),
+        Insertion::AlreadyPresent
+    );
+    assert!(ontology.is_punned(&iri("A")).class);
+}
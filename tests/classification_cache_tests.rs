@@ -0,0 +1,138 @@
+//! Tests for saving and reloading a computed class hierarchy
+
+use owl2_reasoner::reasoning::{ClassHierarchy, ClassificationEngine};
+use owl2_reasoner::*;
+
+fn build_ontology() -> Ontology {
+    let mut ontology = Ontology::new();
+    let animal = Class::new("http://example.org/Animal");
+    let mammal = Class::new("http://example.org/Mammal");
+    let dog = Class::new("http://example.org/Dog");
+
+    for class in [&animal, &mammal, &dog] {
+        ontology.add_class(class.clone()).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(mammal.clone()),
+            ClassExpression::from(animal),
+        ))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(dog),
+            ClassExpression::from(mammal),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_hierarchy_save_and_load_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hierarchy.json");
+
+    let ontology = build_ontology();
+    let expected_hash = ontology.content_hash();
+    let mut engine = ClassificationEngine::new(ontology);
+    let result = engine.classify().unwrap();
+    result.hierarchy.save(&path).unwrap();
+
+    let loaded = ClassHierarchy::load(&path).unwrap();
+    let dog = IRI::new("http://example.org/Dog").unwrap();
+    let animal = IRI::new("http://example.org/Animal").unwrap();
+    assert_eq!(loaded.ontology_hash(), Some(expected_hash));
+    assert!(loaded.get_all_superclasses(&dog).contains(&animal));
+    assert_eq!(
+        loaded.get_all_superclasses(&dog),
+        result.hierarchy.get_all_superclasses(&dog)
+    );
+}
+
+#[test]
+fn test_classify_with_cache_reuses_valid_hierarchy() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hierarchy.json");
+
+    let first = ClassificationEngine::new(build_ontology())
+        .classify_with_cache(&path)
+        .unwrap();
+    assert!(!first.from_cache);
+    assert!(path.exists());
+
+    let second = ClassificationEngine::new(build_ontology())
+        .classify_with_cache(&path)
+        .unwrap();
+    assert!(second.from_cache);
+
+    let dog = IRI::new("http://example.org/Dog").unwrap();
+    assert_eq!(
+        second.hierarchy.get_all_superclasses(&dog),
+        first.hierarchy.get_all_superclasses(&dog)
+    );
+}
+
+#[test]
+fn test_classify_with_cache_recomputes_for_modified_ontology() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("hierarchy.json");
+
+    ClassificationEngine::new(build_ontology())
+        .classify_with_cache(&path)
+        .unwrap();
+
+    let mut modified = build_ontology();
+    let cat = Class::new("http://example.org/Cat");
+    modified.add_class(cat.clone()).unwrap();
+    modified
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(cat),
+            ClassExpression::from(Class::new("http://example.org/Mammal")),
+        ))
+        .unwrap();
+
+    let result = ClassificationEngine::new(modified.clone())
+        .classify_with_cache(&path)
+        .unwrap();
+    assert!(!result.from_cache);
+
+    let cat_iri = IRI::new("http://example.org/Cat").unwrap();
+    let animal = IRI::new("http://example.org/Animal").unwrap();
+    assert!(result
+        .hierarchy
+        .get_all_superclasses(&cat_iri)
+        .contains(&animal));
+
+    // The rewritten cache now matches the modified ontology
+    assert!(ClassHierarchy::load(&path).unwrap().is_valid_for(&modified));
+}
+
+#[test]
+fn test_content_hash_is_a_fixed_function_of_the_content() {
+    let build = |iri: IRI| {
+        let mut ontology = Ontology::new();
+        let class = Class::new(iri);
+        ontology.add_class(class.clone()).unwrap();
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(
+                ClassExpression::Class(class),
+                ClassExpression::Class(Class::new("http://example.org/hash#Animal")),
+            ))
+            .unwrap();
+        ontology
+    };
+    let plain = build(IRI::new("http://example.org/hash#Dog").unwrap());
+    let prefixed = build(IRI::with_prefix("http://example.org/hash#Dog", "ex").unwrap());
+
+    // The prefix an IRI was written with is not part of the content
+    assert_eq!(plain.content_hash(), prefixed.content_hash());
+    // Saved hierarchies compare against this value, so it must never drift
+    assert_eq!(plain.content_hash(), 0x97eb_2a74_559e_a1af);
+
+    // Axioms are hashed in functional syntax, which spells out only IRIs
+    let axiom = &plain.axioms()[0];
+    assert_eq!(
+        axiom.to_string(),
+        "SubClassOf(<http://example.org/hash#Dog> <http://example.org/hash#Animal>)"
+    );
+}
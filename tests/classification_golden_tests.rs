@@ -0,0 +1,121 @@
+//! Golden-file tests pinning the classification of reference ontologies
+//!
+//! Each reference ontology is classified and its hierarchy rendered as
+//! Manchester syntax frames, once with direct and once with all superclasses,
+//! and compared with `tests/data/classification/<name>.hierarchy`. Any change
+//! fails the test. After an intended change, rerun with
+//! `OWL2_UPDATE_GOLDEN=1` to rewrite the golden files and review the diff.
+
+use owl2_reasoner::parser::{OntologyParser, OwlFunctionalSyntaxParser, TurtleParser};
+use owl2_reasoner::reasoning::{ClassificationEngine, ManchesterFrameConfig};
+use owl2_reasoner::*;
+use std::path::PathBuf;
+
+fn data_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/classification")
+}
+
+/// The classification of an ontology in the golden file format
+fn render(ontology: Ontology) -> String {
+    let mut engine = ClassificationEngine::new(ontology);
+    let hierarchy = engine.classify().unwrap().hierarchy;
+    let all = ManchesterFrameConfig {
+        direct_only: false,
+        ..Default::default()
+    };
+    format!(
+        "# Direct superclasses\n\n{}# All superclasses\n\n{}",
+        hierarchy.to_manchester(),
+        hierarchy.to_manchester_with(&all)
+    )
+}
+
+/// The class frames of a rendering, each prefixed by the heading of its section
+fn frames(rendering: &str) -> Vec<String> {
+    let mut section = "";
+    let mut frames = Vec::new();
+    for block in rendering
+        .split("\n\n")
+        .filter(|block| !block.trim().is_empty())
+    {
+        if block.starts_with('#') {
+            section = block;
+        } else {
+            frames.push(format!("{}\n{}", section, block.trim_end()));
+        }
+    }
+    frames
+}
+
+/// Compare with the golden file, listing the frames that differ
+fn assert_matches_golden(name: &str, actual: &str) {
+    let path = data_dir().join(format!("{}.hierarchy", name));
+    if std::env::var_os("OWL2_UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+    if actual == expected {
+        return;
+    }
+
+    let expected_frames = frames(&expected);
+    let actual_frames = frames(actual);
+    let difference = |from: &[String], to: &[String]| {
+        from.iter()
+            .filter(|frame| !to.contains(frame))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+    panic!(
+        "classification of {} changed from {}\n\nexpected frames:\n{}\n\nactual frames:\n{}\n\n\
+         rerun with OWL2_UPDATE_GOLDEN=1 if the change is intended",
+        name,
+        path.display(),
+        difference(&expected_frames, &actual_frames),
+        difference(&actual_frames, &expected_frames),
+    );
+}
+
+/// The binary tree hierarchy classified by the reasoning benchmarks
+fn benchmark_hierarchy(size: usize) -> Ontology {
+    let mut ontology = Ontology::new();
+    let classes: Vec<Class> = (0..size)
+        .map(|i| Class::new(IRI::new(format!("http://example.org/class{}", i)).unwrap()))
+        .collect();
+    for class in &classes {
+        ontology.add_class(class.clone()).unwrap();
+    }
+    for i in 1..size {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(
+                ClassExpression::Class(classes[i].clone()),
+                ClassExpression::Class(classes[(i - 1) / 2].clone()),
+            ))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_family_classification_matches_golden() {
+    let ontology = OwlFunctionalSyntaxParser::new()
+        .parse_file(&data_dir().join("family.ofn"))
+        .unwrap();
+    assert_matches_golden("family", &render(ontology));
+}
+
+#[test]
+fn test_animals_classification_matches_golden() {
+    let ontology = TurtleParser::new()
+        .parse_file(&data_dir().join("animals.ttl"))
+        .unwrap();
+    assert_matches_golden("animals", &render(ontology));
+}
+
+#[test]
+fn test_benchmark_hierarchy_classification_matches_golden() {
+    assert_matches_golden("benchmark_hierarchy", &render(benchmark_hierarchy(15)));
+}
@@ -0,0 +1,191 @@
+//! Tests for closed-world query answering over selected predicates
+
+use owl2_reasoner::reasoning::{ClosedPredicates, QueryConfig, QueryValue};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+/// alice is Licensed, carol is not, and nothing is said about bob
+fn drivers() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["alice", "bob", "carol"] {
+        ontology
+            .add_named_individual(NamedIndividual::new(iri(name)))
+            .unwrap();
+    }
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            ClassExpression::from(Class::new(iri("Licensed"))),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("carol")),
+            ClassExpression::ObjectComplementOf(Arc::new(ClassExpression::from(Class::new(iri(
+                "Licensed",
+            ))))),
+        ))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("knows")),
+            Arc::new(iri("bob")),
+        ))
+        .unwrap();
+    ontology
+}
+
+fn individuals(result: &reasoning::QueryResult) -> Vec<IRI> {
+    result
+        .iter()
+        .filter_map(|binding| match binding.get_value("individual") {
+            Some(QueryValue::IRI(iri)) => Some(iri.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_closed_class_non_instances_include_unasserted_individuals() {
+    let config = QueryConfig::new()
+        .with_closed_predicates(ClosedPredicates::new().with_class(iri("Licensed")));
+    let engine = QueryEngine::with_config(drivers(), config);
+
+    let result = engine.get_class_non_instances(&iri("Licensed")).unwrap();
+    assert_eq!(individuals(&result), vec![iri("bob"), iri("carol")]);
+}
+
+#[test]
+fn test_open_class_non_instances_require_negative_assertion() {
+    let engine = QueryEngine::new(drivers());
+
+    let result = engine.get_class_non_instances(&iri("Licensed")).unwrap();
+    assert_eq!(individuals(&result), vec![iri("carol")]);
+}
+
+#[test]
+fn test_closed_property_treats_missing_assertion_as_false() {
+    let open = QueryEngine::new(drivers());
+    assert_eq!(
+        open.has_property_value(&iri("alice"), &iri("knows"), &iri("bob"))
+            .unwrap(),
+        Some(true)
+    );
+    assert_eq!(
+        open.has_property_value(&iri("bob"), &iri("knows"), &iri("alice"))
+            .unwrap(),
+        None
+    );
+
+    let config = QueryConfig::new()
+        .with_closed_predicates(ClosedPredicates::new().with_property(iri("knows")));
+    let closed = QueryEngine::with_config(drivers(), config);
+    assert_eq!(
+        closed
+            .has_property_value(&iri("bob"), &iri("knows"), &iri("alice"))
+            .unwrap(),
+        Some(false)
+    );
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+        ClassExpression::from(Class::new(iri(sub))),
+        ClassExpression::from(Class::new(iri(sup))),
+    )))
+}
+
+/// dave is a TruckDriver ⊑ Licensed, and erin is not a Driver ⊒ Licensed
+fn drivers_with_hierarchy() -> Ontology {
+    let mut ontology = drivers();
+    ontology
+        .add_axiom(subclass("TruckDriver", "Licensed"))
+        .unwrap();
+    ontology.add_axiom(subclass("Licensed", "Driver")).unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("dave")),
+            ClassExpression::from(Class::new(iri("TruckDriver"))),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("erin")),
+            ClassExpression::ObjectComplementOf(Arc::new(ClassExpression::from(Class::new(iri(
+                "Driver",
+            ))))),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_non_instances_follow_the_subclass_hierarchy() {
+    let config = QueryConfig::new()
+        .with_closed_predicates(ClosedPredicates::new().with_class(iri("Licensed")));
+    let closed = QueryEngine::with_config(drivers_with_hierarchy(), config);
+    let result = closed.get_class_non_instances(&iri("Licensed")).unwrap();
+    assert_eq!(
+        individuals(&result),
+        vec![iri("bob"), iri("carol"), iri("erin")]
+    );
+    assert!(result.stats.reasoning_used);
+
+    let open = QueryEngine::new(drivers_with_hierarchy());
+    let result = open.get_class_non_instances(&iri("Licensed")).unwrap();
+    assert_eq!(individuals(&result), vec![iri("carol"), iri("erin")]);
+
+    let told = QueryEngine::with_config(
+        drivers_with_hierarchy(),
+        QueryConfig::new().with_reasoning(false),
+    );
+    let result = told.get_class_non_instances(&iri("Licensed")).unwrap();
+    assert_eq!(individuals(&result), vec![iri("carol")]);
+    assert!(!result.stats.reasoning_used);
+}
+
+#[test]
+fn test_property_value_follows_sub_and_inverse_properties() {
+    let mut ontology = drivers();
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri("knows")), Arc::new(iri("acquaintedWith"))),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::InverseObjectProperties(Box::new(
+            InverseObjectPropertiesAxiom::new(
+                ObjectProperty::new(iri("knows")).into(),
+                ObjectProperty::new(iri("knownBy")).into(),
+            ),
+        )))
+        .unwrap();
+
+    let engine = QueryEngine::new(ontology.clone());
+    assert_eq!(
+        engine
+            .has_property_value(&iri("alice"), &iri("acquaintedWith"), &iri("bob"))
+            .unwrap(),
+        Some(true)
+    );
+    assert_eq!(
+        engine
+            .has_property_value(&iri("bob"), &iri("knownBy"), &iri("alice"))
+            .unwrap(),
+        Some(true)
+    );
+
+    let told = QueryEngine::with_config(ontology, QueryConfig::new().with_reasoning(false));
+    assert_eq!(
+        told.has_property_value(&iri("alice"), &iri("acquaintedWith"), &iri("bob"))
+            .unwrap(),
+        None
+    );
+}
@@ -0,0 +1,94 @@
+//! Tests for keeping Turtle comments through a load and save
+
+use owl2_reasoner::constants::SOURCE_COMMENT;
+use owl2_reasoner::parser::{OntologyParser, OwlXmlSerializer, ParserConfig, TurtleParser};
+use owl2_reasoner::*;
+
+const DOCUMENT: &str = r#"# Pizza ontology, maintained by the kitchen
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix ex: <http://example.org/pizza#> .
+
+# Anything with a base and toppings
+ex:Pizza a owl:Class .
+
+ex:Margherita a owl:Class ;  # the classic
+    rdfs:subClassOf ex:Pizza .
+"#;
+
+fn parse(preserve_comments: bool) -> Ontology {
+    let config = ParserConfig {
+        preserve_comments,
+        ..Default::default()
+    };
+    TurtleParser::with_config(config)
+        .parse_str(DOCUMENT)
+        .unwrap()
+}
+
+/// The source comments attached to an entity
+fn comments(ontology: &Ontology, name: &str) -> Vec<String> {
+    let subject = IRI::new(format!("http://example.org/pizza#{}", name)).unwrap();
+    ontology
+        .annotation_assertion_axioms()
+        .into_iter()
+        .filter(|axiom| {
+            axiom.annotation_property().as_str() == SOURCE_COMMENT && **axiom.subject() == subject
+        })
+        .map(|axiom| match axiom.value() {
+            AnnotationValue::Literal(literal) => literal.lexical_form().to_string(),
+            value => panic!("unexpected comment value {:?}", value),
+        })
+        .collect()
+}
+
+#[test]
+fn test_comments_are_attached_to_the_following_entity() {
+    let ontology = parse(true);
+
+    assert_eq!(
+        comments(&ontology, "Pizza"),
+        ["Anything with a base and toppings"]
+    );
+    assert_eq!(comments(&ontology, "Margherita"), ["the classic"]);
+    assert_eq!(
+        ontology
+            .header()
+            .annotation_values(&IRI::new(SOURCE_COMMENT).unwrap()),
+        [&AnnotationValue::Literal(Literal::simple(
+            "Pizza ontology, maintained by the kitchen"
+        ))]
+    );
+    assert_eq!(ontology.classes().len(), 2);
+    assert_eq!(ontology.subclass_axioms().len(), 1);
+}
+
+#[test]
+fn test_comments_survive_serialization() {
+    let document = OwlXmlSerializer::new().serialize(&parse(true)).unwrap();
+
+    let comment = document
+        .find("<!-- Anything with a base and toppings -->")
+        .expect("class comment was not written");
+    let declaration = document
+        .find(r#"<Class IRI="http://example.org/pizza#Pizza"/>"#)
+        .unwrap();
+    assert!(comment < declaration, "{}", document);
+    assert!(document.contains("<!-- the classic -->"), "{}", document);
+    assert!(
+        document.contains("<!-- Pizza ontology, maintained by the kitchen -->"),
+        "{}",
+        document
+    );
+    assert!(!document.contains(SOURCE_COMMENT), "{}", document);
+}
+
+#[test]
+fn test_comments_are_dropped_by_default() {
+    let ontology = parse(false);
+
+    assert!(ontology.annotation_assertion_axioms().is_empty());
+    assert!(ontology.annotations().is_empty());
+    let document = OwlXmlSerializer::new().serialize(&ontology).unwrap();
+    assert!(!document.contains("<!--"), "{}", document);
+}
@@ -0,0 +1,36 @@
+//! Builders shared by the integration tests
+//!
+//! A test file declares `mod common;` and names entities by their local name
+//! in the [`EX`] namespace. Not every file uses every builder.
+#![allow(dead_code)]
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+/// Namespace of the entities the builders name
+pub const EX: &str = "http://example.org/";
+
+/// IRI of `name` in the [`EX`] namespace
+pub fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+/// Named class expression for `name`
+pub fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+/// Named object property expression for `name`
+pub fn property(name: &str) -> ObjectPropertyExpression {
+    ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(iri(name))))
+}
+
+/// ∃property.filler
+pub fn some(property_name: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(Box::new(property(property_name)), Arc::new(filler))
+}
+
+/// sub ⊑ sup between named classes
+pub fn subclass(sub: &str, sup: &str) -> SubClassOfAxiom {
+    SubClassOfAxiom::new(class(sub), class(sup))
+}
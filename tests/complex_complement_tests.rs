@@ -0,0 +1,102 @@
+//! Tests for clashes between complex class expressions and their complements
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/zoo#";
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(format!("{}{}", EX, name)))
+}
+
+fn eats() -> ObjectPropertyExpression {
+    ObjectProperty::new(IRI::new(format!("{}eats", EX)).unwrap()).into()
+}
+
+fn not(expression: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectComplementOf(Arc::new(expression))
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn or(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectUnionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn some(filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(Box::new(eats()), Arc::new(filler))
+}
+
+fn only(filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectAllValuesFrom(Box::new(eats()), Arc::new(filler))
+}
+
+#[test]
+fn test_existential_with_universal_complement_is_unsatisfiable() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    // The existential forces a successor that is both Meat and not Meat
+    let concept = and(vec![some(class("Meat")), only(not(class("Meat")))]);
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+
+    // A named class defined that way is unsatisfiable too
+    let mut ontology = Ontology::new();
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::from_expressions(vec![
+            class("Impossible"),
+            concept,
+        ]))
+        .unwrap();
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(!reasoner
+        .is_class_satisfiable(&IRI::new(format!("{}Impossible", EX)).unwrap())
+        .unwrap());
+}
+
+#[test]
+fn test_complex_expression_and_its_complement_clash() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    let meat = class("Meat");
+    let plant = class("Plant");
+
+    for expression in [
+        some(meat.clone()),
+        only(meat.clone()),
+        and(vec![meat.clone(), plant.clone()]),
+        or(vec![meat.clone(), plant.clone()]),
+        not(meat.clone()),
+        and(vec![
+            some(meat.clone()),
+            only(or(vec![meat.clone(), plant])),
+        ]),
+    ] {
+        let concept = and(vec![expression.clone(), not(expression.clone())]);
+        assert!(
+            !reasoner.is_class_expression_satisfiable(&concept).unwrap(),
+            "{:?}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_repeated_complements_are_satisfiable() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    let meat = class("Meat");
+
+    for concept in [
+        and(vec![not(meat.clone()), not(meat.clone())]),
+        and(vec![not(some(meat.clone())), only(not(meat.clone()))]),
+        and(vec![some(meat.clone()), only(meat.clone())]),
+        and(vec![some(meat.clone()), not(only(meat.clone()))]),
+    ] {
+        assert!(
+            reasoner.is_class_expression_satisfiable(&concept).unwrap(),
+            "{:?}",
+            concept
+        );
+    }
+}
@@ -0,0 +1,107 @@
+//! Tests for the reasoning configuration presets
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::reasoning::ReasoningConfig;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> Arc<ClassExpression> {
+    Arc::new(ClassExpression::Class(Class::new(
+        IRI::new(format!("http://example.org/{}", name)).unwrap(),
+    )))
+}
+
+/// (A ⊔ B) ⊓ ¬A, which needs the tableau to branch
+fn disjunctive_concept() -> ClassExpression {
+    let a = class("A");
+    ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(ClassExpression::ObjectUnionOf(
+                vec![a.clone(), class("B")].into(),
+            )),
+            Arc::new(ClassExpression::ObjectComplementOf(a)),
+        ]
+        .into(),
+    )
+}
+
+#[test]
+fn test_fast_preset_skips_tableaux() {
+    let config = ReasoningConfig::fast();
+
+    assert!(config.enable_reasoning);
+    assert!(!config.use_advanced_reasoning);
+    assert_eq!(config.tableaux_config.max_depth, 100);
+    assert_eq!(config.tableaux_config.timeout, Some(1_000));
+    assert_eq!(config.tableaux_config.max_branches, Some(1_000));
+}
+
+#[test]
+fn test_complete_preset_has_no_limits() {
+    let config = ReasoningConfig::complete();
+
+    assert!(config.enable_reasoning);
+    assert!(config.use_advanced_reasoning);
+    assert_eq!(config.tableaux_config.max_depth, 10_000);
+    assert_eq!(config.tableaux_config.timeout, None);
+    assert_eq!(config.tableaux_config.max_branches, None);
+}
+
+#[test]
+fn test_server_preset_bounds_each_call() {
+    let config = ReasoningConfig::server();
+
+    assert!(config.enable_reasoning);
+    assert!(config.use_advanced_reasoning);
+    assert_eq!(config.tableaux_config.max_depth, 500);
+    assert_eq!(config.tableaux_config.timeout, Some(5_000));
+    assert_eq!(config.tableaux_config.max_branches, Some(10_000));
+}
+
+#[test]
+fn test_server_preset_enforces_timeout() {
+    let mut config = ReasoningConfig::server();
+    // Expire the deadline at once so the first check trips it
+    config.tableaux_config.timeout = Some(0);
+    let reasoner = TableauxReasoner::with_config(Ontology::new(), config.tableaux_config);
+
+    let error = reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept())
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        OwlError::TimeoutError { timeout_ms: 0, .. }
+    ));
+
+    // Within the preset's own timeout the same question is answered
+    let reasoner =
+        TableauxReasoner::with_config(Ontology::new(), ReasoningConfig::server().tableaux_config);
+    assert!(reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept())
+        .unwrap());
+}
+
+#[test]
+fn test_presets_answer_queries() {
+    let mut ontology = Ontology::new();
+    let a = Class::new("http://example.org/A");
+    let b = Class::new("http://example.org/B");
+    ontology.add_class(a.clone()).unwrap();
+    ontology.add_class(b.clone()).unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::Class(a.clone()),
+            ClassExpression::Class(b.clone()),
+        ))
+        .unwrap();
+
+    for config in [
+        ReasoningConfig::fast(),
+        ReasoningConfig::complete(),
+        ReasoningConfig::server(),
+    ] {
+        let mut reasoner = OwlReasoner::with_config(ontology.clone(), config);
+        assert!(reasoner.is_consistent().unwrap());
+        assert!(reasoner.is_subclass_of(a.iri(), b.iri()).unwrap());
+    }
+}
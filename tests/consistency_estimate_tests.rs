@@ -0,0 +1,105 @@
+//! Tests for the quick consistency estimate
+
+mod common;
+
+use common::{class, iri, subclass};
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::reasoning::ConsistencyEstimate;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn assert_type(ontology: &mut Ontology, individual: &str, class_expr: ClassExpression) {
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri(individual)),
+            class_expr,
+        ))
+        .unwrap();
+}
+
+/// Dog ⊑ Animal, Animal disjoint with Plant, rex a Dog
+fn animals_disjoint_from_plants() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_subclass_axiom(subclass("Dog", "Animal"))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Plant")),
+        ]))
+        .unwrap();
+    assert_type(&mut ontology, "rex", class("Dog"));
+    ontology
+}
+
+#[test]
+fn test_asserted_clashes_are_certain() {
+    let mut ontology = animals_disjoint_from_plants();
+    assert_type(&mut ontology, "fern", class("Animal"));
+    assert_type(&mut ontology, "fern", class("Plant"));
+    assert_type(
+        &mut ontology,
+        "rex",
+        ClassExpression::ObjectComplementOf(Arc::new(class("Dog"))),
+    );
+    ontology
+        .add_axiom(Axiom::SameIndividual(Box::new(SameIndividualAxiom::new(
+            vec![Arc::new(iri("rex")), Arc::new(iri("fido"))],
+        ))))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::DifferentIndividuals(Box::new(
+            DifferentIndividualsAxiom::new(vec![Arc::new(iri("rex")), Arc::new(iri("fido"))]),
+        )))
+        .unwrap();
+
+    let estimate = OwlReasoner::new(ontology).estimate_consistency();
+    assert!(!estimate.likely_consistent);
+    assert_eq!(estimate.confidence, 1.0);
+    assert_eq!(estimate.cheap_violations.len(), 3, "{:?}", estimate);
+    assert!(estimate
+        .cheap_violations
+        .iter()
+        .any(|violation| violation.contains("disjoint classes")));
+}
+
+#[test]
+fn test_horn_ontologies_are_decided_by_completion() {
+    let reasoner = OwlReasoner::new(animals_disjoint_from_plants());
+    let estimate = reasoner.estimate_consistency();
+    assert!(estimate.likely_consistent);
+    assert_eq!(estimate.confidence, 1.0);
+    assert!(estimate.cheap_violations.is_empty());
+
+    // No told clash, but rex is an Animal through Dog
+    let mut ontology = animals_disjoint_from_plants();
+    assert_type(&mut ontology, "rex", class("Plant"));
+    let mut reasoner = OwlReasoner::new(ontology);
+    let estimate = reasoner.estimate_consistency();
+    assert!(!estimate.likely_consistent);
+    assert_eq!(estimate.confidence, 1.0);
+    assert!(estimate.cheap_violations.is_empty());
+    assert!(!reasoner.is_consistent().unwrap());
+}
+
+#[test]
+fn test_other_ontologies_get_a_hedged_answer() {
+    let mut ontology = animals_disjoint_from_plants();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("Pet"),
+            ClassExpression::ObjectUnionOf(
+                vec![Arc::new(class("Dog")), Arc::new(class("Cat"))].into(),
+            ),
+        ))
+        .unwrap();
+
+    let estimate = OwlReasoner::new(ontology).estimate_consistency();
+    assert!(estimate.likely_consistent);
+    assert!(estimate.confidence < 1.0);
+    assert_eq!(
+        estimate.confidence,
+        ConsistencyEstimate::UNDECIDED_CONFIDENCE
+    );
+}
@@ -290,6 +290,40 @@ fn test_ontology_class_management() {
     assert_eq!(ontology.classes().iter().count(), 2);
 }
 
+#[test]
+fn test_duplicate_entity_addition_reports_already_present() {
+    let mut ontology = Ontology::new();
+    let person = Class::new(Arc::new(IRI::new("http://example.org/Person").unwrap()));
+
+    assert_eq!(
+        ontology.add_class(person.clone()).unwrap(),
+        Insertion::Inserted
+    );
+    assert_eq!(
+        ontology.add_class(person).unwrap(),
+        Insertion::AlreadyPresent
+    );
+    assert_eq!(ontology.classes().len(), 1);
+
+    let has_parent =
+        ObjectProperty::new(Arc::new(IRI::new("http://example.org/hasParent").unwrap()));
+    assert!(ontology
+        .add_object_property(has_parent.clone())
+        .unwrap()
+        .is_inserted());
+    assert!(!ontology
+        .add_object_property(has_parent)
+        .unwrap()
+        .is_inserted());
+
+    let john = NamedIndividual::new(Arc::new(IRI::new("http://example.org/John").unwrap()));
+    ontology.add_named_individual(john.clone()).unwrap();
+    assert_eq!(
+        ontology.add_named_individual(john).unwrap(),
+        Insertion::AlreadyPresent
+    );
+}
+
 #[test]
 fn test_ontology_property_management() {
     let mut ontology = Ontology::new();
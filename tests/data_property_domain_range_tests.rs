@@ -0,0 +1,136 @@
+//! Tests for reasoning with data property domains and ranges
+
+use owl2_reasoner::datatypes::is_literal_of_datatype;
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::reasoning::ReasoningConfig;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/hr#";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn xsd(name: &str) -> IRI {
+    IRI::new(format!("{}{}", XSD, name)).unwrap()
+}
+
+/// hasAge has domain Person and range xsd:integer
+fn staff(age: Literal) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_class(Class::new(iri("Person"))).unwrap();
+    ontology
+        .add_axiom(Axiom::DataPropertyDomain(Box::new(
+            DataPropertyDomainAxiom::new(
+                iri("hasAge"),
+                ClassExpression::Class(Class::new(iri("Person"))),
+            ),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::DataPropertyRange(Box::new(
+            DataPropertyRangeAxiom::new(iri("hasAge"), xsd("integer")),
+        )))
+        .unwrap();
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("hasAge")),
+            age,
+        ))
+        .unwrap();
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            Arc::new(iri("bob")),
+            Arc::new(iri("hasName")),
+            Literal::simple("Bob"),
+        ))
+        .unwrap();
+    ontology
+}
+
+fn is_consistent(ontology: Ontology) -> [bool; 3] {
+    let simple = ReasoningConfig {
+        use_advanced_reasoning: false,
+        ..Default::default()
+    };
+    [
+        OwlReasoner::new(ontology.clone()).is_consistent().unwrap(),
+        OwlReasoner::with_config(ontology.clone(), simple)
+            .is_consistent()
+            .unwrap(),
+        TableauxReasoner::new(ontology).check_consistency().unwrap(),
+    ]
+}
+
+#[test]
+fn test_value_outside_range_is_inconsistent() {
+    assert_eq!(is_consistent(staff(Literal::simple("forty"))), [false; 3]);
+    assert_eq!(
+        is_consistent(staff(Literal::typed("forty", xsd("integer")))),
+        [false; 3]
+    );
+    assert_eq!(
+        is_consistent(staff(Literal::typed("40", xsd("integer")))),
+        [true; 3]
+    );
+    // xsd:int is derived from xsd:integer
+    assert_eq!(
+        is_consistent(staff(Literal::typed("40", xsd("int")))),
+        [true; 3]
+    );
+}
+
+#[test]
+fn test_domain_infers_class_membership() {
+    let ontology = staff(Literal::typed("40", xsd("integer")));
+
+    let tableaux = TableauxReasoner::new(ontology.clone());
+    assert!(tableaux
+        .is_instance_of(&iri("alice"), &iri("Person"))
+        .unwrap());
+    assert!(!tableaux
+        .is_instance_of(&iri("bob"), &iri("Person"))
+        .unwrap());
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Person"))
+        .unwrap());
+}
+
+#[test]
+fn test_literal_datatype_membership() {
+    let integer = xsd("integer");
+    assert!(is_literal_of_datatype(
+        &Literal::typed("-3", xsd("negativeInteger")),
+        &integer
+    ));
+    assert!(!is_literal_of_datatype(
+        &Literal::typed("3", xsd("decimal")),
+        &integer
+    ));
+    assert!(!is_literal_of_datatype(
+        &Literal::typed("1.5", xsd("double")),
+        &xsd("decimal")
+    ));
+    assert!(is_literal_of_datatype(
+        &Literal::lang_tagged("chat", "fr"),
+        &IRI::new("http://www.w3.org/1999/02/22-rdf-syntax-ns#PlainLiteral").unwrap()
+    ));
+    assert!(!is_literal_of_datatype(
+        &Literal::lang_tagged("chat", "fr"),
+        &xsd("string")
+    ));
+    assert!(is_literal_of_datatype(
+        &Literal::simple("anything"),
+        &IRI::new("http://www.w3.org/2000/01/rdf-schema#Literal").unwrap()
+    ));
+    // Datatypes that are not built in are not checked
+    assert!(is_literal_of_datatype(
+        &Literal::simple("warm"),
+        &IRI::new("http://example.org/units#celsius").unwrap()
+    ));
+}
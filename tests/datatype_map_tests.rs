@@ -0,0 +1,112 @@
+//! Tests for configuring the global datatype map
+
+use owl2_reasoner::datatypes::{data_range_violations, is_literal_of_datatype};
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::*;
+use std::sync::{Arc, Mutex};
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const PERCENT: &str = "http://example.org/units#percent";
+
+/// The tests replace the process-wide map, so they must not overlap
+static GLOBAL_MAP: Mutex<()> = Mutex::new(());
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/units#{}", name)).unwrap()
+}
+
+fn percent(lexical: &str) -> Literal {
+    Literal::typed(lexical, IRI::new(PERCENT).unwrap())
+}
+
+/// The default map with percentages, integers from 0 to 100
+fn with_percent() -> LiteralParserRegistry {
+    let mut map = LiteralParserRegistry::new();
+    map.register_datatype(PERCENT, Some(&format!("{}integer", XSD)));
+    map.register(PERCENT, |lexical| {
+        let value: i64 = lexical.trim().parse().ok()?;
+        (0..=100)
+            .contains(&value)
+            .then_some(LiteralValue::Integer(value))
+    });
+    map
+}
+
+#[test]
+fn test_custom_datatype_validates_literals() {
+    let _guard = GLOBAL_MAP.lock().unwrap();
+    let datatype = IRI::new(PERCENT).unwrap();
+
+    // Unrecognized, the datatype accepts any literal
+    assert!(is_literal_of_datatype(&Literal::simple("many"), &datatype));
+
+    let previous = set_global_literal_parsers(with_percent());
+    assert!(is_literal_of_datatype(&percent("42"), &datatype));
+    assert_eq!(percent("42").as_i64(), Some(42));
+    assert!(!is_literal_of_datatype(&percent("142"), &datatype));
+    assert!(!is_literal_of_datatype(&Literal::simple("42"), &datatype));
+    // Percentages are integers, but not every integer is a percentage
+    assert!(is_literal_of_datatype(
+        &percent("42"),
+        &IRI::new(format!("{}integer", XSD)).unwrap()
+    ));
+    assert!(!is_literal_of_datatype(
+        &Literal::typed("42", IRI::new(format!("{}integer", XSD)).unwrap()),
+        &datatype
+    ));
+
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::DataPropertyRange(Box::new(
+            DataPropertyRangeAxiom::new(iri("humidity"), datatype),
+        )))
+        .unwrap();
+    for (station, value) in [("north", percent("80")), ("south", percent("180"))] {
+        ontology
+            .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+                Arc::new(iri(station)),
+                Arc::new(iri("humidity")),
+                value,
+            ))
+            .unwrap();
+    }
+    let violations = data_range_violations(&ontology);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].value().lexical_form(), "180");
+
+    set_global_literal_parsers(previous);
+}
+
+#[test]
+fn test_turtle_infers_only_recognized_datatypes() {
+    let _guard = GLOBAL_MAP.lock().unwrap();
+    let content = "@prefix ex: <http://example.org/units#> .\n\
+                   ex:north ex:humidity 80 .\n\
+                   ex:north ex:ratio 1.5e2 .\n";
+    let datatypes = || {
+        let ontology = TurtleParser::new().parse_str(content).unwrap();
+        let mut datatypes: Vec<String> = ontology
+            .data_property_assertions()
+            .iter()
+            .map(|assertion| assertion.value().datatype().as_str().to_string())
+            .collect();
+        datatypes.sort();
+        datatypes
+    };
+
+    assert_eq!(
+        datatypes(),
+        vec![format!("{}double", XSD), format!("{}integer", XSD)]
+    );
+
+    // A community that does not use xsd:double
+    let mut map = LiteralParserRegistry::new();
+    assert!(map.unregister_datatype(&format!("{}double", XSD)));
+    let previous = set_global_literal_parsers(map);
+    let subset = datatypes();
+    set_global_literal_parsers(previous);
+    assert_eq!(
+        subset,
+        vec![format!("{}integer", XSD), format!("{}string", XSD)]
+    );
+}
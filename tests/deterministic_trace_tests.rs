@@ -0,0 +1,78 @@
+//! Tests for deterministic tableau expansion and reasoning traces
+
+use owl2_reasoner::reasoning::tableaux::{ReasoningConfig, TableauxReasoner, TraceEvent};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> Arc<ClassExpression> {
+    Arc::new(ClassExpression::Class(Class::new(
+        IRI::new(format!("http://example.org/{}", name)).unwrap(),
+    )))
+}
+
+/// (A1 ⊔ B1) ⊓ … ⊓ (An ⊔ Bn) ⊓ ¬A1 ⊓ … ⊓ ¬An, plus `extra` unrelated named classes
+fn disjunctive_concept(size: usize, extra: usize) -> ClassExpression {
+    let mut conjuncts = Vec::new();
+    for i in 0..size {
+        let a = class(&format!("A{}", i));
+        conjuncts.push(Arc::new(ClassExpression::ObjectUnionOf(
+            vec![a.clone(), class(&format!("B{}", i))].into(),
+        )));
+        conjuncts.push(Arc::new(ClassExpression::ObjectComplementOf(a)));
+    }
+    for i in 0..extra {
+        conjuncts.push(class(&format!("C{}", i)));
+    }
+    ClassExpression::ObjectIntersectionOf(conjuncts.into())
+}
+
+fn traced_run(concept: &ClassExpression) -> (bool, Vec<TraceEvent>) {
+    let config = ReasoningConfig {
+        deterministic: true,
+        record_trace: true,
+        ..Default::default()
+    };
+    let reasoner = TableauxReasoner::with_config(Ontology::new(), config);
+    let satisfiable = reasoner.is_class_expression_satisfiable(concept).unwrap();
+    (satisfiable, reasoner.last_trace())
+}
+
+#[test]
+fn test_deterministic_mode_yields_identical_traces() {
+    // Well over the number of concepts a node keeps in its small vector
+    let concept = disjunctive_concept(5, 12);
+
+    let (first_result, first_trace) = traced_run(&concept);
+    let (second_result, second_trace) = traced_run(&concept);
+
+    assert!(first_result);
+    assert_eq!(first_result, second_result);
+    assert!(first_trace
+        .iter()
+        .any(|event| matches!(event, TraceEvent::Branch { .. })));
+    assert_eq!(first_trace, second_trace);
+}
+
+#[test]
+fn test_trace_records_clash_for_unsatisfiable_concept() {
+    let a = class("A");
+    let mut conjuncts = vec![a.clone(), Arc::new(ClassExpression::ObjectComplementOf(a))];
+    conjuncts.extend((0..10).map(|i| class(&format!("C{}", i))));
+    let concept = ClassExpression::ObjectIntersectionOf(conjuncts.into());
+
+    let (satisfiable, trace) = traced_run(&concept);
+    assert!(!satisfiable);
+    assert!(trace
+        .iter()
+        .any(|event| matches!(event, TraceEvent::Clash { .. })));
+    assert_eq!(trace, traced_run(&concept).1);
+}
+
+#[test]
+fn test_trace_is_empty_unless_recording() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    assert!(reasoner
+        .is_class_expression_satisfiable(&disjunctive_concept(3, 0))
+        .unwrap());
+    assert!(reasoner.last_trace().is_empty());
+}
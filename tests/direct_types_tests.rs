@@ -0,0 +1,95 @@
+//! Tests for the most specific named types of an individual
+
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/zoo#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn types(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+/// Puppy ⊑ Dog ⊑ Animal and Pet, with ∃hasPet.Animal ⊑ Owner
+fn zoo() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Dog", "Puppy", "Pet", "Owner"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [("Puppy", "Dog"), ("Dog", "Animal")] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+            .unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::ObjectSomeValuesFrom(
+                Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+                    ObjectProperty::new(iri("hasPet")),
+                ))),
+                Arc::new(class("Animal")),
+            ),
+            class("Owner"),
+        ))
+        .unwrap();
+    for (individual, type_name) in [
+        ("rex", "Puppy"),
+        ("fido", "Dog"),
+        ("fido", "Animal"),
+        ("fido", "Pet"),
+    ] {
+        ontology
+            .add_class_assertion(ClassAssertionAxiom::new(
+                Arc::new(iri(individual)),
+                class(type_name),
+            ))
+            .unwrap();
+    }
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("john")),
+            Arc::new(iri("hasPet")),
+            Arc::new(iri("rex")),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_subclass_assertion_gives_only_the_subclass() {
+    let mut reasoner = OwlReasoner::new(zoo());
+
+    assert!(reasoner
+        .is_instance_of(&iri("rex"), &iri("Animal"))
+        .unwrap());
+    assert_eq!(
+        reasoner.direct_types(&iri("rex")).unwrap(),
+        types(&["Puppy"])
+    );
+}
+
+#[test]
+fn test_asserted_ancestors_are_pruned() {
+    let mut reasoner = OwlReasoner::new(zoo());
+    assert_eq!(
+        reasoner.direct_types(&iri("fido")).unwrap(),
+        types(&["Dog", "Pet"])
+    );
+}
+
+#[test]
+fn test_inferred_types_are_included() {
+    let mut reasoner = OwlReasoner::new(zoo());
+    assert_eq!(
+        reasoner.direct_types(&iri("john")).unwrap(),
+        types(&["Owner"])
+    );
+}
@@ -0,0 +1,64 @@
+//! Tests for enumerating the entailed disjointness between named classes
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/nature#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn pair(a: &str, b: &str) -> (IRI, IRI) {
+    let (a, b) = (iri(a), iri(b));
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+fn nature() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Plant", "Dog", "Oak"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Plant")),
+        ]))
+        .unwrap();
+    for (sub, sup) in [("Dog", "Animal"), ("Oak", "Plant")] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_explicit_and_inferred_disjointness() {
+    let mut reasoner = OwlReasoner::new(nature());
+    let pairs = reasoner.disjoint_class_pairs().unwrap();
+
+    assert!(pairs.contains(&pair("Animal", "Plant")));
+    // Inherited from the disjointness of the superclasses
+    assert!(pairs.contains(&pair("Dog", "Oak")));
+    assert!(pairs.contains(&pair("Dog", "Plant")));
+    assert!(pairs.contains(&pair("Animal", "Oak")));
+    assert_eq!(pairs.len(), 4);
+}
+
+#[test]
+fn test_subsumed_classes_are_not_disjoint() {
+    let mut reasoner = OwlReasoner::new(nature());
+    let pairs = reasoner.disjoint_class_pairs().unwrap();
+
+    assert!(!pairs.contains(&pair("Dog", "Animal")));
+    assert!(!pairs.contains(&pair("Oak", "Plant")));
+}
@@ -0,0 +1,95 @@
+//! Tests for publishing test outcomes as W3C EARL reports
+
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::validation::{EarlOutcome, EarlReporter};
+use owl2_reasoner::*;
+
+const EARL: &str = "http://www.w3.org/ns/earl#";
+const SUBJECT: &str = "http://example.org/owl2-reasoner";
+const TESTS: &str = "http://www.w3.org/2007/OWL/testOntology#";
+
+fn earl(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EARL, name)).unwrap()
+}
+
+fn report() -> EarlReporter {
+    let mut reporter = EarlReporter::new(SUBJECT, "http://example.org/ci")
+        .with_subject_name("owl2-reasoner")
+        .with_subject_version("0.2.0")
+        .with_assertor_name("Continuous integration")
+        .with_date("2024-05-01T12:00:00Z");
+    reporter.add_result(format!("{}consistent-001", TESTS), true);
+    reporter.add_result(format!("{}inconsistent-002", TESTS), true);
+    reporter.add_result_with_info(
+        format!("{}entailment-003", TESTS),
+        false,
+        "expected \"A ⊑ B\" to be entailed",
+    );
+    reporter.add_result(format!("{}rdf-based-004", TESTS), EarlOutcome::Inapplicable);
+    reporter
+}
+
+/// The outcome asserted for a test, following earl:test and earl:result
+fn outcome_of(ontology: &Ontology, test: &str) -> Option<IRI> {
+    let objects = |subject: &IRI, property: &str| -> Vec<IRI> {
+        ontology
+            .property_assertions()
+            .into_iter()
+            .filter(|axiom| **axiom.subject() == *subject && **axiom.property() == earl(property))
+            .filter_map(|axiom| match axiom.object() {
+                PropertyAssertionObject::Named(object) => Some((**object).clone()),
+                PropertyAssertionObject::Anonymous(node) => {
+                    IRI::new(format!("http://blank.node/{}", node.node_id())).ok()
+                }
+            })
+            .collect()
+    };
+
+    let test = IRI::new(test).unwrap();
+    let assertion = ontology.property_assertions().into_iter().find(|axiom| {
+        **axiom.property() == earl("test")
+            && axiom.object_iri().is_some_and(|object| **object == test)
+    })?;
+    let result = objects(assertion.subject(), "result").pop()?;
+    objects(&result, "outcome").pop()
+}
+
+#[test]
+fn test_earl_report_parses_with_expected_assertions() {
+    let reporter = report();
+    assert_eq!(reporter.count(EarlOutcome::Passed), 2);
+    assert_eq!(reporter.count(EarlOutcome::Failed), 1);
+
+    let turtle = reporter.to_turtle();
+    assert!(turtle.contains("earl:info \"expected \\\"A ⊑ B\\\" to be entailed\""));
+    let ontology = TurtleParser::new().parse_str(&turtle).unwrap();
+
+    let assertions = ontology
+        .class_assertions()
+        .into_iter()
+        .filter(|axiom| {
+            axiom.class_expr() == &ClassExpression::Class(Class::new(earl("Assertion")))
+        })
+        .count();
+    assert_eq!(assertions, 4);
+
+    for (test, outcome) in [
+        ("consistent-001", "passed"),
+        ("inconsistent-002", "passed"),
+        ("entailment-003", "failed"),
+        ("rdf-based-004", "inapplicable"),
+    ] {
+        assert_eq!(
+            outcome_of(&ontology, &format!("{}{}", TESTS, test)),
+            Some(earl(outcome)),
+            "outcome of {}",
+            test
+        );
+    }
+
+    let subject = IRI::new(SUBJECT).unwrap();
+    assert!(ontology.property_assertions().into_iter().all(|axiom| {
+        **axiom.property() != earl("subject")
+            || axiom.object_iri().is_some_and(|object| **object == subject)
+    }));
+}
@@ -0,0 +1,98 @@
+//! Tests that every parser reads empty and blank documents as an empty ontology
+
+use owl2_reasoner::parser::{
+    JsonLdParser, ManchesterParser, NtriplesParser, OntologyParser, OwlFunctionalSyntaxParser,
+    OwlXmlParser, RdfXmlParser, TurtleParser,
+};
+
+/// Parse each document and check it yields an empty ontology
+fn assert_empty_documents(parser: &dyn OntologyParser, documents: &[&str]) {
+    for document in documents {
+        let ontology = parser.parse_str(document).unwrap_or_else(|e| {
+            panic!(
+                "{} rejected blank document {:?}: {}",
+                parser.format_name(),
+                document,
+                e
+            )
+        });
+        assert_eq!(
+            ontology.entity_count(),
+            0,
+            "{} found entities in {:?}",
+            parser.format_name(),
+            document
+        );
+    }
+}
+
+#[test]
+fn test_empty_turtle_input() {
+    assert_empty_documents(
+        &TurtleParser::new(),
+        &["", "  \n\t\n", "# a comment\n   # another\n"],
+    );
+}
+
+#[test]
+fn test_empty_ntriples_input() {
+    assert_empty_documents(&NtriplesParser::new(), &["", "  \n\t\n", "# a comment\n"]);
+}
+
+#[test]
+fn test_empty_rdf_xml_input() {
+    assert_empty_documents(
+        &RdfXmlParser::new(),
+        &[
+            "",
+            "  \n\t\n",
+            "<?xml version=\"1.0\"?>\n<!-- a comment -->\n",
+        ],
+    );
+}
+
+#[test]
+fn test_empty_owl_xml_input() {
+    assert_empty_documents(
+        &OwlXmlParser::new(),
+        &["", "  \n\t\n", "<!-- a comment -->\n"],
+    );
+}
+
+#[test]
+fn test_empty_functional_syntax_input() {
+    assert_empty_documents(
+        &OwlFunctionalSyntaxParser::new(),
+        &["", "  \n\t\n", "# a comment\n  # another\n"],
+    );
+}
+
+#[test]
+fn test_empty_manchester_input() {
+    assert_empty_documents(
+        &ManchesterParser::new(),
+        &["", "  \n\t\n", "# a comment\n\n"],
+    );
+}
+
+#[test]
+fn test_empty_json_ld_input() {
+    assert_empty_documents(&JsonLdParser::new(), &["", "  \n\t\n"]);
+}
+
+#[test]
+fn test_comments_around_content_are_ignored() {
+    let functional = OwlFunctionalSyntaxParser::new()
+        .parse_str(
+            "# leading comment\n\
+             Ontology(<http://example.org/o> # trailing comment\n\
+             Declaration(Class(<http://example.org/o#A>)))\n",
+        )
+        .unwrap();
+    assert_eq!(functional.classes().len(), 1);
+
+    let manchester = ManchesterParser::new()
+        .parse_str("# leading comment\n\nClass: A\n\nClass: B\n")
+        .unwrap();
+    assert_eq!(manchester.classes().len(), 2);
+}
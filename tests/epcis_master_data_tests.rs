@@ -0,0 +1,159 @@
+//! Tests for writing and reloading EPCIS events with their master data
+
+use owl2_reasoner::epcis_parser::{EPCISDocumentParser, EPCISDocumentWriter};
+use owl2_reasoner::*;
+use std::time::{Duration, SystemTime};
+
+fn warehouse() -> BusinessLocation {
+    BusinessLocation::new(
+        "urn:epc:id:sgln:0614141.00777.0".to_string(),
+        "Central Warehouse".to_string(),
+        Address::new(
+            "1 Dock Road".to_string(),
+            "Rotterdam".to_string(),
+            "South Holland".to_string(),
+            "3011 AA".to_string(),
+            "NL".to_string(),
+        ),
+    )
+    .with_coordinates(51.9225, 4.47917)
+    .add_capability(LocationCapability::Warehousing)
+    .add_capability(LocationCapability::Customs)
+}
+
+fn receiving_event(id: &str, read_point: &ReadPoint) -> EPCISEvent {
+    let mut event = EPCISEvent::new(id.to_string(), EPCISEventType::ObjectEvent)
+        .with_business_step(EPCISBusinessStep::Receiving);
+    event.event_time = SystemTime::UNIX_EPOCH + Duration::new(1_700_000_000, 123_000_000);
+    event.record_time = event.event_time + Duration::from_secs(5);
+    event.action = EPCISAction::Observe;
+    event.disposition = Some(EPCISDisposition::InProgress);
+    event.read_point = Some(read_point.clone());
+    event.business_location = Some(read_point.location.clone());
+    event.add_epc("urn:epc:id:sgtin:0614141.107346.2018".to_string());
+    event.add_quantity("urn:epc:class:lgtin:4012345.012345.998877".to_string(), 200);
+    event
+}
+
+#[test]
+fn test_master_data_survives_round_trip() {
+    let read_point = ReadPoint::new(
+        "urn:epc:id:sgln:0614141.00777.door-3".to_string(),
+        "Dock door 3".to_string(),
+        warehouse(),
+        ReaderType::RFID,
+    );
+    let events = vec![
+        receiving_event("event-1", &read_point),
+        receiving_event("event-2", &read_point),
+    ];
+
+    let xml = EPCISDocumentWriter::new()
+        .write_events_xml(&events)
+        .unwrap();
+    // The shared master data is written once and referenced by id
+    assert_eq!(xml.matches("<VocabularyElement ").count(), 2, "{}", xml);
+    assert_eq!(
+        xml.matches("<readPoint><id>urn:epc:id:sgln:0614141.00777.door-3</id></readPoint>")
+            .count(),
+        2
+    );
+
+    let reloaded = EPCISDocumentParser::default()
+        .parse_events_xml_str(&xml)
+        .unwrap();
+    assert_eq!(reloaded.len(), 2);
+
+    let event = &reloaded[0];
+    assert_eq!(event.event_id, "event-1");
+    assert_eq!(event.event_type, EPCISEventType::ObjectEvent);
+    assert_eq!(event.event_time, events[0].event_time);
+    assert_eq!(event.record_time, events[0].record_time);
+    assert_eq!(event.action, EPCISAction::Observe);
+    assert_eq!(event.biz_step, Some(EPCISBusinessStep::Receiving));
+    assert_eq!(event.disposition, Some(EPCISDisposition::InProgress));
+    assert_eq!(event.epc_list, events[0].epc_list);
+    assert_eq!(event.quantity_list, events[0].quantity_list);
+
+    let reloaded_point = event.read_point.as_ref().unwrap();
+    assert_eq!(reloaded_point.id, read_point.id);
+    assert_eq!(reloaded_point.name, "Dock door 3");
+    assert_eq!(reloaded_point.reader_type, ReaderType::RFID);
+
+    let location = &reloaded_point.location;
+    assert_eq!(location.id, "urn:epc:id:sgln:0614141.00777.0");
+    assert_eq!(location.name, "Central Warehouse");
+    assert_eq!(location.coordinates, Some((51.9225, 4.47917)));
+    assert_eq!(location.address.street, "1 Dock Road");
+    assert_eq!(location.address.city, "Rotterdam");
+    assert_eq!(location.address.state, "South Holland");
+    assert_eq!(location.address.postal_code, "3011 AA");
+    assert_eq!(location.address.country, "NL");
+    assert_eq!(
+        location.capabilities,
+        vec![LocationCapability::Warehousing, LocationCapability::Customs]
+    );
+
+    let business_location = event.business_location.as_ref().unwrap();
+    assert_eq!(business_location.id, location.id);
+    assert_eq!(business_location.coordinates, location.coordinates);
+}
+
+#[test]
+fn test_unknown_references_and_custom_vocabulary() {
+    let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<EPCISDocument xmlns="urn:epcglobal:epcis:xsd:2" schemaVersion="2.0">
+    <EPCISBody>
+        <EventList>
+            <AggregationEvent>
+                <eventTime>2024-03-01T10:00:00Z</eventTime>
+                <eventID>pack-1</eventID>
+                <parentID>urn:epc:id:sscc:0614141.1234567890</parentID>
+                <childEPCs>
+                    <epc>urn:epc:id:sgtin:0614141.107346.1</epc>
+                    <epc>urn:epc:id:sgtin:0614141.107346.2</epc>
+                </childEPCs>
+                <action>ADD</action>
+                <bizStep>urn:example:bizstep:palletizing</bizStep>
+                <bizLocation><id>urn:epc:id:sgln:0614141.00888.0</id></bizLocation>
+            </AggregationEvent>
+        </EventList>
+    </EPCISBody>
+</EPCISDocument>"#;
+
+    let events = EPCISDocumentParser::default()
+        .parse_events_xml_str(xml)
+        .unwrap();
+    assert_eq!(events.len(), 1);
+    let event = &events[0];
+    assert_eq!(event.event_type, EPCISEventType::AggregationEvent);
+    assert_eq!(event.event_time, event.record_time);
+    assert_eq!(
+        event.parent_id.as_deref(),
+        Some("urn:epc:id:sscc:0614141.1234567890")
+    );
+    assert_eq!(event.child_epcs.as_ref().map(Vec::len), Some(2));
+    assert_eq!(
+        event.biz_step,
+        Some(EPCISBusinessStep::Custom(
+            "urn:example:bizstep:palletizing".to_string()
+        ))
+    );
+    // A location without master data keeps its id only
+    let location = event.business_location.as_ref().unwrap();
+    assert_eq!(location.id, "urn:epc:id:sgln:0614141.00888.0");
+    assert!(location.name.is_empty());
+    assert_eq!(location.coordinates, None);
+
+    let rewritten = EPCISDocumentWriter::new()
+        .write_events_xml(&events)
+        .unwrap();
+    assert!(rewritten.contains("<bizStep>urn:example:bizstep:palletizing</bizStep>"));
+    assert_eq!(
+        EPCISDocumentParser::default()
+            .parse_events_xml_str(&rewritten)
+            .unwrap()[0]
+            .child_epcs,
+        event.child_epcs
+    );
+}
@@ -0,0 +1,110 @@
+//! Tests for concurrent EPCIS event stream processing
+
+use owl2_reasoner::epcis_stream::{EpcisQuery, EpcisStreamProcessor};
+use owl2_reasoner::*;
+use parking_lot::Mutex;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+fn location(id: &str) -> BusinessLocation {
+    BusinessLocation::new(
+        id.to_string(),
+        format!("Site {}", id),
+        Address::new(
+            "1 Dock Road".to_string(),
+            "Springfield".to_string(),
+            "IL".to_string(),
+            "62701".to_string(),
+            "US".to_string(),
+        ),
+    )
+}
+
+fn event(id: &str, step: EPCISBusinessStep, location_id: &str) -> EPCISEvent {
+    let mut event = EPCISEvent::new(id.to_string(), EPCISEventType::ObjectEvent)
+        .with_business_step(step)
+        .with_business_location(location(location_id));
+    event.add_epc(format!("epc-{}", id));
+    event
+}
+
+#[test]
+fn test_arrival_query_fires_for_events_from_channel() {
+    let processor = Arc::new(EpcisStreamProcessor::new());
+    let arrivals = Arc::new(Mutex::new(Vec::new()));
+
+    let seen = Arc::clone(&arrivals);
+    processor.register_query(
+        EpcisQuery::new()
+            .business_step(EPCISBusinessStep::Receiving)
+            .at_location("warehouse-x"),
+        move |event| seen.lock().push(event.event_id.clone()),
+    );
+
+    let (sender, receiver) = mpsc::channel();
+    let worker = processor.spawn(receiver);
+    sender
+        .send(event("e1", EPCISBusinessStep::Shipping, "warehouse-x"))
+        .unwrap();
+    sender
+        .send(event("e2", EPCISBusinessStep::Receiving, "warehouse-x"))
+        .unwrap();
+    sender
+        .send(event("e3", EPCISBusinessStep::Receiving, "store-y"))
+        .unwrap();
+    drop(sender);
+
+    assert_eq!(worker.join().unwrap().unwrap(), 3);
+    assert_eq!(*arrivals.lock(), vec!["e2".to_string()]);
+    assert_eq!(processor.events_processed(), 3);
+
+    // Every event was inserted into the shared ontology, with its location
+    let ontology = processor.ontology();
+    for id in ["e1", "e2", "e3"] {
+        let iri = IRI::new(format!("http://example.org/epcis/events/{}", id)).unwrap();
+        assert!(ontology
+            .named_individuals()
+            .iter()
+            .any(|individual| **individual.iri() == iri));
+    }
+    let location_prop = IRI::new("http://example.org/epcis/bizLocation").unwrap();
+    assert_eq!(
+        ontology
+            .property_assertions()
+            .iter()
+            .filter(|assertion| **assertion.property() == location_prop)
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn test_queries_only_see_events_after_registration() {
+    let processor = EpcisStreamProcessor::new();
+    processor
+        .push(event("early", EPCISBusinessStep::Receiving, "warehouse-x"))
+        .unwrap();
+
+    let count = Arc::new(Mutex::new(0));
+    let counter = Arc::clone(&count);
+    let id = processor.register_query(EpcisQuery::new().with_epc("epc-late"), move |_| {
+        *counter.lock() += 1
+    });
+
+    assert_eq!(
+        processor
+            .push(event("late", EPCISBusinessStep::Receiving, "warehouse-x"))
+            .unwrap(),
+        1
+    );
+    assert_eq!(*count.lock(), 1);
+
+    assert!(processor.unregister_query(id));
+    assert_eq!(processor.query_count(), 0);
+    assert_eq!(
+        processor
+            .push(event("late", EPCISBusinessStep::Receiving, "warehouse-x"))
+            .unwrap(),
+        0
+    );
+}
@@ -0,0 +1,120 @@
+//! Tests for reasoning over EquivalentClasses definitions with complex operands
+
+use owl2_reasoner::parser::{OwlXmlParser, OwlXmlSerializer};
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+/// Parent ≡ ∃hasChild.Person, with alice hasChild bob and bob : Person
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Parent", "Person"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::from_expressions(vec![
+            class("Parent"),
+            ClassExpression::ObjectSomeValuesFrom(
+                Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+                    ObjectProperty::new(iri("hasChild")),
+                ))),
+                Arc::new(class("Person")),
+            ),
+        ]))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("bob")),
+            class("Person"),
+        ))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("hasChild")),
+            Arc::new(iri("bob")),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_individual_with_child_person_is_realized_as_parent() {
+    let reasoner = TableauxReasoner::new(family());
+
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Parent"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("bob"), &iri("Parent"))
+        .unwrap());
+    assert_eq!(
+        reasoner.get_types(&iri("alice")).unwrap(),
+        vec![iri("Parent")]
+    );
+    assert_eq!(
+        reasoner.get_types(&iri("bob")).unwrap(),
+        vec![iri("Person")]
+    );
+
+    let mut owl_reasoner = OwlReasoner::new(family());
+    assert!(owl_reasoner
+        .is_instance_of(&iri("alice"), &iri("Parent"))
+        .unwrap());
+}
+
+#[test]
+fn test_definition_keeps_complex_operands() {
+    let ontology = family();
+    let axioms = ontology.equivalent_classes_axioms();
+    assert!(axioms[0].is_definition());
+    assert_eq!(axioms[0].classes(), &vec![Arc::new(iri("Parent"))]);
+    assert_eq!(axioms[0].class_expressions().len(), 2);
+
+    // Named-only axioms keep working through the original constructor
+    let named = EquivalentClassesAxiom::new(vec![Arc::new(iri("A")), Arc::new(iri("B"))]);
+    assert!(!named.is_definition());
+    assert_eq!(named.class_expressions(), &[class("A"), class("B")]);
+
+    // Definitions survive an OWL/XML round trip
+    let xml = OwlXmlSerializer::new().serialize(&ontology).unwrap();
+    let reparsed = OwlXmlParser::new().parse_str(&xml).unwrap();
+    assert_eq!(
+        reparsed.equivalent_classes_axioms()[0],
+        ontology.equivalent_classes_axioms()[0]
+    );
+}
+
+#[test]
+fn test_negated_definition_is_unfolded() {
+    let reasoner = TableauxReasoner::new(family());
+    let has_child_person = ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(iri("hasChild")),
+        ))),
+        Arc::new(class("Person")),
+    );
+
+    // ¬Parent unfolds to ∀hasChild.¬Person, which clashes with ∃hasChild.Person
+    let not_parent = ClassExpression::ObjectComplementOf(Arc::new(class("Parent")));
+    assert!(!reasoner
+        .is_class_expression_satisfiable(&ClassExpression::ObjectIntersectionOf(
+            vec![Arc::new(has_child_person.clone()), Arc::new(not_parent)].into(),
+        ))
+        .unwrap());
+    assert!(reasoner
+        .is_class_expression_satisfiable(&ClassExpression::ObjectIntersectionOf(
+            vec![Arc::new(has_child_person), Arc::new(class("Parent"))].into(),
+        ))
+        .unwrap());
+}
@@ -0,0 +1,130 @@
+//! Tests that equivalent properties are treated as one property when answering queries
+
+use owl2_reasoner::reasoning::query::{QueryConfig, QueryValue};
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+/// `hasSpouse ≡ marriedTo`, `hasName ≡ name`, with assertions made only with
+/// `marriedTo` and `name`
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::EquivalentObjectProperties(Box::new(
+            EquivalentObjectPropertiesAxiom::new(vec![
+                Arc::new(iri("hasSpouse")),
+                Arc::new(iri("marriedTo")),
+            ]),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::EquivalentDataProperties(Box::new(
+            EquivalentDataPropertiesAxiom::new(vec![
+                Arc::new(iri("hasName")),
+                Arc::new(iri("name")),
+            ]),
+        )))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("marriedTo")),
+            Arc::new(iri("bob")),
+        ))
+        .unwrap();
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("name")),
+            Literal::simple("Alice"),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_query_via_equivalent_property_returns_assertion() {
+    let engine = QueryEngine::new(family());
+
+    let result = engine
+        .get_property_values(&iri("alice"), &iri("hasSpouse"))
+        .unwrap();
+    assert_eq!(result.len(), 1);
+    assert_eq!(
+        result.bindings[0].get_value("value"),
+        Some(&QueryValue::IRI(iri("bob")))
+    );
+    assert_eq!(
+        engine
+            .has_property_value(&iri("alice"), &iri("hasSpouse"), &iri("bob"))
+            .unwrap(),
+        Some(true)
+    );
+
+    let pattern = QueryPattern::BasicGraphPattern(vec![TriplePattern {
+        subject: PatternTerm::IRI(iri("alice")),
+        predicate: PatternTerm::IRI(iri("hasSpouse")),
+        object: PatternTerm::Variable("spouse".to_string()),
+    }]);
+    assert_eq!(engine.execute(&pattern).unwrap().len(), 1);
+}
+
+#[test]
+fn test_query_via_equivalent_data_property_returns_value() {
+    let engine = QueryEngine::new(family());
+
+    let result = engine
+        .get_property_values(&iri("alice"), &iri("hasName"))
+        .unwrap();
+    assert_eq!(
+        result.bindings[0].get_value("value"),
+        Some(&QueryValue::Literal("Alice".to_string()))
+    );
+}
+
+#[test]
+fn test_equivalence_ignored_without_reasoning() {
+    let config = QueryConfig {
+        enable_reasoning: false,
+        ..Default::default()
+    };
+    let engine = QueryEngine::with_config(family(), config);
+
+    let result = engine
+        .get_property_values(&iri("alice"), &iri("hasSpouse"))
+        .unwrap();
+    assert!(result.is_empty());
+}
+
+#[test]
+fn test_universal_restriction_applies_to_equivalent_property() {
+    let reasoner = TableauxReasoner::new(family());
+    let person = Arc::new(ClassExpression::Class(Class::new(iri("Person"))));
+    let spouse = |name: &str| {
+        Box::new(ObjectPropertyExpression::from(ObjectProperty::new(iri(
+            name,
+        ))))
+    };
+
+    // Every spouse is a person, so a marriedTo successor that is not a person clashes
+    let concept = ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(ClassExpression::ObjectAllValuesFrom(
+                spouse("hasSpouse"),
+                person.clone(),
+            )),
+            Arc::new(ClassExpression::ObjectSomeValuesFrom(
+                spouse("marriedTo"),
+                Arc::new(ClassExpression::ObjectComplementOf(person)),
+            )),
+        ]
+        .into(),
+    );
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+}
@@ -0,0 +1,66 @@
+//! Tests for abbreviating IRIs in error messages
+
+use owl2_reasoner::iri::IRIRegistry;
+use owl2_reasoner::*;
+
+const PERSON: &str = "http://example.org/people#Person";
+
+fn example_display() -> IriDisplay {
+    IriDisplay::new().with_prefix("ex", "http://example.org/people#")
+}
+
+#[test]
+fn test_error_message_uses_registered_prefix() {
+    let error = OwlError::ReasoningError(format!("class <{}> is unsatisfiable", PERSON));
+    assert_eq!(
+        error.to_string(),
+        format!("Reasoning error: class <{}> is unsatisfiable", PERSON)
+    );
+
+    let previous = set_iri_display(example_display());
+    assert_eq!(
+        error.to_string(),
+        "Reasoning error: class ex:Person is unsatisfiable"
+    );
+    let parse_error = OwlError::ParseError(format!("unknown class {}.", PERSON));
+    assert_eq!(
+        parse_error.to_string(),
+        "Parse error: unknown class ex:Person."
+    );
+
+    // Verbose mode keeps the full IRI
+    set_iri_display(example_display().verbose(true));
+    assert!(error.to_string().contains(PERSON));
+
+    set_iri_display(previous);
+    assert!(error.to_string().contains(PERSON));
+}
+
+#[test]
+fn test_abbreviation_respects_namespace_boundaries() {
+    let display = IriDisplay::new()
+        .with_prefix("ex", "http://example.org/")
+        .with_prefix("people", "http://example.org/people#");
+
+    // The longest matching namespace wins
+    assert_eq!(
+        display.abbreviate("<http://example.org/people#Person> and <http://example.org/Thing>"),
+        "people:Person and ex:Thing"
+    );
+    // An IRI with a further path segment is not a name in the namespace
+    assert_eq!(
+        display.abbreviate("see http://example.org/docs/intro"),
+        "see http://example.org/docs/intro"
+    );
+}
+
+#[test]
+fn test_display_from_registry() {
+    let mut registry = IRIRegistry::new();
+    registry
+        .register("ex", "http://example.org/people#")
+        .unwrap();
+    let display = IriDisplay::from_registry(&registry);
+
+    assert_eq!(display.abbreviate(PERSON), "ex:Person");
+}
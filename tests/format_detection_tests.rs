@@ -0,0 +1,87 @@
+//! Tests for detecting the serialization format of a document
+
+use owl2_reasoner::parser::{detect_format, Format, ParserFactory};
+
+const TURTLE: &str = "# Pets ontology\n# maintained by hand\n\n@prefix ex: <http://example.org/pets#> .\nex:Dog a <http://www.w3.org/2002/07/owl#Class> .\n";
+
+const SPARQL_TURTLE: &str = "PREFIX ex: <http://example.org/pets#>\nex:Dog a ex:Class .\n";
+
+const NTRIPLES: &str = "# exported triples\n<http://example.org/pets#Dog> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#Class> .\n";
+
+const RDF_XML: &str = r#"<?xml version="1.0"?>
+<!-- generated by the pets tool -->
+<!DOCTYPE rdf:RDF [
+    <!ENTITY owl "http://www.w3.org/2002/07/owl#" >
+]>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+    <owl:Class rdf:about="http://example.org/pets#Dog"/>
+</rdf:RDF>"#;
+
+const OWL_XML: &str = r##"<?xml version="1.0"?>
+<!-- Ontology( is not functional syntax here -->
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.org/pets">
+    <Declaration><Class IRI="#Dog"/></Declaration>
+</Ontology>"##;
+
+const JSON_LD: &str = r#"  {
+  "@context": { "ex": "http://example.org/pets#" },
+  "@id": "ex:Dog"
+}"#;
+
+const FUNCTIONAL: &str = "# Functional syntax\nPrefix(:=<http://example.org/pets#>)\nOntology(<http://example.org/pets>\n  Declaration(Class(:Dog))\n)\n";
+
+const MANCHESTER: &str =
+    "Prefix: : <http://example.org/pets#>\nOntology: <http://example.org/pets>\nClass: Dog\n";
+
+#[test]
+fn test_detects_each_format() {
+    for (content, format) in [
+        (TURTLE, Format::Turtle),
+        (SPARQL_TURTLE, Format::Turtle),
+        (NTRIPLES, Format::NTriples),
+        (RDF_XML, Format::RdfXml),
+        (OWL_XML, Format::OwlXml),
+        (JSON_LD, Format::JsonLd),
+        (FUNCTIONAL, Format::OwlFunctional),
+        (MANCHESTER, Format::Manchester),
+    ] {
+        assert_eq!(detect_format(content), Some(format), "{}", content);
+    }
+}
+
+#[test]
+fn test_byte_order_mark_and_comments_are_skipped() {
+    assert_eq!(
+        detect_format(&format!("\u{feff}{}", TURTLE)),
+        Some(Format::Turtle)
+    );
+    assert_eq!(
+        detect_format(&format!("\u{feff}{}", RDF_XML)),
+        Some(Format::RdfXml)
+    );
+    assert_eq!(
+        detect_format("<!-- a --><!-- b -->\n<rdf:Description rdf:about=\"x\"/>"),
+        Some(Format::RdfXml)
+    );
+    assert_eq!(detect_format("# only a comment\n"), None);
+    assert_eq!(detect_format(""), None);
+    assert_eq!(detect_format("just some text"), None);
+}
+
+#[test]
+fn test_auto_detect_uses_detected_format() {
+    for format in Format::ALL {
+        assert_eq!(format.parser().format_name(), format.name());
+    }
+
+    let parser = ParserFactory::auto_detect(RDF_XML).unwrap();
+    assert_eq!(parser.format_name(), "RDF/XML");
+    assert_eq!(Format::RdfXml.to_string(), "RDF/XML");
+
+    let ontology = ParserFactory::auto_detect(TURTLE)
+        .unwrap()
+        .parse_str(TURTLE)
+        .unwrap();
+    assert_eq!(ontology.classes().len(), 1);
+}
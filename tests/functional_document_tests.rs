@@ -0,0 +1,96 @@
+//! Tests for OWL Functional Syntax documents holding several ontologies
+
+use owl2_reasoner::parser::{OntologyParser, OwlFunctionalSyntaxParser};
+use owl2_reasoner::*;
+
+const DOCUMENT: &str = r#"
+Prefix(:=<http://example.org/animals#>)
+Prefix(plants:=<http://example.org/plants#>)
+Document(
+    Ontology(<http://example.org/animals>
+        Declaration(Class(:Animal))
+        Declaration(Class(:Dog))
+        SubClassOf(:Dog :Animal)
+    )
+    Ontology(<http://example.org/plants>
+        Declaration(Class(plants:Plant))
+        Declaration(Class(plants:Oak))
+        Declaration(Class(plants:Tree))
+        SubClassOf(plants:Oak plants:Tree)
+        SubClassOf(plants:Tree plants:Plant)
+    )
+)
+"#;
+
+fn iri(value: &str) -> IRI {
+    IRI::new(value).unwrap()
+}
+
+fn subclass_pairs(ontology: &Ontology) -> Vec<(String, String)> {
+    ontology
+        .subclass_axioms()
+        .iter()
+        .map(|axiom| {
+            (
+                axiom.sub_class().as_named().unwrap().iri().to_string(),
+                axiom.super_class().as_named().unwrap().iri().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_document_returns_each_ontology() {
+    let ontologies = OwlFunctionalSyntaxParser::new()
+        .parse_document(DOCUMENT)
+        .unwrap();
+    assert_eq!(ontologies.len(), 2);
+
+    let animals = &ontologies[0];
+    assert_eq!(animals.iri(), Some(&iri("http://example.org/animals")));
+    assert_eq!(animals.classes().len(), 2);
+    assert_eq!(
+        subclass_pairs(animals),
+        vec![(
+            "http://example.org/animals#Dog".to_string(),
+            "http://example.org/animals#Animal".to_string()
+        )]
+    );
+
+    let plants = &ontologies[1];
+    assert_eq!(plants.iri(), Some(&iri("http://example.org/plants")));
+    assert_eq!(plants.classes().len(), 3);
+    assert_eq!(plants.subclass_axioms().len(), 2);
+}
+
+#[test]
+fn test_parse_str_returns_first_ontology() {
+    let ontology = OwlFunctionalSyntaxParser::new()
+        .parse_str(DOCUMENT)
+        .unwrap();
+    assert_eq!(ontology.iri(), Some(&iri("http://example.org/animals")));
+    assert_eq!(ontology.subclass_axioms().len(), 1);
+}
+
+#[test]
+fn test_unwrapped_ontologies_and_single_ontology_documents() {
+    let parser = OwlFunctionalSyntaxParser::new();
+    let unwrapped = "Prefix(:=<http://example.org/>)\n\
+                     Ontology(<http://example.org/a> SubClassOf(:A :B))\n\
+                     Ontology(<http://example.org/b> SubClassOf(:C :D) SubClassOf(:D :E))";
+    let ontologies = parser.parse_document(unwrapped).unwrap();
+    assert_eq!(ontologies.len(), 2);
+    assert_eq!(ontologies[0].iri(), Some(&iri("http://example.org/a")));
+    assert_eq!(ontologies[1].iri(), Some(&iri("http://example.org/b")));
+    assert_eq!(ontologies[1].subclass_axioms().len(), 2);
+
+    let single = "Prefix(:=<http://example.org/>)\n\
+                  Ontology(<http://example.org/a>\n    EquivalentClasses(:A :B)\n)";
+    let ontologies = parser.parse_document(single).unwrap();
+    assert_eq!(ontologies.len(), 1);
+    assert_eq!(ontologies[0].equivalent_classes_axioms().len(), 1);
+
+    assert!(parser
+        .parse_document("Document(Ontology(<http://example.org/a>)")
+        .is_err());
+}
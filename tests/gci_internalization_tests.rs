@@ -0,0 +1,94 @@
+//! Tests for reasoning with general concept inclusions
+
+mod common;
+
+use common::{class, iri, some};
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+/// ∃hasPet.Dog ⊑ DogOwner, with john hasPet rex and rex : Dog
+fn dog_owner_gci() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Dog", "DogOwner"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            some("hasPet", class("Dog")),
+            class("DogOwner"),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(Arc::new(iri("rex")), class("Dog")))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("john")),
+            Arc::new(iri("hasPet")),
+            Arc::new(iri("rex")),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_owner_of_a_dog_is_classified_as_dog_owner() {
+    let reasoner = TableauxReasoner::new(dog_owner_gci());
+
+    assert!(reasoner
+        .is_instance_of(&iri("john"), &iri("DogOwner"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("rex"), &iri("DogOwner"))
+        .unwrap());
+    assert_eq!(
+        reasoner.get_types(&iri("john")).unwrap(),
+        vec![iri("DogOwner")]
+    );
+}
+
+#[test]
+fn test_gci_participates_in_satisfiability() {
+    // A ⊑ ∃r.B, ∃r.B ⊑ C, C ⊑ ¬A
+    let mut ontology = Ontology::new();
+    for (sub, sup) in [
+        (class("A"), some("r", class("B"))),
+        (some("r", class("B")), class("C")),
+        (
+            class("C"),
+            ClassExpression::ObjectComplementOf(Arc::new(class("A"))),
+        ),
+    ] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(sub, sup))
+            .unwrap();
+    }
+    let reasoner = TableauxReasoner::new(ontology);
+
+    assert!(!reasoner.is_class_satisfiable(&iri("A")).unwrap());
+    assert!(reasoner.is_class_satisfiable(&iri("C")).unwrap());
+    assert!(reasoner
+        .is_class_expression_satisfiable(&some("r", class("B")))
+        .unwrap());
+}
+
+#[test]
+fn test_gci_entails_named_subsumption() {
+    // PuppyOwner ⊑ ∃hasPet.Dog, so PuppyOwner ⊑ DogOwner
+    let mut ontology = dog_owner_gci();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("PuppyOwner"),
+            some("hasPet", class("Dog")),
+        ))
+        .unwrap();
+    let reasoner = TableauxReasoner::new(ontology);
+
+    assert!(reasoner
+        .is_subclass_of(&iri("PuppyOwner"), &iri("DogOwner"))
+        .unwrap());
+    assert!(!reasoner
+        .is_subclass_of(&iri("DogOwner"), &iri("PuppyOwner"))
+        .unwrap());
+}
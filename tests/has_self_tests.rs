@@ -0,0 +1,152 @@
+//! Tests for parsing and reasoning over owl:hasSelf restrictions
+
+use owl2_reasoner::parser::{
+    OntologyParser, OwlFunctionalSyntaxParser, RdfXmlParser, TurtleParser,
+};
+use owl2_reasoner::reasoning::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/people#";
+
+const TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix ex: <http://example.org/people#> .
+
+ex:Narcissist a owl:Class .
+ex:loves a owl:ObjectProperty .
+ex:Narcissist owl:equivalentClass [ a owl:Restriction ; owl:onProperty ex:loves ; owl:hasSelf true ] .
+ex:alice ex:loves ex:alice .
+ex:bob ex:loves ex:alice .
+"#;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn has_self_restriction(ontology: &Ontology) -> bool {
+    let loves = iri("loves");
+    let is_self = |expression: &ClassExpression| {
+        matches!(expression, ClassExpression::ObjectHasSelf(property)
+            if matches!(property.as_ref(), ObjectPropertyExpression::ObjectProperty(p) if **p.iri() == loves))
+    };
+    ontology
+        .subclass_axioms()
+        .iter()
+        .any(|axiom| is_self(axiom.super_class()) || is_self(axiom.sub_class()))
+        || ontology
+            .equivalent_classes_axioms()
+            .iter()
+            .any(|axiom| axiom.class_expressions().iter().any(is_self))
+}
+
+#[test]
+fn test_turtle_self_restriction_classifies_individuals() {
+    let ontology = TurtleParser::new().parse_str(TURTLE).unwrap();
+    assert!(has_self_restriction(&ontology));
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Narcissist"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("bob"), &iri("Narcissist"))
+        .unwrap());
+}
+
+#[test]
+fn test_rdf_xml_self_restriction() {
+    let ontology = RdfXmlParser::new()
+        .parse_str(
+            r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:ex="http://example.org/people#">
+  <owl:ObjectProperty rdf:about="http://example.org/people#loves"/>
+  <owl:Class rdf:about="http://example.org/people#Narcissist">
+    <owl:equivalentClass>
+      <owl:Restriction>
+        <owl:onProperty rdf:resource="http://example.org/people#loves"/>
+        <owl:hasSelf rdf:datatype="http://www.w3.org/2001/XMLSchema#boolean">true</owl:hasSelf>
+      </owl:Restriction>
+    </owl:equivalentClass>
+  </owl:Class>
+  <rdf:Description rdf:about="http://example.org/people#alice">
+    <ex:loves rdf:resource="http://example.org/people#alice"/>
+  </rdf:Description>
+</rdf:RDF>
+"#,
+        )
+        .unwrap();
+    assert!(has_self_restriction(&ontology));
+    // The restriction node is not mistaken for an individual
+    assert!(ontology
+        .named_individuals()
+        .iter()
+        .all(|individual| !individual.iri().as_str().starts_with("_:")));
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Narcissist"))
+        .unwrap());
+}
+
+#[test]
+fn test_functional_self_restriction() {
+    let ontology = OwlFunctionalSyntaxParser::new()
+        .parse_str(
+            r#"Prefix(:=<http://example.org/people#>)
+Ontology(<http://example.org/people>
+    Declaration(Class(:Narcissist))
+    Declaration(ObjectProperty(:loves))
+    EquivalentClasses(:Narcissist ObjectHasSelf(:loves))
+    ObjectPropertyAssertion(:loves :alice :alice)
+)
+"#,
+        )
+        .unwrap();
+    assert!(has_self_restriction(&ontology));
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Narcissist"))
+        .unwrap());
+}
+
+#[test]
+fn test_reflexive_property_satisfies_self_restriction() {
+    let mut ontology = TurtleParser::new().parse_str(TURTLE).unwrap();
+    ontology
+        .add_axiom(Axiom::ReflexiveProperty(Box::new(
+            ReflexivePropertyAxiom::new(Arc::new(iri("loves"))),
+        )))
+        .unwrap();
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_instance_of(&iri("bob"), &iri("Narcissist"))
+        .unwrap());
+}
+
+#[test]
+fn test_self_restriction_on_irreflexive_property_is_unsatisfiable() {
+    let mut ontology = TurtleParser::new()
+        .parse_str(
+            r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix ex: <http://example.org/people#> .
+
+ex:Narcissist a owl:Class .
+ex:loves a owl:ObjectProperty .
+ex:Narcissist rdfs:subClassOf [ a owl:Restriction ; owl:onProperty ex:loves ; owl:hasSelf true ] .
+"#,
+        )
+        .unwrap();
+    assert!(has_self_restriction(&ontology));
+    ontology
+        .add_axiom(Axiom::IrreflexiveProperty(Box::new(
+            IrreflexivePropertyAxiom::new(Arc::new(iri("loves"))),
+        )))
+        .unwrap();
+
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(!reasoner.is_class_satisfiable(&iri("Narcissist")).unwrap());
+}
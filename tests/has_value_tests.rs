@@ -0,0 +1,113 @@
+//! Tests for expanding ObjectHasValue restrictions with sameAs-aware matching
+
+use owl2_reasoner::axioms::property_expressions::ObjectPropertyExpression;
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::entities::Individual;
+use owl2_reasoner::reasoning::tableaux::{ReasoningRules, TableauxGraph, TableauxReasoner};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(name: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(format!("http://example.org/{}", name)).unwrap())
+}
+
+/// ∃property.{individual}
+fn has_value(property: &str, individual: &str) -> ClassExpression {
+    ClassExpression::ObjectHasValue(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(format!("http://example.org/{}", property)),
+        ))),
+        Individual::Named(NamedIndividual::new(format!(
+            "http://example.org/{}",
+            individual
+        ))),
+    )
+}
+
+fn same(individuals: &[&str]) -> Axiom {
+    Axiom::SameIndividual(Box::new(SameIndividualAxiom::new(
+        individuals.iter().map(|name| iri(name)).collect(),
+    )))
+}
+
+/// x : ∃R.{a} with the assertion R(x, object)
+fn ontology(object: &str, same_as: Option<Axiom>) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::PropertyAssertion(Box::new(
+            PropertyAssertionAxiom::new(iri("x"), iri("R"), iri(object)),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+            iri("x"),
+            has_value("R", "a"),
+        ))))
+        .unwrap();
+    if let Some(axiom) = same_as {
+        ontology.add_axiom(axiom).unwrap();
+    }
+    ontology
+}
+
+/// Expand the tableau of `x` and count its nodes
+fn tableau_nodes(ontology: Ontology) -> usize {
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(!reasoner.is_instance_of(&iri("x"), &iri("C")).unwrap());
+    reasoner.last_reasoning_stats().total_nodes
+}
+
+#[test]
+fn test_has_value_is_satisfied_by_same_individual() {
+    // The successor b is a, so no node is added for a
+    assert_eq!(tableau_nodes(ontology("b", Some(same(&["a", "b"])))), 2);
+}
+
+#[test]
+fn test_has_value_adds_missing_value() {
+    // Without sameAs, a is a different node from b
+    assert_eq!(tableau_nodes(ontology("b", None)), 3);
+}
+
+#[test]
+fn test_has_value_reuses_existing_successor() {
+    assert_eq!(tableau_nodes(ontology("a", None)), 2);
+}
+
+#[test]
+fn test_same_individual_classes_are_transitive() {
+    let mut ontology = Ontology::new();
+    ontology.add_axiom(same(&["a", "b"])).unwrap();
+    ontology.add_axiom(same(&["c", "b"])).unwrap();
+    ontology.add_axiom(same(&["d", "e"])).unwrap();
+
+    let classes = ReasoningRules::new(&ontology).same_individual_classes();
+    let abc: Vec<IRI> = ["a", "b", "c"]
+        .iter()
+        .map(|name| (*iri(name)).clone())
+        .collect();
+    assert_eq!(classes[&*iri("a")], abc);
+    assert_eq!(classes[&*iri("c")], abc);
+    assert_eq!(classes[&*iri("e")].len(), 2);
+    assert!(!classes.contains_key(&*iri("x")));
+}
+
+#[test]
+fn test_graph_matches_individuals_through_same_as() {
+    let mut ontology = Ontology::new();
+    ontology.add_axiom(same(&["a", "b"])).unwrap();
+    let classes = ReasoningRules::new(&ontology).same_individual_classes();
+
+    let mut graph = TableauxGraph::new();
+    let node = graph.add_node();
+    graph
+        .associate_node_with_individual(node, &iri("b"))
+        .unwrap();
+    assert!(!graph.is_equal_to_individual(node, &iri("a")));
+
+    graph.set_same_individuals(Arc::new(classes));
+    assert!(graph.is_equal_to_individual(node, &iri("a")));
+    assert!(graph.is_equal_to_individual(node, &iri("b")));
+    assert!(!graph.is_equal_to_individual(node, &iri("c")));
+    assert_eq!(graph.get_node_for_individual(&iri("a")), Some(node));
+}
@@ -0,0 +1,95 @@
+//! Tests for rendering a computed class hierarchy as Manchester syntax frames
+
+use owl2_reasoner::reasoning::{ClassificationEngine, ManchesterFrameConfig};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn label(name: &str, label: &str) -> Axiom {
+    Axiom::AnnotationAssertion(Box::new(AnnotationAssertionAxiom::new(
+        Arc::new(IRI::new("http://www.w3.org/2000/01/rdf-schema#label").unwrap()),
+        Arc::new(iri(name)),
+        AnnotationValue::Literal(Literal::simple(label)),
+    )))
+}
+
+/// Mother ⊑ Woman ⊑ Person and Father ≡ Dad ⊑ Man
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Person", "Woman", "Mother", "Man", "Father", "Dad"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [("Woman", "Person"), ("Mother", "Woman"), ("Dad", "Man")] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+            .unwrap();
+    }
+    ontology
+        .add_axiom(Axiom::EquivalentClasses(Box::new(
+            EquivalentClassesAxiom::new(vec![Arc::new(iri("Father")), Arc::new(iri("Dad"))]),
+        )))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_manchester_frames_list_direct_superclasses() {
+    let mut engine = ClassificationEngine::new(family());
+    let hierarchy = engine.classify().unwrap().hierarchy;
+
+    let frames = hierarchy.to_manchester();
+    assert!(frames.contains(&format!(
+        "Class: <{EX}Mother>\n    SubClassOf: <{EX}Woman>\n\n"
+    )));
+    assert!(frames.contains(&format!(
+        "Class: <{EX}Dad>\n    SubClassOf: <{EX}Man>\n    EquivalentTo: <{EX}Father>\n\n"
+    )));
+    assert!(frames.contains(&format!("Class: <{EX}Person>\n\n")));
+    assert!(!frames.contains("owl#Thing"));
+    assert!(!frames.contains("owl#Nothing"));
+}
+
+#[test]
+fn test_manchester_frames_include_inferred_subclasses() {
+    let mut engine = ClassificationEngine::new(family());
+    let hierarchy = engine.classify().unwrap().hierarchy;
+    let config = ManchesterFrameConfig {
+        direct_only: false,
+        ..Default::default()
+    };
+
+    let frames = hierarchy.to_manchester_with(&config);
+    // Mother ⊑ Person is only inferred
+    assert!(frames.contains(&format!(
+        "Class: <{EX}Mother>\n    SubClassOf: <{EX}Person>, <{EX}Woman>\n"
+    )));
+}
+
+#[test]
+fn test_manchester_frames_use_labels_where_available() {
+    let mut ontology = family();
+    ontology.add_axiom(label("Mother", "Mother")).unwrap();
+    ontology.add_axiom(label("Woman", "adult woman")).unwrap();
+    // A label two classes share would be ambiguous
+    ontology.add_axiom(label("Father", "Father")).unwrap();
+    ontology.add_axiom(label("Dad", "Father")).unwrap();
+    let mut engine = ClassificationEngine::new(ontology.clone());
+    let hierarchy = engine.classify().unwrap().hierarchy;
+    let config = ManchesterFrameConfig {
+        labels: Some(&ontology),
+        ..Default::default()
+    };
+
+    let frames = hierarchy.to_manchester_with(&config);
+    assert!(frames.contains("Class: Mother\n    SubClassOf: 'adult woman'\n"));
+    assert!(frames.contains(&format!("Class: <{EX}Father>\n")));
+}
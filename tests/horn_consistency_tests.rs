@@ -0,0 +1,179 @@
+//! Tests for the completion-based consistency check of Horn EL ontologies
+
+use owl2_reasoner::reasoning::{ConsistencyMethod, ElCompletion, TableauxReasoner};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/anatomy#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn some(property: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectProperty::new(iri(property)).into()),
+        Arc::new(filler),
+    )
+}
+
+fn subclass(ontology: &mut Ontology, sub: ClassExpression, sup: ClassExpression) {
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(sub, sup))
+        .unwrap();
+}
+
+fn disjoint(ontology: &mut Ontology, a: &str, b: &str) {
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri(a)),
+            Arc::new(iri(b)),
+        ]))
+        .unwrap();
+}
+
+fn typed(ontology: &mut Ontology, individual: &str, class: ClassExpression) {
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(Arc::new(iri(individual)), class))
+        .unwrap();
+}
+
+fn related(ontology: &mut Ontology, subject: &str, property: &str, object: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(subject)),
+            Arc::new(iri(property)),
+            Arc::new(iri(object)),
+        ))
+        .unwrap();
+}
+
+/// Hearts are organs that are part of some circulatory system
+fn anatomy() -> Ontology {
+    let mut ontology = Ontology::new();
+    subclass(&mut ontology, class("Heart"), class("Organ"));
+    subclass(
+        &mut ontology,
+        class("Heart"),
+        some("partOf", class("CirculatorySystem")),
+    );
+    subclass(&mut ontology, class("CirculatorySystem"), class("System"));
+    disjoint(&mut ontology, "Organ", "System");
+    ontology
+        .add_axiom(Axiom::TransitiveProperty(Box::new(
+            TransitivePropertyAxiom::new(Arc::new(iri("partOf"))),
+        )))
+        .unwrap();
+    typed(&mut ontology, "heart1", class("Heart"));
+    ontology
+}
+
+/// Decide consistency and report the procedure that decided it
+fn check(ontology: Ontology) -> (bool, Option<ConsistencyMethod>) {
+    let mut reasoner = OwlReasoner::new(ontology);
+    let consistent = reasoner.is_consistent().unwrap();
+    (consistent, reasoner.last_consistency_method())
+}
+
+#[test]
+fn test_el_ontology_uses_completion() {
+    let ontology = anatomy();
+    assert!(ElCompletion::is_horn(&ontology));
+
+    let (consistent, method) = check(ontology);
+    assert!(consistent);
+    assert_eq!(method, Some(ConsistencyMethod::HornCompletion));
+}
+
+#[test]
+fn test_completion_agrees_with_tableaux() {
+    let mut clash = anatomy();
+    typed(&mut clash, "heart1", class("System"));
+
+    for (ontology, expected) in [(anatomy(), true), (clash, false)] {
+        let tableaux = TableauxReasoner::new(ontology.clone())
+            .is_consistent()
+            .unwrap();
+        let (consistent, method) = check(ontology);
+        assert_eq!(consistent, expected);
+        assert_eq!(consistent, tableaux);
+        assert_eq!(method, Some(ConsistencyMethod::HornCompletion));
+    }
+}
+
+#[test]
+fn test_completion_propagates_clash_from_existential_witness() {
+    // Every heart is part of something that is both an organ and a system
+    let mut ontology = anatomy();
+    subclass(
+        &mut ontology,
+        class("Heart"),
+        some(
+            "partOf",
+            ClassExpression::ObjectIntersectionOf(
+                [class("Organ"), class("System")]
+                    .into_iter()
+                    .map(Arc::new)
+                    .collect(),
+            ),
+        ),
+    );
+
+    let (consistent, method) = check(ontology);
+    assert!(!consistent);
+    assert_eq!(method, Some(ConsistencyMethod::HornCompletion));
+}
+
+#[test]
+fn test_completion_applies_domain_and_range() {
+    let mut ontology = anatomy();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::new(iri("pumps"), class("Fluid")),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(Arc::new(iri("pumps")), class("Organ")),
+        )))
+        .unwrap();
+    disjoint(&mut ontology, "Fluid", "Organ");
+    related(&mut ontology, "heart1", "pumps", "blood");
+    let (consistent, _) = check(ontology.clone());
+    assert!(consistent);
+
+    // blood is inferred to be a fluid, and heart2 an organ
+    let mut clash = ontology.clone();
+    typed(&mut clash, "blood", class("Organ"));
+    assert!(!check(clash).0);
+    let mut clash = ontology;
+    related(&mut clash, "heart2", "pumps", "blood");
+    typed(&mut clash, "heart2", class("System"));
+    let (consistent, method) = check(clash);
+    assert!(!consistent);
+    assert_eq!(method, Some(ConsistencyMethod::HornCompletion));
+}
+
+#[test]
+fn test_non_horn_ontology_falls_back_to_tableaux() {
+    let mut ontology = anatomy();
+    subclass(
+        &mut ontology,
+        class("Organ"),
+        ClassExpression::ObjectUnionOf(
+            [class("Heart"), class("Lung")]
+                .into_iter()
+                .map(Arc::new)
+                .collect(),
+        ),
+    );
+    assert!(!ElCompletion::is_horn(&ontology));
+
+    let (consistent, method) = check(ontology);
+    assert!(consistent);
+    assert_eq!(method, Some(ConsistencyMethod::Tableaux));
+}
@@ -0,0 +1,122 @@
+//! Tests for reporting import cycles while still merging the import closure
+
+use owl2_reasoner::parser::{ImportResolverConfig, ImportSource};
+use owl2_reasoner::*;
+use std::collections::HashMap;
+
+/// Serves ontologies from memory
+struct MemorySource {
+    ontologies: HashMap<String, Ontology>,
+}
+
+impl ImportSource for MemorySource {
+    fn can_resolve(&self, iri: &IRI) -> bool {
+        iri.as_str().starts_with("memory://")
+    }
+
+    fn resolve(&self, iri: &IRI, _config: &ImportResolverConfig) -> OwlResult<Ontology> {
+        self.ontologies
+            .get(iri.as_str())
+            .cloned()
+            .ok_or_else(|| OwlError::ImportResolutionError {
+                iri: iri.clone(),
+                message: "not found".to_string(),
+            })
+    }
+
+    fn name(&self) -> &'static str {
+        "Memory"
+    }
+}
+
+fn ontology_iri(name: &str) -> IRI {
+    IRI::new(format!("memory://example.org/{}", name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(
+        IRI::new(format!("http://example.org/zoo#{}", name)).unwrap(),
+    ))
+}
+
+/// An ontology stating `sub ⊑ sup` and importing `imports`
+fn module(name: &str, imports: &str, sub: &str, sup: &str) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.set_iri(ontology_iri(name));
+    ontology.add_import(ontology_iri(imports));
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_three_ontology_cycle_is_merged_and_reported() {
+    let source = MemorySource {
+        ontologies: HashMap::from([
+            (
+                "memory://example.org/a".to_string(),
+                module("a", "b", "Dog", "Mammal"),
+            ),
+            (
+                "memory://example.org/b".to_string(),
+                module("b", "c", "Mammal", "Animal"),
+            ),
+            (
+                "memory://example.org/c".to_string(),
+                module("c", "a", "Animal", "Organism"),
+            ),
+        ]),
+    };
+    let mut ontology = module("a", "b", "Dog", "Mammal");
+    let mut resolver = ImportResolver::new().unwrap();
+    resolver.add_source(Box::new(source));
+
+    let cycles = resolver.resolve_imports(&mut ontology).unwrap();
+
+    assert_eq!(
+        cycles,
+        vec![ImportCycle {
+            iris: vec![ontology_iri("a"), ontology_iri("b"), ontology_iri("c")],
+        }]
+    );
+    assert_eq!(
+        cycles[0].to_string(),
+        "memory://example.org/a -> memory://example.org/b -> memory://example.org/c -> memory://example.org/a"
+    );
+    assert_eq!(resolver.stats().circular_dependencies_detected, 1);
+    assert_eq!(resolver.stats().failed_resolutions, 0);
+
+    // Every ontology on the cycle contributed its axiom
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_subclass_of(
+            &IRI::new("http://example.org/zoo#Dog").unwrap(),
+            &IRI::new("http://example.org/zoo#Organism").unwrap(),
+        )
+        .unwrap());
+}
+
+#[test]
+fn test_acyclic_imports_report_no_cycle() {
+    let mut leaf = Ontology::new();
+    leaf.set_iri(ontology_iri("leaf"));
+    leaf.add_subclass_axiom(SubClassOfAxiom::new(class("Mammal"), class("Animal")))
+        .unwrap();
+    let source = MemorySource {
+        ontologies: HashMap::from([
+            (
+                "memory://example.org/middle".to_string(),
+                module("middle", "leaf", "Dog", "Mammal"),
+            ),
+            ("memory://example.org/leaf".to_string(), leaf),
+        ]),
+    };
+    let mut ontology = module("top", "middle", "Puppy", "Dog");
+    let mut resolver = ImportResolver::new().unwrap();
+    resolver.add_source(Box::new(source));
+
+    assert!(resolver.resolve_imports(&mut ontology).unwrap().is_empty());
+    // The transitively imported ontology is merged as well
+    assert_eq!(ontology.subclass_axioms().len(), 3);
+}
@@ -0,0 +1,112 @@
+//! Tests for adding ABox assertions one at a time with immediate consistency feedback
+
+mod common;
+
+use common::{class, iri, subclass};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn instance_of(individual: &str, class_name: &str) -> Axiom {
+    Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+        Arc::new(iri(individual)),
+        class(class_name),
+    )))
+}
+
+/// Puppy ⊑ Dog, Dog and Cat disjoint, rex a Dog
+fn disjoint_dogs_and_cats() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Dog", "Cat", "Puppy"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(subclass("Puppy", "Dog"))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Dog")),
+            Arc::new(iri("Cat")),
+        ]))
+        .unwrap();
+    ontology.add_axiom(instance_of("rex", "Dog")).unwrap();
+    ontology
+}
+
+#[test]
+fn test_assertion_violating_disjointness_is_rejected() {
+    let mut reasoner = SimpleReasoner::new(disjoint_dogs_and_cats());
+    let before = reasoner.ontology.axiom_count();
+
+    match reasoner
+        .try_add_assertion(instance_of("rex", "Cat"))
+        .unwrap()
+    {
+        AssertionOutcome::WouldBeInconsistent {
+            reason,
+            conflicting,
+        } => {
+            assert!(reason.contains("disjoint classes"), "{}", reason);
+            assert!(
+                conflicting.contains(&instance_of("rex", "Dog")),
+                "{:?}",
+                conflicting
+            );
+        }
+        AssertionOutcome::Consistent => panic!("rex cannot be both a dog and a cat"),
+    }
+    assert_eq!(reasoner.ontology.axiom_count(), before);
+    assert!(reasoner.is_consistent().unwrap());
+}
+
+#[test]
+fn test_inferred_clash_is_rejected() {
+    let mut reasoner = SimpleReasoner::new(disjoint_dogs_and_cats());
+    reasoner
+        .try_add_assertion(instance_of("tom", "Cat"))
+        .unwrap();
+
+    // tom is a dog only through Puppy ⊑ Dog
+    let outcome = reasoner
+        .try_add_assertion(instance_of("tom", "Puppy"))
+        .unwrap();
+    let AssertionOutcome::WouldBeInconsistent {
+        reason,
+        conflicting,
+    } = outcome
+    else {
+        panic!("tom cannot be both a puppy and a cat");
+    };
+    let puppies_are_dogs = Axiom::SubClassOf(Box::new(subclass("Puppy", "Dog")));
+    assert!(conflicting.contains(&puppies_are_dogs), "{:?}", conflicting);
+    // The conflicting axioms are spelled out in functional syntax
+    assert!(
+        reason.contains("SubClassOf(<http://example.org/Puppy> <http://example.org/Dog>)"),
+        "{}",
+        reason
+    );
+    assert!(!reasoner
+        .ontology
+        .axioms()
+        .iter()
+        .any(|axiom| **axiom == instance_of("tom", "Puppy")));
+}
+
+#[test]
+fn test_consistent_assertion_is_added() {
+    let mut reasoner = SimpleReasoner::new(disjoint_dogs_and_cats());
+    let before = reasoner.ontology.axiom_count();
+
+    let outcome = reasoner
+        .try_add_assertion(instance_of("felix", "Cat"))
+        .unwrap();
+    assert_eq!(outcome, AssertionOutcome::Consistent);
+    assert_eq!(reasoner.ontology.axiom_count(), before + 1);
+}
+
+#[test]
+fn test_tbox_axioms_are_refused() {
+    let mut reasoner = SimpleReasoner::new(disjoint_dogs_and_cats());
+    let axiom = Axiom::SubClassOf(Box::new(subclass("Cat", "Dog")));
+
+    assert!(reasoner.try_add_assertion(axiom).is_err());
+}
@@ -0,0 +1,149 @@
+//! Tests for updating a classified hierarchy after an edit
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::reasoning::{ClassHierarchy, ClassificationEngine};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/tree#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class(sub), class(sup))))
+}
+
+fn names(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+/// Animal > {Mammal > {Dog, Cat}, Bird > Eagle}, Plant > Tree, with Animal and
+/// Plant disjoint
+fn living() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in [
+        "Animal", "Mammal", "Dog", "Cat", "Bird", "Eagle", "Plant", "Tree", "Puppy",
+    ] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [
+        ("Mammal", "Animal"),
+        ("Dog", "Mammal"),
+        ("Cat", "Mammal"),
+        ("Bird", "Animal"),
+        ("Eagle", "Bird"),
+        ("Tree", "Plant"),
+    ] {
+        ontology.add_axiom(subclass(sub, sup)).unwrap();
+    }
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Plant")),
+        ]))
+        .unwrap();
+    ontology
+}
+
+fn assert_same_hierarchy(actual: &ClassHierarchy, expected: &ClassHierarchy, ontology: &Ontology) {
+    for class in ontology.classes() {
+        let class = &**class.iri();
+        assert_eq!(
+            actual.get_all_superclasses(class),
+            expected.get_all_superclasses(class),
+            "superclasses of {}",
+            class
+        );
+        assert_eq!(
+            actual.get_all_subclasses(class),
+            expected.get_all_subclasses(class),
+            "subclasses of {}",
+            class
+        );
+        assert_eq!(
+            actual.get_equivalent_classes(class),
+            expected.get_equivalent_classes(class)
+        );
+        assert_eq!(
+            actual.get_disjoint_classes(class),
+            expected.get_disjoint_classes(class),
+            "disjoint classes of {}",
+            class
+        );
+    }
+}
+
+#[test]
+fn test_added_subclass_reclassifies_only_affected_subtree() {
+    let mut hierarchy = ClassificationEngine::new(living())
+        .classify()
+        .unwrap()
+        .hierarchy;
+    let untouched = hierarchy.get_all_superclasses(&iri("Eagle"));
+
+    let mut edited = living();
+    let added = subclass("Puppy", "Dog");
+    edited.add_axiom(added.clone()).unwrap();
+    let mut reasoner = TableauxReasoner::new(edited.clone());
+
+    let reclassified = hierarchy.update_after(&mut reasoner, &[added]).unwrap();
+    assert_eq!(reclassified, names(&["Puppy", "Dog", "Mammal", "Animal"]));
+    assert!(hierarchy.is_valid_for(&edited));
+    assert_eq!(hierarchy.get_all_superclasses(&iri("Eagle")), untouched);
+    assert!(hierarchy
+        .get_all_superclasses(&iri("Puppy"))
+        .is_superset(&names(&["Dog", "Mammal", "Animal"])));
+    assert!(hierarchy.are_disjoint(&iri("Puppy"), &iri("Tree")));
+
+    let full = ClassificationEngine::new(edited.clone())
+        .classify()
+        .unwrap()
+        .hierarchy;
+    assert_same_hierarchy(&hierarchy, &full, &edited);
+}
+
+#[test]
+fn test_update_after_moving_a_class() {
+    let mut hierarchy = ClassificationEngine::new(living())
+        .classify()
+        .unwrap()
+        .hierarchy;
+
+    // Cat is now a plant, which makes it unsatisfiable
+    let added = subclass("Cat", "Plant");
+    let mut edited = living();
+    edited.add_axiom(added.clone()).unwrap();
+    let mut reasoner = TableauxReasoner::new(edited.clone());
+
+    let reclassified = hierarchy.update_after(&mut reasoner, &[added]).unwrap();
+    assert!(!reclassified.contains(&iri("Eagle")));
+    assert!(reclassified.contains(&iri("Cat")));
+
+    let full = ClassificationEngine::new(edited.clone())
+        .classify()
+        .unwrap()
+        .hierarchy;
+    assert_same_hierarchy(&hierarchy, &full, &edited);
+}
+
+#[test]
+fn test_update_after_rejects_cycle() {
+    let mut hierarchy = ClassificationEngine::new(living())
+        .classify()
+        .unwrap()
+        .hierarchy;
+
+    let added = subclass("Animal", "Dog");
+    let mut edited = living();
+    edited.add_axiom(added.clone()).unwrap();
+    let mut reasoner = TableauxReasoner::new(edited);
+
+    assert!(hierarchy.update_after(&mut reasoner, &[added]).is_err());
+}
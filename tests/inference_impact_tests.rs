@@ -0,0 +1,154 @@
+//! Tests for computing the inferences lost by removing an axiom
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/zoo#{}", name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class(sub), class(sup))))
+}
+
+fn pairs(names: &[(&str, &str)]) -> Vec<(IRI, IRI)> {
+    let mut pairs: Vec<(IRI, IRI)> = names.iter().map(|(a, b)| (iri(a), iri(b))).collect();
+    pairs.sort();
+    pairs
+}
+
+/// Dog ⊑ Mammal ⊑ Animal ⊑ LivingThing, Cat ⊑ Mammal, Mammal ⊑ Vertebrate ⊑ Animal
+fn zoo() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in [
+        "Dog",
+        "Cat",
+        "Mammal",
+        "Vertebrate",
+        "Animal",
+        "LivingThing",
+    ] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [
+        ("Dog", "Mammal"),
+        ("Cat", "Mammal"),
+        ("Mammal", "Animal"),
+        ("Mammal", "Vertebrate"),
+        ("Vertebrate", "Animal"),
+        ("Animal", "LivingThing"),
+    ] {
+        ontology.add_axiom(subclass(sub, sup)).unwrap();
+    }
+    ontology
+        .add_named_individual(NamedIndividual::new(iri("rex")))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(Arc::new(iri("rex")), class("Dog")))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_removing_a_key_subclass_axiom_reports_lost_inferences() {
+    let ontology = zoo();
+    let axiom_count = ontology.axioms().len();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    let report = reasoner
+        .inference_impact(&subclass("Mammal", "Animal"))
+        .unwrap();
+    // Mammal ⊑ Vertebrate ⊑ Animal still holds, so that axiom is redundant
+    assert!(report.is_empty(), "{:?}", report);
+
+    let report = reasoner
+        .inference_impact(&subclass("Animal", "LivingThing"))
+        .unwrap();
+    assert_eq!(
+        report.lost_subsumptions,
+        pairs(&[
+            ("Animal", "LivingThing"),
+            ("Cat", "LivingThing"),
+            ("Dog", "LivingThing"),
+            ("Mammal", "LivingThing"),
+            ("Vertebrate", "LivingThing"),
+        ])
+    );
+    assert_eq!(report.lost_memberships, pairs(&[("rex", "LivingThing")]));
+
+    let report = reasoner
+        .inference_impact(&subclass("Dog", "Mammal"))
+        .unwrap();
+    assert_eq!(
+        report.lost_subsumptions,
+        pairs(&[
+            ("Dog", "Animal"),
+            ("Dog", "LivingThing"),
+            ("Dog", "Mammal"),
+            ("Dog", "Vertebrate"),
+        ])
+    );
+    assert_eq!(
+        report.lost_memberships,
+        pairs(&[
+            ("rex", "Animal"),
+            ("rex", "LivingThing"),
+            ("rex", "Mammal"),
+            ("rex", "Vertebrate"),
+        ])
+    );
+
+    // The removals were only tentative
+    assert_eq!(reasoner.ontology().axioms().len(), axiom_count);
+    assert!(reasoner
+        .is_subclass_of(&iri("Dog"), &iri("LivingThing"))
+        .unwrap());
+}
+
+#[test]
+fn test_impact_of_an_absent_axiom_is_an_error() {
+    let mut reasoner = OwlReasoner::new(zoo());
+    assert!(reasoner.inference_impact(&subclass("Cat", "Dog")).is_err());
+}
+
+#[test]
+fn test_remove_axiom_keeps_entities() {
+    let mut ontology = zoo();
+    assert!(ontology.remove_axiom(&subclass("Cat", "Mammal")).unwrap());
+    assert!(!ontology.remove_axiom(&subclass("Cat", "Mammal")).unwrap());
+    assert!(ontology
+        .classes()
+        .iter()
+        .any(|class| **class.iri() == iri("Cat")));
+    assert_eq!(ontology.subclass_axioms().len(), 5);
+}
+
+#[test]
+fn test_removing_an_equivalence_reports_both_directions() {
+    let mut ontology = zoo();
+    ontology.add_class(Class::new(iri("Canine"))).unwrap();
+    let equivalence = Axiom::EquivalentClasses(Box::new(EquivalentClassesAxiom::new(vec![
+        Arc::new(iri("Dog")),
+        Arc::new(iri("Canine")),
+    ])));
+    ontology.add_axiom(equivalence.clone()).unwrap();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    let report = reasoner.inference_impact(&equivalence).unwrap();
+    assert_eq!(
+        report.lost_subsumptions,
+        pairs(&[
+            ("Canine", "Animal"),
+            ("Canine", "Dog"),
+            ("Canine", "LivingThing"),
+            ("Canine", "Mammal"),
+            ("Canine", "Vertebrate"),
+            ("Dog", "Canine"),
+        ])
+    );
+    assert_eq!(report.lost_memberships, pairs(&[("rex", "Canine")]));
+}
@@ -0,0 +1,118 @@
+//! Tests for validating axioms as they are added to an ontology
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/zoo#";
+
+fn class(iri: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri))
+}
+
+fn subclass(sub: &str, sup: &str) -> SubClassOfAxiom {
+    SubClassOfAxiom::new(class(sub), class(sup))
+}
+
+/// Rejects axioms about entities outside the `ex:` namespace
+fn require_ex_namespace(axiom: &Axiom) -> OwlResult<()> {
+    match axiom
+        .signature()
+        .into_iter()
+        .find(|iri| !iri.as_str().starts_with(EX))
+    {
+        Some(iri) => Err(OwlError::ValidationError(format!(
+            "{} is outside the ex: namespace",
+            iri
+        ))),
+        None => Ok(()),
+    }
+}
+
+#[test]
+fn test_validator_rejects_out_of_namespace_classes() {
+    let mut ontology = Ontology::new();
+    ontology.set_insertion_validator(require_ex_namespace);
+
+    ontology
+        .add_subclass_axiom(subclass(
+            "http://example.org/zoo#Lion",
+            "http://example.org/zoo#Cat",
+        ))
+        .unwrap();
+
+    let error = ontology
+        .add_subclass_axiom(subclass(
+            "http://example.org/zoo#Tiger",
+            "http://other.org/animals#Cat",
+        ))
+        .unwrap_err();
+    assert!(error.to_string().contains("http://other.org/animals#Cat"));
+
+    let error = ontology
+        .add_axiom(Axiom::DisjointClasses(Box::new(DisjointClassesAxiom::new(
+            vec![
+                Arc::new(IRI::new("http://example.org/zoo#Lion").unwrap()),
+                Arc::new(IRI::new("http://other.org/animals#Dog").unwrap()),
+            ],
+        ))))
+        .unwrap_err();
+    assert!(matches!(error, OwlError::ValidationError(_)));
+
+    // Rejected axioms are not added
+    assert_eq!(ontology.axioms().len(), 1);
+    assert_eq!(ontology.subclass_axioms().len(), 1);
+    assert!(ontology.disjoint_classes_axioms().is_empty());
+}
+
+#[test]
+fn test_validator_can_be_replaced_and_cleared() {
+    let mut ontology = Ontology::new();
+    assert!(ontology.insertion_validator().is_none());
+
+    ontology.set_insertion_validator(|_| {
+        Err(OwlError::ValidationError("read-only ontology".to_string()))
+    });
+    assert!(ontology
+        .add_subclass_axiom(subclass(
+            "http://example.org/zoo#Lion",
+            "http://example.org/zoo#Cat"
+        ))
+        .is_err());
+
+    ontology.set_insertion_validator(require_ex_namespace);
+    let validator = ontology.insertion_validator().unwrap().clone();
+    assert!(validator
+        .check(&Axiom::SubClassOf(Box::new(subclass(
+            "http://other.org/animals#Cat",
+            "http://example.org/zoo#Animal"
+        ))))
+        .is_err());
+
+    assert!(ontology.clear_insertion_validator().is_some());
+    ontology
+        .add_subclass_axiom(subclass(
+            "http://other.org/animals#Cat",
+            "http://example.org/zoo#Animal",
+        ))
+        .unwrap();
+    assert_eq!(ontology.axioms().len(), 1);
+}
+
+#[test]
+fn test_validator_survives_compaction() {
+    let mut ontology = Ontology::new();
+    ontology.set_insertion_validator(require_ex_namespace);
+    let lion = subclass("http://example.org/zoo#Lion", "http://example.org/zoo#Cat");
+    ontology.add_subclass_axiom(lion.clone()).unwrap();
+    ontology.add_subclass_axiom(lion).unwrap();
+
+    ontology.compact().unwrap();
+    assert!(ontology.insertion_validator().is_some());
+    assert!(ontology
+        .add_subclass_axiom(subclass(
+            "http://example.org/zoo#Tiger",
+            "http://other.org/animals#Cat"
+        ))
+        .is_err());
+    assert_eq!(ontology.axioms().len(), 1);
+}
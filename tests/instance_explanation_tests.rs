@@ -0,0 +1,109 @@
+//! Tests for explaining why an individual is an instance of a class
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class(sub), class(sup))))
+}
+
+fn typed(individual: &str, class_name: &str) -> Axiom {
+    Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+        Arc::new(iri(individual)),
+        class(class_name),
+    )))
+}
+
+fn related(subject: &str, property: &str, object: &str) -> Axiom {
+    Axiom::PropertyAssertion(Box::new(PropertyAssertionAxiom::new(
+        Arc::new(iri(subject)),
+        Arc::new(iri(property)),
+        Arc::new(iri(object)),
+    )))
+}
+
+fn domain(property: &str, class_name: &str) -> Axiom {
+    Axiom::ObjectPropertyDomain(Box::new(ObjectPropertyDomainAxiom::new(
+        Arc::new(iri(property)),
+        class(class_name),
+    )))
+}
+
+fn ontology(axioms: &[Axiom]) -> Ontology {
+    let mut ontology = Ontology::new();
+    for axiom in axioms {
+        ontology.add_axiom(axiom.clone()).unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_superclass_membership_is_explained_by_assertion_and_subclass() {
+    let mut reasoner = OwlReasoner::new(ontology(&[
+        subclass("Parent", "Person"),
+        subclass("Person", "Agent"),
+        subclass("Dog", "Animal"),
+        typed("alice", "Parent"),
+        typed("rex", "Dog"),
+        related("alice", "owns", "rex"),
+    ]));
+
+    let explanation = reasoner
+        .explain_instance_of(&iri("alice"), &iri("Person"))
+        .unwrap();
+    assert_eq!(
+        explanation,
+        vec![subclass("Parent", "Person"), typed("alice", "Parent")]
+    );
+
+    let explanation = reasoner
+        .explain_instance_of(&iri("alice"), &iri("Agent"))
+        .unwrap();
+    assert_eq!(explanation.len(), 3);
+    assert!(explanation.contains(&subclass("Person", "Agent")));
+}
+
+#[test]
+fn test_membership_through_property_domain() {
+    let mut reasoner = OwlReasoner::new(ontology(&[
+        domain("hasChild", "Parent"),
+        subclass("Parent", "Person"),
+        related("alice", "hasChild", "bob"),
+        typed("bob", "Person"),
+    ]));
+
+    let explanation = reasoner
+        .explain_instance_of(&iri("alice"), &iri("Person"))
+        .unwrap();
+    assert_eq!(
+        explanation,
+        vec![
+            domain("hasChild", "Parent"),
+            subclass("Parent", "Person"),
+            related("alice", "hasChild", "bob"),
+        ]
+    );
+}
+
+#[test]
+fn test_non_entailed_membership_is_an_error() {
+    let mut reasoner = OwlReasoner::new(ontology(&[
+        subclass("Parent", "Person"),
+        typed("alice", "Person"),
+    ]));
+
+    let error = reasoner
+        .explain_instance_of(&iri("alice"), &iri("Parent"))
+        .unwrap_err();
+    assert!(matches!(error, OwlError::ReasoningError(_)), "{:?}", error);
+}
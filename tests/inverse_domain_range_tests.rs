@@ -0,0 +1,104 @@
+//! Tests for domain and range axioms over inverse object property expressions
+
+use owl2_reasoner::parser::{OwlXmlParser, OwlXmlSerializer};
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn property(name: &str) -> ObjectPropertyExpression {
+    ObjectProperty::new(iri(name)).into()
+}
+
+fn relate(ontology: &mut Ontology, subject: &str, property: &str, object: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(subject)),
+            Arc::new(iri(property)),
+            Arc::new(iri(object)),
+        ))
+        .unwrap();
+}
+
+/// hasParent has range Person and hasChild is its inverse
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_class(Class::new(iri("Person"))).unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::new(iri("hasParent"), class("Person")),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::InverseObjectProperties(Box::new(
+            InverseObjectPropertiesAxiom::new(property("hasParent"), property("hasChild")),
+        )))
+        .unwrap();
+    relate(&mut ontology, "alice", "hasParent", "bob");
+    relate(&mut ontology, "carol", "hasChild", "dave");
+    ontology
+}
+
+#[test]
+fn test_range_constrains_objects_and_subjects_of_the_inverse() {
+    let reasoner = TableauxReasoner::new(family());
+
+    // Objects of hasParent are persons
+    assert!(reasoner
+        .is_instance_of(&iri("bob"), &iri("Person"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("alice"), &iri("Person"))
+        .unwrap());
+
+    // carol hasChild dave means dave hasParent carol
+    assert!(reasoner
+        .is_instance_of(&iri("carol"), &iri("Person"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("dave"), &iri("Person"))
+        .unwrap());
+
+    let mut owl_reasoner = OwlReasoner::new(family());
+    assert!(owl_reasoner
+        .is_instance_of(&iri("carol"), &iri("Person"))
+        .unwrap());
+}
+
+#[test]
+fn test_domain_of_inverse_property_constrains_objects() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::from_expression(
+                property("hasChild").inverse(),
+                class("Person"),
+            ),
+        )))
+        .unwrap();
+    relate(&mut ontology, "eve", "hasChild", "frank");
+    let reasoner = TableauxReasoner::new(ontology.clone());
+
+    assert!(reasoner
+        .is_instance_of(&iri("frank"), &iri("Person"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("eve"), &iri("Person"))
+        .unwrap());
+
+    // The inverse expression survives an OWL/XML round trip
+    let axiom = &ontology.object_property_domain_axioms()[0];
+    assert_eq!(axiom.property(), &iri("hasChild"));
+    let xml = OwlXmlSerializer::new().serialize(&ontology).unwrap();
+    let reparsed = OwlXmlParser::new().parse_str(&xml).unwrap();
+    assert_eq!(reparsed.object_property_domain_axioms()[0], *axiom);
+}
@@ -0,0 +1,91 @@
+//! Tests for optional IRI normalization before interning
+
+use owl2_reasoner::iri::{set_global_iri_normalization, IriNormalization};
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::*;
+
+fn normalized(iri: &str) -> String {
+    IriNormalization::all().apply(iri).into_owned()
+}
+
+#[test]
+fn test_trailing_slash_variants_intern_to_the_same_iri() {
+    set_global_iri_normalization(IriNormalization::all());
+
+    let plain = IRI::new("http://example.org/shop/Person").unwrap();
+    let slashed = IRI::new("http://example.org/shop/Person/").unwrap();
+    assert_eq!(plain, slashed);
+    assert_eq!(slashed.as_str(), "http://example.org/shop/Person");
+    assert_eq!(
+        *IRI::new_optimized("http://example.org/shop/Person/").unwrap(),
+        plain
+    );
+    assert_eq!(
+        Class::new_shared("http://example.org/shop/Person/").unwrap(),
+        Class::new_shared("http://example.org/shop/Person").unwrap()
+    );
+
+    let ontology = TurtleParser::new()
+        .parse_str(
+            r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+<http://example.org/shop/Person> a owl:Class .
+<http://example.org/shop/Person/> a owl:Class .
+<http://example.org:80/shop/Caf%c3%a9#> a owl:Class .
+<http://example.org/shop/Caf%C3%A9> a owl:Class .
+"#,
+        )
+        .unwrap();
+    let classes: Vec<_> = ontology
+        .classes()
+        .iter()
+        .map(|class| class.iri().as_str().to_string())
+        .filter(|iri| iri.contains("/shop/"))
+        .collect();
+    assert_eq!(classes.len(), 2, "{:?}", classes);
+}
+
+#[test]
+fn test_normalization_rewrites() {
+    assert_eq!(normalized("http://ex.org/Person/"), "http://ex.org/Person");
+    assert_eq!(normalized("http://ex.org/Person#"), "http://ex.org/Person");
+    assert_eq!(normalized("http://ex.org/Person/#"), "http://ex.org/Person");
+    assert_eq!(
+        normalized("http://ex.org/Caf%c3%a9"),
+        "http://ex.org/Caf%C3%A9"
+    );
+    assert_eq!(
+        normalized("http://ex.org:80/Person"),
+        "http://ex.org/Person"
+    );
+    assert_eq!(
+        normalized("https://ex.org:443/Person"),
+        "https://ex.org/Person"
+    );
+
+    // Meaningful parts are kept
+    assert_eq!(normalized("http://ex.org/"), "http://ex.org/");
+    assert_eq!(normalized("http://ex.org:8080/a"), "http://ex.org:8080/a");
+    assert_eq!(normalized("https://ex.org:80/a"), "https://ex.org:80/a");
+    assert_eq!(normalized("http://ex.org/a#b"), "http://ex.org/a#b");
+    assert_eq!(
+        normalized("http://ex.org/a?next=/"),
+        "http://ex.org/a?next=/"
+    );
+    assert_eq!(normalized("http://ex.org/100%25"), "http://ex.org/100%25");
+}
+
+#[test]
+fn test_normalization_is_opt_in() {
+    let none = IriNormalization::none();
+    assert!(!none.is_enabled());
+    assert_eq!(none.apply("http://ex.org/Person/"), "http://ex.org/Person/");
+
+    let slash_only = IriNormalization {
+        trailing_slash: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        slash_only.apply("http://ex.org:80/Person/"),
+        "http://ex.org:80/Person"
+    );
+}
@@ -0,0 +1,139 @@
+//! Tests for imports kept apart and consulted on demand during reasoning
+
+mod common;
+
+use common::{iri, subclass};
+use owl2_reasoner::memory::{tracked_heap_bytes, TrackingAllocator};
+use owl2_reasoner::parser::{ImportResolverConfig, ImportSource};
+use owl2_reasoner::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+/// Serializes the tests so allocations of one do not count towards another
+static MEASURING: Mutex<()> = Mutex::new(());
+
+const UPSTREAM: &str = "memory://example.org/upstream";
+const BASE: &str = "memory://example.org/base";
+
+/// Serves ontologies from memory
+struct MemorySource {
+    ontologies: HashMap<String, Ontology>,
+}
+
+impl ImportSource for MemorySource {
+    fn can_resolve(&self, iri: &IRI) -> bool {
+        iri.as_str().starts_with("memory://")
+    }
+
+    fn resolve(&self, iri: &IRI, _config: &ImportResolverConfig) -> OwlResult<Ontology> {
+        self.ontologies
+            .get(iri.as_str())
+            .cloned()
+            .ok_or_else(|| OwlError::ImportResolutionError {
+                iri: iri.clone(),
+                message: "not found".to_string(),
+            })
+    }
+
+    fn name(&self) -> &'static str {
+        "Memory"
+    }
+}
+
+/// Upstream: `Dog ⊑ Mammal` and `unrelated` axioms `Ui ⊑ Vi`, importing
+/// base: `Mammal ⊑ Animal`
+fn source(unrelated: usize) -> MemorySource {
+    let mut upstream = Ontology::new();
+    upstream.set_iri(UPSTREAM);
+    upstream.add_import(IRI::new(BASE).unwrap());
+    upstream
+        .add_subclass_axiom(subclass("Dog", "Mammal"))
+        .unwrap();
+    for i in 0..unrelated {
+        upstream
+            .add_subclass_axiom(subclass(&format!("U{}", i), &format!("V{}", i)))
+            .unwrap();
+    }
+
+    let mut base = Ontology::new();
+    base.set_iri(BASE);
+    base.add_subclass_axiom(subclass("Mammal", "Animal"))
+        .unwrap();
+
+    MemorySource {
+        ontologies: HashMap::from([(UPSTREAM.to_string(), upstream), (BASE.to_string(), base)]),
+    }
+}
+
+/// `Pet ⊑ Dog`, importing upstream
+fn pet_importing_upstream(source: MemorySource, lazy: bool) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_import(IRI::new(UPSTREAM).unwrap());
+    ontology.add_subclass_axiom(subclass("Pet", "Dog")).unwrap();
+
+    let mut resolver = ImportResolver::new().unwrap();
+    resolver.add_source(Box::new(source));
+    if lazy {
+        resolver.resolve_imports_lazily(&mut ontology).unwrap();
+    } else {
+        resolver.resolve_imports(&mut ontology).unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_imported_axioms_are_inlined_on_demand() {
+    let _guard = MEASURING.lock().unwrap();
+    let ontology = pet_importing_upstream(source(100), true);
+
+    // Both ontologies of the import closure are kept apart
+    assert_eq!(ontology.subclass_axioms().len(), 1);
+    let imported: Vec<usize> = ontology
+        .imported_ontologies()
+        .iter()
+        .map(|imported| imported.subclass_axioms().len())
+        .collect();
+    assert_eq!(imported, vec![101, 1]);
+
+    // Pet ⊑ Dog ⊑ Mammal ⊑ Animal goes through both imports
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_subclass_of(&iri("Pet"), &iri("Animal"))
+        .unwrap());
+    assert!(!reasoner
+        .is_subclass_of(&iri("Animal"), &iri("Pet"))
+        .unwrap());
+    assert_eq!(reasoner.ontology().subclass_axioms().len(), 3);
+
+    // An unrelated imported axiom is inlined once a query asks about it
+    assert!(reasoner.is_subclass_of(&iri("U7"), &iri("V7")).unwrap());
+    assert_eq!(reasoner.ontology().subclass_axioms().len(), 4);
+    assert!(reasoner.is_consistent().unwrap());
+}
+
+#[test]
+fn test_lazy_imports_use_less_memory_than_merging() {
+    let _guard = MEASURING.lock().unwrap();
+
+    // Fill the global IRI cache so neither measurement pays for it
+    drop(source(2_000));
+
+    // Heap retained by a reasoner that has answered Pet ⊑ Mammal
+    let retained = |lazy: bool| {
+        let before = tracked_heap_bytes().unwrap();
+        let mut reasoner = OwlReasoner::new(pet_importing_upstream(source(2_000), lazy));
+        assert!(reasoner
+            .is_subclass_of(&iri("Pet"), &iri("Mammal"))
+            .unwrap());
+        let retained = tracked_heap_bytes().unwrap().saturating_sub(before);
+        drop(reasoner);
+        retained
+    };
+
+    let eager = retained(false);
+    let lazy = retained(true);
+    assert!(lazy < eager, "lazy {} bytes, eager {} bytes", lazy, eager);
+}
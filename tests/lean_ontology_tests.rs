@@ -0,0 +1,88 @@
+//! Tests for removing every axiom entailed by the rest of an ontology
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/zoo#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class(sub), class(sup))))
+}
+
+fn instance(individual: &str, of: &str) -> Axiom {
+    Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+        Arc::new(iri(individual)),
+        class(of),
+    )))
+}
+
+fn ontology(axioms: Vec<Axiom>) -> Ontology {
+    let mut ontology = Ontology::new();
+    for axiom in axioms {
+        ontology.add_axiom(axiom).unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_lean_removes_explicit_transitive_subclass_axiom() {
+    let original = ontology(vec![
+        subclass("Dog", "Mammal"),
+        subclass("Mammal", "Animal"),
+        subclass("Dog", "Animal"),
+        instance("rex", "Dog"),
+        instance("rex", "Mammal"),
+    ]);
+    let mut lean = original.clone();
+
+    assert_eq!(lean.lean().unwrap(), 2);
+
+    let remaining: Vec<&Axiom> = lean.axioms().iter().map(|axiom| axiom.as_ref()).collect();
+    assert_eq!(
+        remaining,
+        vec![
+            &subclass("Dog", "Mammal"),
+            &subclass("Mammal", "Animal"),
+            &instance("rex", "Dog"),
+        ]
+    );
+    let mut reasoner = OwlReasoner::new(lean.clone());
+    assert!(reasoner
+        .is_subclass_of(&iri("Dog"), &iri("Animal"))
+        .unwrap());
+    assert!(reasoner.are_logically_equivalent(&original, &lean).unwrap());
+}
+
+#[test]
+fn test_lean_keeps_ontology_without_redundancy() {
+    let mut lean = ontology(vec![subclass("Dog", "Mammal"), subclass("Cat", "Mammal")]);
+
+    assert_eq!(lean.lean().unwrap(), 0);
+    assert_eq!(lean.axioms().len(), 2);
+}
+
+#[test]
+fn test_lean_rejects_inconsistent_ontology() {
+    let mut inconsistent = ontology(vec![
+        Axiom::DisjointClasses(Box::new(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Dog")),
+            Arc::new(iri("Cat")),
+        ]))),
+        instance("rex", "Dog"),
+        instance("rex", "Cat"),
+    ]);
+
+    assert!(matches!(
+        inconsistent.lean(),
+        Err(OwlError::InconsistentOntology(_))
+    ));
+    assert_eq!(inconsistent.axioms().len(), 3);
+}
@@ -0,0 +1,112 @@
+//! Tests for the datatypes given to literals by the Turtle and N-Triples parsers
+
+use owl2_reasoner::parser::{NtriplesParser, OntologyParser, ParserConfig, TurtleParser};
+use owl2_reasoner::*;
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+fn xsd(name: &str) -> IRI {
+    IRI::new(format!("{}{}", XSD, name)).unwrap()
+}
+
+/// The literal values of the data property assertions, in document order
+fn literals(ontology: &Ontology) -> Vec<Literal> {
+    ontology
+        .data_property_assertions()
+        .into_iter()
+        .map(|axiom| axiom.value().clone())
+        .collect()
+}
+
+fn turtle(statements: &str) -> String {
+    format!(
+        "@prefix ex: <http://example.org/> .\n\
+         @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n{}",
+        statements
+    )
+}
+
+#[test]
+fn test_turtle_infers_native_literal_datatypes() {
+    let ontology = TurtleParser::new()
+        .parse_str(&turtle(
+            "ex:item ex:count 42 .\n\
+             ex:item ex:quoted \"42\" .\n\
+             ex:item ex:price 3.14 .\n\
+             ex:item ex:weight -1.5e3 .\n\
+             ex:item ex:available true .\n",
+        ))
+        .unwrap();
+
+    let literals = literals(&ontology);
+    assert_eq!(
+        literals,
+        vec![
+            Literal::typed("42", xsd("integer")),
+            Literal::simple("42"),
+            Literal::typed("3.14", xsd("decimal")),
+            Literal::typed("-1.5e3", xsd("double")),
+            Literal::typed("true", xsd("boolean")),
+        ]
+    );
+    assert_eq!(**literals[0].datatype(), xsd("integer"));
+    assert_eq!(**literals[1].datatype(), xsd("string"));
+}
+
+#[test]
+fn test_turtle_typed_and_language_tagged_literals() {
+    let ontology = TurtleParser::new()
+        .parse_str(&turtle(
+            "ex:item ex:count \"7\"^^xsd:integer .\n\
+             ex:item ex:label \"Widget\"@en .\n",
+        ))
+        .unwrap();
+
+    assert_eq!(
+        literals(&ontology),
+        vec![
+            Literal::typed("7", xsd("integer")),
+            Literal::lang_tagged("Widget", "en"),
+        ]
+    );
+}
+
+#[test]
+fn test_turtle_datatype_inference_can_be_disabled() {
+    let config = ParserConfig {
+        infer_literal_datatypes: false,
+        ..Default::default()
+    };
+    let ontology = TurtleParser::with_config(config)
+        .parse_str(&turtle("ex:item ex:count 42 .\n"))
+        .unwrap();
+
+    assert_eq!(literals(&ontology), vec![Literal::simple("42")]);
+}
+
+#[test]
+fn test_ntriples_requires_explicit_datatypes() {
+    let ontology = NtriplesParser::new()
+        .parse_str(
+            "<http://example.org/item> <http://example.org/count> \"42\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n\
+             <http://example.org/item> <http://example.org/quoted> \"42\" .\n\
+             <http://example.org/item> <http://example.org/label> \"Widget\"@en .\n",
+        )
+        .unwrap();
+
+    let literals = literals(&ontology);
+    assert_eq!(
+        literals,
+        vec![
+            Literal::typed("42", xsd("integer")),
+            Literal::simple("42"),
+            Literal::lang_tagged("Widget", "en"),
+        ]
+    );
+    assert_eq!(**literals[1].datatype(), xsd("string"));
+
+    // Bare numbers are a Turtle shortcut, not N-Triples
+    assert!(NtriplesParser::new()
+        .parse_str("<http://example.org/item> <http://example.org/count> 42 .\n")
+        .is_err());
+}
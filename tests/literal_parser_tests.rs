@@ -0,0 +1,119 @@
+//! Tests for the per-datatype literal parser registry
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+fn xsd(name: &str, lexical: &str) -> Literal {
+    Literal::typed(lexical, IRI::new(format!("{}{}", XSD, name)).unwrap())
+}
+
+/// Parses temperatures written like "21.5C"
+fn parse_celsius(lexical: &str) -> Option<LiteralValue> {
+    lexical
+        .strip_suffix('C')?
+        .parse()
+        .ok()
+        .map(LiteralValue::Double)
+}
+
+#[test]
+fn test_builtin_xsd_parsers() {
+    assert_eq!(xsd("int", "42").as_i64(), Some(42));
+    assert_eq!(xsd("integer", "+7").as_f64(), Some(7.0));
+    assert_eq!(xsd("boolean", "false").as_bool(), Some(false));
+    assert_eq!(xsd("double", "1.5e2").as_f64(), Some(150.0));
+    assert_eq!(xsd("double", "-INF").as_f64(), Some(f64::NEG_INFINITY));
+    assert_eq!(
+        Literal::simple("plain").as_string(),
+        Some("plain".to_string())
+    );
+
+    for invalid in [
+        xsd("integer", "abc"),
+        xsd("byte", "300"),
+        xsd("positiveInteger", "0"),
+        xsd("decimal", "1e3"),
+        xsd("double", "inf"),
+    ] {
+        assert_eq!(invalid.value(), None, "{}", invalid.lexical_form());
+        assert!(!invalid.is_well_formed());
+    }
+    assert_eq!(xsd("int", "42").as_bool(), None);
+}
+
+#[test]
+fn test_custom_datatype_parser_used_by_literal_accessors() {
+    let celsius = "http://example.org/units#celsius";
+    let reading = Literal::typed("21.5C", IRI::new(celsius).unwrap());
+
+    // Unknown datatypes have no value and are accepted as is
+    assert_eq!(reading.value(), None);
+    assert!(reading.is_well_formed());
+
+    register_literal_parser(celsius, parse_celsius);
+    assert_eq!(reading.as_f64(), Some(21.5));
+    assert_eq!(reading.value(), Some(LiteralValue::Double(21.5)));
+    assert!(!Literal::typed("warm", IRI::new(celsius).unwrap()).is_well_formed());
+
+    assert!(unregister_literal_parser(celsius).is_some());
+    assert_eq!(reading.as_f64(), None);
+}
+
+#[test]
+fn test_standalone_registry() {
+    let kelvin = "http://example.org/units#kelvin";
+    let reading = Literal::typed("300C", IRI::new(kelvin).unwrap());
+
+    let mut registry = LiteralParserRegistry::empty();
+    assert_eq!(registry.parse(&xsd("int", "1")), None);
+    assert!(registry.is_well_formed(&reading));
+
+    registry.register(kelvin, |lexical| {
+        parse_celsius(lexical).map(|v| match v {
+            LiteralValue::Double(celsius) => LiteralValue::Double(celsius + 273.15),
+            other => other,
+        })
+    });
+    assert_eq!(registry.parse(&reading), Some(LiteralValue::Double(573.15)));
+    assert!(LiteralParserRegistry::new()
+        .parser(&format!("{}int", XSD))
+        .is_some());
+}
+
+#[test]
+fn test_shacl_datatype_rejects_ill_formed_literal() {
+    let shapes = ShapesGraph::parse_turtle(
+        r#"
+@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/hr#> .
+
+ex:PersonShape a sh:NodeShape ;
+    sh:targetClass ex:Person ;
+    sh:property [ sh:path ex:age ; sh:datatype xsd:integer ] .
+"#,
+    )
+    .unwrap();
+
+    let person = Arc::new(IRI::new("http://example.org/hr#alice").unwrap());
+    let mut ontology = Ontology::new();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            person.clone(),
+            ClassExpression::Class(Class::new("http://example.org/hr#Person")),
+        ))
+        .unwrap();
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            person,
+            Arc::new(IRI::new("http://example.org/hr#age").unwrap()),
+            xsd("integer", "forty"),
+        ))
+        .unwrap();
+
+    let report = shapes.validate(&ontology).unwrap();
+    assert!(!report.conforms());
+    assert_eq!(report.violations()[0].value.as_deref(), Some("forty"));
+}
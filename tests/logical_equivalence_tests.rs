@@ -0,0 +1,120 @@
+//! Tests for entailment and logical equivalence of ontologies
+
+use owl2_reasoner::axioms::property_expressions::ObjectPropertyExpression;
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/{}", name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(format!("http://example.org/{}", name)))
+}
+
+fn ontology(subclasses: &[(&str, &str)]) -> Ontology {
+    let mut ontology = Ontology::new();
+    for (sub, sup) in subclasses {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+            .unwrap();
+    }
+    ontology
+}
+
+fn reasoner() -> OwlReasoner {
+    OwlReasoner::new(Ontology::new())
+}
+
+#[test]
+fn test_equivalent_classes_matches_mutual_subclasses() {
+    let mut equivalent = Ontology::new();
+    equivalent
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::new(vec![
+            Arc::new(iri("Person")),
+            Arc::new(iri("Human")),
+        ]))
+        .unwrap();
+    let mutual = ontology(&[("Person", "Human"), ("Human", "Person")]);
+
+    assert!(reasoner()
+        .are_logically_equivalent(&equivalent, &mutual)
+        .unwrap());
+    assert!(reasoner()
+        .are_logically_equivalent(&mutual, &equivalent)
+        .unwrap());
+
+    // Only one direction of the subsumption is not enough
+    let one_way = ontology(&[("Person", "Human")]);
+    assert!(!reasoner()
+        .are_logically_equivalent(&equivalent, &one_way)
+        .unwrap());
+}
+
+#[test]
+fn test_redundant_axioms_do_not_change_meaning() {
+    let chain = ontology(&[("A", "B"), ("B", "C")]);
+    let closed = ontology(&[("A", "B"), ("B", "C"), ("A", "C")]);
+    assert!(reasoner()
+        .are_logically_equivalent(&chain, &closed)
+        .unwrap());
+
+    let reversed = ontology(&[("B", "A"), ("C", "B")]);
+    assert!(!reasoner()
+        .are_logically_equivalent(&chain, &reversed)
+        .unwrap());
+}
+
+#[test]
+fn test_domain_matches_its_subclass_form() {
+    let property =
+        ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(iri("hasPet"))));
+    let mut domain = Ontology::new();
+    domain
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(Arc::new(iri("hasPet")), class("Owner")),
+        )))
+        .unwrap();
+    let mut subclass = Ontology::new();
+    subclass
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::ObjectSomeValuesFrom(
+                Box::new(property),
+                Arc::new(ClassExpression::Class(Class::new(
+                    "http://www.w3.org/2002/07/owl#Thing",
+                ))),
+            ),
+            class("Owner"),
+        ))
+        .unwrap();
+
+    assert!(reasoner()
+        .are_logically_equivalent(&domain, &subclass)
+        .unwrap());
+}
+
+#[test]
+fn test_entails_and_inconsistency() {
+    let chain = ontology(&[("A", "B"), ("B", "C")]);
+    let mut reasoner = OwlReasoner::new(chain.clone());
+    let entailed = Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class("A"), class("C"))));
+    let not_entailed = Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class("C"), class("A"))));
+    assert!(reasoner.entails(&entailed).unwrap());
+    assert!(!reasoner.entails(&not_entailed).unwrap());
+
+    // An inconsistent ontology entails everything but is not equivalent to a consistent one
+    let mut inconsistent = chain.clone();
+    inconsistent
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::Class(Class::new("http://www.w3.org/2002/07/owl#Thing")),
+            ClassExpression::Class(Class::new("http://www.w3.org/2002/07/owl#Nothing")),
+        ))
+        .unwrap();
+    assert!(OwlReasoner::new(inconsistent.clone())
+        .entails(&not_entailed)
+        .unwrap());
+    assert!(!OwlReasoner::new(Ontology::new())
+        .are_logically_equivalent(&chain, &inconsistent)
+        .unwrap());
+}
@@ -0,0 +1,101 @@
+//! Tests for the handling of literals whose lexical form is invalid for their datatype
+
+use owl2_reasoner::parser::{
+    MalformedLiteralPolicy, NtriplesParser, OntologyParser, ParserConfig, TurtleParser,
+};
+use owl2_reasoner::*;
+
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+const TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/people#> .
+
+ex:age a owl:DatatypeProperty .
+ex:alice ex:age "abc"^^xsd:integer .
+ex:bob ex:age "42"^^xsd:integer .
+"#;
+
+fn parse_turtle(malformed_literal: MalformedLiteralPolicy) -> OwlResult<Ontology> {
+    TurtleParser::with_config(ParserConfig {
+        malformed_literal,
+        ..Default::default()
+    })
+    .parse_str(TURTLE)
+}
+
+fn age_of(ontology: &Ontology, name: &str) -> Literal {
+    let subject = IRI::new(format!("http://example.org/people#{}", name)).unwrap();
+    ontology
+        .data_property_assertions()
+        .into_iter()
+        .find(|assertion| **assertion.subject() == subject)
+        .map(|assertion| assertion.value().clone())
+        .unwrap()
+}
+
+#[test]
+fn test_keep_stores_malformed_literal_as_written() {
+    let ontology = parse_turtle(MalformedLiteralPolicy::Keep).unwrap();
+
+    let age = age_of(&ontology, "alice");
+    assert_eq!(age.lexical_form(), "abc");
+    assert_eq!(age.datatype().as_str(), XSD_INTEGER);
+    assert_eq!(datatypes::malformed_literals(&ontology).len(), 1);
+}
+
+#[test]
+fn test_warn_keeps_malformed_literal() {
+    let ontology = parse_turtle(MalformedLiteralPolicy::Warn).unwrap();
+
+    assert_eq!(age_of(&ontology, "alice").datatype().as_str(), XSD_INTEGER);
+    assert!(datatypes::is_malformed_literal(&age_of(&ontology, "alice")));
+}
+
+#[test]
+fn test_error_reports_location_of_malformed_literal() {
+    match parse_turtle(MalformedLiteralPolicy::Error) {
+        Err(OwlError::ParseErrorWithLocation { line, column, .. }) => {
+            assert_eq!((line, column), (6, 17));
+        }
+        other => panic!("expected a located parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_coerce_turns_malformed_literal_into_string() {
+    let ontology = parse_turtle(MalformedLiteralPolicy::Coerce).unwrap();
+
+    let age = age_of(&ontology, "alice");
+    assert_eq!(age.lexical_form(), "abc");
+    assert_eq!(age.datatype().as_str(), XSD_STRING);
+    // Well-formed literals are untouched
+    assert_eq!(age_of(&ontology, "bob").datatype().as_str(), XSD_INTEGER);
+    assert!(datatypes::malformed_literals(&ontology).is_empty());
+}
+
+#[test]
+fn test_ntriples_policies() {
+    let document = format!(
+        "<http://example.org/people#age> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/2002/07/owl#DatatypeProperty> .\n\
+         <http://example.org/people#alice> <http://example.org/people#age> \"abc\"^^<{}> .\n",
+        XSD_INTEGER
+    );
+    let parse = |malformed_literal| {
+        NtriplesParser::with_config(ParserConfig {
+            malformed_literal,
+            ..Default::default()
+        })
+        .parse_str(&document)
+    };
+
+    let ontology = parse(MalformedLiteralPolicy::Keep).unwrap();
+    assert_eq!(age_of(&ontology, "alice").datatype().as_str(), XSD_INTEGER);
+    let ontology = parse(MalformedLiteralPolicy::Coerce).unwrap();
+    assert_eq!(age_of(&ontology, "alice").datatype().as_str(), XSD_STRING);
+    assert!(matches!(
+        parse(MalformedLiteralPolicy::Error),
+        Err(OwlError::ParseErrorWithLocation { line: 2, .. })
+    ));
+}
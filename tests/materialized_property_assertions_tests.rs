@@ -0,0 +1,69 @@
+//! Tests for materializing the asserted and inferred object property assertions
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/places#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn assertion(subject: &str, property: &str, object: &str) -> PropertyAssertionAxiom {
+    PropertyAssertionAxiom::new(
+        Arc::new(iri(subject)),
+        Arc::new(iri(property)),
+        Arc::new(iri(object)),
+    )
+}
+
+#[test]
+fn test_symmetric_property_produces_reverse_edge() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::SymmetricProperty(Box::new(
+            SymmetricPropertyAxiom::new(Arc::new(iri("borders"))),
+        )))
+        .unwrap();
+    ontology
+        .add_property_assertion(assertion("france", "borders", "spain"))
+        .unwrap();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    assert_eq!(
+        reasoner.materialized_property_assertions().unwrap(),
+        vec![
+            assertion("france", "borders", "spain"),
+            assertion("spain", "borders", "france"),
+        ]
+    );
+}
+
+#[test]
+fn test_transitive_property_produces_closure() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::TransitiveProperty(Box::new(
+            TransitivePropertyAxiom::new(Arc::new(iri("partOf"))),
+        )))
+        .unwrap();
+    for (subject, object) in [("paris", "france"), ("france", "europe")] {
+        ontology
+            .add_property_assertion(assertion(subject, "partOf", object))
+            .unwrap();
+    }
+    // Asserting an edge that is also entailed must not duplicate it
+    ontology
+        .add_property_assertion(assertion("paris", "partOf", "europe"))
+        .unwrap();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    assert_eq!(
+        reasoner.materialized_property_assertions().unwrap(),
+        vec![
+            assertion("france", "partOf", "europe"),
+            assertion("paris", "partOf", "europe"),
+            assertion("paris", "partOf", "france"),
+        ]
+    );
+}
@@ -0,0 +1,123 @@
+//! Tests for merging successors to satisfy max-cardinality restrictions
+
+use owl2_reasoner::reasoning::tableaux::{ReasoningConfig, TableauxReasoner, TraceEvent};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn property(name: &str) -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectProperty::new(iri(name)).into())
+}
+
+fn not(expression: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectComplementOf(Arc::new(expression))
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn some(name: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(property(name), Arc::new(filler))
+}
+
+fn only(name: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectAllValuesFrom(property(name), Arc::new(filler))
+}
+
+fn at_least(n: u32) -> ClassExpression {
+    ClassExpression::ObjectMinCardinality(n, property("hasChild"))
+}
+
+fn at_most(n: u32) -> ClassExpression {
+    ClassExpression::ObjectMaxCardinality(n, property("hasChild"))
+}
+
+#[test]
+fn test_pairwise_different_fillers_cannot_be_merged() {
+    // Three children that are pairwise different cannot fit into two
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    let concept = and(vec![
+        at_least(3),
+        only("hasChild", class("Person")),
+        at_most(2),
+    ]);
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+
+    // Three existential fillers of pairwise disjoint classes are different too
+    let mut ontology = Ontology::new();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Infant")),
+            Arc::new(iri("Teen")),
+            Arc::new(iri("Adult")),
+        ]))
+        .unwrap();
+    let reasoner = TableauxReasoner::new(ontology);
+    let concept = and(vec![
+        some("hasChild", class("Infant")),
+        some("hasChild", class("Teen")),
+        some("hasChild", class("Adult")),
+        at_most(2),
+    ]);
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+}
+
+#[test]
+fn test_merged_fillers_are_expanded_again() {
+    // The only child would have to both have and not have a pet
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    let concept = and(vec![
+        at_most(1),
+        some("hasChild", some("hasPet", class("Dog"))),
+        some("hasChild", only("hasPet", not(class("Dog")))),
+    ]);
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+}
+
+#[test]
+fn test_merge_choices_are_backtracked() {
+    let config = ReasoningConfig {
+        record_trace: true,
+        ..Default::default()
+    };
+    let reasoner = TableauxReasoner::with_config(Ontology::new(), config);
+
+    // Without a max-cardinality restriction the children are kept apart
+    let concept = and(vec![
+        some("hasChild", class("Doctor")),
+        some("hasChild", not(class("Doctor"))),
+    ]);
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+
+    // The two children can be one and the same person
+    let concept = and(vec![
+        at_most(1),
+        some("hasChild", class("Doctor")),
+        some("hasChild", class("Musician")),
+    ]);
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+    assert!(reasoner
+        .last_trace()
+        .iter()
+        .any(|event| matches!(event, TraceEvent::Merge { .. })));
+
+    // Only one of the possible merges is free of clashes
+    let concept = and(vec![
+        at_most(2),
+        some("hasChild", class("Doctor")),
+        some("hasChild", class("Musician")),
+        some("hasChild", not(class("Doctor"))),
+        only("hasChild", some("hasPet", class("Dog"))),
+    ]);
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+}
@@ -0,0 +1,74 @@
+//! Guards the measured per-entity memory footprint against regressions
+//!
+//! The heap is counted by `TrackingAllocator`, so the figures are measured
+//! rather than estimated. The allocator is process-wide, so this binary holds
+//! a single test: allocations of tests running alongside it would be counted.
+//!
+//! Bytes per class depend on the toolchain and allocator as much as on this
+//! crate, so an absolute limit is only enforced when `OWL2_MEMORY_BASELINE`
+//! names one in bytes. Run with `--nocapture` to see the measured figures.
+
+use owl2_reasoner::memory::{get_memory_stats, TrackingAllocator};
+use owl2_reasoner::validation::memory_profiler::{EntityMemoryProfile, MemoryProfiler};
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+/// Allowed growth over the baseline before the guard fails
+const TOLERANCE: f64 = 1.25;
+
+/// Bytes per class named by `OWL2_MEMORY_BASELINE`, if any
+fn baseline_bytes_per_entity() -> Option<usize> {
+    let baseline = std::env::var("OWL2_MEMORY_BASELINE").ok()?;
+    Some(
+        baseline
+            .parse()
+            .expect("OWL2_MEMORY_BASELINE must be a number of bytes"),
+    )
+}
+
+fn report(profiles: &[EntityMemoryProfile]) -> String {
+    profiles
+        .iter()
+        .map(|profile| {
+            format!(
+                "{} classes: {} bytes per class, {:.2} MB",
+                profile.count, profile.average_memory_bytes, profile.total_memory_mb
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[test]
+fn test_bytes_per_entity_stay_within_baseline() {
+    assert!(get_memory_stats().measured);
+    let mut profiler = MemoryProfiler::new().unwrap();
+
+    let mut profiles = Vec::new();
+    for count in [1_000, 2_000, 4_000] {
+        profiles.push(profiler.profile_entities(count).unwrap());
+    }
+
+    println!("measured {}", report(&profiles));
+
+    if let Some(baseline) = baseline_bytes_per_entity() {
+        let limit = (baseline as f64 * TOLERANCE) as usize;
+        assert!(
+            profiles
+                .iter()
+                .all(|profile| profile.average_memory_bytes <= limit),
+            "over the {} byte limit per class: {}",
+            limit,
+            report(&profiles)
+        );
+    }
+    // Memory grows linearly: larger ontologies cost no more per entity
+    let smallest = profiles[0].average_memory_bytes as f64;
+    let largest = profiles[profiles.len() - 1].average_memory_bytes as f64;
+    assert!(
+        largest <= smallest * TOLERANCE,
+        "memory per class grows with the ontology: {}",
+        report(&profiles)
+    );
+}
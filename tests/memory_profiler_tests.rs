@@ -0,0 +1,15 @@
+//! Tests for the per-entity memory profiler
+
+use owl2_reasoner::validation::memory_profiler::{MemoryAnalysisReport, MemoryProfiler};
+
+#[test]
+fn test_empty_profile_is_rejected() {
+    assert!(MemoryProfiler::new().unwrap().profile_entities(0).is_err());
+}
+
+#[test]
+fn test_default_report_claims_no_measurements() {
+    let report = MemoryAnalysisReport::default();
+    assert!(report.entity_profiles.is_empty());
+    assert_eq!(report.total_memory_mb, 0.0);
+}
@@ -0,0 +1,133 @@
+//! Tests for parsing the n-ary owl:AllDisjointClasses / owl:AllDifferent encodings
+
+use owl2_reasoner::parser::{OntologyParser, RdfXmlParser, TurtleParser};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+
+fn expected_members(names: &[&str]) -> HashSet<String> {
+    names
+        .iter()
+        .map(|name| format!("http://example.org/{}", name))
+        .collect()
+}
+
+fn disjoint_members(ontology: &Ontology) -> Vec<HashSet<String>> {
+    ontology
+        .disjoint_classes_axioms()
+        .iter()
+        .map(|axiom| {
+            axiom
+                .classes()
+                .iter()
+                .map(|iri| iri.as_str().to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_rdf_xml_all_disjoint_classes() {
+    let content = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+  <owl:Class rdf:about="http://example.org/Cat"/>
+  <owl:Class rdf:about="http://example.org/Dog"/>
+  <owl:Class rdf:about="http://example.org/Bird"/>
+  <owl:AllDisjointClasses>
+    <owl:members rdf:parseType="Collection">
+      <owl:Class rdf:about="http://example.org/Cat"/>
+      <owl:Class rdf:about="http://example.org/Dog"/>
+      <owl:Class rdf:about="http://example.org/Bird"/>
+    </owl:members>
+  </owl:AllDisjointClasses>
+</rdf:RDF>"#;
+
+    let ontology = RdfXmlParser::new().parse_str(content).unwrap();
+    assert_eq!(
+        disjoint_members(&ontology),
+        vec![expected_members(&["Cat", "Dog", "Bird"])]
+    );
+}
+
+#[test]
+fn test_rdf_xml_all_different() {
+    let content = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+  <owl:AllDifferent>
+    <owl:distinctMembers rdf:parseType="Collection">
+      <rdf:Description rdf:about="http://example.org/alice"/>
+      <rdf:Description rdf:about="http://example.org/bob"/>
+      <rdf:Description rdf:about="http://example.org/carol"/>
+    </owl:distinctMembers>
+  </owl:AllDifferent>
+</rdf:RDF>"#;
+
+    let ontology = RdfXmlParser::new().parse_str(content).unwrap();
+    let axioms = ontology.different_individuals_axioms();
+    assert_eq!(axioms.len(), 1);
+    let members: HashSet<String> = axioms[0]
+        .individuals()
+        .iter()
+        .map(|iri| iri.as_str().to_string())
+        .collect();
+    assert_eq!(members, expected_members(&["alice", "bob", "carol"]));
+}
+
+#[test]
+fn test_turtle_all_disjoint_classes() {
+    let content = r#"@prefix : <http://example.org/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+
+:Cat a owl:Class .
+:Dog a owl:Class .
+:Bird a owl:Class .
+
+[] a owl:AllDisjointClasses ;
+   owl:members ( :Cat :Dog :Bird ) .
+"#;
+
+    let ontology = TurtleParser::new().parse_str(content).unwrap();
+    assert_eq!(
+        disjoint_members(&ontology),
+        vec![expected_members(&["Cat", "Dog", "Bird"])]
+    );
+    assert!(ontology.class_assertions().is_empty());
+}
+
+#[test]
+fn test_turtle_explicit_member_list_cells_are_not_individuals() {
+    let content = r#"@prefix : <http://example.org/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+
+:Cat a owl:Class .
+:Dog a owl:Class .
+
+_:n a owl:AllDisjointClasses ;
+   owl:members _:c1 .
+_:c1 rdf:first :Cat ;
+   rdf:rest _:c2 .
+_:c2 rdf:first :Dog ;
+   rdf:rest rdf:nil .
+[] a owl:AllDifferent ;
+   owl:distinctMembers ( :rex :fido ) .
+"#;
+
+    let parser = TurtleParser::new();
+    let first = parser.parse_str(content).unwrap();
+    let second = parser.parse_str(content).unwrap();
+
+    assert_eq!(
+        disjoint_members(&first),
+        vec![expected_members(&["Cat", "Dog"])]
+    );
+    assert!(first
+        .named_individuals()
+        .iter()
+        .all(|individual| !individual.iri().as_str().starts_with("http://blank.node/")));
+    assert!(first.property_assertions().is_empty());
+
+    // Blank node labels are numbered afresh for every document
+    assert_eq!(first.content_hash(), second.content_hash());
+}
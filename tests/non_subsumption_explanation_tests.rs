@@ -0,0 +1,80 @@
+//! Tests for explaining why a class is not a subclass of another
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/pets#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn some(property: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(iri(property)),
+        ))),
+        Arc::new(filler),
+    )
+}
+
+/// Dog ⊑ Animal, Cat ⊑ Animal and Dog ⊑ ∃hasOwner.Person
+fn pets() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Dog", "Cat", "Person"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_object_property(ObjectProperty::new(iri("hasOwner")))
+        .unwrap();
+    for (sub, sup) in [
+        (class("Dog"), class("Animal")),
+        (class("Cat"), class("Animal")),
+        (class("Dog"), some("hasOwner", class("Person"))),
+    ] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(sub, sup))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_counter_model_shows_instance_lacking_superclass() {
+    let mut reasoner = OwlReasoner::new(pets());
+    assert!(!reasoner.is_subclass_of(&iri("Dog"), &iri("Cat")).unwrap());
+
+    let counter_model = reasoner
+        .explain_non_subsumption(&iri("Dog"), &iri("Cat"))
+        .unwrap();
+    assert_eq!(counter_model.subclass, iri("Dog"));
+    assert_eq!(counter_model.superclass, iri("Cat"));
+
+    let witness = counter_model.witness();
+    assert!(witness.is_instance_of(&iri("Dog")));
+    assert!(witness.is_instance_of(&iri("Animal")));
+    assert!(witness.is_not_instance_of(&iri("Cat")));
+    assert!(!witness.is_instance_of(&iri("Cat")));
+
+    // The witness has the owner its class requires
+    let owner = counter_model
+        .model
+        .edges
+        .iter()
+        .find(|(from, property, _)| *from == witness.node && *property == iri("hasOwner"))
+        .map(|(_, _, to)| counter_model.model.individual(*to).unwrap())
+        .expect("the witness has an owner");
+    assert!(owner.is_instance_of(&iri("Person")));
+}
+
+#[test]
+fn test_entailed_subsumption_has_no_counter_model() {
+    let mut reasoner = OwlReasoner::new(pets());
+    assert!(reasoner
+        .explain_non_subsumption(&iri("Dog"), &iri("Animal"))
+        .is_err());
+}
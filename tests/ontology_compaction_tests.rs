@@ -0,0 +1,131 @@
+//! Tests for removing duplicate and entailed axioms from an ontology
+
+use owl2_reasoner::parser::{OntologyParser, ParserConfig, TurtleParser};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/";
+
+const DUPLICATED: &str = r#"
+@prefix ex: <http://example.org/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+ex:Animal a owl:Class .
+ex:Mammal a owl:Class .
+ex:Dog a owl:Class .
+ex:Mammal rdfs:subClassOf ex:Animal .
+ex:Dog rdfs:subClassOf ex:Mammal .
+ex:Dog rdfs:subClassOf ex:Mammal .
+ex:Mammal rdfs:subClassOf ex:Animal .
+ex:Dog rdfs:subClassOf ex:Mammal .
+"#;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn subclass(sub: &str, sup: &str) -> Axiom {
+    Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(class(sub), class(sup))))
+}
+
+fn disjoint(names: &[&str]) -> Axiom {
+    Axiom::DisjointClasses(Box::new(DisjointClassesAxiom::new(
+        names.iter().map(|name| Arc::new(iri(name))).collect(),
+    )))
+}
+
+/// Subsumption between every pair of the given classes
+fn subsumptions(ontology: Ontology, names: &[&str]) -> Vec<bool> {
+    let reasoner = SimpleReasoner::new(ontology);
+    names
+        .iter()
+        .flat_map(|sub| names.iter().map(move |sup| (sub, sup)))
+        .map(|(sub, sup)| reasoner.is_subclass_of(&iri(sub), &iri(sup)).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_compact_removes_duplicate_subclass_axioms() {
+    let names = ["Animal", "Mammal", "Dog"];
+    let mut ontology = TurtleParser::new().parse_str(DUPLICATED).unwrap();
+    assert_eq!(ontology.subclass_axioms().len(), 5);
+    let before = subsumptions(ontology.clone(), &names);
+
+    let report = ontology.compact().unwrap();
+    assert_eq!(report.duplicates, 3);
+    assert_eq!(report.entailed, 0);
+    assert_eq!(ontology.subclass_axioms().len(), 2);
+    assert_eq!(ontology.axioms().len(), 2);
+    assert_eq!(ontology.classes().len(), 3);
+    assert_eq!(ontology.axioms_referencing(&iri("Dog")).len(), 1);
+    assert_eq!(subsumptions(ontology.clone(), &names), before);
+
+    // A compact ontology is left as it is
+    assert_eq!(ontology.compact().unwrap().removed(), 0);
+}
+
+#[test]
+fn test_deduplicate_on_load() {
+    let config = ParserConfig {
+        deduplicate_on_load: true,
+        ..Default::default()
+    };
+    let ontology = TurtleParser::with_config(config)
+        .parse_str(DUPLICATED)
+        .unwrap();
+    assert_eq!(ontology.subclass_axioms().len(), 2);
+}
+
+#[test]
+fn test_compact_entailed_removes_implied_axioms() {
+    let names = ["Animal", "Plant", "Mammal", "Dog", "Oak"];
+    let mut ontology = Ontology::new();
+    for axiom in [
+        subclass("Mammal", "Animal"),
+        subclass("Dog", "Mammal"),
+        subclass("Oak", "Plant"),
+        // Follows from the hierarchy above
+        subclass("Dog", "Animal"),
+        subclass("Dog", "Dog"),
+        disjoint(&["Animal", "Plant"]),
+        // Follows from Animal and Plant being disjoint
+        disjoint(&["Dog", "Oak"]),
+        disjoint(&["Dog", "Oak"]),
+    ] {
+        ontology.add_axiom(axiom).unwrap();
+    }
+    let before = subsumptions(ontology.clone(), &names);
+
+    let report = ontology.compact_entailed().unwrap();
+    assert_eq!(report.duplicates, 1);
+    assert_eq!(report.entailed, 3);
+    assert_eq!(ontology.subclass_axioms().len(), 3);
+    assert_eq!(ontology.disjoint_classes_axioms().len(), 1);
+    assert_eq!(subsumptions(ontology, &names), before);
+}
+
+#[test]
+fn test_compact_entailed_keeps_one_of_mutually_entailed_axioms() {
+    // A and B are equivalent through a cycle, so each of A ⊑ C and B ⊑ C
+    // follows from the other; only one of them may go
+    let mut ontology = Ontology::new();
+    for axiom in [
+        subclass("A", "B"),
+        subclass("B", "A"),
+        subclass("A", "C"),
+        subclass("B", "C"),
+    ] {
+        ontology.add_axiom(axiom).unwrap();
+    }
+
+    let report = ontology.compact_entailed().unwrap();
+    assert_eq!(report.entailed, 1);
+    let reasoner = SimpleReasoner::new(ontology);
+    assert!(reasoner.is_subclass_of(&iri("A"), &iri("C")).unwrap());
+    assert!(reasoner.is_subclass_of(&iri("B"), &iri("C")).unwrap());
+}
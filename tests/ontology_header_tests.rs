@@ -0,0 +1,148 @@
+//! Tests for ontology header metadata: version IRIs, annotations and annotated imports
+
+use owl2_reasoner::parser::{
+    OntologyParser, OwlFunctionalSyntaxParser, OwlXmlParser, OwlXmlSerializer, RdfXmlParser,
+    TurtleParser,
+};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const ONTOLOGY: &str = "http://example.org/pizza";
+const VERSION: &str = "http://example.org/pizza/2.1";
+const DC_CREATOR: &str = "http://purl.org/dc/elements/1.1/creator";
+
+fn iri(value: &str) -> IRI {
+    IRI::new(value).unwrap()
+}
+
+/// Check the header shared by every document in this file
+fn assert_pizza_header(ontology: &Ontology) {
+    let header = ontology.header();
+    assert_eq!(header.ontology_iri.as_deref(), Some(&iri(ONTOLOGY)));
+    assert_eq!(header.version_iri.as_deref(), Some(&iri(VERSION)));
+    assert_eq!(header.version_info(), Some("2.1"));
+    assert_eq!(ontology.version_iri(), Some(&iri(VERSION)));
+}
+
+#[test]
+fn test_turtle_header() {
+    let ontology = TurtleParser::new()
+        .parse_str(
+            r#"
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix dc: <http://purl.org/dc/elements/1.1/> .
+@prefix ex: <http://example.org/pizza#> .
+
+<http://example.org/pizza> a owl:Ontology ;
+    owl:versionIRI <http://example.org/pizza/2.1> ;
+    owl:versionInfo "2.1" ;
+    dc:creator "Alice" ;
+    owl:imports <http://example.org/food> .
+
+ex:Pizza a owl:Class .
+"#,
+        )
+        .unwrap();
+
+    assert_pizza_header(&ontology);
+    assert_eq!(
+        ontology.header().annotation_values(&iri(DC_CREATOR)),
+        [&AnnotationValue::Literal(Literal::simple("Alice"))]
+    );
+    assert_eq!(ontology.annotations().len(), 2);
+    // Header triples are not mistaken for individuals or assertions
+    assert!(ontology.named_individuals().is_empty());
+    assert!(ontology.data_property_assertions().is_empty());
+    assert!(ontology.imports().contains(&iri("http://example.org/food")));
+}
+
+#[test]
+fn test_functional_header() {
+    let ontology = OwlFunctionalSyntaxParser::new()
+        .parse_str(
+            r#"
+Prefix(owl:=<http://www.w3.org/2002/07/owl#>)
+Prefix(dc:=<http://purl.org/dc/elements/1.1/>)
+Ontology(<http://example.org/pizza> <http://example.org/pizza/2.1>
+    Annotation(owl:versionInfo "2.1")
+    Annotation(dc:creator "Alice"@en)
+    Declaration(Class(<http://example.org/pizza#Pizza>))
+)
+"#,
+        )
+        .unwrap();
+
+    assert_pizza_header(&ontology);
+    assert_eq!(
+        ontology.header().annotation_values(&iri(DC_CREATOR)),
+        [&AnnotationValue::Literal(Literal::lang_tagged(
+            "Alice", "en"
+        ))]
+    );
+}
+
+#[test]
+fn test_rdf_xml_header() {
+    let ontology = RdfXmlParser::new()
+        .parse_str(
+            r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <owl:Ontology rdf:about="http://example.org/pizza">
+        <owl:versionIRI rdf:resource="http://example.org/pizza/2.1"/>
+        <owl:versionInfo>2.1</owl:versionInfo>
+        <dc:creator>Alice</dc:creator>
+    </owl:Ontology>
+    <owl:Class rdf:about="http://example.org/pizza#Pizza"/>
+</rdf:RDF>"#,
+        )
+        .unwrap();
+
+    assert_pizza_header(&ontology);
+    assert_eq!(
+        ontology.header().annotation_values(&iri(DC_CREATOR)).len(),
+        1
+    );
+}
+
+#[test]
+fn test_owl_xml_header_round_trip() {
+    let mut ontology = Ontology::with_iri(iri(ONTOLOGY));
+    ontology.set_version_iri(iri(VERSION));
+    ontology.add_annotation(Annotation::new(
+        iri("http://www.w3.org/2002/07/owl#versionInfo"),
+        Literal::simple("2.1"),
+    ));
+    ontology.add_annotation(Annotation::new(iri(DC_CREATOR), Literal::simple("Alice")));
+    ontology
+        .add_class(Class::new(iri("http://example.org/pizza#Pizza")))
+        .unwrap();
+
+    let document = OwlXmlSerializer::new().serialize(&ontology).unwrap();
+    assert!(document.contains(r#"versionIRI="http://example.org/pizza/2.1""#));
+    let parsed = OwlXmlParser::new().parse_str(&document).unwrap();
+
+    assert_pizza_header(&parsed);
+    assert_eq!(parsed.header(), ontology.header());
+}
+
+#[test]
+fn test_annotated_import() {
+    let comment = Annotation::new(
+        iri("http://www.w3.org/2000/01/rdf-schema#comment"),
+        Literal::simple("Shared food vocabulary"),
+    );
+    let mut ontology = Ontology::with_iri(iri(ONTOLOGY));
+    ontology
+        .add_axiom(Axiom::Import(ImportAxiom::with_annotations(
+            Arc::new(iri("http://example.org/food")),
+            vec![comment.clone()],
+        )))
+        .unwrap();
+
+    assert!(ontology.imports().contains(&iri("http://example.org/food")));
+    let imports = ontology.import_axioms();
+    assert_eq!(imports.len(), 1);
+    assert_eq!(imports[0].annotations(), [comment]);
+}
@@ -0,0 +1,62 @@
+//! Tests for the by-type axiom indexes on Ontology
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(s: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(s).unwrap())
+}
+
+#[test]
+fn test_axiom_type_counts_match_added_axioms() {
+    let mut ontology = Ontology::new();
+
+    let person = Class::new("http://example.org/Person");
+    let parent = Class::new("http://example.org/Parent");
+    let animal = Class::new("http://example.org/Animal");
+
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(parent.clone()),
+            ClassExpression::from(person.clone()),
+        ))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(person.clone()),
+            ClassExpression::from(animal.clone()),
+        ))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            person.iri().clone(),
+            iri("http://example.org/Rock"),
+        ]))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            iri("http://example.org/john"),
+            ClassExpression::from(person.clone()),
+        ))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::TransitiveProperty(Box::new(
+            TransitivePropertyAxiom::new(iri("http://example.org/ancestorOf")),
+        )))
+        .unwrap();
+
+    let counts = ontology.axiom_type_counts();
+    assert_eq!(counts.get(&AxiomType::SubClassOf), Some(&2));
+    assert_eq!(counts.get(&AxiomType::DisjointClasses), Some(&1));
+    assert_eq!(counts.get(&AxiomType::ClassAssertion), Some(&1));
+    assert_eq!(counts.get(&AxiomType::TransitiveProperty), Some(&1));
+    assert_eq!(counts.get(&AxiomType::EquivalentClasses), None);
+    assert_eq!(counts.values().sum::<usize>(), ontology.axiom_count());
+
+    let subclass_axioms: Vec<_> = ontology.axioms_of_type(AxiomType::SubClassOf).collect();
+    assert_eq!(subclass_axioms.len(), 2);
+    assert!(subclass_axioms
+        .iter()
+        .all(|axiom| axiom.axiom_type() == AxiomType::SubClassOf));
+    assert_eq!(ontology.axioms_of_type(AxiomType::HasKey).count(), 0);
+}
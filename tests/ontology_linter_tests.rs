@@ -0,0 +1,188 @@
+//! Tests for the ontology modeling linter
+
+use owl2_reasoner::reasoning::ClassificationEngine;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(s: &str) -> IRI {
+    IRI::new(s).unwrap()
+}
+
+fn warnings_of(warnings: &[LintWarning], kind: LintKind) -> Vec<&LintWarning> {
+    warnings.iter().filter(|w| w.kind == kind).collect()
+}
+
+#[test]
+fn test_orphan_class_warning() {
+    let mut ontology = Ontology::new();
+    let animal = Class::new("http://example.org/Animal");
+    let dog = Class::new("http://example.org/Dog");
+    let rock = Class::new("http://example.org/Rock");
+    for class in [&animal, &dog, &rock] {
+        ontology.add_class(class.clone()).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(dog),
+            ClassExpression::from(animal),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("http://example.org/stone1")),
+            ClassExpression::from(rock),
+        ))
+        .unwrap();
+
+    let warnings = OntologyLinter::new(&ontology).lint();
+    let orphans = warnings_of(&warnings, LintKind::OrphanClass);
+    assert_eq!(orphans.len(), 1);
+    assert_eq!(orphans[0].subject, iri("http://example.org/Rock"));
+    assert_eq!(orphans[0].severity, LintSeverity::Info);
+    // Rock is used by a class assertion, so it is not reported as unused
+    assert!(warnings_of(&warnings, LintKind::UnusedEntity).is_empty());
+}
+
+#[test]
+fn test_property_without_range_warning() {
+    let mut ontology = Ontology::new();
+    let person = Class::new("http://example.org/Person");
+    ontology.add_class(person.clone()).unwrap();
+    ontology
+        .add_object_property(ObjectProperty::new("http://example.org/hasPet"))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(
+                Arc::new(iri("http://example.org/hasPet")),
+                ClassExpression::from(person.clone()),
+            ),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(
+                Arc::new(iri("http://example.org/hasParent")),
+                ClassExpression::from(person.clone()),
+            ),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyRange(Box::new(
+            ObjectPropertyRangeAxiom::new(
+                iri("http://example.org/hasParent"),
+                ClassExpression::from(person),
+            ),
+        )))
+        .unwrap();
+
+    let warnings = OntologyLinter::new(&ontology).lint();
+    let missing_range = warnings_of(&warnings, LintKind::PropertyWithoutRange);
+    assert_eq!(missing_range.len(), 1);
+    assert_eq!(missing_range[0].subject, iri("http://example.org/hasPet"));
+    assert_eq!(missing_range[0].severity, LintSeverity::Warning);
+    // Warnings come first in the ordering
+    assert_eq!(warnings[0].severity, LintSeverity::Warning);
+}
+
+#[test]
+fn test_unused_entity_and_disabled_checks() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_class(Class::new("http://example.org/Unused"))
+        .unwrap();
+
+    let warnings = OntologyLinter::new(&ontology).lint();
+    assert_eq!(warnings_of(&warnings, LintKind::UnusedEntity).len(), 1);
+
+    let warnings = OntologyLinter::new(&ontology)
+        .without(LintKind::UnusedEntity)
+        .without(LintKind::OrphanClass)
+        .lint();
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn test_axiom_signature_includes_nested_entities() {
+    let axiom = Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+        ClassExpression::from(Class::new("http://example.org/Parent")),
+        ClassExpression::ObjectSomeValuesFrom(
+            Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+                ObjectProperty::new("http://example.org/hasChild"),
+            ))),
+            Arc::new(ClassExpression::from(Class::new(
+                "http://example.org/Person",
+            ))),
+        ),
+    )));
+
+    let signature: Vec<String> = axiom
+        .signature()
+        .iter()
+        .map(|iri| iri.as_str().to_string())
+        .collect();
+    assert_eq!(
+        signature,
+        vec![
+            "http://example.org/Parent",
+            "http://example.org/hasChild",
+            "http://example.org/Person"
+        ]
+    );
+}
+
+#[test]
+fn test_reflexive_subclass_is_reported_and_ignored_by_classification() {
+    let mut ontology = Ontology::new();
+    let animal = Class::new("http://example.org/Animal");
+    let dog = Class::new("http://example.org/Dog");
+    for class in [&animal, &dog] {
+        ontology.add_class(class.clone()).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::from(dog.clone()),
+            ClassExpression::from(animal.clone()),
+        ))
+        .unwrap();
+    let before = ontology.clone();
+    for class in [&animal, &dog] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(
+                ClassExpression::from(class.clone()),
+                ClassExpression::from(class.clone()),
+            ))
+            .unwrap();
+    }
+
+    let warnings = OntologyLinter::new(&ontology).lint();
+    let reflexive = warnings_of(&warnings, LintKind::ReflexiveSubClass);
+    assert_eq!(reflexive.len(), 2);
+    assert_eq!(reflexive[0].subject, iri("http://example.org/Animal"));
+    assert_eq!(reflexive[0].severity, LintSeverity::Info);
+    assert!(OntologyLinter::new(&ontology)
+        .without(LintKind::ReflexiveSubClass)
+        .lint()
+        .iter()
+        .all(|w| w.kind != LintKind::ReflexiveSubClass));
+
+    // The tautologies leave the hierarchy unchanged
+    let hierarchy = |ontology: Ontology| ClassificationEngine::new(ontology).classify().unwrap();
+    let (without, with) = (hierarchy(before), hierarchy(ontology.clone()));
+    for class in ["http://example.org/Animal", "http://example.org/Dog"] {
+        assert_eq!(
+            with.hierarchy.superclasses(&iri(class), true),
+            without.hierarchy.superclasses(&iri(class), true)
+        );
+    }
+    assert_eq!(
+        with.hierarchy
+            .superclasses(&iri("http://example.org/Animal"), true),
+        [owl2_reasoner::constants::owl::thing()]
+            .into_iter()
+            .collect()
+    );
+
+    let reasoner = SimpleReasoner::new(ontology);
+    assert!(reasoner.is_consistent().unwrap());
+}
@@ -0,0 +1,55 @@
+//! Tests for querying an ontology's OWL2 profile membership directly
+
+use owl2_reasoner::profiles::{Owl2Profile, ProfileViolationType};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/pets#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn pets() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("Dog"), class("Pet")))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_simple_hierarchy_is_in_el() {
+    let ontology = pets();
+
+    assert!(ontology.is_in_profile(Owl2Profile::EL).unwrap());
+    assert!(ontology
+        .profile_violations(Owl2Profile::EL)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_superclass_disjunction_is_not_in_el() {
+    // Pet ⊑ Cat ⊔ Dog
+    let mut ontology = pets();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("Pet"),
+            ClassExpression::ObjectUnionOf(
+                vec![Arc::new(class("Cat")), Arc::new(class("Dog"))].into(),
+            ),
+        ))
+        .unwrap();
+
+    assert!(!ontology.is_in_profile(Owl2Profile::EL).unwrap());
+    let violations = ontology.profile_violations(Owl2Profile::EL).unwrap();
+    assert!(!violations.is_empty());
+    assert!(violations
+        .iter()
+        .any(|violation| violation.violation_type == ProfileViolationType::ComplexSubclassAxiom));
+}
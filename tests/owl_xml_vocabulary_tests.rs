@@ -0,0 +1,250 @@
+//! Tests for the OWL/XML axiom vocabulary and serializer round-trip
+
+use owl2_reasoner::parser::{OwlXmlParser, OwlXmlSerializer, ParserConfig};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn object_property(name: &str) -> ObjectPropertyExpression {
+    ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(iri(name))))
+}
+
+fn document(body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0"?>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#" ontologyIRI="http://example.org/family">
+    <Prefix name="owl" IRI="http://www.w3.org/2002/07/owl#"/>
+    <Prefix name="ex" IRI="{EX}"/>
+{body}
+</Ontology>"#
+    )
+}
+
+fn parse(body: &str) -> Ontology {
+    OwlXmlParser::new().parse_str(&document(body)).unwrap()
+}
+
+#[test]
+fn test_sub_object_property_of_chains() {
+    let ontology = parse(
+        r#"
+    <SubObjectPropertyOf>
+        <ObjectPropertyChain>
+            <ObjectProperty abbreviatedIRI="ex:hasParent"/>
+            <ObjectProperty abbreviatedIRI="ex:hasBrother"/>
+        </ObjectPropertyChain>
+        <ObjectProperty abbreviatedIRI="ex:hasUncle"/>
+    </SubObjectPropertyOf>
+    <SubObjectPropertyOf>
+        <ObjectProperty abbreviatedIRI="ex:hasMother"/>
+        <ObjectProperty abbreviatedIRI="ex:hasParent"/>
+    </SubObjectPropertyOf>
+"#,
+    );
+
+    let chains = ontology.sub_property_chain_axioms();
+    assert_eq!(chains.len(), 1);
+    assert_eq!(
+        chains[0].property_chain(),
+        &[object_property("hasParent"), object_property("hasBrother")]
+    );
+    assert_eq!(chains[0].super_property(), &object_property("hasUncle"));
+
+    let sub_properties = ontology.subobject_property_axioms();
+    assert_eq!(sub_properties.len(), 1);
+    assert_eq!(**sub_properties[0].sub_property(), iri("hasMother"));
+    assert_eq!(**sub_properties[0].super_property(), iri("hasParent"));
+}
+
+#[test]
+fn test_has_key() {
+    let ontology = parse(
+        r#"
+    <HasKey>
+        <Class abbreviatedIRI="ex:Person"/>
+        <ObjectProperty abbreviatedIRI="ex:hasBirthMother"/>
+        <DataProperty abbreviatedIRI="ex:hasSSN"/>
+    </HasKey>
+"#,
+    );
+
+    let keys = ontology.has_key_axioms();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(
+        keys[0].class_expression(),
+        &ClassExpression::Class(Class::new(iri("Person")))
+    );
+    assert_eq!(
+        keys[0].properties(),
+        &[Arc::new(iri("hasBirthMother")), Arc::new(iri("hasSSN"))]
+    );
+}
+
+#[test]
+fn test_qualified_cardinality() {
+    let ontology = parse(
+        r#"
+    <SubClassOf>
+        <Class abbreviatedIRI="owl:Thing"/>
+        <ObjectMaxCardinality cardinality="1">
+            <ObjectProperty abbreviatedIRI="ex:hasMother"/>
+            <Class abbreviatedIRI="ex:Woman"/>
+        </ObjectMaxCardinality>
+    </SubClassOf>
+    <SubClassOf>
+        <Class abbreviatedIRI="ex:Parent"/>
+        <ObjectMinCardinality cardinality="1">
+            <ObjectProperty abbreviatedIRI="ex:hasChild"/>
+            <Class abbreviatedIRI="ex:Person"/>
+        </ObjectMinCardinality>
+    </SubClassOf>
+    <SubClassOf>
+        <Class abbreviatedIRI="ex:Couple"/>
+        <ObjectExactCardinality cardinality="2">
+            <ObjectProperty abbreviatedIRI="ex:hasMember"/>
+        </ObjectExactCardinality>
+    </SubClassOf>
+"#,
+    );
+
+    let qualified = ontology.object_max_qualified_cardinality_axioms();
+    assert_eq!(qualified.len(), 1);
+    assert_eq!(qualified[0].cardinality(), 1);
+    assert_eq!(qualified[0].property(), &object_property("hasMother"));
+    assert_eq!(
+        qualified[0].filler(),
+        &ClassExpression::Class(Class::new(iri("Woman")))
+    );
+
+    let subclasses = ontology.subclass_axioms();
+    assert_eq!(subclasses.len(), 2);
+    assert_eq!(
+        subclasses[0].super_class(),
+        &ClassExpression::ObjectSomeValuesFrom(
+            Box::new(object_property("hasChild")),
+            Arc::new(ClassExpression::Class(Class::new(iri("Person"))))
+        )
+    );
+    assert_eq!(
+        subclasses[1].super_class(),
+        &ClassExpression::ObjectExactCardinality(2, Box::new(object_property("hasMember")))
+    );
+}
+
+#[test]
+fn test_serializer_round_trip() {
+    let ontology = parse(
+        r#"
+    <Declaration><Class IRI="http://example.org/family#Person"/></Declaration>
+    <Declaration><DataProperty IRI="http://example.org/family#hasAge"/></Declaration>
+    <SubObjectPropertyOf>
+        <ObjectPropertyChain>
+            <ObjectProperty abbreviatedIRI="ex:hasParent"/>
+            <ObjectInverseOf><ObjectProperty abbreviatedIRI="ex:hasChild"/></ObjectInverseOf>
+        </ObjectPropertyChain>
+        <ObjectProperty abbreviatedIRI="ex:hasSibling"/>
+    </SubObjectPropertyOf>
+    <TransitiveObjectProperty><ObjectProperty abbreviatedIRI="ex:hasAncestor"/></TransitiveObjectProperty>
+    <HasKey>
+        <Class abbreviatedIRI="ex:Person"/>
+        <DataProperty abbreviatedIRI="ex:hasAge"/>
+    </HasKey>
+    <SubClassOf>
+        <Class abbreviatedIRI="owl:Thing"/>
+        <DataMaxCardinality cardinality="1">
+            <DataProperty abbreviatedIRI="ex:hasAge"/>
+            <Datatype abbreviatedIRI="xsd:integer"/>
+        </DataMaxCardinality>
+    </SubClassOf>
+    <ClassAssertion>
+        <ObjectAllValuesFrom>
+            <ObjectProperty abbreviatedIRI="ex:hasChild"/>
+            <Class abbreviatedIRI="ex:Person"/>
+        </ObjectAllValuesFrom>
+        <NamedIndividual abbreviatedIRI="ex:alice"/>
+    </ClassAssertion>
+    <ObjectPropertyAssertion>
+        <ObjectInverseOf><ObjectProperty abbreviatedIRI="ex:hasChild"/></ObjectInverseOf>
+        <NamedIndividual abbreviatedIRI="ex:bob"/>
+        <NamedIndividual abbreviatedIRI="ex:alice"/>
+    </ObjectPropertyAssertion>
+    <DataPropertyAssertion>
+        <DataProperty abbreviatedIRI="ex:hasAge"/>
+        <NamedIndividual abbreviatedIRI="ex:alice"/>
+        <Literal datatypeIRI="http://www.w3.org/2001/XMLSchema#integer">42</Literal>
+    </DataPropertyAssertion>
+    <AnnotationAssertion>
+        <AnnotationProperty abbreviatedIRI="rdfs:label"/>
+        <IRI>http://example.org/family#alice</IRI>
+        <Literal xml:lang="en">Alice &amp; co</Literal>
+    </AnnotationAssertion>
+    <DifferentIndividuals>
+        <NamedIndividual abbreviatedIRI="ex:alice"/>
+        <NamedIndividual abbreviatedIRI="ex:bob"/>
+    </DifferentIndividuals>
+"#,
+    );
+    assert_eq!(ontology.axioms().len(), 9);
+
+    let xml = OwlXmlSerializer::new().serialize(&ontology).unwrap();
+    let reparsed = OwlXmlParser::new().parse_str(&xml).unwrap();
+
+    assert_eq!(reparsed.iri(), ontology.iri());
+    assert_eq!(reparsed.axioms(), ontology.axioms());
+    assert_eq!(reparsed.classes(), ontology.classes());
+    assert_eq!(reparsed.object_properties(), ontology.object_properties());
+    assert_eq!(reparsed.data_properties(), ontology.data_properties());
+    assert_eq!(reparsed.named_individuals(), ontology.named_individuals());
+
+    let label = reparsed.annotation_assertion_axioms();
+    assert_eq!(
+        label[0].value(),
+        &AnnotationValue::Literal(Literal::lang_tagged("Alice & co", "en"))
+    );
+}
+
+#[test]
+fn test_qualified_cardinality_filler_is_not_dropped() {
+    let body = r#"
+    <SubClassOf>
+        <Class abbreviatedIRI="ex:Parent"/>
+        <ObjectMaxCardinality cardinality="2">
+            <ObjectProperty abbreviatedIRI="ex:hasChild"/>
+            <Class abbreviatedIRI="ex:Person"/>
+        </ObjectMaxCardinality>
+    </SubClassOf>
+    <SubClassOf>
+        <Class abbreviatedIRI="ex:Adult"/>
+        <DataMinCardinality cardinality="2">
+            <DataProperty abbreviatedIRI="ex:hasName"/>
+            <Datatype abbreviatedIRI="xsd:string"/>
+        </DataMinCardinality>
+    </SubClassOf>
+    <SubClassOf>
+        <ObjectIntersectionOf>
+            <Class abbreviatedIRI="ex:Person"/>
+            <ObjectExactCardinality cardinality="3">
+                <ObjectProperty abbreviatedIRI="ex:hasChild"/>
+                <Class abbreviatedIRI="ex:Person"/>
+            </ObjectExactCardinality>
+        </ObjectIntersectionOf>
+        <Class abbreviatedIRI="ex:Parent"/>
+    </SubClassOf>
+"#;
+
+    // Leniently, the axioms are skipped rather than weakened to ≤2 hasChild
+    let ontology = parse(body);
+    assert!(ontology.subclass_axioms().is_empty());
+
+    let strict = OwlXmlParser::with_config(ParserConfig {
+        strict_validation: true,
+        ..Default::default()
+    });
+    let error = strict.parse_str(&document(body)).unwrap_err();
+    assert!(error.to_string().contains("Qualified"), "{}", error);
+}
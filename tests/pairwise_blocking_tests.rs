@@ -0,0 +1,235 @@
+//! Tests for pairwise blocking over ontologies with inverse roles
+
+use owl2_reasoner::axioms::property_expressions::ObjectPropertyExpression;
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::reasoning::tableaux::{
+    BlockingManager, BlockingStrategy, NodeId, ReasoningRules, TableauxGraph, TableauxReasoner,
+};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(format!("http://example.org/{}", name)))
+}
+
+fn role(name: &str) -> ObjectPropertyExpression {
+    ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(format!(
+        "http://example.org/{}",
+        name
+    ))))
+}
+
+fn inverse(name: &str) -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectPropertyExpression::ObjectInverseOf(Box::new(role(
+        name,
+    ))))
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn subclass(ontology: &mut Ontology, sub: ClassExpression, sup: ClassExpression) {
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(sub, sup))
+        .unwrap();
+}
+
+/// A chain of nodes where each carries the given concept names
+fn chain(labels: &[&[&str]], inverse_edges: bool) -> (TableauxGraph, Vec<NodeId>) {
+    let property = IRI::new("http://example.org/R").unwrap();
+    let mut graph = TableauxGraph::new();
+    let mut nodes: Vec<NodeId> = Vec::new();
+    for (i, names) in labels.iter().enumerate() {
+        let node = if i == 0 {
+            graph.get_root()
+        } else {
+            graph.add_node()
+        };
+        for name in *names {
+            graph.add_concept(node, class(name));
+        }
+        if let Some(&parent) = nodes.last() {
+            if inverse_edges {
+                graph.add_edge(node, &property, parent);
+            } else {
+                graph.add_edge(parent, &property, node);
+            }
+        }
+        nodes.push(node);
+    }
+    (graph, nodes)
+}
+
+#[test]
+fn test_pairwise_blocking_needs_matching_parents() {
+    let (graph, nodes) = chain(&[&["A"], &["B"], &["B"], &["B"]], false);
+
+    // Equal concepts alone are enough for equality blocking
+    let equality = BlockingManager::new(BlockingStrategy::Equality);
+    assert!(equality.should_block_node(nodes[2], &graph));
+
+    // The parents of nodes 1 and 2 differ, so only node 3 is pairwise blocked
+    let pairwise = BlockingManager::new(BlockingStrategy::Pairwise);
+    assert!(!pairwise.should_block_node(nodes[1], &graph));
+    assert!(!pairwise.should_block_node(nodes[2], &graph));
+    let constraint = pairwise.detect_blocking(nodes[3], &graph).unwrap();
+    assert!(constraint.is_pairwise());
+    assert_eq!(constraint.blocking_node, nodes[2]);
+}
+
+#[test]
+fn test_pairwise_blocking_follows_inverse_edges() {
+    let (graph, nodes) = chain(&[&["A"], &["B"], &["B"], &["B"]], true);
+
+    let pairwise = BlockingManager::new(BlockingStrategy::Pairwise);
+    assert!(!pairwise.should_block_node(nodes[2], &graph));
+    assert_eq!(
+        pairwise
+            .detect_blocking(nodes[3], &graph)
+            .map(|c| c.blocking_node),
+        Some(nodes[2])
+    );
+
+    // Edges of different directions do not match, so node 2 skips its parent
+    // and is blocked by node 0, whose parent edge also points forward
+    let property = IRI::new("http://example.org/R").unwrap();
+    let mut mixed = TableauxGraph::new();
+    let root = mixed.get_root();
+    let ids: Vec<NodeId> = (0..3).map(|_| mixed.add_node()).collect();
+    mixed.add_concept(root, class("B"));
+    for &id in &ids {
+        mixed.add_concept(id, class("B"));
+    }
+    mixed.add_edge(root, &property, ids[0]);
+    mixed.add_edge(ids[1], &property, ids[0]);
+    mixed.add_edge(ids[1], &property, ids[2]);
+    assert!(!pairwise.should_block_node(ids[1], &mixed));
+    assert_eq!(
+        pairwise
+            .detect_blocking(ids[2], &mixed)
+            .map(|c| c.blocking_node),
+        Some(ids[0])
+    );
+}
+
+#[test]
+fn test_pairwise_blocking_sees_removed_and_merged_edges() {
+    let property = IRI::new("http://example.org/R").unwrap();
+    let pairwise = BlockingManager::new(BlockingStrategy::Pairwise);
+
+    // Without the edge to its parent, node 3 is no longer in the tree
+    let (mut graph, nodes) = chain(&[&["A"], &["B"], &["B"], &["B"]], false);
+    graph.edges.pop_edge(nodes[2], &property, nodes[3]);
+    assert!(graph.edges.get_adjacent(nodes[3]).is_empty());
+    assert!(pairwise.detect_blocking(nodes[3], &graph).is_none());
+
+    let (mut graph, nodes) = chain(&[&["A"], &["B"], &["B"], &["B"]], false);
+    graph.edges.retain_edges(|(from, _, _)| *from != nodes[2]);
+    assert!(pairwise.detect_blocking(nodes[3], &graph).is_none());
+
+    // Merging node 2 into node 1 moves its child under node 1
+    let (mut graph, nodes) = chain(&[&["A"], &["B"], &["B"], &["B"]], false);
+    graph.merge_nodes(nodes[2], nodes[1]);
+    assert_eq!(
+        graph.edges.get_adjacent(nodes[3]),
+        &[(nodes[1], property, false)]
+    );
+}
+
+#[test]
+fn test_inverse_roles_select_pairwise_blocking() {
+    let mut ontology = Ontology::new();
+    subclass(
+        &mut ontology,
+        class("B"),
+        ClassExpression::ObjectSomeValuesFrom(Box::new(role("R")), Arc::new(class("B"))),
+    );
+    assert!(!ReasoningRules::new(&ontology).uses_inverse_roles());
+
+    subclass(
+        &mut ontology,
+        class("C"),
+        ClassExpression::ObjectAllValuesFrom(inverse("R"), Arc::new(class("B"))),
+    );
+    assert!(ReasoningRules::new(&ontology).uses_inverse_roles());
+}
+
+/// B ⊑ ∃R⁻.B ⊓ ≤1 R⁻ needs an infinite chain of predecessors
+#[test]
+fn test_cyclic_inverse_definition_terminates() {
+    let mut ontology = Ontology::new();
+    subclass(
+        &mut ontology,
+        class("B"),
+        and(vec![
+            ClassExpression::ObjectSomeValuesFrom(inverse("R"), Arc::new(class("B"))),
+            ClassExpression::ObjectMaxCardinality(1, inverse("R")),
+        ]),
+    );
+
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(reasoner
+        .is_class_expression_satisfiable(&class("B"))
+        .unwrap());
+    // The root and its predecessor differ, so the chain stops one node later
+    // than equality blocking would, once a node and its parent both repeat
+    assert_eq!(reasoner.last_reasoning_stats().total_nodes, 3);
+}
+
+/// B ⊑ ∃R⁻.(B ⊓ ∀R.¬B): the predecessor forces its successor out of B
+#[test]
+fn test_inverse_successor_constrains_its_predecessor() {
+    let mut ontology = Ontology::new();
+    subclass(
+        &mut ontology,
+        class("B"),
+        ClassExpression::ObjectSomeValuesFrom(
+            inverse("R"),
+            Arc::new(and(vec![
+                class("B"),
+                ClassExpression::ObjectAllValuesFrom(
+                    Box::new(role("R")),
+                    Arc::new(ClassExpression::ObjectComplementOf(Arc::new(class("B")))),
+                ),
+            ])),
+        ),
+    );
+
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(!reasoner
+        .is_class_expression_satisfiable(&class("B"))
+        .unwrap());
+}
+
+/// B ⊑ ∃R⁻.(B ⊓ C) ⊓ ∃R⁻.(B ⊓ D) ⊓ ≤1 R⁻ with C and D disjoint
+#[test]
+fn test_number_restriction_on_inverse_role() {
+    let mut ontology = Ontology::new();
+    subclass(
+        &mut ontology,
+        class("B"),
+        and(vec![
+            ClassExpression::ObjectSomeValuesFrom(
+                inverse("R"),
+                Arc::new(and(vec![class("B"), class("C")])),
+            ),
+            ClassExpression::ObjectSomeValuesFrom(
+                inverse("R"),
+                Arc::new(and(vec![class("B"), class("D")])),
+            ),
+            ClassExpression::ObjectMaxCardinality(1, inverse("R")),
+        ]),
+    );
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(IRI::new("http://example.org/C").unwrap()),
+            Arc::new(IRI::new("http://example.org/D").unwrap()),
+        ]))
+        .unwrap();
+
+    let reasoner = TableauxReasoner::new(ontology);
+    assert!(!reasoner
+        .is_class_expression_satisfiable(&class("B"))
+        .unwrap());
+}
@@ -0,0 +1,67 @@
+//! Tests for parsing N-Triples lines in parallel
+
+use owl2_reasoner::parser::{NtriplesParser, OntologyParser, ParserConfig};
+
+const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+const SUBCLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+const OWL_CLASS: &str = "http://www.w3.org/2002/07/owl#Class";
+const RDFS_LABEL: &str = "http://www.w3.org/2000/01/rdf-schema#label";
+
+fn document(classes: usize) -> String {
+    let mut content = String::from("# generated\n\n");
+    for i in 0..classes {
+        content.push_str(&format!(
+            "<http://example.org/C{i}> <{RDF_TYPE}> <{OWL_CLASS}> .\n"
+        ));
+        if i > 0 {
+            content.push_str(&format!(
+                "<http://example.org/C{i}> <{SUBCLASS_OF}> <http://example.org/C{}> .\n",
+                i / 2
+            ));
+        }
+        content.push_str(&format!(
+            "<http://example.org/i{i}> <{RDF_TYPE}> <http://example.org/C{i}> .\n"
+        ));
+        content.push_str(&format!(
+            "<http://example.org/C{i}> <{RDFS_LABEL}> \"class {i}\"@en .\n"
+        ));
+    }
+    content
+}
+
+fn parallel_parser() -> NtriplesParser {
+    NtriplesParser::with_config(ParserConfig {
+        parallel_ntriples: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn test_parallel_parse_matches_sequential_parse() {
+    let content = document(2_000);
+
+    let sequential = NtriplesParser::new().parse_str(&content).unwrap();
+    let parallel = parallel_parser().parse_str(&content).unwrap();
+
+    assert!(sequential.classes().len() >= 2_000);
+    assert_eq!(parallel.classes().len(), sequential.classes().len());
+    assert_eq!(
+        parallel.named_individuals().len(),
+        sequential.named_individuals().len()
+    );
+    assert_eq!(parallel.axioms(), sequential.axioms());
+}
+
+#[test]
+fn test_parallel_parse_reports_the_failing_line() {
+    let mut content = document(50);
+    content.push_str("<http://example.org/broken> <http://example.org/p>\n");
+    content.push_str(&document(50));
+
+    let sequential = NtriplesParser::new().parse_str(&content).unwrap_err();
+    let parallel = parallel_parser().parse_str(&content).unwrap_err();
+
+    // Two header lines and 199 generated ones precede the broken line
+    assert!(parallel.to_string().contains("line 202"), "{}", parallel);
+    assert_eq!(parallel.to_string(), sequential.to_string());
+}
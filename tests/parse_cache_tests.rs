@@ -0,0 +1,64 @@
+//! Tests for caching parsed ontologies by their source document
+
+use owl2_reasoner::cache_manager::ParseCache;
+use owl2_reasoner::parser::{Format, ParserConfig};
+use std::sync::Arc;
+
+const TURTLE: &str = r#"
+@prefix : <http://example.org/> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+:Dog a owl:Class ; rdfs:subClassOf :Animal .
+:Animal a owl:Class .
+:rex a :Dog ; :age 3 .
+"#;
+
+#[test]
+fn test_second_parse_of_same_content_hits_cache() {
+    let cache = ParseCache::default();
+    let config = ParserConfig::default();
+
+    let first = cache.parse(TURTLE, Format::Turtle, &config).unwrap();
+    let second = cache.parse(TURTLE, Format::Turtle, &config).unwrap();
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses), (1, 1));
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(cache.len().unwrap(), 1);
+
+    // The cached ontology is the one a parser builds
+    let fresh = Format::Turtle
+        .parser_with_config(config)
+        .parse_str(TURTLE)
+        .unwrap();
+    assert_eq!(second.axioms(), fresh.axioms());
+    assert_eq!(second.classes(), fresh.classes());
+}
+
+#[test]
+fn test_other_config_or_content_misses_cache() {
+    let cache = ParseCache::default();
+    let config = ParserConfig::default();
+    let plain_literals = ParserConfig {
+        infer_literal_datatypes: false,
+        ..ParserConfig::default()
+    };
+
+    cache.parse(TURTLE, Format::Turtle, &config).unwrap();
+    cache
+        .parse(TURTLE, Format::Turtle, &plain_literals)
+        .unwrap();
+    let edited = TURTLE.replace(":age 3", ":age 4");
+    cache.parse(&edited, Format::Turtle, &config).unwrap();
+    let stats = cache.stats();
+    assert_eq!((stats.hits, stats.misses), (0, 3));
+
+    // Equal configurations built separately share entries
+    cache
+        .parse(TURTLE, Format::Turtle, &ParserConfig::default())
+        .unwrap();
+    assert_eq!(cache.stats().hits, 1);
+
+    cache.clear().unwrap();
+    assert!(cache.is_empty().unwrap());
+}
@@ -0,0 +1,143 @@
+//! Tests for bounding the size of parsed ontologies
+
+use owl2_reasoner::parser::{
+    NtriplesParser, OntologyParser, OwlFunctionalSyntaxParser, ParserConfig, TurtleParser,
+};
+use owl2_reasoner::*;
+
+/// A Turtle document declaring `classes` classes in a subclass chain
+fn chain_turtle(classes: usize) -> String {
+    let mut content = String::from(
+        "@prefix ex: <http://example.org/> .\n\
+         @prefix owl: <http://www.w3.org/2002/07/owl#> .\n\
+         @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n",
+    );
+    for i in 0..classes {
+        content.push_str(&format!("ex:C{} a owl:Class .\n", i));
+        if i > 0 {
+            content.push_str(&format!("ex:C{} rdfs:subClassOf ex:C{} .\n", i, i - 1));
+        }
+    }
+    content
+}
+
+fn assert_limit_error(result: OwlResult<Ontology>, resource: &str, expected: usize) {
+    match result {
+        Err(OwlError::ResourceLimitExceeded {
+            resource_type,
+            limit,
+            ..
+        }) => {
+            assert_eq!(resource_type, resource);
+            assert_eq!(limit, expected);
+        }
+        other => panic!("expected a {} limit error, got {:?}", resource, other),
+    }
+}
+
+#[test]
+fn test_turtle_axiom_limit() {
+    let config = ParserConfig {
+        max_axioms: 5,
+        ..Default::default()
+    };
+
+    // Nine subclass axioms would be produced
+    let result = TurtleParser::with_config(config.clone()).parse_str(&chain_turtle(10));
+    assert_limit_error(result, "axioms", 5);
+
+    // A document within the limit still parses
+    let ontology = TurtleParser::with_config(config)
+        .parse_str(&chain_turtle(4))
+        .unwrap();
+    assert_eq!(ontology.axiom_count(), 3);
+}
+
+#[test]
+fn test_turtle_entity_limit() {
+    let config = ParserConfig {
+        max_entities: 8,
+        ..Default::default()
+    };
+
+    let result = TurtleParser::with_config(config).parse_str(&chain_turtle(10));
+    assert_limit_error(result, "entities", 8);
+}
+
+#[test]
+fn test_ntriples_axiom_limit() {
+    let content: String = (1..=20)
+        .map(|i| {
+            format!(
+                "<http://example.org/C{}> <http://www.w3.org/2000/01/rdf-schema#subClassOf> \
+                 <http://example.org/C{}> .\n",
+                i,
+                i - 1
+            )
+        })
+        .collect();
+    let config = ParserConfig {
+        max_axioms: 10,
+        ..Default::default()
+    };
+
+    let result = NtriplesParser::with_config(config).parse_str(&content);
+    assert_limit_error(result, "axioms", 10);
+}
+
+#[test]
+fn test_functional_syntax_axiom_limit() {
+    let mut content = String::from("Ontology(<http://example.org/o>\n");
+    for i in 1..=10 {
+        content.push_str(&format!(
+            "SubClassOf(<http://example.org/C{}> <http://example.org/C{}>)\n",
+            i,
+            i - 1
+        ));
+    }
+    content.push(')');
+    let config = ParserConfig {
+        max_axioms: 3,
+        ..Default::default()
+    };
+
+    let result = OwlFunctionalSyntaxParser::with_config(config).parse_str(&content);
+    assert_limit_error(result, "axioms", 3);
+}
+
+#[test]
+fn test_default_config_is_unlimited() {
+    let ontology = TurtleParser::new().parse_str(&chain_turtle(50)).unwrap();
+    assert_eq!(ontology.axiom_count(), 49);
+    assert_eq!(ontology.size_limits(), SizeLimits::default());
+}
+
+#[test]
+fn test_rejected_insertion_leaves_ontology_unchanged() {
+    let mut ontology = Ontology::new();
+    ontology.set_size_limits(SizeLimits {
+        max_axioms: Some(1),
+        max_entities: Some(2),
+    });
+
+    let a = Class::new("http://example.org/A");
+    let b = Class::new("http://example.org/B");
+    ontology.add_class(a.clone()).unwrap();
+    ontology.add_class(b.clone()).unwrap();
+    assert!(ontology
+        .add_class(Class::new("http://example.org/C"))
+        .is_err());
+    // Re-adding a known entity does not count against the limit
+    assert!(ontology.add_class(a.clone()).is_ok());
+    assert_eq!(ontology.entity_count(), 2);
+
+    let axiom = |sub: &Class, sup: &Class| {
+        axioms::Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+            ClassExpression::Class(sub.clone()),
+            ClassExpression::Class(sup.clone()),
+        )))
+    };
+    ontology.add_axiom(axiom(&a, &b)).unwrap();
+    assert!(ontology.add_axiom(axiom(&b, &a)).is_err());
+    assert_eq!(ontology.axiom_count(), 1);
+}
@@ -0,0 +1,118 @@
+//! Tests for data ranges and datatypes in OWL2 profile validation
+
+use owl2_reasoner::axioms::class_expressions::{DataRange, FacetRestriction};
+use owl2_reasoner::axioms::property_expressions::DataPropertyExpression;
+use owl2_reasoner::profiles::{
+    Owl2Profile, Owl2ProfileValidator, ProfileValidator, ProfileViolationType,
+};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/{}", name)).unwrap()
+}
+
+fn integer() -> IRI {
+    IRI::new(format!("{}integer", XSD)).unwrap()
+}
+
+/// `sub ⊑ ∃age.range`
+fn age_restriction(sub: &str, range: DataRange) -> SubClassOfAxiom {
+    SubClassOfAxiom::new(
+        ClassExpression::Class(Class::new(iri(sub))),
+        ClassExpression::DataSomeValuesFrom(
+            Box::new(DataPropertyExpression::DataProperty(DataProperty::new(
+                iri("age"),
+            ))),
+            Box::new(range),
+        ),
+    )
+}
+
+fn adults() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_subclass_axiom(age_restriction("Adult", DataRange::Datatype(integer())))
+        .unwrap();
+    ontology
+}
+
+fn violations(ontology: Ontology, profile: Owl2Profile) -> Vec<ProfileViolationType> {
+    let result = Owl2ProfileValidator::new(Arc::new(ontology))
+        .unwrap()
+        .validate_profile(profile)
+        .unwrap();
+    assert_eq!(result.is_valid, result.violations.is_empty());
+    result
+        .violations
+        .into_iter()
+        .map(|violation| violation.violation_type)
+        .collect()
+}
+
+#[test]
+fn test_data_one_of_takes_ontology_out_of_el() {
+    assert!(violations(adults(), Owl2Profile::EL).is_empty());
+
+    let mut teens = adults();
+    teens
+        .add_subclass_axiom(age_restriction(
+            "Teen",
+            DataRange::DataOneOf(vec![
+                Literal::typed("13", integer()),
+                Literal::typed("14", integer()),
+            ]),
+        ))
+        .unwrap();
+    assert_eq!(
+        violations(teens, Owl2Profile::EL),
+        vec![ProfileViolationType::DataOneOf]
+    );
+
+    // An enumeration of a single literal is allowed in EL
+    let mut thirteen = adults();
+    thirteen
+        .add_subclass_axiom(age_restriction(
+            "Thirteen",
+            DataRange::DataOneOf(vec![Literal::typed("13", integer())]),
+        ))
+        .unwrap();
+    assert!(violations(thirteen, Owl2Profile::EL).is_empty());
+}
+
+#[test]
+fn test_facets_and_datatypes_outside_the_profiles() {
+    let mut minors = Ontology::new();
+    minors
+        .add_subclass_axiom(age_restriction(
+            "Minor",
+            DataRange::DatatypeRestriction(
+                integer(),
+                vec![FacetRestriction::new(
+                    IRI::new(format!("{}maxExclusive", XSD)).unwrap(),
+                    Literal::typed("18", integer()),
+                )],
+            ),
+        ))
+        .unwrap();
+    for profile in [Owl2Profile::EL, Owl2Profile::QL, Owl2Profile::RL] {
+        assert!(
+            violations(minors.clone(), profile).contains(&ProfileViolationType::ComplexDataRanges)
+        );
+    }
+
+    // xsd:double is in the RL datatype map but not in the EL one
+    let mut heights = Ontology::new();
+    heights
+        .add_axiom(Axiom::DataPropertyRange(Box::new(
+            DataPropertyRangeAxiom::new(iri("height"), IRI::new(format!("{}double", XSD)).unwrap()),
+        )))
+        .unwrap();
+    assert_eq!(
+        violations(heights.clone(), Owl2Profile::EL),
+        vec![ProfileViolationType::DataPropertyRanges]
+    );
+    assert!(violations(heights, Owl2Profile::RL).is_empty());
+}
@@ -0,0 +1,127 @@
+//! Tests for rejecting irregular property chains before reasoning
+
+use owl2_reasoner::reasoning::RegularityChecker;
+use owl2_reasoner::*;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn property(name: &str) -> ObjectPropertyExpression {
+    ObjectProperty::new(iri(name)).into()
+}
+
+fn chain(ontology: &mut Ontology, chain: &[&str], super_property: &str) {
+    ontology
+        .add_axiom(Axiom::SubPropertyChainOf(Box::new(
+            SubPropertyChainOfAxiom::new(
+                chain.iter().map(|name| property(name)).collect(),
+                property(super_property),
+            ),
+        )))
+        .unwrap();
+}
+
+fn subproperty(ontology: &mut Ontology, sub: &str, sup: &str) {
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri(sub)), Arc::new(iri(sup))),
+        )))
+        .unwrap();
+}
+
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("hasParent")),
+            Arc::new(iri("bob")),
+        ))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("bob")),
+            Arc::new(iri("hasBrother")),
+            Arc::new(iri("carl")),
+        ))
+        .unwrap();
+    ontology
+}
+
+/// Check consistency on another thread so that a hang fails the test
+fn consistency(ontology: Ontology) -> OwlResult<bool> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(OwlReasoner::new(ontology).is_consistent());
+    });
+    receiver
+        .recv_timeout(Duration::from_secs(30))
+        .expect("consistency check did not terminate")
+}
+
+#[test]
+fn test_mutually_recursive_chains_are_rejected() {
+    let mut ontology = family();
+    chain(&mut ontology, &["hasParent", "hasBrother"], "hasBrother");
+    chain(&mut ontology, &["hasBrother", "hasParent"], "hasParent");
+
+    let error = consistency(ontology).unwrap_err();
+    assert!(matches!(error, OwlError::OwlViolation(_)));
+    let message = error.to_string();
+    assert!(message.contains("Irregular property chains"), "{}", message);
+    assert!(message.contains("hasParent"), "{}", message);
+    assert!(message.contains("hasBrother"), "{}", message);
+}
+
+#[test]
+fn test_cycle_through_the_property_hierarchy_is_rejected() {
+    let mut ontology = family();
+    chain(&mut ontology, &["hasParent", "hasBrother"], "hasUncle");
+    subproperty(&mut ontology, "hasUncle", "hasRelative");
+    subproperty(&mut ontology, "hasRelative", "hasParent");
+
+    let checker = RegularityChecker::new(&ontology);
+    assert!(!checker.is_regular());
+    let message = checker.violation().unwrap();
+    assert!(message.contains("hasRelative"), "{}", message);
+    assert!(consistency(ontology).is_err());
+}
+
+#[test]
+fn test_property_in_the_middle_of_its_own_chain_is_rejected() {
+    let mut ontology = family();
+    chain(
+        &mut ontology,
+        &["hasBrother", "hasParent", "hasBrother"],
+        "hasParent",
+    );
+
+    let message = RegularityChecker::new(&ontology).violation().unwrap();
+    assert!(message.contains("precede itself"), "{}", message);
+}
+
+#[test]
+fn test_regular_chains_are_accepted() {
+    let mut ontology = family();
+    chain(&mut ontology, &["hasParent", "hasBrother"], "hasUncle");
+    subproperty(&mut ontology, "hasUncle", "hasRelative");
+    // Transitivity and chains recursive on the left or right are regular
+    chain(
+        &mut ontology,
+        &["hasAncestor", "hasAncestor"],
+        "hasAncestor",
+    );
+    chain(&mut ontology, &["hasAncestor", "hasParent"], "hasAncestor");
+    chain(&mut ontology, &["hasParent", "hasRelative"], "hasRelative");
+    subproperty(&mut ontology, "hasParent", "hasAncestor");
+
+    assert!(RegularityChecker::new(&ontology).check().is_ok());
+    assert!(consistency(ontology).unwrap());
+}
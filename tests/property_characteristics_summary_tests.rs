@@ -0,0 +1,67 @@
+//! Tests for the summary of an object property's characteristics
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(format!("{}{}", EX, name)).unwrap())
+}
+
+#[test]
+fn test_summary_reflects_every_declared_characteristic() {
+    let mut ontology = Ontology::new();
+    let property = iri("hasRelative");
+    ontology
+        .add_object_property(ObjectProperty::new((*property).clone()))
+        .unwrap();
+    for axiom in [
+        Axiom::TransitiveProperty(Box::new(TransitivePropertyAxiom::new(property.clone()))),
+        Axiom::SymmetricProperty(Box::new(SymmetricPropertyAxiom::new(property.clone()))),
+        Axiom::IrreflexiveProperty(Box::new(IrreflexivePropertyAxiom::new(property.clone()))),
+        // A characteristic of another property does not leak into the summary
+        Axiom::FunctionalProperty(Box::new(FunctionalPropertyAxiom::new(iri("hasMother")))),
+    ] {
+        ontology.add_axiom(axiom).unwrap();
+    }
+
+    let characteristics = ontology.property_characteristics(&property);
+    assert_eq!(
+        characteristics,
+        PropertyCharacteristics {
+            transitive: true,
+            symmetric: true,
+            irreflexive: true,
+            ..Default::default()
+        }
+    );
+    assert!(characteristics.has(ObjectPropertyCharacteristic::Symmetric));
+    assert!(!characteristics.has(ObjectPropertyCharacteristic::Functional));
+    assert!(
+        ontology
+            .property_characteristics(&iri("hasMother"))
+            .functional
+    );
+    assert!(ontology
+        .property_characteristics(&iri("unknown"))
+        .is_empty());
+}
+
+#[test]
+fn test_summary_includes_characteristics_of_the_property_entity() {
+    let mut ontology = Ontology::new();
+    let mut property = ObjectProperty::new((*iri("hasSpouse")).clone());
+    property.add_characteristic(ObjectPropertyCharacteristic::Functional);
+    ontology.add_object_property(property).unwrap();
+    ontology
+        .add_axiom(Axiom::InverseFunctionalProperty(Box::new(
+            InverseFunctionalPropertyAxiom::new(iri("hasSpouse")),
+        )))
+        .unwrap();
+
+    let characteristics = ontology.property_characteristics(&iri("hasSpouse"));
+    assert!(characteristics.functional);
+    assert!(characteristics.inverse_functional);
+    assert!(!characteristics.transitive);
+}
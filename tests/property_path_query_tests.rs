@@ -0,0 +1,172 @@
+//! Tests for querying with SPARQL-style property paths
+
+use owl2_reasoner::reasoning::{
+    PatternTerm, PropertyPath, QueryConfig, QueryEngine, QueryPattern, QueryResult, QueryValue,
+};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn property(name: &str) -> PropertyPath {
+    PropertyPath::Property(iri(name))
+}
+
+fn assert_relation(ontology: &mut Ontology, subject: &str, property: &str, object: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(subject)),
+            Arc::new(iri(property)),
+            Arc::new(iri(object)),
+        ))
+        .unwrap();
+}
+
+/// alice → bob → carol → dave along hasParent, and erin is alice's guardian
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    assert_relation(&mut ontology, "alice", "hasParent", "bob");
+    assert_relation(&mut ontology, "bob", "hasParent", "carol");
+    assert_relation(&mut ontology, "carol", "hasParent", "dave");
+    assert_relation(&mut ontology, "alice", "hasGuardian", "erin");
+    ontology
+}
+
+fn query(ontology: Ontology, subject: PatternTerm, path: PropertyPath) -> QueryResult {
+    QueryEngine::new(ontology)
+        .execute(&QueryPattern::Path {
+            subject,
+            path,
+            object: PatternTerm::Variable("x".to_string()),
+        })
+        .unwrap()
+}
+
+fn values(result: &QueryResult, variable: &str) -> HashSet<IRI> {
+    result
+        .iter()
+        .filter_map(|binding| match binding.get_value(variable) {
+            Some(QueryValue::IRI(iri)) => Some(iri.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn names(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+#[test]
+fn test_one_or_more_retrieves_all_ancestors() {
+    // hasAncestor := hasParent+
+    let has_ancestor = PropertyPath::OneOrMore(Box::new(property("hasParent")));
+    let result = query(
+        family(),
+        PatternTerm::IRI(iri("alice")),
+        has_ancestor.clone(),
+    );
+    assert_eq!(result.variables, vec!["x".to_string()]);
+    assert_eq!(values(&result, "x"), names(&["bob", "carol", "dave"]));
+
+    // Zero steps also reach the individual itself
+    let result = query(
+        family(),
+        PatternTerm::IRI(iri("alice")),
+        PropertyPath::ZeroOrMore(Box::new(property("hasParent"))),
+    );
+    assert_eq!(
+        values(&result, "x"),
+        names(&["alice", "bob", "carol", "dave"])
+    );
+
+    // Followed backwards the path yields the descendants
+    let result = query(
+        family(),
+        PatternTerm::IRI(iri("carol")),
+        PropertyPath::Inverse(Box::new(has_ancestor)),
+    );
+    assert_eq!(values(&result, "x"), names(&["alice", "bob"]));
+}
+
+#[test]
+fn test_alternative_and_sequence_paths() {
+    let result = query(
+        family(),
+        PatternTerm::IRI(iri("alice")),
+        PropertyPath::Alt(vec![property("hasParent"), property("hasGuardian")]),
+    );
+    assert_eq!(values(&result, "x"), names(&["bob", "erin"]));
+
+    // Grandparents
+    let result = query(
+        family(),
+        PatternTerm::IRI(iri("alice")),
+        PropertyPath::Seq(vec![property("hasParent"), property("hasParent")]),
+    );
+    assert_eq!(values(&result, "x"), names(&["carol"]));
+}
+
+#[test]
+fn test_path_with_unbound_subject() {
+    let engine = QueryEngine::new(family());
+    let has_ancestor = PropertyPath::OneOrMore(Box::new(property("hasParent")));
+
+    let result = engine
+        .execute(&QueryPattern::Path {
+            subject: PatternTerm::Variable("descendant".to_string()),
+            path: has_ancestor.clone(),
+            object: PatternTerm::IRI(iri("dave")),
+        })
+        .unwrap();
+    assert_eq!(
+        values(&result, "descendant"),
+        names(&["alice", "bob", "carol"])
+    );
+
+    let result = engine
+        .execute(&QueryPattern::Path {
+            subject: PatternTerm::Variable("descendant".to_string()),
+            path: has_ancestor,
+            object: PatternTerm::Variable("ancestor".to_string()),
+        })
+        .unwrap();
+    // Three pairs from alice, two from bob and one from carol
+    assert_eq!(result.len(), 6);
+}
+
+#[test]
+fn test_path_follows_inferred_edges() {
+    let mut ontology = family();
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri("hasMother")), Arc::new(iri("hasParent"))),
+        )))
+        .unwrap();
+    assert_relation(&mut ontology, "dave", "hasMother", "frida");
+    let has_ancestor = PropertyPath::OneOrMore(Box::new(property("hasParent")));
+
+    let result = query(
+        ontology.clone(),
+        PatternTerm::IRI(iri("alice")),
+        has_ancestor.clone(),
+    );
+    assert_eq!(
+        values(&result, "x"),
+        names(&["bob", "carol", "dave", "frida"])
+    );
+
+    // Without reasoning only the asserted edges are followed
+    let result = QueryEngine::with_config(ontology, QueryConfig::new().with_reasoning(false))
+        .execute(&QueryPattern::Path {
+            subject: PatternTerm::IRI(iri("alice")),
+            path: has_ancestor,
+            object: PatternTerm::Variable("x".to_string()),
+        })
+        .unwrap();
+    assert_eq!(values(&result, "x"), names(&["bob", "carol", "dave"]));
+}
@@ -0,0 +1,124 @@
+//! Tests for retrieving asserted and inferred property values of an individual
+
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn property(name: &str) -> ObjectPropertyExpression {
+    ObjectProperty::new(iri(name)).into()
+}
+
+fn assert_relation(ontology: &mut Ontology, subject: &str, property: &str, object: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(subject)),
+            Arc::new(iri(property)),
+            Arc::new(iri(object)),
+        ))
+        .unwrap();
+}
+
+fn values(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+#[test]
+fn test_values_of_sub_property_are_included() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri("hasSon")), Arc::new(iri("hasChild"))),
+        )))
+        .unwrap();
+    assert_relation(&mut ontology, "alice", "hasChild", "carol");
+    assert_relation(&mut ontology, "alice", "hasSon", "bob");
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    assert_eq!(
+        reasoner
+            .property_values(&iri("alice"), &property("hasChild"))
+            .unwrap(),
+        values(&["bob", "carol"])
+    );
+    assert_eq!(
+        reasoner
+            .property_values(&iri("alice"), &property("hasSon"))
+            .unwrap(),
+        values(&["bob"])
+    );
+}
+
+#[test]
+fn test_values_through_inverses_and_chains() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::InverseObjectProperties(Box::new(
+            InverseObjectPropertiesAxiom::new(property("hasChild"), property("hasParent")),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::SubPropertyChainOf(Box::new(
+            SubPropertyChainOfAxiom::new(
+                vec![property("hasParent"), property("hasParent")],
+                property("hasGrandparent"),
+            ),
+        )))
+        .unwrap();
+    assert_relation(&mut ontology, "alice", "hasChild", "bob");
+    assert_relation(&mut ontology, "bob", "hasChild", "dave");
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    assert_eq!(
+        reasoner
+            .property_values(&iri("dave"), &property("hasParent"))
+            .unwrap(),
+        values(&["bob"])
+    );
+    assert_eq!(
+        reasoner
+            .property_values(&iri("dave"), &property("hasGrandparent"))
+            .unwrap(),
+        values(&["alice"])
+    );
+    assert_eq!(
+        reasoner
+            .property_values(&iri("alice"), &property("hasGrandparent").inverse())
+            .unwrap(),
+        values(&["dave"])
+    );
+}
+
+#[test]
+fn test_data_values_of_sub_property_are_included() {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::SubDataProperty(Box::new(SubDataPropertyAxiom::new(
+            Arc::new(iri("hasNickname")),
+            Arc::new(iri("hasName")),
+        ))))
+        .unwrap();
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            Arc::new(iri("hasNickname")),
+            Literal::simple("Ali"),
+        ))
+        .unwrap();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    let name = DataPropertyExpression::DataProperty(DataProperty::new(iri("hasName")));
+    assert_eq!(
+        reasoner.data_property_values(&iri("alice"), &name).unwrap(),
+        HashSet::from([Literal::simple("Ali")])
+    );
+    assert!(reasoner
+        .data_property_values(&iri("bob"), &name)
+        .unwrap()
+        .is_empty());
+}
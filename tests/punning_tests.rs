@@ -0,0 +1,118 @@
+//! Tests for reasoning with punned IRIs used as both class and individual
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/zoo#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn typed(ontology: &mut Ontology, individual: &str, class_name: &str) {
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri(individual)),
+            class(class_name),
+        ))
+        .unwrap();
+}
+
+/// Eagle is a class of birds and, as an individual, an instance of Species
+fn zoo() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Eagle", "Bird", "Species"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_named_individual(NamedIndividual::new(iri("Eagle")))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("Eagle"), class("Bird")))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Bird")),
+            Arc::new(iri("Species")),
+        ]))
+        .unwrap();
+    typed(&mut ontology, "harry", "Eagle");
+    typed(&mut ontology, "Eagle", "Species");
+    ontology
+}
+
+#[test]
+fn test_punned_kinds_are_reported() {
+    let ontology = zoo();
+
+    let eagle = ontology.is_punned(&iri("Eagle"));
+    assert!(eagle.class && eagle.individual);
+    assert!(!eagle.object_property && !eagle.data_property);
+    assert!(eagle.is_punned());
+    assert_eq!(eagle.count(), 2);
+
+    let harry = ontology.is_punned(&iri("harry"));
+    assert!(harry.individual && !harry.class);
+    assert!(!harry.is_punned());
+    assert!(!ontology.is_punned(&iri("Species")).is_punned());
+    assert_eq!(ontology.is_punned(&iri("unknown")).count(), 0);
+}
+
+#[test]
+fn test_punning_is_found_from_usage() {
+    let mut ontology = Ontology::new();
+    typed(&mut ontology, "Eagle", "Species");
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("harry")),
+            Arc::new(iri("Eagle")),
+            Arc::new(iri("sally")),
+        ))
+        .unwrap();
+
+    let kinds = ontology.is_punned(&iri("Eagle"));
+    assert!(kinds.individual && kinds.object_property && !kinds.class);
+    assert!(ontology.is_punned(&iri("Species")).class);
+}
+
+#[test]
+fn test_class_and_metaclass_assertions_do_not_interfere() {
+    let mut reasoner = OwlReasoner::new(zoo());
+    assert!(reasoner.is_consistent().unwrap());
+
+    // Eagle the individual is a species; Eagle the class holds the birds
+    assert_eq!(
+        reasoner.get_instances(&iri("Species")).unwrap(),
+        vec![Arc::new(iri("Eagle"))]
+    );
+    assert_eq!(
+        reasoner.get_instances(&iri("Eagle")).unwrap(),
+        vec![Arc::new(iri("harry"))]
+    );
+    assert!(reasoner
+        .is_instance_of(&iri("Eagle"), &iri("Species"))
+        .unwrap());
+    assert!(reasoner
+        .is_instance_of(&iri("harry"), &iri("Bird"))
+        .unwrap());
+
+    // Neither interpretation inherits the other's types
+    assert!(!reasoner
+        .is_instance_of(&iri("Eagle"), &iri("Bird"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("Eagle"), &iri("Eagle"))
+        .unwrap());
+    assert!(!reasoner
+        .is_instance_of(&iri("harry"), &iri("Species"))
+        .unwrap());
+    assert!(!reasoner
+        .is_subclass_of(&iri("Eagle"), &iri("Species"))
+        .unwrap());
+    assert!(reasoner.is_class_satisfiable(&iri("Eagle")).unwrap());
+    assert!(reasoner.is_class_satisfiable(&iri("Species")).unwrap());
+}
@@ -0,0 +1,159 @@
+//! Tests for OWL2 QL query answering by perfect reformulation
+
+use owl2_reasoner::profiles::ql::{ConjunctiveQuery, QlRewriter, QueryAtom, QueryTerm};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/pets#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn has_pet() -> ObjectPropertyExpression {
+    ObjectPropertyExpression::ObjectProperty(Box::new(ObjectProperty::new(iri("hasPet"))))
+}
+
+fn individual(name: &str) -> QueryTerm {
+    QueryTerm::Individual(iri(name))
+}
+
+fn answers(rows: &[&[&str]]) -> HashSet<Vec<IRI>> {
+    rows.iter()
+        .map(|row| row.iter().map(|name| iri(name)).collect())
+        .collect()
+}
+
+/// Puppy ⊑ Dog ⊑ Animal, hasDog ⊑ hasPet, domain(hasPet) = Owner and
+/// Breeder ⊑ ∃hasPet.Dog
+fn pets() -> Arc<Ontology> {
+    let mut ontology = Ontology::new();
+    for (sub, sup) in [("Puppy", "Dog"), ("Dog", "Animal")] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(class(sub), class(sup)))
+            .unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("Breeder"),
+            ClassExpression::ObjectSomeValuesFrom(Box::new(has_pet()), Arc::new(class("Dog"))),
+        ))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri("hasDog")), Arc::new(iri("hasPet"))),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(Arc::new(iri("hasPet")), class("Owner")),
+        )))
+        .unwrap();
+
+    for (name, class_name) in [
+        ("rex", "Dog"),
+        ("bit", "Puppy"),
+        ("leo", "Animal"),
+        ("bea", "Breeder"),
+    ] {
+        ontology
+            .add_class_assertion(ClassAssertionAxiom::new(
+                Arc::new(iri(name)),
+                class(class_name),
+            ))
+            .unwrap();
+    }
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri("ann")),
+            Arc::new(iri("hasDog")),
+            Arc::new(iri("rex")),
+        ))
+        .unwrap();
+    Arc::new(ontology)
+}
+
+#[test]
+fn test_superclass_query_matches_subclass_assertions() {
+    let ontology = pets();
+    let rewriter = QlRewriter::new(ontology.clone()).unwrap();
+    let query = ConjunctiveQuery::new(
+        vec!["x".to_string()],
+        vec![QueryAtom::Class {
+            class: iri("Animal"),
+            term: QueryTerm::var("x"),
+        }],
+    );
+
+    let rewritings = rewriter.rewrite(&query);
+    assert!(rewritings.contains(&query));
+    for sub in ["Dog", "Puppy"] {
+        assert!(rewritings.iter().any(|q| q.atoms
+            == vec![QueryAtom::Class {
+                class: iri(sub),
+                term: QueryTerm::var("x"),
+            }]));
+    }
+
+    assert_eq!(
+        rewriter.answer(&query),
+        answers(&[&["rex"], &["bit"], &["leo"]])
+    );
+    // Nothing was materialized into the ontology
+    assert_eq!(ontology.class_assertions().len(), 4);
+}
+
+#[test]
+fn test_domain_and_subproperty_rewriting() {
+    let rewriter = QlRewriter::new(pets()).unwrap();
+
+    let owners = ConjunctiveQuery::new(
+        vec!["x".to_string()],
+        vec![QueryAtom::Class {
+            class: iri("Owner"),
+            term: QueryTerm::var("x"),
+        }],
+    );
+    assert_eq!(rewriter.answer(&owners), answers(&[&["ann"], &["bea"]]));
+
+    let pets_of_ann = ConjunctiveQuery::new(
+        vec!["y".to_string()],
+        vec![QueryAtom::Property {
+            property: iri("hasPet"),
+            subject: individual("ann"),
+            object: QueryTerm::var("y"),
+        }],
+    );
+    assert_eq!(rewriter.answer(&pets_of_ann), answers(&[&["rex"]]));
+}
+
+#[test]
+fn test_qualified_existential_needs_reduction() {
+    let rewriter = QlRewriter::new(pets()).unwrap();
+
+    // Owners of some animal: bea's dog is unnamed, ann's is asserted
+    let query = ConjunctiveQuery::new(
+        vec!["x".to_string()],
+        vec![
+            QueryAtom::Property {
+                property: iri("hasPet"),
+                subject: QueryTerm::var("x"),
+                object: QueryTerm::var("y"),
+            },
+            QueryAtom::Class {
+                class: iri("Animal"),
+                term: QueryTerm::var("y"),
+            },
+        ],
+    );
+    assert_eq!(rewriter.answer(&query), answers(&[&["ann"], &["bea"]]));
+
+    // The pet itself is only known for ann
+    let with_pet = ConjunctiveQuery::new(vec!["x".to_string(), "y".to_string()], query.atoms);
+    assert_eq!(rewriter.answer(&with_pet), answers(&[&["ann", "rex"]]));
+}
@@ -0,0 +1,114 @@
+//! Tests that unqualified cardinality behaves like cardinality qualified with owl:Thing
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn has_child() -> ObjectPropertyExpression {
+    ObjectProperty::new(iri("hasChild")).into()
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn at_least(n: u32) -> ClassExpression {
+    ClassExpression::ObjectMinCardinality(n, Box::new(has_child()))
+}
+
+fn at_most(n: u32) -> ClassExpression {
+    ClassExpression::ObjectMaxCardinality(n, Box::new(has_child()))
+}
+
+fn exactly(n: u32) -> ClassExpression {
+    ClassExpression::ObjectExactCardinality(n, Box::new(has_child()))
+}
+
+/// `⊤ ⊑ restriction`, stated as a subclass axiom with an unqualified restriction
+fn unqualified(restriction: ClassExpression) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::thing().clone(),
+            restriction,
+        ))
+        .unwrap();
+    ontology
+}
+
+/// The same restriction stated as a qualified cardinality axiom with an owl:Thing filler
+fn qualified(axiom: Axiom) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_axiom(axiom).unwrap();
+    ontology
+}
+
+fn satisfiability(ontology: Ontology, concepts: &[ClassExpression]) -> Vec<bool> {
+    let reasoner = TableauxReasoner::new(ontology);
+    concepts
+        .iter()
+        .map(|concept| reasoner.is_class_expression_satisfiable(concept).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_unqualified_restriction_is_viewed_as_qualified_with_thing() {
+    let axiom = Axiom::ObjectMinQualifiedCardinality(Box::new(
+        ObjectMinQualifiedCardinalityAxiom::new(2, has_child(), ClassExpression::thing().clone()),
+    ));
+    let from_axiom = axiom.as_object_cardinality().unwrap();
+    let expression = at_least(2);
+    let from_expression = expression.as_object_cardinality().unwrap();
+
+    assert_eq!(from_axiom, from_expression);
+    assert_eq!(from_expression.bound, CardinalityBound::Min);
+    assert!(!from_expression.is_qualified());
+    assert_eq!(from_expression.to_unqualified(), Some(at_least(2)));
+    assert_eq!(exactly(3).as_object_cardinality().unwrap().min(), Some(3));
+    assert_eq!(exactly(3).as_object_cardinality().unwrap().max(), Some(3));
+}
+
+#[test]
+fn test_min_cardinality_forms_agree() {
+    let concepts = [at_most(1), at_most(2), at_least(3)];
+    let axiom = Axiom::ObjectMinQualifiedCardinality(Box::new(
+        ObjectMinQualifiedCardinalityAxiom::new(2, has_child(), ClassExpression::thing().clone()),
+    ));
+
+    let expected = vec![false, true, true];
+    assert_eq!(
+        satisfiability(unqualified(at_least(2)), &concepts),
+        expected
+    );
+    assert_eq!(satisfiability(qualified(axiom), &concepts), expected);
+}
+
+#[test]
+fn test_max_cardinality_forms_agree() {
+    let concepts = [at_least(2), at_least(1), and(vec![at_least(1), at_most(0)])];
+    let axiom = Axiom::ObjectMaxQualifiedCardinality(Box::new(
+        ObjectMaxQualifiedCardinalityAxiom::new(1, has_child(), ClassExpression::thing().clone()),
+    ));
+
+    let expected = vec![false, true, false];
+    assert_eq!(satisfiability(unqualified(at_most(1)), &concepts), expected);
+    assert_eq!(satisfiability(qualified(axiom), &concepts), expected);
+}
+
+#[test]
+fn test_exact_cardinality_forms_agree() {
+    let concepts = [at_least(2), at_most(0), exactly(1)];
+    let axiom = Axiom::ObjectExactQualifiedCardinality(Box::new(
+        ObjectExactQualifiedCardinalityAxiom::new(1, has_child(), ClassExpression::thing().clone()),
+    ));
+
+    let expected = vec![false, false, true];
+    assert_eq!(satisfiability(unqualified(exactly(1)), &concepts), expected);
+    assert_eq!(satisfiability(qualified(axiom), &concepts), expected);
+}
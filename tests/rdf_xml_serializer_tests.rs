@@ -0,0 +1,234 @@
+//! Tests for writing ontologies out as RDF/XML
+
+use owl2_reasoner::parser::{OntologyParser, ParserConfig, RdfXmlParser, RdfXmlSerializer};
+use owl2_reasoner::*;
+use smallvec::smallvec;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+const FAMILY: &str = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:ex="http://example.org/family#">
+  <owl:Ontology rdf:about="http://example.org/family"/>
+  <owl:ObjectProperty rdf:about="http://example.org/family#hasChild"/>
+  <owl:DatatypeProperty rdf:about="http://example.org/family#age"/>
+  <owl:Class rdf:about="http://example.org/family#Person"/>
+  <owl:Class rdf:about="http://example.org/family#Woman">
+    <rdfs:subClassOf rdf:resource="http://example.org/family#Person"/>
+    <owl:disjointWith rdf:resource="http://example.org/family#Man"/>
+  </owl:Class>
+  <owl:Class rdf:about="http://example.org/family#Man">
+    <rdfs:subClassOf rdf:resource="http://example.org/family#Person"/>
+  </owl:Class>
+  <owl:Class rdf:about="http://example.org/family#Parent">
+    <owl:equivalentClass>
+      <owl:Class>
+        <owl:intersectionOf rdf:parseType="Collection">
+          <rdf:Description rdf:about="http://example.org/family#Person"/>
+          <owl:Restriction>
+            <owl:onProperty rdf:resource="http://example.org/family#hasChild"/>
+            <owl:someValuesFrom rdf:resource="http://example.org/family#Person"/>
+          </owl:Restriction>
+        </owl:intersectionOf>
+      </owl:Class>
+    </owl:equivalentClass>
+  </owl:Class>
+  <owl:Class rdf:about="http://example.org/family#Childless">
+    <rdfs:subClassOf>
+      <owl:Class>
+        <owl:complementOf>
+          <owl:Restriction>
+            <owl:onProperty rdf:resource="http://example.org/family#hasChild"/>
+            <owl:minCardinality rdf:datatype="http://www.w3.org/2001/XMLSchema#nonNegativeInteger">1</owl:minCardinality>
+          </owl:Restriction>
+        </owl:complementOf>
+      </owl:Class>
+    </rdfs:subClassOf>
+  </owl:Class>
+  <owl:Class rdf:about="http://example.org/family#MotherOfSons">
+    <rdfs:subClassOf>
+      <owl:Restriction>
+        <owl:onProperty rdf:resource="http://example.org/family#hasChild"/>
+        <owl:allValuesFrom>
+          <owl:Class>
+            <owl:unionOf rdf:parseType="Collection">
+              <rdf:Description rdf:about="http://example.org/family#Man"/>
+              <owl:Class rdf:about="http://example.org/family#Boy"/>
+            </owl:unionOf>
+          </owl:Class>
+        </owl:allValuesFrom>
+      </owl:Restriction>
+    </rdfs:subClassOf>
+  </owl:Class>
+  <owl:NamedIndividual rdf:about="http://example.org/family#alice">
+    <rdf:type rdf:resource="http://example.org/family#Woman"/>
+    <ex:hasChild rdf:resource="http://example.org/family#bob"/>
+    <ex:age rdf:datatype="http://www.w3.org/2001/XMLSchema#integer">42</ex:age>
+  </owl:NamedIndividual>
+</rdf:RDF>
+"#;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn has_child() -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+        ObjectProperty::new(iri("hasChild")),
+    )))
+}
+
+/// Everything the two ontologies must agree on
+fn contents(ontology: &Ontology) -> (Option<IRI>, HashSet<IRI>, HashSet<Axiom>) {
+    let entities = ontology
+        .classes()
+        .iter()
+        .map(|c| (**c.iri()).clone())
+        .chain(
+            ontology
+                .object_properties()
+                .iter()
+                .map(|p| (**p.iri()).clone()),
+        )
+        .chain(
+            ontology
+                .data_properties()
+                .iter()
+                .map(|p| (**p.iri()).clone()),
+        )
+        .chain(
+            ontology
+                .named_individuals()
+                .iter()
+                .map(|i| (**i.iri()).clone()),
+        )
+        .collect();
+    let axioms = ontology.axioms().iter().map(|a| (**a).clone()).collect();
+    (ontology.iri().cloned(), entities, axioms)
+}
+
+#[test]
+fn test_anonymous_class_expressions_are_parsed() {
+    let ontology = RdfXmlParser::new().parse_str(FAMILY).unwrap();
+    let axioms: HashSet<Axiom> = ontology.axioms().iter().map(|a| (**a).clone()).collect();
+
+    let parent = EquivalentClassesAxiom::from_expressions(vec![
+        class("Parent"),
+        ClassExpression::ObjectIntersectionOf(smallvec![
+            Arc::new(class("Person")),
+            Arc::new(ClassExpression::ObjectSomeValuesFrom(
+                has_child(),
+                Arc::new(class("Person")),
+            )),
+        ]),
+    ]);
+    let childless = SubClassOfAxiom::new(
+        class("Childless"),
+        ClassExpression::ObjectComplementOf(Arc::new(ClassExpression::ObjectMinCardinality(
+            1,
+            has_child(),
+        ))),
+    );
+    let mother_of_sons = SubClassOfAxiom::new(
+        class("MotherOfSons"),
+        ClassExpression::ObjectAllValuesFrom(
+            has_child(),
+            Arc::new(ClassExpression::ObjectUnionOf(smallvec![
+                Arc::new(class("Man")),
+                Arc::new(class("Boy")),
+            ])),
+        ),
+    );
+    assert!(axioms.contains(&Axiom::EquivalentClasses(Box::new(parent))));
+    assert!(axioms.contains(&Axiom::SubClassOf(Box::new(childless))));
+    assert!(axioms.contains(&Axiom::SubClassOf(Box::new(mother_of_sons))));
+    // The nodes of the expressions are not mistaken for individuals
+    assert!(ontology
+        .named_individuals()
+        .iter()
+        .all(|individual| !individual.iri().as_str().starts_with("_:")));
+}
+
+#[test]
+fn test_round_trip_through_rdf_xml_parser() {
+    let parser = RdfXmlParser::new();
+    let original = parser.parse_str(FAMILY).unwrap();
+
+    let xml = RdfXmlSerializer::new()
+        .serialize_ontology(&original)
+        .unwrap();
+    let reparsed = parser.parse_str(&xml).unwrap();
+
+    assert_eq!(contents(&reparsed), contents(&original), "{}", xml);
+    assert!(xml.starts_with("<?xml version=\"1.0\"?>\n<rdf:RDF"));
+    assert!(xml.contains("<owl:intersectionOf rdf:parseType=\"Collection\">"));
+    assert!(xml.contains(
+        "<ns1:age rdf:datatype=\"http://www.w3.org/2001/XMLSchema#integer\">42</ns1:age>"
+    ));
+}
+
+#[test]
+fn test_output_is_stable() {
+    let parser = RdfXmlParser::new();
+    let serializer = RdfXmlSerializer::new();
+    let first = serializer
+        .serialize_ontology(&parser.parse_str(FAMILY).unwrap())
+        .unwrap();
+    let second = serializer
+        .serialize_ontology(&parser.parse_str(&first).unwrap())
+        .unwrap();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_entity_namespaces_become_prefixes() {
+    let mut ontology = Ontology::new();
+    ontology.add_class(Class::new(iri("Mother"))).unwrap();
+    ontology
+        .add_object_property(ObjectProperty::new(iri("hasChild")))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("Mother"), class("Woman")))
+        .unwrap();
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(IRI::new("http://other.org/people/carol").unwrap()),
+            Arc::new(iri("hasChild")),
+            Arc::new(IRI::new("http://other.org/people/dan").unwrap()),
+        ))
+        .unwrap();
+
+    let xml = RdfXmlSerializer::new()
+        .serialize_ontology(&ontology)
+        .unwrap();
+    assert!(xml.contains("xmlns:ns1=\"http://example.org/family#\""));
+    assert!(xml.contains("xmlns:ns2=\"http://other.org/people/\""));
+    assert!(xml.contains("<owl:Class rdf:about=\"http://example.org/family#Mother\">"));
+    assert!(xml.contains("<rdfs:subClassOf rdf:resource=\"http://example.org/family#Woman\"/>"));
+    assert!(xml.contains("<ns1:hasChild rdf:resource=\"http://other.org/people/dan\"/>"));
+
+    let config = ParserConfig {
+        prefixes: HashMap::from([("fam".to_string(), EX.to_string())]),
+        ..Default::default()
+    };
+    let xml = RdfXmlSerializer::with_config(config)
+        .serialize_ontology(&ontology)
+        .unwrap();
+    assert!(xml.contains("xmlns:fam=\"http://example.org/family#\""));
+    assert!(xml.contains("xmlns:ns1=\"http://other.org/people/\""));
+    assert!(xml.contains("<fam:hasChild rdf:resource=\"http://other.org/people/dan\"/>"));
+
+    let reparsed = RdfXmlParser::new().parse_str(&xml).unwrap();
+    for axiom in ontology.axioms() {
+        assert!(reparsed.axioms().contains(axiom), "{:?} lost", axiom);
+    }
+}
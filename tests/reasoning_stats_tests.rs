@@ -0,0 +1,97 @@
+//! Tests for the blocking and backtracking statistics reported after reasoning
+
+use owl2_reasoner::axioms::property_expressions::ObjectPropertyExpression;
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(format!("http://example.org/{}", name)))
+}
+
+fn some(property: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(format!("http://example.org/{}", property)),
+        ))),
+        Arc::new(filler),
+    )
+}
+
+/// A ⊑ ∃R.B and B ⊑ ∃R.B, with a an A: the B successor is covered by the root
+fn cyclic_ontology() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("A"), some("R", class("B"))))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("B"), some("R", class("B"))))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(IRI::new("http://example.org/a").unwrap()),
+            class("A"),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_consistency_result_reports_subset_blocks() {
+    let mut reasoner = TableauxReasoner::new(cyclic_ontology());
+    let result = reasoner.check_consistency_with_stats().unwrap();
+
+    assert!(result.is_consistent);
+    assert!(!result.has_clash);
+    assert!(result.nodes_expanded > 1);
+    assert!(result.blocking_stats.subset_blocks > 0);
+    assert_eq!(
+        result.blocking_stats.total_blocks,
+        result.blocking_stats.subset_blocks + result.blocking_stats.equality_blocks
+    );
+    assert!(!result.blocking_stats.blocked_nodes.is_empty());
+    assert_eq!(result.backtrack_stats.total_backtracks, 0);
+}
+
+#[test]
+fn test_satisfiability_stats_report_blocks() {
+    let reasoner = TableauxReasoner::new(cyclic_ontology());
+    assert!(reasoner
+        .is_class_expression_satisfiable(&some("R", class("A")))
+        .unwrap());
+
+    let stats = reasoner.last_reasoning_stats();
+    assert!(stats.blocking.total_blocks > 0);
+    assert!(stats.blocking.subset_blocks > 0);
+}
+
+#[test]
+fn test_closed_branches_are_counted_as_backtracks() {
+    // (A ⊔ B) ⊓ ¬A: the A branch clashes and is abandoned for B
+    let concept = ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(ClassExpression::ObjectUnionOf(
+                vec![Arc::new(class("A")), Arc::new(class("B"))].into(),
+            )),
+            Arc::new(ClassExpression::ObjectComplementOf(Arc::new(class("A")))),
+        ]
+        .into(),
+    );
+    let reasoner = TableauxReasoner::new(Ontology::new());
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+
+    let stats = reasoner.last_reasoning_stats();
+    assert_eq!(stats.backtracking.choices_explored, 2);
+    assert_eq!(stats.backtracking.total_backtracks, 1);
+    assert_eq!(stats.backtracking.naive_backtracks, 1);
+    assert_eq!(stats.backtracking.contradictions_detected, 1);
+
+    // The next call starts from fresh statistics
+    assert!(reasoner
+        .is_class_expression_satisfiable(&some("R", class("A")))
+        .unwrap());
+    let stats = reasoner.last_reasoning_stats();
+    assert_eq!(stats.backtracking.choices_explored, 0);
+    assert_eq!(stats.backtracking.total_backtracks, 0);
+}
@@ -0,0 +1,106 @@
+//! Tests for the self-loops entailed by reflexive object properties
+
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/social#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn property(name: &str) -> ObjectPropertyExpression {
+    ObjectProperty::new(iri(name)).into()
+}
+
+fn reflexive(name: &str) -> Axiom {
+    Axiom::ReflexiveProperty(Box::new(ReflexivePropertyAxiom::new(Arc::new(iri(name)))))
+}
+
+fn irreflexive(name: &str) -> Axiom {
+    Axiom::IrreflexiveProperty(Box::new(IrreflexivePropertyAxiom::new(Arc::new(iri(name)))))
+}
+
+fn assert_relation(ontology: &mut Ontology, subject: &str, property: &str, object: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(subject)),
+            Arc::new(iri(property)),
+            Arc::new(iri(object)),
+        ))
+        .unwrap();
+}
+
+/// alice knows bob, and carol is only declared
+fn social() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_axiom(reflexive("knows")).unwrap();
+    assert_relation(&mut ontology, "alice", "knows", "bob");
+    ontology
+        .add_named_individual(NamedIndividual::new(iri("carol")))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_every_individual_knows_itself() {
+    let mut reasoner = OwlReasoner::new(social());
+
+    for name in ["alice", "bob", "carol"] {
+        let known = reasoner
+            .property_values(&iri(name), &property("knows"))
+            .unwrap();
+        assert!(known.contains(&iri(name)), "{} should know itself", name);
+    }
+    assert_eq!(
+        reasoner
+            .property_values(&iri("alice"), &property("knows"))
+            .unwrap(),
+        ["alice", "bob"]
+            .iter()
+            .map(|name| iri(name))
+            .collect::<HashSet<_>>()
+    );
+    assert!(reasoner.is_consistent().unwrap());
+}
+
+#[test]
+fn test_reflexive_property_satisfies_has_self() {
+    let mut ontology = social();
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::from_expressions(vec![
+            ClassExpression::Class(Class::new(iri("SelfAware"))),
+            ClassExpression::ObjectHasSelf(Box::new(property("knows"))),
+        ]))
+        .unwrap();
+    let mut reasoner = OwlReasoner::new(ontology);
+
+    assert!(reasoner
+        .is_instance_of(&iri("carol"), &iri("SelfAware"))
+        .unwrap());
+}
+
+#[test]
+fn test_reflexive_and_irreflexive_property_is_inconsistent() {
+    let mut ontology = social();
+    ontology.add_axiom(irreflexive("knows")).unwrap();
+
+    assert!(!SimpleReasoner::new(ontology.clone())
+        .is_consistent()
+        .unwrap());
+    assert!(!OwlReasoner::new(ontology).is_consistent().unwrap());
+}
+
+#[test]
+fn test_irreflexive_property_with_self_loop_is_inconsistent() {
+    let mut ontology = Ontology::new();
+    ontology.add_axiom(irreflexive("knows")).unwrap();
+    assert_relation(&mut ontology, "alice", "knows", "bob");
+    assert!(SimpleReasoner::new(ontology.clone())
+        .is_consistent()
+        .unwrap());
+
+    assert_relation(&mut ontology, "bob", "knows", "bob");
+    assert!(!SimpleReasoner::new(ontology).is_consistent().unwrap());
+}
@@ -0,0 +1,141 @@
+//! Tests for reading reified statements into reification axioms
+
+use owl2_reasoner::parser::{NtriplesParser, OntologyParser, RdfXmlParser, TurtleParser};
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(format!("{}{}", EX, name)).unwrap())
+}
+
+fn reifications(ontology: &Ontology) -> Vec<&ReificationAxiom> {
+    ontology
+        .axioms()
+        .iter()
+        .filter_map(|axiom| match axiom.as_ref() {
+            Axiom::Reification(reification) => Some(reification.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn assert_claim(reification: &ReificationAxiom) {
+    assert_eq!(reification.subject(), &iri("alice"));
+    assert_eq!(reification.predicate(), &iri("hasParent"));
+    assert_eq!(reification.object(), &ReificationObject::Named(iri("bob")));
+    assert_eq!(reification.properties().len(), 1);
+    assert_eq!(reification.properties()[0].property(), &iri("source"));
+    assert_eq!(
+        reification.properties()[0].object_iri(),
+        Some(&iri("census"))
+    );
+}
+
+#[test]
+fn test_turtle_reified_statement() {
+    let ontology = TurtleParser::new()
+        .parse_str(
+            r#"@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix ex: <http://example.org/family#> .
+
+ex:claim a rdf:Statement ;
+    rdf:subject ex:alice ;
+    rdf:predicate ex:hasParent ;
+    rdf:object ex:bob ;
+    ex:source ex:census .
+"#,
+        )
+        .unwrap();
+
+    let reifications = reifications(&ontology);
+    assert_eq!(reifications.len(), 1);
+    assert_eq!(reifications[0].reification_resource(), &iri("claim"));
+    assert_claim(reifications[0]);
+    // The statement is not asserted, only described
+    assert!(ontology
+        .property_assertions()
+        .iter()
+        .all(|assertion| assertion.property() != &iri("hasParent")));
+}
+
+#[test]
+fn test_ntriples_reified_statement_with_literal_object() {
+    let ontology = NtriplesParser::new()
+        .parse_str(
+            r#"_:s <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://www.w3.org/1999/02/22-rdf-syntax-ns#Statement> .
+_:s <http://www.w3.org/1999/02/22-rdf-syntax-ns#subject> <http://example.org/family#alice> .
+_:s <http://www.w3.org/1999/02/22-rdf-syntax-ns#predicate> <http://example.org/family#hasAge> .
+_:s <http://www.w3.org/1999/02/22-rdf-syntax-ns#object> "42"^^<http://www.w3.org/2001/XMLSchema#integer> .
+_:s <http://example.org/family#source> <http://example.org/family#census> .
+"#,
+        )
+        .unwrap();
+
+    let reifications = reifications(&ontology);
+    assert_eq!(reifications.len(), 1);
+    let reification = reifications[0];
+    assert_eq!(reification.predicate(), &iri("hasAge"));
+    let ReificationObject::Literal(literal) = reification.object() else {
+        panic!("expected a literal object, got {:?}", reification.object());
+    };
+    assert_eq!(literal.lexical_form(), "42");
+    assert_eq!(reification.properties().len(), 1);
+
+    // The literal maps back to a data property assertion on rdf:object
+    let assertions = reification.to_property_assertions().unwrap();
+    let object = assertions
+        .iter()
+        .find_map(|axiom| match axiom {
+            Axiom::DataPropertyAssertion(assertion) => Some(assertion),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(
+        object.property().as_str(),
+        "http://www.w3.org/1999/02/22-rdf-syntax-ns#object"
+    );
+    assert_eq!(object.value(), literal);
+}
+
+#[test]
+fn test_rdf_xml_reified_statement() {
+    let ontology = RdfXmlParser::new()
+        .parse_str(
+            r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/family#">
+  <rdf:Statement rdf:about="http://example.org/family#claim">
+    <rdf:subject rdf:resource="http://example.org/family#alice"/>
+    <rdf:predicate rdf:resource="http://example.org/family#hasParent"/>
+    <rdf:object rdf:resource="http://example.org/family#bob"/>
+    <ex:source rdf:resource="http://example.org/family#census"/>
+  </rdf:Statement>
+</rdf:RDF>
+"#,
+        )
+        .unwrap();
+
+    let reifications = reifications(&ontology);
+    assert_eq!(reifications.len(), 1);
+    assert_claim(reifications[0]);
+}
+
+#[test]
+fn test_incomplete_statement_keeps_its_triples() {
+    let ontology = TurtleParser::new()
+        .parse_str(
+            r#"@prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#> .
+@prefix ex: <http://example.org/family#> .
+
+ex:claim rdf:subject ex:alice .
+"#,
+        )
+        .unwrap();
+
+    assert!(reifications(&ontology).is_empty());
+    assert!(ontology.property_assertions().iter().any(|assertion| {
+        assertion.subject() == &iri("claim") && assertion.object_iri() == Some(&iri("alice"))
+    }));
+}
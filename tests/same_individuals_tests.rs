@@ -0,0 +1,96 @@
+//! Tests for retrieving the individuals entailed to be the same as an individual
+
+use owl2_reasoner::*;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/{}", name)).unwrap()
+}
+
+fn arc(name: &str) -> Arc<IRI> {
+    Arc::new(iri(name))
+}
+
+fn names(names: &[&str]) -> HashSet<IRI> {
+    names.iter().map(|name| iri(name)).collect()
+}
+
+/// People identified by their `ssn`, with a functional `hasMother`
+fn people() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::HasKey(Box::new(HasKeyAxiom::new(
+            ClassExpression::Class(Class::new(iri("Person"))),
+            vec![arc("ssn")],
+        ))))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::FunctionalProperty(Box::new(
+            FunctionalPropertyAxiom::new(arc("hasMother")),
+        )))
+        .unwrap();
+    for (person, ssn) in [("a", "123"), ("c", "123"), ("d", "456")] {
+        ontology
+            .add_class_assertion(ClassAssertionAxiom::new(
+                arc(person),
+                ClassExpression::Class(Class::new(iri("Person"))),
+            ))
+            .unwrap();
+        ontology
+            .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+                arc(person),
+                arc("ssn"),
+                Literal::simple(ssn),
+            ))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_same_as_and_key_inferences_form_one_class() {
+    let mut ontology = people();
+    ontology
+        .add_axiom(Axiom::SameIndividual(Box::new(SameIndividualAxiom::new(
+            vec![arc("a"), arc("b")],
+        ))))
+        .unwrap();
+
+    let mut reasoner = OwlReasoner::new(ontology);
+    // b by sameAs, c by sharing a's ssn; d has another ssn
+    assert_eq!(
+        reasoner.same_individuals(&iri("a")).unwrap(),
+        names(&["a", "b", "c"])
+    );
+    assert_eq!(
+        reasoner.same_individuals(&iri("c")).unwrap(),
+        names(&["a", "b", "c"])
+    );
+    assert_eq!(reasoner.same_individuals(&iri("d")).unwrap(), names(&["d"]));
+}
+
+#[test]
+fn test_functional_values_of_merged_individuals_are_merged() {
+    let mut ontology = people();
+    for (child, mother) in [("a", "m1"), ("c", "m2"), ("d", "m3")] {
+        ontology
+            .add_property_assertion(PropertyAssertionAxiom::new(
+                arc(child),
+                arc("hasMother"),
+                arc(mother),
+            ))
+            .unwrap();
+    }
+
+    // a and c share a key, so their mothers are the same
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert_eq!(
+        reasoner.same_individuals(&iri("m1")).unwrap(),
+        names(&["m1", "m2"])
+    );
+    assert_eq!(
+        reasoner.same_individuals(&iri("m3")).unwrap(),
+        names(&["m3"])
+    );
+}
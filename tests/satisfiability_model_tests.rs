@@ -0,0 +1,90 @@
+//! Tests for exporting the tableau model of a satisfiable concept
+
+use owl2_reasoner::reasoning::tableaux::MODEL_NODE_NAMESPACE;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/pets#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn some(property: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(iri(property)),
+        ))),
+        Arc::new(filler),
+    )
+}
+
+/// Dog ⊑ Animal, with Animal and Person disjoint
+fn pets() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Dog", "Person"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("Dog"), class("Animal")))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Person")),
+        ]))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_existential_model_has_successor_and_edge() {
+    let mut reasoner = OwlReasoner::new(pets());
+    let concept = ClassExpression::ObjectIntersectionOf(
+        vec![
+            Arc::new(class("Person")),
+            Arc::new(some("hasPet", class("Dog"))),
+        ]
+        .into(),
+    );
+
+    let model = reasoner.satisfiability_model(&concept).unwrap().unwrap();
+    let root = &model.individuals[0];
+    assert!(root.is_instance_of(&iri("Person")));
+    assert!(root.concepts.contains(&concept));
+
+    let successors = model.successors(root.node);
+    assert_eq!(successors.len(), 1);
+    let (property, pet) = successors[0];
+    assert_eq!(*property, iri("hasPet"));
+    let pet = model.individual(pet).unwrap();
+    assert!(pet.is_instance_of(&iri("Dog")));
+    assert!(pet.is_instance_of(&iri("Animal")));
+    assert_eq!(model.edges, vec![(root.node, iri("hasPet"), pet.node)]);
+
+    let turtle = model.to_turtle();
+    let root_term = format!("<{}{}>", MODEL_NODE_NAMESPACE, root.node.as_usize());
+    let pet_term = format!("<{}{}>", MODEL_NODE_NAMESPACE, pet.node.as_usize());
+    assert!(turtle.contains(&format!("{} a owl:NamedIndividual", root_term)));
+    assert!(turtle.contains(&format!("<{}> {}", iri("hasPet"), pet_term)));
+    assert!(turtle.contains(&format!("a <{}>", iri("Dog"))));
+    assert!(turtle.contains(&format!(
+        "[ a owl:Restriction ; owl:onProperty <{}> ; owl:someValuesFrom <{}> ]",
+        iri("hasPet"),
+        iri("Dog")
+    )));
+}
+
+#[test]
+fn test_unsatisfiable_concept_has_no_model() {
+    let mut reasoner = OwlReasoner::new(pets());
+    let concept = ClassExpression::ObjectIntersectionOf(
+        vec![Arc::new(class("Person")), Arc::new(class("Dog"))].into(),
+    );
+
+    assert_eq!(reasoner.satisfiability_model(&concept).unwrap(), None);
+}
@@ -0,0 +1,81 @@
+//! Tests for canonical class expression keys and the satisfiability cache
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(
+        IRI::new(format!("http://example.org/{}", name)).unwrap(),
+    ))
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn or(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectUnionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn not(operand: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectComplementOf(Arc::new(operand))
+}
+
+#[test]
+fn test_semantic_key_ignores_operand_order_and_nesting() {
+    let ab = and(vec![class("A"), class("B")]);
+    let ba = and(vec![class("B"), class("A")]);
+    assert_ne!(ab, ba);
+    assert_eq!(ab.semantic_key(), ba.semantic_key());
+
+    // A ⊓ (B ⊓ A) flattens and deduplicates to A ⊓ B
+    let nested = and(vec![class("A"), ba.clone()]);
+    assert_eq!(nested.semantic_key(), ab.semantic_key());
+
+    // ¬(A ⊓ B) is keyed in negation normal form as ¬B ⊔ ¬A
+    let negated = not(ba);
+    let nnf = or(vec![not(class("B")), not(class("A"))]);
+    assert_eq!(negated.semantic_key(), nnf.semantic_key());
+    assert_eq!(not(not(ab.clone())).semantic_key(), ab.semantic_key());
+
+    assert_ne!(
+        ab.semantic_key(),
+        or(vec![class("A"), class("B")]).semantic_key()
+    );
+}
+
+#[test]
+fn test_commuted_intersections_share_a_cache_entry() {
+    let reasoner = TableauxReasoner::new(Ontology::new());
+
+    assert!(reasoner
+        .is_class_expression_satisfiable(&and(vec![class("A"), class("B")]))
+        .unwrap());
+    assert_eq!(reasoner.last_reasoning_stats().cache_misses, 1);
+
+    assert!(reasoner
+        .is_class_expression_satisfiable(&and(vec![class("B"), class("A")]))
+        .unwrap());
+    assert_eq!(reasoner.last_reasoning_stats().cache_hits, 1);
+    assert_eq!(reasoner.cache.satisfiability_cache.borrow().len(), 1);
+}
+
+#[test]
+fn test_semantic_key_sorts_operands_by_their_ordering() {
+    // Operands are sorted by the expression ordering, where IRIs compare as strings
+    let ab = and(vec![class("A"), class("B")]);
+    assert_eq!(and(vec![class("B"), class("A")]).semantic_key(), ab);
+
+    let individual = |name: &str| {
+        Individual::Named(NamedIndividual::new(
+            IRI::new(format!("http://example.org/{}", name)).unwrap(),
+        ))
+    };
+    let one_of = |names: &[&str]| {
+        ClassExpression::ObjectOneOf(Box::new(
+            names.iter().map(|name| individual(name)).collect(),
+        ))
+    };
+    assert_eq!(one_of(&["b", "a", "b"]).semantic_key(), one_of(&["a", "b"]));
+}
@@ -0,0 +1,97 @@
+//! Tests for streaming serializer output to writers
+
+use owl2_reasoner::parser::{OwlXmlParser, OwlXmlSerializer};
+use owl2_reasoner::*;
+use std::io::{self, Write};
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn family() -> Ontology {
+    let mut ontology = Ontology::with_iri(IRI::new("http://example.org/family").unwrap());
+    for name in ["Person", "Parent"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::Class(Class::new(iri("Parent"))),
+            ClassExpression::Class(Class::new(iri("Person"))),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("alice")),
+            ClassExpression::Class(Class::new(iri("Parent"))),
+        ))
+        .unwrap();
+    ontology
+}
+
+/// Writer that fails after accepting a fixed number of bytes
+struct FailingWriter {
+    remaining: usize,
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.remaining == 0 {
+            return Err(io::Error::other("disk full"));
+        }
+        let written = buf.len().min(self.remaining);
+        self.remaining -= written;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_writer_output_matches_string_output() {
+    let ontology = family();
+    let serializer = OwlXmlSerializer::new();
+
+    let mut buffer = Vec::new();
+    serializer
+        .serialize_to_writer(&ontology, &mut buffer)
+        .unwrap();
+    let document = serializer.serialize(&ontology).unwrap();
+
+    assert_eq!(buffer, document.as_bytes());
+    let parsed = OwlXmlParser::new()
+        .parse_str(std::str::from_utf8(&buffer).unwrap())
+        .unwrap();
+    assert_eq!(parsed.subclass_axioms().len(), 1);
+    assert_eq!(parsed.class_assertions().len(), 1);
+}
+
+#[test]
+fn test_serialize_to_file_matches_string_output() {
+    let ontology = family();
+    let serializer = OwlXmlSerializer::new();
+    let path = std::env::temp_dir().join(format!(
+        "owl2_reasoner_writer_test_{}.owx",
+        std::process::id()
+    ));
+
+    serializer.serialize_to_file(&ontology, &path).unwrap();
+    let written = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(written, serializer.serialize(&ontology).unwrap());
+}
+
+#[test]
+fn test_write_errors_are_reported() {
+    let error = OwlXmlSerializer::new()
+        .serialize_to_writer(&family(), FailingWriter { remaining: 64 })
+        .unwrap_err();
+
+    assert!(matches!(error, OwlError::IoError(_)), "{:?}", error);
+    assert!(error.to_string().contains("disk full"), "{}", error);
+}
@@ -0,0 +1,202 @@
+//! Tests for validating individuals against SHACL-lite shapes
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/hr#";
+
+const SHAPES: &str = r#"
+@prefix sh: <http://www.w3.org/ns/shacl#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+@prefix ex: <http://example.org/hr#> .
+
+# Every person has exactly one name and works for companies only
+ex:PersonShape
+    a sh:NodeShape ;
+    sh:targetClass ex:Person ;
+    sh:property [
+        sh:path ex:hasName ;
+        sh:minCount 1 ;
+        sh:maxCount 1 ;
+        sh:datatype xsd:string ;
+    ] ;
+    sh:property [
+        sh:path ex:worksFor ;
+        sh:class ex:Company ;
+    ] .
+"#;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn typed(ontology: &mut Ontology, individual: &str, class_name: &str) {
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri(individual)),
+            class(class_name),
+        ))
+        .unwrap();
+}
+
+fn named(ontology: &mut Ontology, individual: &str, property: &str, value: Literal) {
+    ontology
+        .add_data_property_assertion(DataPropertyAssertionAxiom::new(
+            Arc::new(iri(individual)),
+            Arc::new(iri(property)),
+            value,
+        ))
+        .unwrap();
+}
+
+fn works_for(ontology: &mut Ontology, individual: &str, employer: &str) {
+    ontology
+        .add_property_assertion(PropertyAssertionAxiom::new(
+            Arc::new(iri(individual)),
+            Arc::new(iri("worksFor")),
+            Arc::new(iri(employer)),
+        ))
+        .unwrap();
+}
+
+/// alice conforms, bob has no name
+fn staff() -> Ontology {
+    let mut ontology = Ontology::new();
+    typed(&mut ontology, "acme", "Company");
+    typed(&mut ontology, "alice", "Person");
+    named(&mut ontology, "alice", "hasName", Literal::simple("Alice"));
+    works_for(&mut ontology, "alice", "acme");
+    typed(&mut ontology, "bob", "Person");
+    ontology
+}
+
+#[test]
+fn test_parse_shapes_graph() {
+    let shapes = ShapesGraph::parse_turtle(SHAPES).unwrap();
+
+    assert_eq!(shapes.shapes().len(), 1);
+    let shape = &shapes.shapes()[0];
+    assert_eq!(shape.iri, iri("PersonShape"));
+    assert_eq!(shape.target_classes, vec![iri("Person")]);
+    assert_eq!(shape.properties.len(), 2);
+    assert_eq!(shape.properties[0].path, iri("hasName"));
+    assert_eq!(
+        shape.properties[0].constraints,
+        vec![
+            ShaclConstraint::MinCount(1),
+            ShaclConstraint::MaxCount(1),
+            ShaclConstraint::Datatype(IRI::new("http://www.w3.org/2001/XMLSchema#string").unwrap()),
+        ]
+    );
+    assert_eq!(
+        shape.properties[1].constraints,
+        vec![ShaclConstraint::Class(iri("Company"))]
+    );
+
+    assert!(ShapesGraph::parse_turtle("ex:Shape sh:targetClass ex:Person .").is_err());
+}
+
+#[test]
+fn test_min_count_flags_individual_without_name() {
+    let shapes = ShapesGraph::parse_turtle(SHAPES).unwrap();
+    let report = shapes.validate(&staff()).unwrap();
+
+    assert!(!report.conforms());
+    assert!(report.violations_for(&iri("alice")).is_empty());
+    let violations = report.violations_for(&iri("bob"));
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].shape, iri("PersonShape"));
+    assert_eq!(violations[0].path, iri("hasName"));
+    assert_eq!(violations[0].constraint, ShaclConstraint::MinCount(1));
+    assert_eq!(
+        report.focus_nodes().into_iter().collect::<Vec<_>>(),
+        [&iri("bob")]
+    );
+
+    let mut ontology = staff();
+    named(&mut ontology, "bob", "hasName", Literal::simple("Bob"));
+    assert!(shapes.validate(&ontology).unwrap().conforms());
+}
+
+#[test]
+fn test_value_constraints() {
+    let shapes = ShapesGraph::parse_turtle(SHAPES).unwrap();
+    let mut ontology = staff();
+    named(&mut ontology, "bob", "hasName", Literal::simple("Bob"));
+    named(&mut ontology, "bob", "hasName", Literal::simple("Robert"));
+    typed(&mut ontology, "carol", "Person");
+    named(
+        &mut ontology,
+        "carol",
+        "hasName",
+        Literal::typed(
+            "42",
+            IRI::new("http://www.w3.org/2001/XMLSchema#integer").unwrap(),
+        ),
+    );
+    works_for(&mut ontology, "carol", "bob");
+
+    let report = shapes.validate(&ontology).unwrap();
+    let bob: Vec<_> = report
+        .violations_for(&iri("bob"))
+        .into_iter()
+        .map(|violation| violation.constraint.clone())
+        .collect();
+    assert_eq!(bob, vec![ShaclConstraint::MaxCount(1)]);
+
+    let carol: Vec<_> = report
+        .violations_for(&iri("carol"))
+        .into_iter()
+        .map(|violation| (violation.constraint.clone(), violation.value.clone()))
+        .collect();
+    assert_eq!(
+        carol,
+        vec![
+            (
+                ShaclConstraint::Datatype(
+                    IRI::new("http://www.w3.org/2001/XMLSchema#string").unwrap()
+                ),
+                Some("42".to_string())
+            ),
+            (
+                ShaclConstraint::Class(iri("Company")),
+                Some(iri("bob").to_string())
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_validation_counts_inferred_facts() {
+    let shapes = ShapesGraph::parse_turtle(SHAPES).unwrap();
+    let mut ontology = staff();
+    named(&mut ontology, "bob", "hasName", Literal::simple("Bob"));
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(class("Employee"), class("Person")))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::SubDataProperty(Box::new(SubDataPropertyAxiom::new(
+            Arc::new(iri("hasFullName")),
+            Arc::new(iri("hasName")),
+        ))))
+        .unwrap();
+    // Both are people only by inference; carol's name is inferred too
+    typed(&mut ontology, "carol", "Employee");
+    named(
+        &mut ontology,
+        "carol",
+        "hasFullName",
+        Literal::simple("Carol"),
+    );
+    typed(&mut ontology, "dave", "Employee");
+
+    let report = shapes.validate(&ontology).unwrap();
+    assert!(report.violations_for(&iri("carol")).is_empty());
+    let dave = report.violations_for(&iri("dave"));
+    assert_eq!(dave.len(), 1);
+    assert_eq!(dave[0].constraint, ShaclConstraint::MinCount(1));
+}
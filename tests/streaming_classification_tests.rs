@@ -0,0 +1,106 @@
+//! Tests for streaming classification results as they are computed
+
+use owl2_reasoner::reasoning::ClassificationEngine;
+use owl2_reasoner::*;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/tree#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+/// Animal > {Mammal > {Dog, Cat}, Bird}, with Hound equivalent to Dog and
+/// Animal disjoint from Plant
+fn living() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Mammal", "Dog", "Hound", "Cat", "Bird", "Plant"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [
+        ("Mammal", "Animal"),
+        ("Dog", "Mammal"),
+        ("Cat", "Mammal"),
+        ("Bird", "Animal"),
+    ] {
+        ontology
+            .add_axiom(Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+                class(sub),
+                class(sup),
+            ))))
+            .unwrap();
+    }
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::new(vec![
+            Arc::new(iri("Dog")),
+            Arc::new(iri("Hound")),
+        ]))
+        .unwrap();
+    ontology
+        .add_disjoint_classes_axiom(DisjointClassesAxiom::new(vec![
+            Arc::new(iri("Animal")),
+            Arc::new(iri("Plant")),
+        ]))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_streamed_results_equal_batch_classification() {
+    let ontology = living();
+    let mut batch = ClassificationEngine::new(ontology.clone());
+    let hierarchy = batch.classify().unwrap().hierarchy;
+    let expected: BTreeMap<IRI, Vec<IRI>> = ontology
+        .classes()
+        .iter()
+        .map(|class| {
+            let class = (**class.iri()).clone();
+            let mut superclasses: Vec<IRI> =
+                hierarchy.superclasses(&class, true).into_iter().collect();
+            superclasses.sort();
+            (class, superclasses)
+        })
+        .collect();
+
+    let mut engine = ClassificationEngine::new(living());
+    let streamed: BTreeMap<IRI, Vec<IRI>> = engine
+        .classify_streaming()
+        .collect::<OwlResult<_>>()
+        .unwrap();
+
+    assert_eq!(streamed, expected);
+    assert_eq!(streamed[&iri("Cat")], vec![iri("Mammal")]);
+    // The engine is left fully classified
+    assert!(engine
+        .hierarchy()
+        .are_disjoint(&iri("Animal"), &iri("Plant")));
+}
+
+#[test]
+fn test_superclasses_are_streamed_before_subclasses() {
+    let mut engine = ClassificationEngine::new(living());
+    let position: HashMap<IRI, usize> = engine
+        .classify_streaming()
+        .enumerate()
+        .map(|(index, result)| (result.unwrap().0, index))
+        .collect();
+
+    for (sub, sup) in [
+        ("Mammal", "Animal"),
+        ("Dog", "Mammal"),
+        ("Cat", "Mammal"),
+        ("Bird", "Animal"),
+    ] {
+        assert!(
+            position[&iri(sup)] < position[&iri(sub)],
+            "{} before {}",
+            sup,
+            sub
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! Tests that edges on a sub-property count as edges on its super-properties in the tableau
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/family#";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn property(name: &str) -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectProperty::new(iri(name)).into())
+}
+
+fn not(expression: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectComplementOf(Arc::new(expression))
+}
+
+fn and(operands: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(operands.into_iter().map(Arc::new).collect())
+}
+
+fn some(name: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(property(name), Arc::new(filler))
+}
+
+fn only(name: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectAllValuesFrom(property(name), Arc::new(filler))
+}
+
+/// `hasSon ⊑ hasChild`
+fn family() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology
+        .add_axiom(Axiom::SubObjectProperty(Box::new(
+            SubObjectPropertyAxiom::new(Arc::new(iri("hasSon")), Arc::new(iri("hasChild"))),
+        )))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_existing_sub_property_successor_satisfies_existential() {
+    let reasoner = TableauxReasoner::new(family());
+
+    // The son is a child, so ∃hasChild.Doctor needs no successor of its own
+    let concept = and(vec![
+        some("hasSon", class("Doctor")),
+        some("hasChild", class("Doctor")),
+    ]);
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+    // The root and the son, with no extra child created for the second restriction
+    assert_eq!(reasoner.last_reasoning_stats().total_nodes, 2);
+}
+
+#[test]
+fn test_universal_restriction_reaches_sub_property_successors() {
+    let reasoner = TableauxReasoner::new(family());
+
+    // Every child is a doctor, so the son is one too
+    let concept = and(vec![
+        only("hasChild", class("Doctor")),
+        some("hasSon", not(class("Doctor"))),
+    ]);
+    assert!(!reasoner.is_class_expression_satisfiable(&concept).unwrap());
+
+    // The super-property's successors are not successors of the sub-property
+    let concept = and(vec![
+        only("hasSon", class("Doctor")),
+        some("hasChild", not(class("Doctor"))),
+    ]);
+    assert!(reasoner.is_class_expression_satisfiable(&concept).unwrap());
+}
@@ -0,0 +1,65 @@
+//! Tests for answering named subsumptions from the class hierarchy
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+/// Dog ⊑ Mammal ⊑ Animal, Hound ≡ Dog
+fn taxonomy() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Mammal", "Dog", "Hound"] {
+        ontology.add_class(Class::new(iri(name))).unwrap();
+    }
+    for (sub, sup) in [("Dog", "Mammal"), ("Mammal", "Animal")] {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(
+                ClassExpression::from(Class::new(iri(sub))),
+                ClassExpression::from(Class::new(iri(sup))),
+            ))
+            .unwrap();
+    }
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::new(vec![
+            Arc::new(iri("Hound")),
+            Arc::new(iri("Dog")),
+        ]))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_asserted_subsumption_skips_tableaux() {
+    let reasoner = TableauxReasoner::new(taxonomy());
+
+    assert!(reasoner
+        .is_subclass_of(&iri("Dog"), &iri("Mammal"))
+        .unwrap());
+    let stats = reasoner.last_reasoning_stats();
+    assert_eq!(stats.total_nodes, 0);
+    assert_eq!(stats.cache_hits, 1);
+
+    // Transitive and equivalence-derived subsumptions are answered the same way
+    assert!(reasoner
+        .is_subclass_of(&iri("Hound"), &iri("Animal"))
+        .unwrap());
+    assert_eq!(reasoner.last_reasoning_stats().total_nodes, 0);
+}
+
+#[test]
+fn test_non_obvious_subsumption_falls_back_to_tableaux() {
+    let reasoner = TableauxReasoner::new(taxonomy());
+
+    assert!(!reasoner
+        .is_subclass_of(&iri("Animal"), &iri("Dog"))
+        .unwrap());
+    let stats = reasoner.last_reasoning_stats();
+    assert!(stats.total_nodes > 0);
+    assert_eq!(stats.cache_misses, 1);
+    assert_eq!(stats.cache_hits, 0);
+}
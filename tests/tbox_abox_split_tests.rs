@@ -0,0 +1,142 @@
+//! Tests for splitting an ontology into its TBox and ABox
+
+use owl2_reasoner::axioms::*;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(name: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(format!("http://example.org/{}", name)).unwrap())
+}
+
+fn named(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(format!("http://example.org/{}", name)))
+}
+
+fn mixed_ontology() -> Ontology {
+    let mut ontology = Ontology::with_iri("http://example.org/people");
+    for name in ["Person", "Parent", "Adult"] {
+        ontology
+            .add_class(Class::new(format!("http://example.org/{}", name)))
+            .unwrap();
+    }
+    ontology
+        .add_object_property(ObjectProperty::new("http://example.org/hasChild"))
+        .unwrap();
+    for name in ["alice", "bob"] {
+        ontology
+            .add_named_individual(NamedIndividual::new(format!("http://example.org/{}", name)))
+            .unwrap();
+    }
+
+    // TBox
+    ontology
+        .add_axiom(Axiom::SubClassOf(Box::new(SubClassOfAxiom::new(
+            named("Parent"),
+            named("Person"),
+        ))))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::EquivalentClasses(Box::new(
+            EquivalentClassesAxiom::new(vec![iri("Adult"), iri("Parent")]),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::TransitiveProperty(Box::new(
+            TransitivePropertyAxiom::new(iri("hasChild")),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::ObjectPropertyDomain(Box::new(
+            ObjectPropertyDomainAxiom::new(iri("hasChild"), named("Parent")),
+        )))
+        .unwrap();
+
+    // ABox
+    ontology
+        .add_axiom(Axiom::ClassAssertion(Box::new(ClassAssertionAxiom::new(
+            iri("alice"),
+            named("Person"),
+        ))))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::PropertyAssertion(Box::new(
+            PropertyAssertionAxiom::new(iri("alice"), iri("hasChild"), iri("bob")),
+        )))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::DifferentIndividuals(Box::new(
+            DifferentIndividualsAxiom::new(vec![iri("alice"), iri("bob")]),
+        )))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_split_categorizes_axioms() {
+    let (tbox, abox) = mixed_ontology().split_tbox_abox();
+
+    assert_eq!(tbox.axioms().len(), 4);
+    assert!(tbox.axioms().iter().all(|axiom| !axiom.is_assertional()));
+    assert_eq!(tbox.subclass_axioms().len(), 1);
+    assert_eq!(tbox.transitive_property_axioms().len(), 1);
+
+    assert_eq!(abox.axioms().len(), 3);
+    assert!(abox.axioms().iter().all(|axiom| axiom.is_assertional()));
+    assert_eq!(abox.class_assertions().len(), 1);
+    assert_eq!(abox.property_assertions().len(), 1);
+}
+
+#[test]
+fn test_split_preserves_every_axiom() {
+    let ontology = mixed_ontology();
+    let (tbox, abox) = ontology.split_tbox_abox();
+
+    let mut original: Vec<String> = ontology
+        .axioms()
+        .iter()
+        .map(|axiom| format!("{:?}", axiom))
+        .collect();
+    let mut split: Vec<String> = tbox
+        .axioms()
+        .iter()
+        .chain(abox.axioms())
+        .map(|axiom| format!("{:?}", axiom))
+        .collect();
+    original.sort();
+    split.sort();
+    assert_eq!(original, split);
+}
+
+#[test]
+fn test_split_keeps_declarations_in_both() {
+    let ontology = mixed_ontology();
+    let (tbox, abox) = ontology.split_tbox_abox();
+
+    for part in [&tbox, &abox] {
+        assert_eq!(part.iri(), ontology.iri());
+        assert_eq!(part.entity_count(), ontology.entity_count());
+        assert_eq!(part.classes().len(), 3);
+        assert_eq!(part.named_individuals().len(), 2);
+    }
+}
+
+#[test]
+fn test_abox_reasons_against_tbox() {
+    let (tbox, abox) = mixed_ontology().split_tbox_abox();
+
+    // The ABox alone knows nothing about parents
+    let mut data_only = OwlReasoner::new(abox.clone());
+    assert!(!data_only
+        .is_instance_of(&iri("alice"), &iri("Parent"))
+        .unwrap());
+
+    // Recombined with the TBox, the domain axiom makes alice a parent
+    let mut combined = tbox;
+    for axiom in abox.axioms() {
+        combined.add_axiom((**axiom).clone()).unwrap();
+    }
+    let mut reasoner = OwlReasoner::new(combined);
+    assert!(reasoner
+        .is_instance_of(&iri("alice"), &iri("Parent"))
+        .unwrap());
+}
@@ -0,0 +1,86 @@
+//! Tests for lazily unfolding TBox subsumptions during tableaux expansion
+
+use owl2_reasoner::reasoning::tableaux::TableauxReasoner;
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn some(property: &str, filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(iri(property)),
+        ))),
+        Arc::new(filler),
+    )
+}
+
+fn ontology(axioms: Vec<(ClassExpression, ClassExpression)>) -> Ontology {
+    let mut ontology = Ontology::new();
+    for (sub, sup) in axioms {
+        ontology
+            .add_subclass_axiom(SubClassOfAxiom::new(sub, sup))
+            .unwrap();
+    }
+    ontology
+}
+
+#[test]
+fn test_subsumption_chain_into_complement_is_unsatisfiable() {
+    // A ⊑ B, B ⊑ ¬A
+    let reasoner = TableauxReasoner::new(ontology(vec![
+        (class("A"), class("B")),
+        (
+            class("B"),
+            ClassExpression::ObjectComplementOf(Arc::new(class("A"))),
+        ),
+    ]));
+
+    assert!(!reasoner.is_class_satisfiable(&iri("A")).unwrap());
+    assert!(reasoner.is_class_satisfiable(&iri("B")).unwrap());
+}
+
+#[test]
+fn test_superclasses_are_unfolded_in_successors() {
+    // A ⊑ ∃r.B, B ⊑ C, C ⊑ ¬B
+    let reasoner = TableauxReasoner::new(ontology(vec![
+        (class("A"), some("r", class("B"))),
+        (class("B"), class("C")),
+        (
+            class("C"),
+            ClassExpression::ObjectComplementOf(Arc::new(class("B"))),
+        ),
+    ]));
+
+    assert!(!reasoner.is_class_satisfiable(&iri("B")).unwrap());
+    assert!(!reasoner.is_class_satisfiable(&iri("A")).unwrap());
+}
+
+#[test]
+fn test_cyclic_definition_terminates() {
+    // A ⊑ ∃r.A needs an infinite model; blocking keeps the tableau finite
+    let reasoner = TableauxReasoner::new(ontology(vec![(class("A"), some("r", class("A")))]));
+
+    assert!(reasoner.is_class_satisfiable(&iri("A")).unwrap());
+}
+
+#[test]
+fn test_thing_subsumptions_apply_to_every_node() {
+    // ⊤ ⊑ ¬A
+    let reasoner = TableauxReasoner::new(ontology(vec![(
+        ClassExpression::Class(Class::new(
+            IRI::new("http://www.w3.org/2002/07/owl#Thing").unwrap(),
+        )),
+        ClassExpression::ObjectComplementOf(Arc::new(class("A"))),
+    )]));
+
+    assert!(!reasoner.is_class_satisfiable(&iri("A")).unwrap());
+}
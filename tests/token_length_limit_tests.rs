@@ -0,0 +1,92 @@
+//! Tests for rejecting over-long IRIs and literals while tokenizing
+
+use owl2_reasoner::parser::{
+    NtriplesParser, OntologyParser, OwlFunctionalSyntaxParser, ParserConfig, TurtleParser,
+};
+use owl2_reasoner::*;
+
+/// An IRI of about a megabyte
+fn huge_iri() -> String {
+    format!("http://example.org/{}", "a".repeat(1024 * 1024))
+}
+
+fn limited(max_iri_length: usize, max_literal_length: usize) -> ParserConfig {
+    ParserConfig {
+        max_iri_length,
+        max_literal_length,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_functional_syntax_rejects_huge_iri_by_default() {
+    let content = format!(
+        "Prefix(:=<http://example.org/>)\nOntology(<http://example.org/o>\n  Declaration(Class(<{}>))\n)",
+        huge_iri()
+    );
+
+    match OwlFunctionalSyntaxParser::new().parse_str(&content) {
+        Err(OwlError::ParseError(message)) => {
+            assert!(message.contains("line 3, column 21"), "{}", message);
+            assert!(
+                message.contains("maximum length of 65536 bytes"),
+                "{}",
+                message
+            );
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_functional_syntax_literal_limit() {
+    let content = "Ontology(<http://example.org/o>\n  Annotation(<http://www.w3.org/2000/01/rdf-schema#label> \"far too long\")\n)";
+
+    let result = OwlFunctionalSyntaxParser::with_config(limited(0, 8)).parse_str(content);
+    assert!(
+        matches!(&result, Err(OwlError::ParseError(message)) if message.contains("Literal exceeds")),
+        "{:?}",
+        result
+    );
+    // Without limits the document parses
+    assert!(OwlFunctionalSyntaxParser::with_config(limited(0, 0))
+        .parse_str(content)
+        .is_ok());
+}
+
+#[test]
+fn test_turtle_reports_location_of_long_iri() {
+    let content = "@prefix ex: <http://example.org/> .\n\
+                   ex:A a <http://www.w3.org/2002/07/owl#Class> .\n";
+
+    match TurtleParser::with_config(limited(24, 0)).parse_str(content) {
+        Err(OwlError::ParseErrorWithLocation {
+            line,
+            column,
+            message,
+        }) => {
+            assert_eq!((line, column), (2, 8));
+            assert_eq!(message, "IRI exceeds the maximum length of 24 bytes");
+        }
+        other => panic!("expected a located parse error, got {:?}", other),
+    }
+    assert!(TurtleParser::with_config(limited(64, 0))
+        .parse_str(content)
+        .is_ok());
+}
+
+#[test]
+fn test_ntriples_literal_limit() {
+    let content = "<http://example.org/a> <http://example.org/name> \"a rather long name\" .\n";
+
+    let result = NtriplesParser::with_config(limited(0, 10)).parse_str(content);
+    assert!(
+        matches!(&result, Err(OwlError::ParseError(message))
+            if message.contains("line 1") && message.contains("Literal exceeds")),
+        "{:?}",
+        result
+    );
+    assert!(NtriplesParser::with_config(limited(0, 32))
+        .parse_str(content)
+        .is_ok());
+}
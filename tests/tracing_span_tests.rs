@@ -0,0 +1,162 @@
+//! Tests for the tracing spans around reasoning phases
+#![cfg(feature = "tracing")]
+
+use owl2_reasoner::reasoning::ClassificationEngine;
+use owl2_reasoner::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// A span as seen by the subscriber: its name and every field recorded on it
+#[derive(Debug, Default, Clone)]
+struct CapturedSpan {
+    name: &'static str,
+    fields: HashMap<&'static str, String>,
+}
+
+impl Visit for CapturedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.fields.insert(field.name(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.fields.insert(field.name(), value.to_string());
+    }
+}
+
+/// Collects every span opened while it is the default subscriber
+#[derive(Default, Clone)]
+struct SpanCollector {
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+}
+
+impl SpanCollector {
+    fn span(&self, name: &str) -> CapturedSpan {
+        let spans = self.spans.lock().unwrap();
+        let matching: Vec<&CapturedSpan> = spans.iter().filter(|s| s.name == name).collect();
+        assert_eq!(
+            matching.len(),
+            1,
+            "expected one {} span in {:?}",
+            name,
+            spans
+        );
+        matching[0].clone()
+    }
+}
+
+impl Subscriber for SpanCollector {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+        let mut span = CapturedSpan {
+            name: attributes.metadata().name(),
+            ..Default::default()
+        };
+        attributes.record(&mut span);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(span);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, id: &Id, values: &Record<'_>) {
+        let mut spans = self.spans.lock().unwrap();
+        values.record(&mut spans[id.into_u64() as usize - 1]);
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+fn iri(name: &str) -> Arc<IRI> {
+    Arc::new(IRI::new(format!("http://example.org/{}", name)).unwrap())
+}
+
+/// Dog ⊑ Animal, Puppy ≡ Dog, with rex a Puppy
+fn ontology() -> Ontology {
+    let mut ontology = Ontology::new();
+    for name in ["Animal", "Dog", "Puppy"] {
+        ontology
+            .add_class(Class::new(format!("http://example.org/{}", name)))
+            .unwrap();
+    }
+    ontology
+        .add_named_individual(NamedIndividual::new("http://example.org/rex"))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::Class(Class::new("http://example.org/Dog")),
+            ClassExpression::Class(Class::new("http://example.org/Animal")),
+        ))
+        .unwrap();
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::new(vec![iri("Puppy"), iri("Dog")]))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            iri("rex"),
+            ClassExpression::Class(Class::new("http://example.org/Puppy")),
+        ))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_classification_span() {
+    let collector = SpanCollector::default();
+    let result = tracing::subscriber::with_default(collector.clone(), || {
+        ClassificationEngine::new(ontology()).classify().unwrap()
+    });
+
+    let span = collector.span("classification");
+    assert_eq!(span.fields["classes"], "3");
+    assert_eq!(span.fields["axioms"], "3");
+    assert_eq!(
+        span.fields["relationships"],
+        result.stats.relationships_discovered.to_string()
+    );
+    assert_eq!(
+        span.fields["equivalences"],
+        result.stats.equivalences_found.to_string()
+    );
+    assert!(span.fields.contains_key("disjointness"));
+}
+
+#[test]
+fn test_consistency_span() {
+    let collector = SpanCollector::default();
+    let consistent = tracing::subscriber::with_default(collector.clone(), || {
+        OwlReasoner::new(ontology()).is_consistent().unwrap()
+    });
+
+    let span = collector.span("consistency");
+    assert_eq!(span.fields["axioms"], "3");
+    assert_eq!(span.fields["individuals"], "1");
+    assert_eq!(span.fields["consistent"], consistent.to_string());
+    assert!(span.fields.contains_key("method"));
+}
+
+#[test]
+fn test_realization_span() {
+    let collector = SpanCollector::default();
+    let types = tracing::subscriber::with_default(collector.clone(), || {
+        OwlReasoner::new(ontology())
+            .direct_types(&iri("rex"))
+            .unwrap()
+    });
+
+    let span = collector.span("realization");
+    assert_eq!(span.fields["individual"], "http://example.org/rex");
+    assert_eq!(span.fields["classes"], "3");
+    assert_eq!(span.fields["types"], types.len().to_string());
+}
@@ -0,0 +1,50 @@
+//! Tests for entity declarations read from Turtle
+
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::*;
+
+const DECLARATIONS: &str = r#"@prefix : <http://example.org/decl#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+:alice a owl:NamedIndividual .
+:age a owl:DatatypeProperty .
+:weight a owl:DataProperty .
+:note a owl:AnnotationProperty .
+"#;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/decl#{}", name)).unwrap()
+}
+
+#[test]
+fn test_named_individual_declaration_is_not_a_class_assertion() {
+    let ontology = TurtleParser::new().parse_str(DECLARATIONS).unwrap();
+
+    assert!(ontology
+        .named_individuals()
+        .iter()
+        .any(|individual| **individual.iri() == iri("alice")));
+    assert!(ontology.class_assertions().is_empty());
+    assert!(ontology
+        .classes()
+        .iter()
+        .all(|class| !class.iri().as_str().ends_with("#NamedIndividual")));
+}
+
+#[test]
+fn test_property_declarations() {
+    let ontology = TurtleParser::new().parse_str(DECLARATIONS).unwrap();
+
+    let data_properties: Vec<IRI> = ontology
+        .data_properties()
+        .iter()
+        .map(|property| (**property.iri()).clone())
+        .collect();
+    assert!(data_properties.contains(&iri("age")));
+    assert!(data_properties.contains(&iri("weight")));
+    assert!(ontology
+        .annotation_properties()
+        .iter()
+        .any(|property| **property.iri() == iri("note")));
+}
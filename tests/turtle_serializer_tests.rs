@@ -0,0 +1,198 @@
+//! Tests for writing ontologies back out as Turtle
+
+use owl2_reasoner::parser::{OntologyParser, ParserConfig, TurtleParser, TurtleSerializer};
+use owl2_reasoner::*;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+const FAMILY: &str = r#"@prefix : <http://example.org/family#> .
+@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+<http://example.org/family> a owl:Ontology .
+:Person a owl:Class .
+:Parent a owl:Class .
+:Woman a owl:Class ; rdfs:subClassOf :Person .
+:Man a owl:Class ; rdfs:subClassOf :Person .
+:Mother a owl:Class ; rdfs:subClassOf :Woman .
+:Human a owl:Class ; owl:equivalentClass :Person .
+:Woman owl:disjointWith :Man .
+:Parent rdfs:subClassOf [ a owl:Restriction ; owl:onProperty :hasChild ; owl:someValuesFrom :Person ] .
+:hasChild a owl:ObjectProperty .
+:hasChild rdfs:domain :Parent .
+:age a owl:DatatypeProperty .
+rdfs:label a owl:AnnotationProperty .
+:alice a :Mother ; :hasChild :bob ; :age "42"^^xsd:integer .
+:bob a :Man ; rdfs:label "Bob"@en .
+"#;
+
+/// Everything the two ontologies must agree on
+fn contents(ontology: &Ontology) -> (Option<IRI>, HashSet<IRI>, HashSet<Axiom>) {
+    let entities = ontology
+        .classes()
+        .iter()
+        .map(|c| (**c.iri()).clone())
+        .chain(
+            ontology
+                .object_properties()
+                .iter()
+                .map(|p| (**p.iri()).clone()),
+        )
+        .chain(
+            ontology
+                .data_properties()
+                .iter()
+                .map(|p| (**p.iri()).clone()),
+        )
+        .chain(
+            ontology
+                .annotation_properties()
+                .iter()
+                .map(|p| (**p.iri()).clone()),
+        )
+        .chain(
+            ontology
+                .named_individuals()
+                .iter()
+                .map(|i| (**i.iri()).clone()),
+        )
+        .collect();
+    let axioms = ontology.axioms().iter().map(|a| (**a).clone()).collect();
+    (ontology.iri().cloned(), entities, axioms)
+}
+
+#[test]
+fn test_round_trip_through_turtle_parser() {
+    let parser = TurtleParser::new();
+    let original = parser.parse_str(FAMILY).unwrap();
+
+    let turtle = TurtleSerializer::new()
+        .serialize_ontology(&original)
+        .unwrap();
+    let reparsed = parser.parse_str(&turtle).unwrap();
+
+    assert_eq!(contents(&reparsed), contents(&original), "{}", turtle);
+    assert!(turtle.contains("rdfs:subClassOf [ a owl:Restriction ;"));
+    assert!(turtle.contains("\"42\"^^xsd:integer"));
+    assert!(turtle.contains("\"Bob\"@en"));
+}
+
+#[test]
+fn test_output_is_stable() {
+    let parser = TurtleParser::new();
+    let serializer = TurtleSerializer::new();
+    let first = serializer
+        .serialize_ontology(&parser.parse_str(FAMILY).unwrap())
+        .unwrap();
+    let second = serializer
+        .serialize_ontology(&parser.parse_str(&first).unwrap())
+        .unwrap();
+
+    assert_eq!(first, second);
+    // Declarations come before the axioms that use them
+    assert!(first.find(" a owl:Class .").unwrap() < first.find("rdfs:subClassOf").unwrap());
+}
+
+#[test]
+fn test_configured_prefixes_abbreviate_iris() {
+    let mut ontology = Ontology::new();
+    let iri = |name: &str| IRI::new(format!("http://example.org/family#{}", name)).unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::Class(Class::new(iri("Mother"))),
+            ClassExpression::Class(Class::new(iri("Woman"))),
+        ))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(IRI::new("http://other.org/people/carol").unwrap()),
+            ClassExpression::Class(Class::new(iri("Mother"))),
+        ))
+        .unwrap();
+
+    // Without a matching prefix the IRIs are written in full
+    let turtle = TurtleSerializer::new()
+        .serialize_ontology(&ontology)
+        .unwrap();
+    assert!(turtle.contains(
+        "<http://example.org/family#Mother> rdfs:subClassOf <http://example.org/family#Woman> ."
+    ));
+    assert!(turtle.starts_with("@prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n"));
+    assert!(!turtle.contains("@prefix owl:"));
+
+    let config = ParserConfig {
+        prefixes: HashMap::from([("fam".to_string(), "http://example.org/family#".to_string())]),
+        ..Default::default()
+    };
+    let turtle = TurtleSerializer::with_config(config)
+        .serialize_ontology(&ontology)
+        .unwrap();
+    assert!(turtle.contains("@prefix fam: <http://example.org/family#> .\n"));
+    assert!(turtle.contains("fam:Mother rdfs:subClassOf fam:Woman ."));
+    assert!(turtle.contains("<http://other.org/people/carol> a fam:Mother ."));
+}
+
+#[test]
+fn test_anonymous_class_expressions_are_written_as_blank_nodes() {
+    let iri = |name: &str| IRI::new(format!("http://example.org/family#{}", name)).unwrap();
+    let class = |name: &str| ClassExpression::Class(Class::new(iri(name)));
+    let has_child = || {
+        Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+            ObjectProperty::new(iri("hasChild")),
+        )))
+    };
+
+    let mut ontology = Ontology::new();
+    ontology
+        .add_equivalent_classes_axiom(EquivalentClassesAxiom::from_expressions(vec![
+            class("Parent"),
+            ClassExpression::ObjectIntersectionOf(
+                vec![
+                    Arc::new(class("Person")),
+                    Arc::new(ClassExpression::ObjectSomeValuesFrom(
+                        has_child(),
+                        Arc::new(class("Person")),
+                    )),
+                ]
+                .into(),
+            ),
+        ]))
+        .unwrap();
+    ontology
+        .add_class_assertion(ClassAssertionAxiom::new(
+            Arc::new(iri("carol")),
+            ClassExpression::ObjectSomeValuesFrom(
+                has_child(),
+                Arc::new(ClassExpression::ObjectUnionOf(
+                    vec![Arc::new(class("Man")), Arc::new(class("Woman"))].into(),
+                )),
+            ),
+        ))
+        .unwrap();
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            ClassExpression::ObjectComplementOf(Arc::new(class("Parent"))),
+            ClassExpression::ObjectMaxCardinality(0, has_child()),
+        ))
+        .unwrap();
+
+    let config = ParserConfig {
+        prefixes: HashMap::from([(String::new(), "http://example.org/family#".to_string())]),
+        ..Default::default()
+    };
+    let turtle = TurtleSerializer::with_config(config)
+        .serialize_ontology(&ontology)
+        .unwrap();
+    for line in [
+        ":Parent owl:equivalentClass [ a owl:Class ; owl:intersectionOf ( :Person \
+         [ a owl:Restriction ; owl:onProperty :hasChild ; owl:someValuesFrom :Person ] ) ] .",
+        ":carol a [ a owl:Restriction ; owl:onProperty :hasChild ; owl:someValuesFrom \
+         [ a owl:Class ; owl:unionOf ( :Man :Woman ) ] ] .",
+        "[ a owl:Class ; owl:complementOf :Parent ] rdfs:subClassOf [ a owl:Restriction ; \
+         owl:onProperty :hasChild ; owl:maxCardinality \"0\"^^xsd:nonNegativeInteger ] .",
+    ] {
+        assert!(turtle.contains(line), "{} missing from\n{}", line, turtle);
+    }
+    assert!(turtle.contains("@prefix xsd: <http://www.w3.org/2001/XMLSchema#> .\n"));
+}
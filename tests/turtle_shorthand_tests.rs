@@ -0,0 +1,165 @@
+//! Tests for Turtle's `a`, blank node and collection shorthand
+
+use owl2_reasoner::axioms::{Axiom, CollectionAxiom, CollectionItem};
+use owl2_reasoner::parser::{OntologyParser, TurtleParser};
+use owl2_reasoner::*;
+
+const PREFIXES: &str = "@prefix : <http://example.org/> .\n\
+                        @prefix owl: <http://www.w3.org/2002/07/owl#> .\n";
+
+fn parse(body: &str) -> Ontology {
+    TurtleParser::new()
+        .parse_str(&format!("{}{}", PREFIXES, body))
+        .unwrap()
+}
+
+fn iri(name: &str) -> String {
+    format!("http://example.org/{}", name)
+}
+
+fn collections(ontology: &Ontology) -> Vec<&CollectionAxiom> {
+    ontology
+        .axioms()
+        .iter()
+        .filter_map(|axiom| match axiom.as_ref() {
+            Axiom::Collection(collection) => Some(collection.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_a_gives_class_assertions() {
+    let ontology = parse(":Person a owl:Class .\n:alice a :Person , :Employee .\n");
+
+    assert_eq!(ontology.classes().len(), 1);
+    let mut types: Vec<(String, String)> = ontology
+        .class_assertions()
+        .iter()
+        .map(|assertion| {
+            (
+                assertion.individual().to_string(),
+                format!("{:?}", assertion.class_expr()),
+            )
+        })
+        .collect();
+    types.sort();
+    assert_eq!(types.len(), 2);
+    assert!(types
+        .iter()
+        .all(|(individual, _)| *individual == iri("alice")));
+    assert!(types[0].1.contains(&iri("Employee")));
+    assert!(types[1].1.contains(&iri("Person")));
+}
+
+#[test]
+fn test_blank_node_object_gives_anonymous_individual() {
+    let ontology = parse(":alice :knows [ a :Person ; :name \"Bob\" ; :likes :cake , :tea ] .\n");
+
+    // alice is linked to a fresh anonymous individual
+    let links: Vec<_> = ontology
+        .property_assertions()
+        .into_iter()
+        .filter(|assertion| assertion.property().as_str() == iri("knows"))
+        .collect();
+    assert_eq!(links.len(), 1);
+    assert_eq!(links[0].subject().as_str(), iri("alice"));
+    let node = links[0].object_anonymous().unwrap().node_id().to_string();
+    assert_eq!(ontology.anonymous_individuals().len(), 1);
+    let node_subject = format!("http://blank.node/{}", node);
+
+    // Every triple inside the brackets is about that individual
+    let assertion = &ontology.class_assertions()[0];
+    assert_eq!(assertion.individual().as_str(), node_subject);
+    assert!(format!("{:?}", assertion.class_expr()).contains(&iri("Person")));
+
+    let names = ontology.data_property_assertions();
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].subject().as_str(), node_subject);
+    assert_eq!(names[0].value().lexical_form(), "Bob");
+
+    let mut likes: Vec<String> = ontology
+        .property_assertions()
+        .into_iter()
+        .filter(|assertion| assertion.property().as_str() == iri("likes"))
+        .inspect(|assertion| assert_eq!(assertion.subject().as_str(), node_subject))
+        .map(|assertion| assertion.object_iri().unwrap().to_string())
+        .collect();
+    likes.sort();
+    assert_eq!(likes, vec![iri("cake"), iri("tea")]);
+}
+
+#[test]
+fn test_nested_and_multi_line_blank_nodes() {
+    let ontology = parse(
+        ":alice :knows [\n\
+             a :Person ;\n\
+             :worksFor [ :name \"ACME\" ]\n\
+         ] ;\n\
+         :age 42 .\n\
+         :bob :knows [ :name \"Carol\" ] .\n",
+    );
+
+    // Each bracket pair is its own anonymous individual
+    assert_eq!(ontology.anonymous_individuals().len(), 3);
+    assert_eq!(ontology.class_assertions().len(), 1);
+    let mut values: Vec<String> = ontology
+        .data_property_assertions()
+        .iter()
+        .map(|assertion| assertion.value().lexical_form().to_string())
+        .collect();
+    values.sort();
+    assert_eq!(values, vec!["42", "ACME", "Carol"]);
+
+    // The statement after the brackets keeps alice as its subject
+    let age = ontology
+        .data_property_assertions()
+        .into_iter()
+        .find(|assertion| assertion.property().as_str() == iri("age"))
+        .unwrap();
+    assert_eq!(age.subject().as_str(), iri("alice"));
+}
+
+#[test]
+fn test_collection_gives_collection_axiom() {
+    let ontology = parse(":team :members ( :alice :bob _:carol \"Dave\" ) .\n");
+
+    let collections = collections(&ontology);
+    assert_eq!(collections.len(), 1);
+    let collection = collections[0];
+    assert_eq!(collection.subject().as_str(), iri("team"));
+    assert_eq!(collection.property().as_str(), iri("members"));
+    assert_eq!(collection.len(), 4);
+    assert!(
+        matches!(&collection.items()[0], CollectionItem::Named(item) if item.as_str() == iri("alice"))
+    );
+    assert!(
+        matches!(&collection.items()[1], CollectionItem::Named(item) if item.as_str() == iri("bob"))
+    );
+    assert!(matches!(
+        &collection.items()[2],
+        CollectionItem::Anonymous(_)
+    ));
+    assert!(matches!(&collection.items()[3], CollectionItem::Literal(_)));
+}
+
+#[test]
+fn test_multi_line_collection() {
+    let ontology = parse(
+        ":team :members (\n\
+             :alice  # the lead\n\
+             :bob\n\
+         ) .\n",
+    );
+
+    let collections = collections(&ontology);
+    assert_eq!(collections.len(), 1);
+    assert_eq!(collections[0].len(), 2);
+}
+
+#[test]
+fn test_unclosed_blank_node_is_rejected() {
+    let result =
+        TurtleParser::new().parse_str(&format!("{}:alice :knows [ :name \"Bob\" .\n", PREFIXES));
+    assert!(result.is_err());
+}
@@ -0,0 +1,69 @@
+//! Tests for universal restrictions reaching successors created after them
+
+use owl2_reasoner::*;
+use std::sync::Arc;
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/{}", name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn r() -> Box<ObjectPropertyExpression> {
+    Box::new(ObjectPropertyExpression::ObjectProperty(Box::new(
+        ObjectProperty::new(iri("R")),
+    )))
+}
+
+fn all(filler: ClassExpression) -> ClassExpression {
+    ClassExpression::ObjectAllValuesFrom(r(), Arc::new(filler))
+}
+
+fn and(conjuncts: Vec<ClassExpression>) -> ClassExpression {
+    ClassExpression::ObjectIntersectionOf(conjuncts.into_iter().map(Arc::new).collect())
+}
+
+#[test]
+fn test_universal_applies_to_successor_of_later_existential() {
+    // The universal comes first, before the existential creates the successor
+    let concept = and(vec![
+        all(class("C")),
+        ClassExpression::ObjectSomeValuesFrom(r(), Arc::new(class("D"))),
+    ]);
+    let mut reasoner = OwlReasoner::new(Ontology::new());
+    let model = reasoner.satisfiability_model(&concept).unwrap().unwrap();
+
+    let root = &model.individuals[0];
+    let successors = model.successors(root.node);
+    assert_eq!(successors.len(), 1);
+    let successor = model.individual(successors[0].1).unwrap();
+    assert!(successor.is_instance_of(&iri("C")));
+    assert!(successor.is_instance_of(&iri("D")));
+
+    // ∀R.¬D ⊓ ∃R.D clashes on the successor
+    let clash = and(vec![
+        all(ClassExpression::ObjectComplementOf(Arc::new(class("D")))),
+        ClassExpression::ObjectSomeValuesFrom(r(), Arc::new(class("D"))),
+    ]);
+    assert_eq!(reasoner.satisfiability_model(&clash).unwrap(), None);
+}
+
+#[test]
+fn test_universal_applies_to_cardinality_successors() {
+    let concept = and(vec![
+        all(and(vec![class("C"), class("E")])),
+        ClassExpression::ObjectMinCardinality(2, r()),
+    ]);
+    let mut reasoner = OwlReasoner::new(Ontology::new());
+    let model = reasoner.satisfiability_model(&concept).unwrap().unwrap();
+
+    let successors = model.successors(model.individuals[0].node);
+    assert_eq!(successors.len(), 2);
+    for (_, node) in successors {
+        let successor = model.individual(node).unwrap();
+        assert!(successor.is_instance_of(&iri("C")));
+        assert!(successor.is_instance_of(&iri("E")));
+    }
+}
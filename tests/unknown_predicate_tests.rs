@@ -0,0 +1,152 @@
+//! Tests for the handling of triples with unknown predicates
+
+use owl2_reasoner::parser::{
+    NtriplesParser, OntologyParser, ParserConfig, RdfXmlParser, TurtleParser,
+    UnknownPredicatePolicy,
+};
+use owl2_reasoner::*;
+
+const EX: &str = "http://example.org/family#";
+
+const TURTLE: &str = r#"@prefix owl: <http://www.w3.org/2002/07/owl#> .
+@prefix ex: <http://example.org/family#> .
+
+ex:alice ex:hasParent ex:bob .
+ex:alice ex:nickname "Ally" .
+ex:alice ex:favouriteColour ex:green .
+ex:hasParent a owl:ObjectProperty .
+"#;
+
+fn config(unknown_predicate: UnknownPredicatePolicy) -> ParserConfig {
+    ParserConfig {
+        unknown_predicate,
+        ..Default::default()
+    }
+}
+
+fn parse_turtle(policy: UnknownPredicatePolicy) -> OwlResult<Ontology> {
+    TurtleParser::with_config(config(policy)).parse_str(TURTLE)
+}
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn uses_property(ontology: &Ontology, name: &str) -> bool {
+    let property = iri(name);
+    ontology
+        .property_assertions()
+        .iter()
+        .any(|assertion| **assertion.property() == property)
+        || ontology
+            .data_property_assertions()
+            .iter()
+            .any(|assertion| **assertion.property() == property)
+}
+
+fn annotations(ontology: &Ontology, name: &str) -> Vec<AnnotationValue> {
+    let property = iri(name);
+    ontology
+        .annotation_assertion_axioms()
+        .iter()
+        .filter(|assertion| **assertion.annotation_property() == property)
+        .map(|assertion| assertion.value().clone())
+        .collect()
+}
+
+#[test]
+fn test_default_keeps_unknown_predicates_as_property_assertions() {
+    let ontology = parse_turtle(UnknownPredicatePolicy::default()).unwrap();
+
+    assert!(uses_property(&ontology, "hasParent"));
+    assert!(uses_property(&ontology, "nickname"));
+    assert!(uses_property(&ontology, "favouriteColour"));
+    assert!(ontology.annotation_assertion_axioms().is_empty());
+}
+
+#[test]
+fn test_ignore_drops_unknown_predicates() {
+    let ontology = parse_turtle(UnknownPredicatePolicy::Ignore).unwrap();
+
+    // Declared later in the document, so still a property
+    assert!(uses_property(&ontology, "hasParent"));
+    assert!(!uses_property(&ontology, "nickname"));
+    assert!(!uses_property(&ontology, "favouriteColour"));
+    assert!(ontology.annotation_assertion_axioms().is_empty());
+}
+
+#[test]
+fn test_unknown_predicates_as_annotations() {
+    let ontology = parse_turtle(UnknownPredicatePolicy::AsAnnotation).unwrap();
+
+    assert!(uses_property(&ontology, "hasParent"));
+    assert!(!uses_property(&ontology, "nickname"));
+    assert_eq!(
+        annotations(&ontology, "nickname"),
+        vec![AnnotationValue::Literal(Literal::simple("Ally"))]
+    );
+    assert_eq!(
+        annotations(&ontology, "favouriteColour"),
+        vec![AnnotationValue::from(iri("green"))]
+    );
+}
+
+#[test]
+fn test_error_rejects_unknown_predicates() {
+    let error = parse_turtle(UnknownPredicatePolicy::Error).unwrap_err();
+
+    assert!(matches!(error, OwlError::ValidationError(_)), "{:?}", error);
+    assert!(error.to_string().contains("nickname"), "{}", error);
+}
+
+#[test]
+fn test_ntriples_follows_the_policy() {
+    let document = r#"<http://example.org/family#alice> <http://example.org/family#nickname> "Ally" .
+<http://example.org/family#alice> <http://example.org/family#favouriteColour> <http://example.org/family#green> .
+"#;
+
+    let ignored = NtriplesParser::with_config(config(UnknownPredicatePolicy::Ignore))
+        .parse_str(document)
+        .unwrap();
+    assert!(!uses_property(&ignored, "nickname"));
+    assert!(!uses_property(&ignored, "favouriteColour"));
+
+    let annotated = NtriplesParser::with_config(config(UnknownPredicatePolicy::AsAnnotation))
+        .parse_str(document)
+        .unwrap();
+    assert_eq!(annotations(&annotated, "nickname").len(), 1);
+    assert_eq!(annotations(&annotated, "favouriteColour").len(), 1);
+
+    assert!(
+        NtriplesParser::with_config(config(UnknownPredicatePolicy::Error))
+            .parse_str(document)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_rdf_xml_follows_the_policy() {
+    let document = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:ex="http://example.org/family#">
+  <rdf:Description rdf:about="http://example.org/family#alice">
+    <ex:nickname>Ally</ex:nickname>
+  </rdf:Description>
+</rdf:RDF>
+"#;
+
+    let default = RdfXmlParser::new().parse_str(document).unwrap();
+    assert!(uses_property(&default, "nickname"));
+
+    let annotated = RdfXmlParser::with_config(config(UnknownPredicatePolicy::AsAnnotation))
+        .parse_str(document)
+        .unwrap();
+    assert!(!uses_property(&annotated, "nickname"));
+    assert_eq!(annotations(&annotated, "nickname").len(), 1);
+
+    assert!(
+        RdfXmlParser::with_config(config(UnknownPredicatePolicy::Error))
+            .parse_str(document)
+            .is_err()
+    );
+}
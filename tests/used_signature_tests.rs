@@ -0,0 +1,86 @@
+//! Tests for computing the signature an ontology's axioms use
+
+use owl2_reasoner::*;
+use smallvec::smallvec;
+use std::sync::Arc;
+
+const EX: &str = "http://example.org/pizza#";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+
+fn iri(name: &str) -> IRI {
+    IRI::new(format!("{}{}", EX, name)).unwrap()
+}
+
+fn class(name: &str) -> ClassExpression {
+    ClassExpression::Class(Class::new(iri(name)))
+}
+
+fn pizza() -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.add_class(Class::new(iri("Pizza"))).unwrap();
+    ontology.add_class(Class::new(iri("Unused"))).unwrap();
+
+    // Pizza ⊑ ∃hasTopping.(Cheese ⊓ ∃hasOrigin.{italy}) ⊓ ∃hasCalories.xsd:integer
+    let origin = ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectProperty::new(iri("hasOrigin")).into()),
+        Arc::new(ClassExpression::ObjectOneOf(Box::new(smallvec![
+            Individual::Named(NamedIndividual::new(iri("italy")))
+        ]))),
+    );
+    let topping = ClassExpression::ObjectSomeValuesFrom(
+        Box::new(ObjectProperty::new(iri("hasTopping")).into()),
+        Arc::new(ClassExpression::ObjectIntersectionOf(smallvec![
+            Arc::new(class("Cheese")),
+            Arc::new(origin),
+        ])),
+    );
+    let calories = ClassExpression::DataSomeValuesFrom(
+        Box::new(DataProperty::new(iri("hasCalories")).into()),
+        Box::new(DataRange::Datatype(IRI::new(XSD_INTEGER).unwrap())),
+    );
+    ontology
+        .add_subclass_axiom(SubClassOfAxiom::new(
+            class("Pizza"),
+            ClassExpression::ObjectIntersectionOf(smallvec![Arc::new(topping), Arc::new(calories)]),
+        ))
+        .unwrap();
+    ontology
+        .add_axiom(Axiom::AnnotationAssertion(Box::new(
+            AnnotationAssertionAxiom::new(
+                Arc::new(IRI::new("http://www.w3.org/2000/01/rdf-schema#label").unwrap()),
+                Arc::new(iri("Pizza")),
+                AnnotationValue::Literal(Literal::simple("Pizza")),
+            ),
+        )))
+        .unwrap();
+    ontology
+}
+
+#[test]
+fn test_entities_in_nested_restrictions_are_used() {
+    let signature = pizza().used_signature();
+
+    assert!(signature.classes.contains(&iri("Cheese")));
+    assert!(signature.object_properties.contains(&iri("hasOrigin")));
+    assert!(signature.individuals.contains(&iri("italy")));
+    assert!(signature.data_properties.contains(&iri("hasCalories")));
+    assert!(signature
+        .datatypes
+        .contains(&IRI::new(XSD_INTEGER).unwrap()));
+    assert_eq!(signature.len(), 7);
+}
+
+#[test]
+fn test_declared_but_unused_entities_are_left_out() {
+    let ontology = pizza();
+    let signature = ontology.used_signature();
+
+    assert!(ontology
+        .classes()
+        .iter()
+        .any(|c| **c.iri() == iri("Unused")));
+    assert!(!signature.contains(&iri("Unused")));
+    // Annotations carry no logical vocabulary
+    assert!(!signature.contains(&IRI::new("http://www.w3.org/2000/01/rdf-schema#label").unwrap()));
+    assert!(Ontology::new().used_signature().is_empty());
+}
@@ -0,0 +1,115 @@
+//! Tests for imports pinned to a version IRI
+
+use owl2_reasoner::parser::{ImportResolverConfig, ImportSource};
+use owl2_reasoner::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const ANIMALS: &str = "memory://example.org/animals";
+const ANIMALS_V1: &str = "memory://example.org/animals/1.0";
+const ANIMALS_V2: &str = "memory://example.org/animals/2.0";
+
+/// Serves ontologies from memory and remembers which IRIs were requested
+struct MemorySource {
+    ontologies: HashMap<String, Ontology>,
+    requested: Arc<Mutex<Vec<String>>>,
+}
+
+impl ImportSource for MemorySource {
+    fn can_resolve(&self, iri: &IRI) -> bool {
+        iri.as_str().starts_with("memory://")
+    }
+
+    fn resolve(&self, iri: &IRI, _config: &ImportResolverConfig) -> OwlResult<Ontology> {
+        self.requested.lock().unwrap().push(iri.to_string());
+        self.ontologies
+            .get(iri.as_str())
+            .cloned()
+            .ok_or_else(|| OwlError::ImportResolutionError {
+                iri: iri.clone(),
+                message: "not found".to_string(),
+            })
+    }
+
+    fn name(&self) -> &'static str {
+        "Memory"
+    }
+}
+
+fn animals(version: &str, class: &str) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.set_iri(ANIMALS);
+    ontology.set_version_iri(version);
+    ontology
+        .add_class(Class::new(format!("http://example.org/animals#{}", class)))
+        .unwrap();
+    ontology
+}
+
+fn resolver(ontologies: Vec<(&str, Ontology)>) -> (ImportResolver, Arc<Mutex<Vec<String>>>) {
+    let requested = Arc::new(Mutex::new(Vec::new()));
+    let mut resolver = ImportResolver::new().unwrap();
+    resolver.add_source(Box::new(MemorySource {
+        ontologies: ontologies
+            .into_iter()
+            .map(|(iri, ontology)| (iri.to_string(), ontology))
+            .collect(),
+        requested: Arc::clone(&requested),
+    }));
+    (resolver, requested)
+}
+
+fn importing(version: &str) -> Ontology {
+    let mut ontology = Ontology::new();
+    ontology.set_iri("http://example.org/zoo");
+    let import = ImportAxiom::new(Arc::new(IRI::new(ANIMALS).unwrap()))
+        .with_version_iri(Arc::new(IRI::new(version).unwrap()));
+    ontology.add_axiom(Axiom::Import(import)).unwrap();
+    ontology
+}
+
+fn has_class(ontology: &Ontology, name: &str) -> bool {
+    let iri = IRI::new(format!("http://example.org/animals#{}", name)).unwrap();
+    ontology.classes().iter().any(|class| **class.iri() == iri)
+}
+
+#[test]
+fn test_import_axiom_version_iri() {
+    let import = ImportAxiom::new(Arc::new(IRI::new(ANIMALS).unwrap()));
+    assert_eq!(import.version_iri(), None);
+    assert_eq!(import.resolution_iri().as_str(), ANIMALS);
+
+    let import = import.with_version_iri(Arc::new(IRI::new(ANIMALS_V1).unwrap()));
+    assert_eq!(import.version_iri().unwrap().as_str(), ANIMALS_V1);
+    assert_eq!(import.resolution_iri().as_str(), ANIMALS_V1);
+    assert_eq!(import.imported_ontology().as_str(), ANIMALS);
+}
+
+#[test]
+fn test_resolver_fetches_the_pinned_version() {
+    let (mut resolver, requested) = resolver(vec![
+        (ANIMALS, animals(ANIMALS_V2, "Dog")),
+        (ANIMALS_V1, animals(ANIMALS_V1, "Cat")),
+        (ANIMALS_V2, animals(ANIMALS_V2, "Dog")),
+    ]);
+    let mut ontology = importing(ANIMALS_V1);
+    resolver.resolve_imports(&mut ontology).unwrap();
+
+    assert_eq!(*requested.lock().unwrap(), vec![ANIMALS_V1.to_string()]);
+    assert!(has_class(&ontology, "Cat"));
+    assert!(!has_class(&ontology, "Dog"));
+}
+
+#[test]
+fn test_resolver_falls_back_to_the_ontology_iri() {
+    let (mut resolver, requested) = resolver(vec![(ANIMALS, animals(ANIMALS_V2, "Dog"))]);
+    let mut ontology = importing(ANIMALS_V1);
+    resolver.resolve_imports(&mut ontology).unwrap();
+
+    assert_eq!(
+        *requested.lock().unwrap(),
+        vec![ANIMALS_V1.to_string(), ANIMALS.to_string()]
+    );
+    assert!(has_class(&ontology, "Dog"));
+    assert_eq!(resolver.stats().failed_resolutions, 0);
+}
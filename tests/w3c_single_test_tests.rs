@@ -0,0 +1,47 @@
+//! Tests for running a single conformance test case by IRI
+
+use owl2_reasoner::validation::{
+    bundled_test_cases, run_w3c_test, run_w3c_test_case, EarlOutcome, TestStatus, W3CTestCase,
+    W3CTestType, BUNDLED_TEST_NAMESPACE,
+};
+
+#[test]
+fn test_bundled_positive_entailment_passes() {
+    let iri = format!("{}subclass-transitivity", BUNDLED_TEST_NAMESPACE);
+    let outcome = run_w3c_test(&iri).unwrap();
+
+    assert_eq!(outcome.test, iri);
+    assert_eq!(outcome.status, TestStatus::Passed, "{:?}", outcome.message);
+    assert!(outcome.passed());
+    assert_eq!(EarlOutcome::from(&outcome), EarlOutcome::Passed);
+}
+
+#[test]
+fn test_every_bundled_test_passes() {
+    for test in bundled_test_cases() {
+        let outcome = run_w3c_test(&test.iri).unwrap();
+        assert!(outcome.passed(), "{}: {:?}", test.iri, outcome.message);
+    }
+}
+
+#[test]
+fn test_wrong_expectations_fail_and_bad_input_errors() {
+    assert!(run_w3c_test("http://example.org/no-such-test").is_err());
+
+    let prefixes = "@prefix : <http://example.org/t#> .\n\
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .\n";
+    let mut test = W3CTestCase {
+        iri: "http://example.org/t#wrong".to_string(),
+        test_type: W3CTestType::PositiveEntailment,
+        premise: format!("{}:A rdfs:subClassOf :B .\n", prefixes),
+        conclusion: Some(format!("{}:B rdfs:subClassOf :A .\n", prefixes)),
+    };
+    let outcome = run_w3c_test_case(&test);
+    assert_eq!(outcome.status, TestStatus::Failed);
+    assert!(outcome.message.is_some());
+
+    test.conclusion = Some("not an ontology".to_string());
+    let outcome = run_w3c_test_case(&test);
+    assert_eq!(outcome.status, TestStatus::Error);
+    assert_eq!(EarlOutcome::from(&outcome), EarlOutcome::CantTell);
+}
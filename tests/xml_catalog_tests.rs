@@ -0,0 +1,64 @@
+//! Tests for resolving imports to local files through an OASIS XML catalog
+
+use owl2_reasoner::parser::XmlCatalogResolver;
+use owl2_reasoner::*;
+use std::path::{Path, PathBuf};
+
+fn catalog_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/catalog")
+}
+
+fn catalog() -> XmlCatalogResolver {
+    XmlCatalogResolver::from_file(catalog_dir().join("catalog-v001.xml")).unwrap()
+}
+
+fn zoo(name: &str) -> IRI {
+    IRI::new(format!("http://example.org/zoo#{}", name)).unwrap()
+}
+
+#[test]
+fn test_catalog_maps_iris_to_local_files() {
+    let catalog = catalog();
+    assert_eq!(catalog.len(), 2);
+
+    let animals = IRI::new("http://example.org/ontologies/animals").unwrap();
+    assert_eq!(
+        catalog.resolve_path(&animals),
+        Some(catalog_dir().join("animals.ttl"))
+    );
+    // Rewrites are relative to the xml:base of their group
+    let plants = IRI::new("http://example.org/mirror/plants.ttl").unwrap();
+    assert!(catalog
+        .resolve_path(&plants)
+        .is_some_and(|path| path.ends_with("plants.ttl") && path.exists()));
+    let unknown = IRI::new("http://example.org/ontologies/unknown").unwrap();
+    assert_eq!(catalog.resolve_path(&unknown), None);
+}
+
+#[test]
+fn test_import_resolves_through_catalog_without_network() {
+    let mut ontology = Ontology::new();
+    ontology.add_import(IRI::new("http://example.org/ontologies/animals").unwrap());
+    ontology.add_import(IRI::new("http://example.org/mirror/plants.ttl").unwrap());
+
+    let mut resolver = ImportResolver::new().unwrap();
+    resolver.add_catalog(catalog());
+    assert!(resolver.resolve_imports(&mut ontology).unwrap().is_empty());
+
+    assert_eq!(resolver.stats().failed_resolutions, 0);
+    let mut reasoner = OwlReasoner::new(ontology);
+    assert!(reasoner
+        .is_subclass_of(&zoo("Dog"), &zoo("Mammal"))
+        .unwrap());
+    assert!(reasoner
+        .is_subclass_of(&zoo("Fern"), &zoo("Plant"))
+        .unwrap());
+}
+
+#[test]
+fn test_catalog_rejects_other_documents() {
+    assert!(matches!(
+        XmlCatalogResolver::parse("<rdf:RDF xmlns:rdf=\"urn:x\"/>", "."),
+        Err(OwlError::ParseError(_))
+    ));
+}
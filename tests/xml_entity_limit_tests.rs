@@ -0,0 +1,153 @@
+//! Tests for XML entity expansion and nesting limits in the XML-based parsers
+
+use owl2_reasoner::parser::{OntologyParser, OwlXmlParser, ParserConfig, RdfXmlParser};
+use owl2_reasoner::OwlError;
+use std::time::{Duration, Instant};
+
+const BILLION_LAUGHS: &str = r#"<?xml version="1.0"?>
+<!DOCTYPE rdf:RDF [
+  <!ENTITY lol "lol">
+  <!ENTITY lol1 "&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;">
+  <!ENTITY lol2 "&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;&lol1;">
+  <!ENTITY lol3 "&lol2;&lol2;&lol2;&lol2;&lol2;&lol2;&lol2;&lol2;&lol2;&lol2;">
+  <!ENTITY lol4 "&lol3;&lol3;&lol3;&lol3;&lol3;&lol3;&lol3;&lol3;&lol3;&lol3;">
+  <!ENTITY lol5 "&lol4;&lol4;&lol4;&lol4;&lol4;&lol4;&lol4;&lol4;&lol4;&lol4;">
+  <!ENTITY lol6 "&lol5;&lol5;&lol5;&lol5;&lol5;&lol5;&lol5;&lol5;&lol5;&lol5;">
+  <!ENTITY lol7 "&lol6;&lol6;&lol6;&lol6;&lol6;&lol6;&lol6;&lol6;&lol6;&lol6;">
+  <!ENTITY lol8 "&lol7;&lol7;&lol7;&lol7;&lol7;&lol7;&lol7;&lol7;&lol7;&lol7;">
+  <!ENTITY lol9 "&lol8;&lol8;&lol8;&lol8;&lol8;&lol8;&lol8;&lol8;&lol8;&lol8;">
+]>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+  <owl:Class rdf:about="http://example.org/Laugh">
+    <rdfs:label>&lol9;</rdfs:label>
+  </owl:Class>
+</rdf:RDF>"#;
+
+fn assert_limit_error(result: Result<impl std::fmt::Debug, OwlError>, expected: &str) {
+    match result {
+        Err(OwlError::ParseError(message)) => assert!(
+            message.contains(expected),
+            "unexpected parse error message: {}",
+            message
+        ),
+        other => panic!("expected a ParseError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rdf_xml_rejects_billion_laughs() {
+    let parser = RdfXmlParser::new();
+    let start = Instant::now();
+    let result = parser.parse_str(BILLION_LAUGHS);
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_limit_error(result, "entity expansion limit");
+}
+
+#[test]
+fn test_owl_xml_rejects_billion_laughs() {
+    let parser = OwlXmlParser::new();
+    assert_limit_error(parser.parse_str(BILLION_LAUGHS), "entity expansion limit");
+}
+
+#[test]
+fn test_recursive_entity_is_rejected() {
+    let content = r#"<?xml version="1.0"?>
+<!DOCTYPE Ontology [
+  <!ENTITY a "&b;">
+  <!ENTITY b "&a;">
+]>
+<Ontology xmlns="http://www.w3.org/2002/07/owl#">&a;</Ontology>"#;
+    let parser = OwlXmlParser::new();
+    assert_limit_error(parser.parse_str(content), "recursive entity");
+}
+
+#[test]
+fn test_nesting_depth_limit_is_configurable() {
+    let mut content =
+        String::from(r#"<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">"#);
+    content.push_str(&"<rdf:Description>".repeat(20));
+    content.push_str(&"</rdf:Description>".repeat(20));
+    content.push_str("</rdf:RDF>");
+
+    let config = ParserConfig {
+        max_xml_nesting_depth: 10,
+        ..Default::default()
+    };
+    let parser = RdfXmlParser::with_config(config);
+    assert_limit_error(parser.parse_str(&content), "nesting depth limit");
+}
+
+#[test]
+fn test_small_entity_use_within_limits() {
+    let content = r#"<?xml version="1.0"?>
+<!DOCTYPE rdf:RDF [
+  <!ENTITY ex "http://example.org/">
+]>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+  <owl:Class rdf:about="&ex;Person"/>
+</rdf:RDF>"#;
+    let parser = RdfXmlParser::new();
+    assert!(parser.parse_str(content).is_ok());
+}
+
+#[test]
+fn test_many_plain_text_entity_references_parse() {
+    let mut content = String::from(
+        r#"<?xml version="1.0"?>
+<!DOCTYPE rdf:RDF [
+  <!ENTITY obo "http://purl.obolibrary.org/obo/">
+  <!ENTITY owl "http://www.w3.org/2002/07/owl#">
+]>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+"#,
+    );
+    // Far more references than the default max_entity_expansions
+    for i in 0..6_000 {
+        content.push_str(&format!(
+            "  <owl:Class rdf:about=\"&obo;GO_{0}\"><rdfs:subClassOf rdf:resource=\"&owl;Thing\"/></owl:Class>\n",
+            i
+        ));
+    }
+    content.push_str("</rdf:RDF>");
+
+    let ontology = RdfXmlParser::new().parse_str(&content).unwrap();
+    assert!(ontology
+        .classes()
+        .iter()
+        .any(|class| class.iri().as_str() == "http://purl.obolibrary.org/obo/GO_5999"));
+}
+
+#[test]
+fn test_expanded_bytes_are_limited() {
+    let big = "x".repeat(1_000);
+    let content = format!(
+        r#"<?xml version="1.0"?>
+<!DOCTYPE rdf:RDF [
+  <!ENTITY big "{}">
+]>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#">
+  <owl:Class rdf:about="http://example.org/Big">
+    <rdfs:label>{}</rdfs:label>
+  </owl:Class>
+</rdf:RDF>"#,
+        big,
+        "&big;".repeat(100)
+    );
+
+    let config = ParserConfig {
+        max_entity_expanded_bytes: 50_000,
+        ..Default::default()
+    };
+    assert_limit_error(
+        RdfXmlParser::with_config(config).parse_str(&content),
+        "entity expansion limit",
+    );
+    assert!(RdfXmlParser::new().parse_str(&content).is_ok());
+}
@@ -0,0 +1,88 @@
+//! Tests for `xml:lang` inheritance in RDF/XML parsing
+
+use owl2_reasoner::parser::{OntologyParser, ParserConfig, RdfXmlParser};
+use owl2_reasoner::*;
+use std::collections::HashSet;
+
+const PETS: &str = r#"<?xml version="1.0"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#"
+         xmlns:rdfs="http://www.w3.org/2000/01/rdf-schema#"
+         xmlns:owl="http://www.w3.org/2002/07/owl#"
+         xmlns:ex="http://example.org/pets#"
+         xml:lang="en">
+    <owl:Ontology rdf:about="http://example.org/pets">
+        <rdfs:label>Pets</rdfs:label>
+    </owl:Ontology>
+    <owl:Class rdf:about="http://example.org/pets#Dog"/>
+    <owl:NamedIndividual rdf:about="http://example.org/pets#rex">
+        <ex:nickname>Rex</ex:nickname>
+        <ex:nickname xml:lang="fr">Roi</ex:nickname>
+        <ex:nickname xml:lang="">Plain</ex:nickname>
+    </owl:NamedIndividual>
+</rdf:RDF>"#;
+
+fn parse(config: ParserConfig) -> Ontology {
+    RdfXmlParser::with_config(config).parse_str(PETS).unwrap()
+}
+
+/// The nicknames of rex with their language tags
+fn nicknames(ontology: &Ontology) -> HashSet<(String, Option<String>)> {
+    ontology
+        .data_property_assertions()
+        .into_iter()
+        .filter(|axiom| axiom.property().as_str() == "http://example.org/pets#nickname")
+        .map(|axiom| {
+            let literal = axiom.value();
+            (
+                literal.lexical_form().to_string(),
+                literal.language_tag().map(str::to_string),
+            )
+        })
+        .collect()
+}
+
+fn ontology_label(ontology: &Ontology) -> &Literal {
+    match ontology.annotations()[0].value() {
+        AnnotationValue::Literal(literal) => literal,
+        other => panic!("label is not a literal: {:?}", other),
+    }
+}
+
+fn tagged(values: &[(&str, Option<&str>)]) -> HashSet<(String, Option<String>)> {
+    values
+        .iter()
+        .map(|(value, language)| (value.to_string(), language.map(str::to_string)))
+        .collect()
+}
+
+#[test]
+fn test_nested_literals_inherit_enclosing_language() {
+    let expected = tagged(&[("Rex", Some("en")), ("Roi", Some("fr")), ("Plain", None)]);
+
+    // Streaming backend
+    assert_eq!(nicknames(&parse(ParserConfig::default())), expected);
+
+    // Legacy parser
+    let ontology = parse(ParserConfig {
+        strict_validation: true,
+        ..Default::default()
+    });
+    assert_eq!(nicknames(&ontology), expected);
+    let label = ontology_label(&ontology);
+    assert_eq!(label.lexical_form(), "Pets");
+    assert_eq!(label.language_tag(), Some("en"));
+}
+
+#[test]
+fn test_inheritance_disabled_keeps_only_own_language() {
+    let ontology = parse(ParserConfig {
+        inherit_xml_lang: false,
+        ..Default::default()
+    });
+    assert_eq!(
+        nicknames(&ontology),
+        tagged(&[("Rex", None), ("Roi", Some("fr")), ("Plain", None)])
+    );
+    let label = ontology_label(&ontology);
+    assert_eq!(label.language_tag(), None);
+}